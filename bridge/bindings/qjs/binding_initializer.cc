@@ -16,6 +16,7 @@
 #include "qjs_character_data.h"
 #include "qjs_close_event.h"
 #include "qjs_comment.h"
+#include "qjs_composition_event.h"
 #include "qjs_computed_css_style_declaration.h"
 #include "qjs_console.h"
 #include "qjs_css_style_declaration.h"
@@ -78,6 +79,7 @@
 #include "qjs_performance_measure.h"
 #include "qjs_pointer_event.h"
 #include "qjs_pop_state_event.h"
+#include "qjs_storage_event.h"
 #include "qjs_promise_rejection_event.h"
 #include "qjs_screen.h"
 #include "qjs_svg_circle_element.h"
@@ -100,6 +102,7 @@
 #include "qjs_touch_list.h"
 #include "qjs_transition_event.h"
 #include "qjs_ui_event.h"
+#include "qjs_wheel_event.h"
 #include "qjs_widget_element.h"
 #include "qjs_window.h"
 #include "qjs_window_or_worker_global_scope.h"
@@ -130,8 +133,11 @@ void InstallBindings(ExecutingContext* context) {
   QJSCustomEvent::Install(context);
   QJSMouseEvent::Install(context);
   QJSPointerEvent::Install(context);
+  QJSWheelEvent::Install(context);
+  QJSCompositionEvent::Install(context);
   QJSTouchEvent::Install(context);
   QJSPopStateEvent::Install(context);
+  QJSStorageEvent::Install(context);
   QJSTransitionEvent::Install(context);
   QJSIntersectionChangeEvent::Install(context);
   QJSKeyboardEvent::Install(context);
@@ -3,29 +3,56 @@
  * Copyright (C) 2022-present The WebF authors. All rights reserved.
  */
 #include "exception_state.h"
+#include <unordered_map>
 #include "plugin_api/exception_state.h"
 
 namespace webf {
 
+namespace {
+
+// https://webidl.spec.whatwg.org/#dfn-error-names-table
+uint16_t DOMExceptionCode(const std::string& name) {
+  static const std::unordered_map<std::string, uint16_t> codes{
+      {"IndexSizeError", 1},         {"HierarchyRequestError", 3},     {"WrongDocumentError", 4},
+      {"InvalidCharacterError", 5},  {"NoModificationAllowedError", 7}, {"NotFoundError", 8},
+      {"NotSupportedError", 9},      {"InUseAttributeError", 10},       {"InvalidStateError", 11},
+      {"SyntaxError", 12},           {"InvalidModificationError", 13},  {"NamespaceError", 14},
+      {"InvalidAccessError", 15},    {"TypeMismatchError", 17},         {"SecurityError", 18},
+      {"NetworkError", 19},          {"AbortError", 20},                {"URLMismatchError", 21},
+      {"QuotaExceededError", 22},    {"TimeoutError", 23},              {"InvalidNodeTypeError", 24},
+      {"DataCloneError", 25},
+  };
+  auto it = codes.find(name);
+  return it == codes.end() ? 0 : it->second;
+}
+
+}  // namespace
+
 ExceptionStatePublicMethods* ExceptionState::publicMethodPointer() {
   return new ExceptionStatePublicMethods();
 }
 
 void ExceptionState::ThrowException(JSContext* ctx, ErrorType type, const std::string& message) {
+  code_ = 0;
   switch (type) {
     case ErrorType::TypeError:
+      name_ = "TypeError";
       exception_ = JS_ThrowTypeError(ctx, "%s", message.c_str());
       break;
     case InternalError:
+      name_ = "InternalError";
       exception_ = JS_ThrowInternalError(ctx, "%s", message.c_str());
       break;
     case RangeError:
+      name_ = "RangeError";
       exception_ = JS_ThrowRangeError(ctx, "%s", message.c_str());
       break;
     case ReferenceError:
+      name_ = "ReferenceError";
       exception_ = JS_ThrowReferenceError(ctx, "%s", message.c_str());
       break;
     case SyntaxError:
+      name_ = "SyntaxError";
       exception_ = JS_ThrowSyntaxError(ctx, "%s", message.c_str());
       break;
   }
@@ -33,6 +60,38 @@ void ExceptionState::ThrowException(JSContext* ctx, ErrorType type, const std::s
 
 void ExceptionState::ThrowException(JSContext* ctx, JSValue exception) {
   exception_ = JS_DupValue(ctx, exception);
+  name_.clear();
+  code_ = 0;
+  if (!JS_IsObject(exception)) {
+    return;
+  }
+
+  JSValue name_value = JS_GetPropertyStr(ctx, exception, "name");
+  if (JS_IsString(name_value)) {
+    const char* name = JS_ToCString(ctx, name_value);
+    name_ = name;
+    JS_FreeCString(ctx, name);
+  }
+  JS_FreeValue(ctx, name_value);
+
+  // Only DOMExceptions have a code.
+  JSValue code_value = JS_GetPropertyStr(ctx, exception, "code");
+  uint32_t code = 0;
+  if (JS_IsNumber(code_value) && JS_ToUint32(ctx, &code, code_value) == 0 && code <= UINT16_MAX) {
+    code_ = static_cast<uint16_t>(code);
+  }
+  JS_FreeValue(ctx, code_value);
+}
+
+void ExceptionState::ThrowDOMException(JSContext* ctx, const char* name, const std::string& message) {
+  name_ = name;
+  code_ = DOMExceptionCode(name_);
+  JSValue error = JS_NewError(ctx);
+  JS_DefinePropertyValueStr(ctx, error, "name", JS_NewString(ctx, name), JS_PROP_WRITABLE | JS_PROP_CONFIGURABLE);
+  JS_DefinePropertyValueStr(ctx, error, "message", JS_NewString(ctx, message.c_str()),
+                            JS_PROP_WRITABLE | JS_PROP_CONFIGURABLE);
+  JS_DefinePropertyValueStr(ctx, error, "code", JS_NewUint32(ctx, code_), JS_PROP_CONFIGURABLE);
+  exception_ = JS_Throw(ctx, error);
 }
 
 bool ExceptionState::HasException() {
@@ -27,7 +27,17 @@ class ExceptionState {
 
   void ThrowException(JSContext* ctx, ErrorType type, const std::string& message);
   void ThrowException(JSContext* ctx, JSValue exception);
+  // Throws an error named as one of the DOMException names, such as NotFoundError.
+  void ThrowDOMException(JSContext* ctx, const char* name, const std::string& message);
   bool HasException();
+  // The name of the thrown exception, such as TypeError or NotFoundError. Empty when nothing is thrown.
+  const std::string& Name() const { return name_; }
+  // The legacy code of a DOMException, 0 for the other exceptions.
+  uint16_t Code() const { return code_; }
+  // Set for the exception states passed by native plugins, which are told about the calls that JavaScript ignores
+  // with a warning, such as preventDefault() in a passive listener.
+  void SetForNativePlugin() { for_native_plugin_ = true; }
+  bool IsForNativePlugin() const { return for_native_plugin_; }
 
   ExceptionState& ReturnThis();
 
@@ -36,6 +46,9 @@ class ExceptionState {
 
  private:
   JSValue exception_{JS_NULL};
+  std::string name_;
+  uint16_t code_{0};
+  bool for_native_plugin_{false};
 };
 
 }  // namespace webf
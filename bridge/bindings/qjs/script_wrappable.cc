@@ -4,6 +4,7 @@
  */
 
 #include "script_wrappable.h"
+#include <algorithm>
 #include <quickjs/quickjs.h>
 #include "built_in_string.h"
 #include "core/executing_context.h"
@@ -22,6 +23,10 @@ ScriptWrappable::~ScriptWrappable() {
   if (status_block_ != nullptr) {
     status_block_->disposed = true;
   }
+  // The weak status blocks are owned by their holders, which free them after seeing they are disposed.
+  for (auto* weak_status_block : weak_status_blocks_) {
+    weak_status_block->disposed = true;
+  }
 }
 
 JSValue ScriptWrappable::ToQuickJS() const {
@@ -321,4 +326,18 @@ void ScriptWrappable::ReleaseAlive() {
   }
 }
 
+WebFValueStatus* ScriptWrappable::KeepWeak() {
+  auto* weak_status_block = new WebFValueStatus();
+  weak_status_blocks_.emplace_back(weak_status_block);
+  return weak_status_block;
+}
+
+void ScriptWrappable::ReleaseWeak(WebFValueStatus* status_block) {
+  auto it = std::find(weak_status_blocks_.begin(), weak_status_blocks_.end(), status_block);
+  if (it != weak_status_blocks_.end()) {
+    weak_status_blocks_.erase(it);
+  }
+  delete status_block;
+}
+
 }  // namespace webf
@@ -7,6 +7,7 @@
 #define BRIDGE_SCRIPT_WRAPPABLE_H
 
 #include <quickjs/quickjs.h>
+#include <vector>
 #include "bindings/qjs/cppgc/garbage_collected.h"
 #include "foundation/macros.h"
 #include "multiple_threading/dispatcher.h"
@@ -68,6 +69,13 @@ class ScriptWrappable : public GarbageCollected<ScriptWrappable> {
   WebFValueStatus* KeepAlive();
   void ReleaseAlive();
 
+  /**
+   * Weak references which don't keep this object alive, each of them gets its own status block which is marked as
+   * disposed once this object is destroyed. Release them via `ReleaseWeak` method.
+   */
+  WebFValueStatus* KeepWeak();
+  void ReleaseWeak(WebFValueStatus* status_block);
+
  private:
   uint32_t alive_count = 0;
   JSValue jsObject_{JS_NULL};
@@ -76,6 +84,7 @@ class ScriptWrappable : public GarbageCollected<ScriptWrappable> {
   double context_id_;
   JSRuntime* runtime_{nullptr};
   WebFValueStatus* status_block_{nullptr};
+  std::vector<WebFValueStatus*> weak_status_blocks_;
   friend class GCVisitor;
 };
 
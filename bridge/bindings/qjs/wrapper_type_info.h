@@ -25,6 +25,8 @@ enum {
   JS_CLASS_CLOSE_EVENT,
   JS_CLASS_TOUCH_EVENT,
   JS_CLASS_POINTER_EVENT,
+  JS_CLASS_WHEEL_EVENT,
+  JS_CLASS_COMPOSITION_EVENT,
   JS_CLASS_MOUSE_EVENT,
   JS_CLASS_CUSTOM_EVENT,
   JS_CLASS_TRANSITION_EVENT,
@@ -35,6 +37,7 @@ enum {
   JS_CLASS_GESTURE_EVENT,
   JS_CLASS_HASHCHANGE_EVENT,
   JS_CLASS_POP_STATE_EVENT,
+  JS_CLASS_STORAGE_EVENT,
   JS_CLASS_INTERSECTION_CHANGE_EVENT,
   JS_CLASS_KEYBOARD_EVENT,
   JS_CLASS_PROMISE_REJECTION_EVENT,
@@ -0,0 +1,213 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/canvas_rendering_context_2d.h"
+#include <string>
+#include <vector>
+#include "binding_call_methods.h"
+#include "core/api/exception_state.h"
+#include "core/html/canvas/canvas_rendering_context_2d.h"
+#include "foundation/native_value_converter.h"
+
+namespace webf {
+
+namespace {
+
+// Invokes the Dart implemented method which only takes numbers.
+void InvokeMethod(CanvasRenderingContext2D* context,
+                  const AtomicString& method,
+                  std::initializer_list<double> values,
+                  SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  std::vector<NativeValue> arguments;
+  arguments.reserve(values.size());
+  for (double value : values) {
+    arguments.emplace_back(NativeValueConverter<NativeTypeDouble>::ToNativeValue(value));
+  }
+  context->InvokeBindingMethod(method, static_cast<int32_t>(arguments.size()), arguments.data(),
+                               FlushUICommandReason::kDependentsOnElement, shared_exception_state->exception_state);
+}
+
+// The length of the bytes passed to Dart is 32 bits.
+bool CheckBytesLength(CanvasRenderingContext2D* context,
+                      int64_t length,
+                      const char* method,
+                      SharedExceptionState* shared_exception_state) {
+  if (length >= 0 && length <= UINT32_MAX) {
+    return true;
+  }
+  std::string message =
+      std::string("Failed to execute '") + method + "': The length of the bytes must not be larger than 4294967295.";
+  shared_exception_state->exception_state.ThrowException(context->ctx(), ErrorType::RangeError, message.c_str());
+  return false;
+}
+
+}  // namespace
+
+void CanvasRenderingContext2DPublicMethods::FillRect(CanvasRenderingContext2D* context,
+                                                     double x,
+                                                     double y,
+                                                     double w,
+                                                     double h,
+                                                     SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::kfillRect, {x, y, w, h}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::StrokeRect(CanvasRenderingContext2D* context,
+                                                       double x,
+                                                       double y,
+                                                       double w,
+                                                       double h,
+                                                       SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::kstrokeRect, {x, y, w, h}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::ClearRect(CanvasRenderingContext2D* context,
+                                                      double x,
+                                                      double y,
+                                                      double w,
+                                                      double h,
+                                                      SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::kclearRect, {x, y, w, h}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::BeginPath(CanvasRenderingContext2D* context,
+                                                      SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::kbeginPath, {}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::MoveTo(CanvasRenderingContext2D* context,
+                                                   double x,
+                                                   double y,
+                                                   SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::kmoveTo, {x, y}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::LineTo(CanvasRenderingContext2D* context,
+                                                   double x,
+                                                   double y,
+                                                   SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::klineTo, {x, y}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::Arc(CanvasRenderingContext2D* context,
+                                                double x,
+                                                double y,
+                                                double radius,
+                                                double start_angle,
+                                                double end_angle,
+                                                int32_t anticlockwise,
+                                                SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  NativeValue arguments[] = {NativeValueConverter<NativeTypeDouble>::ToNativeValue(x),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(y),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(radius),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(start_angle),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(end_angle),
+                             NativeValueConverter<NativeTypeBool>::ToNativeValue(anticlockwise != 0)};
+  context->InvokeBindingMethod(binding_call_methods::karc, sizeof(arguments) / sizeof(NativeValue), arguments,
+                               FlushUICommandReason::kDependentsOnElement, shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::Fill(CanvasRenderingContext2D* context,
+                                                 SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  context->fill(shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::Stroke(CanvasRenderingContext2D* context,
+                                                   SharedExceptionState* shared_exception_state) {
+  InvokeMethod(context, binding_call_methods::kstroke, {}, shared_exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::SetFillStyle(CanvasRenderingContext2D* context,
+                                                         const char* style,
+                                                         SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  NativeValue value =
+      NativeValueConverter<NativeTypeString>::ToNativeValue(context->ctx(), AtomicString(context->ctx(), style));
+  context->SetBindingProperty(binding_call_methods::kfillStyle, value, shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::SetFont(CanvasRenderingContext2D* context,
+                                                    const char* font,
+                                                    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  NativeValue value =
+      NativeValueConverter<NativeTypeString>::ToNativeValue(context->ctx(), AtomicString(context->ctx(), font));
+  context->SetBindingProperty(binding_call_methods::kfont, value, shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::FillText(CanvasRenderingContext2D* context,
+                                                     const char* text,
+                                                     double x,
+                                                     double y,
+                                                     SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  NativeValue arguments[] = {
+      NativeValueConverter<NativeTypeString>::ToNativeValue(context->ctx(), AtomicString(context->ctx(), text)),
+      NativeValueConverter<NativeTypeDouble>::ToNativeValue(x),
+      NativeValueConverter<NativeTypeDouble>::ToNativeValue(y)};
+  context->InvokeBindingMethod(binding_call_methods::kfillText, sizeof(arguments) / sizeof(NativeValue), arguments,
+                               FlushUICommandReason::kDependentsOnElement, shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::DrawImageBytes(CanvasRenderingContext2D* context,
+                                                           const uint8_t* bytes,
+                                                           int64_t length,
+                                                           double dx,
+                                                           double dy,
+                                                           SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  if (!CheckBytesLength(context, length, "drawImageBytes", shared_exception_state)) {
+    return;
+  }
+  // The bytes are copied by the Dart side during the call, so they are only borrowed here.
+  NativeValue arguments[] = {Native_NewUint8Bytes(static_cast<uint32_t>(length), const_cast<uint8_t*>(bytes)),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(dx),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(dy)};
+  context->InvokeBindingMethod(binding_call_methods::k__drawImageBytes__, sizeof(arguments) / sizeof(NativeValue),
+                               arguments, FlushUICommandReason::kDependentsOnElement,
+                               shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::PutImageData(CanvasRenderingContext2D* context,
+                                                         const uint8_t* data,
+                                                         int64_t length,
+                                                         int64_t width,
+                                                         int64_t height,
+                                                         double dx,
+                                                         double dy,
+                                                         SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  if (!CheckBytesLength(context, length, "putImageData", shared_exception_state)) {
+    return;
+  }
+  NativeValue arguments[] = {Native_NewUint8Bytes(static_cast<uint32_t>(length), const_cast<uint8_t*>(data)),
+                             NativeValueConverter<NativeTypeInt64>::ToNativeValue(width),
+                             NativeValueConverter<NativeTypeInt64>::ToNativeValue(height),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(dx),
+                             NativeValueConverter<NativeTypeDouble>::ToNativeValue(dy)};
+  context->InvokeBindingMethod(binding_call_methods::k__putImageData__, sizeof(arguments) / sizeof(NativeValue),
+                               arguments, FlushUICommandReason::kDependentsOnElement,
+                               shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::GetImageData(CanvasRenderingContext2D* context,
+                                                         int64_t sx,
+                                                         int64_t sy,
+                                                         int64_t sw,
+                                                         int64_t sh,
+                                                         WebFNativeFunctionContext* callback_context,
+                                                         SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context->GetExecutingContext()};
+  auto callback_impl = WebFNativeFunction::Create(callback_context, shared_exception_state);
+  context->getImageData(sx, sy, sw, sh, callback_impl, shared_exception_state->exception_state);
+}
+
+void CanvasRenderingContext2DPublicMethods::Release(CanvasRenderingContext2D* context) {
+  context->ReleaseAlive();
+}
+
+}  // namespace webf
@@ -3,6 +3,27 @@
  */
 
 #include "plugin_api/character_data.h"
-#include "core/dom/node.h"
+#include "core/api/exception_state.h"
+#include "core/dom/character_data.h"
+#include "foundation/dart_readable.h"
 
-namespace webf {}  // namespace webf
\ No newline at end of file
+namespace webf {
+
+char* CharacterDataPublicMethods::Data(CharacterData* character_data, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{character_data->GetExecutingContext()};
+  return CopyToCallerOwnedString(character_data->data().ToStdString(character_data->ctx()));
+}
+
+void CharacterDataPublicMethods::SetData(CharacterData* character_data,
+                                         const char* data,
+                                         SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{character_data->GetExecutingContext()};
+  AtomicString data_atomic = AtomicString(character_data->ctx(), data);
+  character_data->setData(data_atomic, shared_exception_state->exception_state);
+}
+
+int64_t CharacterDataPublicMethods::Length(CharacterData* character_data) {
+  return character_data->length();
+}
+
+}  // namespace webf
@@ -0,0 +1,107 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/css_style_declaration.h"
+#include <cctype>
+#include "core/api/exception_state.h"
+#include "core/css/css_style_declaration.h"
+#include "core/executing_context.h"
+#include "foundation/dart_readable.h"
+
+namespace webf {
+
+namespace {
+
+bool IsImportantPriority(const char* priority) {
+  static const char kImportant[] = "important";
+  for (size_t i = 0; i < sizeof(kImportant); i++) {
+    if (std::tolower(static_cast<unsigned char>(priority[i])) != kImportant[i]) {
+      return false;
+    }
+  }
+  return true;
+}
+
+// https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-setproperty
+// Computed styles are read-only, so changing them throws instead of being forwarded to the element.
+bool ThrowIfReadOnly(CSSStyleDeclaration* style_declaration,
+                     const char* method,
+                     const char* property,
+                     SharedExceptionState* shared_exception_state) {
+  if (!style_declaration->IsComputedCssStyleDeclaration()) {
+    return false;
+  }
+  shared_exception_state->exception_state.ThrowException(
+      style_declaration->ctx(), ErrorType::TypeError,
+      std::string("Failed to execute '") + method +
+          "' on 'CSSStyleDeclaration': These styles are computed, and therefore the '" + property +
+          "' property is read-only.");
+  return true;
+}
+
+}  // namespace
+
+char* CSSStyleDeclarationPublicMethods::GetPropertyValue(CSSStyleDeclaration* style_declaration,
+                                                         const char* property,
+                                                         SharedExceptionState* shared_exception_state) {
+  AtomicString value = style_declaration->getPropertyValue(AtomicString(style_declaration->ctx(), property),
+                                                           shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return nullptr;
+  }
+  // Properties which are not set read as empty strings, as in JavaScript.
+  if (value.IsNull()) {
+    return CopyToCallerOwnedString("");
+  }
+  return CopyToCallerOwnedString(value.ToStdString(style_declaration->ctx()));
+}
+
+void CSSStyleDeclarationPublicMethods::SetProperty(CSSStyleDeclaration* style_declaration,
+                                                   const char* property,
+                                                   const char* value,
+                                                   const char* priority,
+                                                   SharedExceptionState* shared_exception_state) {
+  if (ThrowIfReadOnly(style_declaration, "setProperty", property, shared_exception_state)) {
+    return;
+  }
+  JSContext* ctx = style_declaration->ctx();
+  // Inline styles are always applied with the important priority, so "important" goes through the same path as "".
+  if (priority != nullptr && priority[0] != '\0' && !IsImportantPriority(priority)) {
+    shared_exception_state->exception_state.ThrowDOMException(
+        ctx, "SyntaxError",
+        std::string("Failed to execute 'setProperty' on 'CSSStyleDeclaration': The priority '") + priority +
+            "' is not valid.");
+    return;
+  }
+
+  MemberMutationScope scope{style_declaration->GetExecutingContext()};
+  AtomicString property_atomic = AtomicString(ctx, property);
+  if (value[0] == '\0') {
+    style_declaration->removeProperty(property_atomic, shared_exception_state->exception_state);
+    return;
+  }
+  style_declaration->setProperty(property_atomic, ScriptValue(ctx, AtomicString(ctx, value)),
+                                 shared_exception_state->exception_state);
+}
+
+char* CSSStyleDeclarationPublicMethods::RemoveProperty(CSSStyleDeclaration* style_declaration,
+                                                       const char* property,
+                                                       SharedExceptionState* shared_exception_state) {
+  if (ThrowIfReadOnly(style_declaration, "removeProperty", property, shared_exception_state)) {
+    return nullptr;
+  }
+  MemberMutationScope scope{style_declaration->GetExecutingContext()};
+  AtomicString old_value = style_declaration->removeProperty(AtomicString(style_declaration->ctx(), property),
+                                                             shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return nullptr;
+  }
+  return CopyToCallerOwnedString(old_value.ToStdString(style_declaration->ctx()));
+}
+
+void CSSStyleDeclarationPublicMethods::Release(CSSStyleDeclaration* style_declaration) {
+  style_declaration->ReleaseAlive();
+}
+
+}  // namespace webf
@@ -0,0 +1,109 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/css_style_sheet.h"
+#include "binding_call_methods.h"
+#include "core/api/exception_state.h"
+#include "core/dom/element.h"
+#include "foundation/dart_readable.h"
+#include "foundation/native_value_converter.h"
+
+namespace webf {
+
+namespace {
+
+// The results of `__insertRule__` on the Dart side when no rule is inserted, see StyleElementMixin.
+constexpr int64_t kInsertRuleIndexOutOfRange = -1;
+constexpr int64_t kInsertRuleSyntaxError = -2;
+
+}  // namespace
+
+int64_t CSSStyleSheetPublicMethods::InsertRule(Element* owner_node,
+                                               const char* rule,
+                                               int64_t index,
+                                               SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{owner_node->GetExecutingContext()};
+  if (index < 0) {
+    shared_exception_state->exception_state.ThrowDOMException(
+        owner_node->ctx(), "IndexSizeError",
+        "Failed to execute 'insertRule' on 'CSSStyleSheet': The index provided (" + std::to_string(index) +
+            ") is negative.");
+    return 0;
+  }
+  NativeValue arguments[] = {NativeValueConverter<NativeTypeString>::ToNativeValue(rule),
+                             NativeValueConverter<NativeTypeInt64>::ToNativeValue(index)};
+  NativeValue result = owner_node->InvokeBindingMethod(
+      binding_call_methods::k__insertRule__, sizeof(arguments) / sizeof(NativeValue), arguments,
+      FlushUICommandReason::kDependentsOnElement, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return 0;
+  }
+
+  int64_t inserted_index = NativeValueConverter<NativeTypeInt64>::FromNativeValue(result);
+  if (inserted_index == kInsertRuleIndexOutOfRange) {
+    shared_exception_state->exception_state.ThrowDOMException(
+        owner_node->ctx(), "IndexSizeError",
+        "Failed to execute 'insertRule' on 'CSSStyleSheet': The index provided (" + std::to_string(index) +
+            ") is larger than the number of rules.");
+    return 0;
+  }
+  if (inserted_index == kInsertRuleSyntaxError) {
+    shared_exception_state->exception_state.ThrowDOMException(
+        owner_node->ctx(), "SyntaxError",
+        std::string("Failed to execute 'insertRule' on 'CSSStyleSheet': Failed to parse the rule '") + rule + "'.");
+    return 0;
+  }
+  return inserted_index;
+}
+
+void CSSStyleSheetPublicMethods::DeleteRule(Element* owner_node,
+                                            int64_t index,
+                                            SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{owner_node->GetExecutingContext()};
+  NativeValue arguments[] = {NativeValueConverter<NativeTypeInt64>::ToNativeValue(index)};
+  NativeValue result = owner_node->InvokeBindingMethod(
+      binding_call_methods::k__deleteRule__, sizeof(arguments) / sizeof(NativeValue), arguments,
+      FlushUICommandReason::kDependentsOnElement, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return;
+  }
+
+  if (!NativeValueConverter<NativeTypeBool>::FromNativeValue(result)) {
+    shared_exception_state->exception_state.ThrowDOMException(
+        owner_node->ctx(), "IndexSizeError",
+        "Failed to execute 'deleteRule' on 'CSSStyleSheet': The index provided (" + std::to_string(index) +
+            ") is outside the range of rules.");
+  }
+}
+
+char** CSSStyleSheetPublicMethods::CSSRules(Element* owner_node,
+                                            int64_t* length,
+                                            SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{owner_node->GetExecutingContext()};
+  NativeValue result = owner_node->InvokeBindingMethod(binding_call_methods::k__cssRules__, 0, nullptr,
+                                                       FlushUICommandReason::kDependentsOnElement,
+                                                       shared_exception_state->exception_state);
+  *length = 0;
+  if (shared_exception_state->exception_state.HasException()) {
+    return nullptr;
+  }
+
+  auto&& rules = NativeValueConverter<NativeTypeArray<NativeTypeString>>::FromNativeValue(owner_node->ctx(), result);
+  *length = static_cast<int64_t>(rules.size());
+  if (rules.empty()) {
+    return nullptr;
+  }
+
+  auto* texts = static_cast<char**>(dart_malloc(sizeof(char*) * rules.size()));
+  for (size_t i = 0; i < rules.size(); i++) {
+    texts[i] = CopyToCallerOwnedString(rules[i].ToStdString(owner_node->ctx()));
+  }
+  return texts;
+}
+
+void CSSStyleSheetPublicMethods::Release(Element* owner_node) {
+  owner_node->ReleaseAlive();
+}
+
+}  // namespace webf
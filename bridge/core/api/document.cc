@@ -8,8 +8,14 @@
 #include "core/dom/comment.h"
 #include "core/dom/document.h"
 #include "core/dom/document_fragment.h"
+#include "core/dom/events/custom_event.h"
 #include "core/dom/events/event.h"
+#include "core/dom/static_node_list.h"
 #include "core/dom/text.h"
+#include "core/events/close_event.h"
+#include "core/events/mouse_event.h"
+#include "core/events/pointer_event.h"
+#include "core/native/script_promise_ref.h"
 #include "core/html/html_body_element.h"
 #include "core/html/html_head_element.h"
 #include "core/html/html_html_element.h"
@@ -183,7 +189,7 @@ WebFValue<Element, ElementPublicMethods> DocumentPublicMethods::QuerySelector(
   webf::AtomicString selectors_atomic = webf::AtomicString(document->ctx(), selectors);
   Element* element = document->querySelector(selectors_atomic, shared_exception_state->exception_state);
 
-  if (shared_exception_state->exception_state.HasException()) {
+  if (shared_exception_state->exception_state.HasException() || element == nullptr) {
     return WebFValue<Element, ElementPublicMethods>::Null();
   }
 
@@ -201,7 +207,7 @@ WebFValue<Element, ElementPublicMethods> DocumentPublicMethods::GetElementById(
   webf::AtomicString id_atomic = webf::AtomicString(document->ctx(), id);
   Element* element = document->getElementById(id_atomic, shared_exception_state->exception_state);
 
-  if (shared_exception_state->exception_state.HasException()) {
+  if (shared_exception_state->exception_state.HasException() || element == nullptr) {
     return WebFValue<Element, ElementPublicMethods>::Null();
   }
 
@@ -252,4 +258,227 @@ void DocumentPublicMethods::ClearCookie(webf::Document* document, webf::SharedEx
                                 FlushUICommandReason::kDependentsOnElement, shared_exception_state->exception_state);
 }
 
+WebFValue<NodeList, NodeListPublicMethods> DocumentPublicMethods::QuerySelectorAll(
+    webf::Document* ptr,
+    const char* selectors,
+    webf::SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString selectors_atomic = webf::AtomicString(document->ctx(), selectors);
+  std::vector<Element*> elements =
+      document->querySelectorAll(selectors_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<NodeList, NodeListPublicMethods>::Null();
+  }
+
+  std::vector<Member<Node>> nodes(elements.begin(), elements.end());
+  NodeList* node_list = StaticNodeList::Adopt(document->ctx(), nodes);
+  WebFValueStatus* status_block = node_list->KeepAlive();
+
+  return WebFValue<NodeList, NodeListPublicMethods>(node_list, node_list->nodeListPublicMethods(), status_block);
+}
+
+WebFValue<CustomEvent, CustomEventPublicMethods> DocumentPublicMethods::CreateCustomEvent(
+    webf::Document* ptr,
+    const char* type,
+    const char* detail_json,
+    int32_t bubbles,
+    int32_t cancelable,
+    webf::SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString type_atomic = webf::AtomicString(document->ctx(), type);
+
+  // A null detail leaves the detail of the event undefined.
+  ScriptValue detail = ScriptValue::Empty(document->ctx());
+  if (detail_json != nullptr) {
+    detail = ScriptValue::CreateJsonObject(document->ctx(), detail_json, strlen(detail_json));
+    if (detail.IsException()) {
+      JSValue error = JS_GetException(document->ctx());
+      shared_exception_state->exception_state.ThrowException(document->ctx(), error);
+      JS_FreeValue(document->ctx(), error);
+      return WebFValue<CustomEvent, CustomEventPublicMethods>::Null();
+    }
+  }
+
+  auto* event = CustomEvent::Create(document->GetExecutingContext(), type_atomic,
+                                    shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<CustomEvent, CustomEventPublicMethods>::Null();
+  }
+  event->initCustomEvent(type_atomic, bubbles, cancelable, detail, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<CustomEvent, CustomEventPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = event->KeepAlive();
+  return WebFValue<CustomEvent, CustomEventPublicMethods>(event, event->customEventPublicMethods(), status_block);
+}
+
+WebFValue<NodeList, HTMLCollectionPublicMethods> DocumentPublicMethods::GetElementsByClassName(
+    Document* ptr,
+    const char* class_names,
+    SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString class_names_atomic = webf::AtomicString(document->ctx(), class_names);
+  std::vector<Element*> elements = document->getElementsByClassName(class_names_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<NodeList, HTMLCollectionPublicMethods>::Null();
+  }
+
+  std::vector<Member<Node>> nodes(elements.begin(), elements.end());
+  StaticNodeList* collection = StaticNodeList::Adopt(document->ctx(), nodes);
+  WebFValueStatus* status_block = collection->KeepAlive();
+
+  return WebFValue<NodeList, HTMLCollectionPublicMethods>(collection, collection->htmlCollectionPublicMethods(),
+                                                          status_block);
+}
+
+WebFValue<NodeList, HTMLCollectionPublicMethods> DocumentPublicMethods::GetElementsByTagName(
+    Document* ptr,
+    const char* tag_name,
+    SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString tag_name_atomic = webf::AtomicString(document->ctx(), tag_name);
+  std::vector<Element*> elements = document->getElementsByTagName(tag_name_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<NodeList, HTMLCollectionPublicMethods>::Null();
+  }
+
+  std::vector<Member<Node>> nodes(elements.begin(), elements.end());
+  StaticNodeList* collection = StaticNodeList::Adopt(document->ctx(), nodes);
+  WebFValueStatus* status_block = collection->KeepAlive();
+
+  return WebFValue<NodeList, HTMLCollectionPublicMethods>(collection, collection->htmlCollectionPublicMethods(),
+                                                          status_block);
+}
+
+WebFValue<MouseEvent, MouseEventPublicMethods> DocumentPublicMethods::CreateMouseEvent(
+    Document* ptr,
+    const char* type,
+    const WebFMouseEventInit* init,
+    SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString type_atomic = webf::AtomicString(document->ctx(), type);
+
+  auto* event = MouseEvent::Create(document->GetExecutingContext(), type_atomic,
+                                   shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<MouseEvent, MouseEventPublicMethods>::Null();
+  }
+  event->initMouseEvent(type_atomic, init->bubbles, init->cancelable, init->client_x, init->client_y, init->ctrl_key,
+                        init->alt_key, init->shift_key, init->meta_key, init->button, init->buttons,
+                        shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<MouseEvent, MouseEventPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = event->KeepAlive();
+  return WebFValue<MouseEvent, MouseEventPublicMethods>(event, event->mouseEventPublicMethods(), status_block);
+}
+
+WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods> DocumentPublicMethods::ExitFullscreen(
+    Document* ptr,
+    SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  ScriptPromise promise = document->exitFullscreen(shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods>::Null();
+  }
+  auto* script_promise_ref = new ScriptPromiseRef{document->GetExecutingContext(), promise};
+  return WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods>(script_promise_ref,
+                                                                     ScriptPromiseRef::publicMethods(), nullptr);
+}
+
+WebFValue<Element, ElementPublicMethods> DocumentPublicMethods::FullscreenElement(Document* ptr) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  Element* element = document->fullscreenElement();
+  if (element == nullptr) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = element->KeepAlive();
+  return WebFValue<Element, ElementPublicMethods>(element, element->elementPublicMethods(), status_block);
+}
+
+WebFValue<CloseEvent, CloseEventPublicMethods> DocumentPublicMethods::CreateCloseEvent(
+    Document* ptr,
+    const char* type,
+    const WebFCloseEventInit* init,
+    SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString type_atomic = webf::AtomicString(document->ctx(), type);
+  webf::AtomicString reason_atomic =
+      init->reason != nullptr ? webf::AtomicString(document->ctx(), init->reason) : webf::AtomicString::Empty();
+
+  auto* event = CloseEvent::Create(document->GetExecutingContext(), type_atomic, static_cast<int32_t>(init->code),
+                                   reason_atomic, init->was_clean, shared_exception_state->exception_state);
+  event->initEvent(type_atomic, init->bubbles, init->cancelable, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<CloseEvent, CloseEventPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = event->KeepAlive();
+  return WebFValue<CloseEvent, CloseEventPublicMethods>(event, event->closeEventPublicMethods(), status_block);
+}
+
+int32_t DocumentPublicMethods::ReadyState(Document* ptr) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  std::string ready_state = document->readyState().ToStdString(document->ctx());
+  if (ready_state == "complete") {
+    return 2;
+  }
+  if (ready_state == "interactive") {
+    return 1;
+  }
+  return 0;
+}
+
+WebFValue<PointerEvent, PointerEventPublicMethods> DocumentPublicMethods::CreatePointerEvent(
+    Document* ptr,
+    const char* type,
+    int32_t bubbles,
+    int32_t cancelable,
+    const WebFPointerEventInit* init,
+    SharedExceptionState* shared_exception_state) {
+  auto* document = static_cast<webf::Document*>(ptr);
+  MemberMutationScope scope{document->GetExecutingContext()};
+  webf::AtomicString type_atomic = webf::AtomicString(document->ctx(), type);
+
+  std::shared_ptr<PointerEventInit> initializer = PointerEventInit::Create();
+  initializer->setBubbles(bubbles);
+  initializer->setCancelable(cancelable);
+  initializer->setIsPrimary(init->is_primary);
+  initializer->setPointerId(init->pointer_id);
+  if (init->pointer_type != nullptr) {
+    initializer->setPointerType(webf::AtomicString(document->ctx(), init->pointer_type));
+  }
+  initializer->setPressure(init->pressure);
+  initializer->setTangentialPressure(init->tangential_pressure);
+  initializer->setTiltX(init->tilt_x);
+  initializer->setTiltY(init->tilt_y);
+  initializer->setTwist(init->twist);
+  initializer->setWidth(init->width);
+  initializer->setHeight(init->height);
+
+  auto* event = PointerEvent::Create(document->GetExecutingContext(), type_atomic, initializer,
+                                     shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<PointerEvent, PointerEventPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = event->KeepAlive();
+  return WebFValue<PointerEvent, PointerEventPublicMethods>(event, event->pointerEventPublicMethods(), status_block);
+}
+
 }  // namespace webf
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/dom_string_map.h"
+#include "core/api/exception_state.h"
+#include "core/dom/dom_string_map.h"
+#include "foundation/dart_readable.h"
+
+namespace webf {
+
+char* DOMStringMapPublicMethods::Get(DOMStringMap* dom_string_map,
+                                     const char* key,
+                                     SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_string_map->GetExecutingContext()};
+  AtomicString key_atomic = AtomicString(dom_string_map->ctx(), key);
+  if (!dom_string_map->NamedPropertyQuery(key_atomic, shared_exception_state->exception_state)) {
+    return nullptr;
+  }
+
+  AtomicString value = dom_string_map->item(key_atomic, shared_exception_state->exception_state);
+  return CopyToCallerOwnedString(value.ToStdString(dom_string_map->ctx()));
+}
+
+void DOMStringMapPublicMethods::Set(DOMStringMap* dom_string_map,
+                                    const char* key,
+                                    const char* value,
+                                    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_string_map->GetExecutingContext()};
+  dom_string_map->SetItem(AtomicString(dom_string_map->ctx(), key), AtomicString(dom_string_map->ctx(), value),
+                          shared_exception_state->exception_state);
+}
+
+void DOMStringMapPublicMethods::Remove(DOMStringMap* dom_string_map,
+                                       const char* key,
+                                       SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_string_map->GetExecutingContext()};
+  dom_string_map->DeleteItem(AtomicString(dom_string_map->ctx(), key), shared_exception_state->exception_state);
+}
+
+WebFDOMStringMapEntry* DOMStringMapPublicMethods::Entries(DOMStringMap* dom_string_map,
+                                                          int64_t* length,
+                                                          SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_string_map->GetExecutingContext()};
+  std::vector<AtomicString> keys;
+  dom_string_map->NamedPropertyEnumerator(keys, shared_exception_state->exception_state);
+  *length = static_cast<int64_t>(keys.size());
+  if (keys.empty()) {
+    return nullptr;
+  }
+
+  JSContext* ctx = dom_string_map->ctx();
+  auto* result = static_cast<WebFDOMStringMapEntry*>(dart_malloc(sizeof(WebFDOMStringMapEntry) * keys.size()));
+  for (size_t i = 0; i < keys.size(); i++) {
+    AtomicString value = dom_string_map->item(keys[i], shared_exception_state->exception_state);
+    result[i] = WebFDOMStringMapEntry{
+        CopyToCallerOwnedString(keys[i].ToStdString(ctx)),
+        CopyToCallerOwnedString(value.ToStdString(ctx)),
+    };
+  }
+  return result;
+}
+
+void DOMStringMapPublicMethods::Release(DOMStringMap* dom_string_map) {
+  dom_string_map->ReleaseAlive();
+}
+
+}  // namespace webf
@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/dom_token_list.h"
+#include "core/api/exception_state.h"
+#include "core/dom/dom_token_list.h"
+
+namespace webf {
+
+namespace {
+
+std::vector<AtomicString> ToAtomicStrings(JSContext* ctx, const char** tokens, uint32_t token_count) {
+  std::vector<AtomicString> result;
+  result.reserve(token_count);
+  for (uint32_t i = 0; i < token_count; i++) {
+    result.emplace_back(ctx, tokens[i]);
+  }
+  return result;
+}
+
+}  // namespace
+
+uint32_t DOMTokenListPublicMethods::Length(DOMTokenList* dom_token_list) {
+  return dom_token_list->length();
+}
+
+void DOMTokenListPublicMethods::Add(DOMTokenList* dom_token_list,
+                                    const char** tokens,
+                                    uint32_t token_count,
+                                    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_token_list->GetExecutingContext()};
+  dom_token_list->add(ToAtomicStrings(dom_token_list->ctx(), tokens, token_count),
+                      shared_exception_state->exception_state);
+}
+
+void DOMTokenListPublicMethods::Remove(DOMTokenList* dom_token_list,
+                                       const char** tokens,
+                                       uint32_t token_count,
+                                       SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_token_list->GetExecutingContext()};
+  dom_token_list->remove(ToAtomicStrings(dom_token_list->ctx(), tokens, token_count),
+                         shared_exception_state->exception_state);
+}
+
+int32_t DOMTokenListPublicMethods::Toggle(DOMTokenList* dom_token_list,
+                                          const char* token,
+                                          SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{dom_token_list->GetExecutingContext()};
+  return dom_token_list->toggle(AtomicString(dom_token_list->ctx(), token), shared_exception_state->exception_state);
+}
+
+int32_t DOMTokenListPublicMethods::Contains(DOMTokenList* dom_token_list,
+                                            const char* token,
+                                            SharedExceptionState* shared_exception_state) {
+  return dom_token_list->contains(AtomicString(dom_token_list->ctx(), token),
+                                  shared_exception_state->exception_state);
+}
+
+void DOMTokenListPublicMethods::Release(DOMTokenList* dom_token_list) {
+  dom_token_list->ReleaseAlive();
+}
+
+}  // namespace webf
@@ -3,12 +3,41 @@
  */
 
 #include "plugin_api/element.h"
+#include <algorithm>
+#include "binding_call_methods.h"
+#include "canvas_types.h"
 #include "core/api/exception_state.h"
+#include "core/css/inline_css_style_declaration.h"
 #include "core/dom/container_node.h"
+#include "core/dom/dom_string_map.h"
+#include "core/dom/dom_token_list.h"
 #include "core/dom/element.h"
+#include "core/dom/legacy/bounding_client_rect.h"
+#include "core/dom/static_node_list.h"
+#include "core/html/canvas/canvas_rendering_context_2d.h"
+#include "core/html/canvas/html_canvas_element.h"
+#include "core/native/script_promise_ref.h"
+#include "foundation/dart_readable.h"
+#include "foundation/native_value_converter.h"
+#include "html_names.h"
 
 namespace webf {
 
+namespace {
+
+// Reads a property which depends on the layout, such as `offsetWidth`, from the Dart side.
+double GetLayoutDependentProperty(Element* element,
+                                  const AtomicString& property,
+                                  SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{element->GetExecutingContext()};
+  NativeValue value = element->GetBindingProperty(
+      property, FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+}  // namespace
+
 void ElementPublicMethods::ToBlob(Element* ptr,
                                   WebFNativeFunctionContext* callback_context,
                                   SharedExceptionState* shared_exception_state) {
@@ -26,4 +55,473 @@ void ElementPublicMethods::ToBlobWithDevicePixelRatio(Element* ptr,
   return element->toBlob(device_pixel_ratio, callback_impl, shared_exception_state->exception_state);
 }
 
+WebFValue<Element, ElementPublicMethods> ElementPublicMethods::QuerySelector(
+    Element* ptr,
+    const char* selectors,
+    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString selectors_atomic = webf::AtomicString(element->ctx(), selectors);
+  Element* result = element->querySelector(selectors_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException() || result == nullptr) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = result->KeepAlive();
+
+  return WebFValue<Element, ElementPublicMethods>(result, result->elementPublicMethods(), status_block);
+}
+
+WebFValue<NodeList, NodeListPublicMethods> ElementPublicMethods::QuerySelectorAll(
+    Element* ptr,
+    const char* selectors,
+    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString selectors_atomic = webf::AtomicString(element->ctx(), selectors);
+  std::vector<Element*> elements = element->querySelectorAll(selectors_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<NodeList, NodeListPublicMethods>::Null();
+  }
+
+  std::vector<Member<Node>> nodes(elements.begin(), elements.end());
+  NodeList* node_list = StaticNodeList::Adopt(element->ctx(), nodes);
+  WebFValueStatus* status_block = node_list->KeepAlive();
+
+  return WebFValue<NodeList, NodeListPublicMethods>(node_list, node_list->nodeListPublicMethods(), status_block);
+}
+
+WebFValue<DOMTokenList, DOMTokenListPublicMethods> ElementPublicMethods::ClassList(Element* ptr) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  DOMTokenList* class_list = element->classList();
+  WebFValueStatus* status_block = class_list->KeepAlive();
+  return WebFValue<DOMTokenList, DOMTokenListPublicMethods>(class_list, class_list->domTokenListPublicMethods(),
+                                                            status_block);
+}
+
+char* ElementPublicMethods::GetAttribute(Element* ptr,
+                                         const char* name,
+                                         SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString name_atomic = webf::AtomicString(element->ctx(), name);
+  AtomicString value = element->getAttribute(name_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException() || value.IsNull()) {
+    return nullptr;
+  }
+
+  return CopyToCallerOwnedString(value.ToStdString(element->ctx()));
+}
+
+void ElementPublicMethods::SetAttribute(Element* ptr,
+                                        const char* name,
+                                        const char* value,
+                                        SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString name_atomic = webf::AtomicString(element->ctx(), name);
+  webf::AtomicString value_atomic = webf::AtomicString(element->ctx(), value);
+  element->setAttribute(name_atomic, value_atomic, shared_exception_state->exception_state);
+}
+
+void ElementPublicMethods::RemoveAttribute(Element* ptr,
+                                           const char* name,
+                                           SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString name_atomic = webf::AtomicString(element->ctx(), name);
+  element->removeAttribute(name_atomic, shared_exception_state->exception_state);
+}
+
+int32_t ElementPublicMethods::HasAttribute(Element* ptr,
+                                           const char* name,
+                                           SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString name_atomic = webf::AtomicString(element->ctx(), name);
+  return element->hasAttribute(name_atomic, shared_exception_state->exception_state);
+}
+
+char* ElementPublicMethods::InnerHTML(Element* ptr, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  return CopyToCallerOwnedString(element->innerHTML());
+}
+
+void ElementPublicMethods::SetInnerHTML(Element* ptr, const char* html, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString html_atomic = webf::AtomicString(element->ctx(), html);
+  element->setInnerHTML(html_atomic, shared_exception_state->exception_state);
+}
+
+char* ElementPublicMethods::OuterHTML(Element* ptr, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  return CopyToCallerOwnedString(element->outerHTML());
+}
+
+WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods> ElementPublicMethods::Style(Element* ptr) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  CSSStyleDeclaration* style = element->style();
+  if (style == nullptr) {
+    return WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = style->KeepAlive();
+  return WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods>(
+      style, style->cssStyleDeclarationPublicMethods(), status_block);
+}
+
+void ElementPublicMethods::GetBoundingClientRect(Element* ptr,
+                                                 BoundingClientRectData* rect,
+                                                 SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  // Flushes pending UI commands and waits for layout, so the geometry is current.
+  BoundingClientRect* bounding_client_rect = element->getBoundingClientRect(shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return;
+  }
+  if (bounding_client_rect == nullptr) {
+    shared_exception_state->exception_state.ThrowException(element->ctx(), ErrorType::InternalError,
+                                                           "Failed to get the bounding client rect of the element.");
+    return;
+  }
+  rect->x = bounding_client_rect->x();
+  rect->y = bounding_client_rect->y();
+  rect->width = bounding_client_rect->width();
+  rect->height = bounding_client_rect->height();
+  rect->top = bounding_client_rect->top();
+  rect->right = bounding_client_rect->right();
+  rect->bottom = bounding_client_rect->bottom();
+  rect->left = bounding_client_rect->left();
+}
+
+void ElementPublicMethods::ScrollTo(Element* ptr,
+                                    double x,
+                                    double y,
+                                    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->scrollTo(x, y, shared_exception_state->exception_state);
+}
+
+void ElementPublicMethods::ScrollBy(Element* ptr,
+                                    double dx,
+                                    double dy,
+                                    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->scrollBy(dx, dy, shared_exception_state->exception_state);
+}
+
+void ElementPublicMethods::ScrollIntoView(Element* ptr,
+                                          const char* block,
+                                          const char* inline_,
+                                          int32_t smooth,
+                                          SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->scrollIntoView(AtomicString(element->ctx(), block), AtomicString(element->ctx(), inline_), smooth != 0,
+                          shared_exception_state->exception_state);
+}
+
+double ElementPublicMethods::ScrollTop(Element* ptr, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  NativeValue value = element->GetBindingProperty(
+      binding_call_methods::kscrollTop,
+      FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+void ElementPublicMethods::SetScrollTop(Element* ptr, double value, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->SetBindingProperty(binding_call_methods::kscrollTop,
+                              NativeValueConverter<NativeTypeDouble>::ToNativeValue(value),
+                              shared_exception_state->exception_state);
+}
+
+double ElementPublicMethods::ScrollLeft(Element* ptr, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  NativeValue value = element->GetBindingProperty(
+      binding_call_methods::kscrollLeft,
+      FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+void ElementPublicMethods::SetScrollLeft(Element* ptr, double value, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->SetBindingProperty(binding_call_methods::kscrollLeft,
+                              NativeValueConverter<NativeTypeDouble>::ToNativeValue(value),
+                              shared_exception_state->exception_state);
+}
+
+WebFValue<NodeList, HTMLCollectionPublicMethods> ElementPublicMethods::GetElementsByClassName(
+    Element* ptr,
+    const char* class_names,
+    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString class_names_atomic = webf::AtomicString(element->ctx(), class_names);
+  std::vector<Element*> elements = element->getElementsByClassName(class_names_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<NodeList, HTMLCollectionPublicMethods>::Null();
+  }
+
+  std::vector<Member<Node>> nodes(elements.begin(), elements.end());
+  StaticNodeList* collection = StaticNodeList::Adopt(element->ctx(), nodes);
+  WebFValueStatus* status_block = collection->KeepAlive();
+
+  return WebFValue<NodeList, HTMLCollectionPublicMethods>(collection, collection->htmlCollectionPublicMethods(),
+                                                          status_block);
+}
+
+WebFValue<NodeList, HTMLCollectionPublicMethods> ElementPublicMethods::GetElementsByTagName(
+    Element* ptr,
+    const char* tag_name,
+    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString tag_name_atomic = webf::AtomicString(element->ctx(), tag_name);
+  std::vector<Element*> elements = element->getElementsByTagName(tag_name_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<NodeList, HTMLCollectionPublicMethods>::Null();
+  }
+
+  std::vector<Member<Node>> nodes(elements.begin(), elements.end());
+  StaticNodeList* collection = StaticNodeList::Adopt(element->ctx(), nodes);
+  WebFValueStatus* status_block = collection->KeepAlive();
+
+  return WebFValue<NodeList, HTMLCollectionPublicMethods>(collection, collection->htmlCollectionPublicMethods(),
+                                                          status_block);
+}
+
+WebFValue<DOMStringMap, DOMStringMapPublicMethods> ElementPublicMethods::Dataset(Element* ptr) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  DOMStringMap* dataset = element->dataset();
+  WebFValueStatus* status_block = dataset->KeepAlive();
+  return WebFValue<DOMStringMap, DOMStringMapPublicMethods>(dataset, dataset->domStringMapPublicMethods(),
+                                                            status_block);
+}
+
+int32_t ElementPublicMethods::Matches(Element* ptr,
+                                      const char* selectors,
+                                      SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString selectors_atomic = webf::AtomicString(element->ctx(), selectors);
+  return element->matches(selectors_atomic, shared_exception_state->exception_state);
+}
+
+WebFValue<Element, ElementPublicMethods> ElementPublicMethods::Closest(Element* ptr,
+                                                                       const char* selectors,
+                                                                       SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString selectors_atomic = webf::AtomicString(element->ctx(), selectors);
+  Element* result = element->closest(selectors_atomic, shared_exception_state->exception_state);
+
+  if (shared_exception_state->exception_state.HasException() || result == nullptr) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = result->KeepAlive();
+
+  return WebFValue<Element, ElementPublicMethods>(result, result->elementPublicMethods(), status_block);
+}
+
+char* ElementPublicMethods::TagName(Element* ptr) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  return CopyToCallerOwnedString(element->tagName().ToStdString(element->ctx()));
+}
+
+char* ElementPublicMethods::Id(Element* ptr) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  AtomicString value = element->id();
+  // Missing attributes reflect as the empty string.
+  return CopyToCallerOwnedString(value.IsNull() ? "" : value.ToStdString(element->ctx()));
+}
+
+void ElementPublicMethods::SetId(Element* ptr, const char* id, SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString id_atomic = webf::AtomicString(element->ctx(), id);
+  element->setId(id_atomic, shared_exception_state->exception_state);
+}
+
+char* ElementPublicMethods::ClassName(Element* ptr) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  AtomicString value = element->className();
+  // Missing attributes reflect as the empty string.
+  return CopyToCallerOwnedString(value.IsNull() ? "" : value.ToStdString(element->ctx()));
+}
+
+void ElementPublicMethods::SetClassName(Element* ptr,
+                                        const char* class_name,
+                                        SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  webf::AtomicString class_name_atomic = webf::AtomicString(element->ctx(), class_name);
+  element->setClassName(class_name_atomic, shared_exception_state->exception_state);
+}
+
+void ElementPublicMethods::SetPointerCapture(Element* ptr,
+                                             int64_t pointer_id,
+                                             SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->setPointerCapture(pointer_id, shared_exception_state->exception_state);
+}
+
+void ElementPublicMethods::ReleasePointerCapture(Element* ptr,
+                                                 int64_t pointer_id,
+                                                 SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  element->releasePointerCapture(pointer_id, shared_exception_state->exception_state);
+}
+
+int32_t ElementPublicMethods::HasPointerCapture(Element* ptr,
+                                                int64_t pointer_id,
+                                                SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  return element->hasPointerCapture(pointer_id, shared_exception_state->exception_state);
+}
+
+WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods> ElementPublicMethods::RequestFullscreen(
+    Element* ptr,
+    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  ScriptPromise promise = element->requestFullscreen(shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods>::Null();
+  }
+  auto* script_promise_ref = new ScriptPromiseRef{element->GetExecutingContext(), promise};
+  return WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods>(script_promise_ref,
+                                                                     ScriptPromiseRef::publicMethods(), nullptr);
+}
+
+WebFValue<CanvasRenderingContext2D, CanvasRenderingContext2DPublicMethods> ElementPublicMethods::GetContext2D(
+    Element* ptr,
+    SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  auto* canvas_element = DynamicTo<HTMLCanvasElement>(element);
+  if (canvas_element == nullptr) {
+    shared_exception_state->exception_state.ThrowException(
+        element->ctx(), ErrorType::TypeError, "Failed to execute 'getContext': The element is not a canvas element.");
+    return WebFValue<CanvasRenderingContext2D, CanvasRenderingContext2DPublicMethods>::Null();
+  }
+  CanvasRenderingContext* context =
+      canvas_element->getContext(canvas_types::k2d, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException() || context == nullptr || !context->IsCanvas2d()) {
+    return WebFValue<CanvasRenderingContext2D, CanvasRenderingContext2DPublicMethods>::Null();
+  }
+  auto* context_2d = static_cast<CanvasRenderingContext2D*>(context);
+  WebFValueStatus* status_block = context_2d->KeepAlive();
+  return WebFValue<CanvasRenderingContext2D, CanvasRenderingContext2DPublicMethods>(
+      context_2d, context_2d->canvasRenderingContext2DPublicMethods(), status_block);
+}
+
+WebFElementAttribute* ElementPublicMethods::Attributes(Element* ptr,
+                                                       int64_t* length,
+                                                       SharedExceptionState* shared_exception_state) {
+  auto* element = static_cast<webf::Element*>(ptr);
+  MemberMutationScope scope{element->GetExecutingContext()};
+  std::vector<std::pair<std::string, std::string>> attributes;
+  for (auto& attribute : *element->attributes()) {
+    attributes.emplace_back(attribute.first.ToStdString(element->ctx()), attribute.second.ToStdString(element->ctx()));
+  }
+  *length = static_cast<int64_t>(attributes.size());
+  if (attributes.empty()) {
+    return nullptr;
+  }
+
+  // The attributes are stored in a hash map, sort them so that the indexes are stable.
+  std::sort(attributes.begin(), attributes.end());
+  auto* result = static_cast<WebFElementAttribute*>(dart_malloc(sizeof(WebFElementAttribute) * attributes.size()));
+  for (size_t i = 0; i < attributes.size(); i++) {
+    result[i] = WebFElementAttribute{
+        CopyToCallerOwnedString(attributes[i].first),
+        CopyToCallerOwnedString(attributes[i].second),
+    };
+  }
+  return result;
+}
+
+double ElementPublicMethods::OffsetTop(Element* element, SharedExceptionState* shared_exception_state) {
+  return GetLayoutDependentProperty(element, binding_call_methods::koffsetTop, shared_exception_state);
+}
+
+double ElementPublicMethods::OffsetLeft(Element* element, SharedExceptionState* shared_exception_state) {
+  return GetLayoutDependentProperty(element, binding_call_methods::koffsetLeft, shared_exception_state);
+}
+
+double ElementPublicMethods::OffsetWidth(Element* element, SharedExceptionState* shared_exception_state) {
+  return GetLayoutDependentProperty(element, binding_call_methods::koffsetWidth, shared_exception_state);
+}
+
+double ElementPublicMethods::OffsetHeight(Element* element, SharedExceptionState* shared_exception_state) {
+  return GetLayoutDependentProperty(element, binding_call_methods::koffsetHeight, shared_exception_state);
+}
+
+WebFValue<Element, ElementPublicMethods> ElementPublicMethods::OffsetParent(
+    Element* element,
+    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{element->GetExecutingContext()};
+  NativeValue value = element->GetBindingProperty(
+      binding_call_methods::koffsetParent,
+      FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+      shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+  Element* result = NativeValueConverter<NativeTypePointer<Element>>::FromNativeValue(element->ctx(), value);
+  if (result == nullptr) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = result->KeepAlive();
+  return WebFValue<Element, ElementPublicMethods>(result, result->elementPublicMethods(), status_block);
+}
+
+double ElementPublicMethods::ClientWidth(Element* element, SharedExceptionState* shared_exception_state) {
+  return GetLayoutDependentProperty(element, binding_call_methods::kclientWidth, shared_exception_state);
+}
+
+double ElementPublicMethods::ClientHeight(Element* element, SharedExceptionState* shared_exception_state) {
+  return GetLayoutDependentProperty(element, binding_call_methods::kclientHeight, shared_exception_state);
+}
+
+WebFValue<Element, CSSStyleSheetPublicMethods> ElementPublicMethods::Sheet(Element* element,
+                                                                     SharedExceptionState* shared_exception_state) {
+  if (element->localName() != html_names::kstyle) {
+    shared_exception_state->exception_state.ThrowException(
+        element->ctx(), ErrorType::TypeError, "Failed to read 'sheet': The element is not a style element.");
+    return WebFValue<Element, CSSStyleSheetPublicMethods>::Null();
+  }
+  static CSSStyleSheetPublicMethods css_style_sheet_public_methods;
+  WebFValueStatus* status_block = element->KeepAlive();
+  return WebFValue<Element, CSSStyleSheetPublicMethods>(element, &css_style_sheet_public_methods, status_block);
+}
+
 }  // namespace webf
@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include <new>
+#include "core/dom/events/event.h"
+#include "core/dom/events/event_target.h"
+#include "foundation/dart_readable.h"
+#include "plugin_api/event.h"
+
+namespace webf {
+
+// The other methods of EventPublicMethods are generated from event.d.ts.
+WebFValue<EventTarget, EventTargetPublicMethods>* EventPublicMethods::ComposedPath(Event* event, int64_t* length) {
+  const std::vector<EventTarget*>& event_path = event->eventPath();
+  *length = static_cast<int64_t>(event_path.size());
+  if (event_path.empty()) {
+    return nullptr;
+  }
+
+  using EventTargetValue = WebFValue<EventTarget, EventTargetPublicMethods>;
+  auto* result = static_cast<EventTargetValue*>(dart_malloc(sizeof(EventTargetValue) * event_path.size()));
+  for (size_t i = 0; i < event_path.size(); i++) {
+    EventTarget* target = event_path[i];
+    new (&result[i]) EventTargetValue(target, target->eventTargetPublicMethods(), target->KeepAlive());
+  }
+  return result;
+}
+
+}  // namespace webf
@@ -50,7 +50,11 @@ void EventTargetPublicMethods::RemoveEventListener(EventTarget* event_target,
   AtomicString event_name = AtomicString(event_target->ctx(), event_name_str);
   auto listener_impl = WebFPublicPluginEventListener::Create(callback_context, shared_exception_state);
 
-  event_target->removeEventListener(event_name, listener_impl, shared_exception_state->exception_state);
+  // A plugin listener is only ever registered in one of the two phases, so look for it in the capturing ones
+  // when it is not found among the bubbling ones.
+  if (!event_target->removeEventListener(event_name, listener_impl, false, shared_exception_state->exception_state)) {
+    event_target->removeEventListener(event_name, listener_impl, true, shared_exception_state->exception_state);
+  }
 }
 
 bool EventTargetPublicMethods::DispatchEvent(EventTarget* event_target,
@@ -171,4 +175,26 @@ WebFValue<EventTarget, WebFPublicMethods> EventTargetPublicMethods::DynamicTo(we
   }
 }
 
+int64_t EventTargetPublicMethods::ListenerCount(EventTarget* event_target, const char* event_name_str) {
+  AtomicString event_name = AtomicString(event_target->ctx(), event_name_str);
+  EventListenerVector* listeners = event_target->GetEventListeners(event_name);
+  if (listeners == nullptr) {
+    return 0;
+  }
+  return static_cast<int64_t>(listeners->size());
+}
+
+WebFValueStatus* EventTargetPublicMethods::Downgrade(EventTarget* event_target) {
+  return event_target->KeepWeak();
+}
+
+void EventTargetPublicMethods::ReleaseWeak(EventTarget* event_target, WebFValueStatus* weak_status) {
+  // The event target is already destroyed when the status is disposed, only the status is left to free.
+  if (weak_status->disposed) {
+    delete weak_status;
+    return;
+  }
+  event_target->ReleaseWeak(weak_status);
+}
+
 }  // namespace webf
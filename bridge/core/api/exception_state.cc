@@ -6,10 +6,13 @@
 #include "bindings/qjs/exception_state.h"
 #include "core/api/exception_state.h"
 #include "core/executing_context.h"
+#include "foundation/dart_readable.h"
 
 namespace webf {
 
-SharedExceptionState::SharedExceptionState() = default;
+SharedExceptionState::SharedExceptionState() {
+  exception_state.SetForNativePlugin();
+}
 
 bool ExceptionStatePublicMethods::HasException(SharedExceptionState* shared_exception_state) {
   return shared_exception_state->exception_state.HasException();
@@ -22,4 +25,20 @@ void ExceptionStatePublicMethods::Stringify(webf::ExecutingContext* context,
   context->HandleException(shared_exception_state->exception_state, errmsg, strlen);
 }
 
-}  // namespace webf
\ No newline at end of file
+char* ExceptionStatePublicMethods::Name(ExecutingContext* context, SharedExceptionState* shared_exception_state) {
+  ExceptionState& exception_state = shared_exception_state->exception_state;
+  if (!exception_state.HasException() || exception_state.Name().empty()) {
+    return nullptr;
+  }
+  return CopyToCallerOwnedString(exception_state.Name());
+}
+
+int32_t ExceptionStatePublicMethods::Code(SharedExceptionState* shared_exception_state) {
+  ExceptionState& exception_state = shared_exception_state->exception_state;
+  if (!exception_state.HasException()) {
+    return 0;
+  }
+  return exception_state.Code();
+}
+
+}  // namespace webf
@@ -6,11 +6,19 @@
 #include "bindings/qjs/exception_state.h"
 #include "core/api/exception_state.h"
 #include "core/dom/document.h"
+#include "core/dom/dom_parser.h"
+#include "core/dom/mutation_observer.h"
+#include "core/events/message_event.h"
 #include "core/executing_context.h"
 #include "core/frame/legacy/location.h"
 #include "core/frame/module_manager.h"
 #include "core/frame/window.h"
 #include "core/frame/window_or_worker_global_scope.h"
+#include "core/native/script_promise_ref.h"
+#include "core/native/script_value_ref.h"
+#include "core/timing/performance.h"
+#include "foundation/dart_readable.h"
+#include "foundation/logging.h"
 #include "foundation/native_value_converter.h"
 
 namespace webf {
@@ -26,6 +34,13 @@ WebFValue<Window, WindowPublicMethods> ExecutingContextWebFMethods::window(webf:
                                                 context->window()->KeepAlive());
 }
 
+WebFValue<Performance, PerformancePublicMethods> ExecutingContextWebFMethods::performance(
+    webf::ExecutingContext* context) {
+  auto* performance = context->performance();
+  return WebFValue<Performance, PerformancePublicMethods>(performance, performance->performancePublicMethods(),
+                                                          performance->KeepAlive());
+}
+
 WebFValue<SharedExceptionState, ExceptionStatePublicMethods> ExecutingContextWebFMethods::CreateExceptionState() {
   return WebFValue<SharedExceptionState, ExceptionStatePublicMethods>(new SharedExceptionState(),
                                                                       ExceptionState::publicMethodPointer(), nullptr);
@@ -136,6 +151,21 @@ void ExecutingContextWebFMethods::ClearInterval(ExecutingContext* context,
   WindowOrWorkerGlobalScope::clearInterval(context, interval_id, shared_exception_state->exception_state);
 }
 
+uint32_t ExecutingContextWebFMethods::RequestAnimationFrame(ExecutingContext* context,
+                                                           WebFNativeFunctionContext* callback_context,
+                                                           SharedExceptionState* shared_exception_state) {
+  auto callback_impl = WebFNativeFunction::Create(callback_context, shared_exception_state);
+
+  return static_cast<uint32_t>(
+      context->window()->requestAnimationFrame(callback_impl, shared_exception_state->exception_state));
+}
+
+void ExecutingContextWebFMethods::CancelAnimationFrame(ExecutingContext* context,
+                                                       uint32_t request_id,
+                                                       SharedExceptionState* shared_exception_state) {
+  context->window()->cancelAnimationFrame(request_id, shared_exception_state->exception_state);
+}
+
 void ExecutingContextWebFMethods::AddRustFutureTask(ExecutingContext* context,
                                                     WebFNativeFunctionContext* callback_context,
                                                     NativeLibrartMetaData* meta_data,
@@ -154,4 +184,139 @@ void ExecutingContextWebFMethods::RemoveRustFutureTask(ExecutingContext* context
   context->RemoveRustFutureTask(callback_impl, meta_data);
 }
 
+WebFValue<EventTarget, EventTargetPublicMethods> ExecutingContextWebFMethods::CreateModuleEventTarget(
+    ExecutingContext* context,
+    const char* module_name,
+    const char* id,
+    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context};
+  EventTarget* event_target = EventTarget::Create(context, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<EventTarget, EventTargetPublicMethods>::Null();
+  }
+  context->ModuleListeners()->AddModuleEventTarget(AtomicString(context->ctx(), module_name),
+                                                   AtomicString(context->ctx(), id), event_target);
+  WebFValueStatus* status_block = event_target->KeepAlive();
+  return WebFValue<EventTarget, EventTargetPublicMethods>(event_target, event_target->eventTargetPublicMethods(),
+                                                          status_block);
+}
+
+void ExecutingContextWebFMethods::RemoveModuleEventTarget(ExecutingContext* context,
+                                                          const char* module_name,
+                                                          const char* id) {
+  context->ModuleListeners()->RemoveModuleEventTarget(AtomicString(context->ctx(), module_name),
+                                                      AtomicString(context->ctx(), id));
+}
+
+void ExecutingContextWebFMethods::GetMessageEventData(ExecutingContext* context,
+                                                      Event* event,
+                                                      WebFMessageEventData* data,
+                                                      SharedExceptionState* shared_exception_state) {
+  auto* message_event = DynamicTo<MessageEvent>(event);
+  if (message_event == nullptr) {
+    shared_exception_state->exception_state.ThrowException(context->ctx(), ErrorType::TypeError,
+                                                           "The event is not a MessageEvent.");
+    return;
+  }
+
+  JSContext* ctx = context->ctx();
+  ScriptValue message = message_event->data();
+  if (message.IsString()) {
+    std::string text = message.ToString(ctx).ToStdString(ctx);
+    data->bytes = static_cast<uint8_t*>(dart_malloc(sizeof(uint8_t) * (text.length() + 1)));
+    memcpy(data->bytes, text.c_str(), text.length() + 1);
+    data->length = static_cast<int64_t>(text.length());
+    data->is_binary = 0;
+    return;
+  }
+
+  size_t length;
+  uint8_t* buffer = JS_GetArrayBuffer(ctx, &length, message.QJSValue());
+  if (buffer == nullptr) {
+    // JS_GetArrayBuffer throws a TypeError when the data is not an ArrayBuffer.
+    JSValue error = JS_GetException(ctx);
+    shared_exception_state->exception_state.ThrowException(ctx, error);
+    JS_FreeValue(ctx, error);
+    return;
+  }
+  // Allocate at least one byte so that an empty message still has a valid buffer to free.
+  data->bytes = static_cast<uint8_t*>(dart_malloc(sizeof(uint8_t) * (length > 0 ? length : 1)));
+  memcpy(data->bytes, buffer, length);
+  data->length = static_cast<int64_t>(length);
+  data->is_binary = 1;
+}
+
+void ExecutingContextWebFMethods::Print(ExecutingContext* context, const char* message, const char* level) {
+  std::stringstream stream;
+  stream << message;
+  printLog(context, stream, level, nullptr);
+}
+
+WebFValue<MutationObserver, MutationObserverPublicMethods> ExecutingContextWebFMethods::CreateMutationObserver(
+    ExecutingContext* context,
+    WebFNativeFunctionContext* callback_context,
+    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context};
+  auto callback_impl = WebFNativeFunction::Create(callback_context, shared_exception_state);
+  MutationObserver* observer =
+      MutationObserver::Create(context, callback_impl, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<MutationObserver, MutationObserverPublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = observer->KeepAlive();
+  return WebFValue<MutationObserver, MutationObserverPublicMethods>(
+      observer, observer->mutationObserverPublicMethods(), status_block);
+}
+
+WebFValue<Document, DocumentPublicMethods> ExecutingContextWebFMethods::ParseFromString(
+    ExecutingContext* context,
+    const char* input,
+    int32_t type,
+    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{context};
+  Document* document = DOMParser::ParseFromString(context, input, static_cast<DOMParserSupportedType>(type),
+                                                  shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<Document, DocumentPublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = document->KeepAlive();
+  return WebFValue<Document, DocumentPublicMethods>(document, document->documentPublicMethods(), status_block);
+}
+
+namespace {
+
+struct QueuedMicrotask {
+  ExecutingContext* context;
+  std::shared_ptr<WebFNativeFunction> callback;
+};
+
+}  // namespace
+
+void ExecutingContextWebFMethods::QueueMicrotask(ExecutingContext* context,
+                                                 WebFNativeFunctionContext* callback_context,
+                                                 SharedExceptionState* shared_exception_state) {
+  auto* task = new QueuedMicrotask{context, WebFNativeFunction::Create(callback_context, shared_exception_state)};
+  context->EnqueueMicrotask(
+      [](void* p) {
+        auto* task = static_cast<QueuedMicrotask*>(p);
+        if (task->context->IsContextValid()) {
+          task->callback->Invoke(task->context, 0, nullptr);
+        }
+        delete task;
+      },
+      task);
+}
+
+const ScriptValueRefPublicMethods* ExecutingContextWebFMethods::ScriptValueRefMethods() {
+  return ScriptValueRef::publicMethods();
+}
+
+const ScriptPromiseRefPublicMethods* ExecutingContextWebFMethods::ScriptPromiseRefMethods() {
+  return ScriptPromiseRef::publicMethods();
+}
+
+void ExecutingContextWebFMethods::RunGC(ExecutingContext* context) {
+  JS_RunGC(context->GetScriptState()->runtime());
+}
+
 }  // namespace webf
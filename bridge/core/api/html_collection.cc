@@ -0,0 +1,69 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/html_collection.h"
+#include "core/dom/element.h"
+#include "core/dom/static_node_list.h"
+#include "binding_call_methods.h"
+
+namespace webf {
+
+namespace {
+
+WebFValue<Element, ElementPublicMethods> ToElementValue(Element* element) {
+  WebFValueStatus* status_block = element->KeepAlive();
+  return WebFValue<Element, ElementPublicMethods>(element, element->elementPublicMethods(), status_block);
+}
+
+}  // namespace
+
+uint32_t HTMLCollectionPublicMethods::Length(NodeList* collection) {
+  return collection->length();
+}
+
+WebFValue<Element, ElementPublicMethods> HTMLCollectionPublicMethods::Item(NodeList* collection, uint32_t index) {
+  ExceptionState exception_state;
+  auto* element = DynamicTo<Element>(collection->item(index, exception_state));
+  if (element == nullptr) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+
+  return ToElementValue(element);
+}
+
+WebFValue<Element, ElementPublicMethods> HTMLCollectionPublicMethods::NamedItem(NodeList* collection,
+                                                                                const char* name) {
+  MemberMutationScope scope{collection->GetExecutingContext()};
+  AtomicString name_atomic = AtomicString(collection->ctx(), name);
+  if (name_atomic.IsEmpty()) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+
+  // Same as HTMLCollection.namedItem(), an element matched by its id takes precedence over the ones matched by name.
+  ExceptionState exception_state;
+  Element* named_element = nullptr;
+  for (uint32_t i = 0; i < collection->length(); i++) {
+    auto* element = DynamicTo<Element>(collection->item(i, exception_state));
+    if (element == nullptr) {
+      continue;
+    }
+    if (element->id() == name_atomic) {
+      return ToElementValue(element);
+    }
+    if (named_element == nullptr && element->getAttribute(binding_call_methods::kname, exception_state) == name_atomic) {
+      named_element = element;
+    }
+  }
+
+  if (named_element == nullptr) {
+    return WebFValue<Element, ElementPublicMethods>::Null();
+  }
+  return ToElementValue(named_element);
+}
+
+void HTMLCollectionPublicMethods::Release(NodeList* collection) {
+  collection->ReleaseAlive();
+}
+
+}  // namespace webf
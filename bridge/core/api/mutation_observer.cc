@@ -0,0 +1,109 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/mutation_observer.h"
+#include "core/api/exception_state.h"
+#include "core/dom/mutation_observer.h"
+#include "core/dom/node.h"
+#include "core/dom/static_node_list.h"
+#include "core/executing_context.h"
+#include "foundation/dart_readable.h"
+
+namespace webf {
+
+namespace {
+
+// Copy the string into a buffer which is owned by the caller, null strings stay null.
+char* CopyNullableToCallerOwnedString(JSContext* ctx, const AtomicString& string) {
+  if (string.IsNull()) {
+    return nullptr;
+  }
+  return CopyToCallerOwnedString(string.ToStdString(ctx));
+}
+
+WebFValue<Node, NodePublicMethods> ToNodeValue(Node* node) {
+  if (node == nullptr) {
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = node->KeepAlive();
+  return WebFValue<Node, NodePublicMethods>(node, node->nodePublicMethods(), status_block);
+}
+
+WebFValue<NodeList, NodeListPublicMethods> ToNodeListValue(StaticNodeList* node_list) {
+  if (node_list == nullptr) {
+    return WebFValue<NodeList, NodeListPublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = node_list->KeepAlive();
+  return WebFValue<NodeList, NodeListPublicMethods>(node_list, node_list->nodeListPublicMethods(), status_block);
+}
+
+}  // namespace
+
+void MutationObserverPublicMethods::Observe(MutationObserver* observer,
+                                            Node* target,
+                                            WebFMutationObserverInit* init,
+                                            SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{observer->GetExecutingContext()};
+  std::shared_ptr<MutationObserverInit> observer_init = MutationObserverInit::Create();
+  if (init->child_list)
+    observer_init->setChildList(true);
+  if (init->attributes)
+    observer_init->setAttributes(true);
+  if (init->character_data)
+    observer_init->setCharacterData(true);
+  if (init->subtree)
+    observer_init->setSubtree(true);
+  if (init->attribute_old_value)
+    observer_init->setAttributeOldValue(true);
+  if (init->character_data_old_value)
+    observer_init->setCharacterDataOldValue(true);
+  if (init->attribute_filter != nullptr) {
+    std::vector<AtomicString> attribute_filter;
+    for (int64_t i = 0; i < init->attribute_filter_length; i++) {
+      attribute_filter.emplace_back(observer->ctx(), init->attribute_filter[i]);
+    }
+    observer_init->setAttributeFilter(attribute_filter);
+  }
+  observer->observe(target, observer_init, shared_exception_state->exception_state);
+}
+
+WebFMutationRecord* MutationObserverPublicMethods::TakeRecords(MutationObserver* observer,
+                                                               int64_t* length,
+                                                               SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{observer->GetExecutingContext()};
+  MutationRecordVector records = observer->takeRecords(shared_exception_state->exception_state);
+  *length = static_cast<int64_t>(records.size());
+  if (records.empty()) {
+    return nullptr;
+  }
+
+  JSContext* ctx = observer->ctx();
+  auto* result = static_cast<WebFMutationRecord*>(dart_malloc(sizeof(WebFMutationRecord) * records.size()));
+  for (size_t i = 0; i < records.size(); i++) {
+    MutationRecord* record = records[i].Get();
+    result[i] = WebFMutationRecord{
+        CopyNullableToCallerOwnedString(ctx, record->type()),
+        ToNodeValue(record->target()),
+        ToNodeListValue(record->addedNodes()),
+        ToNodeListValue(record->removedNodes()),
+        ToNodeValue(record->previousSibling()),
+        ToNodeValue(record->nextSibling()),
+        CopyNullableToCallerOwnedString(ctx, record->attributeName()),
+        CopyNullableToCallerOwnedString(ctx, record->oldValue()),
+    };
+  }
+  return result;
+}
+
+void MutationObserverPublicMethods::Disconnect(MutationObserver* observer,
+                                               SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{observer->GetExecutingContext()};
+  observer->disconnect(shared_exception_state->exception_state);
+}
+
+void MutationObserverPublicMethods::Release(MutationObserver* observer) {
+  observer->ReleaseAlive();
+}
+
+}  // namespace webf
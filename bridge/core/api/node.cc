@@ -5,10 +5,27 @@
 #include "plugin_api/node.h"
 #include "core/api/exception_state.h"
 #include "core/dom/events/event_target.h"
+#include "core/dom/container_node.h"
+#include "core/dom/document_fragment.h"
 #include "core/dom/node.h"
+#include "core/dom/node_list.h"
+#include "foundation/dart_readable.h"
 
 namespace webf {
 
+namespace {
+
+WebFValue<Node, NodePublicMethods> ToNodeValue(Node* node) {
+  if (node == nullptr) {
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = node->KeepAlive();
+  return WebFValue<Node, NodePublicMethods>(node, node->nodePublicMethods(), status_block);
+}
+
+}  // namespace
+
 NodePublicMethods::NodePublicMethods() {}
 
 WebFValue<Node, NodePublicMethods> NodePublicMethods::AppendChild(Node* self_node,
@@ -24,6 +41,58 @@ WebFValue<Node, NodePublicMethods> NodePublicMethods::AppendChild(Node* self_nod
   return WebFValue<Node, NodePublicMethods>(returned_node, returned_node->nodePublicMethods(), status_block);
 }
 
+void NodePublicMethods::AppendChildren(Node* self_node,
+                                       Node** nodes,
+                                       int64_t length,
+                                       SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  if (length == 1) {
+    self_node->appendChild(nodes[0], shared_exception_state->exception_state);
+    return;
+  }
+
+  // Same as ParentNode.append(), the nodes are collected into a fragment so they are inserted at once.
+  Node* fragment = DocumentFragment::Create(self_node->GetDocument());
+  for (int64_t i = 0; i < length; i++) {
+    fragment->appendChild(nodes[i], shared_exception_state->exception_state);
+    if (shared_exception_state->exception_state.HasException()) {
+      return;
+    }
+  }
+  self_node->appendChild(fragment, shared_exception_state->exception_state);
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::CloneNode(Node* self_node,
+                                                                int32_t deep,
+                                                                SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  if (self_node->IsDocumentNode()) {
+    shared_exception_state->exception_state.ThrowException(self_node->ctx(), ErrorType::TypeError,
+                                                           "Failed to execute 'cloneNode' on 'Node': Documents can not be cloned.");
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+
+  Node* returned_node = self_node->cloneNode(deep != 0, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+
+  return ToNodeValue(returned_node);
+}
+
+int32_t NodePublicMethods::Contains(Node* self_node, Node* other_node) {
+  return self_node == other_node || other_node->IsDescendantOf(self_node);
+}
+
+uint16_t NodePublicMethods::CompareDocumentPosition(Node* self_node, Node* other_node) {
+  return self_node->compareDocumentPosition(other_node);
+}
+
+void NodePublicMethods::Normalize(Node* self_node, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  self_node->normalize(shared_exception_state->exception_state);
+}
+
 WebFValue<Node, NodePublicMethods> NodePublicMethods::RemoveChild(webf::Node* self_node,
                                                                   webf::Node* target_node,
                                                                   webf::SharedExceptionState* shared_exception_state) {
@@ -37,4 +106,78 @@ WebFValue<Node, NodePublicMethods> NodePublicMethods::RemoveChild(webf::Node* se
   return WebFValue<Node, NodePublicMethods>(returned_node, returned_node->nodePublicMethods(), status_block);
 }
 
+char* NodePublicMethods::TextContent(webf::Node* self_node, webf::SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  AtomicString text_content = self_node->textContent();
+  if (text_content.IsNull()) {
+    return nullptr;
+  }
+
+  // The returned string is owned by the caller.
+  return CopyToCallerOwnedString(text_content.ToStdString(self_node->ctx()));
+}
+
+void NodePublicMethods::SetTextContent(webf::Node* self_node,
+                                       const char* text,
+                                       webf::SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  AtomicString text_atomic = AtomicString(self_node->ctx(), text);
+  self_node->setTextContent(text_atomic, shared_exception_state->exception_state);
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::InsertBefore(webf::Node* self_node,
+                                                                   webf::Node* new_node,
+                                                                   webf::Node* reference_node,
+                                                                   webf::SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  Node* returned_node = self_node->insertBefore(new_node, reference_node, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = returned_node->KeepAlive();
+  return WebFValue<Node, NodePublicMethods>(returned_node, returned_node->nodePublicMethods(), status_block);
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::ReplaceChild(webf::Node* self_node,
+                                                                   webf::Node* new_node,
+                                                                   webf::Node* old_node,
+                                                                   webf::SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  Node* returned_node = self_node->replaceChild(new_node, old_node, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = returned_node->KeepAlive();
+  return WebFValue<Node, NodePublicMethods>(returned_node, returned_node->nodePublicMethods(), status_block);
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::FirstChild(webf::Node* self_node) {
+  return ToNodeValue(self_node->firstChild());
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::LastChild(webf::Node* self_node) {
+  return ToNodeValue(self_node->lastChild());
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::ParentNode(webf::Node* self_node) {
+  return ToNodeValue(self_node->parentNode());
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::NextSibling(webf::Node* self_node) {
+  return ToNodeValue(self_node->nextSibling());
+}
+
+WebFValue<Node, NodePublicMethods> NodePublicMethods::PreviousSibling(webf::Node* self_node) {
+  return ToNodeValue(self_node->previousSibling());
+}
+
+WebFValue<NodeList, NodeListPublicMethods> NodePublicMethods::ChildNodes(webf::Node* self_node) {
+  MemberMutationScope member_mutation_scope{self_node->GetExecutingContext()};
+  NodeList* node_list = self_node->childNodes();
+  WebFValueStatus* status_block = node_list->KeepAlive();
+  return WebFValue<NodeList, NodeListPublicMethods>(node_list, node_list->nodeListPublicMethods(), status_block);
+}
+
 }  // namespace webf
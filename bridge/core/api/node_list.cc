@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/node_list.h"
+#include "core/dom/node.h"
+#include "core/dom/node_list.h"
+
+namespace webf {
+
+uint32_t NodeListPublicMethods::Length(NodeList* node_list) {
+  return node_list->length();
+}
+
+WebFValue<Node, NodePublicMethods> NodeListPublicMethods::Item(NodeList* node_list, uint32_t index) {
+  ExceptionState exception_state;
+  Node* node = node_list->item(index, exception_state);
+  if (node == nullptr) {
+    return WebFValue<Node, NodePublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = node->KeepAlive();
+  return WebFValue<Node, NodePublicMethods>(node, node->nodePublicMethods(), status_block);
+}
+
+void NodeListPublicMethods::Release(NodeList* node_list) {
+  node_list->ReleaseAlive();
+}
+
+}  // namespace webf
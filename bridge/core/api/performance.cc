@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/performance.h"
+#include "core/api/exception_state.h"
+#include "core/timing/performance.h"
+#include "performance_entry_names.h"
+
+namespace webf {
+
+double PerformancePublicMethods::Now(Performance* performance) {
+  return performance->HighResolutionNow();
+}
+
+double PerformancePublicMethods::TimeOrigin(Performance* performance) {
+  return static_cast<double>(performance->timeOrigin());
+}
+
+void PerformancePublicMethods::Mark(Performance* performance,
+                                    const char* name,
+                                    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{performance->GetExecutingContext()};
+  performance->mark(AtomicString(performance->ctx(), name), shared_exception_state->exception_state);
+}
+
+double PerformancePublicMethods::Measure(Performance* performance,
+                                         const char* name,
+                                         const char* start_mark,
+                                         const char* end_mark,
+                                         SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{performance->GetExecutingContext()};
+  AtomicString measure_name = AtomicString(performance->ctx(), name);
+  AtomicString start_mark_atomic =
+      start_mark != nullptr ? AtomicString(performance->ctx(), start_mark) : AtomicString::Empty();
+  AtomicString end_mark_atomic =
+      end_mark != nullptr ? AtomicString(performance->ctx(), end_mark) : AtomicString::Empty();
+  performance->measure(measure_name, start_mark_atomic, end_mark_atomic, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return 0;
+  }
+
+  auto entries = performance->getEntriesByName(measure_name, performance_entry_names::kmeasure,
+                                               shared_exception_state->exception_state);
+  if (entries.empty()) {
+    return 0;
+  }
+  return static_cast<double>(entries.back()->duration());
+}
+
+void PerformancePublicMethods::Release(Performance* performance) {
+  performance->ReleaseAlive();
+}
+
+}  // namespace webf
@@ -0,0 +1,85 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/script_promise_ref.h"
+#include "bindings/qjs/qjs_function.h"
+#include "core/api/exception_state.h"
+#include "core/native/native_function.h"
+#include "core/native/script_promise_ref.h"
+
+namespace webf {
+
+namespace {
+
+// Shared by the fulfill and reject handlers. A promise settles only once, so whichever handler runs frees it.
+struct PromiseSettleContext {
+  ExecutingContext* context;
+  std::shared_ptr<WebFNativeFunction> callback;
+};
+
+ScriptValue HandlePromiseFulfilled(JSContext* ctx,
+                                   const ScriptValue& this_val,
+                                   uint32_t argc,
+                                   const ScriptValue* argv,
+                                   void* private_data) {
+  auto* settle_context = static_cast<PromiseSettleContext*>(private_data);
+  ExceptionState exception_state;
+  NativeValue params[2];
+  params[0] = Native_NewNull();
+  params[1] = argc > 0 ? argv[0].ToNative(ctx, exception_state) : Native_NewNull();
+  if (exception_state.HasException()) {
+    NativeValue error_object = Native_NewCString("Failed to convert the fulfilled value of the promise.");
+    settle_context->callback->Invoke(settle_context->context, 1, &error_object);
+  } else {
+    settle_context->callback->Invoke(settle_context->context, 2, params);
+  }
+  delete settle_context;
+  return ScriptValue::Empty(ctx);
+}
+
+ScriptValue HandlePromiseRejected(JSContext* ctx,
+                                  const ScriptValue& this_val,
+                                  uint32_t argc,
+                                  const ScriptValue* argv,
+                                  void* private_data) {
+  auto* settle_context = static_cast<PromiseSettleContext*>(private_data);
+  std::string reason = argc > 0 ? argv[0].ToString(ctx).ToStdString(ctx) : "";
+  NativeValue error_object = Native_NewCString(reason);
+  settle_context->callback->Invoke(settle_context->context, 1, &error_object);
+  delete settle_context;
+  return ScriptValue::Empty(ctx);
+}
+
+}  // namespace
+
+void ScriptPromiseRefPublicMethods::Then(ScriptPromiseRef* script_promise_ref,
+                                         WebFNativeFunctionContext* callback_context,
+                                         SharedExceptionState* shared_exception_state) {
+  ExecutingContext* context = script_promise_ref->context;
+  JSContext* ctx = context->ctx();
+  auto* settle_context =
+      new PromiseSettleContext{context, WebFNativeFunction::Create(callback_context, shared_exception_state)};
+  auto on_fulfilled = QJSFunction::Create(ctx, HandlePromiseFulfilled, 1, settle_context);
+  auto on_rejected = QJSFunction::Create(ctx, HandlePromiseRejected, 1, settle_context);
+
+  JSValue promise = script_promise_ref->script_promise.ToQuickJS();
+  JSValue then = JS_GetPropertyStr(ctx, promise, "then");
+  JSValue arguments[] = {on_fulfilled->ToQuickJSUnsafe(), on_rejected->ToQuickJSUnsafe()};
+  JSValue result = JS_Call(ctx, then, promise, 2, arguments);
+  if (JS_IsException(result)) {
+    delete settle_context;
+    JSValue exception = ExceptionState::CurrentException(ctx);
+    shared_exception_state->exception_state.ThrowException(ctx, exception);
+    JS_FreeValue(ctx, exception);
+  }
+  JS_FreeValue(ctx, result);
+  JS_FreeValue(ctx, then);
+  JS_FreeValue(ctx, promise);
+}
+
+void ScriptPromiseRefPublicMethods::Release(ScriptPromiseRef* script_promise_ref) {
+  delete script_promise_ref;
+}
+
+}  // namespace webf
@@ -5,6 +5,7 @@
 #include "plugin_api/script_value_ref.h"
 #include "core/api/exception_state.h"
 #include "core/native/script_value_ref.h"
+#include "foundation/dart_readable.h"
 
 namespace webf {
 
@@ -30,4 +31,19 @@ void ScriptValueRefPublicMethods::Release(webf::ScriptValueRef* script_value_ref
   delete script_value_ref;
 }
 
+char* ScriptValueRefPublicMethods::ToJSON(webf::ScriptValueRef* script_value_ref,
+                                          webf::SharedExceptionState* shared_exception_state) {
+  // Null and undefined have no JSON representation for the caller, the same as functions and symbols.
+  if (script_value_ref->script_value.IsEmpty()) {
+    return nullptr;
+  }
+  JSContext* ctx = script_value_ref->context->ctx();
+  ScriptValue json =
+      script_value_ref->script_value.ToJSONStringify(ctx, &shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException() || !json.IsString()) {
+    return nullptr;
+  }
+  return CopyToCallerOwnedString(json.ToString(ctx).ToStdString(ctx));
+}
+
 }  // namespace webf
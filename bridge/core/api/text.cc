@@ -3,6 +3,28 @@
  */
 
 #include "plugin_api/text.h"
+#include "core/api/exception_state.h"
 #include "core/dom/character_data.h"
+#include "core/dom/text.h"
+#include "foundation/dart_readable.h"
 
-namespace webf {}  // namespace webf
\ No newline at end of file
+namespace webf {
+
+WebFValue<Text, TextNodePublicMethods> TextNodePublicMethods::SplitText(Text* text,
+                                                                        int64_t offset,
+                                                                        SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{text->GetExecutingContext()};
+  Text* new_text = text->splitText(offset, shared_exception_state->exception_state);
+  if (shared_exception_state->exception_state.HasException()) {
+    return WebFValue<Text, TextNodePublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = new_text->KeepAlive();
+  return WebFValue<Text, TextNodePublicMethods>(new_text, new_text->textNodePublicMethods(), status_block);
+}
+
+char* TextNodePublicMethods::WholeText(Text* text, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope member_mutation_scope{text->GetExecutingContext()};
+  return CopyToCallerOwnedString(text->wholeText().ToStdString(text->ctx()));
+}
+
+}  // namespace webf
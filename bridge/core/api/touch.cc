@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/touch.h"
+#include "core/dom/events/event_target.h"
+#include "core/input/touch.h"
+
+namespace webf {
+
+int64_t TouchPublicMethods::Identifier(Touch* touch) {
+  return static_cast<int64_t>(touch->identifier());
+}
+
+double TouchPublicMethods::ClientX(Touch* touch) {
+  return touch->clientX();
+}
+
+double TouchPublicMethods::ClientY(Touch* touch) {
+  return touch->clientY();
+}
+
+double TouchPublicMethods::PageX(Touch* touch) {
+  return touch->pageX();
+}
+
+double TouchPublicMethods::PageY(Touch* touch) {
+  return touch->pageY();
+}
+
+double TouchPublicMethods::ScreenX(Touch* touch) {
+  return touch->screenX();
+}
+
+double TouchPublicMethods::ScreenY(Touch* touch) {
+  return touch->screenY();
+}
+
+double TouchPublicMethods::RadiusX(Touch* touch) {
+  return touch->radiusX();
+}
+
+double TouchPublicMethods::RadiusY(Touch* touch) {
+  return touch->radiusY();
+}
+
+double TouchPublicMethods::Force(Touch* touch) {
+  return touch->force();
+}
+
+WebFValue<EventTarget, EventTargetPublicMethods> TouchPublicMethods::Target(Touch* touch) {
+  EventTarget* target = touch->target();
+  if (target == nullptr) {
+    return WebFValue<EventTarget, EventTargetPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = target->KeepAlive();
+  return WebFValue<EventTarget, EventTargetPublicMethods>(target, target->eventTargetPublicMethods(), status_block);
+}
+
+void TouchPublicMethods::Release(Touch* touch) {
+  touch->ReleaseAlive();
+}
+
+}  // namespace webf
@@ -0,0 +1,34 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "plugin_api/touch_list.h"
+#include "core/input/touch.h"
+#include "core/input/touch_list.h"
+
+namespace webf {
+
+uint32_t TouchListPublicMethods::Length(TouchList* touch_list) {
+  return touch_list->length();
+}
+
+WebFValue<Touch, TouchPublicMethods> TouchListPublicMethods::Item(TouchList* touch_list, uint32_t index) {
+  if (index >= touch_list->length()) {
+    return WebFValue<Touch, TouchPublicMethods>::Null();
+  }
+
+  ExceptionState exception_state;
+  Touch* touch = touch_list->item(index, exception_state);
+  if (touch == nullptr) {
+    return WebFValue<Touch, TouchPublicMethods>::Null();
+  }
+
+  WebFValueStatus* status_block = touch->KeepAlive();
+  return WebFValue<Touch, TouchPublicMethods>(touch, touch->touchPublicMethods(), status_block);
+}
+
+void TouchListPublicMethods::Release(TouchList* touch_list) {
+  touch_list->ReleaseAlive();
+}
+
+}  // namespace webf
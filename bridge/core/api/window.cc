@@ -3,9 +3,13 @@
  */
 
 #include "plugin_api/window.h"
+#include "binding_call_methods.h"
 #include "core/api/exception_state.h"
+#include "core/css/computed_css_style_declaration.h"
+#include "core/dom/element.h"
 #include "core/dom/events/event_target.h"
 #include "core/frame/window.h"
+#include "foundation/native_value_converter.h"
 
 namespace webf {
 
@@ -16,4 +20,70 @@ void WindowPublicMethods::ScrollToWithXAndY(Window* window,
   window->scrollTo(x, y, shared_exception_state->exception_state);
 }
 
+WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods> WindowPublicMethods::GetComputedStyle(
+    Window* window,
+    Element* element,
+    const char* pseudo_elt,
+    SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{window->GetExecutingContext()};
+  ComputedCssStyleDeclaration* style;
+  if (pseudo_elt != nullptr) {
+    style = window->getComputedStyle(element, AtomicString(window->ctx(), pseudo_elt),
+                                     shared_exception_state->exception_state);
+  } else {
+    style = window->getComputedStyle(element, shared_exception_state->exception_state);
+  }
+  if (style == nullptr) {
+    return WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods>::Null();
+  }
+  WebFValueStatus* status_block = style->KeepAlive();
+  return WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods>(
+      style, style->cssStyleDeclarationPublicMethods(), status_block);
+}
+
+double WindowPublicMethods::InnerWidth(Window* window, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{window->GetExecutingContext()};
+  NativeValue value = window->GetBindingProperty(
+      binding_call_methods::kinnerWidth,
+      FlushUICommandReason::kDependentsOnElement,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+double WindowPublicMethods::InnerHeight(Window* window, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{window->GetExecutingContext()};
+  NativeValue value = window->GetBindingProperty(
+      binding_call_methods::kinnerHeight,
+      FlushUICommandReason::kDependentsOnElement,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+double WindowPublicMethods::DevicePixelRatio(Window* window, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{window->GetExecutingContext()};
+  NativeValue value = window->GetBindingProperty(
+      binding_call_methods::kdevicePixelRatio,
+      FlushUICommandReason::kDependentsOnElement,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+double WindowPublicMethods::ScrollX(Window* window, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{window->GetExecutingContext()};
+  NativeValue value = window->GetBindingProperty(
+      binding_call_methods::kscrollX,
+      FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
+double WindowPublicMethods::ScrollY(Window* window, SharedExceptionState* shared_exception_state) {
+  MemberMutationScope scope{window->GetExecutingContext()};
+  NativeValue value = window->GetBindingProperty(
+      binding_call_methods::kscrollY,
+      FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+      shared_exception_state->exception_state);
+  return NativeValueConverter<NativeTypeDouble>::FromNativeValue(value);
+}
+
 }  // namespace webf
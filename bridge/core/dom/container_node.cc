@@ -249,7 +249,7 @@ Node* ContainerNode::ReplaceChild(Node* new_child, Node* old_child, ExceptionSta
 Node* ContainerNode::RemoveChild(Node* old_child, ExceptionState& exception_state) {
   // NotFoundError: Raised if oldChild is not a child of this node.
   if (!old_child || old_child->parentNode() != this) {
-    exception_state.ThrowException(ctx(), ErrorType::TypeError, "The node to be removed is not a child of this node.");
+    exception_state.ThrowDOMException(ctx(), "NotFoundError", "The node to be removed is not a child of this node.");
     return nullptr;
   }
 
@@ -258,10 +258,10 @@ Node* ContainerNode::RemoveChild(Node* old_child, ExceptionState& exception_stat
   // Events fired when blurring currently focused node might have moved this
   // child into a different parent.
   if (child->parentNode() != this) {
-    exception_state.ThrowException(ctx(), ErrorType::TypeError,
-                                   "The node to be removed is no longer a "
-                                   "child of this node. Perhaps it was moved "
-                                   "in a 'blur' event handler?");
+    exception_state.ThrowDOMException(ctx(), "NotFoundError",
+                                      "The node to be removed is no longer a "
+                                      "child of this node. Perhaps it was moved "
+                                      "in a 'blur' event handler?");
     return nullptr;
   }
 
@@ -5,6 +5,7 @@
 #include "document.h"
 #include "binding_call_methods.h"
 #include "bindings/qjs/exception_message.h"
+#include "bindings/qjs/script_promise_resolver.h"
 #include "core/dom/comment.h"
 #include "core/dom/document_fragment.h"
 #include "core/dom/element.h"
@@ -35,9 +36,18 @@ Document* Document::Create(ExecutingContext* context, ExceptionState& exception_
   return MakeGarbageCollected<Document>(context);
 }
 
-Document::Document(ExecutingContext* context)
-    : ContainerNode(context, this, ConstructionType::kCreateDocument), TreeScope(*this) {
-  GetExecutingContext()->uiCommandBuffer()->AddCommand(UICommand::kCreateDocument, nullptr, bindingObject(), nullptr);
+Document* Document::CreateInert(ExecutingContext* context) {
+  return MakeGarbageCollected<Document>(context, true);
+}
+
+Document::Document(ExecutingContext* context) : Document(context, false) {}
+
+Document::Document(ExecutingContext* context, bool is_inert)
+    : ContainerNode(context, this, ConstructionType::kCreateDocument), TreeScope(*this), is_inert_(is_inert) {
+  // The Dart side holds a single document for the window, so the tree of an inert document is kept
+  // by a document fragment there, which is never attached and rendered.
+  GetExecutingContext()->uiCommandBuffer()->AddCommand(
+      is_inert_ ? UICommand::kCreateDocumentFragment : UICommand::kCreateDocument, nullptr, bindingObject(), nullptr);
 }
 
 // https://dom.spec.whatwg.org/#dom-document-createelement
@@ -408,7 +418,90 @@ std::shared_ptr<EventListener> Document::GetWindowAttributeEventListener(const A
   return window->GetAttributeEventListener(event_type);
 }
 
+Element* Document::fullscreenElement() const {
+  return fullscreen_element_;
+}
+
+ScriptPromise Document::exitFullscreen(ExceptionState& exception_state) {
+  auto resolver = ScriptPromiseResolver::Create(GetExecutingContext());
+  ScriptPromise promise = resolver->Promise();
+
+  if (fullscreen_element_ == nullptr) {
+    ExceptionState reject_exception_state;
+    reject_exception_state.ThrowException(ctx(), ErrorType::TypeError, "The document is not in fullscreen.");
+    JSValue error_object = ExceptionState::CurrentException(ctx());
+    resolver->Reject(error_object);
+    JS_FreeValue(ctx(), error_object);
+    return promise;
+  }
+
+  Element* element = fullscreen_element_;
+  fullscreen_element_ = nullptr;
+  element->InvokeBindingMethod(binding_call_methods::kexitFullscreen, 0, nullptr,
+                               FlushUICommandReason::kDependentsOnElement, exception_state);
+  if (exception_state.HasException()) {
+    return promise;
+  }
+
+  DispatchFullscreenChangeEvent(*element, exception_state);
+  resolver->Resolve(JS_UNDEFINED);
+  return promise;
+}
+
+bool Document::EnterFullscreen(Element& element, ExceptionState& exception_state) {
+  if (fullscreen_element_ == &element) {
+    return true;
+  }
+
+  NativeValue result = element.InvokeBindingMethod(binding_call_methods::krequestFullscreen, 0, nullptr,
+                                                   FlushUICommandReason::kDependentsOnElement, exception_state);
+  if (exception_state.HasException()) {
+    return false;
+  }
+  // Dart side returns false when there is no transient user activation.
+  if (!NativeValueConverter<NativeTypeBool>::FromNativeValue(result)) {
+    return false;
+  }
+
+  // Only one element is fullscreen at the time, the previous one leaves fullscreen without an event.
+  if (fullscreen_element_ != nullptr) {
+    fullscreen_element_->InvokeBindingMethod(binding_call_methods::kexitFullscreen, 0, nullptr,
+                                             FlushUICommandReason::kDependentsOnElement, exception_state);
+  }
+
+  fullscreen_element_ = &element;
+  DispatchFullscreenChangeEvent(element, exception_state);
+  return true;
+}
+
+void Document::FullscreenElementRemoved() {
+  Element* element = fullscreen_element_;
+  fullscreen_element_ = nullptr;
+  ExceptionState exception_state;
+  element->InvokeBindingMethod(binding_call_methods::kexitFullscreen, 0, nullptr, FlushUICommandReason::kStandard,
+                               exception_state);
+
+  // The tree is being mutated, so the event is fired at the document once the removal is done.
+  GetExecutingContext()->EnqueueMicrotask(
+      [](void* data) {
+        auto* document = static_cast<Document*>(data);
+        MemberMutationScope scope{document->GetExecutingContext()};
+        ExceptionState exception_state;
+        document->DispatchFullscreenChangeEvent(*document, exception_state);
+      },
+      this);
+}
+
+void Document::DispatchFullscreenChangeEvent(EventTarget& target, ExceptionState& exception_state) {
+  auto* event = MakeGarbageCollected<Event>(GetExecutingContext(), event_type_names::kfullscreenchange,
+                                            Event::Bubbles::kYes, Event::Cancelable::kNo,
+                                            Event::ComposedMode::kComposed,
+                                            std::chrono::system_clock::now().time_since_epoch().count());
+  target.dispatchEvent(event, exception_state);
+}
+
 void Document::Trace(GCVisitor* visitor) const {
+  visitor->TraceMember(fullscreen_element_);
   script_animation_controller_.Trace(visitor);
   ContainerNode::Trace(visitor);
 }
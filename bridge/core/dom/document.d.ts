@@ -28,6 +28,7 @@ interface Document extends Node, ParentNode {
   readonly visibilityState: DartImpl<string>;
   readonly hidden: boolean;
   readonly defaultView: Window;
+  readonly fullscreenElement: Element | null;
 
   createElement(tagName: string, options?: any): Element;
   createElementNS(uri: string | null, tagName: string, options?: any): Element;
@@ -46,6 +47,9 @@ interface Document extends Node, ParentNode {
 
   elementFromPoint(x: number, y: number): Element | null;
 
+  // https://fullscreen.spec.whatwg.org/#api
+  exitFullscreen(): Promise<void>;
+
   onreadystatechange: IDLEventHandler | null;
   new(): Document;
 }
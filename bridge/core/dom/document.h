@@ -6,6 +6,7 @@
 #define BRIDGE_DOCUMENT_H
 
 #include "bindings/qjs/cppgc/local_handle.h"
+#include "bindings/qjs/script_promise.h"
 #include "container_node.h"
 #include "event_type_names.h"
 #include "plugin_api/document.h"
@@ -43,8 +44,13 @@ class Document : public ContainerNode, public TreeScope {
   using ImplType = Document*;
 
   explicit Document(ExecutingContext* context);
+  Document(ExecutingContext* context, bool is_inert);
 
   static Document* Create(ExecutingContext* context, ExceptionState& exception_state);
+  // Creates a document which doesn't belong to the window and is never rendered, such as the one returned by DOMParser.
+  static Document* CreateInert(ExecutingContext* context);
+
+  bool IsInert() const { return is_inert_; }
 
   Element* createElement(const AtomicString& name, ExceptionState& exception_state);
   Element* createElement(const AtomicString& name, const ScriptValue& options, ExceptionState& exception_state);
@@ -74,6 +80,15 @@ class Document : public ContainerNode, public TreeScope {
 
   Element* elementFromPoint(double x, double y, ExceptionState& exception_state);
 
+  // https://fullscreen.spec.whatwg.org/#api
+  Element* fullscreenElement() const;
+  ScriptPromise exitFullscreen(ExceptionState& exception_state);
+  // Makes the element the fullscreen element, used by Element::requestFullscreen().
+  // Returns false when Dart side denies the request because there is no user activation.
+  bool EnterFullscreen(Element& element, ExceptionState& exception_state);
+  // Called when the fullscreen element is removed from the document.
+  void FullscreenElementRemoved();
+
   Window* defaultView() const;
   AtomicString domain();
   void setDomain(const AtomicString& value, ExceptionState& exception_state);
@@ -130,7 +145,11 @@ class Document : public ContainerNode, public TreeScope {
   const DocumentPublicMethods* documentPublicMethods();
 
  private:
+  void DispatchFullscreenChangeEvent(EventTarget& target, ExceptionState& exception_state);
+
   int node_count_{0};
+  bool is_inert_{false};
+  Member<Element> fullscreen_element_;
   ScriptAnimationController script_animation_controller_;
   MutationObserverOptions mutation_observer_types_;
 };
@@ -0,0 +1,429 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "dom_parser.h"
+
+#include <cstring>
+#include <unordered_map>
+#include <vector>
+
+#include "core/dom/comment.h"
+#include "core/dom/document.h"
+#include "core/dom/element.h"
+#include "core/dom/text.h"
+#include "core/executing_context.h"
+#include "core/html/parser/html_parser.h"
+#include "html_names.h"
+
+namespace webf {
+
+namespace {
+
+const char kXMLNamespaceURI[] = "http://www.w3.org/XML/1998/namespace";
+
+void AppendUTF8(std::string& output, uint32_t code_point) {
+  if (code_point < 0x80) {
+    output.push_back(static_cast<char>(code_point));
+  } else if (code_point < 0x800) {
+    output.push_back(static_cast<char>(0xC0 | (code_point >> 6)));
+    output.push_back(static_cast<char>(0x80 | (code_point & 0x3F)));
+  } else if (code_point < 0x10000) {
+    output.push_back(static_cast<char>(0xE0 | (code_point >> 12)));
+    output.push_back(static_cast<char>(0x80 | ((code_point >> 6) & 0x3F)));
+    output.push_back(static_cast<char>(0x80 | (code_point & 0x3F)));
+  } else {
+    output.push_back(static_cast<char>(0xF0 | (code_point >> 18)));
+    output.push_back(static_cast<char>(0x80 | ((code_point >> 12) & 0x3F)));
+    output.push_back(static_cast<char>(0x80 | ((code_point >> 6) & 0x3F)));
+    output.push_back(static_cast<char>(0x80 | (code_point & 0x3F)));
+  }
+}
+
+bool IsNameStartChar(char c) {
+  return (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || c == '_' || c == ':' ||
+         static_cast<unsigned char>(c) >= 0x80;
+}
+
+bool IsNameChar(char c) {
+  return IsNameStartChar(c) || (c >= '0' && c <= '9') || c == '-' || c == '.';
+}
+
+// Elements are parsed recursively, so the nesting is bounded to keep untrusted input from overflowing the stack.
+// Same as the default limit of libxml2.
+constexpr size_t kMaxXMLNestingDepth = 256;
+
+// Builds the tree of a well-formed XML document, the DTD and the processing instructions are skipped.
+// The first error found is thrown as a SyntaxError, and nothing is built after it.
+class XMLTreeBuilder {
+ public:
+  XMLTreeBuilder(Document* document, const std::string& input, ExceptionState& exception_state)
+      : document_(document), ctx_(document->ctx()), input_(input), exception_state_(exception_state) {}
+
+  bool Build() {
+    bool has_root_element = false;
+    while (true) {
+      SkipWhitespace();
+      if (AtEnd())
+        break;
+      if (StartsWith("<!--")) {
+        if (!ParseComment(document_))
+          return false;
+      } else if (StartsWith("<?")) {
+        if (!SkipProcessingInstruction())
+          return false;
+      } else if (StartsWith("<!DOCTYPE")) {
+        if (has_root_element)
+          return Fail("The doctype must come before the root element.");
+        if (!SkipDoctype())
+          return false;
+      } else if (StartsWith("<")) {
+        if (has_root_element)
+          return Fail("Extra content at the end of the document.");
+        if (!ParseElement(document_))
+          return false;
+        has_root_element = true;
+      } else {
+        return Fail("Text is not allowed outside of the root element.");
+      }
+    }
+
+    if (!has_root_element)
+      return Fail("The document has no root element.");
+    return true;
+  }
+
+ private:
+  bool AtEnd() const { return position_ >= input_.length(); }
+
+  bool StartsWith(const char* prefix) const { return input_.compare(position_, strlen(prefix), prefix) == 0; }
+
+  bool SkipWhitespace() {
+    size_t start = position_;
+    while (!AtEnd() && (input_[position_] == ' ' || input_[position_] == '\t' || input_[position_] == '\r' ||
+                        input_[position_] == '\n')) {
+      position_++;
+    }
+    return position_ > start;
+  }
+
+  bool Fail(const std::string& message) {
+    size_t line = 1;
+    for (size_t i = 0; i < position_ && i < input_.length(); i++) {
+      if (input_[i] == '\n')
+        line++;
+    }
+    exception_state_.ThrowException(ctx_, ErrorType::SyntaxError,
+                                    "Failed to parse XML: " + message + " (line " + std::to_string(line) + ")");
+    return false;
+  }
+
+  bool ParseName(std::string& name) {
+    if (AtEnd() || !IsNameStartChar(input_[position_]))
+      return Fail("A name was expected.");
+    size_t start = position_;
+    while (!AtEnd() && IsNameChar(input_[position_])) {
+      position_++;
+    }
+    name = input_.substr(start, position_ - start);
+    return true;
+  }
+
+  bool ParseComment(ContainerNode* parent) {
+    position_ += strlen("<!--");
+    size_t end = input_.find("--", position_);
+    if (end == std::string::npos)
+      return Fail("Unterminated comment.");
+    if (input_.compare(end, 3, "-->") != 0) {
+      position_ = end;
+      return Fail("'--' is not allowed in a comment.");
+    }
+    std::string data = input_.substr(position_, end - position_);
+    position_ = end + strlen("-->");
+    parent->AppendChild(document_->createComment(AtomicString(ctx_, data), ASSERT_NO_EXCEPTION()));
+    return true;
+  }
+
+  bool SkipProcessingInstruction() {
+    size_t end = input_.find("?>", position_);
+    if (end == std::string::npos)
+      return Fail("Unterminated processing instruction.");
+    position_ = end + strlen("?>");
+    return true;
+  }
+
+  bool SkipDoctype() {
+    position_ += strlen("<!DOCTYPE");
+    bool in_internal_subset = false;
+    while (!AtEnd()) {
+      char c = input_[position_++];
+      if (c == '"' || c == '\'') {
+        size_t end = input_.find(c, position_);
+        if (end == std::string::npos)
+          break;
+        position_ = end + 1;
+      } else if (c == '[') {
+        in_internal_subset = true;
+      } else if (c == ']') {
+        in_internal_subset = false;
+      } else if (c == '>' && !in_internal_subset) {
+        return true;
+      }
+    }
+    return Fail("Unterminated doctype.");
+  }
+
+  bool DecodeText(const std::string& raw, std::string& decoded) {
+    for (size_t i = 0; i < raw.length();) {
+      if (raw[i] != '&') {
+        decoded.push_back(raw[i++]);
+        continue;
+      }
+
+      size_t end = raw.find(';', i);
+      if (end == std::string::npos)
+        return Fail("Unterminated entity reference.");
+      std::string entity = raw.substr(i + 1, end - i - 1);
+      if (entity == "lt") {
+        decoded.push_back('<');
+      } else if (entity == "gt") {
+        decoded.push_back('>');
+      } else if (entity == "amp") {
+        decoded.push_back('&');
+      } else if (entity == "quot") {
+        decoded.push_back('"');
+      } else if (entity == "apos") {
+        decoded.push_back('\'');
+      } else if (entity.length() > 1 && entity[0] == '#') {
+        bool is_hex = entity[1] == 'x';
+        std::string digits = entity.substr(is_hex ? 2 : 1);
+        char* digits_end = nullptr;
+        unsigned long code_point = digits.empty() ? 0 : strtoul(digits.c_str(), &digits_end, is_hex ? 16 : 10);
+        if (digits.empty() || *digits_end != '\0' || code_point == 0 || code_point > 0x10FFFF)
+          return Fail("Invalid character reference '&" + entity + ";'.");
+        AppendUTF8(decoded, static_cast<uint32_t>(code_point));
+      } else {
+        return Fail("Entity '" + entity + "' is not defined.");
+      }
+      i = end + 1;
+    }
+    return true;
+  }
+
+  bool LookupNamespace(const std::string& prefix, std::string& namespace_uri) {
+    if (prefix == "xml") {
+      namespace_uri = kXMLNamespaceURI;
+      return true;
+    }
+    for (auto it = namespace_scopes_.rbegin(); it != namespace_scopes_.rend(); it++) {
+      auto found = it->find(prefix);
+      if (found != it->end()) {
+        namespace_uri = found->second;
+        return true;
+      }
+    }
+    // Elements without a prefix are in no namespace unless a default namespace is declared.
+    namespace_uri.clear();
+    return prefix.empty();
+  }
+
+  bool ParseElement(ContainerNode* parent) {
+    // Each open element has a namespace scope, so their count is the current depth.
+    if (namespace_scopes_.size() >= kMaxXMLNestingDepth)
+      return Fail("Elements are nested deeper than " + std::to_string(kMaxXMLNestingDepth) + " levels.");
+    position_++;
+    std::string qualified_name;
+    if (!ParseName(qualified_name))
+      return false;
+
+    std::vector<std::pair<std::string, std::string>> attributes;
+    bool is_self_closing = false;
+    while (true) {
+      bool has_whitespace = SkipWhitespace();
+      if (AtEnd())
+        return Fail("Unterminated start tag '" + qualified_name + "'.");
+      if (StartsWith("/>")) {
+        position_ += strlen("/>");
+        is_self_closing = true;
+        break;
+      }
+      if (input_[position_] == '>') {
+        position_++;
+        break;
+      }
+      if (!has_whitespace)
+        return Fail("Attributes must be separated by whitespace.");
+
+      std::string attribute_name;
+      if (!ParseName(attribute_name))
+        return false;
+      SkipWhitespace();
+      if (AtEnd() || input_[position_] != '=')
+        return Fail("Attribute '" + attribute_name + "' has no value.");
+      position_++;
+      SkipWhitespace();
+      if (AtEnd() || (input_[position_] != '"' && input_[position_] != '\''))
+        return Fail("The value of attribute '" + attribute_name + "' is not quoted.");
+      char quote = input_[position_++];
+      size_t end = input_.find(quote, position_);
+      if (end == std::string::npos)
+        return Fail("Unterminated value of attribute '" + attribute_name + "'.");
+      std::string raw_value = input_.substr(position_, end - position_);
+      if (raw_value.find('<') != std::string::npos)
+        return Fail("'<' is not allowed in the value of attribute '" + attribute_name + "'.");
+      std::string value;
+      if (!DecodeText(raw_value, value))
+        return false;
+      position_ = end + 1;
+
+      for (auto& attribute : attributes) {
+        if (attribute.first == attribute_name)
+          return Fail("Attribute '" + attribute_name + "' is redefined.");
+      }
+      attributes.emplace_back(attribute_name, value);
+    }
+
+    std::unordered_map<std::string, std::string> namespace_scope;
+    for (auto& attribute : attributes) {
+      if (attribute.first == "xmlns") {
+        namespace_scope[""] = attribute.second;
+      } else if (attribute.first.compare(0, strlen("xmlns:"), "xmlns:") == 0) {
+        namespace_scope[attribute.first.substr(strlen("xmlns:"))] = attribute.second;
+      }
+    }
+    namespace_scopes_.push_back(std::move(namespace_scope));
+
+    size_t colon = qualified_name.find(':');
+    std::string prefix = colon == std::string::npos ? "" : qualified_name.substr(0, colon);
+    std::string namespace_uri;
+    if (!LookupNamespace(prefix, namespace_uri))
+      return Fail("Namespace prefix '" + prefix + "' is not defined.");
+
+    Element* element = document_->createElementNS(
+        namespace_uri.empty() ? AtomicString::Null() : AtomicString(ctx_, namespace_uri),
+        AtomicString(ctx_, qualified_name), exception_state_);
+    if (exception_state_.HasException())
+      return false;
+    for (auto& attribute : attributes) {
+      element->setAttribute(AtomicString(ctx_, attribute.first), AtomicString(ctx_, attribute.second),
+                            exception_state_);
+      if (exception_state_.HasException())
+        return false;
+    }
+    parent->AppendChild(element, exception_state_);
+    if (exception_state_.HasException())
+      return false;
+
+    if (!is_self_closing && !ParseContent(element, qualified_name))
+      return false;
+
+    namespace_scopes_.pop_back();
+    return true;
+  }
+
+  bool ParseContent(Element* element, const std::string& qualified_name) {
+    std::string text;
+    while (true) {
+      if (AtEnd())
+        return Fail("Unterminated element '" + qualified_name + "'.");
+
+      if (input_[position_] != '<') {
+        size_t end = input_.find('<', position_);
+        if (end == std::string::npos)
+          end = input_.length();
+        std::string raw_text = input_.substr(position_, end - position_);
+        if (raw_text.find("]]>") != std::string::npos)
+          return Fail("']]>' is not allowed in text.");
+        if (!DecodeText(raw_text, text))
+          return false;
+        position_ = end;
+        continue;
+      }
+
+      if (StartsWith("<![CDATA[")) {
+        position_ += strlen("<![CDATA[");
+        size_t end = input_.find("]]>", position_);
+        if (end == std::string::npos)
+          return Fail("Unterminated CDATA section.");
+        text.append(input_, position_, end - position_);
+        position_ = end + strlen("]]>");
+        continue;
+      }
+
+      // Adjacent text and CDATA sections are merged into a single text node.
+      if (!text.empty()) {
+        element->AppendChild(document_->createTextNode(AtomicString(ctx_, text), ASSERT_NO_EXCEPTION()));
+        text.clear();
+      }
+
+      if (StartsWith("</")) {
+        position_ += strlen("</");
+        std::string end_name;
+        if (!ParseName(end_name))
+          return false;
+        SkipWhitespace();
+        if (AtEnd() || input_[position_] != '>')
+          return Fail("Unterminated end tag '" + end_name + "'.");
+        position_++;
+        if (end_name != qualified_name)
+          return Fail("Opening and ending tag mismatch: '" + qualified_name + "' and '" + end_name + "'.");
+        return true;
+      }
+
+      if (StartsWith("<!--")) {
+        if (!ParseComment(element))
+          return false;
+      } else if (StartsWith("<?")) {
+        if (!SkipProcessingInstruction())
+          return false;
+      } else if (StartsWith("<!")) {
+        return Fail("Declarations are not allowed in an element.");
+      } else if (!ParseElement(element)) {
+        return false;
+      }
+    }
+  }
+
+  Document* document_;
+  JSContext* ctx_;
+  const std::string& input_;
+  ExceptionState& exception_state_;
+  size_t position_{0};
+  std::vector<std::unordered_map<std::string, std::string>> namespace_scopes_;
+};
+
+Document* ParseHTMLDocument(ExecutingContext* context, const std::string& input) {
+  Document* document = Document::CreateInert(context);
+  Element* html = document->createElement(html_names::khtml, ASSERT_NO_EXCEPTION());
+  document->AppendChild(html);
+  HTMLParser::parseHTML(input, html);
+
+  // The parser skips blank input, while browsers always create the head and the body.
+  if (document->body() == nullptr) {
+    html->AppendChild(document->createElement(html_names::khead, ASSERT_NO_EXCEPTION()));
+    html->AppendChild(document->createElement(html_names::kbody, ASSERT_NO_EXCEPTION()));
+  }
+  return document;
+}
+
+}  // namespace
+
+Document* DOMParser::ParseFromString(ExecutingContext* context,
+                                     const std::string& input,
+                                     DOMParserSupportedType type,
+                                     ExceptionState& exception_state) {
+  if (type == DOMParserSupportedType::kTextHtml) {
+    return ParseHTMLDocument(context, input);
+  }
+
+  // SVG documents are XML documents, the elements are created as SVG elements
+  // when they are in the SVG namespace, such as <svg xmlns="http://www.w3.org/2000/svg">.
+  Document* document = Document::CreateInert(context);
+  XMLTreeBuilder builder(document, input, exception_state);
+  if (!builder.Build()) {
+    return nullptr;
+  }
+  return document;
+}
+
+}  // namespace webf
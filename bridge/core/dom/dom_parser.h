@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_DOM_DOM_PARSER_H_
+#define WEBF_CORE_DOM_DOM_PARSER_H_
+
+#include <string>
+#include "bindings/qjs/exception_state.h"
+
+namespace webf {
+
+class Document;
+class ExecutingContext;
+
+// https://html.spec.whatwg.org/multipage/dynamic-markup-insertion.html#domparsersupportedtype
+enum class DOMParserSupportedType : int32_t {
+  kTextHtml = 0,
+  kTextXml = 1,
+  kImageSvgXml = 2,
+};
+
+// https://html.spec.whatwg.org/multipage/dynamic-markup-insertion.html#dom-parsing-and-serialization
+class DOMParser {
+ public:
+  // Parses the input into a new inert document.
+  // HTML is parsed leniently, while XML which is not well-formed throws a SyntaxError.
+  static Document* ParseFromString(ExecutingContext* context,
+                                   const std::string& input,
+                                   DOMParserSupportedType type,
+                                   ExceptionState& exception_state);
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_DOM_DOM_PARSER_H_
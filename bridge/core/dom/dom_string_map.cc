@@ -123,7 +123,8 @@ void DOMStringMap::NamedPropertyEnumerator(std::vector<AtomicString>& props, web
 
 bool DOMStringMap::NamedPropertyQuery(const webf::AtomicString& key, webf::ExceptionState& exception_state) {
   for (auto& attribute : *owner_element_->attributes()) {
-    if (PropertyNameMatchesAttributeName(key, attribute.first, key.length(), attribute.first.length())) {
+    if (IsValidAttributeName(attribute.first) &&
+        PropertyNameMatchesAttributeName(key, attribute.first, key.length(), attribute.first.length())) {
       return true;
     }
   }
@@ -132,7 +133,8 @@ bool DOMStringMap::NamedPropertyQuery(const webf::AtomicString& key, webf::Excep
 
 AtomicString DOMStringMap::item(const webf::AtomicString& key, webf::ExceptionState& exception_state) {
   for (auto& attribute : *owner_element_->attributes()) {
-    if (PropertyNameMatchesAttributeName(key, attribute.first, key.length(), attribute.first.length())) {
+    if (IsValidAttributeName(attribute.first) &&
+        PropertyNameMatchesAttributeName(key, attribute.first, key.length(), attribute.first.length())) {
       return attribute.second;
     }
   }
@@ -162,6 +164,11 @@ bool DOMStringMap::DeleteItem(const webf::AtomicString& key, webf::ExceptionStat
   return false;
 }
 
+const DOMStringMapPublicMethods* DOMStringMap::domStringMapPublicMethods() {
+  static DOMStringMapPublicMethods dom_string_map_public_methods;
+  return &dom_string_map_public_methods;
+}
+
 void DOMStringMap::Trace(webf::GCVisitor* visitor) const {
   visitor->TraceMember(owner_element_);
 }
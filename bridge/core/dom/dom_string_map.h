@@ -9,6 +9,7 @@
 #include "bindings/qjs/cppgc/member.h"
 #include "bindings/qjs/exception_state.h"
 #include "bindings/qjs/script_wrappable.h"
+#include "plugin_api/dom_string_map.h"
 
 namespace webf {
 
@@ -30,6 +31,8 @@ class DOMStringMap : public ScriptWrappable {
 
   void Trace(webf::GCVisitor* visitor) const override;
 
+  const DOMStringMapPublicMethods* domStringMapPublicMethods();
+
  private:
   Member<Element> owner_element_;
 };
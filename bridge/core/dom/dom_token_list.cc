@@ -287,4 +287,9 @@ void DOMTokenList::DidUpdateAttributeValue(const AtomicString& old_value, const
     token_set_.Set(element_->ctx(), new_value);
 }
 
+const DOMTokenListPublicMethods* DOMTokenList::domTokenListPublicMethods() {
+  static DOMTokenListPublicMethods dom_token_list_public_methods;
+  return &dom_token_list_public_methods;
+}
+
 }  // namespace webf
\ No newline at end of file
@@ -8,6 +8,7 @@
 #include "bindings/qjs/cppgc/gc_visitor.h"
 #include "bindings/qjs/cppgc/member.h"
 #include "bindings/qjs/script_wrappable.h"
+#include "plugin_api/dom_token_list.h"
 #include "space_split_string.h"
 
 namespace webf {
@@ -44,6 +45,8 @@ class DOMTokenList : public ScriptWrappable {
 
   void Trace(GCVisitor* visitor) const override;
 
+  const DOMTokenListPublicMethods* domTokenListPublicMethods();
+
   bool NamedPropertyQuery(const AtomicString& key, ExceptionState& exception_state);
   void NamedPropertyEnumerator(std::vector<AtomicString>& props, ExceptionState& exception_state);
 
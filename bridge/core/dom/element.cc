@@ -174,6 +174,20 @@ void Element::scrollTo(const std::shared_ptr<ScrollToOptions>& options, Exceptio
   return scroll(options, exception_state);
 }
 
+void Element::scrollIntoView(const AtomicString& block,
+                             const AtomicString& inline_,
+                             bool smooth,
+                             ExceptionState& exception_state) {
+  const NativeValue args[] = {
+      NativeValueConverter<NativeTypeString>::ToNativeValue(ctx(), block),
+      NativeValueConverter<NativeTypeString>::ToNativeValue(ctx(), inline_),
+      NativeValueConverter<NativeTypeBool>::ToNativeValue(smooth),
+  };
+  InvokeBindingMethod(binding_call_methods::kscrollIntoView, 3, args,
+                      FlushUICommandReason::kDependentsOnElement | FlushUICommandReason::kDependentsOnLayout,
+                      exception_state);
+}
+
 bool Element::HasTagName(const AtomicString& name) const {
   return name == local_name_;
 }
@@ -262,6 +276,83 @@ Element* Element::closest(const AtomicString& selectors, ExceptionState& excepti
   return NativeValueConverter<NativeTypePointer<Element>>::FromNativeValue(ctx(), result);
 }
 
+// https://w3c.github.io/pointerevents/#dom-element-setpointercapture
+void Element::setPointerCapture(int64_t pointer_id, ExceptionState& exception_state) {
+  if (!isConnected()) {
+    exception_state.ThrowDOMException(ctx(), "InvalidStateError", "The element is not connected to a document.");
+    return;
+  }
+  NativeValue arguments[] = {NativeValueConverter<NativeTypeInt64>::ToNativeValue(pointer_id)};
+  NativeValue result = InvokeBindingMethod(binding_call_methods::ksetPointerCapture, 1, arguments,
+                                           FlushUICommandReason::kDependentsOnElement, exception_state);
+  if (exception_state.HasException()) {
+    return;
+  }
+  // Dart side returns false when the pointer is not active.
+  if (!NativeValueConverter<NativeTypeBool>::FromNativeValue(result)) {
+    exception_state.ThrowDOMException(ctx(), "NotFoundError", "No active pointer with the given id is found.");
+  }
+}
+
+// https://w3c.github.io/pointerevents/#dom-element-releasepointercapture
+void Element::releasePointerCapture(int64_t pointer_id, ExceptionState& exception_state) {
+  NativeValue arguments[] = {NativeValueConverter<NativeTypeInt64>::ToNativeValue(pointer_id)};
+  NativeValue result = InvokeBindingMethod(binding_call_methods::kreleasePointerCapture, 1, arguments,
+                                           FlushUICommandReason::kDependentsOnElement, exception_state);
+  if (exception_state.HasException()) {
+    return;
+  }
+  if (!NativeValueConverter<NativeTypeBool>::FromNativeValue(result)) {
+    exception_state.ThrowDOMException(ctx(), "NotFoundError", "No active pointer with the given id is found.");
+  }
+}
+
+bool Element::hasPointerCapture(int64_t pointer_id, ExceptionState& exception_state) {
+  NativeValue arguments[] = {NativeValueConverter<NativeTypeInt64>::ToNativeValue(pointer_id)};
+  NativeValue result = InvokeBindingMethod(binding_call_methods::khasPointerCapture, 1, arguments,
+                                           FlushUICommandReason::kDependentsOnElement, exception_state);
+  if (exception_state.HasException()) {
+    return false;
+  }
+  return NativeValueConverter<NativeTypeBool>::FromNativeValue(result);
+}
+
+ScriptPromise Element::requestFullscreen(ExceptionState& exception_state) {
+  auto resolver = ScriptPromiseResolver::Create(GetExecutingContext());
+  ScriptPromise promise = resolver->Promise();
+
+  const char* error_message = nullptr;
+  if (!isConnected()) {
+    error_message = "The element is not connected to a document.";
+  } else {
+    bool entered = GetDocument().EnterFullscreen(*this, exception_state);
+    if (exception_state.HasException()) {
+      return promise;
+    }
+    if (!entered) {
+      error_message = "The request is not allowed without a user gesture.";
+    }
+  }
+
+  if (error_message != nullptr) {
+    auto* error_event = MakeGarbageCollected<Event>(GetExecutingContext(), event_type_names::kfullscreenerror,
+                                                    Event::Bubbles::kYes, Event::Cancelable::kNo,
+                                                    Event::ComposedMode::kComposed,
+                                                    std::chrono::system_clock::now().time_since_epoch().count());
+    dispatchEvent(error_event, exception_state);
+
+    ExceptionState reject_exception_state;
+    reject_exception_state.ThrowException(ctx(), ErrorType::TypeError, error_message);
+    JSValue error_object = ExceptionState::CurrentException(ctx());
+    resolver->Reject(error_object);
+    JS_FreeValue(ctx(), error_object);
+    return promise;
+  }
+
+  resolver->Resolve(JS_UNDEFINED);
+  return promise;
+}
+
 InlineCssStyleDeclaration* Element::style() {
   if (!IsStyledElement())
     return nullptr;
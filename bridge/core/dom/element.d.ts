@@ -65,6 +65,15 @@ interface Element extends Node, ParentNode, ChildNode {
 
   closest(selectors: string): Element | null;
 
+  // Pointer Events
+  // https://w3c.github.io/pointerevents/#extensions-to-the-element-interface
+  setPointerCapture(pointerId: int64): void;
+  releasePointerCapture(pointerId: int64): void;
+  hasPointerCapture(pointerId: int64): boolean;
+
+  // https://fullscreen.spec.whatwg.org/#api
+  requestFullscreen(): Promise<void>;
+
   scroll(options?: ScrollToOptions): void;
   scroll(x: number, y: number): void;
   scrollBy(options?: ScrollToOptions): void;
@@ -78,6 +78,11 @@ class Element : public ContainerNode {
   void scrollBy(ExceptionState& exception_state);
   void scrollBy(double x, double y, ExceptionState& exception_state);
   void scrollBy(const std::shared_ptr<ScrollToOptions>& options, ExceptionState& exception_state);
+  // Not exposed to JavaScript yet. |block| and |inline_| take the ScrollLogicalPosition values of the CSSOM View spec.
+  void scrollIntoView(const AtomicString& block,
+                      const AtomicString& inline_,
+                      bool smooth,
+                      ExceptionState& exception_state);
 
   ScriptPromise toBlob(double device_pixel_ratio, ExceptionState& exception_state);
   ScriptPromise toBlob(ExceptionState& exception_state);
@@ -131,6 +136,13 @@ class Element : public ContainerNode {
 
   Element* closest(const AtomicString& selectors, ExceptionState& exception_state);
 
+  void setPointerCapture(int64_t pointer_id, ExceptionState& exception_state);
+  void releasePointerCapture(int64_t pointer_id, ExceptionState& exception_state);
+  bool hasPointerCapture(int64_t pointer_id, ExceptionState& exception_state);
+
+  // https://fullscreen.spec.whatwg.org/#dom-element-requestfullscreen
+  ScriptPromise requestFullscreen(ExceptionState& exception_state);
+
   InlineCssStyleDeclaration* style();
   InlineCssStyleDeclaration& EnsureCSSStyleDeclaration();
   DOMTokenList* classList();
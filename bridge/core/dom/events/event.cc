@@ -7,17 +7,36 @@
 #include "bindings/qjs/cppgc/gc_visitor.h"
 #include "core/executing_context.h"
 #include "event_target.h"
+#include "foundation/logging.h"
 #include "foundation/native_value_converter.h"
 
 namespace webf {
 
+namespace {
+
+// Event timestamps are measured from the time origin of the context, the same as performance.now().
+double TimeStampSinceTimeOrigin(ExecutingContext* context) {
+  auto duration = std::chrono::duration_cast<std::chrono::microseconds>(std::chrono::steady_clock::now() -
+                                                                        context->steadyTimeOrigin());
+  return static_cast<double>(duration.count()) / 1000;
+}
+
+// The timestamps of the events from Dart are the milliseconds since the Unix epoch.
+double TimeStampSinceTimeOrigin(ExecutingContext* context, int64_t epoch_time_stamp) {
+  auto time_origin =
+      std::chrono::duration_cast<std::chrono::milliseconds>(context->timeOrigin().time_since_epoch()).count();
+  return static_cast<double>(epoch_time_stamp - time_origin);
+}
+
+}  // namespace
+
 Event::Event(ExecutingContext* context, const AtomicString& event_type)
     : Event(context,
             event_type,
             Bubbles::kNo,
             Cancelable::kNo,
             ComposedMode::kComposed,
-            std::chrono::system_clock::now().time_since_epoch().count()) {}
+            TimeStampSinceTimeOrigin(context)) {}
 
 Event::Event(ExecutingContext* context, const AtomicString& type, const std::shared_ptr<EventInit>& init)
     : Event(context,
@@ -25,7 +44,7 @@ Event::Event(ExecutingContext* context, const AtomicString& type, const std::sha
             init->bubbles() ? Bubbles::kYes : Bubbles::kNo,
             init->cancelable() ? Cancelable::kYes : Cancelable::kNo,
             init->composed() ? ComposedMode::kComposed : ComposedMode::kScoped,
-            std::chrono::system_clock::now().time_since_epoch().count()) {}
+            TimeStampSinceTimeOrigin(context)) {}
 
 Event::Event(ExecutingContext* context,
              const AtomicString& event_type,
@@ -59,7 +78,7 @@ Event::Event(ExecutingContext* context, const AtomicString& event_type, NativeEv
       bubbles_(native_event->bubbles),
       composed_(native_event->composed),
       cancelable_(native_event->cancelable),
-      time_stamp_(native_event->timeStamp),
+      time_stamp_(TimeStampSinceTimeOrigin(context, native_event->timeStamp)),
       default_prevented_(native_event->defaultPrevented),
       propagation_stopped_(false),
       immediate_propagation_stopped_(false),
@@ -274,10 +293,18 @@ bool Event::IsPointerEvent() const {
   return false;
 }
 
+bool Event::IsWheelEvent() const {
+  return false;
+}
+
 bool Event::IsInputEvent() const {
   return false;
 }
 
+bool Event::IsCompositionEvent() const {
+  return false;
+}
+
 bool Event::IsCloseEvent() const {
   return false;
 }
@@ -310,6 +337,10 @@ bool Event::IsHybridRouterChangeEvent() const {
   return false;
 }
 
+bool Event::IsStorageEvent() const {
+  return false;
+}
+
 bool Event::IsIntersectionchangeEvent() const {
   return false;
 }
@@ -332,6 +363,15 @@ bool Event::IsPromiseRejectionEvent() const {
 
 void Event::preventDefault(ExceptionState& exception_state) {
   if (handling_passive_ != PassiveMode::kNotPassive && handling_passive_ != PassiveMode::kNotPassiveDefault) {
+    if (exception_state.IsForNativePlugin()) {
+      exception_state.ThrowDOMException(ctx(), "NotAllowedError",
+                                        "Unable to preventDefault inside passive event listener invocation.");
+      return;
+    }
+    // The same as browsers, the call is ignored with a warning so that developers notice.
+    std::stringstream stream;
+    stream << "Unable to preventDefault inside passive event listener invocation.";
+    printLog(GetExecutingContext(), stream, "warn", nullptr);
     return;
   }
 
@@ -15,6 +15,10 @@ interface Event {
    * Returns the object whose event listener's callback is currently being invoked.
    */
   readonly currentTarget: EventTarget | null;
+  /**
+   * Returns the event's phase, which is one of NONE, CAPTURING_PHASE, AT_TARGET, and BUBBLING_PHASE.
+   */
+  readonly eventPhase: int64;
   /**
    * Returns true if preventDefault() was invoked successfully to indicate cancelation, and false otherwise.
    */
@@ -6,6 +6,7 @@
 #define BRIDGE_EVENT_H
 
 #include <cinttypes>
+#include <vector>
 #include "bindings/qjs/atomic_string.h"
 #include "bindings/qjs/cppgc/member.h"
 #include "bindings/qjs/script_wrappable.h"
@@ -149,6 +150,11 @@ class Event : public ScriptWrappable {
   uint8_t eventPhase() const { return event_phase_; }
   void SetEventPhase(uint8_t event_phase) { event_phase_ = event_phase; }
 
+  // The targets the event propagates through, from the target up to the window. Only set while the event is being
+  // dispatched, the dispatcher keeps the targets alive until then.
+  const std::vector<EventTarget*>& eventPath() const { return event_path_; }
+  void SetEventPath(std::vector<EventTarget*> event_path) { event_path_ = std::move(event_path); }
+
   bool NamedPropertyQuery(const AtomicString& key, ExceptionState& exception_state);
   void NamedPropertyEnumerator(std::vector<AtomicString>& names, ExceptionState&);
   ScriptValue item(const AtomicString& key, ExceptionState& exception_state);
@@ -163,7 +169,9 @@ class Event : public ScriptWrappable {
   virtual bool IsTouchEvent() const;
   virtual bool IsGestureEvent() const;
   virtual bool IsPointerEvent() const;
+  virtual bool IsWheelEvent() const;
   virtual bool IsInputEvent() const;
+  virtual bool IsCompositionEvent() const;
   virtual bool IsCloseEvent() const;
   virtual bool IsCustomEvent() const;
   virtual bool IsTransitionEvent() const;
@@ -173,6 +181,7 @@ class Event : public ScriptWrappable {
   virtual bool IsIntersectionchangeEvent() const;
   virtual bool IsHashChangeEvent() const;
   virtual bool IsHybridRouterChangeEvent() const;
+  virtual bool IsStorageEvent() const;
 
   // Drag events are a subset of mouse events.
   virtual bool IsDragEvent() const;
@@ -267,6 +276,7 @@ class Event : public ScriptWrappable {
   NativeEvent* raw_event_ = nullptr;
   Member<EventTarget> target_;
   Member<EventTarget> current_target_;
+  std::vector<EventTarget*> event_path_;
   std::vector<ScriptValue> customized_event_props_;
   EventPublicMethods event_public_methods;
   friend void set_event_prop(JSContext* ctx,
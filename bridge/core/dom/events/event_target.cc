@@ -5,6 +5,9 @@
 #include "plugin_api/event_target.h"
 #include <cstdint>
 #include "binding_call_methods.h"
+#include "core/dom/container_node.h"
+#include "core/dom/document.h"
+#include "core/frame/window.h"
 #include "bindings/qjs/converter_impl.h"
 #include "event_factory.h"
 #include "event_target.h"
@@ -316,9 +319,12 @@ bool EventTarget::RemoveEventListenerInternal(const AtomicString& event_type,
   size_t index_of_removed_listener;
   RegisteredEventListener registered_listener;
 
+  bool is_capture = options->hasCapture() && options->capture();
+  EventListenerMap& listener_map = is_capture ? d->event_capture_listener_map : d->event_listener_map;
+
   uint32_t listener_count = UINT32_MAX;
-  if (!d->event_listener_map.Remove(event_type, listener, options, &index_of_removed_listener, &registered_listener,
-                                    &listener_count))
+  if (!listener_map.Remove(event_type, listener, options, &index_of_removed_listener, &registered_listener,
+                           &listener_count))
     return false;
 
   // Notify firing events planning to invoke the listener at 'index' that
@@ -342,11 +348,9 @@ bool EventTarget::RemoveEventListenerInternal(const AtomicString& event_type,
   }
 
   if (listener_count == 0) {
-    bool has_capture = options->hasCapture() && options->capture();
-
     GetExecutingContext()->uiCommandBuffer()->AddCommand(UICommand::kRemoveEvent,
                                                          std::move(event_type.ToNativeString(ctx())), bindingObject(),
-                                                         has_capture ? (void*)0x01 : nullptr);
+                                                         is_capture ? (void*)0x01 : nullptr);
   }
 
   return true;
@@ -354,11 +358,65 @@ bool EventTarget::RemoveEventListenerInternal(const AtomicString& event_type,
 
 DispatchEventResult EventTarget::DispatchEventInternal(Event& event, ExceptionState& exception_state) {
   event.SetTarget(this);
+
+  // The targets above a node target, nearest first. Their wrappers are held until the dispatch finishes, so
+  // listeners that detach part of the tree can not free a target that is still waiting for the event.
+  std::vector<EventTarget*> event_path;
+  if (auto* node = DynamicTo<Node>(this)) {
+    for (ContainerNode* parent = node->parentNode(); parent != nullptr; parent = parent->parentNode()) {
+      event_path.emplace_back(parent);
+    }
+    // Events of the document tree propagate to the window at last, the same as on the Dart side.
+    EventTarget* root = event_path.empty() ? this : event_path.back();
+    if (auto* document = DynamicTo<Document>(root)) {
+      event_path.emplace_back(document->defaultView());
+    }
+  }
+  std::vector<JSValue> event_path_values;
+  event_path_values.reserve(event_path.size());
+  for (EventTarget* target : event_path) {
+    event_path_values.emplace_back(target->ToQuickJS());
+  }
+
+  std::vector<EventTarget*> composed_path;
+  composed_path.reserve(event_path.size() + 1);
+  composed_path.emplace_back(this);
+  composed_path.insert(composed_path.end(), event_path.begin(), event_path.end());
+  event.SetEventPath(std::move(composed_path));
+
+  // Refs: https://dom.spec.whatwg.org/#concept-event-dispatch
+  event.SetEventPhase(Event::kCapturingPhase);
+  for (auto it = event_path.rbegin(); it != event_path.rend() && !event.propagationStopped(); ++it) {
+    event.SetCurrentTarget(*it);
+    (*it)->FireEventListeners(event, true, exception_state);
+  }
+
   event.SetCurrentTarget(this);
   event.SetEventPhase(Event::kAtTarget);
-  DispatchEventResult dispatch_result = FireEventListeners(event, exception_state);
+  if (!event.propagationStopped()) {
+    FireEventListeners(event, true, exception_state);
+  }
+  if (!event.propagationStopped()) {
+    FireEventListeners(event, false, exception_state);
+  }
+
+  if (event.bubbles()) {
+    event.SetEventPhase(Event::kBubblingPhase);
+    for (auto it = event_path.begin(); it != event_path.end() && !event.propagationStopped(); ++it) {
+      event.SetCurrentTarget(*it);
+      (*it)->FireEventListeners(event, false, exception_state);
+    }
+  }
+
+  event.SetCurrentTarget(this);
   event.SetEventPhase(0);
-  return dispatch_result;
+  event.SetEventPath({});
+
+  for (JSValue value : event_path_values) {
+    JS_FreeValue(ctx(), value);
+  }
+
+  return GetDispatchEventResult(event);
 }
 
 NativeValue EventTarget::HandleCallFromDartSide(const AtomicString& method,
@@ -398,7 +456,12 @@ NativeValue EventTarget::HandleDispatchEventFromDart(int32_t argc, const NativeV
 
   ExceptionState exception_state;
   event->SetTrusted(false);
-  event->SetEventPhase(Event::kAtTarget);
+  // The Dart side walks the event path itself and calls into each target once per phase.
+  if (event->target() == this) {
+    event->SetEventPhase(Event::kAtTarget);
+  } else {
+    event->SetEventPhase(isCapture ? Event::kCapturingPhase : Event::kBubblingPhase);
+  }
   DispatchEventResult dispatch_result = FireEventListeners(*event, isCapture, exception_state);
   event->SetEventPhase(0);
 
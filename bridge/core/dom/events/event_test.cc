@@ -24,3 +24,24 @@ TEST(MouseEvent, init) {
   EXPECT_EQ(errorCalled, false);
   EXPECT_EQ(logCalled, true);
 }
+
+TEST(Event, timeStamp) {
+  bool static errorCalled = false;
+  bool static logCalled = false;
+  webf::WebFPage::consoleMessageHandler = [](void* ctx, const std::string& message, int logLevel) {
+    EXPECT_STREQ(message.c_str(), "true true");
+    logCalled = true;
+  };
+  auto env = TEST_init([](double contextId, const char* errmsg) { errorCalled = true; });
+  auto context = env->page()->getContext();
+  const char* code = R"(
+let before = performance.now();
+let event = new Event('click');
+let after = performance.now();
+console.log(event.timeStamp >= before, event.timeStamp < after + 1);
+)";
+  env->page()->evaluateScript(code, strlen(code), "vm://", 0);
+
+  EXPECT_EQ(errorCalled, false);
+  EXPECT_EQ(logCalled, true);
+}
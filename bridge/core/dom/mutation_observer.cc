@@ -109,11 +109,23 @@ MutationObserver* MutationObserver::Create(ExecutingContext* context,
   return MakeGarbageCollected<MutationObserver>(context, function);
 }
 
+MutationObserver* MutationObserver::Create(ExecutingContext* context,
+                                           const std::shared_ptr<WebFNativeFunction>& native_function,
+                                           ExceptionState& exception_state) {
+  return MakeGarbageCollected<MutationObserver>(context, native_function);
+}
+
 MutationObserver::MutationObserver(ExecutingContext* context, const std::shared_ptr<QJSFunction>& function)
     : ScriptWrappable(context->ctx()), function_(function) {
   priority_ = g_observer_priority++;
 }
 
+MutationObserver::MutationObserver(ExecutingContext* context,
+                                   const std::shared_ptr<WebFNativeFunction>& native_function)
+    : ScriptWrappable(context->ctx()), native_function_(native_function) {
+  priority_ = g_observer_priority++;
+}
+
 MutationObserver::~MutationObserver() {}
 
 void MutationObserver::observe(Node* node,
@@ -237,6 +249,12 @@ void MutationObserver::Deliver() {
   if (records_.empty())
     return;
 
+  // Native observers take the records by themselves, so that they are converted only once.
+  if (native_function_ != nullptr) {
+    native_function_->Invoke(GetExecutingContext(), 0, nullptr);
+    return;
+  }
+
   MutationRecordVector records;
   swap(records_, records);
 
@@ -267,7 +285,14 @@ void MutationObserver::Trace(GCVisitor* visitor) const {
   for (auto& re : registrations_) {
     visitor->TraceMember(re);
   }
-  function_->Trace(visitor);
+  if (function_ != nullptr) {
+    function_->Trace(visitor);
+  }
+}
+
+const MutationObserverPublicMethods* MutationObserver::mutationObserverPublicMethods() {
+  static MutationObserverPublicMethods mutation_observer_public_methods;
+  return &mutation_observer_public_methods;
 }
 
 }  // namespace webf
\ No newline at end of file
@@ -38,7 +38,9 @@
 #include "bindings/qjs/cppgc/member.h"
 #include "bindings/qjs/exception_state.h"
 #include "bindings/qjs/script_wrappable.h"
+#include "core/native/native_function.h"
 #include "mutation_record.h"
+#include "plugin_api/mutation_observer.h"
 #include "qjs_mutation_observer_init.h"
 
 namespace webf {
@@ -75,8 +77,14 @@ class MutationObserver final : public ScriptWrappable {
   static MutationObserver* Create(ExecutingContext* context,
                                   const std::shared_ptr<QJSFunction>& function,
                                   ExceptionState& exception_state);
+  // Creates an observer for native plugins. The callback is invoked without arguments,
+  // and the pending records are retrieved with takeRecords().
+  static MutationObserver* Create(ExecutingContext* context,
+                                  const std::shared_ptr<WebFNativeFunction>& native_function,
+                                  ExceptionState& exception_state);
 
   MutationObserver(ExecutingContext*, const std::shared_ptr<QJSFunction>& function);
+  MutationObserver(ExecutingContext*, const std::shared_ptr<WebFNativeFunction>& native_function);
   ~MutationObserver() override;
 
   void observe(Node*, const std::shared_ptr<MutationObserverInit>& init, ExceptionState&);
@@ -95,10 +103,13 @@ class MutationObserver final : public ScriptWrappable {
 
   void Trace(webf::GCVisitor* visitor) const override;
 
+  const MutationObserverPublicMethods* mutationObserverPublicMethods();
+
  private:
   MutationRecordVector records_;
   MutationObserverRegistrationSet registrations_;
   std::shared_ptr<QJSFunction> function_;
+  std::shared_ptr<WebFNativeFunction> native_function_;
   unsigned priority_;
 };
 
@@ -289,6 +289,23 @@ Node* Node::cloneNode(ExceptionState& exception_state) const {
   return cloneNode(false, exception_state);
 }
 
+// https://dom.spec.whatwg.org/#dom-node-normalize
+void Node::normalize(ExceptionState& exception_state) {
+  // Go through the subtree in post order, so that the Text nodes are merged before their parents are visited.
+  Node* node = this;
+  while (Node* first_child = node->firstChild())
+    node = first_child;
+  while (node && node != this) {
+    if (node->IsTextNode()) {
+      node = To<Text>(node)->MergeNextSiblingNodesIfPossible(exception_state);
+      if (exception_state.HasException())
+        return;
+    } else {
+      node = NodeTraversal::NextPostOrder(*node);
+    }
+  }
+}
+
 Node* Node::cloneNode(bool deep, ExceptionState&) const {
   // https://dom.spec.whatwg.org/#dom-node-clonenode
 
@@ -600,7 +617,11 @@ void Node::RemovedFrom(ContainerNode& insertion_point) {
   assert(insertion_point.isConnected() || IsContainerNode());
   if (insertion_point.isConnected()) {
     ClearFlag(kIsConnectedFlag);
-    insertion_point.GetDocument().DecrementNodeCount();
+    Document& document = insertion_point.GetDocument();
+    document.DecrementNodeCount();
+    if (document.fullscreenElement() == this) {
+      document.FullscreenElementRemoved();
+    }
   }
 }
 
@@ -644,6 +665,45 @@ bool Node::contains(const Node* node, ExceptionState& exception_state) const {
   return this == node || node->IsDescendantOf(this);
 }
 
+uint16_t Node::compareDocumentPosition(const Node* other_node) const {
+  if (other_node == this)
+    return kDocumentPositionEquivalent;
+
+  // Collect the ancestor chains, the root comes last.
+  std::vector<const Node*> chain1;
+  std::vector<const Node*> chain2;
+  for (const Node* node = this; node; node = node->parentNode())
+    chain1.push_back(node);
+  for (const Node* node = other_node; node; node = node->parentNode())
+    chain2.push_back(node);
+
+  // Nodes in different trees are disconnected, the order between them only has to be consistent.
+  if (chain1.back() != chain2.back()) {
+    return kDocumentPositionDisconnected | kDocumentPositionImplementationSpecific |
+           (this < other_node ? kDocumentPositionFollowing : kDocumentPositionPreceding);
+  }
+
+  // Walk down from the root until the chains differ, the nodes there are siblings.
+  size_t index1 = chain1.size();
+  size_t index2 = chain2.size();
+  while (index1 > 0 && index2 > 0) {
+    const Node* child1 = chain1[--index1];
+    const Node* child2 = chain2[--index2];
+    if (child1 != child2) {
+      for (const Node* child = child2->nextSibling(); child; child = child->nextSibling()) {
+        if (child == child1)
+          return kDocumentPositionPreceding;
+      }
+      return kDocumentPositionFollowing;
+    }
+  }
+
+  // One chain is exhausted, so one node is an ancestor of the other.
+  if (index1 == 0)
+    return kDocumentPositionContainedBy | kDocumentPositionFollowing;
+  return kDocumentPositionContains | kDocumentPositionPreceding;
+}
+
 bool Node::ContainsIncludingHostElements(const Node& node) const {
   const Node* current = &node;
   do {
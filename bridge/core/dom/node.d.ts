@@ -79,6 +79,10 @@ interface Node extends EventTarget {
    */
   isEqualNode(otherNode: Node | null): boolean;
   isSameNode(otherNode: Node | null): boolean;
+  /**
+   * Removes empty Text nodes and merges adjacent Text nodes in the subtree.
+   */
+  normalize(): void;
   removeChild(oldChild: Node): Node;
   remove(): void;
   replaceChild(newChild: Node, oldChild: Node): Node;
@@ -64,6 +64,17 @@ class Node : public EventTarget {
     kDocumentFragmentNode = 11,
   };
 
+  // https://dom.spec.whatwg.org/#dom-node-comparedocumentposition
+  enum DocumentPosition {
+    kDocumentPositionEquivalent = 0x00,
+    kDocumentPositionDisconnected = 0x01,
+    kDocumentPositionPreceding = 0x02,
+    kDocumentPositionFollowing = 0x04,
+    kDocumentPositionContains = 0x08,
+    kDocumentPositionContainedBy = 0x10,
+    kDocumentPositionImplementationSpecific = 0x20,
+  };
+
   // Constant properties.
   static int ELEMENT_NODE;
   static int ATTRIBUTE_NODE;
@@ -103,6 +114,7 @@ class Node : public EventTarget {
   bool hasChildren() const { return firstChild(); }
   Node* cloneNode(bool deep, ExceptionState&) const;
   Node* cloneNode(ExceptionState&) const;
+  void normalize(ExceptionState& exception_state);
 
   void prepend(const std::vector<std::shared_ptr<QJSUnionDomStringNode>>& nodes, ExceptionState& exception_state);
   void prepend(ExceptionState& exception_state);
@@ -222,6 +234,7 @@ class Node : public EventTarget {
   bool IsNode() const override;
   bool IsDescendantOf(const Node*) const;
   bool contains(const Node*, ExceptionState&) const;
+  uint16_t compareDocumentPosition(const Node*) const;
   [[nodiscard]] bool ContainsIncludingHostElements(const Node&) const;
   Node* CommonAncestor(const Node&, ContainerNode* (*parent)(const Node&)) const;
 
@@ -18,4 +18,9 @@ void NodeList::Trace(webf::GCVisitor* visitor) const {
   }
 }
 
+const NodeListPublicMethods* NodeList::nodeListPublicMethods() {
+  static NodeListPublicMethods node_list_public_methods;
+  return &node_list_public_methods;
+}
+
 }  // namespace webf
\ No newline at end of file
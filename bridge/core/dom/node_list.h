@@ -38,6 +38,7 @@
 #include "bindings/qjs/script_wrappable.h"
 #include "core/html/collection_type.h"
 #include "core/html/html_collection.h"
+#include "plugin_api/node_list.h"
 
 namespace webf {
 
@@ -69,6 +70,8 @@ class NodeList : public ScriptWrappable {
 
   virtual Node* VirtualOwnerNode() const { return nullptr; }
 
+  const NodeListPublicMethods* nodeListPublicMethods();
+
   virtual void InvalidateCache();
   template <typename T>
   T* AddCache(ContainerNode& node, CollectionType collection_type) {
@@ -44,4 +44,9 @@ bool StaticNodeList::NamedPropertyQuery(const webf::AtomicString& key, webf::Exc
   return nodes_[number];
 }
 
+const HTMLCollectionPublicMethods* StaticNodeList::htmlCollectionPublicMethods() {
+  static HTMLCollectionPublicMethods html_collection_public_methods;
+  return &html_collection_public_methods;
+}
+
 }  // namespace webf
\ No newline at end of file
@@ -3,6 +3,7 @@
 
 #include "bindings/qjs/cppgc/gc_visitor.h"
 #include "node_list.h"
+#include "plugin_api/html_collection.h"
 
 namespace webf {
 
@@ -21,6 +22,9 @@ class StaticNodeList final : public NodeList {
   bool NamedPropertyQuery(const AtomicString& key, ExceptionState& exception_state) override;
   void NamedPropertyEnumerator(std::vector<AtomicString>& names, ExceptionState& exception_state) override;
 
+  // Exposes a list of elements as an HTMLCollection to the plugin API, such as the result of getElementsByClassName().
+  const HTMLCollectionPublicMethods* htmlCollectionPublicMethods();
+
  private:
   std::vector<Member<Node>> nodes_;
 };
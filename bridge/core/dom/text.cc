@@ -4,10 +4,29 @@
  */
 
 #include "text.h"
+#include <vector>
 #include "document.h"
+#include "node_traversal.h"
 
 namespace webf {
 
+namespace {
+
+// The offset and length are in UTF-16 code units, the same as the length of CharacterData.
+AtomicString Substring(JSContext* ctx, const AtomicString& string, int64_t offset, int64_t length) {
+  if (length == 0) {
+    return AtomicString::Empty();
+  }
+  if (string.Is8Bit()) {
+    const uint8_t* characters = string.Character8() + offset;
+    std::vector<uint16_t> buffer(characters, characters + length);
+    return AtomicString(ctx, buffer.data(), buffer.size());
+  }
+  return AtomicString(ctx, string.Character16() + offset, length);
+}
+
+}  // namespace
+
 Text* Text::Create(Document& document, const AtomicString& value) {
   return MakeGarbageCollected<Text>(document, value, ConstructionType::kCreateText);
 }
@@ -29,6 +48,65 @@ const TextNodePublicMethods* Text::textNodePublicMethods() {
   return &text_node_public_methods;
 }
 
+// https://dom.spec.whatwg.org/#concept-text-split
+Text* Text::splitText(int64_t offset, ExceptionState& exception_state) {
+  int64_t old_length = length();
+  if (offset < 0 || offset > old_length) {
+    exception_state.ThrowDOMException(
+        ctx(), "IndexSizeError",
+        "The offset " + std::to_string(offset) + " is larger than the Text node's length.");
+    return nullptr;
+  }
+
+  Text* new_text = Create(GetDocument(), Substring(ctx(), data(), offset, old_length - offset));
+  if (ContainerNode* parent = parentNode()) {
+    parent->insertBefore(new_text, nextSibling(), exception_state);
+    if (exception_state.HasException()) {
+      return nullptr;
+    }
+  }
+
+  setData(Substring(ctx(), data(), 0, offset), exception_state);
+  return new_text;
+}
+
+// https://dom.spec.whatwg.org/#dom-text-wholetext
+AtomicString Text::wholeText() const {
+  const Node* start = this;
+  while (start->previousSibling() && start->previousSibling()->IsTextNode()) {
+    start = start->previousSibling();
+  }
+
+  std::string whole_text;
+  for (const Node* node = start; node && node->IsTextNode(); node = node->nextSibling()) {
+    whole_text += To<Text>(node)->data().ToStdString(ctx());
+  }
+  return AtomicString(ctx(), whole_text);
+}
+
+Node* Text::MergeNextSiblingNodesIfPossible(ExceptionState& exception_state) {
+  if (length() == 0) {
+    Node* next_node = NodeTraversal::NextPostOrder(*this);
+    parentNode()->removeChild(this, exception_state);
+    return next_node;
+  }
+
+  std::string merged_data;
+  while (Node* next_sibling = nextSibling()) {
+    if (!next_sibling->IsTextNode())
+      break;
+    merged_data += To<Text>(next_sibling)->data().ToStdString(ctx());
+    parentNode()->removeChild(next_sibling, exception_state);
+    if (exception_state.HasException())
+      return nullptr;
+  }
+
+  if (!merged_data.empty()) {
+    setData(AtomicString(ctx(), data().ToStdString(ctx()) + merged_data), exception_state);
+  }
+  return NodeTraversal::NextPostOrder(*this);
+}
+
 std::string Text::nodeName() const {
   return "#text";
 }
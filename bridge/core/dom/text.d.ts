@@ -1,5 +1,7 @@
 import {CharacterData} from "./character_data";
 
 interface Text extends CharacterData {
+  readonly wholeText: string;
+  splitText(offset: int64): Text;
   new(value?: string): Text;
 }
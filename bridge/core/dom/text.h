@@ -29,6 +29,13 @@ class Text : public CharacterData {
   NodeType nodeType() const override;
   const TextNodePublicMethods* textNodePublicMethods();
 
+  Text* splitText(int64_t offset, ExceptionState& exception_state);
+  AtomicString wholeText() const;
+
+  // Used by Node::normalize(). Removes this node when it's empty, or appends the data of the following Text siblings
+  // and removes them. Returns the next node in post order.
+  Node* MergeNextSiblingNodesIfPossible(ExceptionState& exception_state);
+
  private:
   std::string nodeName() const override;
   Node* Clone(Document&, CloneChildrenFlag) const override;
@@ -0,0 +1,62 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "composition_event.h"
+#include "qjs_composition_event.h"
+
+namespace webf {
+
+CompositionEvent* CompositionEvent::Create(ExecutingContext* context,
+                                           const AtomicString& type,
+                                           ExceptionState& exception_state) {
+  return MakeGarbageCollected<CompositionEvent>(context, type, exception_state);
+}
+
+CompositionEvent* CompositionEvent::Create(ExecutingContext* context,
+                                           const AtomicString& type,
+                                           const std::shared_ptr<CompositionEventInit>& initializer,
+                                           ExceptionState& exception_state) {
+  return MakeGarbageCollected<CompositionEvent>(context, type, initializer, exception_state);
+}
+
+CompositionEvent::CompositionEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state)
+    : UIEvent(context, type, exception_state), data_(AtomicString::Empty()) {}
+
+CompositionEvent::CompositionEvent(ExecutingContext* context,
+                                   const AtomicString& type,
+                                   const std::shared_ptr<CompositionEventInit>& initializer,
+                                   ExceptionState& exception_state)
+    : UIEvent(context, type, initializer, exception_state),
+      data_(initializer->hasData() ? initializer->data() : AtomicString::Empty()) {}
+
+CompositionEvent::CompositionEvent(ExecutingContext* context,
+                                   const AtomicString& type,
+                                   NativeCompositionEvent* native_composition_event)
+    : UIEvent(context, type, &native_composition_event->native_event),
+#if ANDROID_32_BIT
+      data_(AtomicString(ctx(),
+                         std::unique_ptr<AutoFreeNativeString>(
+                             reinterpret_cast<AutoFreeNativeString*>(native_composition_event->data))))
+#else
+      data_(AtomicString(ctx(),
+                         std::unique_ptr<AutoFreeNativeString>(
+                             reinterpret_cast<AutoFreeNativeString*>(native_composition_event->data))))
+#endif
+{
+}
+
+const AtomicString& CompositionEvent::data() const {
+  return data_;
+}
+
+bool CompositionEvent::IsCompositionEvent() const {
+  return true;
+}
+
+const CompositionEventPublicMethods* CompositionEvent::compositionEventPublicMethods() {
+  static CompositionEventPublicMethods composition_event_public_methods;
+  return &composition_event_public_methods;
+}
+
+}  // namespace webf
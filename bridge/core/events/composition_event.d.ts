@@ -0,0 +1,8 @@
+import {UIEvent} from "./ui_event";
+import {CompositionEventInit} from "./composition_event_init";
+
+interface CompositionEvent extends UIEvent {
+  readonly data: string;
+  [key: string]: any;
+  new(type: string, init?: CompositionEventInit): CompositionEvent;
+}
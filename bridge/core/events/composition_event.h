@@ -0,0 +1,60 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef BRIDGE_CORE_EVENTS_COMPOSITION_EVENT_H_
+#define BRIDGE_CORE_EVENTS_COMPOSITION_EVENT_H_
+
+#include "bindings/qjs/dictionary_base.h"
+#include "bindings/qjs/source_location.h"
+#include "plugin_api/composition_event.h"
+#include "qjs_composition_event_init.h"
+#include "ui_event.h"
+
+namespace webf {
+
+struct NativeCompositionEvent;
+
+// https://w3c.github.io/uievents/#interface-compositionevent
+class CompositionEvent : public UIEvent {
+  DEFINE_WRAPPERTYPEINFO();
+
+ public:
+  using ImplType = CompositionEvent*;
+
+  static CompositionEvent* Create(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state);
+
+  static CompositionEvent* Create(ExecutingContext* context,
+                                  const AtomicString& type,
+                                  const std::shared_ptr<CompositionEventInit>& initializer,
+                                  ExceptionState& exception_state);
+
+  explicit CompositionEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state);
+
+  explicit CompositionEvent(ExecutingContext* context,
+                            const AtomicString& type,
+                            const std::shared_ptr<CompositionEventInit>& initializer,
+                            ExceptionState& exception_state);
+
+  explicit CompositionEvent(ExecutingContext* context,
+                            const AtomicString& type,
+                            NativeCompositionEvent* native_composition_event);
+
+  const AtomicString& data() const;
+
+  bool IsCompositionEvent() const override;
+
+  const CompositionEventPublicMethods* compositionEventPublicMethods();
+
+ private:
+  AtomicString data_;
+};
+
+template <>
+struct DowncastTraits<CompositionEvent> {
+  static bool AllowFrom(const Event& event) { return event.IsCompositionEvent(); }
+};
+
+}  // namespace webf
+
+#endif  // BRIDGE_CORE_EVENTS_COMPOSITION_EVENT_H_
@@ -0,0 +1,7 @@
+import {UIEventInit} from "./ui_event_init";
+
+// @ts-ignore
+@Dictionary()
+export interface CompositionEventInit extends UIEventInit {
+    data?: string;
+}
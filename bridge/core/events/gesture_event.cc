@@ -22,7 +22,15 @@ GestureEvent* GestureEvent::Create(ExecutingContext* context,
 }
 
 GestureEvent::GestureEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state)
-    : Event(context, type) {}
+    : Event(context, type),
+      state_(AtomicString::Empty()),
+      direction_(AtomicString::Empty()),
+      deltaX_(0.0),
+      deltaY_(0.0),
+      velocityX_(0.0),
+      velocityY_(0.0),
+      scale_(0.0),
+      rotation_(0.0) {}
 
 GestureEvent::GestureEvent(ExecutingContext* context,
                            const AtomicString& type,
@@ -33,6 +41,8 @@ GestureEvent::GestureEvent(ExecutingContext* context,
       direction_(initializer->hasDirection() ? initializer->direction() : AtomicString::Empty()),
       deltaX_(initializer->hasDeltaX() ? initializer->deltaX() : 0.0),
       deltaY_(initializer->hasDeltaY() ? initializer->deltaY() : 0.0),
+      velocityX_(initializer->hasVelocityX() ? initializer->velocityX() : 0.0),
+      velocityY_(initializer->hasVelocityY() ? initializer->velocityY() : 0.0),
       scale_(initializer->hasScale() ? initializer->scale() : 0.0),
       rotation_(initializer->hasRotation() ? initializer->rotation() : 0.0) {}
 
@@ -29,7 +29,8 @@ InputEvent::InputEvent(ExecutingContext* context,
                        ExceptionState& exception_state)
     : UIEvent(context, type, initializer, exception_state),
       input_type_(initializer->hasInputType() ? initializer->inputType() : AtomicString::Empty()),
-      data_(initializer->hasData() ? initializer->data() : AtomicString::Empty()) {}
+      data_(initializer->hasData() ? initializer->data() : AtomicString::Empty()),
+      is_composing_(initializer->hasIsComposing() && initializer->isComposing()) {}
 
 InputEvent::InputEvent(ExecutingContext* context, const AtomicString& type, NativeInputEvent* native_input_event)
     : UIEvent(context, type, &native_input_event->native_event),
@@ -39,16 +40,16 @@ InputEvent::InputEvent(ExecutingContext* context, const AtomicString& type, Nati
                                    reinterpret_cast<AutoFreeNativeString*>(native_input_event->inputType)))),
       data_(AtomicString(
           ctx(),
-          std::unique_ptr<AutoFreeNativeString>(reinterpret_cast<AutoFreeNativeString*>(native_input_event->data))))
+          std::unique_ptr<AutoFreeNativeString>(reinterpret_cast<AutoFreeNativeString*>(native_input_event->data)))),
 #else
       input_type_(AtomicString(ctx(),
                                std::unique_ptr<AutoFreeNativeString>(
                                    reinterpret_cast<AutoFreeNativeString*>(native_input_event->inputType)))),
       data_(AtomicString(
           ctx(),
-          std::unique_ptr<AutoFreeNativeString>(reinterpret_cast<AutoFreeNativeString*>(native_input_event->data))))
+          std::unique_ptr<AutoFreeNativeString>(reinterpret_cast<AutoFreeNativeString*>(native_input_event->data)))),
 #endif
-{
+      is_composing_(native_input_event->isComposing) {
 }
 
 const AtomicString& InputEvent::inputType() const {
@@ -59,6 +60,10 @@ const AtomicString& InputEvent::data() const {
   return data_;
 }
 
+bool InputEvent::isComposing() const {
+  return is_composing_;
+}
+
 bool InputEvent::IsInputEvent() const {
   return true;
 }
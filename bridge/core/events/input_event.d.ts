@@ -4,6 +4,7 @@ import {InputEventInit} from "./input_event_init";
 interface InputEvent extends UIEvent {
   readonly inputType: string;
   readonly data: string;
+  readonly isComposing: boolean;
   [key: string]: any;
   new(type: string, init?: InputEventInit): InputEvent;
 }
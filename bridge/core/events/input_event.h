@@ -40,6 +40,7 @@ class InputEvent : public UIEvent {
 
   const AtomicString& inputType() const;
   const AtomicString& data() const;
+  bool isComposing() const;
 
   bool IsInputEvent() const override;
 
@@ -48,6 +49,7 @@ class InputEvent : public UIEvent {
  private:
   AtomicString input_type_;
   AtomicString data_;
+  bool is_composing_{false};
 };
 
 template <>
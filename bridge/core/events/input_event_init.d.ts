@@ -5,4 +5,5 @@ import {UIEventInit} from "./ui_event_init";
 export interface InputEventInit extends UIEventInit {
     inputType?: string;
     data?: string;
+    isComposing?: boolean;
 }
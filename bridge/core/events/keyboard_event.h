@@ -8,6 +8,7 @@
 
 #include "bindings/qjs/dictionary_base.h"
 #include "bindings/qjs/source_location.h"
+#include "plugin_api/keyboard_event.h"
 #include "qjs_keyboard_event_init.h"
 #include "ui_event.h"
 
@@ -64,6 +65,8 @@ class KeyboardEvent : public UIEvent {
 
   bool getModifierState(const AtomicString& key_args, ExceptionState& exception_state);
 
+  const KeyboardEventPublicMethods* keyboardEventPublicMethods();
+
   bool IsKeyboardEvent() const override;
 
  private:
@@ -80,6 +83,11 @@ class KeyboardEvent : public UIEvent {
   bool shift_key_;
 };
 
+template <>
+struct DowncastTraits<KeyboardEvent> {
+  static bool AllowFrom(const Event& event) { return event.IsKeyboardEvent(); }
+};
+
 }  // namespace webf
 
 #endif  // BRIDGE_CORE_EVENTS_KEYBOARD_EVENT_H_
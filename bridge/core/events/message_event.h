@@ -45,6 +45,11 @@ class MessageEvent : public Event {
   AtomicString source_;
 };
 
+template <>
+struct DowncastTraits<MessageEvent> {
+  static bool AllowFrom(const Event& event) { return event.IsMessageEvent(); }
+};
+
 }  // namespace webf
 
 #endif  // BRIDGE_CORE_EVENTS_MESSAGE_EVENT_H_
@@ -42,24 +42,24 @@ MouseEvent::MouseEvent(ExecutingContext* context,
 
 MouseEvent::MouseEvent(ExecutingContext* context, const AtomicString& type, NativeMouseEvent* native_mouse_event)
     : UIEvent(context, type, &native_mouse_event->native_event),
-      //    alt_key_(native_mouse_event->altKey),
-      //    button_(native_mouse_event->button),
-      //    buttons_(native_mouse_event->buttons),
+      alt_key_(native_mouse_event->altKey),
+      button_(native_mouse_event->button),
+      buttons_(native_mouse_event->buttons),
       client_x_(native_mouse_event->clientX),
       client_y_(native_mouse_event->clientY),
-      //    ctrl_key_(native_mouse_event->ctrlKey),
-      //    meta_key_(native_mouse_event->metaKey),
-      //    movement_x_(native_mouse_event->movementX),
-      //    movement_y_(native_mouse_event->movementY),
+      ctrl_key_(native_mouse_event->ctrlKey),
+      meta_key_(native_mouse_event->metaKey),
+      movement_x_(native_mouse_event->movementX),
+      movement_y_(native_mouse_event->movementY),
       offset_x_(native_mouse_event->offsetX),
-      offset_y_(native_mouse_event->offsetY)
-//    page_x_(native_mouse_event->pageX),
-//    page_y_(native_mouse_event->pageY),
-//    screen_x_(native_mouse_event->screenX),
-//    screen_y_(native_mouse_event->screenY),
-//    shift_key_(native_mouse_event->shiftKey),
-//    x_(native_mouse_event->x),
-//    y_(native_mouse_event->y)
+      offset_y_(native_mouse_event->offsetY),
+      page_x_(native_mouse_event->pageX),
+      page_y_(native_mouse_event->pageY),
+      screen_x_(native_mouse_event->screenX),
+      screen_y_(native_mouse_event->screenY),
+      shift_key_(native_mouse_event->shiftKey),
+      x_(native_mouse_event->x),
+      y_(native_mouse_event->y)
 {}
 
 bool MouseEvent::altKey() const {
@@ -121,6 +121,38 @@ EventTarget* MouseEvent::relatedTarget() const {
   return related_target_;
 }
 
+void MouseEvent::initMouseEvent(const AtomicString& type,
+                                bool bubbles,
+                                bool cancelable,
+                                double client_x,
+                                double client_y,
+                                bool ctrl_key,
+                                bool alt_key,
+                                bool shift_key,
+                                bool meta_key,
+                                double button,
+                                double buttons,
+                                ExceptionState& exception_state) {
+  if (IsBeingDispatched()) {
+    return;
+  }
+
+  initEvent(type, bubbles, cancelable, exception_state);
+
+  client_x_ = client_x;
+  client_y_ = client_y;
+  page_x_ = client_x;
+  page_y_ = client_y;
+  x_ = client_x;
+  y_ = client_y;
+  ctrl_key_ = ctrl_key;
+  alt_key_ = alt_key;
+  shift_key_ = shift_key;
+  meta_key_ = meta_key;
+  button_ = button;
+  buttons_ = buttons;
+}
+
 bool MouseEvent::IsMouseEvent() const {
   return true;
 }
@@ -55,6 +55,21 @@ class MouseEvent : public UIEvent {
   double y() const;
   EventTarget* relatedTarget() const;
 
+  // Re-initializes the event which is created without an initializer, used by the plugin API
+  // to create synthetic mouse events.
+  void initMouseEvent(const AtomicString& type,
+                      bool bubbles,
+                      bool cancelable,
+                      double client_x,
+                      double client_y,
+                      bool ctrl_key,
+                      bool alt_key,
+                      bool shift_key,
+                      bool meta_key,
+                      double button,
+                      double buttons,
+                      ExceptionState& exception_state);
+
   void Trace(GCVisitor* visitor) const override;
 
   const MouseEventPublicMethods* mouseEventPublicMethods();
@@ -62,24 +77,24 @@ class MouseEvent : public UIEvent {
   bool IsMouseEvent() const override;
 
  private:
-  bool alt_key_;
-  double button_;
-  double buttons_;
-  double client_x_;
-  double client_y_;
-  bool ctrl_key_;
-  bool meta_key_;
-  double movement_x_;
-  double movement_y_;
-  double offset_x_;
-  double offset_y_;
-  double page_x_;
-  double page_y_;
-  double screen_x_;
-  double screen_y_;
-  bool shift_key_;
-  double x_;
-  double y_;
+  bool alt_key_{false};
+  double button_{0.0};
+  double buttons_{0.0};
+  double client_x_{0.0};
+  double client_y_{0.0};
+  bool ctrl_key_{false};
+  bool meta_key_{false};
+  double movement_x_{0.0};
+  double movement_y_{0.0};
+  double offset_x_{0.0};
+  double offset_y_{0.0};
+  double page_x_{0.0};
+  double page_y_{0.0};
+  double screen_x_{0.0};
+  double screen_y_{0.0};
+  bool shift_key_{false};
+  double x_{0.0};
+  double y_{0.0};
   Member<EventTarget> related_target_;
 };
 
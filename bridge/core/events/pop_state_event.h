@@ -6,6 +6,7 @@
 #define WEBF_CORE_EVENTS_POP_STATE_EVENT_H_
 
 #include "core/dom/events/event.h"
+#include "plugin_api/pop_state_event.h"
 #include "qjs_pop_state_event_init.h"
 
 namespace webf {
@@ -38,6 +39,8 @@ class PopStateEvent : public Event {
 
   bool IsPopstateEvent() const override;
 
+  const PopStateEventPublicMethods* popStateEventPublicMethods();
+
   void Trace(GCVisitor* visitor) const override;
 
  private:
@@ -0,0 +1,105 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "storage_event.h"
+#include "bindings/qjs/cppgc/gc_visitor.h"
+#include "core/executing_context.h"
+#include "qjs_storage_event.h"
+
+namespace webf {
+
+namespace {
+
+// Null strings are sent as null pointers.
+AtomicString NullableNativeString(JSContext* ctx, AutoFreeNativeString* native_string) {
+  if (native_string == nullptr) {
+    return AtomicString::Null();
+  }
+  return AtomicString(ctx, std::unique_ptr<AutoFreeNativeString>(native_string));
+}
+
+}  // namespace
+
+StorageEvent* StorageEvent::Create(ExecutingContext* context,
+                                   const AtomicString& type,
+                                   ExceptionState& exception_state) {
+  return MakeGarbageCollected<StorageEvent>(context, type, exception_state);
+}
+
+StorageEvent* StorageEvent::Create(ExecutingContext* context,
+                                   const AtomicString& type,
+                                   const std::shared_ptr<StorageEventInit>& initializer,
+                                   ExceptionState& exception_state) {
+  return MakeGarbageCollected<StorageEvent>(context, type, initializer, exception_state);
+}
+
+StorageEvent::StorageEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state)
+    : Event(context, type), url_(AtomicString::Empty()), storage_area_(ScriptValue::Empty(ctx())) {}
+
+StorageEvent::StorageEvent(ExecutingContext* context,
+                           const AtomicString& type,
+                           const std::shared_ptr<StorageEventInit>& initializer,
+                           ExceptionState& exception_state)
+    : Event(context, type, initializer),
+      key_(initializer->hasKey() ? initializer->key() : AtomicString::Null()),
+      old_value_(initializer->hasOldValue() ? initializer->oldValue() : AtomicString::Null()),
+      new_value_(initializer->hasNewValue() ? initializer->newValue() : AtomicString::Null()),
+      url_(initializer->hasUrl() ? initializer->url() : AtomicString::Empty()),
+      storage_area_(initializer->hasStorageArea() ? initializer->storageArea() : ScriptValue::Empty(ctx())) {}
+
+StorageEvent::StorageEvent(ExecutingContext* context,
+                           const AtomicString& type,
+                           NativeStorageEvent* native_storage_event)
+    : Event(context, type, &native_storage_event->native_event),
+      key_(NullableNativeString(ctx(), reinterpret_cast<AutoFreeNativeString*>(native_storage_event->key))),
+      old_value_(NullableNativeString(ctx(), reinterpret_cast<AutoFreeNativeString*>(native_storage_event->oldValue))),
+      new_value_(NullableNativeString(ctx(), reinterpret_cast<AutoFreeNativeString*>(native_storage_event->newValue))),
+      url_(NullableNativeString(ctx(), reinterpret_cast<AutoFreeNativeString*>(native_storage_event->url))),
+      storage_area_(ScriptValue::Empty(ctx())) {
+  AtomicString storage_area_name =
+      NullableNativeString(ctx(), reinterpret_cast<AutoFreeNativeString*>(native_storage_event->storageArea));
+  if (storage_area_name.IsNull()) {
+    return;
+  }
+  JSValue storage_area =
+      JS_GetPropertyStr(ctx(), context->Global(), storage_area_name.ToStdString(ctx()).c_str());
+  storage_area_ = ScriptValue(ctx(), storage_area);
+  JS_FreeValue(ctx(), storage_area);
+}
+
+const AtomicString& StorageEvent::key() const {
+  return key_;
+}
+
+const AtomicString& StorageEvent::oldValue() const {
+  return old_value_;
+}
+
+const AtomicString& StorageEvent::newValue() const {
+  return new_value_;
+}
+
+const AtomicString& StorageEvent::url() const {
+  return url_;
+}
+
+ScriptValue StorageEvent::storageArea() const {
+  return storage_area_;
+}
+
+bool StorageEvent::IsStorageEvent() const {
+  return true;
+}
+
+const StorageEventPublicMethods* StorageEvent::storageEventPublicMethods() {
+  static StorageEventPublicMethods storage_event_public_methods;
+  return &storage_event_public_methods;
+}
+
+void StorageEvent::Trace(GCVisitor* visitor) const {
+  storage_area_.Trace(visitor);
+  Event::Trace(visitor);
+}
+
+}  // namespace webf
@@ -0,0 +1,12 @@
+import {Event} from "../dom/events/event";
+import {StorageEventInit} from "./storage_event_init";
+
+interface StorageEvent extends Event {
+  readonly key: string | null;
+  readonly oldValue: string | null;
+  readonly newValue: string | null;
+  readonly url: string;
+  readonly storageArea: any;
+  [key: string]: any;
+  new(type: string, init?: StorageEventInit): StorageEvent;
+}
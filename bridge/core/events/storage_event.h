@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_EVENTS_STORAGE_EVENT_H_
+#define WEBF_CORE_EVENTS_STORAGE_EVENT_H_
+
+#include "core/dom/events/event.h"
+#include "plugin_api/storage_event.h"
+#include "qjs_storage_event_init.h"
+
+namespace webf {
+
+struct NativeStorageEvent;
+
+// https://html.spec.whatwg.org/multipage/webstorage.html#the-storageevent-interface
+class StorageEvent : public Event {
+  DEFINE_WRAPPERTYPEINFO();
+
+ public:
+  using ImplType = StorageEvent*;
+
+  static StorageEvent* Create(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state);
+
+  static StorageEvent* Create(ExecutingContext* context,
+                              const AtomicString& type,
+                              const std::shared_ptr<StorageEventInit>& initializer,
+                              ExceptionState& exception_state);
+
+  explicit StorageEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state);
+
+  explicit StorageEvent(ExecutingContext* context,
+                        const AtomicString& type,
+                        const std::shared_ptr<StorageEventInit>& initializer,
+                        ExceptionState& exception_state);
+
+  // Created by Dart when another context of the same origin changed the storage.
+  // The storage area is the name of the global storage object, such as `localStorage`.
+  explicit StorageEvent(ExecutingContext* context, const AtomicString& type, NativeStorageEvent* native_storage_event);
+
+  const AtomicString& key() const;
+  const AtomicString& oldValue() const;
+  const AtomicString& newValue() const;
+  const AtomicString& url() const;
+  ScriptValue storageArea() const;
+
+  bool IsStorageEvent() const override;
+
+  const StorageEventPublicMethods* storageEventPublicMethods();
+
+  void Trace(GCVisitor* visitor) const override;
+
+ private:
+  AtomicString key_;
+  AtomicString old_value_;
+  AtomicString new_value_;
+  AtomicString url_;
+  ScriptValue storage_area_;
+};
+
+template <>
+struct DowncastTraits<StorageEvent> {
+  static bool AllowFrom(const Event& event) { return event.IsStorageEvent(); }
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_EVENTS_STORAGE_EVENT_H_
@@ -0,0 +1,11 @@
+import { EventInit } from "../dom/events/event_init";
+
+// @ts-ignore
+@Dictionary()
+export interface StorageEventInit extends EventInit {
+    key?: string | null;
+    oldValue?: string | null;
+    newValue?: string | null;
+    url?: string;
+    storageArea?: any;
+}
@@ -22,6 +22,10 @@ TouchEvent* TouchEvent::Create(ExecutingContext* context,
 
 TouchEvent::TouchEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state)
     : UIEvent(context, type, exception_state),
+      alt_key_(false),
+      ctrl_key_(false),
+      meta_key_(false),
+      shift_key_(false),
       changed_touches_(TouchList::Create(context)),
       touches_(TouchList::Create(context)),
       target_touches_(TouchList::Create(context)) {}
@@ -102,4 +106,9 @@ bool TouchEvent::IsTouchEvent() const {
   return true;
 }
 
+const TouchEventPublicMethods* TouchEvent::touchEventPublicMethods() {
+  static TouchEventPublicMethods touch_event_public_methods;
+  return &touch_event_public_methods;
+}
+
 }  // namespace webf
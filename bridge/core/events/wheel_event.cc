@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "wheel_event.h"
+#include "qjs_wheel_event.h"
+
+namespace webf {
+
+double WheelEvent::DOM_DELTA_PIXEL = DeltaModeCode::kDomDeltaPixel;
+double WheelEvent::DOM_DELTA_LINE = DeltaModeCode::kDomDeltaLine;
+double WheelEvent::DOM_DELTA_PAGE = DeltaModeCode::kDomDeltaPage;
+
+WheelEvent* WheelEvent::Create(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state) {
+  return MakeGarbageCollected<WheelEvent>(context, type, exception_state);
+}
+
+WheelEvent* WheelEvent::Create(ExecutingContext* context,
+                               const AtomicString& type,
+                               const std::shared_ptr<WheelEventInit>& initializer,
+                               ExceptionState& exception_state) {
+  return MakeGarbageCollected<WheelEvent>(context, type, initializer, exception_state);
+}
+
+WheelEvent::WheelEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state)
+    : MouseEvent(context, type, exception_state) {}
+
+WheelEvent::WheelEvent(ExecutingContext* context,
+                       const AtomicString& type,
+                       const std::shared_ptr<WheelEventInit>& initializer,
+                       ExceptionState& exception_state)
+    : MouseEvent(context, type, initializer, exception_state),
+      delta_x_(initializer->hasDeltaX() ? initializer->deltaX() : 0.0),
+      delta_y_(initializer->hasDeltaY() ? initializer->deltaY() : 0.0),
+      delta_z_(initializer->hasDeltaZ() ? initializer->deltaZ() : 0.0),
+      delta_mode_(initializer->hasDeltaMode() ? initializer->deltaMode() : DeltaModeCode::kDomDeltaPixel) {}
+
+WheelEvent::WheelEvent(ExecutingContext* context, const AtomicString& type, NativeWheelEvent* native_wheel_event)
+    : MouseEvent(context, type, &native_wheel_event->native_event),
+      delta_x_(native_wheel_event->deltaX),
+      delta_y_(native_wheel_event->deltaY),
+      delta_z_(native_wheel_event->deltaZ),
+      delta_mode_(native_wheel_event->deltaMode) {}
+
+double WheelEvent::deltaX() const {
+  return delta_x_;
+}
+
+double WheelEvent::deltaY() const {
+  return delta_y_;
+}
+
+double WheelEvent::deltaZ() const {
+  return delta_z_;
+}
+
+double WheelEvent::deltaMode() const {
+  return delta_mode_;
+}
+
+bool WheelEvent::IsWheelEvent() const {
+  return true;
+}
+
+const WheelEventPublicMethods* WheelEvent::wheelEventPublicMethods() {
+  static WheelEventPublicMethods wheel_event_public_methods;
+  return &wheel_event_public_methods;
+}
+
+}  // namespace webf
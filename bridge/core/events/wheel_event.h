@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_EVENTS_WHEEL_EVENT_H_
+#define WEBF_CORE_EVENTS_WHEEL_EVENT_H_
+
+#include "mouse_event.h"
+#include "plugin_api/wheel_event.h"
+#include "qjs_wheel_event_init.h"
+
+namespace webf {
+
+struct NativeWheelEvent;
+
+class WheelEvent : public MouseEvent {
+  DEFINE_WRAPPERTYPEINFO();
+
+ public:
+  enum DeltaModeCode {
+    kDomDeltaPixel = 0x00,
+    kDomDeltaLine = 0x01,
+    kDomDeltaPage = 0x02,
+  };
+  using ImplType = WheelEvent*;
+
+  static double DOM_DELTA_PIXEL;
+  static double DOM_DELTA_LINE;
+  static double DOM_DELTA_PAGE;
+
+  static WheelEvent* Create(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state);
+
+  static WheelEvent* Create(ExecutingContext* context,
+                            const AtomicString& type,
+                            const std::shared_ptr<WheelEventInit>& initializer,
+                            ExceptionState& exception_state);
+
+  explicit WheelEvent(ExecutingContext* context, const AtomicString& type, ExceptionState& exception_state);
+
+  explicit WheelEvent(ExecutingContext* context,
+                      const AtomicString& type,
+                      const std::shared_ptr<WheelEventInit>& initializer,
+                      ExceptionState& exception_state);
+
+  explicit WheelEvent(ExecutingContext* context, const AtomicString& type, NativeWheelEvent* native_wheel_event);
+
+  double deltaX() const;
+  double deltaY() const;
+  double deltaZ() const;
+  double deltaMode() const;
+
+  bool IsWheelEvent() const override;
+
+  const WheelEventPublicMethods* wheelEventPublicMethods();
+
+ private:
+  double delta_x_{0.0};
+  double delta_y_{0.0};
+  double delta_z_{0.0};
+  double delta_mode_{kDomDeltaPixel};
+};
+
+template <>
+struct DowncastTraits<WheelEvent> {
+  static bool AllowFrom(const Event& event) { return event.IsWheelEvent(); }
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_EVENTS_WHEEL_EVENT_H_
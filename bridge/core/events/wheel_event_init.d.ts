@@ -0,0 +1,10 @@
+import {MouseEventInit} from "./mouse_event_init";
+
+// @ts-ignore
+@Dictionary()
+export interface WheelEventInit extends MouseEventInit {
+    deltaX?: number;
+    deltaY?: number;
+    deltaZ?: number;
+    deltaMode?: number;
+}
\ No newline at end of file
@@ -55,6 +55,7 @@ ExecutingContext::ExecutingContext(DartIsolateContext* dart_isolate_context,
     running_context_list = context_id;
 
   time_origin_ = std::chrono::system_clock::now();
+  steady_time_origin_ = std::chrono::steady_clock::now();
 
   JSContext* ctx = script_state_.ctx();
   global_object_ = JS_GetGlobalObject(script_state_.ctx());
@@ -356,6 +357,9 @@ void ExecutingContext::DrainMicrotasks() {
 
   DrainPendingPromiseJobs();
 
+  // Promise jobs may have settled values that pending Rust futures are waiting for.
+  RunRustFutureTasks();
+
   dart_isolate_context_->profiler()->FinishTrackSteps();
 
   ui_command_buffer_.AddCommand(UICommand::kFinishRecordingCommand, nullptr, nullptr, nullptr);
@@ -405,8 +409,16 @@ void ExecutingContext::RemoveRustFutureTask(const std::shared_ptr<WebFNativeFunc
 }
 
 void ExecutingContext::RunRustFutureTasks() {
+  // Polling a future may call back into the engine and reach here again. The outer run picks up
+  // any progress, so skip the nested one instead of polling the same futures re-entrantly.
+  if (in_run_rust_future_tasks_)
+    return;
+  in_run_rust_future_tasks_ = true;
+
   for (auto& meta_data : native_library_meta_data_contaner_) {
-    for (auto& callback : meta_data->callbacks) {
+    // Copy the list, futures spawned while running append new tasks to it.
+    std::vector<std::shared_ptr<WebFNativeFunction>> callbacks = meta_data->callbacks;
+    for (auto& callback : callbacks) {
       dart_isolate_context_->profiler()->StartTrackAsyncEvaluation();
       callback->Invoke(this, 0, nullptr);
       dart_isolate_context_->profiler()->FinishTrackAsyncEvaluation();
@@ -425,6 +437,8 @@ void ExecutingContext::RunRustFutureTasks() {
       meta_data->load_context = nullptr;
     }
   }
+
+  in_run_rust_future_tasks_ = false;
 }
 
 void ExecutingContext::RegisterNativeLibraryMetaData(NativeLibrartMetaData* meta_data) {
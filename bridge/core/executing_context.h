@@ -151,6 +151,10 @@ class ExecutingContext {
   FORCE_INLINE ExecutingContextWebFMethods* publicMethodPtr() const { return public_method_ptr_.get(); }
   FORCE_INLINE bool isDedicated() { return is_dedicated_; }
   FORCE_INLINE std::chrono::time_point<std::chrono::system_clock> timeOrigin() const { return time_origin_; }
+  // The same moment as timeOrigin() on a monotonic clock, durations since the time origin are measured from here.
+  FORCE_INLINE std::chrono::time_point<std::chrono::steady_clock> steadyTimeOrigin() const {
+    return steady_time_origin_;
+  }
 
   // Force dart side to execute the pending ui commands.
   void FlushUICommand(const BindingObject* self, uint32_t reason);
@@ -179,6 +183,7 @@ class ExecutingContext {
 
  private:
   std::chrono::time_point<std::chrono::system_clock> time_origin_;
+  std::chrono::time_point<std::chrono::steady_clock> steady_time_origin_;
   int32_t unique_id_;
 
   void InstallDocument();
@@ -223,6 +228,7 @@ class ExecutingContext {
   ModuleContextCoordinator module_contexts_;
   ExecutionContextData context_data_{this};
   bool in_dispatch_error_event_{false};
+  bool in_run_rust_future_tasks_{false};
   RejectedPromises rejected_promises_;
   MemberMutationScope* active_mutation_scope{nullptr};
   std::unordered_set<ScriptWrappable*> active_wrappers_;
@@ -26,4 +26,22 @@ void ModuleListenerContainer::Clear() {
   listeners_.clear();
 }
 
+void ModuleListenerContainer::AddModuleEventTarget(const AtomicString& name,
+                                                   const AtomicString& id,
+                                                   EventTarget* event_target) {
+  event_targets_[name][id] = event_target;
+}
+
+void ModuleListenerContainer::RemoveModuleEventTarget(const AtomicString& name, const AtomicString& id) {
+  if (event_targets_.count(name) == 0)
+    return;
+  event_targets_[name].erase(id);
+}
+
+EventTarget* ModuleListenerContainer::ModuleEventTarget(const AtomicString& name, const AtomicString& id) {
+  if (event_targets_.count(name) == 0 || event_targets_[name].count(id) == 0)
+    return nullptr;
+  return event_targets_[name][id];
+}
+
 }  // namespace webf
@@ -10,6 +10,8 @@
 
 namespace webf {
 
+class EventTarget;
+
 class ModuleListenerContainer final {
  public:
   void AddModuleListener(const AtomicString& name, const std::shared_ptr<ModuleListener>& listener);
@@ -17,8 +19,16 @@ class ModuleListenerContainer final {
   std::shared_ptr<ModuleListener> listener(const AtomicString& name);
   void Clear();
 
+  // Module events whose data equals to `id` are dispatched to the event target instead of the module listener.
+  // The event target is not traced, owners must remove it before it is released.
+  void AddModuleEventTarget(const AtomicString& name, const AtomicString& id, EventTarget* event_target);
+  void RemoveModuleEventTarget(const AtomicString& name, const AtomicString& id);
+  EventTarget* ModuleEventTarget(const AtomicString& name, const AtomicString& id);
+
  private:
+  using ModuleEventTargetMap = std::unordered_map<AtomicString, EventTarget*, AtomicString::KeyHasher>;
   std::unordered_map<AtomicString, std::shared_ptr<ModuleListener>, AtomicString::KeyHasher> listeners_;
+  std::unordered_map<AtomicString, ModuleEventTargetMap, AtomicString::KeyHasher> event_targets_;
   friend ModuleListener;
 };
 
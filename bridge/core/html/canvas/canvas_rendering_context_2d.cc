@@ -7,6 +7,7 @@
 #include "canvas_gradient.h"
 #include "core/html/canvas/html_canvas_element.h"
 #include "core/html/html_image_element.h"
+#include "foundation/dart_readable.h"
 #include "foundation/native_value_converter.h"
 
 namespace webf {
@@ -15,6 +16,11 @@ bool CanvasRenderingContext2D::IsCanvas2d() const {
   return true;
 }
 
+const CanvasRenderingContext2DPublicMethods* CanvasRenderingContext2D::canvasRenderingContext2DPublicMethods() {
+  static CanvasRenderingContext2DPublicMethods canvas_rendering_context_2d_public_methods;
+  return &canvas_rendering_context_2d_public_methods;
+}
+
 CanvasRenderingContext2D::CanvasRenderingContext2D(ExecutingContext* context,
                                                    NativeBindingObject* native_binding_object)
     : CanvasRenderingContext(context->ctx(), native_binding_object) {}
@@ -192,6 +198,75 @@ void CanvasRenderingContext2D::fill(std::shared_ptr<const QJSUnionPath2DDomStrin
                       FlushUICommandReason::kDependentsOnElement, exception_state);
 }
 
+namespace {
+
+struct ImageDataNativeFunctionContext {
+  ExecutingContext* context;
+  std::shared_ptr<WebFNativeFunction> function;
+};
+
+void HandleImageDataFromDart(ImageDataNativeFunctionContext* image_data_context,
+                             NativeValue* native_value,
+                             double context_id,
+                             const char* errmsg) {
+  ExecutingContext* context = image_data_context->context;
+  if (context->IsContextValid() && context->contextId() == context_id) {
+    if (native_value != nullptr) {
+      NativeValue params[] = {Native_NewNull(), *native_value};
+      image_data_context->function->Invoke(context, 2, params);
+    } else {
+      NativeValue error_object = Native_NewCString(errmsg);
+      image_data_context->function->Invoke(context, 1, &error_object);
+    }
+    context->RunRustFutureTasks();
+  }
+
+  // The pixels and the error message are allocated by the Dart side and copied by the callback.
+  if (native_value != nullptr) {
+    dart_free(native_value->u.ptr);
+    dart_free(native_value);
+  }
+  if (errmsg != nullptr) {
+    dart_free(const_cast<char*>(errmsg));
+  }
+  delete image_data_context;
+}
+
+void HandleImageDataFromDartWrapper(void* ptr, NativeValue* native_value, double context_id, const char* errmsg) {
+  auto* image_data_context = static_cast<ImageDataNativeFunctionContext*>(ptr);
+  ExecutingContext* context = image_data_context->context;
+  context->dartIsolateContext()->dispatcher()->PostToJs(context->isDedicated(), context_id, HandleImageDataFromDart,
+                                                        image_data_context, native_value, context_id, errmsg);
+}
+
+}  // namespace
+
+void CanvasRenderingContext2D::getImageData(int64_t sx,
+                                            int64_t sy,
+                                            int64_t sw,
+                                            int64_t sh,
+                                            const std::shared_ptr<WebFNativeFunction>& callback,
+                                            ExceptionState& exception_state) {
+  auto* image_data_context = new ImageDataNativeFunctionContext{GetExecutingContext(), callback};
+
+  NativeValue arguments[] = {
+      NativeValueConverter<NativeTypeString>::ToNativeValue(ctx(), binding_call_methods::k__getImageData__),
+      NativeValueConverter<NativeTypeDouble>::ToNativeValue(GetExecutingContext()->contextId()),
+      NativeValueConverter<NativeTypePointer<ImageDataNativeFunctionContext>>::ToNativeValue(image_data_context),
+      NativeValueConverter<NativeTypePointer<void>>::ToNativeValue(
+          reinterpret_cast<void*>(HandleImageDataFromDartWrapper)),
+      NativeValueConverter<NativeTypeInt64>::ToNativeValue(sx),
+      NativeValueConverter<NativeTypeInt64>::ToNativeValue(sy),
+      NativeValueConverter<NativeTypeInt64>::ToNativeValue(sw),
+      NativeValueConverter<NativeTypeInt64>::ToNativeValue(sh)};
+  InvokeBindingMethod(BindingMethodCallOperations::kAsyncAnonymousFunction, sizeof(arguments) / sizeof(NativeValue),
+                      arguments, FlushUICommandReason::kDependentsOnElement, exception_state);
+
+  if (exception_state.HasException()) {
+    delete image_data_context;
+  }
+}
+
 void CanvasRenderingContext2D::Trace(GCVisitor* visitor) const {
   if (fill_style_ != nullptr)
     fill_style_->Trace(visitor);
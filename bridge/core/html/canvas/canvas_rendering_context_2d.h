@@ -8,7 +8,9 @@
 #include "canvas_gradient.h"
 #include "canvas_pattern.h"
 #include "canvas_rendering_context.h"
+#include "core/native/native_function.h"
 #include "path_2d.h"
+#include "plugin_api/canvas_rendering_context_2d.h"
 #include "qjs_union_dom_stringcanvas_gradient.h"
 #include "qjs_unionhtml_image_elementhtml_canvas_element.h"
 #include "qjs_unionpath_2_d_dom_string.h"
@@ -46,6 +48,8 @@ class CanvasRenderingContext2D : public CanvasRenderingContext {
   void setFillStyle(const std::shared_ptr<QJSUnionDomStringCanvasGradient>& style, ExceptionState& exception_state);
   bool IsCanvas2d() const override;
 
+  const CanvasRenderingContext2DPublicMethods* canvasRenderingContext2DPublicMethods();
+
   void fill(ExceptionState& exception_state);
   void fill(std::shared_ptr<const QJSUnionPath2DDomString> pathOrPattern, ExceptionState& exception_state);
   void fill(std::shared_ptr<const QJSUnionPath2DDomString> pathOrPattern,
@@ -62,6 +66,15 @@ class CanvasRenderingContext2D : public CanvasRenderingContext {
                  std::shared_ptr<const QJSUnionDoubleSequenceDouble> radii,
                  ExceptionState& exception_state);
 
+  // Reads back the RGBA pixels of the rectangle of the canvas for the native plugins. The callback is called with the
+  // pixels once the drawing commands sent before are painted.
+  void getImageData(int64_t sx,
+                    int64_t sy,
+                    int64_t sw,
+                    int64_t sh,
+                    const std::shared_ptr<WebFNativeFunction>& callback,
+                    ExceptionState& exception_state);
+
   void Trace(GCVisitor* visitor) const override;
 
  private:
@@ -113,6 +113,11 @@ EventTarget* Touch::target() const {
   return target_;
 }
 
+const TouchPublicMethods* Touch::touchPublicMethods() {
+  static TouchPublicMethods touch_public_methods;
+  return &touch_public_methods;
+}
+
 void Touch::Trace(GCVisitor* visitor) const {
   visitor->TraceMember(target_);
 }
@@ -8,6 +8,7 @@
 #include "bindings/qjs/cppgc/member.h"
 #include "bindings/qjs/script_wrappable.h"
 #include "core/dom/events/event_target.h"
+#include "plugin_api/touch.h"
 #include "qjs_touch_init.h"
 
 namespace webf {
@@ -61,6 +62,8 @@ class Touch : public ScriptWrappable {
   double screenY() const;
   EventTarget* target() const;
 
+  const TouchPublicMethods* touchPublicMethods();
+
   void Trace(GCVisitor* visitor) const override;
 
  private:
@@ -6,6 +6,7 @@
 #define BRIDGE_CORE_INPUT_TOUCH_LIST_H_
 
 #include "bindings/qjs/atomic_string.h"
+#include "plugin_api/touch_list.h"
 #include "touch.h"
 
 namespace webf {
@@ -38,6 +39,8 @@ class TouchList : public ScriptWrappable {
 
   void Trace(GCVisitor* visitor) const override;
 
+  const TouchListPublicMethods* touchListPublicMethods();
+
  private:
   std::vector<Member<Touch>> values_;
 };
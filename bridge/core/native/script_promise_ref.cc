@@ -0,0 +1,14 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#include "core/native/script_promise_ref.h"
+
+namespace webf {
+
+ScriptPromiseRefPublicMethods* ScriptPromiseRef::publicMethods() {
+  static ScriptPromiseRefPublicMethods public_methods;
+  return &public_methods;
+}
+
+}  // namespace webf
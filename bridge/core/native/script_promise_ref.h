@@ -0,0 +1,23 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_NATIVE_SCRIPT_PROMISE_REF_H_
+#define WEBF_CORE_NATIVE_SCRIPT_PROMISE_REF_H_
+
+#include "bindings/qjs/script_promise.h"
+#include "core/executing_context.h"
+#include "plugin_api/script_promise_ref.h"
+
+namespace webf {
+
+struct ScriptPromiseRef {
+  static ScriptPromiseRefPublicMethods* publicMethods();
+
+  ExecutingContext* context;
+  ScriptPromise script_promise;
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_NATIVE_SCRIPT_PROMISE_REF_H_
@@ -82,6 +82,22 @@ NativeValue* WebFPage::invokeModuleEvent(SharedNativeString* native_module_name,
   ScriptValue extraObject = ScriptValue(ctx, const_cast<const NativeValue&>(*extra));
   AtomicString module_name = AtomicString(
       ctx, std::unique_ptr<AutoFreeNativeString>(reinterpret_cast<AutoFreeNativeString*>(native_module_name)));
+
+  // Events of module instances created by native plugins, such as WebSocket, go to their own event targets.
+  if (event != nullptr && extraObject.IsString()) {
+    EventTarget* module_event_target =
+        context_->ModuleListeners()->ModuleEventTarget(module_name, extraObject.ToString(ctx));
+    if (module_event_target != nullptr) {
+      ExceptionState exception_state;
+      module_event_target->dispatchEvent(event, exception_state);
+      if (exception_state.HasException()) {
+        context_->HandleException(exception_state);
+      }
+      context_->RunRustFutureTasks();
+      return nullptr;
+    }
+  }
+
   auto listener = context_->ModuleListeners()->listener(module_name);
 
   if (listener == nullptr) {
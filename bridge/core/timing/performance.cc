@@ -21,12 +21,19 @@ using namespace std::chrono;
 Performance::Performance(ExecutingContext* context) : ScriptWrappable(context->ctx()) {}
 
 int64_t Performance::now(ExceptionState& exception_state) const {
-  auto now = std::chrono::system_clock::now();
-  auto duration = std::chrono::duration_cast<std::chrono::microseconds>(now - GetExecutingContext()->timeOrigin());
+  auto now = std::chrono::steady_clock::now();
+  auto duration =
+      std::chrono::duration_cast<std::chrono::microseconds>(now - GetExecutingContext()->steadyTimeOrigin());
   auto reducedDuration = std::floor(duration / 1000us) * 1000us;
   return std::chrono::duration_cast<std::chrono::milliseconds>(reducedDuration).count();
 }
 
+double Performance::HighResolutionNow() const {
+  auto duration = std::chrono::duration_cast<std::chrono::microseconds>(std::chrono::steady_clock::now() -
+                                                                        GetExecutingContext()->steadyTimeOrigin());
+  return static_cast<double>(duration.count()) / 1000;
+}
+
 int64_t Performance::timeOrigin() const {
   return std::chrono::duration_cast<std::chrono::milliseconds>(GetExecutingContext()->timeOrigin().time_since_epoch())
       .count();
@@ -163,6 +170,11 @@ void Performance::Trace(GCVisitor* visitor) const {
   }
 }
 
+const PerformancePublicMethods* Performance::performancePublicMethods() {
+  static PerformancePublicMethods performance_public_methods;
+  return &performance_public_methods;
+}
+
 void Performance::measure(const AtomicString& measure_name, ExceptionState& exception_state) {
   measure(measure_name, AtomicString::Empty(), AtomicString::Empty(), exception_state);
 }
@@ -205,7 +217,8 @@ void Performance::measure(const AtomicString& measure_name,
                           const AtomicString& end_mark,
                           ExceptionState& exception_state) {
   if (start_mark.IsEmpty()) {
-    auto* measure = PerformanceMeasure::Create(GetExecutingContext(), measure_name, timeOrigin(), now(exception_state),
+    // Without a start mark, the measure starts from the time origin.
+    auto* measure = PerformanceMeasure::Create(GetExecutingContext(), measure_name, 0, now(exception_state),
                                                ScriptValue::Empty(ctx()), exception_state);
     entries_.emplace_back(measure);
     return;
@@ -217,6 +230,12 @@ void Performance::measure(const AtomicString& measure_name,
   if (end_mark.IsEmpty()) {
     auto start_entry = std::find_if(start_it, entries_.end(),
                                     [&start_mark](auto&& entry) -> bool { return entry->name() == start_mark; });
+    if (start_entry == entries_.end()) {
+      exception_state.ThrowException(ctx(), ErrorType::TypeError,
+                                     "Failed to execute 'measure' on 'Performance': The mark " +
+                                         start_mark.ToStdString(ctx()) + " does not exist.");
+      return;
+    }
     auto* measure = PerformanceMeasure::Create(GetExecutingContext(), measure_name, (*start_entry)->startTime(),
                                                now(exception_state), ScriptValue::Empty(ctx()), exception_state);
     entries_.emplace_back(measure);
@@ -10,6 +10,7 @@
 #include "bindings/qjs/cppgc/member.h"
 #include "bindings/qjs/script_wrappable.h"
 #include "core/binding_object.h"
+#include "plugin_api/performance.h"
 #include "performance_entry.h"
 #include "qjs_performance_mark_options.h"
 
@@ -31,6 +32,8 @@ class Performance : public ScriptWrappable {
   explicit Performance(ExecutingContext* context);
 
   int64_t now(ExceptionState& exception_state) const;
+  // The milliseconds since the time origin with microsecond precision, which event timestamps are measured from too.
+  double HighResolutionNow() const;
   int64_t timeOrigin() const;
   ScriptValue toJSON(ExceptionState& exception_state) const;
   AtomicString ___webf_navigation_summary__(ExceptionState& exception_state) const;
@@ -60,15 +63,16 @@ class Performance : public ScriptWrappable {
                const ScriptValue& start_mark_or_options,
                const AtomicString& end_mark,
                ExceptionState& exception_state);
-
-  void Trace(GCVisitor* visitor) const override;
-
- private:
   void measure(const AtomicString& measure_name,
                const AtomicString& start_mark,
                const AtomicString& end_mark,
                ExceptionState& exception_state);
 
+  void Trace(GCVisitor* visitor) const override;
+  const PerformancePublicMethods* performancePublicMethods();
+
+ private:
+
   std::vector<Member<PerformanceEntry>> entries_;
 };
 
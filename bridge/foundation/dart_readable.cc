@@ -4,6 +4,7 @@
 
 #include "dart_readable.h"
 #include <cstdlib>
+#include <cstring>
 #include <memory>
 
 #if defined(_WIN32)
@@ -28,6 +29,12 @@ void dart_free(void* ptr) {
 #endif
 }
 
+char* CopyToCallerOwnedString(const std::string& string) {
+  auto* buffer = static_cast<char*>(dart_malloc(sizeof(char) * (string.length() + 1)));
+  memcpy(buffer, string.c_str(), string.length() + 1);
+  return buffer;
+}
+
 void* DartReadable::operator new(std::size_t size) {
   return dart_malloc(size);
 }
@@ -26,7 +26,7 @@ struct AnimationEventPublicMethods : public WebFPublicMethods {
   static double ElapsedTime(AnimationEvent* animation_event);
   static const char* PseudoElement(AnimationEvent* animation_event);
   static const char* DupPseudoElement(AnimationEvent* animation_event);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicAnimationEventGetAnimationName animation_event_get_animation_name{AnimationName};
   PublicAnimationEventDupAnimationName animation_event_dup_animation_name{DupAnimationName};
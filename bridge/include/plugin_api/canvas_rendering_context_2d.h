@@ -0,0 +1,143 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_CANVAS_RENDERING_CONTEXT_2D_H_
+#define WEBF_CORE_RUST_API_CANVAS_RENDERING_CONTEXT_2D_H_
+
+#include "webf_value.h"
+
+namespace webf {
+
+class CanvasRenderingContext2D;
+class SharedExceptionState;
+typedef struct WebFNativeFunctionContext WebFNativeFunctionContext;
+
+using PublicCanvasRenderingContext2DRect = void (*)(CanvasRenderingContext2D*,
+                                                    double,
+                                                    double,
+                                                    double,
+                                                    double,
+                                                    SharedExceptionState*);
+using PublicCanvasRenderingContext2DPathOperation = void (*)(CanvasRenderingContext2D*, SharedExceptionState*);
+using PublicCanvasRenderingContext2DPoint = void (*)(CanvasRenderingContext2D*, double, double, SharedExceptionState*);
+using PublicCanvasRenderingContext2DArc =
+    void (*)(CanvasRenderingContext2D*, double, double, double, double, double, int32_t, SharedExceptionState*);
+using PublicCanvasRenderingContext2DSetString = void (*)(CanvasRenderingContext2D*,
+                                                         const char*,
+                                                         SharedExceptionState*);
+using PublicCanvasRenderingContext2DFillText =
+    void (*)(CanvasRenderingContext2D*, const char*, double, double, SharedExceptionState*);
+using PublicCanvasRenderingContext2DDrawImageBytes =
+    void (*)(CanvasRenderingContext2D*, const uint8_t*, int64_t, double, double, SharedExceptionState*);
+using PublicCanvasRenderingContext2DPutImageData = void (*)(CanvasRenderingContext2D*,
+                                                            const uint8_t*,
+                                                            int64_t,
+                                                            int64_t,
+                                                            int64_t,
+                                                            double,
+                                                            double,
+                                                            SharedExceptionState*);
+using PublicCanvasRenderingContext2DGetImageData = void (*)(CanvasRenderingContext2D*,
+                                                            int64_t,
+                                                            int64_t,
+                                                            int64_t,
+                                                            int64_t,
+                                                            WebFNativeFunctionContext*,
+                                                            SharedExceptionState*);
+using PublicCanvasRenderingContext2DRelease = void (*)(CanvasRenderingContext2D*);
+
+struct CanvasRenderingContext2DPublicMethods : WebFPublicMethods {
+  static void FillRect(CanvasRenderingContext2D* context,
+                       double x,
+                       double y,
+                       double w,
+                       double h,
+                       SharedExceptionState* shared_exception_state);
+  static void StrokeRect(CanvasRenderingContext2D* context,
+                         double x,
+                         double y,
+                         double w,
+                         double h,
+                         SharedExceptionState* shared_exception_state);
+  static void ClearRect(CanvasRenderingContext2D* context,
+                        double x,
+                        double y,
+                        double w,
+                        double h,
+                        SharedExceptionState* shared_exception_state);
+  static void BeginPath(CanvasRenderingContext2D* context, SharedExceptionState* shared_exception_state);
+  static void MoveTo(CanvasRenderingContext2D* context,
+                     double x,
+                     double y,
+                     SharedExceptionState* shared_exception_state);
+  static void LineTo(CanvasRenderingContext2D* context,
+                     double x,
+                     double y,
+                     SharedExceptionState* shared_exception_state);
+  static void Arc(CanvasRenderingContext2D* context,
+                  double x,
+                  double y,
+                  double radius,
+                  double start_angle,
+                  double end_angle,
+                  int32_t anticlockwise,
+                  SharedExceptionState* shared_exception_state);
+  static void Fill(CanvasRenderingContext2D* context, SharedExceptionState* shared_exception_state);
+  static void Stroke(CanvasRenderingContext2D* context, SharedExceptionState* shared_exception_state);
+  static void SetFillStyle(CanvasRenderingContext2D* context,
+                           const char* style,
+                           SharedExceptionState* shared_exception_state);
+  static void SetFont(CanvasRenderingContext2D* context,
+                      const char* font,
+                      SharedExceptionState* shared_exception_state);
+  static void FillText(CanvasRenderingContext2D* context,
+                       const char* text,
+                       double x,
+                       double y,
+                       SharedExceptionState* shared_exception_state);
+  static void DrawImageBytes(CanvasRenderingContext2D* context,
+                             const uint8_t* bytes,
+                             int64_t length,
+                             double dx,
+                             double dy,
+                             SharedExceptionState* shared_exception_state);
+  static void PutImageData(CanvasRenderingContext2D* context,
+                           const uint8_t* data,
+                           int64_t length,
+                           int64_t width,
+                           int64_t height,
+                           double dx,
+                           double dy,
+                           SharedExceptionState* shared_exception_state);
+  static void GetImageData(CanvasRenderingContext2D* context,
+                           int64_t sx,
+                           int64_t sy,
+                           int64_t sw,
+                           int64_t sh,
+                           WebFNativeFunctionContext* callback_context,
+                           SharedExceptionState* shared_exception_state);
+  static void Release(CanvasRenderingContext2D* context);
+
+  double version{2.0};
+  PublicCanvasRenderingContext2DRect canvas_rendering_context_2d_fill_rect{FillRect};
+  PublicCanvasRenderingContext2DRect canvas_rendering_context_2d_stroke_rect{StrokeRect};
+  PublicCanvasRenderingContext2DRect canvas_rendering_context_2d_clear_rect{ClearRect};
+  PublicCanvasRenderingContext2DPathOperation canvas_rendering_context_2d_begin_path{BeginPath};
+  PublicCanvasRenderingContext2DPoint canvas_rendering_context_2d_move_to{MoveTo};
+  PublicCanvasRenderingContext2DPoint canvas_rendering_context_2d_line_to{LineTo};
+  PublicCanvasRenderingContext2DArc canvas_rendering_context_2d_arc{Arc};
+  PublicCanvasRenderingContext2DPathOperation canvas_rendering_context_2d_fill{Fill};
+  PublicCanvasRenderingContext2DPathOperation canvas_rendering_context_2d_stroke{Stroke};
+  PublicCanvasRenderingContext2DSetString canvas_rendering_context_2d_set_fill_style{SetFillStyle};
+  PublicCanvasRenderingContext2DSetString canvas_rendering_context_2d_set_font{SetFont};
+  PublicCanvasRenderingContext2DFillText canvas_rendering_context_2d_fill_text{FillText};
+  PublicCanvasRenderingContext2DDrawImageBytes canvas_rendering_context_2d_draw_image_bytes{DrawImageBytes};
+  PublicCanvasRenderingContext2DPutImageData canvas_rendering_context_2d_put_image_data{PutImageData};
+  PublicCanvasRenderingContext2DGetImageData canvas_rendering_context_2d_get_image_data{GetImageData};
+  PublicCanvasRenderingContext2DRelease canvas_rendering_context_2d_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_CANVAS_RENDERING_CONTEXT_2D_H_
@@ -13,10 +13,23 @@ class EventTarget;
 class SharedExceptionState;
 class ExecutingContext;
 class Event;
+class CharacterData;
+
+using PublicCharacterDataGetData = char* (*)(CharacterData*, SharedExceptionState*);
+using PublicCharacterDataSetData = void (*)(CharacterData*, const char*, SharedExceptionState*);
+using PublicCharacterDataGetLength = int64_t (*)(CharacterData*);
 
 struct CharacterDataPublicMethods : WebFPublicMethods {
-  double version{1.0};
+  // The returned string is owned by the caller.
+  static char* Data(CharacterData* character_data, SharedExceptionState* shared_exception_state);
+  static void SetData(CharacterData* character_data, const char* data, SharedExceptionState* shared_exception_state);
+  static int64_t Length(CharacterData* character_data);
+
+  double version{2.0};
   NodePublicMethods node;
+  PublicCharacterDataGetData character_data_get_data{Data};
+  PublicCharacterDataSetData character_data_set_data{SetData};
+  PublicCharacterDataGetLength character_data_get_length{Length};
 };
 
 }  // namespace webf
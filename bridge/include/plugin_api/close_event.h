@@ -24,7 +24,7 @@ struct CloseEventPublicMethods : public WebFPublicMethods {
   static const char* Reason(CloseEvent* close_event);
   static const char* DupReason(CloseEvent* close_event);
   static int32_t WasClean(CloseEvent* close_event);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicCloseEventGetCode close_event_get_code{Code};
   PublicCloseEventGetReason close_event_get_reason{Reason};
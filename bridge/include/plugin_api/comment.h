@@ -15,7 +15,7 @@ class ExecutingContext;
 class Event;
 
 struct CommentPublicMethods : WebFPublicMethods {
-  double version{1.0};
+  double version{2.0};
   CharacterDataPublicMethods character_data;
 };
 
@@ -0,0 +1,29 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+// clang-format off
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+#ifndef WEBF_CORE_WEBF_API_PLUGIN_API_COMPOSITION_EVENT_H_
+#define WEBF_CORE_WEBF_API_PLUGIN_API_COMPOSITION_EVENT_H_
+#include <stdint.h>
+#include "rust_readable.h"
+#include "script_value_ref.h"
+#include "ui_event.h"
+namespace webf {
+class SharedExceptionState;
+class ExecutingContext;
+class CompositionEvent;
+typedef struct ScriptValueRef ScriptValueRef;
+using PublicCompositionEventGetData = const char* (*)(CompositionEvent*);
+using PublicCompositionEventDupData = const char* (*)(CompositionEvent*);
+struct CompositionEventPublicMethods : public WebFPublicMethods {
+  static const char* Data(CompositionEvent* composition_event);
+  static const char* DupData(CompositionEvent* composition_event);
+  double version{2.0};
+  UIEventPublicMethods ui_event;
+  PublicCompositionEventGetData composition_event_get_data{Data};
+  PublicCompositionEventDupData composition_event_dup_data{DupData};
+};
+}  // namespace webf
+#endif  // WEBF_CORE_WEBF_API_PLUGIN_API_COMPOSITION_EVENT_H_
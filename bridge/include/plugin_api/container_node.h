@@ -14,7 +14,7 @@ class SharedExceptionState;
 class ExecutingContext;
 
 struct ContainerNodePublicMethods : WebFPublicMethods {
-  double version{1.0};
+  double version{2.0};
   NodePublicMethods node;
 };
 
@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_CSS_STYLE_DECLARATION_H_
+#define WEBF_CORE_RUST_API_CSS_STYLE_DECLARATION_H_
+
+#include "webf_value.h"
+
+namespace webf {
+
+class CSSStyleDeclaration;
+class SharedExceptionState;
+
+using PublicCSSStyleDeclarationGetPropertyValue = char* (*)(CSSStyleDeclaration*, const char*, SharedExceptionState*);
+using PublicCSSStyleDeclarationSetProperty =
+    void (*)(CSSStyleDeclaration*, const char*, const char*, const char*, SharedExceptionState*);
+using PublicCSSStyleDeclarationRemoveProperty = char* (*)(CSSStyleDeclaration*, const char*, SharedExceptionState*);
+using PublicCSSStyleDeclarationRelease = void (*)(CSSStyleDeclaration*);
+
+struct CSSStyleDeclarationPublicMethods : WebFPublicMethods {
+  static char* GetPropertyValue(CSSStyleDeclaration* style_declaration,
+                                const char* property,
+                                SharedExceptionState* shared_exception_state);
+  static void SetProperty(CSSStyleDeclaration* style_declaration,
+                          const char* property,
+                          const char* value,
+                          const char* priority,
+                          SharedExceptionState* shared_exception_state);
+  static char* RemoveProperty(CSSStyleDeclaration* style_declaration,
+                              const char* property,
+                              SharedExceptionState* shared_exception_state);
+  static void Release(CSSStyleDeclaration* style_declaration);
+
+  double version{2.0};
+  PublicCSSStyleDeclarationGetPropertyValue css_style_declaration_get_property_value{GetPropertyValue};
+  PublicCSSStyleDeclarationSetProperty css_style_declaration_set_property{SetProperty};
+  PublicCSSStyleDeclarationRemoveProperty css_style_declaration_remove_property{RemoveProperty};
+  PublicCSSStyleDeclarationRelease css_style_declaration_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_CSS_STYLE_DECLARATION_H_
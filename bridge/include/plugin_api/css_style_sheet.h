@@ -0,0 +1,42 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_CSS_STYLE_SHEET_H_
+#define WEBF_CORE_RUST_API_CSS_STYLE_SHEET_H_
+
+#include <cstdint>
+#include "webf_value.h"
+
+namespace webf {
+
+class Element;
+class SharedExceptionState;
+
+// The style sheet of a <style> element, which is parsed and applied by the CSS engine on the Dart side.
+// The value is the <style> element which owns the style sheet.
+using PublicCSSStyleSheetInsertRule = int64_t (*)(Element*, const char*, int64_t, SharedExceptionState*);
+using PublicCSSStyleSheetDeleteRule = void (*)(Element*, int64_t, SharedExceptionState*);
+using PublicCSSStyleSheetGetCSSRules = char** (*)(Element*, int64_t*, SharedExceptionState*);
+using PublicCSSStyleSheetRelease = void (*)(Element*);
+
+struct CSSStyleSheetPublicMethods : WebFPublicMethods {
+  static int64_t InsertRule(Element* owner_node,
+                            const char* rule,
+                            int64_t index,
+                            SharedExceptionState* shared_exception_state);
+  static void DeleteRule(Element* owner_node, int64_t index, SharedExceptionState* shared_exception_state);
+  // The text of each rule, the length is written to |length|.
+  static char** CSSRules(Element* owner_node, int64_t* length, SharedExceptionState* shared_exception_state);
+  static void Release(Element* owner_node);
+
+  double version{2.0};
+  PublicCSSStyleSheetInsertRule css_style_sheet_insert_rule{InsertRule};
+  PublicCSSStyleSheetDeleteRule css_style_sheet_delete_rule{DeleteRule};
+  PublicCSSStyleSheetGetCSSRules css_style_sheet_get_css_rules{CSSRules};
+  PublicCSSStyleSheetRelease css_style_sheet_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_CSS_STYLE_SHEET_H_
@@ -20,7 +20,7 @@ using PublicCustomEventInitCustomEvent = void (*)(CustomEvent*, const char*, int
 struct CustomEventPublicMethods : public WebFPublicMethods {
   static WebFValue<ScriptValueRef, ScriptValueRefPublicMethods> Detail(CustomEvent* custom_event);
   static void InitCustomEvent(CustomEvent* custom_event, const char* type, int32_t can_bubble, int32_t cancelable, ScriptValueRef* detail, SharedExceptionState* shared_exception_state);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicCustomEventGetDetail custom_event_get_detail{Detail};
   PublicCustomEventInitCustomEvent custom_event_init_custom_event{InitCustomEvent};
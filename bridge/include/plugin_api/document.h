@@ -5,12 +5,21 @@
 #ifndef WEBF_CORE_RUST_API_DOCUMENT_H_
 #define WEBF_CORE_RUST_API_DOCUMENT_H_
 
+#include "close_event.h"
+#include "close_event_init.h"
 #include "comment.h"
 #include "container_node.h"
+#include "custom_event.h"
 #include "document_fragment.h"
 #include "element.h"
 #include "event.h"
+#include "html_collection.h"
 #include "html_element.h"
+#include "mouse_event.h"
+#include "node_list.h"
+#include "pointer_event.h"
+#include "pointer_event_init.h"
+#include "script_promise_ref.h"
 #include "text.h"
 
 namespace webf {
@@ -24,11 +33,29 @@ class Document;
 class Text;
 class Comment;
 class Event;
+class CustomEvent;
+class MouseEvent;
+class PointerEvent;
+class CloseEvent;
+class NodeList;
 
 struct WebFElementCreationOptions {
   const char* is;
 };
 
+struct WebFMouseEventInit {
+  int32_t bubbles;
+  int32_t cancelable;
+  double client_x;
+  double client_y;
+  int32_t button;
+  int32_t buttons;
+  int32_t alt_key;
+  int32_t ctrl_key;
+  int32_t meta_key;
+  int32_t shift_key;
+};
+
 using PublicDocumentCreateElement =
     WebFValue<Element, ElementPublicMethods> (*)(Document*, const char*, SharedExceptionState* shared_exception_state);
 using PublicDocumentCreateElementWithElementCreationOptions =
@@ -69,6 +96,40 @@ using PublicDocumentGetDocumentElement = WebFValue<Element, HTMLElementPublicMet
 using PublicDocumentGetDocumentHeader = WebFValue<Element, HTMLElementPublicMethods> (*)(Document*);
 using PublicDocumentGetDocumentBody = WebFValue<Element, HTMLElementPublicMethods> (*)(Document*);
 using PublicDocumentClearCookie = void (*)(Document*, SharedExceptionState*);
+using PublicDocumentQuerySelectorAll =
+    WebFValue<NodeList, NodeListPublicMethods> (*)(Document*, const char*, SharedExceptionState* shared_exception_state);
+using PublicDocumentCreateCustomEvent = WebFValue<CustomEvent, CustomEventPublicMethods> (*)(
+    Document*,
+    const char*,
+    const char*,
+    int32_t,
+    int32_t,
+    SharedExceptionState* shared_exception_state);
+using PublicDocumentGetElementsByClassName =
+    WebFValue<NodeList, HTMLCollectionPublicMethods> (*)(Document*, const char*, SharedExceptionState*);
+using PublicDocumentGetElementsByTagName =
+    WebFValue<NodeList, HTMLCollectionPublicMethods> (*)(Document*, const char*, SharedExceptionState*);
+using PublicDocumentCreateMouseEvent = WebFValue<MouseEvent, MouseEventPublicMethods> (*)(
+    Document*,
+    const char*,
+    const WebFMouseEventInit*,
+    SharedExceptionState* shared_exception_state);
+using PublicDocumentExitFullscreen =
+    WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods> (*)(Document*, SharedExceptionState*);
+using PublicDocumentGetFullscreenElement = WebFValue<Element, ElementPublicMethods> (*)(Document*);
+using PublicDocumentGetReadyState = int32_t (*)(Document*);
+using PublicDocumentCreatePointerEvent = WebFValue<PointerEvent, PointerEventPublicMethods> (*)(
+    Document*,
+    const char*,
+    int32_t,
+    int32_t,
+    const WebFPointerEventInit*,
+    SharedExceptionState* shared_exception_state);
+using PublicDocumentCreateCloseEvent = WebFValue<CloseEvent, CloseEventPublicMethods> (*)(
+    Document*,
+    const char*,
+    const WebFCloseEventInit*,
+    SharedExceptionState* shared_exception_state);
 
 struct DocumentPublicMethods : public WebFPublicMethods {
   static WebFValue<Element, ElementPublicMethods> CreateElement(Document* document,
@@ -115,8 +176,49 @@ struct DocumentPublicMethods : public WebFPublicMethods {
   static WebFValue<Element, HTMLElementPublicMethods> Head(Document* document);
   static WebFValue<Element, HTMLElementPublicMethods> Body(Document* document);
   static void ClearCookie(Document* document, SharedExceptionState* shared_exception_state);
+  static WebFValue<NodeList, NodeListPublicMethods> QuerySelectorAll(Document* document,
+                                                                     const char* selectors,
+                                                                     SharedExceptionState* shared_exception_state);
+  static WebFValue<CustomEvent, CustomEventPublicMethods> CreateCustomEvent(
+      Document* document,
+      const char* type,
+      const char* detail_json,
+      int32_t bubbles,
+      int32_t cancelable,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<NodeList, HTMLCollectionPublicMethods> GetElementsByClassName(
+      Document* document,
+      const char* class_names,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<NodeList, HTMLCollectionPublicMethods> GetElementsByTagName(
+      Document* document,
+      const char* tag_name,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<MouseEvent, MouseEventPublicMethods> CreateMouseEvent(
+      Document* document,
+      const char* type,
+      const WebFMouseEventInit* init,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods> ExitFullscreen(
+      Document* document,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<Element, ElementPublicMethods> FullscreenElement(Document* document);
+  static WebFValue<CloseEvent, CloseEventPublicMethods> CreateCloseEvent(
+      Document* document,
+      const char* type,
+      const WebFCloseEventInit* init,
+      SharedExceptionState* shared_exception_state);
+  // 0 for loading, 1 for interactive and 2 for complete.
+  static int32_t ReadyState(Document* document);
+  static WebFValue<PointerEvent, PointerEventPublicMethods> CreatePointerEvent(
+      Document* document,
+      const char* type,
+      int32_t bubbles,
+      int32_t cancelable,
+      const WebFPointerEventInit* init,
+      SharedExceptionState* shared_exception_state);
 
-  double version{1.0};
+  double version{2.0};
   ContainerNodePublicMethods container_node;
   PublicDocumentCreateElement document_create_element{CreateElement};
   PublicDocumentCreateElementWithElementCreationOptions document_create_element_with_element_creation_options{
@@ -135,6 +237,16 @@ struct DocumentPublicMethods : public WebFPublicMethods {
   PublicDocumentGetDocumentHeader document_get_document_header{Head};
   PublicDocumentGetDocumentBody document_get_document_body{Body};
   PublicDocumentClearCookie document_clear_cookie{ClearCookie};
+  PublicDocumentQuerySelectorAll document_query_selector_all{QuerySelectorAll};
+  PublicDocumentCreateCustomEvent document_create_custom_event{CreateCustomEvent};
+  PublicDocumentGetElementsByClassName document_get_elements_by_class_name{GetElementsByClassName};
+  PublicDocumentGetElementsByTagName document_get_elements_by_tag_name{GetElementsByTagName};
+  PublicDocumentCreateMouseEvent document_create_mouse_event{CreateMouseEvent};
+  PublicDocumentExitFullscreen document_exit_fullscreen{ExitFullscreen};
+  PublicDocumentGetFullscreenElement document_get_fullscreen_element{FullscreenElement};
+  PublicDocumentCreateCloseEvent document_create_close_event{CreateCloseEvent};
+  PublicDocumentGetReadyState document_get_ready_state{ReadyState};
+  PublicDocumentCreatePointerEvent document_create_pointer_event{CreatePointerEvent};
 };
 
 }  // namespace webf
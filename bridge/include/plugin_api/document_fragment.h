@@ -16,7 +16,7 @@ class DocumentFragment;
 class Document;
 
 struct DocumentFragmentPublicMethods : WebFPublicMethods {
-  double version{1.0};
+  double version{2.0};
   ContainerNodePublicMethods container_node;
 };
 
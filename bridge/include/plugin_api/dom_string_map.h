@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_DOM_STRING_MAP_H_
+#define WEBF_CORE_RUST_API_DOM_STRING_MAP_H_
+
+#include <cstdint>
+#include "webf_value.h"
+
+namespace webf {
+
+class DOMStringMap;
+class SharedExceptionState;
+
+// A data attribute which is readable from the Rust side. The strings are owned by the caller.
+struct WebFDOMStringMapEntry {
+  char* key;
+  char* value;
+};
+
+using PublicDOMStringMapGet = char* (*)(DOMStringMap*, const char*, SharedExceptionState*);
+using PublicDOMStringMapSet = void (*)(DOMStringMap*, const char*, const char*, SharedExceptionState*);
+using PublicDOMStringMapRemove = void (*)(DOMStringMap*, const char*, SharedExceptionState*);
+using PublicDOMStringMapEntries = WebFDOMStringMapEntry* (*)(DOMStringMap*, int64_t*, SharedExceptionState*);
+using PublicDOMStringMapRelease = void (*)(DOMStringMap*);
+
+struct DOMStringMapPublicMethods : WebFPublicMethods {
+  // Returns null when there is no data attribute for the key, the returned string is owned by the caller.
+  static char* Get(DOMStringMap* dom_string_map, const char* key, SharedExceptionState* shared_exception_state);
+  static void Set(DOMStringMap* dom_string_map,
+                  const char* key,
+                  const char* value,
+                  SharedExceptionState* shared_exception_state);
+  static void Remove(DOMStringMap* dom_string_map, const char* key, SharedExceptionState* shared_exception_state);
+  // The returned entries are owned by the caller.
+  static WebFDOMStringMapEntry* Entries(DOMStringMap* dom_string_map,
+                                        int64_t* length,
+                                        SharedExceptionState* shared_exception_state);
+  static void Release(DOMStringMap* dom_string_map);
+
+  double version{2.0};
+  PublicDOMStringMapGet dom_string_map_get{Get};
+  PublicDOMStringMapSet dom_string_map_set{Set};
+  PublicDOMStringMapRemove dom_string_map_remove{Remove};
+  PublicDOMStringMapEntries dom_string_map_entries{Entries};
+  PublicDOMStringMapRelease dom_string_map_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_DOM_STRING_MAP_H_
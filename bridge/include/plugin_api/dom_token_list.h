@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_DOM_TOKEN_LIST_H_
+#define WEBF_CORE_RUST_API_DOM_TOKEN_LIST_H_
+
+#include <cstdint>
+#include "webf_value.h"
+
+namespace webf {
+
+class DOMTokenList;
+class SharedExceptionState;
+
+using PublicDOMTokenListGetLength = uint32_t (*)(DOMTokenList*);
+using PublicDOMTokenListAdd = void (*)(DOMTokenList*, const char**, uint32_t, SharedExceptionState*);
+using PublicDOMTokenListRemove = void (*)(DOMTokenList*, const char**, uint32_t, SharedExceptionState*);
+using PublicDOMTokenListToggle = int32_t (*)(DOMTokenList*, const char*, SharedExceptionState*);
+using PublicDOMTokenListContains = int32_t (*)(DOMTokenList*, const char*, SharedExceptionState*);
+using PublicDOMTokenListRelease = void (*)(DOMTokenList*);
+
+struct DOMTokenListPublicMethods : WebFPublicMethods {
+  static uint32_t Length(DOMTokenList* dom_token_list);
+  static void Add(DOMTokenList* dom_token_list,
+                  const char** tokens,
+                  uint32_t token_count,
+                  SharedExceptionState* shared_exception_state);
+  static void Remove(DOMTokenList* dom_token_list,
+                     const char** tokens,
+                     uint32_t token_count,
+                     SharedExceptionState* shared_exception_state);
+  static int32_t Toggle(DOMTokenList* dom_token_list, const char* token, SharedExceptionState* shared_exception_state);
+  static int32_t Contains(DOMTokenList* dom_token_list,
+                          const char* token,
+                          SharedExceptionState* shared_exception_state);
+  static void Release(DOMTokenList* dom_token_list);
+
+  double version{2.0};
+  PublicDOMTokenListGetLength dom_token_list_get_length{Length};
+  PublicDOMTokenListAdd dom_token_list_add{Add};
+  PublicDOMTokenListRemove dom_token_list_remove{Remove};
+  PublicDOMTokenListToggle dom_token_list_toggle{Toggle};
+  PublicDOMTokenListContains dom_token_list_contains{Contains};
+  PublicDOMTokenListRelease dom_token_list_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_DOM_TOKEN_LIST_H_
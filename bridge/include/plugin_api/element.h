@@ -5,7 +5,14 @@
 #ifndef WEBF_CORE_RUST_API_ELEMENT_H_
 #define WEBF_CORE_RUST_API_ELEMENT_H_
 
+#include "canvas_rendering_context_2d.h"
 #include "container_node.h"
+#include "css_style_declaration.h"
+#include "css_style_sheet.h"
+#include "dom_string_map.h"
+#include "dom_token_list.h"
+#include "node_list.h"
+#include "script_promise_ref.h"
 
 namespace webf {
 
@@ -14,13 +21,75 @@ class SharedExceptionState;
 class ExecutingContext;
 class Element;
 class Document;
+class NodeList;
+class DOMTokenList;
+class DOMStringMap;
+class CSSStyleDeclaration;
+class CanvasRenderingContext2D;
+typedef struct HTMLCollectionPublicMethods HTMLCollectionPublicMethods;
+struct BoundingClientRectData;
 typedef struct WebFNativeFunctionContext WebFNativeFunctionContext;
 
+// An attribute of an element which is readable from the Rust side. The strings are owned by the caller.
+struct WebFElementAttribute {
+  char* name;
+  char* value;
+};
+
 using PublicElementToBlob = void (*)(Element*, WebFNativeFunctionContext*, SharedExceptionState*);
 using PublicElementToBlobWithDevicePixelRatio = void (*)(Element*,
                                                          double,
                                                          WebFNativeFunctionContext*,
                                                          SharedExceptionState*);
+using PublicElementQuerySelector =
+    WebFValue<Element, ElementPublicMethods> (*)(Element*, const char*, SharedExceptionState* shared_exception_state);
+using PublicElementQuerySelectorAll =
+    WebFValue<NodeList, NodeListPublicMethods> (*)(Element*, const char*, SharedExceptionState* shared_exception_state);
+using PublicElementGetClassList = WebFValue<DOMTokenList, DOMTokenListPublicMethods> (*)(Element*);
+using PublicElementGetAttribute = char* (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementSetAttribute = void (*)(Element*, const char*, const char*, SharedExceptionState*);
+using PublicElementRemoveAttribute = void (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementHasAttribute = int32_t (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementGetInnerHTML = char* (*)(Element*, SharedExceptionState*);
+using PublicElementSetInnerHTML = void (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementGetOuterHTML = char* (*)(Element*, SharedExceptionState*);
+using PublicElementGetStyle = WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods> (*)(Element*);
+using PublicElementGetBoundingClientRect = void (*)(Element*, BoundingClientRectData*, SharedExceptionState*);
+using PublicElementScrollTo = void (*)(Element*, double, double, SharedExceptionState*);
+using PublicElementScrollBy = void (*)(Element*, double, double, SharedExceptionState*);
+using PublicElementScrollIntoView = void (*)(Element*, const char*, const char*, int32_t, SharedExceptionState*);
+using PublicElementGetScrollTop = double (*)(Element*, SharedExceptionState*);
+using PublicElementSetScrollTop = void (*)(Element*, double, SharedExceptionState*);
+using PublicElementGetScrollLeft = double (*)(Element*, SharedExceptionState*);
+using PublicElementSetScrollLeft = void (*)(Element*, double, SharedExceptionState*);
+using PublicElementGetElementsByClassName =
+    WebFValue<NodeList, HTMLCollectionPublicMethods> (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementGetElementsByTagName =
+    WebFValue<NodeList, HTMLCollectionPublicMethods> (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementGetDataset = WebFValue<DOMStringMap, DOMStringMapPublicMethods> (*)(Element*);
+using PublicElementMatches = int32_t (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementClosest = WebFValue<Element, ElementPublicMethods> (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementGetTagName = char* (*)(Element*);
+using PublicElementGetId = char* (*)(Element*);
+using PublicElementSetId = void (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementGetClassName = char* (*)(Element*);
+using PublicElementSetClassName = void (*)(Element*, const char*, SharedExceptionState*);
+using PublicElementSetPointerCapture = void (*)(Element*, int64_t, SharedExceptionState*);
+using PublicElementReleasePointerCapture = void (*)(Element*, int64_t, SharedExceptionState*);
+using PublicElementHasPointerCapture = int32_t (*)(Element*, int64_t, SharedExceptionState*);
+using PublicElementRequestFullscreen =
+    WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods> (*)(Element*, SharedExceptionState*);
+using PublicElementGetContext2D =
+    WebFValue<CanvasRenderingContext2D, CanvasRenderingContext2DPublicMethods> (*)(Element*, SharedExceptionState*);
+using PublicElementGetAttributes = WebFElementAttribute* (*)(Element*, int64_t*, SharedExceptionState*);
+using PublicElementGetOffsetTop = double (*)(Element*, SharedExceptionState*);
+using PublicElementGetOffsetLeft = double (*)(Element*, SharedExceptionState*);
+using PublicElementGetOffsetWidth = double (*)(Element*, SharedExceptionState*);
+using PublicElementGetOffsetHeight = double (*)(Element*, SharedExceptionState*);
+using PublicElementGetOffsetParent = WebFValue<Element, ElementPublicMethods> (*)(Element*, SharedExceptionState*);
+using PublicElementGetClientWidth = double (*)(Element*, SharedExceptionState*);
+using PublicElementGetClientHeight = double (*)(Element*, SharedExceptionState*);
+using PublicElementGetSheet = WebFValue<Element, CSSStyleSheetPublicMethods> (*)(Element*, SharedExceptionState*);
 
 struct ElementPublicMethods : WebFPublicMethods {
   static void ToBlob(Element* element, WebFNativeFunctionContext* context, SharedExceptionState* exception_state);
@@ -28,11 +97,131 @@ struct ElementPublicMethods : WebFPublicMethods {
                                          double device_pixel_ratio,
                                          WebFNativeFunctionContext* context,
                                          SharedExceptionState* exception_state);
+  static WebFValue<Element, ElementPublicMethods> QuerySelector(Element* element,
+                                                                const char* selectors,
+                                                                SharedExceptionState* shared_exception_state);
+  static WebFValue<NodeList, NodeListPublicMethods> QuerySelectorAll(Element* element,
+                                                                     const char* selectors,
+                                                                     SharedExceptionState* shared_exception_state);
+  static WebFValue<DOMTokenList, DOMTokenListPublicMethods> ClassList(Element* element);
+  static char* GetAttribute(Element* element, const char* name, SharedExceptionState* shared_exception_state);
+  static void SetAttribute(Element* element,
+                           const char* name,
+                           const char* value,
+                           SharedExceptionState* shared_exception_state);
+  static void RemoveAttribute(Element* element, const char* name, SharedExceptionState* shared_exception_state);
+  static int32_t HasAttribute(Element* element, const char* name, SharedExceptionState* shared_exception_state);
+  static char* InnerHTML(Element* element, SharedExceptionState* shared_exception_state);
+  static void SetInnerHTML(Element* element, const char* html, SharedExceptionState* shared_exception_state);
+  static char* OuterHTML(Element* element, SharedExceptionState* shared_exception_state);
+  static WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods> Style(Element* element);
+  static void GetBoundingClientRect(Element* element,
+                                    BoundingClientRectData* rect,
+                                    SharedExceptionState* shared_exception_state);
+  static void ScrollTo(Element* element, double x, double y, SharedExceptionState* shared_exception_state);
+  static void ScrollBy(Element* element, double dx, double dy, SharedExceptionState* shared_exception_state);
+  static void ScrollIntoView(Element* element,
+                             const char* block,
+                             const char* inline_,
+                             int32_t smooth,
+                             SharedExceptionState* shared_exception_state);
+  static double ScrollTop(Element* element, SharedExceptionState* shared_exception_state);
+  static void SetScrollTop(Element* element, double value, SharedExceptionState* shared_exception_state);
+  static double ScrollLeft(Element* element, SharedExceptionState* shared_exception_state);
+  static void SetScrollLeft(Element* element, double value, SharedExceptionState* shared_exception_state);
+  static WebFValue<NodeList, HTMLCollectionPublicMethods> GetElementsByClassName(
+      Element* element,
+      const char* class_names,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<NodeList, HTMLCollectionPublicMethods> GetElementsByTagName(
+      Element* element,
+      const char* tag_name,
+      SharedExceptionState* shared_exception_state);
+  static WebFValue<DOMStringMap, DOMStringMapPublicMethods> Dataset(Element* element);
+  static int32_t Matches(Element* element, const char* selectors, SharedExceptionState* shared_exception_state);
+  static WebFValue<Element, ElementPublicMethods> Closest(Element* element,
+                                                          const char* selectors,
+                                                          SharedExceptionState* shared_exception_state);
+  static char* TagName(Element* element);
+  static char* Id(Element* element);
+  static void SetId(Element* element, const char* id, SharedExceptionState* shared_exception_state);
+  static char* ClassName(Element* element);
+  static void SetClassName(Element* element, const char* class_name, SharedExceptionState* shared_exception_state);
+  static void SetPointerCapture(Element* element, int64_t pointer_id, SharedExceptionState* shared_exception_state);
+  static void ReleasePointerCapture(Element* element,
+                                    int64_t pointer_id,
+                                    SharedExceptionState* shared_exception_state);
+  static int32_t HasPointerCapture(Element* element, int64_t pointer_id, SharedExceptionState* shared_exception_state);
+  static WebFValue<ScriptPromiseRef, ScriptPromiseRefPublicMethods> RequestFullscreen(
+      Element* element,
+      SharedExceptionState* shared_exception_state);
+  // Throws a TypeError when the element is not a canvas.
+  static WebFValue<CanvasRenderingContext2D, CanvasRenderingContext2DPublicMethods> GetContext2D(
+      Element* element,
+      SharedExceptionState* shared_exception_state);
+  // The attributes are sorted by name, the length is written to |length|.
+  static WebFElementAttribute* Attributes(Element* element,
+                                          int64_t* length,
+                                          SharedExceptionState* shared_exception_state);
+  static double OffsetTop(Element* element, SharedExceptionState* shared_exception_state);
+  static double OffsetLeft(Element* element, SharedExceptionState* shared_exception_state);
+  static double OffsetWidth(Element* element, SharedExceptionState* shared_exception_state);
+  static double OffsetHeight(Element* element, SharedExceptionState* shared_exception_state);
+  static WebFValue<Element, ElementPublicMethods> OffsetParent(Element* element,
+                                                               SharedExceptionState* shared_exception_state);
+  static double ClientWidth(Element* element, SharedExceptionState* shared_exception_state);
+  static double ClientHeight(Element* element, SharedExceptionState* shared_exception_state);
+  // Throws a TypeError when the element is not a <style> element.
+  static WebFValue<Element, CSSStyleSheetPublicMethods> Sheet(Element* element,
+                                                              SharedExceptionState* shared_exception_state);
 
-  double version{1.0};
+  double version{2.0};
   ContainerNodePublicMethods container_node;
   PublicElementToBlob element_to_blob{ToBlob};
   PublicElementToBlobWithDevicePixelRatio element_to_blob_with_device_pixel_ratio{ToBlobWithDevicePixelRatio};
+  PublicElementQuerySelector element_query_selector{QuerySelector};
+  PublicElementQuerySelectorAll element_query_selector_all{QuerySelectorAll};
+  PublicElementGetClassList element_get_class_list{ClassList};
+  PublicElementGetAttribute element_get_attribute{GetAttribute};
+  PublicElementSetAttribute element_set_attribute{SetAttribute};
+  PublicElementRemoveAttribute element_remove_attribute{RemoveAttribute};
+  PublicElementHasAttribute element_has_attribute{HasAttribute};
+  PublicElementGetInnerHTML element_get_inner_html{InnerHTML};
+  PublicElementSetInnerHTML element_set_inner_html{SetInnerHTML};
+  PublicElementGetOuterHTML element_get_outer_html{OuterHTML};
+  PublicElementGetStyle element_get_style{Style};
+  PublicElementGetBoundingClientRect element_get_bounding_client_rect{GetBoundingClientRect};
+  PublicElementScrollTo element_scroll_to{ScrollTo};
+  PublicElementScrollBy element_scroll_by{ScrollBy};
+  PublicElementScrollIntoView element_scroll_into_view{ScrollIntoView};
+  PublicElementGetScrollTop element_get_scroll_top{ScrollTop};
+  PublicElementSetScrollTop element_set_scroll_top{SetScrollTop};
+  PublicElementGetScrollLeft element_get_scroll_left{ScrollLeft};
+  PublicElementSetScrollLeft element_set_scroll_left{SetScrollLeft};
+  PublicElementGetElementsByClassName element_get_elements_by_class_name{GetElementsByClassName};
+  PublicElementGetElementsByTagName element_get_elements_by_tag_name{GetElementsByTagName};
+  PublicElementGetDataset element_get_dataset{Dataset};
+  PublicElementMatches element_matches{Matches};
+  PublicElementClosest element_closest{Closest};
+  PublicElementGetTagName element_get_tag_name{TagName};
+  PublicElementGetId element_get_id{Id};
+  PublicElementSetId element_set_id{SetId};
+  PublicElementGetClassName element_get_class_name{ClassName};
+  PublicElementSetClassName element_set_class_name{SetClassName};
+  PublicElementSetPointerCapture element_set_pointer_capture{SetPointerCapture};
+  PublicElementReleasePointerCapture element_release_pointer_capture{ReleasePointerCapture};
+  PublicElementHasPointerCapture element_has_pointer_capture{HasPointerCapture};
+  PublicElementRequestFullscreen element_request_fullscreen{RequestFullscreen};
+  PublicElementGetContext2D element_get_context_2d{GetContext2D};
+  PublicElementGetAttributes element_get_attributes{Attributes};
+  PublicElementGetOffsetTop element_get_offset_top{OffsetTop};
+  PublicElementGetOffsetLeft element_get_offset_left{OffsetLeft};
+  PublicElementGetOffsetWidth element_get_offset_width{OffsetWidth};
+  PublicElementGetOffsetHeight element_get_offset_height{OffsetHeight};
+  PublicElementGetOffsetParent element_get_offset_parent{OffsetParent};
+  PublicElementGetClientWidth element_get_client_width{ClientWidth};
+  PublicElementGetClientHeight element_get_client_height{ClientHeight};
+  PublicElementGetSheet element_get_sheet{Sheet};
 };
 
 }  // namespace webf
@@ -29,14 +29,21 @@ enum class EventType {
   kUIEvent = 8,
   kFocusEvent = 9,
   kInputEvent = 10,
-  kMouseEvent = 11,
-  kPointerEvent = 12,
+  kKeyboardEvent = 11,
+  kMouseEvent = 12,
+  kPointerEvent = 13,
+  kWheelEvent = 14,
+  kPopStateEvent = 15,
+  kCompositionEvent = 16,
+  kTouchEvent = 17,
+  kStorageEvent = 18,
 };
 using PublicEventGetBubbles = int32_t (*)(Event*);
 using PublicEventGetCancelBubble = int32_t (*)(Event*);
 using PublicEventSetCancelBubble = void (*)(Event*, int32_t, SharedExceptionState*);
 using PublicEventGetCancelable = int32_t (*)(Event*);
 using PublicEventGetCurrentTarget = WebFValue<EventTarget, EventTargetPublicMethods> (*)(Event*);
+using PublicEventGetEventPhase = int64_t (*)(Event*);
 using PublicEventGetDefaultPrevented = int32_t (*)(Event*);
 using PublicEventGetSrcElement = WebFValue<EventTarget, EventTargetPublicMethods> (*)(Event*);
 using PublicEventGetTarget = WebFValue<EventTarget, EventTargetPublicMethods> (*)(Event*);
@@ -50,12 +57,14 @@ using PublicEventStopImmediatePropagation = void (*)(Event*, SharedExceptionStat
 using PublicEventStopPropagation = void (*)(Event*, SharedExceptionState*);
 using PublicEventRelease = void (*)(Event*);
 using PublicEventDynamicTo = WebFValue<Event, WebFPublicMethods> (*)(Event*, EventType);
+using PublicEventComposedPath = WebFValue<EventTarget, EventTargetPublicMethods>* (*)(Event*, int64_t*);
 struct EventPublicMethods : public WebFPublicMethods {
   static int32_t Bubbles(Event* event);
   static int32_t CancelBubble(Event* event);
   static void SetCancelBubble(Event* event, int32_t cancelBubble, SharedExceptionState* shared_exception_state);
   static int32_t Cancelable(Event* event);
   static WebFValue<EventTarget, EventTargetPublicMethods> CurrentTarget(Event* event);
+  static int64_t EventPhase(Event* event);
   static int32_t DefaultPrevented(Event* event);
   static WebFValue<EventTarget, EventTargetPublicMethods> SrcElement(Event* event);
   static WebFValue<EventTarget, EventTargetPublicMethods> Target(Event* event);
@@ -69,12 +78,15 @@ struct EventPublicMethods : public WebFPublicMethods {
   static void StopPropagation(Event* event, SharedExceptionState* shared_exception_state);
   static void Release(Event* event);
   static WebFValue<Event, WebFPublicMethods> DynamicTo(Event* event, EventType event_type);
-  double version{1.0};
+  // Implemented in core/api/event.cc.
+  static WebFValue<EventTarget, EventTargetPublicMethods>* ComposedPath(Event* event, int64_t* length);
+  double version{2.0};
   PublicEventGetBubbles event_get_bubbles{Bubbles};
   PublicEventGetCancelBubble event_get_cancel_bubble{CancelBubble};
   PublicEventSetCancelBubble event_set_cancel_bubble{SetCancelBubble};
   PublicEventGetCancelable event_get_cancelable{Cancelable};
   PublicEventGetCurrentTarget event_get_current_target{CurrentTarget};
+  PublicEventGetEventPhase event_get_event_phase{EventPhase};
   PublicEventGetDefaultPrevented event_get_default_prevented{DefaultPrevented};
   PublicEventGetSrcElement event_get_src_element{SrcElement};
   PublicEventGetTarget event_get_target{Target};
@@ -88,6 +100,7 @@ struct EventPublicMethods : public WebFPublicMethods {
   PublicEventStopPropagation event_stop_propagation{StopPropagation};
   PublicEventRelease event_release{Release};
   PublicEventDynamicTo event_dynamic_to{DynamicTo};
+  PublicEventComposedPath event_composed_path{ComposedPath};
 };
 }  // namespace webf
 #endif  // WEBF_CORE_WEBF_API_PLUGIN_API_EVENT_H_
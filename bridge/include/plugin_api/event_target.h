@@ -49,6 +49,9 @@ using PublicEventTargetDispatchEvent = bool (*)(EventTarget* event_target,
                                                 SharedExceptionState* shared_exception_state);
 
 using PublicEventTargetRelease = void (*)(EventTarget*);
+using PublicEventTargetListenerCount = int64_t (*)(EventTarget*, const char*);
+using PublicEventTargetDowngrade = WebFValueStatus* (*)(EventTarget*);
+using PublicEventTargetReleaseWeak = void (*)(EventTarget*, WebFValueStatus*);
 
 using PublicEventTargetDynamicTo = WebFValue<EventTarget, WebFPublicMethods> (*)(EventTarget*,
                                                                                  EventTargetType event_target_type);
@@ -67,13 +70,19 @@ struct EventTargetPublicMethods : public WebFPublicMethods {
   static void Release(EventTarget* event_target);
   static WebFValue<EventTarget, WebFPublicMethods> DynamicTo(EventTarget* event_target,
                                                              EventTargetType event_target_type);
+  static int64_t ListenerCount(EventTarget* event_target, const char* event_name_str);
+  static WebFValueStatus* Downgrade(EventTarget* event_target);
+  static void ReleaseWeak(EventTarget* event_target, WebFValueStatus* weak_status);
 
-  double version{1.0};
+  double version{2.0};
   PublicEventTargetAddEventListener event_target_add_event_listener{AddEventListener};
   PublicEventTargetRemoveEventListener event_target_remove_event_listener{RemoveEventListener};
   PublicEventTargetDispatchEvent event_target_dispatch_event{DispatchEvent};
   PublicEventTargetRelease event_target_release{Release};
   PublicEventTargetDynamicTo event_target_dynamic_to{DynamicTo};
+  PublicEventTargetListenerCount event_target_listener_count{ListenerCount};
+  PublicEventTargetDowngrade event_target_downgrade{Downgrade};
+  PublicEventTargetReleaseWeak event_target_release_weak{ReleaseWeak};
 };
 
 }  // namespace webf
@@ -19,6 +19,8 @@ using PublicExceptionStateStringify = void (*)(ExecutingContext* context,
                                                SharedExceptionState* shared_exception_state,
                                                char** errmsg,
                                                uint32_t* strlen);
+using PublicExceptionStateName = char* (*)(ExecutingContext* context, SharedExceptionState* shared_exception_state);
+using PublicExceptionStateCode = int32_t (*)(SharedExceptionState* shared_exception_state);
 
 class ExceptionStatePublicMethods : public WebFPublicMethods {
   static bool HasException(SharedExceptionState* shared_exception_state);
@@ -26,10 +28,15 @@ class ExceptionStatePublicMethods : public WebFPublicMethods {
                         SharedExceptionState* shared_exception_state,
                         char** errmsg,
                         uint32_t* strlen);
+  // The returned string is owned by the caller, it's null when there is no exception.
+  static char* Name(ExecutingContext* context, SharedExceptionState* shared_exception_state);
+  static int32_t Code(SharedExceptionState* shared_exception_state);
 
-  double version{1.0};
+  double version{2.0};
   PublicExceptionStateHasException has_exception_{HasException};
   PublicExceptionStateStringify stringify_{Stringify};
+  PublicExceptionStateName name_{Name};
+  PublicExceptionStateCode code_{Code};
 };
 
 }  // namespace webf
@@ -10,6 +10,10 @@
 #include "document.h"
 #include "exception_state.h"
 #include "foundation/native_value.h"
+#include "mutation_observer.h"
+#include "performance.h"
+#include "script_promise_ref.h"
+#include "script_value_ref.h"
 #include "window.h"
 
 namespace webf {
@@ -17,6 +21,18 @@ namespace webf {
 class Document;
 class ExecutingContext;
 class Window;
+class Event;
+class EventTarget;
+class MutationObserver;
+class Performance;
+
+// The data of a MessageEvent. Text messages are encoded as UTF-8.
+struct WebFMessageEventData {
+  // Owned by the caller.
+  uint8_t* bytes;
+  int64_t length;
+  int32_t is_binary;
+};
 
 using PublicContextGetDocument = WebFValue<Document, DocumentPublicMethods> (*)(ExecutingContext*);
 using PublicContextGetWindow = WebFValue<Window, WindowPublicMethods> (*)(ExecutingContext*);
@@ -43,6 +59,10 @@ using PublicContextSetInterval = int32_t (*)(ExecutingContext*,
                                              SharedExceptionState*);
 using PublicContextClearTimeout = void (*)(ExecutingContext*, int32_t, SharedExceptionState*);
 using PublicContextClearInterval = void (*)(ExecutingContext*, int32_t, SharedExceptionState*);
+using PublicContextRequestAnimationFrame = uint32_t (*)(ExecutingContext*,
+                                                        WebFNativeFunctionContext*,
+                                                        SharedExceptionState*);
+using PublicContextCancelAnimationFrame = void (*)(ExecutingContext*, uint32_t, SharedExceptionState*);
 using PublicContextAddRustFutureTask = void (*)(ExecutingContext*,
                                                 WebFNativeFunctionContext*,
                                                 NativeLibrartMetaData*,
@@ -51,6 +71,30 @@ using PublicContextRemoveRustFutureTask = void (*)(ExecutingContext*,
                                                    WebFNativeFunctionContext*,
                                                    NativeLibrartMetaData*,
                                                    SharedExceptionState*);
+using PublicContextCreateModuleEventTarget =
+    WebFValue<EventTarget, EventTargetPublicMethods> (*)(ExecutingContext*,
+                                                         const char*,
+                                                         const char*,
+                                                         SharedExceptionState*);
+using PublicContextRemoveModuleEventTarget = void (*)(ExecutingContext*, const char*, const char*);
+using PublicContextGetMessageEventData = void (*)(ExecutingContext*,
+                                                  Event*,
+                                                  WebFMessageEventData*,
+                                                  SharedExceptionState*);
+using PublicContextPrint = void (*)(ExecutingContext*, const char*, const char*);
+using PublicContextCreateMutationObserver =
+    WebFValue<MutationObserver, MutationObserverPublicMethods> (*)(ExecutingContext*,
+                                                                   WebFNativeFunctionContext*,
+                                                                   SharedExceptionState*);
+using PublicContextParseFromString = WebFValue<Document, DocumentPublicMethods> (*)(ExecutingContext*,
+                                                                                    const char*,
+                                                                                    int32_t,
+                                                                                    SharedExceptionState*);
+using PublicContextQueueMicrotask = void (*)(ExecutingContext*, WebFNativeFunctionContext*, SharedExceptionState*);
+using PublicContextGetPerformance = WebFValue<Performance, PerformancePublicMethods> (*)(ExecutingContext*);
+using PublicContextGetScriptValueRefMethods = const ScriptValueRefPublicMethods* (*)();
+using PublicContextGetScriptPromiseRefMethods = const ScriptPromiseRefPublicMethods* (*)();
+using PublicContextRunGC = void (*)(ExecutingContext*);
 // Memory aligned and readable from WebF side.
 // Only C type member can be included in this class, any C++ type and classes can is not allowed to use here.
 struct ExecutingContextWebFMethods {
@@ -87,6 +131,12 @@ struct ExecutingContextWebFMethods {
   static void ClearInterval(ExecutingContext* context,
                             int32_t interval_id,
                             SharedExceptionState* shared_exception_state);
+  static uint32_t RequestAnimationFrame(ExecutingContext* context,
+                                        WebFNativeFunctionContext* callback_context,
+                                        SharedExceptionState* shared_exception_state);
+  static void CancelAnimationFrame(ExecutingContext* context,
+                                   uint32_t request_id,
+                                   SharedExceptionState* shared_exception_state);
   static void AddRustFutureTask(ExecutingContext* context,
                                 WebFNativeFunctionContext* callback_context,
                                 NativeLibrartMetaData* meta_data,
@@ -95,8 +145,36 @@ struct ExecutingContextWebFMethods {
                                    WebFNativeFunctionContext* callback_context,
                                    NativeLibrartMetaData* meta_data,
                                    SharedExceptionState* shared_exception_state);
+  static WebFValue<EventTarget, EventTargetPublicMethods> CreateModuleEventTarget(
+      ExecutingContext* context,
+      const char* module_name,
+      const char* id,
+      SharedExceptionState* shared_exception_state);
+  static void RemoveModuleEventTarget(ExecutingContext* context, const char* module_name, const char* id);
+  static void GetMessageEventData(ExecutingContext* context,
+                                  Event* event,
+                                  WebFMessageEventData* data,
+                                  SharedExceptionState* shared_exception_state);
+  static void Print(ExecutingContext* context, const char* message, const char* level);
+  static WebFValue<MutationObserver, MutationObserverPublicMethods> CreateMutationObserver(
+      ExecutingContext* context,
+      WebFNativeFunctionContext* callback_context,
+      SharedExceptionState* shared_exception_state);
+  // The type is one of DOMParserSupportedType, the returned document doesn't belong to the window.
+  static WebFValue<Document, DocumentPublicMethods> ParseFromString(ExecutingContext* context,
+                                                                    const char* input,
+                                                                    int32_t type,
+                                                                    SharedExceptionState* shared_exception_state);
+  static void QueueMicrotask(ExecutingContext* context,
+                             WebFNativeFunctionContext* callback_context,
+                             SharedExceptionState* shared_exception_state);
+  static WebFValue<Performance, PerformancePublicMethods> performance(ExecutingContext* context);
+  // The tables of the values which are only returned by other methods, so that their versions can be checked up front.
+  static const ScriptValueRefPublicMethods* ScriptValueRefMethods();
+  static const ScriptPromiseRefPublicMethods* ScriptPromiseRefMethods();
+  static void RunGC(ExecutingContext* context);
 
-  double version{1.0};
+  double version{2.0};
   PublicContextGetDocument context_get_document{document};
   PublicContextGetWindow context_get_window{window};
   PublicContextGetExceptionState context_get_exception_state{CreateExceptionState};
@@ -113,6 +191,19 @@ struct ExecutingContextWebFMethods {
   PublicContextClearInterval context_clear_interval{ClearInterval};
   PublicContextAddRustFutureTask context_add_rust_future_task{AddRustFutureTask};
   PublicContextRemoveRustFutureTask context_remove_rust_future_task{RemoveRustFutureTask};
+  PublicContextRequestAnimationFrame context_request_animation_frame{RequestAnimationFrame};
+  PublicContextCancelAnimationFrame context_cancel_animation_frame{CancelAnimationFrame};
+  PublicContextCreateModuleEventTarget context_create_module_event_target{CreateModuleEventTarget};
+  PublicContextRemoveModuleEventTarget context_remove_module_event_target{RemoveModuleEventTarget};
+  PublicContextGetMessageEventData context_get_message_event_data{GetMessageEventData};
+  PublicContextPrint context_print{Print};
+  PublicContextCreateMutationObserver context_create_mutation_observer{CreateMutationObserver};
+  PublicContextParseFromString context_parse_from_string{ParseFromString};
+  PublicContextQueueMicrotask context_queue_microtask{QueueMicrotask};
+  PublicContextGetPerformance context_get_performance{performance};
+  PublicContextGetScriptValueRefMethods context_get_script_value_ref_methods{ScriptValueRefMethods};
+  PublicContextGetScriptPromiseRefMethods context_get_script_promise_ref_methods{ScriptPromiseRefMethods};
+  PublicContextRunGC context_run_gc{RunGC};
 };
 
 }  // namespace webf
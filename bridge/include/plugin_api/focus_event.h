@@ -20,7 +20,7 @@ typedef struct ScriptValueRef ScriptValueRef;
 using PublicFocusEventGetRelatedTarget = WebFValue<EventTarget, EventTargetPublicMethods> (*)(FocusEvent*);
 struct FocusEventPublicMethods : public WebFPublicMethods {
   static WebFValue<EventTarget, EventTargetPublicMethods> RelatedTarget(FocusEvent* focus_event);
-  double version{1.0};
+  double version{2.0};
   UIEventPublicMethods ui_event;
   PublicFocusEventGetRelatedTarget focus_event_get_related_target{RelatedTarget};
 };
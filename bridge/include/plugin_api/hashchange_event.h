@@ -24,7 +24,7 @@ struct HashchangeEventPublicMethods : public WebFPublicMethods {
   static const char* DupNewURL(HashchangeEvent* hashchange_event);
   static const char* OldURL(HashchangeEvent* hashchange_event);
   static const char* DupOldURL(HashchangeEvent* hashchange_event);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicHashchangeEventGetNewURL hashchange_event_get_new_url{NewURL};
   PublicHashchangeEventDupNewURL hashchange_event_dup_new_url{DupNewURL};
@@ -10,7 +10,7 @@
 namespace webf {
 
 struct HTMLCanvasElementPublicMethods : WebFPublicMethods {
-  double version{1.0};
+  double version{2.0};
   HTMLElementPublicMethods html_element_public_methods;
 };
 
@@ -0,0 +1,37 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_HTML_COLLECTION_H_
+#define WEBF_CORE_RUST_API_HTML_COLLECTION_H_
+
+#include "element.h"
+
+namespace webf {
+
+class Element;
+class NodeList;
+
+// The HTMLCollection returned by getElementsByClassName() and getElementsByTagName().
+// The elements are looked up once, so the collection is backed by a static node list which only holds elements.
+using PublicHTMLCollectionGetLength = uint32_t (*)(NodeList*);
+using PublicHTMLCollectionItem = WebFValue<Element, ElementPublicMethods> (*)(NodeList*, uint32_t);
+using PublicHTMLCollectionNamedItem = WebFValue<Element, ElementPublicMethods> (*)(NodeList*, const char*);
+using PublicHTMLCollectionRelease = void (*)(NodeList*);
+
+struct HTMLCollectionPublicMethods : WebFPublicMethods {
+  static uint32_t Length(NodeList* collection);
+  static WebFValue<Element, ElementPublicMethods> Item(NodeList* collection, uint32_t index);
+  static WebFValue<Element, ElementPublicMethods> NamedItem(NodeList* collection, const char* name);
+  static void Release(NodeList* collection);
+
+  double version{2.0};
+  PublicHTMLCollectionGetLength html_collection_get_length{Length};
+  PublicHTMLCollectionItem html_collection_item{Item};
+  PublicHTMLCollectionNamedItem html_collection_named_item{NamedItem};
+  PublicHTMLCollectionRelease html_collection_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_HTML_COLLECTION_H_
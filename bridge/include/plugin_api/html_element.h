@@ -10,7 +10,7 @@
 namespace webf {
 
 struct HTMLElementPublicMethods : WebFPublicMethods {
-  double version{1.0};
+  double version{2.0};
   ElementPublicMethods element_public_methods;
 };
 
@@ -19,17 +19,20 @@ using PublicInputEventGetInputType = const char* (*)(InputEvent*);
 using PublicInputEventDupInputType = const char* (*)(InputEvent*);
 using PublicInputEventGetData = const char* (*)(InputEvent*);
 using PublicInputEventDupData = const char* (*)(InputEvent*);
+using PublicInputEventGetIsComposing = int32_t (*)(InputEvent*);
 struct InputEventPublicMethods : public WebFPublicMethods {
   static const char* InputType(InputEvent* input_event);
   static const char* DupInputType(InputEvent* input_event);
   static const char* Data(InputEvent* input_event);
   static const char* DupData(InputEvent* input_event);
-  double version{1.0};
+  static int32_t IsComposing(InputEvent* input_event);
+  double version{2.0};
   UIEventPublicMethods ui_event;
   PublicInputEventGetInputType input_event_get_input_type{InputType};
   PublicInputEventDupInputType input_event_dup_input_type{DupInputType};
   PublicInputEventGetData input_event_get_data{Data};
   PublicInputEventDupData input_event_dup_data{DupData};
+  PublicInputEventGetIsComposing input_event_get_is_composing{IsComposing};
 };
 }  // namespace webf
 #endif  // WEBF_CORE_WEBF_API_PLUGIN_API_INPUT_EVENT_H_
@@ -17,6 +17,7 @@ struct WebFInputEventInit {
   double which;
   const char* input_type;
   const char* data;
+  int32_t is_composing;
 };
 }  // namespace webf
 #endif  // WEBF_CORE_WEBF_API_PLUGIN_API_INPUT_EVENT_INIT_H_
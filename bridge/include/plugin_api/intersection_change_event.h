@@ -18,7 +18,7 @@ typedef struct ScriptValueRef ScriptValueRef;
 using PublicIntersectionChangeEventGetIntersectionRatio = double (*)(IntersectionChangeEvent*);
 struct IntersectionChangeEventPublicMethods : public WebFPublicMethods {
   static double IntersectionRatio(IntersectionChangeEvent* intersection_change_event);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicIntersectionChangeEventGetIntersectionRatio intersection_change_event_get_intersection_ratio{IntersectionRatio};
 };
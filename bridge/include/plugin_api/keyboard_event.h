@@ -0,0 +1,62 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+// clang-format off
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+#ifndef WEBF_CORE_WEBF_API_PLUGIN_API_KEYBOARD_EVENT_H_
+#define WEBF_CORE_WEBF_API_PLUGIN_API_KEYBOARD_EVENT_H_
+#include <stdint.h>
+#include "rust_readable.h"
+#include "script_value_ref.h"
+#include "ui_event.h"
+namespace webf {
+class SharedExceptionState;
+class ExecutingContext;
+class KeyboardEvent;
+typedef struct ScriptValueRef ScriptValueRef;
+using PublicKeyboardEventGetAltKey = int32_t (*)(KeyboardEvent*);
+using PublicKeyboardEventGetCharCode = double (*)(KeyboardEvent*);
+using PublicKeyboardEventGetCode = const char* (*)(KeyboardEvent*);
+using PublicKeyboardEventDupCode = const char* (*)(KeyboardEvent*);
+using PublicKeyboardEventGetCtrlKey = int32_t (*)(KeyboardEvent*);
+using PublicKeyboardEventGetIsComposing = int32_t (*)(KeyboardEvent*);
+using PublicKeyboardEventGetKey = const char* (*)(KeyboardEvent*);
+using PublicKeyboardEventDupKey = const char* (*)(KeyboardEvent*);
+using PublicKeyboardEventGetKeyCode = double (*)(KeyboardEvent*);
+using PublicKeyboardEventGetLocation = double (*)(KeyboardEvent*);
+using PublicKeyboardEventGetMetaKey = int32_t (*)(KeyboardEvent*);
+using PublicKeyboardEventGetRepeat = int32_t (*)(KeyboardEvent*);
+using PublicKeyboardEventGetShiftKey = int32_t (*)(KeyboardEvent*);
+struct KeyboardEventPublicMethods : public WebFPublicMethods {
+  static int32_t AltKey(KeyboardEvent* keyboard_event);
+  static double CharCode(KeyboardEvent* keyboard_event);
+  static const char* Code(KeyboardEvent* keyboard_event);
+  static const char* DupCode(KeyboardEvent* keyboard_event);
+  static int32_t CtrlKey(KeyboardEvent* keyboard_event);
+  static int32_t IsComposing(KeyboardEvent* keyboard_event);
+  static const char* Key(KeyboardEvent* keyboard_event);
+  static const char* DupKey(KeyboardEvent* keyboard_event);
+  static double KeyCode(KeyboardEvent* keyboard_event);
+  static double Location(KeyboardEvent* keyboard_event);
+  static int32_t MetaKey(KeyboardEvent* keyboard_event);
+  static int32_t Repeat(KeyboardEvent* keyboard_event);
+  static int32_t ShiftKey(KeyboardEvent* keyboard_event);
+  double version{2.0};
+  UIEventPublicMethods ui_event;
+  PublicKeyboardEventGetAltKey keyboard_event_get_alt_key{AltKey};
+  PublicKeyboardEventGetCharCode keyboard_event_get_char_code{CharCode};
+  PublicKeyboardEventGetCode keyboard_event_get_code{Code};
+  PublicKeyboardEventDupCode keyboard_event_dup_code{DupCode};
+  PublicKeyboardEventGetCtrlKey keyboard_event_get_ctrl_key{CtrlKey};
+  PublicKeyboardEventGetIsComposing keyboard_event_get_is_composing{IsComposing};
+  PublicKeyboardEventGetKey keyboard_event_get_key{Key};
+  PublicKeyboardEventDupKey keyboard_event_dup_key{DupKey};
+  PublicKeyboardEventGetKeyCode keyboard_event_get_key_code{KeyCode};
+  PublicKeyboardEventGetLocation keyboard_event_get_location{Location};
+  PublicKeyboardEventGetMetaKey keyboard_event_get_meta_key{MetaKey};
+  PublicKeyboardEventGetRepeat keyboard_event_get_repeat{Repeat};
+  PublicKeyboardEventGetShiftKey keyboard_event_get_shift_key{ShiftKey};
+};
+}  // namespace webf
+#endif  // WEBF_CORE_WEBF_API_PLUGIN_API_KEYBOARD_EVENT_H_
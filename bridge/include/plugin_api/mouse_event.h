@@ -15,21 +15,63 @@ class SharedExceptionState;
 class ExecutingContext;
 class MouseEvent;
 typedef struct ScriptValueRef ScriptValueRef;
+using PublicMouseEventGetAltKey = int32_t (*)(MouseEvent*);
+using PublicMouseEventGetButton = double (*)(MouseEvent*);
+using PublicMouseEventGetButtons = double (*)(MouseEvent*);
 using PublicMouseEventGetClientX = double (*)(MouseEvent*);
 using PublicMouseEventGetClientY = double (*)(MouseEvent*);
+using PublicMouseEventGetCtrlKey = int32_t (*)(MouseEvent*);
+using PublicMouseEventGetMetaKey = int32_t (*)(MouseEvent*);
+using PublicMouseEventGetMovementX = double (*)(MouseEvent*);
+using PublicMouseEventGetMovementY = double (*)(MouseEvent*);
 using PublicMouseEventGetOffsetX = double (*)(MouseEvent*);
 using PublicMouseEventGetOffsetY = double (*)(MouseEvent*);
+using PublicMouseEventGetPageX = double (*)(MouseEvent*);
+using PublicMouseEventGetPageY = double (*)(MouseEvent*);
+using PublicMouseEventGetScreenX = double (*)(MouseEvent*);
+using PublicMouseEventGetScreenY = double (*)(MouseEvent*);
+using PublicMouseEventGetShiftKey = int32_t (*)(MouseEvent*);
+using PublicMouseEventGetX = double (*)(MouseEvent*);
+using PublicMouseEventGetY = double (*)(MouseEvent*);
 struct MouseEventPublicMethods : public WebFPublicMethods {
+  static int32_t AltKey(MouseEvent* mouse_event);
+  static double Button(MouseEvent* mouse_event);
+  static double Buttons(MouseEvent* mouse_event);
   static double ClientX(MouseEvent* mouse_event);
   static double ClientY(MouseEvent* mouse_event);
+  static int32_t CtrlKey(MouseEvent* mouse_event);
+  static int32_t MetaKey(MouseEvent* mouse_event);
+  static double MovementX(MouseEvent* mouse_event);
+  static double MovementY(MouseEvent* mouse_event);
   static double OffsetX(MouseEvent* mouse_event);
   static double OffsetY(MouseEvent* mouse_event);
-  double version{1.0};
+  static double PageX(MouseEvent* mouse_event);
+  static double PageY(MouseEvent* mouse_event);
+  static double ScreenX(MouseEvent* mouse_event);
+  static double ScreenY(MouseEvent* mouse_event);
+  static int32_t ShiftKey(MouseEvent* mouse_event);
+  static double X(MouseEvent* mouse_event);
+  static double Y(MouseEvent* mouse_event);
+  double version{2.0};
   UIEventPublicMethods ui_event;
+  PublicMouseEventGetAltKey mouse_event_get_alt_key{AltKey};
+  PublicMouseEventGetButton mouse_event_get_button{Button};
+  PublicMouseEventGetButtons mouse_event_get_buttons{Buttons};
   PublicMouseEventGetClientX mouse_event_get_client_x{ClientX};
   PublicMouseEventGetClientY mouse_event_get_client_y{ClientY};
+  PublicMouseEventGetCtrlKey mouse_event_get_ctrl_key{CtrlKey};
+  PublicMouseEventGetMetaKey mouse_event_get_meta_key{MetaKey};
+  PublicMouseEventGetMovementX mouse_event_get_movement_x{MovementX};
+  PublicMouseEventGetMovementY mouse_event_get_movement_y{MovementY};
   PublicMouseEventGetOffsetX mouse_event_get_offset_x{OffsetX};
   PublicMouseEventGetOffsetY mouse_event_get_offset_y{OffsetY};
+  PublicMouseEventGetPageX mouse_event_get_page_x{PageX};
+  PublicMouseEventGetPageY mouse_event_get_page_y{PageY};
+  PublicMouseEventGetScreenX mouse_event_get_screen_x{ScreenX};
+  PublicMouseEventGetScreenY mouse_event_get_screen_y{ScreenY};
+  PublicMouseEventGetShiftKey mouse_event_get_shift_key{ShiftKey};
+  PublicMouseEventGetX mouse_event_get_x{X};
+  PublicMouseEventGetY mouse_event_get_y{Y};
 };
 }  // namespace webf
 #endif  // WEBF_CORE_WEBF_API_PLUGIN_API_MOUSE_EVENT_H_
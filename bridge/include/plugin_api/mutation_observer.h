@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_MUTATION_OBSERVER_H_
+#define WEBF_CORE_RUST_API_MUTATION_OBSERVER_H_
+
+#include "node.h"
+#include "node_list.h"
+
+namespace webf {
+
+class MutationObserver;
+class Node;
+class NodeList;
+class SharedExceptionState;
+
+// The options of MutationObserver.observe(). Only the members set to true are forwarded,
+// so omitted options are implied the same as in JavaScript.
+struct WebFMutationObserverInit {
+  int32_t child_list;
+  int32_t attributes;
+  int32_t character_data;
+  int32_t subtree;
+  int32_t attribute_old_value;
+  int32_t character_data_old_value;
+  // Null when the attributes are not filtered.
+  const char** attribute_filter;
+  int64_t attribute_filter_length;
+};
+
+// A MutationRecord which is readable from the Rust side. The strings are owned by the caller.
+struct WebFMutationRecord {
+  char* type;
+  WebFValue<Node, NodePublicMethods> target;
+  WebFValue<NodeList, NodeListPublicMethods> added_nodes;
+  WebFValue<NodeList, NodeListPublicMethods> removed_nodes;
+  WebFValue<Node, NodePublicMethods> previous_sibling;
+  WebFValue<Node, NodePublicMethods> next_sibling;
+  char* attribute_name;
+  char* old_value;
+};
+
+using PublicMutationObserverObserve = void (*)(MutationObserver*,
+                                               Node*,
+                                               WebFMutationObserverInit*,
+                                               SharedExceptionState*);
+using PublicMutationObserverTakeRecords = WebFMutationRecord* (*)(MutationObserver*, int64_t*, SharedExceptionState*);
+using PublicMutationObserverDisconnect = void (*)(MutationObserver*, SharedExceptionState*);
+using PublicMutationObserverRelease = void (*)(MutationObserver*);
+
+struct MutationObserverPublicMethods : WebFPublicMethods {
+  static void Observe(MutationObserver* observer,
+                      Node* target,
+                      WebFMutationObserverInit* init,
+                      SharedExceptionState* shared_exception_state);
+  // The returned records are owned by the caller.
+  static WebFMutationRecord* TakeRecords(MutationObserver* observer,
+                                         int64_t* length,
+                                         SharedExceptionState* shared_exception_state);
+  static void Disconnect(MutationObserver* observer, SharedExceptionState* shared_exception_state);
+  static void Release(MutationObserver* observer);
+
+  double version{2.0};
+  PublicMutationObserverObserve mutation_observer_observe{Observe};
+  PublicMutationObserverTakeRecords mutation_observer_take_records{TakeRecords};
+  PublicMutationObserverDisconnect mutation_observer_disconnect{Disconnect};
+  PublicMutationObserverRelease mutation_observer_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_MUTATION_OBSERVER_H_
@@ -14,30 +14,108 @@ class Node;
 class SharedExceptionState;
 class ExecutingContext;
 class Event;
+class NodeList;
 
 struct NodePublicMethods;
+struct NodeListPublicMethods;
 
 using PublicNodeAppendChild = WebFValue<Node, NodePublicMethods> (*)(Node* self_node,
                                                                      Node* new_node,
                                                                      SharedExceptionState* shared_exception_state);
 
+using PublicNodeAppendChildren = void (*)(Node* self_node,
+                                          Node** nodes,
+                                          int64_t length,
+                                          SharedExceptionState* shared_exception_state);
+
+using PublicNodeCloneNode = WebFValue<Node, NodePublicMethods> (*)(Node* self_node,
+                                                                   int32_t deep,
+                                                                   SharedExceptionState* shared_exception_state);
+
+using PublicNodeContains = int32_t (*)(Node* self_node, Node* other_node);
+
+using PublicNodeCompareDocumentPosition = uint16_t (*)(Node* self_node, Node* other_node);
+
 using PublicNodeRemoveChild = WebFValue<Node, NodePublicMethods> (*)(Node* self_node,
                                                                      Node* target_node,
                                                                      SharedExceptionState* shared_exception_state);
 
+using PublicNodeInsertBefore = WebFValue<Node, NodePublicMethods> (*)(Node* self_node,
+                                                                      Node* new_node,
+                                                                      Node* reference_node,
+                                                                      SharedExceptionState* shared_exception_state);
+
+using PublicNodeReplaceChild = WebFValue<Node, NodePublicMethods> (*)(Node* self_node,
+                                                                      Node* new_node,
+                                                                      Node* old_node,
+                                                                      SharedExceptionState* shared_exception_state);
+
+using PublicNodeGetTextContent = char* (*)(Node* self_node, SharedExceptionState* shared_exception_state);
+
+using PublicNodeSetTextContent = void (*)(Node* self_node,
+                                          const char* text,
+                                          SharedExceptionState* shared_exception_state);
+
+using PublicNodeGetRelativeNode = WebFValue<Node, NodePublicMethods> (*)(Node* self_node);
+
+using PublicNodeNormalize = void (*)(Node* self_node, SharedExceptionState* shared_exception_state);
+
+using PublicNodeGetChildNodes = WebFValue<NodeList, NodeListPublicMethods> (*)(Node* self_node);
+
 struct NodePublicMethods : WebFPublicMethods {
   explicit NodePublicMethods();
 
   static WebFValue<Node, NodePublicMethods> AppendChild(Node* self_node,
                                                         Node* new_node,
                                                         SharedExceptionState* shared_exception_state);
+  static void AppendChildren(Node* self_node,
+                             Node** nodes,
+                             int64_t length,
+                             SharedExceptionState* shared_exception_state);
+  static WebFValue<Node, NodePublicMethods> CloneNode(Node* self_node,
+                                                      int32_t deep,
+                                                      SharedExceptionState* shared_exception_state);
+  static int32_t Contains(Node* self_node, Node* other_node);
+  static uint16_t CompareDocumentPosition(Node* self_node, Node* other_node);
   static WebFValue<Node, NodePublicMethods> RemoveChild(Node* self_node,
                                                         Node* target_node,
                                                         SharedExceptionState* shared_exception_state);
-  double version{1.0};
+  static WebFValue<Node, NodePublicMethods> InsertBefore(Node* self_node,
+                                                         Node* new_node,
+                                                         Node* reference_node,
+                                                         SharedExceptionState* shared_exception_state);
+  static WebFValue<Node, NodePublicMethods> ReplaceChild(Node* self_node,
+                                                         Node* new_node,
+                                                         Node* old_node,
+                                                         SharedExceptionState* shared_exception_state);
+  static char* TextContent(Node* self_node, SharedExceptionState* shared_exception_state);
+  static void SetTextContent(Node* self_node, const char* text, SharedExceptionState* shared_exception_state);
+  static WebFValue<Node, NodePublicMethods> FirstChild(Node* self_node);
+  static WebFValue<Node, NodePublicMethods> LastChild(Node* self_node);
+  static WebFValue<Node, NodePublicMethods> ParentNode(Node* self_node);
+  static WebFValue<Node, NodePublicMethods> NextSibling(Node* self_node);
+  static WebFValue<Node, NodePublicMethods> PreviousSibling(Node* self_node);
+  static WebFValue<NodeList, NodeListPublicMethods> ChildNodes(Node* self_node);
+  static void Normalize(Node* self_node, SharedExceptionState* shared_exception_state);
+  double version{2.0};
   EventTargetPublicMethods event_target;
   PublicNodeAppendChild rust_node_append_child{AppendChild};
   PublicNodeRemoveChild public_node_remove_child{RemoveChild};
+  PublicNodeGetTextContent public_node_get_text_content{TextContent};
+  PublicNodeSetTextContent public_node_set_text_content{SetTextContent};
+  PublicNodeInsertBefore public_node_insert_before{InsertBefore};
+  PublicNodeReplaceChild public_node_replace_child{ReplaceChild};
+  PublicNodeGetRelativeNode public_node_get_first_child{FirstChild};
+  PublicNodeGetRelativeNode public_node_get_last_child{LastChild};
+  PublicNodeGetRelativeNode public_node_get_parent_node{ParentNode};
+  PublicNodeGetRelativeNode public_node_get_next_sibling{NextSibling};
+  PublicNodeGetRelativeNode public_node_get_previous_sibling{PreviousSibling};
+  PublicNodeGetChildNodes public_node_get_child_nodes{ChildNodes};
+  PublicNodeAppendChildren public_node_append_children{AppendChildren};
+  PublicNodeCloneNode public_node_clone_node{CloneNode};
+  PublicNodeContains public_node_contains{Contains};
+  PublicNodeCompareDocumentPosition public_node_compare_document_position{CompareDocumentPosition};
+  PublicNodeNormalize public_node_normalize{Normalize};
 };
 
 }  // namespace webf
@@ -0,0 +1,33 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_NODE_LIST_H_
+#define WEBF_CORE_RUST_API_NODE_LIST_H_
+
+#include "node.h"
+
+namespace webf {
+
+class Node;
+class NodeList;
+class SharedExceptionState;
+
+using PublicNodeListGetLength = uint32_t (*)(NodeList*);
+using PublicNodeListItem = WebFValue<Node, NodePublicMethods> (*)(NodeList*, uint32_t);
+using PublicNodeListRelease = void (*)(NodeList*);
+
+struct NodeListPublicMethods : WebFPublicMethods {
+  static uint32_t Length(NodeList* node_list);
+  static WebFValue<Node, NodePublicMethods> Item(NodeList* node_list, uint32_t index);
+  static void Release(NodeList* node_list);
+
+  double version{2.0};
+  PublicNodeListGetLength node_list_get_length{Length};
+  PublicNodeListItem node_list_item{Item};
+  PublicNodeListRelease node_list_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_NODE_LIST_H_
@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_PERFORMANCE_H_
+#define WEBF_CORE_RUST_API_PERFORMANCE_H_
+
+#include <cstdint>
+#include "webf_value.h"
+
+namespace webf {
+
+class Performance;
+class SharedExceptionState;
+
+using PublicPerformanceNow = double (*)(Performance*);
+using PublicPerformanceGetTimeOrigin = double (*)(Performance*);
+using PublicPerformanceMark = void (*)(Performance*, const char*, SharedExceptionState*);
+using PublicPerformanceMeasure = double (*)(Performance*, const char*, const char*, const char*, SharedExceptionState*);
+using PublicPerformanceRelease = void (*)(Performance*);
+
+struct PerformancePublicMethods : WebFPublicMethods {
+  static double Now(Performance* performance);
+  static double TimeOrigin(Performance* performance);
+  static void Mark(Performance* performance, const char* name, SharedExceptionState* shared_exception_state);
+  // The marks are optional and nullptr when omitted, returns the duration of the created measure.
+  static double Measure(Performance* performance,
+                        const char* name,
+                        const char* start_mark,
+                        const char* end_mark,
+                        SharedExceptionState* shared_exception_state);
+  static void Release(Performance* performance);
+
+  double version{2.0};
+  PublicPerformanceNow performance_now{Now};
+  PublicPerformanceGetTimeOrigin performance_get_time_origin{TimeOrigin};
+  PublicPerformanceMark performance_mark{Mark};
+  PublicPerformanceMeasure performance_measure{Measure};
+  PublicPerformanceRelease performance_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_PERFORMANCE_H_
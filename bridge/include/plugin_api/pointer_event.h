@@ -38,7 +38,7 @@ struct PointerEventPublicMethods : public WebFPublicMethods {
   static double TiltY(PointerEvent* pointer_event);
   static double Twist(PointerEvent* pointer_event);
   static double Width(PointerEvent* pointer_event);
-  double version{1.0};
+  double version{2.0};
   MouseEventPublicMethods mouse_event;
   PublicPointerEventGetHeight pointer_event_get_height{Height};
   PublicPointerEventGetIsPrimary pointer_event_get_is_primary{IsPrimary};
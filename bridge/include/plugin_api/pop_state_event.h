@@ -0,0 +1,26 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+// clang-format off
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+#ifndef WEBF_CORE_WEBF_API_PLUGIN_API_POP_STATE_EVENT_H_
+#define WEBF_CORE_WEBF_API_PLUGIN_API_POP_STATE_EVENT_H_
+#include <stdint.h>
+#include "rust_readable.h"
+#include "script_value_ref.h"
+#include "event.h"
+namespace webf {
+class SharedExceptionState;
+class ExecutingContext;
+class PopStateEvent;
+typedef struct ScriptValueRef ScriptValueRef;
+using PublicPopStateEventGetState = WebFValue<ScriptValueRef, ScriptValueRefPublicMethods> (*)(PopStateEvent*);
+struct PopStateEventPublicMethods : public WebFPublicMethods {
+  static WebFValue<ScriptValueRef, ScriptValueRefPublicMethods> State(PopStateEvent* pop_state_event);
+  double version{2.0};
+  EventPublicMethods event;
+  PublicPopStateEventGetState pop_state_event_get_state{State};
+};
+}  // namespace webf
+#endif  // WEBF_CORE_WEBF_API_PLUGIN_API_POP_STATE_EVENT_H_
@@ -0,0 +1,33 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_INCLUDE_PLUGIN_API_SCRIPT_PROMISE_REF_H_
+#define WEBF_INCLUDE_PLUGIN_API_SCRIPT_PROMISE_REF_H_
+
+#include "webf_value.h"
+
+namespace webf {
+
+typedef struct ScriptPromiseRef ScriptPromiseRef;
+typedef struct WebFNativeFunctionContext WebFNativeFunctionContext;
+class SharedExceptionState;
+
+using PublicScriptPromiseRefThen = void (*)(ScriptPromiseRef*, WebFNativeFunctionContext*, SharedExceptionState*);
+using PublicScriptPromiseRefRelease = void (*)(ScriptPromiseRef*);
+
+struct ScriptPromiseRefPublicMethods : WebFPublicMethods {
+  // Invokes the callback once the promise settles, with (reason) when rejected and (null, value) when fulfilled.
+  static void Then(ScriptPromiseRef* script_promise_ref,
+                   WebFNativeFunctionContext* callback_context,
+                   SharedExceptionState* shared_exception_state);
+  static void Release(ScriptPromiseRef* script_promise_ref);
+
+  double version{2.0};
+  PublicScriptPromiseRefThen then{Then};
+  PublicScriptPromiseRefRelease release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_INCLUDE_PLUGIN_API_SCRIPT_PROMISE_REF_H_
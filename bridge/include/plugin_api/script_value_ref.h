@@ -15,6 +15,7 @@ class SharedExceptionState;
 using PublicScriptValueRefToString = const char* (*)(ScriptValueRef*, SharedExceptionState*);
 using PublicScriptValueRefSetAsString = void (*)(ScriptValueRef*, const char*, SharedExceptionState*);
 using PublicScriptValueRefRelease = void (*)(ScriptValueRef*);
+using PublicScriptValueRefToJSON = char* (*)(ScriptValueRef*, SharedExceptionState*);
 
 struct ScriptValueRefPublicMethods : WebFPublicMethods {
   static const char* ToString(ScriptValueRef* script_value_ref, SharedExceptionState* shared_exception_state);
@@ -22,9 +23,13 @@ struct ScriptValueRefPublicMethods : WebFPublicMethods {
                           const char* value,
                           SharedExceptionState* shared_exception_state);
   static void Release(ScriptValueRef* script_value_ref);
+  static char* ToJSON(ScriptValueRef* script_value_ref, SharedExceptionState* shared_exception_state);
+
+  double version{2.0};
   PublicScriptValueRefToString to_string{ToString};
   PublicScriptValueRefSetAsString set_as_string{SetAsString};
   PublicScriptValueRefRelease release{Release};
+  PublicScriptValueRefToJSON to_json{ToJSON};
 };
 
 }  // namespace webf
@@ -0,0 +1,50 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+// clang-format off
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+#ifndef WEBF_CORE_WEBF_API_PLUGIN_API_STORAGE_EVENT_H_
+#define WEBF_CORE_WEBF_API_PLUGIN_API_STORAGE_EVENT_H_
+#include <stdint.h>
+#include "rust_readable.h"
+#include "script_value_ref.h"
+#include "event.h"
+namespace webf {
+class SharedExceptionState;
+class ExecutingContext;
+class StorageEvent;
+typedef struct ScriptValueRef ScriptValueRef;
+using PublicStorageEventGetKey = const char* (*)(StorageEvent*);
+using PublicStorageEventDupKey = const char* (*)(StorageEvent*);
+using PublicStorageEventGetOldValue = const char* (*)(StorageEvent*);
+using PublicStorageEventDupOldValue = const char* (*)(StorageEvent*);
+using PublicStorageEventGetNewValue = const char* (*)(StorageEvent*);
+using PublicStorageEventDupNewValue = const char* (*)(StorageEvent*);
+using PublicStorageEventGetUrl = const char* (*)(StorageEvent*);
+using PublicStorageEventDupUrl = const char* (*)(StorageEvent*);
+using PublicStorageEventGetStorageArea = WebFValue<ScriptValueRef, ScriptValueRefPublicMethods> (*)(StorageEvent*);
+struct StorageEventPublicMethods : public WebFPublicMethods {
+  static const char* Key(StorageEvent* storage_event);
+  static const char* DupKey(StorageEvent* storage_event);
+  static const char* OldValue(StorageEvent* storage_event);
+  static const char* DupOldValue(StorageEvent* storage_event);
+  static const char* NewValue(StorageEvent* storage_event);
+  static const char* DupNewValue(StorageEvent* storage_event);
+  static const char* Url(StorageEvent* storage_event);
+  static const char* DupUrl(StorageEvent* storage_event);
+  static WebFValue<ScriptValueRef, ScriptValueRefPublicMethods> StorageArea(StorageEvent* storage_event);
+  double version{2.0};
+  EventPublicMethods event;
+  PublicStorageEventGetKey storage_event_get_key{Key};
+  PublicStorageEventDupKey storage_event_dup_key{DupKey};
+  PublicStorageEventGetOldValue storage_event_get_old_value{OldValue};
+  PublicStorageEventDupOldValue storage_event_dup_old_value{DupOldValue};
+  PublicStorageEventGetNewValue storage_event_get_new_value{NewValue};
+  PublicStorageEventDupNewValue storage_event_dup_new_value{DupNewValue};
+  PublicStorageEventGetUrl storage_event_get_url{Url};
+  PublicStorageEventDupUrl storage_event_dup_url{DupUrl};
+  PublicStorageEventGetStorageArea storage_event_get_storage_area{StorageArea};
+};
+}  // namespace webf
+#endif  // WEBF_CORE_WEBF_API_PLUGIN_API_STORAGE_EVENT_H_
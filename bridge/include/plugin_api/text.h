@@ -13,10 +13,23 @@ class EventTarget;
 class SharedExceptionState;
 class ExecutingContext;
 class Event;
+class Text;
+typedef struct TextNodePublicMethods TextNodePublicMethods;
+
+using PublicTextSplitText = WebFValue<Text, TextNodePublicMethods> (*)(Text*, int64_t, SharedExceptionState*);
+using PublicTextGetWholeText = char* (*)(Text*, SharedExceptionState*);
 
 struct TextNodePublicMethods : WebFPublicMethods {
-  double version{1.0};
+  static WebFValue<Text, TextNodePublicMethods> SplitText(Text* text,
+                                                          int64_t offset,
+                                                          SharedExceptionState* shared_exception_state);
+  // The returned string is owned by the caller.
+  static char* WholeText(Text* text, SharedExceptionState* shared_exception_state);
+
+  double version{2.0};
   CharacterDataPublicMethods character_data;
+  PublicTextSplitText text_split_text{SplitText};
+  PublicTextGetWholeText text_get_whole_text{WholeText};
 };
 
 }  // namespace webf
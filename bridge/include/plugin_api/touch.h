@@ -0,0 +1,59 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_TOUCH_H_
+#define WEBF_CORE_RUST_API_TOUCH_H_
+
+#include "event_target.h"
+
+namespace webf {
+
+class EventTarget;
+class Touch;
+
+using PublicTouchGetIdentifier = int64_t (*)(Touch*);
+using PublicTouchGetClientX = double (*)(Touch*);
+using PublicTouchGetClientY = double (*)(Touch*);
+using PublicTouchGetPageX = double (*)(Touch*);
+using PublicTouchGetPageY = double (*)(Touch*);
+using PublicTouchGetScreenX = double (*)(Touch*);
+using PublicTouchGetScreenY = double (*)(Touch*);
+using PublicTouchGetRadiusX = double (*)(Touch*);
+using PublicTouchGetRadiusY = double (*)(Touch*);
+using PublicTouchGetForce = double (*)(Touch*);
+using PublicTouchGetTarget = WebFValue<EventTarget, EventTargetPublicMethods> (*)(Touch*);
+using PublicTouchRelease = void (*)(Touch*);
+
+struct TouchPublicMethods : WebFPublicMethods {
+  static int64_t Identifier(Touch* touch);
+  static double ClientX(Touch* touch);
+  static double ClientY(Touch* touch);
+  static double PageX(Touch* touch);
+  static double PageY(Touch* touch);
+  static double ScreenX(Touch* touch);
+  static double ScreenY(Touch* touch);
+  static double RadiusX(Touch* touch);
+  static double RadiusY(Touch* touch);
+  static double Force(Touch* touch);
+  static WebFValue<EventTarget, EventTargetPublicMethods> Target(Touch* touch);
+  static void Release(Touch* touch);
+
+  double version{2.0};
+  PublicTouchGetIdentifier touch_get_identifier{Identifier};
+  PublicTouchGetClientX touch_get_client_x{ClientX};
+  PublicTouchGetClientY touch_get_client_y{ClientY};
+  PublicTouchGetPageX touch_get_page_x{PageX};
+  PublicTouchGetPageY touch_get_page_y{PageY};
+  PublicTouchGetScreenX touch_get_screen_x{ScreenX};
+  PublicTouchGetScreenY touch_get_screen_y{ScreenY};
+  PublicTouchGetRadiusX touch_get_radius_x{RadiusX};
+  PublicTouchGetRadiusY touch_get_radius_y{RadiusY};
+  PublicTouchGetForce touch_get_force{Force};
+  PublicTouchGetTarget touch_get_target{Target};
+  PublicTouchRelease touch_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_TOUCH_H_
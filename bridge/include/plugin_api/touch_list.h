@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+
+#ifndef WEBF_CORE_RUST_API_TOUCH_LIST_H_
+#define WEBF_CORE_RUST_API_TOUCH_LIST_H_
+
+#include "touch.h"
+
+namespace webf {
+
+class Touch;
+class TouchList;
+
+using PublicTouchListGetLength = uint32_t (*)(TouchList*);
+using PublicTouchListItem = WebFValue<Touch, TouchPublicMethods> (*)(TouchList*, uint32_t);
+using PublicTouchListRelease = void (*)(TouchList*);
+
+struct TouchListPublicMethods : WebFPublicMethods {
+  static uint32_t Length(TouchList* touch_list);
+  static WebFValue<Touch, TouchPublicMethods> Item(TouchList* touch_list, uint32_t index);
+  static void Release(TouchList* touch_list);
+
+  double version{2.0};
+  PublicTouchListGetLength touch_list_get_length{Length};
+  PublicTouchListItem touch_list_item{Item};
+  PublicTouchListRelease touch_list_release{Release};
+};
+
+}  // namespace webf
+
+#endif  // WEBF_CORE_RUST_API_TOUCH_LIST_H_
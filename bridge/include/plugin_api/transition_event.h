@@ -26,7 +26,7 @@ struct TransitionEventPublicMethods : public WebFPublicMethods {
   static const char* DupPropertyName(TransitionEvent* transition_event);
   static const char* PseudoElement(TransitionEvent* transition_event);
   static const char* DupPseudoElement(TransitionEvent* transition_event);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicTransitionEventGetElapsedTime transition_event_get_elapsed_time{ElapsedTime};
   PublicTransitionEventGetPropertyName transition_event_get_property_name{PropertyName};
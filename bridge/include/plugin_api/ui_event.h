@@ -24,7 +24,7 @@ struct UIEventPublicMethods : public WebFPublicMethods {
   static double Detail(UIEvent* ui_event);
   static WebFValue<Window, WindowPublicMethods> View(UIEvent* ui_event);
   static double Which(UIEvent* ui_event);
-  double version{1.0};
+  double version{2.0};
   EventPublicMethods event;
   PublicUIEventGetDetail ui_event_get_detail{Detail};
   PublicUIEventGetView ui_event_get_view{View};
@@ -27,6 +27,8 @@ struct WebFEventListenerContext : public RustReadable {
   WebFImplEventCallback callback;
   FreePtrFn free_ptr;
   void* ptr;
+  // Listeners registered with a non-zero id can only be matched by a context carrying the same id.
+  uint32_t listener_id;
 };
 
 class WebFPublicPluginEventListener : public EventListener {
@@ -56,7 +58,8 @@ class WebFPublicPluginEventListener : public EventListener {
   [[nodiscard]] bool Matches(const EventListener& other) const override {
     const auto* other_listener = DynamicTo<WebFPublicPluginEventListener>(other);
     return other_listener && other_listener->callback_context_ &&
-           other_listener->callback_context_->callback == callback_context_->callback;
+           other_listener->callback_context_->callback == callback_context_->callback &&
+           other_listener->callback_context_->listener_id == callback_context_->listener_id;
   }
 
   void Trace(GCVisitor* visitor) const override {}
@@ -0,0 +1,35 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+// clang-format off
+/*
+ * Copyright (C) 2022-present The WebF authors. All rights reserved.
+ */
+#ifndef WEBF_CORE_WEBF_API_PLUGIN_API_WHEEL_EVENT_H_
+#define WEBF_CORE_WEBF_API_PLUGIN_API_WHEEL_EVENT_H_
+#include <stdint.h>
+#include "rust_readable.h"
+#include "script_value_ref.h"
+#include "mouse_event.h"
+namespace webf {
+class SharedExceptionState;
+class ExecutingContext;
+class WheelEvent;
+typedef struct ScriptValueRef ScriptValueRef;
+using PublicWheelEventGetDeltaX = double (*)(WheelEvent*);
+using PublicWheelEventGetDeltaY = double (*)(WheelEvent*);
+using PublicWheelEventGetDeltaZ = double (*)(WheelEvent*);
+using PublicWheelEventGetDeltaMode = double (*)(WheelEvent*);
+struct WheelEventPublicMethods : public WebFPublicMethods {
+  static double DeltaX(WheelEvent* wheel_event);
+  static double DeltaY(WheelEvent* wheel_event);
+  static double DeltaZ(WheelEvent* wheel_event);
+  static double DeltaMode(WheelEvent* wheel_event);
+  double version{2.0};
+  MouseEventPublicMethods mouse_event;
+  PublicWheelEventGetDeltaX wheel_event_get_delta_x{DeltaX};
+  PublicWheelEventGetDeltaY wheel_event_get_delta_y{DeltaY};
+  PublicWheelEventGetDeltaZ wheel_event_get_delta_z{DeltaZ};
+  PublicWheelEventGetDeltaMode wheel_event_get_delta_mode{DeltaMode};
+};
+}  // namespace webf
+#endif  // WEBF_CORE_WEBF_API_PLUGIN_API_WHEEL_EVENT_H_
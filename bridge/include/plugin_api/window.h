@@ -5,6 +5,7 @@
 #ifndef WEBF_CORE_RUST_API_WINDOW_H_
 #define WEBF_CORE_RUST_API_WINDOW_H_
 
+#include "css_style_declaration.h"
 #include "event_target.h"
 
 namespace webf {
@@ -14,15 +15,43 @@ class SharedExceptionState;
 class ExecutingContext;
 class Event;
 class Window;
+class Element;
+class CSSStyleDeclaration;
 
 using PublicWindowScrollToWithXAndY = void (*)(Window*, double, double, SharedExceptionState*);
+using PublicWindowInnerWidth = double (*)(Window*, SharedExceptionState*);
+using PublicWindowInnerHeight = double (*)(Window*, SharedExceptionState*);
+using PublicWindowDevicePixelRatio = double (*)(Window*, SharedExceptionState*);
+using PublicWindowScrollX = double (*)(Window*, SharedExceptionState*);
+using PublicWindowScrollY = double (*)(Window*, SharedExceptionState*);
+using PublicWindowGetComputedStyle =
+    WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods> (*)(Window*,
+                                                                         Element*,
+                                                                         const char*,
+                                                                         SharedExceptionState*);
 
 struct WindowPublicMethods : WebFPublicMethods {
   static void ScrollToWithXAndY(Window* window, double x, double y, SharedExceptionState* shared_exception_state);
-
-  double version{1.0};
+  static WebFValue<CSSStyleDeclaration, CSSStyleDeclarationPublicMethods> GetComputedStyle(
+      Window* window,
+      Element* element,
+      const char* pseudo_elt,
+      SharedExceptionState* shared_exception_state);
+  static double InnerWidth(Window* window, SharedExceptionState* shared_exception_state);
+  static double InnerHeight(Window* window, SharedExceptionState* shared_exception_state);
+  static double DevicePixelRatio(Window* window, SharedExceptionState* shared_exception_state);
+  static double ScrollX(Window* window, SharedExceptionState* shared_exception_state);
+  static double ScrollY(Window* window, SharedExceptionState* shared_exception_state);
+
+  double version{2.0};
   EventTargetPublicMethods event_target;
   PublicWindowScrollToWithXAndY window_scroll_to_with_x_and_y{ScrollToWithXAndY};
+  PublicWindowGetComputedStyle window_get_computed_style{GetComputedStyle};
+  PublicWindowInnerWidth window_inner_width{InnerWidth};
+  PublicWindowInnerHeight window_inner_height{InnerHeight};
+  PublicWindowDevicePixelRatio window_device_pixel_ratio{DevicePixelRatio};
+  PublicWindowScrollX window_scroll_x{ScrollX};
+  PublicWindowScrollY window_scroll_y{ScrollY};
 };
 
 }  // namespace webf
@@ -0,0 +1,281 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::{Expr, LitStr};
+
+/// Builds a DOM tree with the markup, expanded to the create and append calls of webf-sys.
+///
+/// The first argument is the `ExecutingContext`, followed by a single root element.
+/// It evaluates to `Result<Element, WebFError>` with the root element, which is not appended to the document.
+///
+/// - Attribute values are string literals, or expressions in braces which are converted with `ToString`.
+/// - Children are elements, string literals, or expressions in braces which are appended as text nodes.
+/// - `@click={handler}` adds an event listener of `click`, the handler is a closure taking `&Event`.
+/// - Elements without children can be closed by `/>`.
+///
+/// For example, `html!(context, <div class="box" @click={handler}><span>{text}</span></div>)`.
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+  match expand(input.into()) {
+    Ok(output) => output.into(),
+    Err(err) => err.to_compile_error().into(),
+  }
+}
+
+enum AttributeValue {
+  Literal(LitStr),
+  Expr(Expr),
+}
+
+struct Attribute {
+  name: String,
+  value: AttributeValue,
+}
+
+struct Listener {
+  event_name: String,
+  handler: Expr,
+}
+
+enum Child {
+  Element(Element),
+  Text(LitStr),
+  Expr(Expr),
+}
+
+struct Element {
+  tag: String,
+  attributes: Vec<Attribute>,
+  listeners: Vec<Listener>,
+  children: Vec<Child>,
+}
+
+struct Parser {
+  tokens: Vec<TokenTree>,
+  index: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&TokenTree> {
+    self.tokens.get(self.index)
+  }
+
+  fn next(&mut self) -> Option<TokenTree> {
+    let token = self.tokens.get(self.index).cloned();
+    self.index += 1;
+    token
+  }
+
+  fn span(&self) -> Span {
+    self.peek().map_or_else(Span::call_site, |token| token.span())
+  }
+
+  fn peek_punct(&self, ch: char) -> bool {
+    matches!(self.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+  }
+
+  fn expect_punct(&mut self, ch: char) -> syn::Result<()> {
+    if self.peek_punct(ch) {
+      self.index += 1;
+      return Ok(());
+    }
+    Err(syn::Error::new(self.span(), format!("expected `{}`", ch)))
+  }
+
+  // Names may contain dashes, such as `data-id` or `my-element`.
+  fn parse_name(&mut self) -> syn::Result<String> {
+    let mut name = match self.next() {
+      Some(TokenTree::Ident(ident)) => ident.to_string(),
+      _ => return Err(syn::Error::new(self.span(), "expected a name")),
+    };
+    while self.peek_punct('-') {
+      self.index += 1;
+      match self.next() {
+        Some(TokenTree::Ident(ident)) => {
+          name.push('-');
+          name.push_str(&ident.to_string());
+        }
+        _ => return Err(syn::Error::new(self.span(), "expected a name after `-`")),
+      }
+    }
+    Ok(name)
+  }
+
+  fn parse_braced_expr(&mut self) -> syn::Result<Option<Expr>> {
+    match self.peek() {
+      Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+        let stream = group.stream();
+        self.index += 1;
+        Ok(Some(syn::parse2::<Expr>(stream)?))
+      }
+      _ => Ok(None),
+    }
+  }
+
+  fn parse_literal(&mut self) -> syn::Result<Option<LitStr>> {
+    match self.peek() {
+      Some(TokenTree::Literal(literal)) => {
+        let literal = syn::parse2::<LitStr>(TokenTree::Literal(literal.clone()).into())?;
+        self.index += 1;
+        Ok(Some(literal))
+      }
+      _ => Ok(None),
+    }
+  }
+
+  fn parse_element(&mut self) -> syn::Result<Element> {
+    self.expect_punct('<')?;
+    let tag = self.parse_name()?;
+    let mut element = Element {
+      tag,
+      attributes: Vec::new(),
+      listeners: Vec::new(),
+      children: Vec::new(),
+    };
+
+    loop {
+      if self.peek_punct('/') {
+        self.index += 1;
+        self.expect_punct('>')?;
+        return Ok(element);
+      }
+      if self.peek_punct('>') {
+        self.index += 1;
+        break;
+      }
+      if self.peek_punct('@') {
+        self.index += 1;
+        let event_name = self.parse_name()?;
+        self.expect_punct('=')?;
+        let handler = self.parse_braced_expr()?
+          .ok_or_else(|| syn::Error::new(self.span(), "expected an event handler in braces"))?;
+        element.listeners.push(Listener { event_name, handler });
+        continue;
+      }
+      let name = self.parse_name()?;
+      self.expect_punct('=')?;
+      let value = if let Some(literal) = self.parse_literal()? {
+        AttributeValue::Literal(literal)
+      } else if let Some(expr) = self.parse_braced_expr()? {
+        AttributeValue::Expr(expr)
+      } else {
+        return Err(syn::Error::new(self.span(), "expected a string literal or an expression in braces"));
+      };
+      element.attributes.push(Attribute { name, value });
+    }
+
+    loop {
+      if self.peek().is_none() {
+        return Err(syn::Error::new(Span::call_site(), format!("`<{}>` is not closed", element.tag)));
+      }
+      if self.peek_punct('<') && matches!(self.tokens.get(self.index + 1), Some(TokenTree::Punct(punct)) if punct.as_char() == '/') {
+        self.index += 2;
+        let span = self.span();
+        let closing_tag = self.parse_name()?;
+        if closing_tag != element.tag {
+          return Err(syn::Error::new(span, format!("expected `</{}>`, found `</{}>`", element.tag, closing_tag)));
+        }
+        self.expect_punct('>')?;
+        return Ok(element);
+      }
+      if self.peek_punct('<') {
+        element.children.push(Child::Element(self.parse_element()?));
+      } else if let Some(literal) = self.parse_literal()? {
+        element.children.push(Child::Text(literal));
+      } else if let Some(expr) = self.parse_braced_expr()? {
+        element.children.push(Child::Expr(expr));
+      } else {
+        return Err(syn::Error::new(self.span(), "expected an element, a string literal or an expression in braces"));
+      }
+    }
+  }
+}
+
+fn expand(input: TokenStream2) -> syn::Result<TokenStream2> {
+  let tokens: Vec<TokenTree> = input.into_iter().collect();
+  let comma = tokens.iter().position(|token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','))
+    .ok_or_else(|| syn::Error::new(Span::call_site(), "expected `html!(context, <tag>...</tag>)`"))?;
+  let context: Expr = syn::parse2(tokens[..comma].iter().cloned().collect())?;
+
+  let mut parser = Parser {
+    tokens: tokens[comma + 1..].to_vec(),
+    index: 0,
+  };
+  let root = parser.parse_element()?;
+  if parser.peek().is_some() {
+    return Err(syn::Error::new(parser.span(), "expected a single root element"));
+  }
+
+  let mut counter = 0;
+  let (root_ident, body) = expand_element(&root, &mut counter);
+  Ok(quote! {
+    {
+      let __webf_context: &::webf_sys::ExecutingContext = &#context;
+      let __webf_exception_state = __webf_context.create_exception_state();
+      let __webf_document = __webf_context.document();
+      (|| -> ::std::result::Result<::webf_sys::Element, ::webf_sys::WebFError> {
+        #body
+        ::std::result::Result::Ok(#root_ident)
+      })()
+    }
+  })
+}
+
+fn expand_element(element: &Element, counter: &mut usize) -> (syn::Ident, TokenStream2) {
+  let ident = format_ident!("__webf_element_{}", *counter);
+  *counter += 1;
+  let tag = &element.tag;
+  let mut body = quote! {
+    let #ident = __webf_document.create_element(#tag, &__webf_exception_state)?;
+  };
+
+  for attribute in &element.attributes {
+    let name = &attribute.name;
+    let value = match &attribute.value {
+      AttributeValue::Literal(literal) => quote! { #literal },
+      AttributeValue::Expr(expr) => quote! { &::std::string::ToString::to_string(&(#expr)) },
+    };
+    body.extend(quote! {
+      #ident.set_attribute(#name, #value, &__webf_exception_state)?;
+    });
+  }
+
+  for listener in &element.listeners {
+    let event_name = &listener.event_name;
+    let handler = &listener.handler;
+    body.extend(quote! {
+      ::webf_sys::EventTargetMethods::add_event_listener(
+        &#ident,
+        #event_name,
+        ::std::boxed::Box::new(#handler),
+        &::webf_sys::AddEventListenerOptions { capture: 0, passive: 0, once: 0 },
+        &__webf_exception_state,
+      )?;
+    });
+  }
+
+  for child in &element.children {
+    let child_node = match child {
+      Child::Element(child) => {
+        let (child_ident, child_body) = expand_element(child, counter);
+        body.extend(child_body);
+        quote! { ::webf_sys::NodeMethods::as_node(&#child_ident) }
+      }
+      Child::Text(literal) => quote! {
+        ::webf_sys::NodeMethods::as_node(&__webf_document.create_text_node(#literal, &__webf_exception_state)?)
+      },
+      Child::Expr(expr) => quote! {
+        ::webf_sys::NodeMethods::as_node(&__webf_document.create_text_node(&::std::string::ToString::to_string(&(#expr)), &__webf_exception_state)?)
+      },
+    };
+    body.extend(quote! {
+      ::webf_sys::NodeMethods::append_child(&#ident, #child_node, &__webf_exception_state)?;
+    });
+  }
+
+  (ident, body)
+}
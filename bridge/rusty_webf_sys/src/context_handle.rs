@@ -0,0 +1,45 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ops::Deref;
+use std::rc::Rc;
+use crate::*;
+
+/// A clonable handle of an `ExecutingContext`, created by `ExecutingContext::handle()`.
+///
+/// Clones share the same context, so it could be moved into as many callbacks as needed instead of capturing the context
+/// or reading it back from `Event::context()`, and gives back `&ExecutingContext` through `get()` or deref.
+///
+/// Same as the context itself, the handle is neither `Send` nor `Sync`: the context belongs to the JavaScript thread of the page,
+/// so the handle must stay on that thread and only be used by the callbacks WebF invokes there.
+/// Once the page is unloaded the handle still exists but the context is disposed, which could be checked with `is_disposed()`.
+#[derive(Clone)]
+pub struct ContextHandle {
+  context: Rc<ExecutingContext>,
+}
+
+impl ContextHandle {
+  pub(crate) fn new(context: ExecutingContext) -> ContextHandle {
+    ContextHandle {
+      context: Rc::new(context),
+    }
+  }
+
+  pub fn get(&self) -> &ExecutingContext {
+    &self.context
+  }
+
+  /// Whether the handles point to the same context.
+  pub fn ptr_eq(&self, other: &ContextHandle) -> bool {
+    self.context.ptr == other.context.ptr
+  }
+}
+
+impl Deref for ContextHandle {
+  type Target = ExecutingContext;
+
+  fn deref(&self) -> &ExecutingContext {
+    &self.context
+  }
+}
@@ -0,0 +1,84 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::*;
+
+#[derive(Default)]
+struct AbortSignalState {
+  aborted: bool,
+  // The targets are held weakly, so the signal doesn't keep them alive until it's aborted.
+  listeners: Vec<(WeakEventTarget, ListenerKey)>,
+}
+
+/// Behavior as same as `AbortSignal` in JavaScript.
+/// Listeners registered by `EventTargetMethods::add_event_listener_with_signal` are removed when the signal is aborted,
+/// dropping the controller and the signals without aborting keeps the listeners registered.
+#[derive(Clone)]
+pub struct AbortSignal {
+  state: Rc<RefCell<AbortSignalState>>,
+}
+
+impl AbortSignal {
+  pub fn aborted(&self) -> bool {
+    self.state.borrow().aborted
+  }
+
+  pub(crate) fn track(&self, event_target: WeakEventTarget, key: ListenerKey) {
+    self.state.borrow_mut().listeners.push((event_target, key));
+  }
+}
+
+/// Behavior as same as `AbortController` in JavaScript.
+pub struct AbortController {
+  signal: AbortSignal,
+}
+
+impl AbortController {
+  pub fn new() -> AbortController {
+    AbortController {
+      signal: AbortSignal {
+        state: Rc::new(RefCell::new(AbortSignalState::default())),
+      },
+    }
+  }
+
+  pub fn signal(&self) -> AbortSignal {
+    self.signal.clone()
+  }
+
+  /// Remove every listener registered with the signal of this controller.
+  /// Returns the first error met, the rest of the listeners are still removed.
+  pub fn abort(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let listeners = {
+      let mut state = self.signal.state.borrow_mut();
+      if state.aborted {
+        return Ok(());
+      }
+      state.aborted = true;
+      std::mem::take(&mut state.listeners)
+    };
+
+    let mut result = Ok(());
+    for (event_target, key) in listeners {
+      // The listeners of a collected target are gone with it.
+      let Some(event_target) = event_target.upgrade() else {
+        continue;
+      };
+      if let Err(err) = event_target.remove_event_listener_by_key(&key, exception_state) {
+        if result.is_ok() {
+          result = Err(err);
+        }
+      }
+    }
+    result
+  }
+}
+
+impl Default for AbortController {
+  fn default() -> Self {
+    Self::new()
+  }
+}
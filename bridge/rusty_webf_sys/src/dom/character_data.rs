@@ -4,11 +4,15 @@
 
 use std::ffi::*;
 use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
 
 #[repr(C)]
 pub struct CharacterDataRustMethods {
   pub version: c_double,
   pub node: NodeRustMethods,
+  pub data: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> *mut c_char,
+  pub set_data: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr),
+  pub length: extern "C" fn(*const OpaquePtr) -> i64,
 }
 
 impl RustMethods for CharacterDataRustMethods {}
@@ -18,7 +22,41 @@ pub struct CharacterData {
   method_pointer: *const CharacterDataRustMethods,
 }
 
-impl CharacterData {}
+impl CharacterData {
+  /// Behavior as same as `CharacterData.data` in JavaScript.
+  pub fn data(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    let value = unsafe {
+      ((*self.method_pointer).data)(self.ptr(), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.node.event_target.context()));
+    }
+
+    let data = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(data)
+  }
+
+  /// Behavior as same as setting `CharacterData.data` in JavaScript.
+  pub fn set_data(&self, data: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let data_c_string = CString::new(data).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_data)(self.ptr(), data_c_string.as_ptr(), exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.node.event_target.context()));
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `CharacterData.length` in JavaScript, the number of UTF-16 code units of the data.
+  pub fn length(&self) -> usize {
+    let value = unsafe {
+      ((*self.method_pointer).length)(self.ptr())
+    };
+    value as usize
+  }
+}
 
 impl EventTargetMethods for CharacterData {
   fn initialize<T: RustMethods>(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const T, status: *const RustValueStatus) -> Self where Self: Sized {
@@ -38,18 +76,26 @@ impl EventTargetMethods for CharacterData {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.node.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.node.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.node.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.node.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.node.downgrade()
+  }
 }
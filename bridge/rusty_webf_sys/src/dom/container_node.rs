@@ -26,14 +26,74 @@ pub trait ContainerNodeMethods : NodeMethods {
 }
 
 impl NodeMethods for ContainerNode {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.node.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.node.remove_child(target_node, exception_state)
   }
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.node.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.node.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.node.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.node.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.node.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.node.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.node.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.node.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.node.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.node.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.node.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.node.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.node.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.node.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.node.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     &self.node
   }
@@ -58,20 +118,28 @@ impl EventTargetMethods for ContainerNode {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.node.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.node.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.node.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.node.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.node.downgrade()
+  }
 }
 
 impl ContainerNodeMethods for ContainerNode {}
@@ -0,0 +1,114 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
+
+#[repr(C)]
+pub struct CSSStyleDeclarationRustMethods {
+  pub version: c_double,
+  pub get_property_value: extern "C" fn(ptr: *const OpaquePtr, property: *const c_char, exception_state: *const OpaquePtr) -> *mut c_char,
+  pub set_property: extern "C" fn(ptr: *const OpaquePtr, property: *const c_char, value: *const c_char, priority: *const c_char, exception_state: *const OpaquePtr) -> c_void,
+  pub remove_property: extern "C" fn(ptr: *const OpaquePtr, property: *const c_char, exception_state: *const OpaquePtr) -> *mut c_char,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for CSSStyleDeclarationRustMethods {}
+
+/// The inline style of an element, such as the one returned by `Element::style()`,
+/// or the read-only computed style returned by `Window::get_computed_style()`.
+pub struct CSSStyleDeclaration {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const CSSStyleDeclarationRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl CSSStyleDeclaration {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const CSSStyleDeclarationRustMethods, status: *const RustValueStatus) -> CSSStyleDeclaration {
+    CSSStyleDeclaration {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `CSSStyleDeclaration.getPropertyValue()` in JavaScript.
+  /// Returns an empty string if the property is not set.
+  pub fn get_property_value(&self, property: &str, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    let property_c_string = CString::new(property).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).get_property_value)(self.ptr(), property_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+
+    let value_string = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(value_string)
+  }
+
+  /// Behavior as same as `CSSStyleDeclaration.setProperty()` in JavaScript.
+  /// Setting a property to an empty string removes it.
+  /// The priority is `None`, `""` or `"important"` (ASCII case-insensitive), inline styles are always applied with the
+  /// important priority so all of them set the same value.
+  /// Returns `Err` with a `SyntaxError` for any other priority, and for computed styles, which are read-only.
+  pub fn set_property(&self, property: &str, value: &str, priority: Option<&str>, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let property_c_string = CString::new(property).unwrap();
+    let value_c_string = CString::new(value).unwrap();
+    let priority_c_string = priority.map(|priority| CString::new(priority).unwrap());
+    let priority_ptr = priority_c_string.as_ref().map_or(std::ptr::null(), |priority| priority.as_ptr());
+    unsafe {
+      ((*self.method_pointer).set_property)(self.ptr(), property_c_string.as_ptr(), value_c_string.as_ptr(), priority_ptr, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `CSSStyleDeclaration.removeProperty()` in JavaScript.
+  /// Returns the value the property had before it was removed.
+  /// Returns `Err` for computed styles, which are read-only.
+  pub fn remove_property(&self, property: &str, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    let property_c_string = CString::new(property).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).remove_property)(self.ptr(), property_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+
+    let old_value = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(old_value)
+  }
+}
+
+impl Drop for CSSStyleDeclaration {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
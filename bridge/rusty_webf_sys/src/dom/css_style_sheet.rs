@@ -0,0 +1,123 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
+
+#[repr(C)]
+pub struct CSSStyleSheetRustMethods {
+  pub version: c_double,
+  pub insert_rule: extern "C" fn(*const OpaquePtr, *const c_char, i64, *const OpaquePtr) -> i64,
+  pub delete_rule: extern "C" fn(*const OpaquePtr, i64, *const OpaquePtr) -> c_void,
+  pub css_rules: extern "C" fn(*const OpaquePtr, *mut i64, *const OpaquePtr) -> *mut *mut c_char,
+  pub release: extern "C" fn(*const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for CSSStyleSheetRustMethods {}
+
+/// The style sheet of a `<style>` element created by `Document::create_style_sheet()`,
+/// behavior as same as `CSSStyleSheet` in JavaScript.
+/// The rules are parsed and applied by the CSS engine of WebF. The `<style>` element is kept in the head
+/// when the style sheet is dropped, so a component could register its styles once at startup.
+pub struct CSSStyleSheet {
+  owner_node: Element,
+  ptr: *const OpaquePtr,
+  method_pointer: *const CSSStyleSheetRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl CSSStyleSheet {
+  pub(crate) fn new(owner_node: Element, exception_state: &ExceptionState) -> Result<CSSStyleSheet, WebFError> {
+    let sheet_value = owner_node.sheet(exception_state)?;
+    Ok(CSSStyleSheet {
+      owner_node,
+      ptr: sheet_value.value,
+      method_pointer: sheet_value.method_pointer,
+      status: sheet_value.status,
+    })
+  }
+
+  /// The `<style>` element which owns the style sheet.
+  pub fn owner_node(&self) -> &Element {
+    &self.owner_node
+  }
+
+  /// Behavior as same as `CSSStyleSheet.cssRules` in JavaScript, returns the `cssText` of each rule in order.
+  /// The text is serialized by the CSS engine, so it may differ from the text passed to `insert_rule()`.
+  pub fn css_rules(&self, exception_state: &ExceptionState) -> Result<Vec<String>, WebFError> {
+    self.check_disposed()?;
+    let mut length: i64 = 0;
+    let rules_ptr = unsafe {
+      ((*self.method_pointer).css_rules)(self.ptr, &mut length, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    if rules_ptr.is_null() {
+      return Ok(Vec::new());
+    }
+
+    let rules = unsafe { std::slice::from_raw_parts(rules_ptr, length as usize) }
+      .iter()
+      .map(|rule| {
+        let text = unsafe { CStr::from_ptr(*rule) }.to_string_lossy().into_owned();
+        safe_free_cpp_ptr(*rule);
+        text
+      })
+      .collect();
+    safe_free_cpp_ptr(rules_ptr);
+    Ok(rules)
+  }
+
+  /// Behavior as same as `CSSStyleSheet.insertRule()` in JavaScript.
+  /// Returns the index of the inserted rule, `Err` with an `IndexSizeError` when `index` is larger than the number of rules,
+  /// or a `SyntaxError` when the CSS engine could not parse a rule from `rule`.
+  pub fn insert_rule(&self, rule: &str, index: u32, exception_state: &ExceptionState) -> Result<u32, WebFError> {
+    self.check_disposed()?;
+    let rule_c_string = CString::new(rule).unwrap();
+    let inserted_index = unsafe {
+      ((*self.method_pointer).insert_rule)(self.ptr, rule_c_string.as_ptr(), index as i64, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(inserted_index as u32)
+  }
+
+  /// Behavior as same as `CSSStyleSheet.deleteRule()` in JavaScript.
+  /// Returns `Err` with an `IndexSizeError` when there is no rule at `index`.
+  pub fn delete_rule(&self, index: u32, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.check_disposed()?;
+    unsafe {
+      ((*self.method_pointer).delete_rule)(self.ptr, index as i64, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(())
+  }
+
+  fn context(&self) -> &ExecutingContext {
+    self.owner_node.as_node().event_target.context()
+  }
+
+  fn check_disposed(&self) -> Result<(), WebFError> {
+    if unsafe { (*self.status).disposed } {
+      return Err(WebFError::Disposed);
+    }
+    Ok(())
+  }
+}
+
+impl Drop for CSSStyleSheet {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr);
+    }
+  }
+}
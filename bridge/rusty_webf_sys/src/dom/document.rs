@@ -2,9 +2,16 @@
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 
+use std::cell::RefCell;
 use std::ffi::*;
+use std::io::Write;
 use crate::*;
 
+thread_local! {
+  // Reused by `Document::create_text_node_fmt()` so that formatting does not allocate once the buffer is large enough.
+  static TEXT_NODE_BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
 #[repr(C)]
 pub struct ElementCreationOptions {
   pub is: *const c_char,
@@ -38,19 +45,53 @@ pub struct DocumentRustMethods {
   pub head: extern "C" fn(document: *const OpaquePtr) -> RustValue<ElementRustMethods>,
   pub body: extern "C" fn(document: *const OpaquePtr) -> RustValue<ElementRustMethods>,
   pub ___clear_cookies__: extern "C" fn(*const OpaquePtr, *const OpaquePtr),
+  pub query_selector_all: extern "C" fn(document: *const OpaquePtr, selectors: *const c_char, exception_state: *const OpaquePtr) -> RustValue<NodeListRustMethods>,
+  pub create_custom_event: extern "C" fn(document: *const OpaquePtr, event_type: *const c_char, detail_json: *const c_char, bubbles: i32, cancelable: i32, exception_state: *const OpaquePtr) -> RustValue<CustomEventRustMethods>,
+  pub get_elements_by_class_name: extern "C" fn(document: *const OpaquePtr, class_names: *const c_char, exception_state: *const OpaquePtr) -> RustValue<HtmlCollectionRustMethods>,
+  pub get_elements_by_tag_name: extern "C" fn(document: *const OpaquePtr, tag_name: *const c_char, exception_state: *const OpaquePtr) -> RustValue<HtmlCollectionRustMethods>,
+  pub create_mouse_event: extern "C" fn(document: *const OpaquePtr, event_type: *const c_char, init: *const MouseEventInitData, exception_state: *const OpaquePtr) -> RustValue<MouseEventRustMethods>,
+  pub exit_fullscreen: extern "C" fn(document: *const OpaquePtr, exception_state: *const OpaquePtr) -> RustValue<ScriptPromiseRefRustMethods>,
+  pub fullscreen_element: extern "C" fn(document: *const OpaquePtr) -> RustValue<ElementRustMethods>,
+  pub create_close_event: extern "C" fn(document: *const OpaquePtr, event_type: *const c_char, init: *const CloseEventInitData, exception_state: *const OpaquePtr) -> RustValue<CloseEventRustMethods>,
+  pub ready_state: extern "C" fn(document: *const OpaquePtr) -> i32,
+  pub create_pointer_event: extern "C" fn(document: *const OpaquePtr, event_type: *const c_char, bubbles: i32, cancelable: i32, init: *const PointerEventInitData, exception_state: *const OpaquePtr) -> RustValue<PointerEventRustMethods>,
 }
 
 impl RustMethods for DocumentRustMethods {}
 
+/// Behavior as same as `document.readyState` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentReadyState {
+  /// The document is still loading.
+  Loading,
+  /// The document has been parsed and `DOMContentLoaded` is fired, but resources such as images may still be loading.
+  Interactive,
+  /// The document and all its resources have loaded, the `load` event of the window is fired.
+  Complete,
+}
+
+pub type DocumentReadyCallback = Box<dyn FnOnce()>;
+
+pub const HTML_NAMESPACE_URI: &str = "http://www.w3.org/1999/xhtml";
+pub const SVG_NAMESPACE_URI: &str = "http://www.w3.org/2000/svg";
+pub const XLINK_NAMESPACE_URI: &str = "http://www.w3.org/1999/xlink";
+pub const XML_NAMESPACE_URI: &str = "http://www.w3.org/XML/1998/namespace";
+pub const XMLNS_NAMESPACE_URI: &str = "http://www.w3.org/2000/xmlns/";
+
 pub struct Document {
   pub container_node: ContainerNode,
   method_pointer: *const DocumentRustMethods,
 }
 
 impl Document {
+  /// Starts building an element of the tag, see `ElementBuilder` for the steps which can be chained.
+  pub fn build_element(&self, tag: &str) -> ElementBuilder<'_> {
+    ElementBuilder::new(self, tag)
+  }
+
   /// Behavior as same as `document.createElement()` in JavaScript.
   /// the createElement() method creates the HTML element specified by tagName, or an HTMLUnknownElement if tagName isn't recognized.
-  pub fn create_element(&self, name: &str, exception_state: &ExceptionState) -> Result<Element, String> {
+  pub fn create_element(&self, name: &str, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let name_c_string = CString::new(name).unwrap();
     let new_element_value = unsafe {
@@ -58,13 +99,13 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Element::initialize(new_element_value.value, event_target.context(), new_element_value.method_pointer, new_element_value.status));
   }
 
-  pub fn create_element_with_element_creation_options(&self, name: &str, options: &mut ElementCreationOptions, exception_state: &ExceptionState) -> Result<Element, String> {
+  pub fn create_element_with_element_creation_options(&self, name: &str, options: &mut ElementCreationOptions, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let name_c_string = CString::new(name).unwrap();
     let new_element_value = unsafe {
@@ -72,13 +113,13 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Element::initialize(new_element_value.value, event_target.context(), new_element_value.method_pointer, new_element_value.status));
   }
 
-  pub fn create_element_with_str(&self, name: &str, str_options: &CString, exception_state: &ExceptionState) -> Result<Element, String> {
+  pub fn create_element_with_str(&self, name: &str, str_options: &CString, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let options = &mut ElementCreationOptions {
       is: str_options.as_ptr(),
     };
@@ -88,20 +129,23 @@ impl Document {
   /// Behavior as same as `document.createElementNS()` in JavaScript.
   /// Creates a new element with the given namespace URI and qualified name.
   /// The qualified name is a concatenation of the namespace prefix, a colon, and the local name.
-  pub fn create_element_ns(&self, uri: &CString, name: &CString, exception_state: &ExceptionState) -> Result<Element, String> {
+  /// Elements created with `SVG_NAMESPACE_URI` are rendered as SVG, and `HTML_NAMESPACE_URI` is the same as `create_element()`.
+  pub fn create_element_ns(&self, uri: &str, name: &str, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
+    let uri_c_string = CString::new(uri).unwrap();
+    let name_c_string = CString::new(name).unwrap();
     let new_element_value = unsafe {
-      ((*self.method_pointer).create_element_ns)(event_target.ptr, uri.as_ptr(), name.as_ptr(), exception_state.ptr)
+      ((*self.method_pointer).create_element_ns)(event_target.ptr, uri_c_string.as_ptr(), name_c_string.as_ptr(), exception_state.ptr)
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Element::initialize(new_element_value.value, event_target.context(), new_element_value.method_pointer, new_element_value.status));
   }
 
-  pub fn create_element_ns_with_element_creation_options(&self, uri: &str, name: &str, options: &mut ElementCreationOptions, exception_state: &ExceptionState) -> Result<Element, String> {
+  pub fn create_element_ns_with_element_creation_options(&self, uri: &str, name: &str, options: &mut ElementCreationOptions, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let uri_c_string = CString::new(uri).unwrap();
     let name_c_string = CString::new(name).unwrap();
@@ -110,13 +154,13 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Element::initialize(new_element_value.value, event_target.context(), new_element_value.method_pointer, new_element_value.status));
   }
 
-  pub fn create_element_ns_with_str(&self, uri: &str, name: &str, str_options: &CString, exception_state: &ExceptionState) -> Result<Element, String> {
+  pub fn create_element_ns_with_str(&self, uri: &str, name: &str, str_options: &CString, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let options = &mut ElementCreationOptions {
       is: str_options.as_ptr(),
     };
@@ -125,7 +169,7 @@ impl Document {
 
   /// Behavior as same as `document.createTextNode()` in JavaScript.
   /// Creates a new Text node. This method can be used to escape HTML characters.
-  pub fn create_text_node(&self, data: &str, exception_state: &ExceptionState) -> Result<Text, String> {
+  pub fn create_text_node(&self, data: &str, exception_state: &ExceptionState) -> Result<Text, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let data_c_string = CString::new(data).unwrap();
     let new_text_node = unsafe {
@@ -133,22 +177,50 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Text::initialize(new_text_node.value, event_target.context(), new_text_node.method_pointer, new_text_node.status));
   }
 
+  /// Same as `create_text_node`, but formats `args` into a buffer reused across the calls instead of a new `String`,
+  /// for the text updated on every frame, such as `format_args!("x: {}, y: {}", x, y)`.
+  ///
+  /// Panics if the formatted text contains a nul byte, the same as `create_text_node`.
+  pub fn create_text_node_fmt(&self, args: std::fmt::Arguments, exception_state: &ExceptionState) -> Result<Text, WebFError> {
+    TEXT_NODE_BUFFER.with(|buffer| {
+      // The buffer is taken when a text node is created while formatting another one, such as by a `Display` impl.
+      let Ok(mut buffer) = buffer.try_borrow_mut() else {
+        return self.create_text_node(&args.to_string(), exception_state);
+      };
+      buffer.clear();
+      buffer.write_fmt(args).expect("a formatting trait implementation returned an error");
+      assert!(!buffer.contains(&0), "The formatted text of a text node must not contain a nul byte");
+      buffer.push(0);
+
+      let event_target: &EventTarget = &self.container_node.node.event_target;
+      let new_text_node = unsafe {
+        ((*self.method_pointer).create_text_node)(event_target.ptr, buffer.as_ptr() as *const c_char, exception_state.ptr)
+      };
+
+      if exception_state.has_exception() {
+        return Err(exception_state.to_error(event_target.context()));
+      }
+
+      Ok(Text::initialize(new_text_node.value, event_target.context(), new_text_node.method_pointer, new_text_node.status))
+    })
+  }
+
   /// Behavior as same as `document.createDocumentFragment()` in JavaScript.
   /// Creates a new DocumentFragment.
-  pub fn create_document_fragment(&self, exception_state: &ExceptionState) -> Result<DocumentFragment, String> {
+  pub fn create_document_fragment(&self, exception_state: &ExceptionState) -> Result<DocumentFragment, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let new_document_fragment = unsafe {
       ((*self.method_pointer).create_document_fragment)(event_target.ptr, exception_state.ptr)
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(DocumentFragment::initialize(new_document_fragment.value, event_target.context(), new_document_fragment.method_pointer, new_document_fragment.status));
@@ -156,7 +228,7 @@ impl Document {
 
   /// Behavior as same as `document.createComment()` in JavaScript.
   /// Creates a new Comment node with the given data.
-  pub fn create_comment(&self, data: &str, exception_state: &ExceptionState) -> Result<Comment, String> {
+  pub fn create_comment(&self, data: &str, exception_state: &ExceptionState) -> Result<Comment, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let data_c_string = CString::new(data).unwrap();
     let new_comment = unsafe {
@@ -164,7 +236,7 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Comment::initialize(new_comment.value, event_target.context(), new_comment.method_pointer, new_comment.status));
@@ -172,7 +244,7 @@ impl Document {
 
   /// Behavior as same as `document.createEvent()` in JavaScript.
   /// Creates a new event of the type specified.
-  pub fn create_event(&self, event_type: &str, exception_state: &ExceptionState) -> Result<Event, String> {
+  pub fn create_event(&self, event_type: &str, exception_state: &ExceptionState) -> Result<Event, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let event_type_c_string = CString::new(event_type).unwrap();
     let new_event = unsafe {
@@ -180,15 +252,163 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Event::initialize(new_event.value, event_target.context(), new_event.method_pointer, new_event.status));
   }
 
+  /// Behavior as same as `new CustomEvent(type, { detail })` in JavaScript.
+  /// The detail is transferred as JSON, a `None` or `ScriptValue::Null` detail leaves `CustomEvent::detail()` as None.
+  pub fn create_custom_event(&self, event_type: &str, detail: Option<ScriptValue>, options: CustomEventInit, exception_state: &ExceptionState) -> Result<CustomEvent, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let event_type_c_string = CString::new(event_type).unwrap();
+    let detail_c_string = detail.map(|detail| CString::new(detail.to_json()).unwrap());
+    let detail_ptr = detail_c_string.as_ref().map_or(std::ptr::null(), |detail| detail.as_ptr());
+    let new_event = unsafe {
+      ((*self.method_pointer).create_custom_event)(event_target.ptr, event_type_c_string.as_ptr(), detail_ptr, i32::from(options.bubbles), i32::from(options.cancelable), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(CustomEvent::initialize(new_event.value, event_target.context(), new_event.method_pointer, new_event.status))
+  }
+
+  /// Behavior as same as `new MouseEvent(type, init)` in JavaScript.
+  /// Dispatching the event only runs the listeners, it does not trigger the default actions of the target such as following a link.
+  pub fn create_mouse_event(&self, event_type: &str, init: MouseEventInit, exception_state: &ExceptionState) -> Result<MouseEvent, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let event_type_c_string = CString::new(event_type).unwrap();
+    let init_data = MouseEventInitData::from(&init);
+    let new_event = unsafe {
+      ((*self.method_pointer).create_mouse_event)(event_target.ptr, event_type_c_string.as_ptr(), &init_data, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(MouseEvent::initialize(new_event.value, event_target.context(), new_event.method_pointer, new_event.status))
+  }
+
+  /// Behavior as same as `new PointerEvent(type, init)` in JavaScript.
+  pub fn create_pointer_event(&self, event_type: &str, init: PointerEventInit, exception_state: &ExceptionState) -> Result<PointerEvent, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let event_type_c_string = CString::new(event_type).unwrap();
+    let init_data = PointerEventInitData::from(&init);
+    let new_event = unsafe {
+      ((*self.method_pointer).create_pointer_event)(event_target.ptr, event_type_c_string.as_ptr(), i32::from(init.bubbles), i32::from(init.cancelable), &init_data, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(PointerEvent::initialize(new_event.value, event_target.context(), new_event.method_pointer, new_event.status))
+  }
+
+  /// Behavior as same as `new CloseEvent(type, init)` in JavaScript.
+  /// Useful for testing the close handling of a `WebSocket` with a specific code, the socket itself is not closed.
+  pub fn create_close_event(&self, event_type: &str, init: CloseEventInit, exception_state: &ExceptionState) -> Result<CloseEvent, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let event_type_c_string = CString::new(event_type).unwrap();
+    let reason_c_string = CString::new(init.reason).unwrap();
+    let init_data = CloseEventInitData {
+      bubbles: i32::from(init.bubbles),
+      cancelable: i32::from(init.cancelable),
+      composed: 0,
+      code: i64::from(init.code),
+      reason: reason_c_string.as_ptr(),
+      was_clean: i32::from(init.was_clean),
+    };
+    let new_event = unsafe {
+      ((*self.method_pointer).create_close_event)(event_target.ptr, event_type_c_string.as_ptr(), &init_data, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(CloseEvent::initialize(new_event.value, event_target.context(), new_event.method_pointer, new_event.status))
+  }
+
+  /// Creates an empty style sheet which applies to the document, the same as appending a `<style>` element to the head.
+  /// Rules are added with `CSSStyleSheet::insert_rule()`.
+  pub fn create_style_sheet(&self, exception_state: &ExceptionState) -> Result<CSSStyleSheet, WebFError> {
+    let style = self.create_element("style", exception_state)?;
+    self.head().append_child(style.as_node(), exception_state)?;
+    CSSStyleSheet::new(style, exception_state)
+  }
+
+  /// Behavior as same as `document.exitFullscreen()` in JavaScript.
+  /// The promise is rejected when no element is fullscreen.
+  pub fn exit_fullscreen(&self, exception_state: &ExceptionState) -> Result<Promise<()>, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let promise_value = unsafe {
+      ((*self.method_pointer).exit_fullscreen)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(Promise::initialize(promise_value.value, event_target.context(), promise_value.method_pointer))
+  }
+
+  /// Behavior as same as `document.readyState` in JavaScript.
+  pub fn ready_state(&self) -> DocumentReadyState {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let ready_state = unsafe {
+      ((*self.method_pointer).ready_state)(event_target.ptr)
+    };
+    match ready_state {
+      2 => DocumentReadyState::Complete,
+      1 => DocumentReadyState::Interactive,
+      _ => DocumentReadyState::Loading,
+    }
+  }
+
+  /// Calls the callback once the document has been parsed, the same as listening to `DOMContentLoaded`.
+  /// When the document is not loading anymore the callback is called right away, so it never misses the event.
+  pub fn on_ready(&self, callback: DocumentReadyCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if self.ready_state() != DocumentReadyState::Loading {
+      callback();
+      return Ok(());
+    }
+
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 1,
+      capture: 0,
+    };
+    let callback = RefCell::new(Some(callback));
+    self.container_node.node.event_target.add_event_listener("DOMContentLoaded", Box::new(move |_| {
+      if let Some(callback) = callback.borrow_mut().take() {
+        callback();
+      }
+    }), &options, exception_state)
+  }
+
+  /// Behavior as same as `document.fullscreenElement` in JavaScript.
+  pub fn fullscreen_element(&self) -> Option<Element> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let element_value = unsafe {
+      ((*self.method_pointer).fullscreen_element)(event_target.ptr)
+    };
+
+    if element_value.value.is_null() {
+      return None;
+    }
+
+    Some(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status))
+  }
+
   /// Behavior as same as `document.querySelector()` in JavaScript.
-  /// Returns the first element that is a descendant of the element on which it is invoked that matches the specified group of selectors.
-  pub fn query_selector(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Element, String> {
+  /// Returns the first element that is a descendant of the element on which it is invoked that matches the specified group of selectors,
+  /// or None if there are no matches.
+  pub fn query_selector(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let selectoc_string = CString::new(selectors).unwrap();
     let element_value = unsafe {
@@ -196,15 +416,69 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
-    return Ok(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status));
+    if element_value.value.is_null() {
+      return Ok(None);
+    }
+
+    return Ok(Some(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status)));
+  }
+
+  /// Behavior as same as `document.querySelectorAll()` in JavaScript.
+  /// Returns a static NodeList representing a list of the document's elements that match the specified group of selectors.
+  pub fn query_selector_all(&self, selectors: &str, exception_state: &ExceptionState) -> Result<NodeList, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let selectors_c_string = CString::new(selectors).unwrap();
+    let node_list_value = unsafe {
+      ((*self.method_pointer).query_selector_all)(event_target.ptr, selectors_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(NodeList::initialize(node_list_value.value, event_target.context(), node_list_value.method_pointer, node_list_value.status))
+  }
+
+  /// Behavior as same as `document.getElementsByClassName()` in JavaScript.
+  /// Returns the elements of the document which have all the given space-separated class names, in tree order.
+  /// The collection is a snapshot which doesn't change when the DOM changes.
+  pub fn get_elements_by_class_name(&self, class_names: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let class_names_c_string = CString::new(class_names).unwrap();
+    let collection_value = unsafe {
+      ((*self.method_pointer).get_elements_by_class_name)(event_target.ptr, class_names_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(HtmlCollection::initialize(collection_value.value, event_target.context(), collection_value.method_pointer, collection_value.status))
+  }
+
+  /// Behavior as same as `document.getElementsByTagName()` in JavaScript.
+  /// Returns the elements of the document with the given tag name in tree order, or all of them when the tag name is `"*"`.
+  /// The collection is a snapshot which doesn't change when the DOM changes.
+  pub fn get_elements_by_tag_name(&self, tag_name: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let tag_name_c_string = CString::new(tag_name).unwrap();
+    let collection_value = unsafe {
+      ((*self.method_pointer).get_elements_by_tag_name)(event_target.ptr, tag_name_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(HtmlCollection::initialize(collection_value.value, event_target.context(), collection_value.method_pointer, collection_value.status))
   }
 
   /// Behavior as same as `document.getElementById()` in JavaScript.
-  /// Returns a reference to the element by its ID.
-  pub fn get_element_by_id(&self, element_id: &str, exception_state: &ExceptionState) -> Result<Element, String> {
+  /// Returns a reference to the element by its ID, or None if no element has that ID.
+  pub fn get_element_by_id(&self, element_id: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let id_c_string = CString::new(element_id).unwrap();
     let element_value = unsafe {
@@ -212,22 +486,26 @@ impl Document {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
-    return Ok(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status));
+    if element_value.value.is_null() {
+      return Ok(None);
+    }
+
+    return Ok(Some(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status)));
   }
 
   /// Behavior as same as `document.elementFromPoint()` in JavaScript.
   /// Returns the element from the document whose elementFromPoint() method is being called which is the topmost element which lies under the given point.
-  pub fn element_from_point(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<Element, String> {
+  pub fn element_from_point(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<Element, WebFError> {
     let event_target: &EventTarget = &self.container_node.node.event_target;
     let element_value = unsafe {
       ((*self.method_pointer).element_from_point)(event_target.ptr, x, y, exception_state.ptr)
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status));
@@ -275,14 +553,74 @@ impl Document {
 trait DocumentMethods: ContainerNodeMethods {}
 
 impl NodeMethods for Document {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.container_node.node.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.container_node.node.remove_child(target_node, exception_state)
   }
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.container_node.node.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.container_node.node.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.container_node.node.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.container_node.node.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.container_node.node.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.container_node.node.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.container_node.node.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.container_node.node.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.container_node.node.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     &self.container_node.node
   }
@@ -312,20 +650,28 @@ impl EventTargetMethods for Document {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.container_node.node.event_target.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.container_node.node.event_target.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.container_node.node.event_target.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.container_node.node.event_target.downgrade()
+  }
 }
 
 impl ContainerNodeMethods for Document {}
@@ -13,8 +13,12 @@ pub struct DocumentFragmentRustMethods {
 
 impl RustMethods for DocumentFragmentRustMethods {}
 
+/// A lightweight container of nodes which is not part of the document tree, created by `Document::create_document_fragment()`.
+///
+/// Building a subtree inside a fragment does not affect layout. Appending or inserting the fragment into a parent
+/// moves all of its children there in one operation and leaves the fragment empty, the same as in the DOM.
 pub struct DocumentFragment {
-  container_node: ContainerNode,
+  pub container_node: ContainerNode,
   method_pointer: *const DocumentFragmentRustMethods,
 }
 
@@ -25,15 +29,75 @@ pub trait DocumentFragmentMethods: ContainerNodeMethods {}
 impl ContainerNodeMethods for DocumentFragment {}
 
 impl NodeMethods for DocumentFragment {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.container_node.node.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.container_node.node.remove_child(target_node, exception_state)
   }
 
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.container_node.node.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.container_node.node.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.container_node.node.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.container_node.node.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.container_node.node.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.container_node.node.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.container_node.node.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.container_node.node.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.container_node.node.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     &self.container_node.node
   }
@@ -62,20 +126,28 @@ impl EventTargetMethods for DocumentFragment {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.container_node.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.container_node.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.container_node.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.container_node.downgrade()
+  }
 }
 
 impl DocumentFragmentMethods for DocumentFragment {}
@@ -0,0 +1,67 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+/// The type of the string parsed by `DOMParser::parse_from_string()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MimeType {
+  /// `text/html`
+  TextHtml,
+  /// `text/xml`
+  TextXml,
+  /// `image/svg+xml`
+  ImageSvgXml,
+}
+
+impl MimeType {
+  fn to_i32(self) -> i32 {
+    match self {
+      MimeType::TextHtml => 0,
+      MimeType::TextXml => 1,
+      MimeType::ImageSvgXml => 2,
+    }
+  }
+}
+
+/// Behavior as same as `DOMParser` in JavaScript.
+pub struct DOMParser {
+  context: *const ExecutingContext,
+}
+
+impl DOMParser {
+  pub fn new(context: &ExecutingContext) -> DOMParser {
+    DOMParser {
+      context
+    }
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `DOMParser.parseFromString()` in JavaScript.
+  ///
+  /// HTML is parsed leniently, the same as browsers, and the returned document always has a head and a body.
+  /// XML which is not well-formed returns `Err` instead of a document with a `<parsererror>` element.
+  /// Elements in SVG documents are SVG elements when they are in the SVG namespace, such as `<svg xmlns="http://www.w3.org/2000/svg">`.
+  ///
+  /// The returned document doesn't belong to the window, so its nodes are never rendered until they are moved into `ExecutingContext::document()`.
+  /// Comments are skipped when parsing HTML.
+  pub fn parse_from_string(&self, input: &str, mime_type: MimeType, exception_state: &ExceptionState) -> Result<Document, WebFError> {
+    let context = self.context();
+    let input_c_string = CString::new(input).unwrap();
+    let document = unsafe {
+      (context.method_pointer().parse_from_string)(context.ptr, input_c_string.as_ptr(), mime_type.to_i32(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(context));
+    }
+
+    Ok(Document::initialize(document.value, context, document.method_pointer, document.status))
+  }
+}
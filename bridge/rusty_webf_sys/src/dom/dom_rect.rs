@@ -0,0 +1,17 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+/// The size and position of an element's box, relative to the viewport.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DomRect {
+  pub x: f64,
+  pub y: f64,
+  pub width: f64,
+  pub height: f64,
+  pub top: f64,
+  pub right: f64,
+  pub bottom: f64,
+  pub left: f64,
+}
@@ -0,0 +1,160 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
+
+#[repr(C)]
+pub struct DomStringMapRustMethods {
+  pub version: c_double,
+  pub get: extern "C" fn(ptr: *const OpaquePtr, key: *const c_char, exception_state: *const OpaquePtr) -> *mut c_char,
+  pub set: extern "C" fn(ptr: *const OpaquePtr, key: *const c_char, value: *const c_char, exception_state: *const OpaquePtr) -> c_void,
+  pub remove: extern "C" fn(ptr: *const OpaquePtr, key: *const c_char, exception_state: *const OpaquePtr) -> c_void,
+  pub entries: extern "C" fn(ptr: *const OpaquePtr, length: *mut i64, exception_state: *const OpaquePtr) -> *mut DomStringMapEntryData,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for DomStringMapRustMethods {}
+
+/// A data attribute, filled by the C++ side. The strings are owned by the Rust side.
+#[repr(C)]
+pub struct DomStringMapEntryData {
+  pub key: *mut c_char,
+  pub value: *mut c_char,
+}
+
+/// The `data-*` attributes of an element, such as the one returned by `Element::dataset()`.
+///
+/// The keys are in camelCase, `userId` is stored as the `data-user-id` attribute. The map is live: it reads and
+/// writes the attributes of the element, so changes made by `Element::set_attribute()` are visible here and vice versa.
+pub struct DomStringMap {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const DomStringMapRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl DomStringMap {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const DomStringMapRustMethods, status: *const RustValueStatus) -> DomStringMap {
+    DomStringMap {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as reading `element.dataset[key]` in JavaScript.
+  /// Returns None when the element has no data attribute for the key.
+  pub fn get(&self, key: &str) -> Option<String> {
+    let exception_state = self.context().create_exception_state();
+    let key_c_string = CString::new(key).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).get)(self.ptr(), key_c_string.as_ptr(), exception_state.ptr)
+    };
+    if value.is_null() {
+      return None;
+    }
+
+    let value_string = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Some(value_string)
+  }
+
+  /// Behavior as same as writing `element.dataset[key]` in JavaScript.
+  /// Returns `Err` when the key has a `-` followed by a lowercase letter, such as `user-id`, which can't be converted to an attribute name.
+  pub fn set(&self, key: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let key_c_string = CString::new(key).unwrap();
+    let value_c_string = CString::new(value).unwrap();
+    unsafe {
+      ((*self.method_pointer).set)(self.ptr(), key_c_string.as_ptr(), value_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `delete element.dataset[key]` in JavaScript.
+  /// Removing a key which doesn't exist does nothing.
+  pub fn remove(&self, key: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let key_c_string = CString::new(key).unwrap();
+    unsafe {
+      ((*self.method_pointer).remove)(self.ptr(), key_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+
+    Ok(())
+  }
+
+  /// The camelCase keys of all the data attributes, in the order of the attributes.
+  pub fn keys(&self) -> Vec<String> {
+    self.entries().into_iter().map(|(key, _)| key).collect()
+  }
+
+  /// The keys and values of all the data attributes, in the order of the attributes.
+  /// The entries are read when this is called, later changes to the attributes are not reflected.
+  pub fn iter(&self) -> std::vec::IntoIter<(String, String)> {
+    self.entries().into_iter()
+  }
+
+  fn entries(&self) -> Vec<(String, String)> {
+    let exception_state = self.context().create_exception_state();
+    let mut length: i64 = 0;
+    let entries_ptr = unsafe {
+      ((*self.method_pointer).entries)(self.ptr(), &mut length, exception_state.ptr)
+    };
+    if entries_ptr.is_null() {
+      return Vec::new();
+    }
+
+    let entries = unsafe { std::slice::from_raw_parts(entries_ptr, length as usize) }
+      .iter()
+      .map(|entry| {
+        let key = unsafe { CStr::from_ptr(entry.key) }.to_string_lossy().into_owned();
+        let value = unsafe { CStr::from_ptr(entry.value) }.to_string_lossy().into_owned();
+        safe_free_cpp_ptr(entry.key);
+        safe_free_cpp_ptr(entry.value);
+        (key, value)
+      })
+      .collect();
+    safe_free_cpp_ptr(entries_ptr);
+    entries
+  }
+}
+
+impl Drop for DomStringMap {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
+
+impl IntoIterator for &DomStringMap {
+  type Item = (String, String);
+  type IntoIter = std::vec::IntoIter<(String, String)>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
@@ -0,0 +1,121 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct DomTokenListRustMethods {
+  pub version: c_double,
+  pub length: extern "C" fn(ptr: *const OpaquePtr) -> u32,
+  pub add: extern "C" fn(ptr: *const OpaquePtr, tokens: *const *const c_char, token_count: u32, exception_state: *const OpaquePtr) -> c_void,
+  pub remove: extern "C" fn(ptr: *const OpaquePtr, tokens: *const *const c_char, token_count: u32, exception_state: *const OpaquePtr) -> c_void,
+  pub toggle: extern "C" fn(ptr: *const OpaquePtr, token: *const c_char, exception_state: *const OpaquePtr) -> i32,
+  pub contains: extern "C" fn(ptr: *const OpaquePtr, token: *const c_char, exception_state: *const OpaquePtr) -> i32,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for DomTokenListRustMethods {}
+
+/// A live set of space-separated tokens, such as the one returned by `Element::class_list()`.
+pub struct DomTokenList {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const DomTokenListRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl DomTokenList {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const DomTokenListRustMethods, status: *const RustValueStatus) -> DomTokenList {
+    DomTokenList {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `DOMTokenList.length` in JavaScript.
+  pub fn len(&self) -> usize {
+    let value = unsafe {
+      ((*self.method_pointer).length)(self.ptr())
+    };
+    value as usize
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Behavior as same as `DOMTokenList.add()` in JavaScript.
+  pub fn add(&self, tokens: &[&str], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let tokens_c_string: Vec<CString> = tokens.iter().map(|token| CString::new(*token).unwrap()).collect();
+    let tokens_ptr: Vec<*const c_char> = tokens_c_string.iter().map(|token| token.as_ptr()).collect();
+    unsafe {
+      ((*self.method_pointer).add)(self.ptr(), tokens_ptr.as_ptr(), tokens_ptr.len() as u32, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `DOMTokenList.remove()` in JavaScript.
+  pub fn remove(&self, tokens: &[&str], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let tokens_c_string: Vec<CString> = tokens.iter().map(|token| CString::new(*token).unwrap()).collect();
+    let tokens_ptr: Vec<*const c_char> = tokens_c_string.iter().map(|token| token.as_ptr()).collect();
+    unsafe {
+      ((*self.method_pointer).remove)(self.ptr(), tokens_ptr.as_ptr(), tokens_ptr.len() as u32, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `DOMTokenList.toggle()` in JavaScript.
+  /// Returns true if the token is in the list after the call.
+  pub fn toggle(&self, token: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    let token_c_string = CString::new(token).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).toggle)(self.ptr(), token_c_string.as_ptr(), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(value != 0)
+  }
+
+  /// Behavior as same as `DOMTokenList.contains()` in JavaScript.
+  pub fn contains(&self, token: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    let token_c_string = CString::new(token).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).contains)(self.ptr(), token_c_string.as_ptr(), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(value != 0)
+  }
+}
+
+impl Drop for DomTokenList {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
@@ -4,6 +4,7 @@
 
 use std::ffi::*;
 use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
 
 #[repr(C)]
 pub struct ElementRustMethods {
@@ -11,6 +12,49 @@ pub struct ElementRustMethods {
   pub container_node: ContainerNodeRustMethods,
   pub to_blob: extern "C" fn(*const OpaquePtr, *const WebFNativeFunctionContext, *const OpaquePtr) -> c_void,
   pub to_blob_with_device_pixel_ratio: extern "C" fn(*const OpaquePtr, c_double, *const WebFNativeFunctionContext, *const OpaquePtr) -> c_void,
+  pub query_selector: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> RustValue<ElementRustMethods>,
+  pub query_selector_all: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> RustValue<NodeListRustMethods>,
+  pub class_list: extern "C" fn(*const OpaquePtr) -> RustValue<DomTokenListRustMethods>,
+  pub get_attribute: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> *mut c_char,
+  pub set_attribute: extern "C" fn(*const OpaquePtr, *const c_char, *const c_char, *const OpaquePtr) -> c_void,
+  pub remove_attribute: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> c_void,
+  pub has_attribute: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> i32,
+  pub inner_html: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> *mut c_char,
+  pub set_inner_html: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> c_void,
+  pub outer_html: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> *mut c_char,
+  pub style: extern "C" fn(*const OpaquePtr) -> RustValue<CSSStyleDeclarationRustMethods>,
+  pub get_bounding_client_rect: extern "C" fn(*const OpaquePtr, *mut DomRect, *const OpaquePtr) -> c_void,
+  pub scroll_to: extern "C" fn(*const OpaquePtr, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub scroll_by: extern "C" fn(*const OpaquePtr, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub scroll_into_view: extern "C" fn(*const OpaquePtr, *const c_char, *const c_char, i32, *const OpaquePtr) -> c_void,
+  pub scroll_top: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub set_scroll_top: extern "C" fn(*const OpaquePtr, c_double, *const OpaquePtr) -> c_void,
+  pub scroll_left: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub set_scroll_left: extern "C" fn(*const OpaquePtr, c_double, *const OpaquePtr) -> c_void,
+  pub get_elements_by_class_name: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> RustValue<HtmlCollectionRustMethods>,
+  pub get_elements_by_tag_name: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> RustValue<HtmlCollectionRustMethods>,
+  pub dataset: extern "C" fn(*const OpaquePtr) -> RustValue<DomStringMapRustMethods>,
+  pub matches: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> i32,
+  pub closest: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> RustValue<ElementRustMethods>,
+  pub tag_name: extern "C" fn(*const OpaquePtr) -> *mut c_char,
+  pub id: extern "C" fn(*const OpaquePtr) -> *mut c_char,
+  pub set_id: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> c_void,
+  pub class_name: extern "C" fn(*const OpaquePtr) -> *mut c_char,
+  pub set_class_name: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> c_void,
+  pub set_pointer_capture: extern "C" fn(*const OpaquePtr, i64, *const OpaquePtr) -> c_void,
+  pub release_pointer_capture: extern "C" fn(*const OpaquePtr, i64, *const OpaquePtr) -> c_void,
+  pub has_pointer_capture: extern "C" fn(*const OpaquePtr, i64, *const OpaquePtr) -> i32,
+  pub request_fullscreen: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> RustValue<ScriptPromiseRefRustMethods>,
+  pub get_context_2d: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> RustValue<CanvasRenderingContext2DRustMethods>,
+  pub attributes: extern "C" fn(*const OpaquePtr, *mut i64, *const OpaquePtr) -> *mut ElementAttributeData,
+  pub offset_top: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub offset_left: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub offset_width: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub offset_height: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub offset_parent: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> RustValue<ElementRustMethods>,
+  pub client_width: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub client_height: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub get_sheet: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> RustValue<CSSStyleSheetRustMethods>,
 }
 
 impl RustMethods for ElementRustMethods {}
@@ -30,7 +74,7 @@ impl Element {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -53,6 +97,7 @@ impl Element {
     });
     let callback_context_ptr = Box::into_raw(callback_context);
     unsafe {
+      self.container_node.node.event_target.assert_not_disposed();
       (((*self.method_pointer).to_blob))(event_target.ptr, callback_context_ptr, exception_state.ptr);
     }
     future_for_return
@@ -67,7 +112,7 @@ impl Element {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -90,28 +135,1038 @@ impl Element {
     });
     let callback_context_ptr = Box::into_raw(callback_context);
     unsafe {
+      self.container_node.node.event_target.assert_not_disposed();
       (((*self.method_pointer).to_blob_with_device_pixel_ratio))(event_target.ptr, device_pixel_ratio, callback_context_ptr, exception_state.ptr);
     }
     future_for_return
   }
+
+  /// Behavior as same as `element.querySelector()` in JavaScript.
+  /// Returns the first descendant element that matches the specified group of selectors, or None if there are no matches.
+  pub fn query_selector(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let selectors_c_string = CString::new(selectors).unwrap();
+    let element_value = unsafe {
+      ((*self.method_pointer).query_selector)(event_target.ptr, selectors_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    if element_value.value.is_null() {
+      return Ok(None);
+    }
+
+    Ok(Some(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status)))
+  }
+
+  /// Behavior as same as `element.matches()` in JavaScript.
+  /// Returns whether the element would be selected by the specified group of selectors, or `Err` when the selectors are invalid.
+  pub fn matches(&self, selectors: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let selectors_c_string = CString::new(selectors).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).matches)(event_target.ptr, selectors_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value != 0)
+  }
+
+  /// Behavior as same as `element.closest()` in JavaScript.
+  /// Returns the element itself or its nearest ancestor that matches the specified group of selectors, or None if there are no matches.
+  pub fn closest(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let selectors_c_string = CString::new(selectors).unwrap();
+    let element_value = unsafe {
+      ((*self.method_pointer).closest)(event_target.ptr, selectors_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    if element_value.value.is_null() {
+      return Ok(None);
+    }
+
+    Ok(Some(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status)))
+  }
+
+  /// Behavior as same as `element.querySelectorAll()` in JavaScript.
+  /// Returns a static NodeList of the descendant elements that match the specified group of selectors.
+  pub fn query_selector_all(&self, selectors: &str, exception_state: &ExceptionState) -> Result<NodeList, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let selectors_c_string = CString::new(selectors).unwrap();
+    let node_list_value = unsafe {
+      ((*self.method_pointer).query_selector_all)(event_target.ptr, selectors_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(NodeList::initialize(node_list_value.value, event_target.context(), node_list_value.method_pointer, node_list_value.status))
+  }
+
+  /// Behavior as same as `element.classList` in JavaScript.
+  /// Returns a live DomTokenList of the element's class attribute.
+  pub fn class_list(&self) -> DomTokenList {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let class_list_value = unsafe {
+      self.container_node.node.event_target.assert_not_disposed();
+      ((*self.method_pointer).class_list)(event_target.ptr)
+    };
+    DomTokenList::initialize(class_list_value.value, event_target.context(), class_list_value.method_pointer, class_list_value.status)
+  }
+
+  /// Behavior as same as `element.dataset` in JavaScript.
+  /// Returns a live DomStringMap of the element's `data-*` attributes.
+  pub fn dataset(&self) -> DomStringMap {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let dataset_value = unsafe {
+      self.container_node.node.event_target.assert_not_disposed();
+      ((*self.method_pointer).dataset)(event_target.ptr)
+    };
+    DomStringMap::initialize(dataset_value.value, event_target.context(), dataset_value.method_pointer, dataset_value.status)
+  }
+
+  /// Behavior as same as `element.attributes` in JavaScript.
+  /// Returns a snapshot of the names and values of all the attributes, such as for copying them to another element.
+  pub fn attributes(&self) -> NamedNodeMap {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    event_target.assert_not_disposed();
+    let exception_state = event_target.context().create_exception_state();
+    let mut length: i64 = 0;
+    let attributes_ptr = unsafe {
+      ((*self.method_pointer).attributes)(event_target.ptr, &mut length, exception_state.ptr)
+    };
+    if attributes_ptr.is_null() {
+      return NamedNodeMap::default();
+    }
+
+    let attributes = unsafe { std::slice::from_raw_parts(attributes_ptr, length as usize) }
+      .iter()
+      .map(|attribute| {
+        let name = unsafe { CStr::from_ptr(attribute.name) }.to_string_lossy().into_owned();
+        let value = unsafe { CStr::from_ptr(attribute.value) }.to_string_lossy().into_owned();
+        safe_free_cpp_ptr(attribute.name);
+        safe_free_cpp_ptr(attribute.value);
+        (name, value)
+      })
+      .collect();
+    safe_free_cpp_ptr(attributes_ptr);
+    NamedNodeMap::new(attributes)
+  }
+
+  /// Behavior as same as `element.tagName` in JavaScript.
+  /// The tag name of HTML elements is uppercased, such as `"DIV"`.
+  pub fn tag_name(&self) -> String {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      event_target.assert_not_disposed();
+      ((*self.method_pointer).tag_name)(event_target.ptr)
+    };
+    let string = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    string
+  }
+
+  /// Behavior as same as `element.id` in JavaScript.
+  /// Returns an empty string when the element has no id attribute.
+  pub fn id(&self) -> String {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      event_target.assert_not_disposed();
+      ((*self.method_pointer).id)(event_target.ptr)
+    };
+    let string = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    string
+  }
+
+  /// Behavior as same as setting `element.id` in JavaScript.
+  pub fn set_id(&self, id: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let id_c_string = CString::new(id).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_id)(event_target.ptr, id_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.className` in JavaScript.
+  /// Returns an empty string when the element has no class attribute.
+  pub fn class_name(&self) -> String {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      event_target.assert_not_disposed();
+      ((*self.method_pointer).class_name)(event_target.ptr)
+    };
+    let string = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    string
+  }
+
+  /// Behavior as same as setting `element.className` in JavaScript.
+  pub fn set_class_name(&self, class_name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let class_name_c_string = CString::new(class_name).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_class_name)(event_target.ptr, class_name_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.setPointerCapture()` in JavaScript.
+  /// The touch events of the pointer are targeted at this element until the capture is released or the pointer is lifted,
+  /// even when the pointer leaves the element.
+  /// Returns `Err` with a `NotFoundError` when the pointer is not active, or an `InvalidStateError` when the element is not connected.
+  pub fn set_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    unsafe {
+      ((*self.method_pointer).set_pointer_capture)(event_target.ptr, pointer_id, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.releasePointerCapture()` in JavaScript.
+  /// Returns `Err` with a `NotFoundError` when the pointer is not active, nothing happens when the pointer is captured by another element.
+  pub fn release_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    unsafe {
+      ((*self.method_pointer).release_pointer_capture)(event_target.ptr, pointer_id, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.hasPointerCapture()` in JavaScript.
+  pub fn has_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).has_pointer_capture)(event_target.ptr, pointer_id, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value != 0)
+  }
+
+  /// Behavior as same as `element.requestFullscreen()` in JavaScript.
+  /// The element is laid over the viewport until `Document::exit_fullscreen()` is called or the element is removed.
+  /// The promise is rejected and a `fullscreenerror` event is fired when the element is not connected,
+  /// or when the user did not press or lift a pointer in the last 5 seconds. A `fullscreenchange` event is fired once the element is fullscreen.
+  pub fn request_fullscreen(&self, exception_state: &ExceptionState) -> Result<Promise<()>, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let promise_value = unsafe {
+      ((*self.method_pointer).request_fullscreen)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(Promise::initialize(promise_value.value, event_target.context(), promise_value.method_pointer))
+  }
+
+  /// Sets the text of the element, reusing its text node instead of creating a new one each time.
+  /// The data of the only child is updated in place when it's a Text node,
+  /// otherwise the children are replaced with a single Text node, the same as setting `element.textContent`.
+  pub fn set_text(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let node = &self.container_node.node;
+    if let Some(child) = node.first_child() {
+      if child.next_sibling().is_none() {
+        if let Ok(text_node) = child.event_target.as_text() {
+          return text_node.set_data(text, exception_state);
+        }
+      }
+    }
+    node.set_text_content(text, exception_state)
+  }
+
+  /// Behavior as same as `canvas.getContext("2d")` in JavaScript.
+  /// Returns `Err` with a `TypeError` when the element is not a `<canvas>`.
+  pub fn get_context_2d(&self, exception_state: &ExceptionState) -> Result<CanvasRenderingContext2D, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let context_value = unsafe {
+      ((*self.method_pointer).get_context_2d)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    if context_value.value.is_null() {
+      return Err(WebFError::Other("Failed to execute 'getContext': The 2d context is not available.".to_string()));
+    }
+
+    Ok(CanvasRenderingContext2D::initialize(context_value.value, event_target.context(), context_value.method_pointer, context_value.status))
+  }
+
+  /// Behavior as same as `element.getAttribute()` in JavaScript.
+  /// Returns None if the attribute does not exist, which differs from an attribute set to an empty string.
+  pub fn get_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let name_c_string = CString::new(name).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).get_attribute)(event_target.ptr, name_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    if value.is_null() {
+      return Ok(None);
+    }
+
+    let attribute = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(Some(attribute))
+  }
+
+  /// Behavior as same as `element.setAttribute()` in JavaScript.
+  pub fn set_attribute(&self, name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let name_c_string = CString::new(name).unwrap();
+    let value_c_string = CString::new(value).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_attribute)(event_target.ptr, name_c_string.as_ptr(), value_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.setAttributeNS()` in JavaScript, such as setting `xlink:href` with `XLINK_NAMESPACE_URI`.
+  /// Returns a `NamespaceError` when the prefix of the qualified name does not match the namespace.
+  /// The attribute is stored by its qualified name, so it's read back with `get_attribute("xlink:href")`.
+  pub fn set_attribute_ns(&self, namespace: Option<&str>, qualified_name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let namespace = namespace.filter(|namespace| !namespace.is_empty());
+    let prefix = qualified_name.split_once(':').map(|(prefix, _)| prefix);
+    let is_xmlns = qualified_name == "xmlns" || prefix == Some("xmlns");
+    if (prefix.is_some() && namespace.is_none())
+      || (prefix == Some("xml") && namespace != Some(XML_NAMESPACE_URI))
+      || (is_xmlns != (namespace == Some(XMLNS_NAMESPACE_URI))) {
+      return Err(WebFError::dom_exception("NamespaceError", &format!("Failed to execute 'setAttributeNS' on 'Element': The qualified name provided ('{}') is not valid in the namespace provided.", qualified_name)));
+    }
+    self.set_attribute(qualified_name, value, exception_state)
+  }
+
+  /// Behavior as same as `element.removeAttribute()` in JavaScript.
+  pub fn remove_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let name_c_string = CString::new(name).unwrap();
+    unsafe {
+      ((*self.method_pointer).remove_attribute)(event_target.ptr, name_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.hasAttribute()` in JavaScript.
+  pub fn has_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let name_c_string = CString::new(name).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).has_attribute)(event_target.ptr, name_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value != 0)
+  }
+
+  /// Behavior as same as `element.innerHTML` in JavaScript.
+  /// Returns the HTML serialization of the element's descendants.
+  pub fn inner_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).inner_html)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    let html = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(html)
+  }
+
+  /// Behavior as same as setting `element.innerHTML` in JavaScript.
+  /// Replaces the element's children with the nodes parsed from the given HTML string.
+  pub fn set_inner_html(&self, html: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let html_c_string = CString::new(html).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_inner_html)(event_target.ptr, html_c_string.as_ptr(), exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.outerHTML` in JavaScript.
+  /// Returns the HTML serialization of the element including its descendants.
+  pub fn outer_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).outer_html)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    let html = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(html)
+  }
+
+  /// Behavior as same as `element.style` in JavaScript.
+  /// Returns the live inline style of the element.
+  ///
+  /// Returns None if the element is not an HTML, SVG or MathML element, which are the only elements with inline styles.
+  pub fn style(&self) -> Option<CSSStyleDeclaration> {
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let style_value = unsafe {
+      self.container_node.node.event_target.assert_not_disposed();
+      ((*self.method_pointer).style)(event_target.ptr)
+    };
+    if style_value.value.is_null() {
+      return None;
+    }
+    Some(CSSStyleDeclaration::initialize(style_value.value, event_target.context(), style_value.method_pointer, style_value.status))
+  }
+
+  /// Behavior as same as `element.getBoundingClientRect()` in JavaScript.
+  /// Pending style and layout changes are applied first, so the returned geometry is current.
+  pub fn get_bounding_client_rect(&self, exception_state: &ExceptionState) -> Result<DomRect, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let mut rect = DomRect::default();
+    unsafe {
+      ((*self.method_pointer).get_bounding_client_rect)(event_target.ptr, &mut rect, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(rect)
+  }
+
+  /// Behavior as same as `element.scrollTo()` in JavaScript.
+  pub fn scroll_to(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    unsafe {
+      ((*self.method_pointer).scroll_to)(event_target.ptr, x, y, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.scrollBy()` in JavaScript.
+  pub fn scroll_by(&self, dx: f64, dy: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    unsafe {
+      ((*self.method_pointer).scroll_by)(event_target.ptr, dx, dy, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.scrollIntoView()` in JavaScript.
+  /// Scrolls each scrollable ancestor of the element so that it becomes visible.
+  pub fn scroll_into_view(&self, options: ScrollIntoViewOptions, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let block_c_string = CString::new(options.block.as_str()).unwrap();
+    let inline_c_string = CString::new(options.inline.as_str()).unwrap();
+    let smooth = i32::from(options.behavior == ScrollBehavior::Smooth);
+    unsafe {
+      ((*self.method_pointer).scroll_into_view)(event_target.ptr, block_c_string.as_ptr(), inline_c_string.as_ptr(), smooth, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.offsetTop` in JavaScript.
+  /// Like the other offset and client getters, the fractional layout value is rounded to an integer.
+  /// The distance from the inner border of the offset parent to the outer border of the element, in CSS pixels.
+  pub fn offset_top(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).offset_top)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value.round() as i64)
+  }
+
+  /// Behavior as same as `element.offsetLeft` in JavaScript.
+  pub fn offset_left(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).offset_left)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value.round() as i64)
+  }
+
+  /// Behavior as same as `element.offsetWidth` in JavaScript.
+  /// The layout width of the element including the borders and padding, 0 when the element is not rendered.
+  pub fn offset_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).offset_width)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value.round() as i64)
+  }
+
+  /// Behavior as same as `element.offsetHeight` in JavaScript.
+  pub fn offset_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).offset_height)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value.round() as i64)
+  }
+
+  /// Behavior as same as `element.offsetParent` in JavaScript.
+  /// Returns the nearest positioned ancestor or the body, which `offset_top()` and `offset_left()` are relative to.
+  /// Returns None for the body, the root element, fixed positioned elements and elements which are not rendered.
+  pub fn offset_parent(&self, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let element_value = unsafe {
+      ((*self.method_pointer).offset_parent)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    if element_value.value.is_null() {
+      return Ok(None);
+    }
+
+    Ok(Some(Element::initialize(element_value.value, event_target.context(), element_value.method_pointer, element_value.status)))
+  }
+
+  /// Behavior as same as `element.clientWidth` in JavaScript.
+  /// The inner width of the element including the padding but not the borders and scrollbars.
+  pub fn client_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).client_width)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value.round() as i64)
+  }
+
+  /// Behavior as same as `element.clientHeight` in JavaScript.
+  pub fn client_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).client_height)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value.round() as i64)
+  }
+
+  // The style sheet of a `<style>` element, which is wrapped by `CSSStyleSheet`.
+  pub(crate) fn sheet(&self, exception_state: &ExceptionState) -> Result<RustValue<CSSStyleSheetRustMethods>, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let sheet_value = unsafe {
+      ((*self.method_pointer).get_sheet)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(sheet_value)
+  }
+
+  /// Behavior as same as `element.scrollTop` in JavaScript.
+  pub fn scroll_top(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).scroll_top)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value)
+  }
+
+  /// Behavior as same as setting `element.scrollTop` in JavaScript.
+  pub fn set_scroll_top(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    unsafe {
+      ((*self.method_pointer).set_scroll_top)(event_target.ptr, value, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.scrollLeft` in JavaScript.
+  pub fn scroll_left(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).scroll_left)(event_target.ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(value)
+  }
+
+  /// Behavior as same as setting `element.scrollLeft` in JavaScript.
+  pub fn set_scroll_left(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    unsafe {
+      ((*self.method_pointer).set_scroll_left)(event_target.ptr, value, exception_state.ptr);
+    }
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(())
+  }
+
+  /// Behavior as same as `element.getElementsByClassName()` in JavaScript.
+  /// Returns the descendant elements which have all the given space-separated class names, in tree order.
+  /// The collection is a snapshot which doesn't change when the DOM changes.
+  pub fn get_elements_by_class_name(&self, class_names: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let class_names_c_string = CString::new(class_names).unwrap();
+    let collection_value = unsafe {
+      ((*self.method_pointer).get_elements_by_class_name)(event_target.ptr, class_names_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(HtmlCollection::initialize(collection_value.value, event_target.context(), collection_value.method_pointer, collection_value.status))
+  }
+
+  /// Behavior as same as `element.getElementsByTagName()` in JavaScript.
+  /// Returns the descendant elements with the given tag name in tree order, or all of them when the tag name is `"*"`.
+  /// The collection is a snapshot which doesn't change when the DOM changes.
+  pub fn get_elements_by_tag_name(&self, tag_name: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.container_node.node.event_target;
+    let tag_name_c_string = CString::new(tag_name).unwrap();
+    let collection_value = unsafe {
+      ((*self.method_pointer).get_elements_by_tag_name)(event_target.ptr, tag_name_c_string.as_ptr(), exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(HtmlCollection::initialize(collection_value.value, event_target.context(), collection_value.method_pointer, collection_value.status))
+  }
+
+  /// Behavior as same as `element.append()` in JavaScript.
+  /// Inserts the nodes and strings after the last child of the element, the strings are inserted as text nodes.
+  pub fn append(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let node = convert_into_node(self.container_node.node.event_target.context(), items, exception_state)?;
+    self.container_node.node.append_child(node.as_node(), exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `element.prepend()` in JavaScript.
+  /// Inserts the nodes and strings before the first child of the element, the strings are inserted as text nodes.
+  pub fn prepend(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let node = convert_into_node(self.container_node.node.event_target.context(), items, exception_state)?;
+    let first_child = self.container_node.node.first_child();
+    self.container_node.node.insert_before(node.as_node(), first_child.as_ref(), exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `element.before()` in JavaScript.
+  /// Inserts the nodes and strings before the element in its parent, nothing happens when the element has no parent.
+  pub fn before(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let Some(parent) = self.container_node.node.parent_node() else {
+      return Ok(());
+    };
+    let mut previous_sibling = self.container_node.node.previous_sibling();
+    while let Some(sibling) = previous_sibling.as_ref().filter(|sibling| contains_node(items, sibling)) {
+      previous_sibling = sibling.previous_sibling();
+    }
+
+    let node = convert_into_node(self.container_node.node.event_target.context(), items, exception_state)?;
+    let reference = match previous_sibling {
+      Some(sibling) => sibling.next_sibling(),
+      None => parent.first_child(),
+    };
+    parent.insert_before(node.as_node(), reference.as_ref(), exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `element.after()` in JavaScript.
+  /// Inserts the nodes and strings after the element in its parent, nothing happens when the element has no parent.
+  pub fn after(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let Some(parent) = self.container_node.node.parent_node() else {
+      return Ok(());
+    };
+    let next_sibling = self.viable_next_sibling(items);
+
+    let node = convert_into_node(self.container_node.node.event_target.context(), items, exception_state)?;
+    parent.insert_before(node.as_node(), next_sibling.as_ref(), exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `element.replaceWith()` in JavaScript.
+  /// Replaces the element in its parent with the nodes and strings, nothing happens when the element has no parent.
+  pub fn replace_with(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.event_target.check_disposed()?;
+    let Some(parent) = self.container_node.node.parent_node() else {
+      return Ok(());
+    };
+    let next_sibling = self.viable_next_sibling(items);
+
+    let node = convert_into_node(self.container_node.node.event_target.context(), items, exception_state)?;
+    // The element itself could be one of the items, which is moved out of the parent by the conversion.
+    let still_in_parent = self.container_node.node.parent_node().is_some_and(|current| current.ptr() == parent.ptr());
+    if still_in_parent {
+      parent.replace_child(node.as_node(), &self.container_node.node, exception_state)?;
+    } else {
+      parent.insert_before(node.as_node(), next_sibling.as_ref(), exception_state)?;
+    }
+    Ok(())
+  }
+
+  // The first following sibling which is not one of the items, the items are inserted before it.
+  fn viable_next_sibling(&self, items: &[NodeOrString]) -> Option<Node> {
+    let mut next_sibling = self.container_node.node.next_sibling();
+    while let Some(sibling) = next_sibling.as_ref().filter(|sibling| contains_node(items, sibling)) {
+      next_sibling = sibling.next_sibling();
+    }
+    next_sibling
+  }
+}
+
+/// An item of `Element::append()` and the other methods inserting a mix of nodes and strings.
+pub enum NodeOrString<'a> {
+  Node(&'a dyn NodeMethods),
+  String(&'a str),
+}
+
+impl<'a, T: NodeMethods> From<&'a T> for NodeOrString<'a> {
+  fn from(node: &'a T) -> Self {
+    NodeOrString::Node(node)
+  }
+}
+
+impl<'a> From<&'a str> for NodeOrString<'a> {
+  fn from(text: &'a str) -> Self {
+    NodeOrString::String(text)
+  }
+}
+
+impl<'a> From<&'a String> for NodeOrString<'a> {
+  fn from(text: &'a String) -> Self {
+    NodeOrString::String(text)
+  }
+}
+
+fn contains_node(items: &[NodeOrString], node: &Node) -> bool {
+  items.iter().any(|item| matches!(item, NodeOrString::Node(item_node) if item_node.ptr() == node.ptr()))
+}
+
+// The node the items are converted into, which borrows the node when it's the only item.
+enum ConvertedNode<'a> {
+  Node(&'a Node),
+  Text(Text),
+  Fragment(DocumentFragment),
+}
+
+impl ConvertedNode<'_> {
+  fn as_node(&self) -> &Node {
+    match self {
+      ConvertedNode::Node(node) => node,
+      ConvertedNode::Text(text) => text.as_node(),
+      ConvertedNode::Fragment(fragment) => fragment.as_node(),
+    }
+  }
+}
+
+// Converts the items into a single node, the same as the "convert nodes into a node" steps of the DOM standard.
+fn convert_into_node<'a>(context: &ExecutingContext, items: &[NodeOrString<'a>], exception_state: &ExceptionState) -> Result<ConvertedNode<'a>, WebFError> {
+  let document = context.document();
+  match items {
+    [NodeOrString::Node(node)] => return Ok(ConvertedNode::Node(node.as_node())),
+    [NodeOrString::String(text)] => return Ok(ConvertedNode::Text(document.create_text_node(text, exception_state)?)),
+    _ => {}
+  }
+
+  let fragment = document.create_document_fragment(exception_state)?;
+  for item in items {
+    match item {
+      NodeOrString::Node(node) => {
+        fragment.append_child(node.as_node(), exception_state)?;
+      }
+      NodeOrString::String(text) => {
+        let text = document.create_text_node(text, exception_state)?;
+        fragment.append_child(text.as_node(), exception_state)?;
+      }
+    }
+  }
+  Ok(ConvertedNode::Fragment(fragment))
 }
 
 pub trait ElementMethods: ContainerNodeMethods {
   fn to_blob(&self, exception_state: &ExceptionState) -> WebFNativeFuture<Vec<u8>>;
   fn to_blob_with_device_pixel_ratio(&self, device_pixel_ratio: f64, exception_state: &ExceptionState) -> WebFNativeFuture<Vec<u8>>;
+  fn query_selector(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError>;
+  fn query_selector_all(&self, selectors: &str, exception_state: &ExceptionState) -> Result<NodeList, WebFError>;
+  fn matches(&self, selectors: &str, exception_state: &ExceptionState) -> Result<bool, WebFError>;
+  fn closest(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError>;
+  fn class_list(&self) -> DomTokenList;
+  fn dataset(&self) -> DomStringMap;
+  fn attributes(&self) -> NamedNodeMap;
+  fn tag_name(&self) -> String;
+  fn id(&self) -> String;
+  fn set_id(&self, id: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn class_name(&self) -> String;
+  fn set_class_name(&self, class_name: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn set_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn release_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn has_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<bool, WebFError>;
+  fn request_fullscreen(&self, exception_state: &ExceptionState) -> Result<Promise<()>, WebFError>;
+  fn get_context_2d(&self, exception_state: &ExceptionState) -> Result<CanvasRenderingContext2D, WebFError>;
+  fn set_text(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn get_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<Option<String>, WebFError>;
+  fn set_attribute(&self, name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn set_attribute_ns(&self, namespace: Option<&str>, qualified_name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn remove_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn has_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<bool, WebFError>;
+  fn inner_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError>;
+  fn set_inner_html(&self, html: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn outer_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError>;
+  fn style(&self) -> Option<CSSStyleDeclaration>;
+  fn get_bounding_client_rect(&self, exception_state: &ExceptionState) -> Result<DomRect, WebFError>;
+  fn scroll_to(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn scroll_by(&self, dx: f64, dy: f64, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn scroll_into_view(&self, options: ScrollIntoViewOptions, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn offset_top(&self, exception_state: &ExceptionState) -> Result<i64, WebFError>;
+  fn offset_left(&self, exception_state: &ExceptionState) -> Result<i64, WebFError>;
+  fn offset_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError>;
+  fn offset_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError>;
+  fn offset_parent(&self, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError>;
+  fn client_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError>;
+  fn client_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError>;
+  fn scroll_top(&self, exception_state: &ExceptionState) -> Result<f64, WebFError>;
+  fn set_scroll_top(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn scroll_left(&self, exception_state: &ExceptionState) -> Result<f64, WebFError>;
+  fn set_scroll_left(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn get_elements_by_class_name(&self, class_names: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError>;
+  fn get_elements_by_tag_name(&self, tag_name: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError>;
+  fn append(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn prepend(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn before(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn after(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn replace_with(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError>;
 }
 
 impl ContainerNodeMethods for Element {}
 
 impl NodeMethods for Element {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.container_node.node.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.container_node.node.remove_child(target_node, exception_state)
   }
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.container_node.node.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.container_node.node.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.container_node.node.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.container_node.node.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.container_node.node.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.container_node.node.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.container_node.node.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.container_node.node.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.container_node.node.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.container_node.node.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.node.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     &self.container_node.node
   }
@@ -140,20 +1195,28 @@ impl EventTargetMethods for Element {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.container_node.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.container_node.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.container_node.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.container_node.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.container_node.downgrade()
+  }
 }
 
 impl ElementMethods for Element {
@@ -163,4 +1226,157 @@ impl ElementMethods for Element {
   fn to_blob_with_device_pixel_ratio(&self, device_pixel_ratio: f64, exception_state: &ExceptionState) -> WebFNativeFuture<Vec<u8>> {
     self.to_blob_with_device_pixel_ratio(device_pixel_ratio, exception_state)
   }
+  fn query_selector(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.query_selector(selectors, exception_state)
+  }
+  fn query_selector_all(&self, selectors: &str, exception_state: &ExceptionState) -> Result<NodeList, WebFError> {
+    self.query_selector_all(selectors, exception_state)
+  }
+  fn matches(&self, selectors: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.matches(selectors, exception_state)
+  }
+  fn closest(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.closest(selectors, exception_state)
+  }
+  fn class_list(&self) -> DomTokenList {
+    self.class_list()
+  }
+  fn dataset(&self) -> DomStringMap {
+    self.dataset()
+  }
+
+  fn attributes(&self) -> NamedNodeMap {
+    self.attributes()
+  }
+  fn tag_name(&self) -> String {
+    self.tag_name()
+  }
+  fn id(&self) -> String {
+    self.id()
+  }
+  fn set_id(&self, id: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_id(id, exception_state)
+  }
+  fn class_name(&self) -> String {
+    self.class_name()
+  }
+  fn set_class_name(&self, class_name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_class_name(class_name, exception_state)
+  }
+  fn set_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_pointer_capture(pointer_id, exception_state)
+  }
+  fn release_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.release_pointer_capture(pointer_id, exception_state)
+  }
+  fn has_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.has_pointer_capture(pointer_id, exception_state)
+  }
+  fn request_fullscreen(&self, exception_state: &ExceptionState) -> Result<Promise<()>, WebFError> {
+    self.request_fullscreen(exception_state)
+  }
+  fn get_context_2d(&self, exception_state: &ExceptionState) -> Result<CanvasRenderingContext2D, WebFError> {
+    self.get_context_2d(exception_state)
+  }
+  fn set_text(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_text(text, exception_state)
+  }
+  fn get_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.get_attribute(name, exception_state)
+  }
+  fn set_attribute(&self, name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_attribute(name, value, exception_state)
+  }
+  fn set_attribute_ns(&self, namespace: Option<&str>, qualified_name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_attribute_ns(namespace, qualified_name, value, exception_state)
+  }
+  fn remove_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.remove_attribute(name, exception_state)
+  }
+  fn has_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.has_attribute(name, exception_state)
+  }
+  fn inner_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.inner_html(exception_state)
+  }
+  fn set_inner_html(&self, html: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_inner_html(html, exception_state)
+  }
+  fn outer_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.outer_html(exception_state)
+  }
+  fn style(&self) -> Option<CSSStyleDeclaration> {
+    self.style()
+  }
+  fn get_bounding_client_rect(&self, exception_state: &ExceptionState) -> Result<DomRect, WebFError> {
+    self.get_bounding_client_rect(exception_state)
+  }
+  fn scroll_to(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.scroll_to(x, y, exception_state)
+  }
+  fn scroll_by(&self, dx: f64, dy: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.scroll_by(dx, dy, exception_state)
+  }
+  fn scroll_into_view(&self, options: ScrollIntoViewOptions, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.scroll_into_view(options, exception_state)
+  }
+  fn offset_top(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.offset_top(exception_state)
+  }
+  fn offset_left(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.offset_left(exception_state)
+  }
+  fn offset_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.offset_width(exception_state)
+  }
+  fn offset_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.offset_height(exception_state)
+  }
+  fn offset_parent(&self, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.offset_parent(exception_state)
+  }
+  fn client_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.client_width(exception_state)
+  }
+  fn client_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.client_height(exception_state)
+  }
+  fn scroll_top(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    self.scroll_top(exception_state)
+  }
+  fn set_scroll_top(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_scroll_top(value, exception_state)
+  }
+  fn scroll_left(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    self.scroll_left(exception_state)
+  }
+  fn set_scroll_left(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_scroll_left(value, exception_state)
+  }
+  fn get_elements_by_class_name(&self, class_names: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    self.get_elements_by_class_name(class_names, exception_state)
+  }
+  fn get_elements_by_tag_name(&self, tag_name: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    self.get_elements_by_tag_name(tag_name, exception_state)
+  }
+
+  fn append(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.append(items, exception_state)
+  }
+
+  fn prepend(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.prepend(items, exception_state)
+  }
+
+  fn before(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.before(items, exception_state)
+  }
+
+  fn after(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.after(items, exception_state)
+  }
+
+  fn replace_with(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.replace_with(items, exception_state)
+  }
 }
@@ -0,0 +1,81 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use crate::*;
+
+type BuildStep<'a> = Box<dyn FnOnce(&Element, &ExceptionState) -> Result<(), WebFError> + 'a>;
+
+/// Builds an element with its attributes, children and event listeners, created by `Document::build_element()`.
+///
+/// The steps are applied in the order they were chained, and nothing is created until `build()` is called.
+pub struct ElementBuilder<'a> {
+  document: &'a Document,
+  tag: String,
+  steps: Vec<BuildStep<'a>>,
+}
+
+impl<'a> ElementBuilder<'a> {
+  pub(crate) fn new(document: &'a Document, tag: &str) -> ElementBuilder<'a> {
+    ElementBuilder {
+      document,
+      tag: tag.to_string(),
+      steps: Vec::new(),
+    }
+  }
+
+  /// Sets the attribute, the same as `Element::set_attribute()`.
+  pub fn attr(mut self, name: &str, value: &str) -> Self {
+    let name = name.to_string();
+    let value = value.to_string();
+    self.steps.push(Box::new(move |element, exception_state| element.set_attribute(&name, &value, exception_state)));
+    self
+  }
+
+  /// Adds the class to the class list, the classes set by `attr("class", ..)` are kept.
+  pub fn class(mut self, class_name: &str) -> Self {
+    let class_name = class_name.to_string();
+    self.steps.push(Box::new(move |element, exception_state| element.class_list().add(&[&class_name], exception_state)));
+    self
+  }
+
+  /// Appends a text node with the data.
+  pub fn text(mut self, data: &str) -> Self {
+    let data = data.to_string();
+    let document = self.document;
+    self.steps.push(Box::new(move |element, exception_state| {
+      let text = document.create_text_node(&data, exception_state)?;
+      element.append_child(text.as_node(), exception_state).map(|_| ())
+    }));
+    self
+  }
+
+  /// Appends the node, it's moved from its current parent if it has one.
+  pub fn child<T: NodeMethods + 'a>(mut self, child: T) -> Self {
+    self.steps.push(Box::new(move |element, exception_state| element.append_child(child.as_node(), exception_state).map(|_| ())));
+    self
+  }
+
+  /// Adds the event listener with the default options, the same as `EventTargetMethods::add_event_listener()`.
+  pub fn on(mut self, event_name: &str, callback: EventListenerCallback) -> Self {
+    let event_name = event_name.to_string();
+    self.steps.push(Box::new(move |element, exception_state| {
+      let options = AddEventListenerOptions {
+        capture: 0,
+        passive: 0,
+        once: 0,
+      };
+      element.add_event_listener(&event_name, callback, &options, exception_state)
+    }));
+    self
+  }
+
+  /// Creates the element and applies the steps, returns the first error of them.
+  pub fn build(self, exception_state: &ExceptionState) -> Result<Element, WebFError> {
+    let element = self.document.create_element(&self.tag, exception_state)?;
+    for step in self.steps {
+      step(&element, exception_state)?;
+    }
+    Ok(element)
+  }
+}
@@ -36,32 +36,33 @@ impl CustomEvent {
   pub fn context<'a>(&self) -> &'a ExecutingContext {
     self.event.context()
   }
-  pub fn detail(&self) -> ScriptValueRef {
+  pub fn detail(&self) -> Option<ScriptValue> {
     let value = unsafe {
       ((*self.method_pointer).detail)(self.ptr())
     };
-    ScriptValueRef::initialize(value.value, self.context(), value.method_pointer)
+    let value = ScriptValueRef::initialize(value.value, self.context(), value.method_pointer);
+    value.to_script_value()
   }
-  pub fn init_custom_event(&self, type_: &str, can_bubble: bool, cancelable: bool, detail: &ScriptValueRef, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn init_custom_event(&self, type_: &str, can_bubble: bool, cancelable: bool, detail: &ScriptValueRef, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).init_custom_event)(self.ptr(), CString::new(type_).unwrap().as_ptr(), i32::from(can_bubble), i32::from(cancelable), detail.ptr, exception_state.ptr);
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
 }
 pub trait CustomEventMethods: EventMethods {
-  fn detail(&self) -> ScriptValueRef;
-  fn init_custom_event(&self, type_: &str, can_bubble: bool, cancelable: bool, detail: &ScriptValueRef, exception_state: &ExceptionState) -> Result<(), String>;
+  fn detail(&self) -> Option<ScriptValue>;
+  fn init_custom_event(&self, type_: &str, can_bubble: bool, cancelable: bool, detail: &ScriptValueRef, exception_state: &ExceptionState) -> Result<(), WebFError>;
   fn as_custom_event(&self) -> &CustomEvent;
 }
 impl CustomEventMethods for CustomEvent {
-  fn detail(&self) -> ScriptValueRef {
+  fn detail(&self) -> Option<ScriptValue> {
     self.detail()
   }
-  fn init_custom_event(&self, type_: &str, can_bubble: bool, cancelable: bool, detail: &ScriptValueRef, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_custom_event(&self, type_: &str, can_bubble: bool, cancelable: bool, detail: &ScriptValueRef, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.init_custom_event(type_, can_bubble, cancelable, detail, exception_state)
   }
   fn as_custom_event(&self) -> &CustomEvent {
@@ -75,7 +76,7 @@ impl EventMethods for CustomEvent {
   fn cancel_bubble(&self) -> bool {
     self.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -84,6 +85,12 @@ impl EventMethods for CustomEvent {
   fn current_target(&self) -> EventTarget {
     self.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.event.default_prevented()
   }
@@ -102,16 +109,16 @@ impl EventMethods for CustomEvent {
   fn type_(&self) -> String {
     self.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
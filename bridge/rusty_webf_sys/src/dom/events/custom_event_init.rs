@@ -0,0 +1,10 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+/// Options of `Document::create_custom_event()`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CustomEventInit {
+  pub bubbles: bool,
+  pub cancelable: bool,
+}
@@ -18,8 +18,36 @@ enum EventType {
   UIEvent = 8,
   FocusEvent = 9,
   InputEvent = 10,
-  MouseEvent = 11,
-  PointerEvent = 12,
+  KeyboardEvent = 11,
+  MouseEvent = 12,
+  PointerEvent = 13,
+  WheelEvent = 14,
+  PopStateEvent = 15,
+  CompositionEvent = 16,
+  TouchEvent = 17,
+  StorageEvent = 18,
+}
+/// The phase of the event flow that is currently being evaluated, behavior as same as `Event.eventPhase` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+  /// The event is not being dispatched.
+  None,
+  /// The event is being propagated through the target's ancestors, from the root down.
+  Capturing,
+  /// The event has arrived at its target.
+  AtTarget,
+  /// The event is propagating back up through the target's ancestors.
+  Bubbling,
+}
+impl EventPhase {
+  fn from_raw(value: i64) -> EventPhase {
+    match value {
+      1 => EventPhase::Capturing,
+      2 => EventPhase::AtTarget,
+      3 => EventPhase::Bubbling,
+      _ => EventPhase::None,
+    }
+  }
 }
 #[repr(C)]
 pub struct EventRustMethods {
@@ -29,6 +57,7 @@ pub struct EventRustMethods {
   pub set_cancel_bubble: extern "C" fn(ptr: *const OpaquePtr, value: i32, exception_state: *const OpaquePtr) -> bool,
   pub cancelable: extern "C" fn(ptr: *const OpaquePtr) -> i32,
   pub current_target: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<EventTargetRustMethods>,
+  pub event_phase: extern "C" fn(ptr: *const OpaquePtr) -> i64,
   pub default_prevented: extern "C" fn(ptr: *const OpaquePtr) -> i32,
   pub src_element: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<EventTargetRustMethods>,
   pub target: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<EventTargetRustMethods>,
@@ -42,6 +71,7 @@ pub struct EventRustMethods {
   pub stop_propagation: extern "C" fn(ptr: *const OpaquePtr, exception_state: *const OpaquePtr) -> c_void,
   pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
   pub dynamic_to: extern "C" fn(ptr: *const OpaquePtr, type_: EventType) -> RustValue<c_void>,
+  pub composed_path: extern "C" fn(ptr: *const OpaquePtr, length: *mut i64) -> *mut RustValue<EventTargetRustMethods>,
 }
 pub struct Event {
   pub ptr: *const OpaquePtr,
@@ -77,12 +107,12 @@ impl Event {
     };
     value != 0
   }
-  pub fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).set_cancel_bubble)(self.ptr(), i32::from(value), exception_state.ptr)
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
@@ -98,6 +128,31 @@ impl Event {
     };
     EventTarget::initialize(value.value, self.context(), value.method_pointer, value.status)
   }
+  /// Behavior as same as `Event.eventPhase` in JavaScript.
+  pub fn event_phase(&self) -> EventPhase {
+    let value = unsafe {
+      ((*self.method_pointer).event_phase)(self.ptr())
+    };
+    EventPhase::from_raw(value)
+  }
+  /// Behavior as same as `Event.composedPath()` in JavaScript.
+  /// Returns the targets the event propagates through, from the target up to the document and the window.
+  /// The path is only available while the event is being dispatched, it's empty otherwise.
+  pub fn composed_path(&self) -> Vec<EventTarget> {
+    let mut length: i64 = 0;
+    let path_ptr = unsafe {
+      ((*self.method_pointer).composed_path)(self.ptr(), &mut length)
+    };
+    if path_ptr.is_null() {
+      return Vec::new();
+    }
+    let path = unsafe { std::slice::from_raw_parts(path_ptr, length as usize) }
+      .iter()
+      .map(|target| EventTarget::initialize(target.value, self.context(), target.method_pointer, target.status))
+      .collect();
+    crate::memory_utils::safe_free_cpp_ptr(path_ptr);
+    path
+  }
   pub fn default_prevented(&self) -> bool {
     let value = unsafe {
       ((*self.method_pointer).default_prevented)(self.ptr())
@@ -135,39 +190,42 @@ impl Event {
     let value = unsafe { std::ffi::CStr::from_ptr(value) };
     value.to_str().unwrap().to_string()
   }
-  pub fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).init_event)(self.ptr(), CString::new(type_).unwrap().as_ptr(), i32::from(bubbles), i32::from(cancelable), exception_state.ptr);
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
-  pub fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  /// Behavior as same as `preventDefault()` in JavaScript.
+  /// Returns `Err(WebFError::DomException)` named `NotAllowedError` when it is called by a passive listener,
+  /// which can not cancel the event, where JavaScript only logs a warning.
+  pub fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).prevent_default)(self.ptr(), exception_state.ptr);
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
-  pub fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).stop_immediate_propagation)(self.ptr(), exception_state.ptr);
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
-  pub fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).stop_propagation)(self.ptr(), exception_state.ptr);
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
@@ -271,6 +329,16 @@ impl Event {
     }
     Ok(InputEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const InputEventRustMethods, raw_ptr.status))
   }
+  pub fn as_keyboard_event(&self) -> Result<KeyboardEvent, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventType::KeyboardEvent)
+    };
+    if (raw_ptr.value == std::ptr::null()) {
+      return Err("The type value of Event does not belong to the KeyboardEvent type.");
+    }
+    Ok(KeyboardEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const KeyboardEventRustMethods, raw_ptr.status))
+  }
   pub fn as_mouse_event(&self) -> Result<MouseEvent, &str> {
     let raw_ptr = unsafe {
       assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
@@ -291,10 +359,63 @@ impl Event {
     }
     Ok(PointerEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const PointerEventRustMethods, raw_ptr.status))
   }
+  pub fn as_wheel_event(&self) -> Result<WheelEvent, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventType::WheelEvent)
+    };
+    if (raw_ptr.value == std::ptr::null()) {
+      return Err("The type value of Event does not belong to the WheelEvent type.");
+    }
+    Ok(WheelEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const WheelEventRustMethods, raw_ptr.status))
+  }
+  pub fn as_pop_state_event(&self) -> Result<PopStateEvent, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventType::PopStateEvent)
+    };
+    if (raw_ptr.value == std::ptr::null()) {
+      return Err("The type value of Event does not belong to the PopStateEvent type.");
+    }
+    Ok(PopStateEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const PopStateEventRustMethods, raw_ptr.status))
+  }
+  pub fn as_composition_event(&self) -> Result<CompositionEvent, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventType::CompositionEvent)
+    };
+    if (raw_ptr.value == std::ptr::null()) {
+      return Err("The type value of Event does not belong to the CompositionEvent type.");
+    }
+    Ok(CompositionEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const CompositionEventRustMethods, raw_ptr.status))
+  }
+  pub fn as_touch_event(&self) -> Result<TouchEvent, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventType::TouchEvent)
+    };
+    if (raw_ptr.value == std::ptr::null()) {
+      return Err("The type value of Event does not belong to the TouchEvent type.");
+    }
+    Ok(TouchEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const TouchEventRustMethods, raw_ptr.status))
+  }
+  pub fn as_storage_event(&self) -> Result<StorageEvent, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventType::StorageEvent)
+    };
+    if (raw_ptr.value == std::ptr::null()) {
+      return Err("The type value of Event does not belong to the StorageEvent type.");
+    }
+    Ok(StorageEvent::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const StorageEventRustMethods, raw_ptr.status))
+  }
 }
 impl Drop for Event {
   fn drop(&mut self) {
     unsafe {
+      if (*self.status).disposed {
+        return;
+      }
       ((*self.method_pointer).release)(self.ptr());
     }
   }
@@ -302,19 +423,21 @@ impl Drop for Event {
 pub trait EventMethods {
   fn bubbles(&self) -> bool;
   fn cancel_bubble(&self) -> bool;
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String>;
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError>;
   fn cancelable(&self) -> bool;
   fn current_target(&self) -> EventTarget;
+  fn event_phase(&self) -> EventPhase;
+  fn composed_path(&self) -> Vec<EventTarget>;
   fn default_prevented(&self) -> bool;
   fn src_element(&self) -> EventTarget;
   fn target(&self) -> EventTarget;
   fn is_trusted(&self) -> bool;
   fn time_stamp(&self) -> f64;
   fn type_(&self) -> String;
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String>;
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String>;
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String>;
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String>;
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError>;
   fn as_event(&self) -> &Event;
 }
 impl EventMethods for Event {
@@ -324,7 +447,7 @@ impl EventMethods for Event {
   fn cancel_bubble(&self) -> bool {
     self.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -333,6 +456,12 @@ impl EventMethods for Event {
   fn current_target(&self) -> EventTarget {
     self.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.default_prevented()
   }
@@ -351,16 +480,16 @@ impl EventMethods for Event {
   fn type_(&self) -> String {
     self.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
@@ -2,7 +2,9 @@
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 
+use std::cell::RefCell;
 use std::ffi::*;
+use std::sync::atomic::{AtomicU32, Ordering};
 use crate::*;
 
 pub trait RustMethods {}
@@ -45,10 +47,17 @@ pub struct EventTargetRustMethods {
     exception_state: *const OpaquePtr) -> bool,
   pub release: extern "C" fn(event_target: *const OpaquePtr),
   pub dynamic_to: extern "C" fn(event_target: *const OpaquePtr, event_target_type: EventTargetType) -> RustValue<c_void>,
+  pub listener_count: extern "C" fn(event_target: *const OpaquePtr, event_name: *const c_char) -> i64,
+  pub downgrade: extern "C" fn(event_target: *const OpaquePtr) -> *const RustValueStatus,
+  pub release_weak: extern "C" fn(event_target: *const OpaquePtr, weak_status: *const RustValueStatus),
 }
 
 impl RustMethods for EventTargetRustMethods {}
 
+// Id 0 is shared by the listeners registered with `add_event_listener`, which `remove_event_listener` matches by callback.
+// The guarded, keyed, handle and signal variants each take a unique id, so only their own listener is removed.
+static NEXT_LISTENER_ID: AtomicU32 = AtomicU32::new(1);
+
 
 pub struct EventTarget {
   pub ptr: *const OpaquePtr,
@@ -67,25 +76,153 @@ impl EventTarget {
     unsafe { &*self.context }
   }
 
+  /// Whether the underline C++ impl had been disposed.
+  /// The fallible methods return `Err(WebFError::Disposed)` for a disposed target, while the others panic.
+  pub fn is_disposed(&self) -> bool {
+    unsafe { (*self.status).disposed }
+  }
+
+  pub(crate) fn assert_not_disposed(&self) {
+    assert!(!self.is_disposed(), "The underline C++ impl of this ptr({:?}) had been disposed", self.method_pointer);
+  }
+
+  /// Returns `Err(WebFError::Disposed)` instead of panicking, used by the fallible methods.
+  pub(crate) fn check_disposed(&self) -> Result<(), WebFError> {
+    if self.is_disposed() {
+      return Err(WebFError::Disposed);
+    }
+    Ok(())
+  }
+
   pub fn add_event_listener(
     &self,
     event_name: &str,
     callback: EventListenerCallback,
     options: &AddEventListenerOptions,
     exception_state: &ExceptionState,
-  ) -> Result<(), String> {
+  ) -> Result<(), WebFError> {
+    self.add_event_listener_with_id(event_name, callback, options, 0, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but returns a guard which removes the listener when dropped.
+  /// Each guarded listener carries its own id, so only the listener registered by this call is removed.
+  pub fn add_event_listener_guarded(
+    &self,
+    event_name: &str,
+    callback: EventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState,
+  ) -> Result<ListenerGuard, WebFError> {
+    let listener_id = NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed);
+    self.add_event_listener_with_id(event_name, callback, options, listener_id, exception_state)?;
+
+    // Hold a reference of the target so that it's still alive when the guard is dropped.
+    let raw_ptr = unsafe {
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventTargetType::EventTarget)
+    };
+    let event_target = EventTarget::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const EventTargetRustMethods, raw_ptr.status);
+
+    Ok(ListenerGuard {
+      event_target,
+      event_name: event_name.to_string(),
+      listener_id,
+      removed: false,
+    })
+  }
+
+  /// Same as `add_event_listener`, but returns a key which identifies the registered listener.
+  /// Pass the key to `remove_event_listener_by_key` to remove exactly this listener,
+  /// even when other listeners with identical callbacks are registered on the same target and type.
+  pub fn add_event_listener_keyed(
+    &self,
+    event_name: &str,
+    callback: EventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState,
+  ) -> Result<ListenerKey, WebFError> {
+    let listener_id = NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed);
+    self.add_event_listener_with_id(event_name, callback, options, listener_id, exception_state)?;
+    Ok(ListenerKey {
+      event_name: event_name.to_string(),
+      listener_id,
+    })
+  }
+
+  /// Removes the listener registered by `add_event_listener_keyed`.
+  /// Removing a listener which was removed already does nothing, the same as `removeEventListener()` in JavaScript.
+  pub fn remove_event_listener_by_key(&self, key: &ListenerKey, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    // The callback is never invoked, it's only used to carry the listener id for matching.
+    self.remove_event_listener_with_id(&key.event_name, Box::new(|_| {}), key.listener_id, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but the callback also receives a handle of the listener,
+  /// so that it can remove itself by calling `ListenerHandle::remove()`.
+  ///
+  /// Removing the listener while the event is being dispatched only skips its later invocations,
+  /// the other listeners of the event are still invoked.
+  pub fn add_event_listener_with_handle(
+    &self,
+    event_name: &str,
+    callback: EventListenerWithHandleCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState,
+  ) -> Result<(), WebFError> {
+    let listener_id = NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed);
+    let handle_event_name = event_name.to_string();
+    // The handle is created for each invocation from the current target instead of being captured,
+    // which would keep the target alive for as long as the listener is registered on it.
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      let handle = ListenerHandle {
+        event_target: event.current_target(),
+        event_name: handle_event_name.clone(),
+        listener_id,
+      };
+      callback(event, &handle);
+    });
+    self.add_event_listener_with_id(event_name, event_callback, options, listener_id, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but the listener will be removed once the controller of `signal` is aborted.
+  /// Nothing will be registered if the signal was already aborted.
+  pub fn add_event_listener_with_signal(
+    &self,
+    event_name: &str,
+    callback: EventListenerCallback,
+    options: &AddEventListenerOptions,
+    signal: &AbortSignal,
+    exception_state: &ExceptionState,
+  ) -> Result<(), WebFError> {
+    if signal.aborted() {
+      return Ok(());
+    }
+    let key = self.add_event_listener_keyed(event_name, callback, options, exception_state)?;
+    signal.track(self.downgrade(), key);
+    Ok(())
+  }
+
+  fn add_event_listener_with_id(
+    &self,
+    event_name: &str,
+    callback: EventListenerCallback,
+    options: &AddEventListenerOptions,
+    listener_id: u32,
+    exception_state: &ExceptionState,
+  ) -> Result<(), WebFError> {
+    self.check_disposed()?;
     let callback_context_data = Box::new(EventCallbackContextData {
       executing_context_ptr: self.context().ptr,
       executing_context_method_pointer: self.context().method_pointer(),
       executing_context_meta_data: self.context().meta_data,
       executing_context_status: self.context().status,
-      func: callback,
+      func: Some(callback),
+      once: options.once != 0,
     });
     let callback_context_data_ptr = Box::into_raw(callback_context_data);
     let callback_context = Box::new(EventCallbackContext {
       callback: invoke_event_listener_callback,
       free_ptr: release_event_listener_callback,
-      ptr: callback_context_data_ptr
+      ptr: callback_context_data_ptr,
+      listener_id,
     });
     let callback_context_ptr = Box::into_raw(callback_context);
     let c_event_name = CString::new(event_name).unwrap();
@@ -98,7 +235,7 @@ impl EventTarget {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
 
     Ok(())
@@ -109,19 +246,32 @@ impl EventTarget {
     event_name: &str,
     callback: EventListenerCallback,
     exception_state: &ExceptionState,
-  ) -> Result<(), String> {
+  ) -> Result<(), WebFError> {
+    self.remove_event_listener_with_id(event_name, callback, 0, exception_state)
+  }
+
+  fn remove_event_listener_with_id(
+    &self,
+    event_name: &str,
+    callback: EventListenerCallback,
+    listener_id: u32,
+    exception_state: &ExceptionState,
+  ) -> Result<(), WebFError> {
+    self.check_disposed()?;
     let callback_context_data = Box::new(EventCallbackContextData {
       executing_context_ptr: self.context().ptr,
       executing_context_method_pointer: self.context().method_pointer(),
       executing_context_meta_data: self.context().meta_data,
       executing_context_status: self.context().status,
-      func: callback,
+      func: Some(callback),
+      once: false,
     });
     let callback_context_data_ptr = Box::into_raw(callback_context_data);
     let callback_context = Box::new(EventCallbackContext {
       callback: invoke_event_listener_callback,
       free_ptr: release_event_listener_callback,
-      ptr: callback_context_data_ptr
+      ptr: callback_context_data_ptr,
+      listener_id,
     });
     let callback_context_ptr = Box::into_raw(callback_context);
     let c_event_name = CString::new(event_name).unwrap();
@@ -133,17 +283,55 @@ impl EventTarget {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
 
     Ok(())
   }
 
-  pub fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
-    unsafe {
-      assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+  /// The number of listeners registered for `event_name`, including the ones registered by JavaScript
+  /// and the `on<event>` handlers. Mainly for debugging and test assertions.
+  pub fn listener_count(&self, event_name: &str) -> Result<usize, WebFError> {
+    self.check_disposed()?;
+    let c_event_name = CString::new(event_name).unwrap();
+    let count = unsafe {
+      ((*self.method_pointer).listener_count)(self.ptr, c_event_name.as_ptr())
+    };
+    Ok(count as usize)
+  }
+
+  /// Creates a weak reference of the target which doesn't keep it alive, see `WeakEventTarget`.
+  pub fn downgrade(&self) -> WeakEventTarget {
+    self.assert_not_disposed();
+    let weak_status = unsafe {
+      ((*self.method_pointer).downgrade)(self.ptr)
+    };
+    WeakEventTarget {
+      ptr: self.ptr,
+      weak_status,
+      context: self.context,
+      method_pointer: self.method_pointer,
+    }
+  }
+
+  /// Whether any listener is registered for `event_name`.
+  pub fn has_listener(&self, event_name: &str) -> Result<bool, WebFError> {
+    Ok(self.listener_count(event_name)? > 0)
+  }
+
+  /// Behavior as same as `dispatchEvent()` in JavaScript.
+  /// Returns `Ok(false)` when the event is cancelable and a listener called `prevent_default()`, `Ok(true)` otherwise.
+  /// Errors thrown by the listeners are reported rather than returned, so `Err` means the event could not be dispatched,
+  /// such as an event which is being dispatched already.
+  pub fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.check_disposed()?;
+    let not_canceled = unsafe {
       ((*self.method_pointer).dispatch_event)(self.ptr, event.ptr, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
     }
+    Ok(not_canceled)
   }
 
   pub fn as_node(&self) -> Result<Node, &str> {
@@ -201,6 +389,28 @@ impl EventTarget {
     Ok(Document::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const DocumentRustMethods, raw_ptr.status))
   }
 
+  pub fn as_document_fragment(&self) -> Result<DocumentFragment, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*self.status).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventTargetType::DocumentFragment)
+    };
+    if raw_ptr.value.is_null() {
+      return Err("The type value of event_target does not belong to the DocumentFragment type.");
+    }
+    Ok(DocumentFragment::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const DocumentFragmentRustMethods, raw_ptr.status))
+  }
+
+  pub fn as_text(&self) -> Result<Text, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*self.status).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventTargetType::Text)
+    };
+    if raw_ptr.value.is_null() {
+      return Err("The type value of event_target does not belong to the Text type.");
+    }
+    Ok(Text::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const TextNodeRustMethods, raw_ptr.status))
+  }
+
   pub fn as_html_element(&self) -> Result<HTMLElement, &str> {
     let raw_ptr = unsafe {
       assert!(!(*((*self).status)).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
@@ -211,6 +421,76 @@ impl EventTarget {
     }
     Ok(HTMLElement::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const HTMLElementRustMethods, raw_ptr.status))
   }
+
+  pub fn as_comment(&self) -> Result<Comment, &str> {
+    let raw_ptr = unsafe {
+      assert!(!(*self.status).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventTargetType::Comment)
+    };
+    if raw_ptr.value.is_null() {
+      return Err("The type value of event_target does not belong to the Comment type.");
+    }
+    Ok(Comment::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const CommentRustMethods, raw_ptr.status))
+  }
+
+  /// Converts the target to the concrete type `T`, such as `Element` or `Window`.
+  /// Returns None when the target does not belong to the type, same as the `as_*` methods but generic over the type.
+  pub fn downcast<T: FromEventTarget>(&self) -> Option<T> {
+    let raw_ptr = unsafe {
+      assert!(!(*self.status).disposed, "The underline C++ impl of this ptr({:?}) had been disposed", (self.method_pointer));
+      ((*self.method_pointer).dynamic_to)(self.ptr, T::EVENT_TARGET_TYPE)
+    };
+    if raw_ptr.value.is_null() {
+      return None;
+    }
+    Some(T::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const EventTargetRustMethods, raw_ptr.status))
+  }
+}
+
+/// The types which an `EventTarget` could be converted to by `EventTarget::downcast()`.
+pub trait FromEventTarget: EventTargetMethods + Sized {
+  /// The type which is checked by the C++ side before the conversion.
+  const EVENT_TARGET_TYPE: EventTargetType;
+}
+
+impl FromEventTarget for EventTarget {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::EventTarget;
+}
+
+impl FromEventTarget for Node {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::Node;
+}
+
+impl FromEventTarget for ContainerNode {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::ContainerNode;
+}
+
+impl FromEventTarget for Window {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::Window;
+}
+
+impl FromEventTarget for Document {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::Document;
+}
+
+impl FromEventTarget for Element {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::Element;
+}
+
+impl FromEventTarget for HTMLElement {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::HTMLElement;
+}
+
+impl FromEventTarget for DocumentFragment {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::DocumentFragment;
+}
+
+impl FromEventTarget for Text {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::Text;
+}
+
+impl FromEventTarget for Comment {
+  const EVENT_TARGET_TYPE: EventTargetType = EventTargetType::Comment;
 }
 
 pub trait EventTargetMethods {
@@ -224,15 +504,160 @@ pub trait EventTargetMethods {
     event_name: &str,
     callback: EventListenerCallback,
     options: &AddEventListenerOptions,
-    exception_state: &ExceptionState) -> Result<(), String>;
+    exception_state: &ExceptionState) -> Result<(), WebFError>;
 
   fn remove_event_listener(
     &self,
     event_name: &str,
     callback: EventListenerCallback,
-    exception_state: &ExceptionState) -> Result<(), String>;
+    exception_state: &ExceptionState) -> Result<(), WebFError>;
+
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError>;
+
+  /// Same as `add_event_listener`, but the listener will be removed once the controller of `signal` is aborted,
+  /// behavior as same as the `signal` option of `addEventListener()` in JavaScript.
+  /// Nothing will be registered if the signal was already aborted.
+  fn add_event_listener_with_signal(
+    &self,
+    event_name: &str,
+    callback: EventListenerCallback,
+    options: &AddEventListenerOptions,
+    signal: &AbortSignal,
+    exception_state: &ExceptionState) -> Result<(), WebFError>;
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool;
+  /// Creates a weak reference of the target which doesn't keep it alive, see `WeakEventTarget`.
+  fn downgrade(&self) -> WeakEventTarget;
+
+  /// Same as `add_event_listener`, but accepts a callback which can mutate its captured state.
+  ///
+  /// The callback is mutably borrowed while it runs. If it dispatches an event which reaches this listener again,
+  /// the nested invocation is skipped instead of running the callback twice at once.
+  fn add_event_listener_mut(
+    &self,
+    event_name: &str,
+    callback: EventListenerMutCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let callback = RefCell::new(callback);
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(mut callback) = callback.try_borrow_mut() {
+        callback(event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to MouseEvent.
+  /// Events of other types are ignored.
+  fn add_mouse_event_listener(
+    &self,
+    event_name: &str,
+    callback: MouseEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(mouse_event) = event.as_mouse_event() {
+        callback(&mouse_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to FocusEvent.
+  /// Events of other types are ignored.
+  fn add_focus_event_listener(
+    &self,
+    event_name: &str,
+    callback: FocusEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(focus_event) = event.as_focus_event() {
+        callback(&focus_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to PointerEvent.
+  /// Events of other types are ignored.
+  fn add_pointer_event_listener(
+    &self,
+    event_name: &str,
+    callback: PointerEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(pointer_event) = event.as_pointer_event() {
+        callback(&pointer_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to KeyboardEvent.
+  /// Events of other types are ignored.
+  fn add_keyboard_event_listener(
+    &self,
+    event_name: &str,
+    callback: KeyboardEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(keyboard_event) = event.as_keyboard_event() {
+        callback(&keyboard_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to InputEvent.
+  /// Events of other types are ignored.
+  fn add_input_event_listener(
+    &self,
+    event_name: &str,
+    callback: InputEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(input_event) = event.as_input_event() {
+        callback(&input_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to CompositionEvent,
+  /// such as `compositionstart`, `compositionupdate` and `compositionend`. Events of other types are ignored.
+  fn add_composition_event_listener(
+    &self,
+    event_name: &str,
+    callback: CompositionEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(composition_event) = event.as_composition_event() {
+        callback(&composition_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
+
+  /// Same as `add_event_listener`, but only invokes the callback with events which can be casted to TouchEvent,
+  /// such as `touchstart`, `touchmove`, `touchend` and `touchcancel`. Events of other types are ignored.
+  fn add_touch_event_listener(
+    &self,
+    event_name: &str,
+    callback: TouchEventListenerCallback,
+    options: &AddEventListenerOptions,
+    exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let event_callback: EventListenerCallback = Box::new(move |event| {
+      if let Ok(touch_event) = event.as_touch_event() {
+        callback(&touch_event);
+      }
+    });
+    self.add_event_listener(event_name, event_callback, options, exception_state)
+  }
 }
 
 impl Drop for EventTarget {
@@ -248,6 +673,119 @@ impl Drop for EventTarget {
   }
 }
 
+/// Identifies a listener registered by `EventTarget::add_event_listener_keyed`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenerKey {
+  event_name: String,
+  listener_id: u32,
+}
+
+impl ListenerKey {
+  pub fn event_name(&self) -> &str {
+    &self.event_name
+  }
+}
+
+/// The listener being invoked, passed to the callbacks registered by `EventTarget::add_event_listener_with_handle`.
+pub struct ListenerHandle {
+  event_target: EventTarget,
+  event_name: String,
+  listener_id: u32,
+}
+
+impl ListenerHandle {
+  pub fn event_name(&self) -> &str {
+    &self.event_name
+  }
+
+  /// Removes the listener from its target, it will not be invoked again.
+  /// Removing a listener which was removed already does nothing, the same as `removeEventListener()` in JavaScript.
+  pub fn remove(&self) -> Result<(), WebFError> {
+    let exception_state = self.event_target.context().create_exception_state();
+    // The callback is never invoked, it's only used to carry the listener id for matching.
+    self.event_target.remove_event_listener_with_id(&self.event_name, Box::new(|_| {}), self.listener_id, &exception_state)
+  }
+}
+
+/// A reference of an `EventTarget` which doesn't keep the target alive, created by `EventTarget::downgrade()`.
+///
+/// Every `EventTarget` and the types built on it, such as `Element`, keep the target alive until they are dropped,
+/// and a listener keeps its callback until the listener is removed or the target is garbage collected.
+/// So a callback capturing the target it's registered on, directly or through another listener, keeps both alive forever.
+/// Capture a `WeakEventTarget` instead, or read the target from `Event::current_target()` in the callback,
+/// then the target and its listeners are freed once the target is removed from the DOM and not referenced anymore.
+pub struct WeakEventTarget {
+  ptr: *const OpaquePtr,
+  weak_status: *const RustValueStatus,
+  context: *const ExecutingContext,
+  method_pointer: *const EventTargetRustMethods,
+}
+
+impl WeakEventTarget {
+  /// Returns the target if it's still alive, use `EventTarget::downcast()` to get the specific type of it.
+  pub fn upgrade(&self) -> Option<EventTarget> {
+    if self.is_dropped() {
+      return None;
+    }
+    let raw_ptr = unsafe {
+      ((*self.method_pointer).dynamic_to)(self.ptr, EventTargetType::EventTarget)
+    };
+    Some(EventTarget::initialize(raw_ptr.value, self.context, raw_ptr.method_pointer as *const EventTargetRustMethods, raw_ptr.status))
+  }
+
+  /// Whether the target has been garbage collected or its page is unloaded, `upgrade()` returns None once it is.
+  pub fn is_dropped(&self) -> bool {
+    unsafe { (*self.weak_status).disposed }
+  }
+}
+
+impl Drop for WeakEventTarget {
+  fn drop(&mut self) {
+    unsafe {
+      ((*self.method_pointer).release_weak)(self.ptr, self.weak_status);
+    }
+  }
+}
+
+/// Removes the event listener it was created for when dropped.
+/// Returned by `EventTarget::add_event_listener_guarded`.
+pub struct ListenerGuard {
+  event_target: EventTarget,
+  event_name: String,
+  listener_id: u32,
+  removed: bool,
+}
+
+impl ListenerGuard {
+  pub fn event_name(&self) -> &str {
+    &self.event_name
+  }
+
+  /// Remove the listener now and report the failure, instead of ignoring it when dropped.
+  pub fn remove(mut self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.removed = true;
+    if self.is_target_disposed() {
+      return Ok(());
+    }
+    // The callback is never invoked, it's only used to carry the listener id for matching.
+    self.event_target.remove_event_listener_with_id(&self.event_name, Box::new(|_| {}), self.listener_id, exception_state)
+  }
+
+  fn is_target_disposed(&self) -> bool {
+    self.event_target.is_disposed()
+  }
+}
+
+impl Drop for ListenerGuard {
+  fn drop(&mut self) {
+    if self.removed || self.is_target_disposed() {
+      return;
+    }
+    let exception_state = self.event_target.context().create_exception_state();
+    let _ = self.event_target.remove_event_listener_with_id(&self.event_name, Box::new(|_| {}), self.listener_id, &exception_state);
+  }
+}
+
 impl EventTargetMethods for EventTarget {
   /// Initialize the instance from cpp raw pointer.
   fn initialize<T>(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const T, status: *const RustValueStatus) -> EventTarget {
@@ -267,18 +805,26 @@ impl EventTargetMethods for EventTarget {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.downgrade()
+  }
 }
@@ -3,6 +3,7 @@
 */
 pub mod add_event_listener_options;
 pub mod custom_event;
+pub mod custom_event_init;
 pub mod event_init;
 pub mod event_listener_options;
 pub mod event_target;
@@ -10,6 +11,7 @@ pub mod event;
 
 pub use add_event_listener_options::*;
 pub use custom_event::*;
+pub use custom_event_init::*;
 pub use event_init::*;
 pub use event_listener_options::*;
 pub use event_target::*;
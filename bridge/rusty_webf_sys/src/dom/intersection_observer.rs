@@ -0,0 +1,136 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use crate::*;
+
+/// Options of an `IntersectionObserver`.
+/// The visibility is always checked against the viewport of the page without margins,
+/// the `root` and `rootMargin` options of JavaScript are not available.
+#[derive(Default)]
+pub struct IntersectionObserverInit {
+  /// The ratios between 0 and 1 at which the callback runs. `[0.0]` when empty, which means the callback runs
+  /// as soon as a single pixel becomes visible or invisible.
+  pub thresholds: Vec<f64>,
+}
+
+/// A change of the intersection between an observed element and the viewport.
+pub struct IntersectionObserverEntry {
+  target: Element,
+  intersection_ratio: f64,
+  is_intersecting: bool,
+}
+
+impl IntersectionObserverEntry {
+  /// How much of the target is visible, from 0 to 1.
+  pub fn intersection_ratio(&self) -> f64 {
+    self.intersection_ratio
+  }
+
+  pub fn is_intersecting(&self) -> bool {
+    self.is_intersecting
+  }
+
+  pub fn target(&self) -> &Element {
+    &self.target
+  }
+}
+
+pub type IntersectionObserverCallback = Box<dyn FnMut(&[IntersectionObserverEntry])>;
+
+struct ObservedTarget {
+  ptr: *const OpaquePtr,
+  _guard: ListenerGuard,
+}
+
+/// Behavior as same as `IntersectionObserver` in JavaScript.
+///
+/// The callback runs when an observed element is first laid out, and then each time its visible ratio crosses one of the thresholds.
+/// Elements are observed until `unobserve()` or `disconnect()` is called, or the observer is dropped.
+pub struct IntersectionObserver {
+  callback: Rc<RefCell<IntersectionObserverCallback>>,
+  thresholds: Rc<Vec<f64>>,
+  targets: RefCell<Vec<ObservedTarget>>,
+}
+
+impl IntersectionObserver {
+  /// Returns an error if a threshold is not between 0 and 1.
+  pub fn new(callback: IntersectionObserverCallback, init: IntersectionObserverInit) -> Result<IntersectionObserver, WebFError> {
+    if init.thresholds.iter().any(|threshold| !(0.0..=1.0).contains(threshold)) {
+      return Err(WebFError::RangeError("Failed to construct 'IntersectionObserver': Threshold values must be numbers between 0 and 1".to_string()));
+    }
+
+    let mut thresholds = if init.thresholds.is_empty() { vec![0.0] } else { init.thresholds };
+    thresholds.sort_by(f64::total_cmp);
+    thresholds.dedup();
+
+    Ok(IntersectionObserver {
+      callback: Rc::new(RefCell::new(callback)),
+      thresholds: Rc::new(thresholds),
+      targets: RefCell::new(Vec::new()),
+    })
+  }
+
+  /// The sorted thresholds of the observer.
+  pub fn thresholds(&self) -> &[f64] {
+    &self.thresholds
+  }
+
+  /// Behavior as same as `IntersectionObserver.observe()` in JavaScript. Observing an element twice has no effect.
+  pub fn observe(&self, target: &Element, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if self.targets.borrow().iter().any(|observed| observed.ptr == target.ptr()) {
+      return Ok(());
+    }
+
+    let callback = self.callback.clone();
+    let thresholds = self.thresholds.clone();
+    let previous_state: Cell<Option<(usize, bool)>> = Cell::new(None);
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    let guard = target.as_node().event_target.add_event_listener_guarded("intersectionchange", Box::new(move |event| {
+      let Ok(intersection_change_event) = event.as_intersection_change_event() else {
+        return;
+      };
+      let intersection_ratio = intersection_change_event.intersection_ratio();
+      let is_intersecting = intersection_ratio > 0.0;
+      let threshold_index = thresholds.iter().position(|threshold| *threshold > intersection_ratio).unwrap_or(thresholds.len());
+      if previous_state.replace(Some((threshold_index, is_intersecting))) == Some((threshold_index, is_intersecting)) {
+        return;
+      }
+
+      let Ok(target) = event.target().as_element() else {
+        return;
+      };
+      let entry = IntersectionObserverEntry {
+        target,
+        intersection_ratio,
+        is_intersecting,
+      };
+      // The callback is not reentrant, changes caused by the callback itself are not reported again.
+      if let Ok(mut callback) = callback.try_borrow_mut() {
+        callback(&[entry]);
+      }
+    }), &options, exception_state)?;
+
+    self.targets.borrow_mut().push(ObservedTarget {
+      ptr: target.ptr(),
+      _guard: guard,
+    });
+    Ok(())
+  }
+
+  /// Behavior as same as `IntersectionObserver.unobserve()` in JavaScript.
+  pub fn unobserve(&self, target: &Element) {
+    self.targets.borrow_mut().retain(|observed| observed.ptr != target.ptr());
+  }
+
+  /// Behavior as same as `IntersectionObserver.disconnect()` in JavaScript, all the elements are unobserved.
+  pub fn disconnect(&self) {
+    self.targets.borrow_mut().clear();
+  }
+}
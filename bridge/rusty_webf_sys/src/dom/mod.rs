@@ -2,25 +2,53 @@
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 pub mod events;
+pub mod abort_controller;
 pub mod character_data;
 pub mod comment;
 pub mod container_node;
+pub mod css_style_declaration;
+pub mod css_style_sheet;
 pub mod document_fragment;
 pub mod document;
+pub mod dom_parser;
+pub mod dom_rect;
+pub mod dom_string_map;
+pub mod dom_token_list;
 pub mod element;
+pub mod element_builder;
+pub mod intersection_observer;
+pub mod mutation_observer;
+pub mod named_node_map;
 pub mod node;
+pub mod node_list;
+pub mod resize_observer;
+pub mod scroll_into_view_options;
 pub mod scroll_options;
 pub mod scroll_to_options;
 pub mod text;
 
 pub use events::*;
+pub use abort_controller::*;
 pub use character_data::*;
 pub use comment::*;
 pub use container_node::*;
+pub use css_style_declaration::*;
+pub use css_style_sheet::*;
 pub use document_fragment::*;
 pub use document::*;
+pub use dom_parser::*;
+pub use dom_rect::*;
+pub use dom_string_map::*;
+pub use dom_token_list::*;
 pub use element::*;
+pub use element_builder::*;
+pub use intersection_observer::*;
+pub use mutation_observer::*;
+pub use named_node_map::*;
 pub use node::*;
+pub use node_list::*;
+pub use resize_observer::*;
+pub use scroll_into_view_options::*;
 pub use scroll_options::*;
 pub use scroll_to_options::*;
 pub use text::*;
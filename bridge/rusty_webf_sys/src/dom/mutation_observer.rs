@@ -0,0 +1,350 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::RefCell;
+use std::ffi::*;
+use std::rc::{Rc, Weak};
+use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
+
+#[repr(C)]
+pub struct MutationObserverRustMethods {
+  pub version: c_double,
+  pub observe: extern "C" fn(*const OpaquePtr, *const OpaquePtr, *const MutationObserverInitData, *const OpaquePtr),
+  pub take_records: extern "C" fn(*const OpaquePtr, *mut i64, *const OpaquePtr) -> *mut MutationRecordData,
+  pub disconnect: extern "C" fn(*const OpaquePtr, *const OpaquePtr),
+  pub release: extern "C" fn(*const OpaquePtr),
+}
+
+impl RustMethods for MutationObserverRustMethods {}
+
+/// The options of `MutationObserver::observe()`, filled by the Rust side.
+#[repr(C)]
+pub struct MutationObserverInitData {
+  pub child_list: i32,
+  pub attributes: i32,
+  pub character_data: i32,
+  pub subtree: i32,
+  pub attribute_old_value: i32,
+  pub character_data_old_value: i32,
+  pub attribute_filter: *const *const c_char,
+  pub attribute_filter_length: i64,
+}
+
+/// A mutation record, filled by the C++ side. The strings are owned by the Rust side.
+#[repr(C)]
+pub struct MutationRecordData {
+  pub record_type: *mut c_char,
+  pub target: RustValue<NodeRustMethods>,
+  pub added_nodes: RustValue<NodeListRustMethods>,
+  pub removed_nodes: RustValue<NodeListRustMethods>,
+  pub previous_sibling: RustValue<NodeRustMethods>,
+  pub next_sibling: RustValue<NodeRustMethods>,
+  pub attribute_name: *mut c_char,
+  pub old_value: *mut c_char,
+}
+
+/// Behavior as same as `MutationObserverInit` in JavaScript.
+///
+/// `attributes` is implied when `attribute_old_value` is set or `attribute_filter` is `Some`,
+/// and `character_data` is implied when `character_data_old_value` is set, the same as leaving them out in JavaScript.
+#[derive(Debug, Clone, Default)]
+pub struct MutationObserverInit {
+  pub child_list: bool,
+  pub attributes: bool,
+  pub character_data: bool,
+  pub subtree: bool,
+  pub attribute_old_value: bool,
+  pub character_data_old_value: bool,
+  /// The local names of the attributes to observe, all the attributes are observed when `None`.
+  pub attribute_filter: Option<Vec<String>>,
+}
+
+/// Behavior as same as `MutationRecord.type` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutationRecordType {
+  Attributes,
+  CharacterData,
+  ChildList,
+}
+
+/// Behavior as same as `MutationRecord` in JavaScript.
+pub struct MutationRecord {
+  record_type: MutationRecordType,
+  target: Node,
+  added_nodes: NodeList,
+  removed_nodes: NodeList,
+  previous_sibling: Option<Node>,
+  next_sibling: Option<Node>,
+  attribute_name: Option<String>,
+  old_value: Option<String>,
+}
+
+impl MutationRecord {
+  pub fn record_type(&self) -> MutationRecordType {
+    self.record_type
+  }
+
+  /// The node affected by the mutation: the element whose attribute changed, the text node whose data changed,
+  /// or the parent whose children changed.
+  pub fn target(&self) -> &Node {
+    &self.target
+  }
+
+  /// The inserted nodes, empty unless the type is `ChildList`.
+  pub fn added_nodes(&self) -> &NodeList {
+    &self.added_nodes
+  }
+
+  /// The removed nodes, empty unless the type is `ChildList`.
+  pub fn removed_nodes(&self) -> &NodeList {
+    &self.removed_nodes
+  }
+
+  pub fn previous_sibling(&self) -> Option<&Node> {
+    self.previous_sibling.as_ref()
+  }
+
+  pub fn next_sibling(&self) -> Option<&Node> {
+    self.next_sibling.as_ref()
+  }
+
+  /// The local name of the changed attribute, `None` unless the type is `Attributes`.
+  pub fn attribute_name(&self) -> Option<&str> {
+    self.attribute_name.as_deref()
+  }
+
+  /// The attribute value or text data before the mutation.
+  /// Only recorded when `attribute_old_value` or `character_data_old_value` is set, `None` otherwise.
+  pub fn old_value(&self) -> Option<&str> {
+    self.old_value.as_deref()
+  }
+
+  fn from_data(data: &MutationRecordData, context: *const ExecutingContext) -> MutationRecord {
+    let record_type = match take_cpp_string(data.record_type).as_deref() {
+      Some("attributes") => MutationRecordType::Attributes,
+      Some("characterData") => MutationRecordType::CharacterData,
+      _ => MutationRecordType::ChildList,
+    };
+    let to_node = |value: &RustValue<NodeRustMethods>| {
+      if value.value.is_null() {
+        return None;
+      }
+      Some(Node::initialize(value.value, context, value.method_pointer, value.status))
+    };
+    MutationRecord {
+      record_type,
+      target: to_node(&data.target).unwrap(),
+      added_nodes: NodeList::initialize(data.added_nodes.value, context, data.added_nodes.method_pointer, data.added_nodes.status),
+      removed_nodes: NodeList::initialize(data.removed_nodes.value, context, data.removed_nodes.method_pointer, data.removed_nodes.status),
+      previous_sibling: to_node(&data.previous_sibling),
+      next_sibling: to_node(&data.next_sibling),
+      attribute_name: take_cpp_string(data.attribute_name),
+      old_value: take_cpp_string(data.old_value),
+    }
+  }
+}
+
+fn take_cpp_string(value: *mut c_char) -> Option<String> {
+  if value.is_null() {
+    return None;
+  }
+  let string = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+  safe_free_cpp_ptr(value);
+  Some(string)
+}
+
+pub type MutationObserverCallback = Box<dyn FnMut(&[MutationRecord])>;
+
+struct MutationObserverHandle {
+  ptr: *const OpaquePtr,
+  method_pointer: *const MutationObserverRustMethods,
+  status: *const RustValueStatus,
+  // Boxed so that the address held by the nodes of the records stays the same.
+  context: Box<ExecutingContext>,
+}
+
+impl MutationObserverHandle {
+  fn take_records(&self) -> Vec<MutationRecord> {
+    unsafe {
+      if (*self.status).disposed {
+        return Vec::new();
+      }
+    }
+    let exception_state = self.context.create_exception_state();
+    let mut length: i64 = 0;
+    let records_ptr = unsafe {
+      ((*self.method_pointer).take_records)(self.ptr, &mut length, exception_state.ptr)
+    };
+    if records_ptr.is_null() {
+      return Vec::new();
+    }
+    let records = unsafe { std::slice::from_raw_parts(records_ptr, length as usize) }
+      .iter()
+      .map(|data| MutationRecord::from_data(data, &*self.context))
+      .collect();
+    safe_free_cpp_ptr(records_ptr);
+    records
+  }
+}
+
+struct MutationObserverInner {
+  callback: RefCell<MutationObserverCallback>,
+  // Records taken while the callback is running, such as for mutations made by the callback itself.
+  // They are delivered once the running callback returns.
+  pending_records: RefCell<Vec<MutationRecord>>,
+  // Created when a node is observed for the first time, since the observer belongs to the context of the node.
+  handle: RefCell<Option<MutationObserverHandle>>,
+}
+
+/// Behavior as same as `MutationObserver` in JavaScript.
+///
+/// Mutations are queued as records and delivered to the callback in a microtask, so the callback runs once
+/// for all the mutations made by the current task. Nodes are observed until `disconnect()` is called or the observer is dropped.
+pub struct MutationObserver {
+  inner: Rc<MutationObserverInner>,
+}
+
+impl MutationObserver {
+  pub fn new(callback: MutationObserverCallback) -> MutationObserver {
+    MutationObserver {
+      inner: Rc::new(MutationObserverInner {
+        callback: RefCell::new(callback),
+        pending_records: RefCell::new(Vec::new()),
+        handle: RefCell::new(None),
+      }),
+    }
+  }
+
+  /// Behavior as same as `MutationObserver.observe()` in JavaScript.
+  /// Returns `Err` when none of `child_list`, `attributes` and `character_data` is observed, either set or implied.
+  /// Observing a node again replaces the options it was observed with.
+  pub fn observe(&self, target: &Node, init: MutationObserverInit, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.ensure_handle(target.event_target.context(), exception_state)?;
+
+    let attribute_filter_c_strings = init.attribute_filter.as_ref().map(|filter| {
+      filter.iter().map(|name| CString::new(name.as_str()).unwrap()).collect::<Vec<_>>()
+    });
+    let attribute_filter_ptrs = attribute_filter_c_strings.as_ref().map(|filter| {
+      filter.iter().map(|name| name.as_ptr()).collect::<Vec<_>>()
+    });
+    let init_data = MutationObserverInitData {
+      child_list: i32::from(init.child_list),
+      attributes: i32::from(init.attributes),
+      character_data: i32::from(init.character_data),
+      subtree: i32::from(init.subtree),
+      attribute_old_value: i32::from(init.attribute_old_value),
+      character_data_old_value: i32::from(init.character_data_old_value),
+      attribute_filter: attribute_filter_ptrs.as_ref().map_or(std::ptr::null(), |filter| filter.as_ptr()),
+      attribute_filter_length: attribute_filter_ptrs.as_ref().map_or(0, |filter| filter.len() as i64),
+    };
+
+    let handle = self.inner.handle.borrow();
+    let handle = handle.as_ref().unwrap();
+    unsafe {
+      ((*handle.method_pointer).observe)(handle.ptr, target.ptr(), &init_data, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(target.event_target.context()));
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `MutationObserver.takeRecords()` in JavaScript.
+  /// The returned records are removed from the queue, so they are not delivered to the callback.
+  pub fn take_records(&self) -> Vec<MutationRecord> {
+    let mut records = std::mem::take(&mut *self.inner.pending_records.borrow_mut());
+    records.extend(self.inner.handle.borrow().as_ref().map_or_else(Vec::new, |handle| handle.take_records()));
+    records
+  }
+
+  /// Behavior as same as `MutationObserver.disconnect()` in JavaScript.
+  /// All the nodes are unobserved and the pending records are discarded.
+  pub fn disconnect(&self) {
+    self.inner.pending_records.borrow_mut().clear();
+    let handle = self.inner.handle.borrow();
+    let Some(handle) = handle.as_ref() else {
+      return;
+    };
+    unsafe {
+      if (*handle.status).disposed {
+        return;
+      }
+    }
+    let exception_state = handle.context.create_exception_state();
+    unsafe {
+      ((*handle.method_pointer).disconnect)(handle.ptr, exception_state.ptr);
+    }
+  }
+
+  fn ensure_handle(&self, context: &ExecutingContext, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if self.inner.handle.borrow().is_some() {
+      return Ok(());
+    }
+
+    let inner: Weak<MutationObserverInner> = Rc::downgrade(&self.inner);
+    let general_callback: WebFNativeFunction = Box::new(move |_argc, _argv| {
+      let Some(inner) = inner.upgrade() else {
+        return NativeValue::new_null();
+      };
+      let records = inner.handle.borrow().as_ref().map_or_else(Vec::new, |handle| handle.take_records());
+      inner.pending_records.borrow_mut().extend(records);
+      // The callback may be running already when records are delivered by a nested microtask checkpoint,
+      // the running invocation delivers the queued records after it returns.
+      let Ok(mut callback) = inner.callback.try_borrow_mut() else {
+        return NativeValue::new_null();
+      };
+      loop {
+        let records = std::mem::take(&mut *inner.pending_records.borrow_mut());
+        if records.is_empty() {
+          break;
+        }
+        callback(&records);
+      }
+      NativeValue::new_null()
+    });
+
+    let callback_data = Box::new(WebFNativeFunctionContextData {
+      func: general_callback,
+    });
+    let callback_context_data_ptr = Box::into_raw(callback_data);
+    let callback_context = Box::new(WebFNativeFunctionContext {
+      callback: invoke_webf_native_function,
+      free_ptr: release_webf_native_function,
+      ptr: callback_context_data_ptr,
+    });
+    let callback_context_ptr = Box::into_raw(callback_context);
+
+    let observer_value = unsafe {
+      (context.method_pointer().create_mutation_observer)(context.ptr, callback_context_ptr, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(context));
+    }
+
+    *self.inner.handle.borrow_mut() = Some(MutationObserverHandle {
+      ptr: observer_value.value,
+      method_pointer: observer_value.method_pointer,
+      status: observer_value.status,
+      context: Box::new(context.clone()),
+    });
+    Ok(())
+  }
+}
+
+impl Drop for MutationObserver {
+  fn drop(&mut self) {
+    self.disconnect();
+    let handle = self.inner.handle.borrow();
+    let Some(handle) = handle.as_ref() else {
+      return;
+    };
+    unsafe {
+      if (*handle.status).disposed {
+        return;
+      }
+      ((*handle.method_pointer).release)(handle.ptr);
+    }
+  }
+}
@@ -0,0 +1,64 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+
+/// An attribute of an element, filled by the C++ side. The strings are owned by the Rust side.
+#[repr(C)]
+pub struct ElementAttributeData {
+  pub name: *mut c_char,
+  pub value: *mut c_char,
+}
+
+/// The attributes of an element returned by `Element::attributes()`, behavior as same as `NamedNodeMap` in JavaScript.
+///
+/// The attributes are read when `Element::attributes()` is called, later changes to the element are not reflected.
+/// They are sorted by name, so the index of an attribute doesn't depend on the order it was set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamedNodeMap {
+  attributes: Vec<(String, String)>,
+}
+
+impl NamedNodeMap {
+  pub(crate) fn new(attributes: Vec<(String, String)>) -> NamedNodeMap {
+    NamedNodeMap { attributes }
+  }
+
+  /// Behavior as same as `NamedNodeMap.length` in JavaScript.
+  pub fn len(&self) -> usize {
+    self.attributes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.attributes.is_empty()
+  }
+
+  /// Behavior as same as `NamedNodeMap.item()` in JavaScript, returns the name and value of the attribute at `index`.
+  pub fn item(&self, index: usize) -> Option<(&str, &str)> {
+    self.attributes.get(index).map(|(name, value)| (name.as_str(), value.as_str()))
+  }
+
+  /// Behavior as same as `NamedNodeMap.getNamedItem()` in JavaScript, returns the value of the attribute.
+  /// The name is matched ASCII case-insensitively when there is no attribute with exactly the same name.
+  pub fn get_named_item(&self, name: &str) -> Option<&str> {
+    self.attributes.iter()
+      .find(|(attribute_name, _)| attribute_name == name)
+      .or_else(|| self.attributes.iter().find(|(attribute_name, _)| attribute_name.eq_ignore_ascii_case(name)))
+      .map(|(_, value)| value.as_str())
+  }
+
+  /// The names and values of the attributes.
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.attributes.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+  }
+}
+
+impl IntoIterator for NamedNodeMap {
+  type Item = (String, String);
+  type IntoIter = std::vec::IntoIter<(String, String)>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.attributes.into_iter()
+  }
+}
@@ -4,6 +4,7 @@
 
 use std::ffi::*;
 use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
 
 #[repr(C)]
 enum NodeType {
@@ -30,12 +31,75 @@ impl NodeType {
   }
 }
 
+/// The bitmask returned by `Node::compare_document_position()`, which describes the position of the other node relative to this node.
+/// Behavior as same as the `Node.DOCUMENT_POSITION_*` constants in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DocumentPosition(u16);
+
+impl DocumentPosition {
+  /// The nodes are the same node.
+  pub const EQUIVALENT: DocumentPosition = DocumentPosition(0x00);
+  /// The nodes are not in the same tree.
+  pub const DISCONNECTED: DocumentPosition = DocumentPosition(0x01);
+  pub const PRECEDING: DocumentPosition = DocumentPosition(0x02);
+  pub const FOLLOWING: DocumentPosition = DocumentPosition(0x04);
+  /// The other node is an ancestor of this node.
+  pub const CONTAINS: DocumentPosition = DocumentPosition(0x08);
+  /// The other node is a descendant of this node.
+  pub const CONTAINED_BY: DocumentPosition = DocumentPosition(0x10);
+  pub const IMPLEMENTATION_SPECIFIC: DocumentPosition = DocumentPosition(0x20);
+
+  pub fn from_bits(bits: u16) -> DocumentPosition {
+    DocumentPosition(bits)
+  }
+
+  pub fn bits(&self) -> u16 {
+    self.0
+  }
+
+  /// Whether all the flags of `other` are set.
+  pub fn contains(&self, other: DocumentPosition) -> bool {
+    self.0 & other.0 == other.0
+  }
+}
+
+impl std::ops::BitOr for DocumentPosition {
+  type Output = DocumentPosition;
+
+  fn bitor(self, rhs: DocumentPosition) -> DocumentPosition {
+    DocumentPosition(self.0 | rhs.0)
+  }
+}
+
+impl std::ops::BitAnd for DocumentPosition {
+  type Output = DocumentPosition;
+
+  fn bitand(self, rhs: DocumentPosition) -> DocumentPosition {
+    DocumentPosition(self.0 & rhs.0)
+  }
+}
+
 #[repr(C)]
 pub struct NodeRustMethods {
   pub version: c_double,
   pub event_target: EventTargetRustMethods,
   pub append_child: extern "C" fn(self_node: *const OpaquePtr, new_node: *const OpaquePtr, exception_state: *const OpaquePtr) -> RustValue<NodeRustMethods>,
   pub remove_node: extern "C" fn(self_node: *const OpaquePtr, target_node: *const OpaquePtr, exception_state: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub text_content: extern "C" fn(self_node: *const OpaquePtr, exception_state: *const OpaquePtr) -> *mut c_char,
+  pub set_text_content: extern "C" fn(self_node: *const OpaquePtr, text: *const c_char, exception_state: *const OpaquePtr),
+  pub insert_before: extern "C" fn(self_node: *const OpaquePtr, new_node: *const OpaquePtr, reference_node: *const OpaquePtr, exception_state: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub replace_child: extern "C" fn(self_node: *const OpaquePtr, new_node: *const OpaquePtr, old_node: *const OpaquePtr, exception_state: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub first_child: extern "C" fn(self_node: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub last_child: extern "C" fn(self_node: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub parent_node: extern "C" fn(self_node: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub next_sibling: extern "C" fn(self_node: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub previous_sibling: extern "C" fn(self_node: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub child_nodes: extern "C" fn(self_node: *const OpaquePtr) -> RustValue<NodeListRustMethods>,
+  pub append_children: extern "C" fn(self_node: *const OpaquePtr, nodes: *const *const OpaquePtr, length: i64, exception_state: *const OpaquePtr),
+  pub clone_node: extern "C" fn(self_node: *const OpaquePtr, deep: i32, exception_state: *const OpaquePtr) -> RustValue<NodeRustMethods>,
+  pub contains: extern "C" fn(self_node: *const OpaquePtr, other_node: *const OpaquePtr) -> i32,
+  pub compare_document_position: extern "C" fn(self_node: *const OpaquePtr, other_node: *const OpaquePtr) -> u16,
+  pub normalize: extern "C" fn(self_node: *const OpaquePtr, exception_state: *const OpaquePtr),
 }
 
 impl RustMethods for NodeRustMethods {}
@@ -47,39 +111,310 @@ pub struct Node {
 
 impl Node {
   /// The appendChild() method of the Node interface adds a node to the end of the list of children of a specified parent node.
-  pub fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  pub fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.event_target.check_disposed()?;
     let event_target: &EventTarget = &self.event_target;
     let returned_result = unsafe {
       ((*self.method_pointer).append_child)(event_target.ptr, new_node.ptr(), exception_state.ptr)
     };
     if (exception_state.has_exception()) {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Node::initialize(returned_result.value, event_target.context(), returned_result.method_pointer, returned_result.status));
   }
 
+  /// Appends all the nodes to the end of the list of children in order, with a single call across the bridge.
+  /// Behavior as same as `ParentNode.append()` with nodes in JavaScript: when there is more than one node,
+  /// they are first moved into a DocumentFragment, so none of them is inserted if one of them cannot be appended.
+  ///
+  /// Prefer it over calling `append_child()` in a loop when appending many nodes, such as the rows of a large list.
+  pub fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.check_disposed()?;
+    if nodes.is_empty() {
+      return Ok(());
+    }
+
+    let event_target: &EventTarget = &self.event_target;
+    let node_ptrs = nodes.iter().map(|node| node.ptr()).collect::<Vec<_>>();
+    unsafe {
+      ((*self.method_pointer).append_children)(event_target.ptr, node_ptrs.as_ptr(), node_ptrs.len() as i64, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `Node.cloneNode()` in JavaScript.
+  /// A deep clone copies the node with its attributes and all of its descendants, a shallow clone copies the node and
+  /// its attributes only. Event listeners are not copied. The clone has no parent until it is inserted.
+  ///
+  /// Use `event_target.as_element()` and the other downcasts of `EventTarget` to get the specific type of the clone.
+  /// Returns `Err` for documents, which can not be cloned.
+  pub fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.event_target;
+    let returned_result = unsafe {
+      ((*self.method_pointer).clone_node)(event_target.ptr, i32::from(deep), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(Node::initialize(returned_result.value, event_target.context(), returned_result.method_pointer, returned_result.status))
+  }
+
+  /// Behavior as same as `Node.contains()` in JavaScript.
+  /// Returns true if `other` is this node or one of its descendants.
+  pub fn contains(&self, other: &dyn NodeMethods) -> bool {
+    unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).contains)(self.event_target.ptr, other.ptr()) != 0
+    }
+  }
+
+  /// Behavior as same as `Node.compareDocumentPosition()` in JavaScript.
+  pub fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    let bits = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).compare_document_position)(self.event_target.ptr, other.ptr())
+    };
+    DocumentPosition::from_bits(bits)
+  }
+
+  /// Behavior as same as `node.normalize()` in JavaScript.
+  /// Removes the empty Text nodes in the subtree and merges each run of adjacent Text nodes into the first one.
+  pub fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.check_disposed()?;
+    unsafe {
+      ((*self.method_pointer).normalize)(self.event_target.ptr, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.event_target.context()));
+    }
+    Ok(())
+  }
+
   /// The removeChild() method of the Node interface removes a child node from the DOM and returns the removed node.
-  pub fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  pub fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.event_target.check_disposed()?;
     let event_target: &EventTarget = &self.event_target;
     let returned_result = unsafe {
       ((*self.method_pointer).remove_node)(event_target.ptr, target_node.ptr(), exception_state.ptr)
     };
     if (exception_state.has_exception()) {
-      return Err(exception_state.stringify(event_target.context()));
+      return Err(exception_state.to_error(event_target.context()));
     }
 
     return Ok(Node::initialize(returned_result.value, event_target.context(), returned_result.method_pointer, returned_result.status));
   }
+
+  /// Behavior as same as `node.remove()` in JavaScript.
+  /// Removes the node from its parent, nothing happens when the node has no parent.
+  pub fn remove(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.check_disposed()?;
+    let Some(parent) = self.parent_node() else {
+      return Ok(());
+    };
+    parent.remove_child(self, exception_state)?;
+    Ok(())
+  }
+
+  /// The insertBefore() method of the Node interface inserts a node before a reference node as a child of a specified parent node.
+  /// If the reference node is None, the new node is inserted at the end of the list of children.
+  pub fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.event_target;
+    let reference_node_ptr = reference_node.map_or(std::ptr::null(), |node| node.ptr());
+    let returned_result = unsafe {
+      ((*self.method_pointer).insert_before)(event_target.ptr, new_node.ptr(), reference_node_ptr, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(Node::initialize(returned_result.value, event_target.context(), returned_result.method_pointer, returned_result.status))
+  }
+
+  /// The replaceChild() method of the Node interface replaces a child node within the given (parent) node.
+  /// Returns the replaced node.
+  pub fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.event_target;
+    let returned_result = unsafe {
+      ((*self.method_pointer).replace_child)(event_target.ptr, new_node.ptr(), old_node.ptr(), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+
+    Ok(Node::initialize(returned_result.value, event_target.context(), returned_result.method_pointer, returned_result.status))
+  }
+
+  /// The firstChild property of the Node interface returns the node's first child in the tree, or None if the node has no children.
+  pub fn first_child(&self) -> Option<Node> {
+    let value = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).first_child)(self.event_target.ptr)
+    };
+    self.to_relative_node(value)
+  }
+
+  /// The lastChild property of the Node interface returns the last child of the node, or None if there are no child nodes.
+  pub fn last_child(&self) -> Option<Node> {
+    let value = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).last_child)(self.event_target.ptr)
+    };
+    self.to_relative_node(value)
+  }
+
+  /// The parentNode property of the Node interface returns the parent of the specified node in the DOM tree, or None if the node has no parent.
+  pub fn parent_node(&self) -> Option<Node> {
+    let value = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).parent_node)(self.event_target.ptr)
+    };
+    self.to_relative_node(value)
+  }
+
+  /// The nextSibling property of the Node interface returns the node immediately following the specified one in their parent's childNodes,
+  /// or None if the specified node is the last child in the parent element.
+  pub fn next_sibling(&self) -> Option<Node> {
+    let value = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).next_sibling)(self.event_target.ptr)
+    };
+    self.to_relative_node(value)
+  }
+
+  /// The previousSibling property of the Node interface returns the node immediately preceding the specified one in its parent's childNodes list,
+  /// or None if the specified node is the first in that list.
+  pub fn previous_sibling(&self) -> Option<Node> {
+    let value = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).previous_sibling)(self.event_target.ptr)
+    };
+    self.to_relative_node(value)
+  }
+
+  /// The childNodes property of the Node interface returns a live NodeList of child nodes of the given element.
+  /// Reading the length or the items of the returned list always reflects the current children.
+  pub fn child_nodes(&self) -> NodeList {
+    let value = unsafe {
+      self.event_target.assert_not_disposed();
+      ((*self.method_pointer).child_nodes)(self.event_target.ptr)
+    };
+    NodeList::initialize(value.value, self.event_target.context(), value.method_pointer, value.status)
+  }
+
+  /// Returns an iterator over the children of the node in tree order.
+  /// The children are collected when the iterator is created, so inserting or removing children while iterating
+  /// does not change which nodes are visited.
+  pub fn children_iter(&self) -> ChildrenIter {
+    ChildrenIter {
+      children: self.child_nodes().iter().collect::<Vec<_>>().into_iter(),
+    }
+  }
+
+  fn to_relative_node(&self, value: RustValue<NodeRustMethods>) -> Option<Node> {
+    if value.value.is_null() {
+      return None;
+    }
+    Some(Node::initialize(value.value, self.event_target.context(), value.method_pointer, value.status))
+  }
+
+  /// The textContent property of the Node interface represents the text content of the node and its descendants.
+  /// Returns None for documents, which have a null textContent.
+  pub fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.event_target;
+    let value = unsafe {
+      ((*self.method_pointer).text_content)(event_target.ptr, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+    if value.is_null() {
+      return Ok(None);
+    }
+
+    let text_content = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(Some(text_content))
+  }
+
+  /// Setting textContent on a node removes all of the node's children and replaces them with a single text node with the given string value.
+  pub fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.check_disposed()?;
+    let event_target: &EventTarget = &self.event_target;
+    let text_c_string = CString::new(text).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_text_content)(event_target.ptr, text_c_string.as_ptr(), exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+    Ok(())
+  }
 }
 
 pub trait NodeMethods: EventTargetMethods {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String>;
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String>;
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError>;
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError>;
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError>;
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError>;
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError>;
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn first_child(&self) -> Option<Node>;
+  fn last_child(&self) -> Option<Node>;
+  fn parent_node(&self) -> Option<Node>;
+  fn next_sibling(&self) -> Option<Node>;
+  fn previous_sibling(&self) -> Option<Node>;
+  fn child_nodes(&self) -> NodeList;
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError>;
+  fn contains(&self, other: &dyn NodeMethods) -> bool;
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError>;
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition;
 
   fn as_node(&self) -> &Node;
+
+  fn children_iter(&self) -> ChildrenIter {
+    self.as_node().children_iter()
+  }
+
+  fn remove(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.as_node().remove(exception_state)
+  }
+}
+
+/// An iterator over the children of a node, returned by `Node::children_iter()`.
+pub struct ChildrenIter {
+  children: std::vec::IntoIter<Node>,
+}
+
+impl Iterator for ChildrenIter {
+  type Item = Node;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.children.next()
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.children.size_hint()
+  }
+}
+
+impl DoubleEndedIterator for ChildrenIter {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.children.next_back()
+  }
 }
 
+impl ExactSizeIterator for ChildrenIter {}
+
 impl EventTargetMethods for Node {
   /// Initialize the instance from cpp raw pointer.
   fn initialize<T: RustMethods>(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const T, status: *const RustValueStatus) -> Self where Self: Sized {
@@ -104,33 +439,101 @@ impl EventTargetMethods for Node {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event_target.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event_target.remove_event_listener(event_name, callback, exception_state)
   }
 
   fn dispatch_event(&self,
                     event: &Event,
-                    exception_state: &ExceptionState) -> bool{
+                    exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.event_target.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.event_target.downgrade()
+  }
 }
 
 impl NodeMethods for Node {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.remove_child(target_node, exception_state)
   }
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     self
   }
@@ -0,0 +1,118 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct NodeListRustMethods {
+  pub version: c_double,
+  pub length: extern "C" fn(ptr: *const OpaquePtr) -> u32,
+  pub item: extern "C" fn(ptr: *const OpaquePtr, index: u32) -> RustValue<NodeRustMethods>,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for NodeListRustMethods {}
+
+/// A collection of nodes, such as the result of `querySelectorAll()` or `childNodes`.
+/// Whether it's live depends on where it comes from, the same as in JavaScript:
+/// the list returned by `Node::child_nodes()` reflects later changes of the children,
+/// while the one returned by `query_selector_all()` is a static snapshot.
+pub struct NodeList {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const NodeListRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl NodeList {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const NodeListRustMethods, status: *const RustValueStatus) -> NodeList {
+    NodeList {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `NodeList.length` in JavaScript.
+  pub fn len(&self) -> usize {
+    let value = unsafe {
+      ((*self.method_pointer).length)(self.ptr())
+    };
+    value as usize
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Behavior as same as `NodeList.item()` in JavaScript.
+  /// Returns None when the index is out of range.
+  pub fn get(&self, index: usize) -> Option<Node> {
+    if index >= self.len() {
+      return None;
+    }
+    let value = unsafe {
+      ((*self.method_pointer).item)(self.ptr(), index as u32)
+    };
+    if value.value.is_null() {
+      return None;
+    }
+    Some(Node::initialize(value.value, self.context, value.method_pointer, value.status))
+  }
+
+  pub fn iter(&self) -> NodeListIter<'_> {
+    NodeListIter {
+      node_list: self,
+      index: 0,
+    }
+  }
+}
+
+impl Drop for NodeList {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
+
+/// Iterates a `NodeList` by index, so a live list visits the nodes it contains at the time of each step.
+/// Use `Node::children_iter()` to iterate over a snapshot of the children instead.
+pub struct NodeListIter<'a> {
+  node_list: &'a NodeList,
+  index: usize,
+}
+
+impl Iterator for NodeListIter<'_> {
+  type Item = Node;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let node = self.node_list.get(self.index)?;
+    self.index += 1;
+    Some(node)
+  }
+}
+
+impl<'a> IntoIterator for &'a NodeList {
+  type Item = Node;
+  type IntoIter = NodeListIter<'a>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
@@ -0,0 +1,214 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+use crate::*;
+
+/// Behavior as same as the `box` option of `ResizeObserver.observe()` in JavaScript, the box whose size changes are observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeObserverBoxOptions {
+  #[default]
+  ContentBox,
+  BorderBox,
+  /// Reported in device pixels, which is the content box scaled by the device pixel ratio.
+  DevicePixelContentBox,
+}
+
+/// Behavior as same as `ResizeObserverSize` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ResizeObserverSize {
+  pub inline_size: f64,
+  pub block_size: f64,
+}
+
+/// A size change of an observed element.
+pub struct ResizeObserverEntry {
+  target: Element,
+  content_rect: DomRect,
+  border_box_size: ResizeObserverSize,
+  content_box_size: ResizeObserverSize,
+}
+
+impl ResizeObserverEntry {
+  pub fn target(&self) -> &Element {
+    &self.target
+  }
+
+  /// The content box of the target, the origin is the top left corner of the content box.
+  pub fn content_rect(&self) -> DomRect {
+    self.content_rect
+  }
+
+  pub fn border_box_size(&self) -> ResizeObserverSize {
+    self.border_box_size
+  }
+
+  pub fn content_box_size(&self) -> ResizeObserverSize {
+    self.content_box_size
+  }
+}
+
+pub type ResizeObserverCallback = Box<dyn FnMut(&[ResizeObserverEntry])>;
+
+struct ResizeObserverInner {
+  callback: RefCell<ResizeObserverCallback>,
+  // Entries waiting for the next frame, keyed by the target so only the last size of each element is reported.
+  pending_entries: RefCell<HashMap<usize, ResizeObserverEntry>>,
+  frame_scheduled: Cell<bool>,
+}
+
+struct ObservedTarget {
+  ptr: *const OpaquePtr,
+  _guard: ListenerGuard,
+}
+
+/// Behavior as same as `ResizeObserver` in JavaScript.
+///
+/// The callback runs when an observed element is first laid out, and then each time the observed box changes its size.
+/// Changes within the same frame are coalesced, so the callback runs at most once per frame with the latest size of each element.
+/// Elements are observed until `unobserve()` or `disconnect()` is called, or the observer is dropped.
+pub struct ResizeObserver {
+  inner: Rc<ResizeObserverInner>,
+  targets: RefCell<Vec<ObservedTarget>>,
+}
+
+impl ResizeObserver {
+  pub fn new(callback: ResizeObserverCallback) -> ResizeObserver {
+    ResizeObserver {
+      inner: Rc::new(ResizeObserverInner {
+        callback: RefCell::new(callback),
+        pending_entries: RefCell::new(HashMap::new()),
+        frame_scheduled: Cell::new(false),
+      }),
+      targets: RefCell::new(Vec::new()),
+    }
+  }
+
+  /// Behavior as same as `ResizeObserver.observe()` in JavaScript.
+  /// Observing an element again replaces the box option it was observed with.
+  pub fn observe(&self, target: &Element, box_option: ResizeObserverBoxOptions, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.unobserve(target);
+
+    let inner = Rc::downgrade(&self.inner);
+    let last_size: Cell<Option<ResizeObserverSize>> = Cell::new(None);
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    // Elements with a `resize` listener receive a CustomEvent whose detail is the JSON of the entry.
+    let guard = target.as_node().event_target.add_event_listener_guarded("resize", Box::new(move |event| {
+      let Some(entry) = to_resize_observer_entry(event) else {
+        return;
+      };
+      let observed_size = match box_option {
+        ResizeObserverBoxOptions::ContentBox => entry.content_box_size,
+        ResizeObserverBoxOptions::BorderBox => entry.border_box_size,
+        ResizeObserverBoxOptions::DevicePixelContentBox => {
+          let exception_state = event.context().create_exception_state();
+          let device_pixel_ratio = event.context().window().device_pixel_ratio(&exception_state).unwrap_or(1.0);
+          ResizeObserverSize {
+            inline_size: entry.content_box_size.inline_size * device_pixel_ratio,
+            block_size: entry.content_box_size.block_size * device_pixel_ratio,
+          }
+        }
+      };
+      if last_size.replace(Some(observed_size)) == Some(observed_size) {
+        return;
+      }
+      schedule_entry(&inner, event.context(), entry);
+    }), &options, exception_state)?;
+
+    self.targets.borrow_mut().push(ObservedTarget {
+      ptr: target.ptr(),
+      _guard: guard,
+    });
+    Ok(())
+  }
+
+  /// Behavior as same as `ResizeObserver.unobserve()` in JavaScript.
+  pub fn unobserve(&self, target: &Element) {
+    self.targets.borrow_mut().retain(|observed| observed.ptr != target.ptr());
+    self.inner.pending_entries.borrow_mut().remove(&(target.ptr() as usize));
+  }
+
+  /// Behavior as same as `ResizeObserver.disconnect()` in JavaScript, all the elements are unobserved.
+  pub fn disconnect(&self) {
+    self.targets.borrow_mut().clear();
+    self.inner.pending_entries.borrow_mut().clear();
+  }
+}
+
+fn schedule_entry(inner: &Weak<ResizeObserverInner>, context: &ExecutingContext, entry: ResizeObserverEntry) {
+  let Some(observer) = inner.upgrade() else {
+    return;
+  };
+  observer.pending_entries.borrow_mut().insert(entry.target.ptr() as usize, entry);
+  if observer.frame_scheduled.replace(true) {
+    return;
+  }
+
+  let inner = inner.clone();
+  let exception_state = context.create_exception_state();
+  let result = context.request_animation_frame(Box::new(move |_| {
+    let Some(observer) = inner.upgrade() else {
+      return;
+    };
+    observer.frame_scheduled.set(false);
+    let entries = observer.pending_entries.borrow_mut().drain().map(|(_, entry)| entry).collect::<Vec<_>>();
+    if entries.is_empty() {
+      return;
+    }
+    // The callback is not reentrant, size changes caused by the callback itself are reported in the next frame.
+    let callback = observer.callback.try_borrow_mut();
+    if let Ok(mut callback) = callback {
+      callback(&entries);
+    }
+  }), &exception_state);
+  if result.is_err() {
+    observer.frame_scheduled.set(false);
+  }
+}
+
+fn to_resize_observer_entry(event: &Event) -> Option<ResizeObserverEntry> {
+  let detail = event.as_custom_event().ok()?.detail()?;
+  let detail = match detail {
+    ScriptValue::String(json) => ScriptValue::from_json(&json).ok()?,
+    detail => detail,
+  };
+  let ScriptValue::Object(members) = detail else {
+    return None;
+  };
+  let border_box_size = to_resize_observer_size(members.get("borderBoxSize")?)?;
+  let content_box_size = to_resize_observer_size(members.get("contentBoxSize")?)?;
+  let target = event.target().as_element().ok()?;
+  Some(ResizeObserverEntry {
+    target,
+    content_rect: DomRect {
+      width: content_box_size.inline_size,
+      height: content_box_size.block_size,
+      right: content_box_size.inline_size,
+      bottom: content_box_size.block_size,
+      ..Default::default()
+    },
+    border_box_size,
+    content_box_size,
+  })
+}
+
+fn to_resize_observer_size(value: &ScriptValue) -> Option<ResizeObserverSize> {
+  let ScriptValue::Object(members) = value else {
+    return None;
+  };
+  let size = |name: &str| match members.get(name) {
+    Some(ScriptValue::Number(value)) => Some(*value),
+    _ => None,
+  };
+  Some(ResizeObserverSize {
+    inline_size: size("inlineSize")?,
+    block_size: size("blockSize")?,
+  })
+}
@@ -0,0 +1,53 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+/// Whether scrolling jumps to the destination or animates to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollBehavior {
+  #[default]
+  Auto,
+  Smooth,
+}
+
+/// Where the element is aligned within its scroll container along one axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollLogicalPosition {
+  Start,
+  Center,
+  End,
+  /// Scrolls the least distance that makes the element visible, or not at all if it already is.
+  Nearest,
+}
+
+impl ScrollLogicalPosition {
+  pub(crate) fn as_str(&self) -> &'static str {
+    match self {
+      ScrollLogicalPosition::Start => "start",
+      ScrollLogicalPosition::Center => "center",
+      ScrollLogicalPosition::End => "end",
+      ScrollLogicalPosition::Nearest => "nearest",
+    }
+  }
+}
+
+/// Options of `Element::scroll_into_view()`.
+///
+/// Defaults to aligning the element at the start of the block axis and the nearest edge of the inline axis,
+/// as `element.scrollIntoView()` does in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollIntoViewOptions {
+  pub behavior: ScrollBehavior,
+  pub block: ScrollLogicalPosition,
+  pub inline: ScrollLogicalPosition,
+}
+
+impl Default for ScrollIntoViewOptions {
+  fn default() -> Self {
+    ScrollIntoViewOptions {
+      behavior: ScrollBehavior::Auto,
+      block: ScrollLogicalPosition::Start,
+      inline: ScrollLogicalPosition::Nearest,
+    }
+  }
+}
@@ -4,11 +4,14 @@
 
 use std::ffi::*;
 use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
 
 #[repr(C)]
 pub struct TextNodeRustMethods {
   pub version: c_double,
   pub character_data: CharacterDataRustMethods,
+  pub split_text: extern "C" fn(*const OpaquePtr, i64, *const OpaquePtr) -> RustValue<TextNodeRustMethods>,
+  pub whole_text: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> *mut c_char,
 }
 
 impl RustMethods for TextNodeRustMethods {}
@@ -19,18 +22,124 @@ pub struct Text {
 }
 
 impl Text {
+  /// Behavior as same as `Text.data` in JavaScript.
+  pub fn data(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.character_data.data(exception_state)
+  }
+
+  /// Behavior as same as setting `Text.data` in JavaScript.
+  /// The node is updated in place, so it keeps its position in the tree.
+  pub fn set_data(&self, data: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.character_data.set_data(data, exception_state)
+  }
+
+  /// Behavior as same as `Text.length` in JavaScript, the number of UTF-16 code units of the data.
+  pub fn length(&self) -> usize {
+    self.character_data.length()
+  }
+
+  /// Behavior as same as `Text.splitText()` in JavaScript, `offset` is in UTF-16 code units.
+  /// This node keeps the data before `offset`, and the returned node, which is inserted after it, gets the rest.
+  /// Returns `Err` with an `IndexSizeError` when `offset` is larger than the length.
+  pub fn split_text(&self, offset: usize, exception_state: &ExceptionState) -> Result<Text, WebFError> {
+    let event_target = &self.character_data.node.event_target;
+    event_target.check_disposed()?;
+    let new_text = unsafe {
+      ((*self.method_pointer).split_text)(self.ptr(), offset as i64, exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+    Ok(Text::initialize(new_text.value, event_target.context(), new_text.method_pointer, new_text.status))
+  }
+
+  /// Behavior as same as `Text.wholeText` in JavaScript,
+  /// the data of this node joined with the data of its adjacent Text siblings in tree order.
+  pub fn whole_text(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    let event_target = &self.character_data.node.event_target;
+    event_target.check_disposed()?;
+    let value = unsafe {
+      ((*self.method_pointer).whole_text)(self.ptr(), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(event_target.context()));
+    }
+    let whole_text = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(value);
+    Ok(whole_text)
+  }
 }
 
 impl NodeMethods for Text {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.character_data.node.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.character_data.node.remove_child(target_node, exception_state)
   }
 
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.character_data.node.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.character_data.node.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.character_data.node.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.character_data.node.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.character_data.node.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.character_data.node.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.character_data.node.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.character_data.node.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.character_data.node.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.character_data.node.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.character_data.node.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.character_data.node.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.character_data.node.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.character_data.node.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.character_data.node.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     &self.character_data.node
   }
@@ -55,18 +164,26 @@ impl EventTargetMethods for Text {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.character_data.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.character_data.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.character_data.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.character_data.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.character_data.downgrade()
+  }
 }
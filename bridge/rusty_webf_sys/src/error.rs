@@ -0,0 +1,161 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::fmt;
+
+// https://webidl.spec.whatwg.org/#idl-DOMException-error-names
+const DOM_EXCEPTION_NAMES: [&str; 30] = [
+  "IndexSizeError",
+  "HierarchyRequestError",
+  "WrongDocumentError",
+  "InvalidCharacterError",
+  "NoModificationAllowedError",
+  "NotFoundError",
+  "NotSupportedError",
+  "InUseAttributeError",
+  "InvalidStateError",
+  "InvalidModificationError",
+  "NamespaceError",
+  "InvalidAccessError",
+  "TypeMismatchError",
+  "SecurityError",
+  "AbortError",
+  "URLMismatchError",
+  "QuotaExceededError",
+  "TimeoutError",
+  "InvalidNodeTypeError",
+  "DataCloneError",
+  "EncodingError",
+  "NotReadableError",
+  "UnknownError",
+  "ConstraintError",
+  "DataError",
+  "TransactionInactiveError",
+  "ReadOnlyError",
+  "VersionError",
+  "OperationError",
+  "NotAllowedError",
+];
+
+/// The error of the WebF APIs, it's what an exception thrown by the same API would be in JavaScript.
+///
+/// `Display` formats the error as `name: message`, the same as `String(error)` in JavaScript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebFError {
+  /// A `DOMException` other than the ones with a variant, `name` is one of the DOMException names such as `NotFoundError`.
+  DomException {
+    name: String,
+    message: String,
+  },
+  TypeError(String),
+  /// A value doesn't have the expected type, such as converting a `ScriptValue` holding a number into a `String`.
+  /// Named as `TypeError`, which is what JavaScript throws for the same conversion.
+  TypeMismatch(String),
+  RangeError(String),
+  /// Both the `SyntaxError` of JavaScript and the `DOMException` of the same name.
+  SyntaxError(String),
+  /// The `DOMException` named `NetworkError`, the request failed before a response was received.
+  NetworkError(String),
+  /// The object or the context it belongs to is disposed.
+  Disposed,
+  /// Errors without a variant, such as a `ReferenceError` thrown by JavaScript, with the full message.
+  Other(String),
+}
+
+impl WebFError {
+  pub fn dom_exception(name: &str, message: &str) -> WebFError {
+    WebFError::DomException {
+      name: name.to_string(),
+      message: message.to_string(),
+    }
+  }
+
+  /// The name of the error, the same as `error.name` in JavaScript. `Other` errors have no name.
+  pub fn name(&self) -> Option<&str> {
+    match self {
+      WebFError::DomException { name, .. } => Some(name),
+      WebFError::TypeError(_) | WebFError::TypeMismatch(_) => Some("TypeError"),
+      WebFError::RangeError(_) => Some("RangeError"),
+      WebFError::SyntaxError(_) => Some("SyntaxError"),
+      WebFError::NetworkError(_) => Some("NetworkError"),
+      WebFError::Disposed => Some("InvalidStateError"),
+      WebFError::Other(_) => None,
+    }
+  }
+
+  /// The message of the error, the same as `error.message` in JavaScript.
+  pub fn message(&self) -> &str {
+    match self {
+      WebFError::DomException { message, .. } => message,
+      WebFError::TypeError(message)
+      | WebFError::TypeMismatch(message)
+      | WebFError::RangeError(message)
+      | WebFError::SyntaxError(message)
+      | WebFError::NetworkError(message)
+      | WebFError::Other(message) => message,
+      WebFError::Disposed => "The object is disposed.",
+    }
+  }
+
+  // Parses the error reported by the engine, which is formatted as `name: message` and followed by the stack.
+  pub(crate) fn from_exception_message(text: &str) -> WebFError {
+    let text = text.trim_end_matches('\0');
+    let Some((name, rest)) = text.split_once(": ") else {
+      return WebFError::Other(text.trim_end().to_string());
+    };
+    let message = match rest.find("\n    at ") {
+      Some(index) => &rest[..index],
+      None => rest.trim_end_matches('\n'),
+    }.to_string();
+    match name {
+      "TypeError" => WebFError::TypeError(message),
+      "RangeError" => WebFError::RangeError(message),
+      "SyntaxError" => WebFError::SyntaxError(message),
+      "NetworkError" => WebFError::NetworkError(message),
+      name if DOM_EXCEPTION_NAMES.contains(&name) => WebFError::DomException { name: name.to_string(), message },
+      name => WebFError::Other(format!("{}: {}", name, message)),
+    }
+  }
+}
+
+impl fmt::Display for WebFError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.name() {
+      Some(name) => write!(f, "{}: {}", name, self.message()),
+      None => f.write_str(self.message()),
+    }
+  }
+}
+
+impl std::error::Error for WebFError {}
+
+impl From<String> for WebFError {
+  fn from(message: String) -> Self {
+    WebFError::Other(message)
+  }
+}
+
+impl From<&str> for WebFError {
+  fn from(message: &str) -> Self {
+    WebFError::Other(message.to_string())
+  }
+}
+
+/// Returned by `initialize_webf_api()` when the method tables reported by WebF don't have the layout this crate is built against,
+/// which happens when the WebF app and the crate are not upgraded together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VersionMismatch {
+  /// The version this crate is built against, which is `WEBF_API_VERSION`.
+  pub expected: f64,
+  /// The version reported by WebF.
+  pub actual: f64,
+}
+
+impl fmt::Display for VersionMismatch {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "The WebF API version {} is not compatible with the version {} which webf_sys is built against.", self.actual, self.expected)
+  }
+}
+
+impl std::error::Error for VersionMismatch {}
@@ -85,7 +85,7 @@ impl EventMethods for CloseEvent {
   fn cancel_bubble(&self) -> bool {
     self.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -94,6 +94,12 @@ impl EventMethods for CloseEvent {
   fn current_target(&self) -> EventTarget {
     self.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.event.default_prevented()
   }
@@ -112,16 +118,16 @@ impl EventMethods for CloseEvent {
   fn type_(&self) -> String {
     self.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
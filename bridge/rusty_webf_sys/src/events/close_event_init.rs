@@ -1,12 +1,23 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 use std::ffi::*;
-use crate::*;
-#[repr(C)]
+
+/// Options of `Document::create_close_event()`, behavior as same as `CloseEventInit` in JavaScript.
+#[derive(Debug, Clone, Default)]
 pub struct CloseEventInit {
+  pub bubbles: bool,
+  pub cancelable: bool,
+  /// The close code sent by the server, such as 1000 for a normal closure or 1006 for an abnormal closure.
+  pub code: u16,
+  pub reason: String,
+  pub was_clean: bool,
+}
+
+/// The options of `Document::create_close_event()`, filled by the Rust side.
+/// The reason is borrowed from the caller and only needs to outlive the call.
+#[repr(C)]
+pub struct CloseEventInitData {
   pub bubbles: i32,
   pub cancelable: i32,
   pub composed: i32,
@@ -75,7 +75,7 @@ impl EventMethods for FocusEvent {
   fn cancel_bubble(&self) -> bool {
     self.ui_event.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -84,6 +84,12 @@ impl EventMethods for FocusEvent {
   fn current_target(&self) -> EventTarget {
     self.ui_event.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.ui_event.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.ui_event.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.ui_event.event.default_prevented()
   }
@@ -102,16 +108,16 @@ impl EventMethods for FocusEvent {
   fn type_(&self) -> String {
     self.ui_event.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
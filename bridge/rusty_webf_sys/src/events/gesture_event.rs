@@ -1,10 +1,30 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 use std::ffi::*;
 use crate::*;
+
+/// The phase of a continuous gesture, such as `pan`, `scale` and `drag`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GestureState {
+  Start,
+  Update,
+  End,
+  Cancel,
+}
+
+impl GestureState {
+  fn from_raw(value: &str) -> Option<GestureState> {
+    match value {
+      "start" => Some(GestureState::Start),
+      "update" => Some(GestureState::Update),
+      "end" => Some(GestureState::End),
+      "cancel" => Some(GestureState::Cancel),
+      _ => None,
+    }
+  }
+}
+
 #[repr(C)]
 pub struct GestureEventRustMethods {
   pub version: c_double,
@@ -44,13 +64,15 @@ impl GestureEvent {
   pub fn context<'a>(&self) -> &'a ExecutingContext {
     self.event.context()
   }
-  pub fn state(&self) -> String {
+  /// The phase of the gesture, `None` for the gestures which have no phases, such as `swipe`.
+  pub fn state(&self) -> Option<GestureState> {
     let value = unsafe {
       ((*self.method_pointer).state)(self.ptr())
     };
     let value = unsafe { std::ffi::CStr::from_ptr(value) };
-    value.to_str().unwrap().to_string()
+    GestureState::from_raw(&value.to_string_lossy())
   }
+  /// The direction of the gesture, an empty string when it is not recognized.
   pub fn direction(&self) -> String {
     let value = unsafe {
       ((*self.method_pointer).direction)(self.ptr())
@@ -58,36 +80,44 @@ impl GestureEvent {
     let value = unsafe { std::ffi::CStr::from_ptr(value) };
     value.to_str().unwrap().to_string()
   }
+  /// The horizontal position of the pointer in global coordinates for `pan` and `drag` gestures, in pixels.
   pub fn delta_x(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).delta_x)(self.ptr())
     };
     value
   }
+  /// The vertical position of the pointer in global coordinates for `pan` and `drag` gestures, in pixels.
   pub fn delta_y(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).delta_y)(self.ptr())
     };
     value
   }
+  /// The horizontal velocity of the pointer when it is lifted, in pixels per second.
+  /// Only reported when the state is `End` and for `swipe` gestures.
   pub fn velocity_x(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).velocity_x)(self.ptr())
     };
     value
   }
+  /// The vertical velocity of the pointer when it is lifted, in pixels per second.
+  /// Only reported when the state is `End` and for `swipe` gestures.
   pub fn velocity_y(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).velocity_y)(self.ptr())
     };
     value
   }
+  /// The scale factor of a `scale` gesture relative to its start.
   pub fn scale(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).scale)(self.ptr())
     };
     value
   }
+  /// The rotation of a `scale` gesture relative to its start, in radians.
   pub fn rotation(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).rotation)(self.ptr())
@@ -96,7 +126,7 @@ impl GestureEvent {
   }
 }
 pub trait GestureEventMethods: EventMethods {
-  fn state(&self) -> String;
+  fn state(&self) -> Option<GestureState>;
   fn direction(&self) -> String;
   fn delta_x(&self) -> f64;
   fn delta_y(&self) -> f64;
@@ -107,7 +137,7 @@ pub trait GestureEventMethods: EventMethods {
   fn as_gesture_event(&self) -> &GestureEvent;
 }
 impl GestureEventMethods for GestureEvent {
-  fn state(&self) -> String {
+  fn state(&self) -> Option<GestureState> {
     self.state()
   }
   fn direction(&self) -> String {
@@ -142,7 +172,7 @@ impl EventMethods for GestureEvent {
   fn cancel_bubble(&self) -> bool {
     self.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -151,6 +181,12 @@ impl EventMethods for GestureEvent {
   fn current_target(&self) -> EventTarget {
     self.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.event.default_prevented()
   }
@@ -169,16 +205,16 @@ impl EventMethods for GestureEvent {
   fn type_(&self) -> String {
     self.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
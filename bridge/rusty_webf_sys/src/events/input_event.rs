@@ -1,10 +1,28 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 use std::ffi::*;
 use crate::*;
+
+/// Behavior as same as `DataTransfer` in JavaScript, the data carried by a paste or drop.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataTransfer {
+  items: Vec<(String, String)>,
+}
+
+impl DataTransfer {
+  /// The formats of the data, such as `text/plain`.
+  pub fn types(&self) -> Vec<&str> {
+    self.items.iter().map(|(format, _)| format.as_str()).collect()
+  }
+
+  /// Behavior as same as `DataTransfer.getData()` in JavaScript, returns an empty string for missing formats.
+  pub fn get_data(&self, format: &str) -> String {
+    self.items.iter()
+      .find(|(key, _)| key.eq_ignore_ascii_case(format))
+      .map_or_else(String::new, |(_, data)| data.clone())
+  }
+}
 #[repr(C)]
 pub struct InputEventRustMethods {
   pub version: c_double,
@@ -13,6 +31,7 @@ pub struct InputEventRustMethods {
   pub dup_input_type: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
   pub data: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
   pub dup_data: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub is_composing: extern "C" fn(ptr: *const OpaquePtr) -> i32,
 }
 pub struct InputEvent {
   pub ui_event: UIEvent,
@@ -38,6 +57,8 @@ impl InputEvent {
   pub fn context<'a>(&self) -> &'a ExecutingContext {
     self.ui_event.context()
   }
+  /// Behavior as same as `InputEvent.inputType` in JavaScript, such as `insertText`, `insertCompositionText`,
+  /// `deleteContentBackward` or `deleteContentForward`.
   pub fn input_type(&self) -> String {
     let value = unsafe {
       ((*self.method_pointer).input_type)(self.ptr())
@@ -45,26 +66,54 @@ impl InputEvent {
     let value = unsafe { std::ffi::CStr::from_ptr(value) };
     value.to_str().unwrap().to_string()
   }
-  pub fn data(&self) -> String {
+  /// Behavior as same as `InputEvent.data` in JavaScript, the inserted text.
+  /// Returns None when no text is inserted, such as for deletions.
+  pub fn data(&self) -> Option<String> {
     let value = unsafe {
       ((*self.method_pointer).data)(self.ptr())
     };
     let value = unsafe { std::ffi::CStr::from_ptr(value) };
-    value.to_str().unwrap().to_string()
+    let value = value.to_str().unwrap();
+    if value.is_empty() {
+      return None;
+    }
+    Some(value.to_string())
+  }
+  /// Behavior as same as `InputEvent.isComposing` in JavaScript,
+  /// whether the event fires between `compositionstart` and `compositionend`.
+  pub fn is_composing(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).is_composing)(self.ptr())
+    };
+    value != 0
+  }
+  /// Behavior as same as `InputEvent.dataTransfer` in JavaScript.
+  /// It is only available for pastes and drops into editable content, which are not dispatched by `<input>` and `<textarea>`,
+  /// so it is always None for now.
+  pub fn data_transfer(&self) -> Option<DataTransfer> {
+    None
   }
 }
 pub trait InputEventMethods: UIEventMethods {
   fn input_type(&self) -> String;
-  fn data(&self) -> String;
+  fn data(&self) -> Option<String>;
+  fn is_composing(&self) -> bool;
+  fn data_transfer(&self) -> Option<DataTransfer>;
   fn as_input_event(&self) -> &InputEvent;
 }
 impl InputEventMethods for InputEvent {
   fn input_type(&self) -> String {
     self.input_type()
   }
-  fn data(&self) -> String {
+  fn data(&self) -> Option<String> {
     self.data()
   }
+  fn is_composing(&self) -> bool {
+    self.is_composing()
+  }
+  fn data_transfer(&self) -> Option<DataTransfer> {
+    self.data_transfer()
+  }
   fn as_input_event(&self) -> &InputEvent {
     self
   }
@@ -90,7 +139,7 @@ impl EventMethods for InputEvent {
   fn cancel_bubble(&self) -> bool {
     self.ui_event.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -99,6 +148,12 @@ impl EventMethods for InputEvent {
   fn current_target(&self) -> EventTarget {
     self.ui_event.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.ui_event.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.ui_event.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.ui_event.event.default_prevented()
   }
@@ -117,16 +172,16 @@ impl EventMethods for InputEvent {
   fn type_(&self) -> String {
     self.ui_event.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
@@ -12,4 +12,5 @@ pub struct InputEventInit {
   pub which: c_double,
   pub input_type: *const c_char,
   pub data: *const c_char,
+  pub is_composing: i32,
 }
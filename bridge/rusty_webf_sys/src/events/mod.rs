@@ -5,6 +5,8 @@ pub mod animation_event_init;
 pub mod animation_event;
 pub mod close_event_init;
 pub mod close_event;
+pub mod composition_event_init;
+pub mod composition_event;
 pub mod focus_event_init;
 pub mod focus_event;
 pub mod gesture_event_init;
@@ -16,19 +18,27 @@ pub mod input_event;
 pub mod intersection_change_event_init;
 pub mod intersection_change_event;
 pub mod keyboard_event_init;
+pub mod keyboard_event;
 pub mod mouse_event_init;
 pub mod mouse_event;
 pub mod pointer_event_init;
 pub mod pointer_event;
+pub mod pop_state_event;
+pub mod storage_event;
+pub mod touch_event;
 pub mod transition_event_init;
 pub mod transition_event;
 pub mod ui_event_init;
 pub mod ui_event;
+pub mod wheel_event_init;
+pub mod wheel_event;
 
 pub use animation_event_init::*;
 pub use animation_event::*;
 pub use close_event_init::*;
 pub use close_event::*;
+pub use composition_event_init::*;
+pub use composition_event::*;
 pub use focus_event_init::*;
 pub use focus_event::*;
 pub use gesture_event_init::*;
@@ -40,11 +50,17 @@ pub use input_event::*;
 pub use intersection_change_event_init::*;
 pub use intersection_change_event::*;
 pub use keyboard_event_init::*;
+pub use keyboard_event::*;
 pub use mouse_event_init::*;
 pub use mouse_event::*;
 pub use pointer_event_init::*;
 pub use pointer_event::*;
+pub use pop_state_event::*;
+pub use storage_event::*;
+pub use touch_event::*;
 pub use transition_event_init::*;
 pub use transition_event::*;
 pub use ui_event_init::*;
 pub use ui_event::*;
+pub use wheel_event_init::*;
+pub use wheel_event::*;
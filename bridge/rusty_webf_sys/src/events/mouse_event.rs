@@ -1,18 +1,101 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 use std::ffi::*;
 use crate::*;
+/// The button which changed state, behavior as same as `MouseEvent.button` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseButton {
+  /// The main button, usually the left button. Events which are not caused by a button report it as well.
+  Left,
+  /// The auxiliary button, usually the wheel button.
+  Middle,
+  /// The secondary button, usually the right button.
+  Right,
+  /// The fourth button, usually the browser back button.
+  Back,
+  /// The fifth button, usually the browser forward button.
+  Forward,
+  /// Any other button, holding the raw value of `MouseEvent.button`.
+  Other(i16),
+}
+impl MouseButton {
+  fn from_raw(value: f64) -> MouseButton {
+    match value as i16 {
+      0 => MouseButton::Left,
+      1 => MouseButton::Middle,
+      2 => MouseButton::Right,
+      3 => MouseButton::Back,
+      4 => MouseButton::Forward,
+      other => MouseButton::Other(other),
+    }
+  }
+}
+/// The buttons held down, behavior as same as the bitmask of `MouseEvent.buttons` in JavaScript.
+/// A chorded press sets several flags, which could be checked by `contains()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MouseButtons(u16);
+impl MouseButtons {
+  /// The main button, usually the left button.
+  pub const LEFT: MouseButtons = MouseButtons(0x01);
+  /// The secondary button, usually the right button.
+  pub const RIGHT: MouseButtons = MouseButtons(0x02);
+  /// The auxiliary button, usually the wheel button.
+  pub const MIDDLE: MouseButtons = MouseButtons(0x04);
+  pub const BACK: MouseButtons = MouseButtons(0x08);
+  pub const FORWARD: MouseButtons = MouseButtons(0x10);
+  fn from_raw(value: f64) -> MouseButtons {
+    MouseButtons(value as u16)
+  }
+  pub fn from_bits(bits: u16) -> MouseButtons {
+    MouseButtons(bits)
+  }
+  pub fn bits(&self) -> u16 {
+    self.0
+  }
+  /// Whether no button is held down.
+  pub fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+  /// Whether all the buttons of `other` are held down.
+  pub fn contains(&self, other: MouseButtons) -> bool {
+    self.0 & other.0 == other.0
+  }
+}
+impl std::ops::BitOr for MouseButtons {
+  type Output = MouseButtons;
+  fn bitor(self, rhs: MouseButtons) -> MouseButtons {
+    MouseButtons(self.0 | rhs.0)
+  }
+}
+impl std::ops::BitAnd for MouseButtons {
+  type Output = MouseButtons;
+  fn bitand(self, rhs: MouseButtons) -> MouseButtons {
+    MouseButtons(self.0 & rhs.0)
+  }
+}
 #[repr(C)]
 pub struct MouseEventRustMethods {
   pub version: c_double,
   pub ui_event: UIEventRustMethods,
+  pub alt_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub button: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub buttons: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
   pub client_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
   pub client_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub ctrl_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub meta_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub movement_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub movement_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
   pub offset_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
   pub offset_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub page_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub page_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub screen_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub screen_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub shift_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
 }
 pub struct MouseEvent {
   pub ui_event: UIEvent,
@@ -38,6 +121,26 @@ impl MouseEvent {
   pub fn context<'a>(&self) -> &'a ExecutingContext {
     self.ui_event.context()
   }
+  pub fn alt_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).alt_key)(self.ptr())
+    };
+    value != 0
+  }
+  /// Behavior as same as `MouseEvent.button` in JavaScript.
+  pub fn button(&self) -> MouseButton {
+    let value = unsafe {
+      ((*self.method_pointer).button)(self.ptr())
+    };
+    MouseButton::from_raw(value)
+  }
+  /// Behavior as same as `MouseEvent.buttons` in JavaScript.
+  pub fn buttons(&self) -> MouseButtons {
+    let value = unsafe {
+      ((*self.method_pointer).buttons)(self.ptr())
+    };
+    MouseButtons::from_raw(value)
+  }
   pub fn client_x(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).client_x)(self.ptr())
@@ -50,6 +153,30 @@ impl MouseEvent {
     };
     value
   }
+  pub fn ctrl_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).ctrl_key)(self.ptr())
+    };
+    value != 0
+  }
+  pub fn meta_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).meta_key)(self.ptr())
+    };
+    value != 0
+  }
+  pub fn movement_x(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).movement_x)(self.ptr())
+    };
+    value
+  }
+  pub fn movement_y(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).movement_y)(self.ptr())
+    };
+    value
+  }
   pub fn offset_x(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).offset_x)(self.ptr())
@@ -62,27 +189,125 @@ impl MouseEvent {
     };
     value
   }
+  pub fn page_x(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).page_x)(self.ptr())
+    };
+    value
+  }
+  pub fn page_y(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).page_y)(self.ptr())
+    };
+    value
+  }
+  pub fn screen_x(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).screen_x)(self.ptr())
+    };
+    value
+  }
+  pub fn screen_y(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).screen_y)(self.ptr())
+    };
+    value
+  }
+  pub fn shift_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).shift_key)(self.ptr())
+    };
+    value != 0
+  }
+  pub fn x(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).x)(self.ptr())
+    };
+    value
+  }
+  pub fn y(&self) -> f64 {
+    let value = unsafe {
+      ((*self.method_pointer).y)(self.ptr())
+    };
+    value
+  }
 }
 pub trait MouseEventMethods: UIEventMethods {
+  fn alt_key(&self) -> bool;
+  fn button(&self) -> MouseButton;
+  fn buttons(&self) -> MouseButtons;
   fn client_x(&self) -> f64;
   fn client_y(&self) -> f64;
+  fn ctrl_key(&self) -> bool;
+  fn meta_key(&self) -> bool;
+  fn movement_x(&self) -> f64;
+  fn movement_y(&self) -> f64;
   fn offset_x(&self) -> f64;
   fn offset_y(&self) -> f64;
+  fn page_x(&self) -> f64;
+  fn page_y(&self) -> f64;
+  fn screen_x(&self) -> f64;
+  fn screen_y(&self) -> f64;
+  fn shift_key(&self) -> bool;
+  fn x(&self) -> f64;
+  fn y(&self) -> f64;
   fn as_mouse_event(&self) -> &MouseEvent;
 }
 impl MouseEventMethods for MouseEvent {
+  fn alt_key(&self) -> bool {
+    self.alt_key()
+  }
+  fn button(&self) -> MouseButton {
+    self.button()
+  }
+  fn buttons(&self) -> MouseButtons {
+    self.buttons()
+  }
   fn client_x(&self) -> f64 {
     self.client_x()
   }
   fn client_y(&self) -> f64 {
     self.client_y()
   }
+  fn ctrl_key(&self) -> bool {
+    self.ctrl_key()
+  }
+  fn meta_key(&self) -> bool {
+    self.meta_key()
+  }
+  fn movement_x(&self) -> f64 {
+    self.movement_x()
+  }
+  fn movement_y(&self) -> f64 {
+    self.movement_y()
+  }
   fn offset_x(&self) -> f64 {
     self.offset_x()
   }
   fn offset_y(&self) -> f64 {
     self.offset_y()
   }
+  fn page_x(&self) -> f64 {
+    self.page_x()
+  }
+  fn page_y(&self) -> f64 {
+    self.page_y()
+  }
+  fn screen_x(&self) -> f64 {
+    self.screen_x()
+  }
+  fn screen_y(&self) -> f64 {
+    self.screen_y()
+  }
+  fn shift_key(&self) -> bool {
+    self.shift_key()
+  }
+  fn x(&self) -> f64 {
+    self.x()
+  }
+  fn y(&self) -> f64 {
+    self.y()
+  }
   fn as_mouse_event(&self) -> &MouseEvent {
     self
   }
@@ -108,7 +333,7 @@ impl EventMethods for MouseEvent {
   fn cancel_bubble(&self) -> bool {
     self.ui_event.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -117,6 +342,12 @@ impl EventMethods for MouseEvent {
   fn current_target(&self) -> EventTarget {
     self.ui_event.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.ui_event.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.ui_event.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.ui_event.event.default_prevented()
   }
@@ -135,16 +366,16 @@ impl EventMethods for MouseEvent {
   fn type_(&self) -> String {
     self.ui_event.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.ui_event.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
@@ -1,13 +1,53 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
-use std::ffi::*;
-use crate::*;
-#[repr(C)]
+
+/// Options of `Document::create_mouse_event()`, behavior as same as `MouseEventInit` in JavaScript.
+/// The coordinates are relative to the viewport, the same as `MouseEvent::client_x()` and `MouseEvent::client_y()`.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct MouseEventInit {
-  pub detail: c_double,
-  pub view: RustValue<WindowRustMethods>,
-  pub which: c_double,
+  pub bubbles: bool,
+  pub cancelable: bool,
+  pub client_x: f64,
+  pub client_y: f64,
+  /// The button which changed state, 0 is the main button, 1 the auxiliary button and 2 the secondary button.
+  pub button: i16,
+  /// The buttons held down as a bitmask, 1 is the main button, 2 the secondary button and 4 the auxiliary button.
+  pub buttons: u16,
+  pub alt_key: bool,
+  pub ctrl_key: bool,
+  pub meta_key: bool,
+  pub shift_key: bool,
+}
+
+/// The options of `Document::create_mouse_event()`, filled by the Rust side.
+#[repr(C)]
+pub struct MouseEventInitData {
+  pub bubbles: i32,
+  pub cancelable: i32,
+  pub client_x: f64,
+  pub client_y: f64,
+  pub button: i32,
+  pub buttons: i32,
+  pub alt_key: i32,
+  pub ctrl_key: i32,
+  pub meta_key: i32,
+  pub shift_key: i32,
+}
+
+impl From<&MouseEventInit> for MouseEventInitData {
+  fn from(init: &MouseEventInit) -> Self {
+    MouseEventInitData {
+      bubbles: i32::from(init.bubbles),
+      cancelable: i32::from(init.cancelable),
+      client_x: init.client_x,
+      client_y: init.client_y,
+      button: i32::from(init.button),
+      buttons: i32::from(init.buttons),
+      alt_key: i32::from(init.alt_key),
+      ctrl_key: i32::from(init.ctrl_key),
+      meta_key: i32::from(init.meta_key),
+      shift_key: i32::from(init.shift_key),
+    }
+  }
 }
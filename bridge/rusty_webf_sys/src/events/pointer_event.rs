@@ -1,10 +1,41 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 use std::ffi::*;
 use crate::*;
+
+/// The type of the device which caused a PointerEvent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerType {
+  Mouse,
+  Pen,
+  Touch,
+  /// The device type can not be detected.
+  Unknown,
+}
+
+impl PointerType {
+  fn from_raw(value: &str) -> PointerType {
+    match value {
+      "mouse" => PointerType::Mouse,
+      "pen" => PointerType::Pen,
+      "touch" => PointerType::Touch,
+      _ => PointerType::Unknown,
+    }
+  }
+
+  // A nul-terminated static string, so that it outlives the FFI call.
+  pub(crate) fn to_raw(self) -> *const c_char {
+    let value: &'static [u8] = match self {
+      PointerType::Mouse => b"mouse\0",
+      PointerType::Pen => b"pen\0",
+      PointerType::Touch => b"touch\0",
+      PointerType::Unknown => b"\0",
+    };
+    value.as_ptr() as *const c_char
+  }
+}
+
 #[repr(C)]
 pub struct PointerEventRustMethods {
   pub version: c_double,
@@ -45,61 +76,70 @@ impl PointerEvent {
   pub fn context<'a>(&self) -> &'a ExecutingContext {
     self.mouse_event.context()
   }
+  /// The height of the contact geometry of the pointer, in CSS pixels.
   pub fn height(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).height)(self.ptr())
     };
     value
   }
+  /// Whether the pointer is the primary pointer of its type, such as the first finger of a multi-touch gesture.
   pub fn is_primary(&self) -> bool {
     let value = unsafe {
       ((*self.method_pointer).is_primary)(self.ptr())
     };
     value != 0
   }
+  /// The unique identifier of the pointer which caused the event.
   pub fn pointer_id(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).pointer_id)(self.ptr())
     };
     value
   }
-  pub fn pointer_type(&self) -> String {
+  pub fn pointer_type(&self) -> PointerType {
     let value = unsafe {
       ((*self.method_pointer).pointer_type)(self.ptr())
     };
     let value = unsafe { std::ffi::CStr::from_ptr(value) };
-    value.to_str().unwrap().to_string()
+    PointerType::from_raw(&value.to_string_lossy())
   }
+  /// The normalized pressure of the pointer in the range of 0 to 1.
   pub fn pressure(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).pressure)(self.ptr())
     };
     value
   }
+  /// The normalized tangential pressure, also known as barrel pressure, in the range of -1 to 1.
   pub fn tangential_pressure(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).tangential_pressure)(self.ptr())
     };
     value
   }
+  /// The plane angle between the Y-Z plane and the plane containing the pen axis and the Y axis, in degrees from -90 to 90.
   pub fn tilt_x(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).tilt_x)(self.ptr())
     };
     value
   }
+  /// The plane angle between the X-Z plane and the plane containing the pen axis and the X axis, in degrees from -90 to 90.
   pub fn tilt_y(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).tilt_y)(self.ptr())
     };
     value
   }
+  /// The clockwise rotation of the pen around its own major axis, in degrees from 0 to 359.
   pub fn twist(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).twist)(self.ptr())
     };
     value
   }
+  /// The width of the contact geometry of the pointer, in CSS pixels.
   pub fn width(&self) -> f64 {
     let value = unsafe {
       ((*self.method_pointer).width)(self.ptr())
@@ -111,7 +151,7 @@ pub trait PointerEventMethods: MouseEventMethods {
   fn height(&self) -> f64;
   fn is_primary(&self) -> bool;
   fn pointer_id(&self) -> f64;
-  fn pointer_type(&self) -> String;
+  fn pointer_type(&self) -> PointerType;
   fn pressure(&self) -> f64;
   fn tangential_pressure(&self) -> f64;
   fn tilt_x(&self) -> f64;
@@ -130,7 +170,7 @@ impl PointerEventMethods for PointerEvent {
   fn pointer_id(&self) -> f64 {
     self.pointer_id()
   }
-  fn pointer_type(&self) -> String {
+  fn pointer_type(&self) -> PointerType {
     self.pointer_type()
   }
   fn pressure(&self) -> f64 {
@@ -156,18 +196,60 @@ impl PointerEventMethods for PointerEvent {
   }
 }
 impl MouseEventMethods for PointerEvent {
+  fn alt_key(&self) -> bool {
+    self.mouse_event.alt_key()
+  }
+  fn button(&self) -> MouseButton {
+    self.mouse_event.button()
+  }
+  fn buttons(&self) -> MouseButtons {
+    self.mouse_event.buttons()
+  }
   fn client_x(&self) -> f64 {
     self.mouse_event.client_x()
   }
   fn client_y(&self) -> f64 {
     self.mouse_event.client_y()
   }
+  fn ctrl_key(&self) -> bool {
+    self.mouse_event.ctrl_key()
+  }
+  fn meta_key(&self) -> bool {
+    self.mouse_event.meta_key()
+  }
+  fn movement_x(&self) -> f64 {
+    self.mouse_event.movement_x()
+  }
+  fn movement_y(&self) -> f64 {
+    self.mouse_event.movement_y()
+  }
   fn offset_x(&self) -> f64 {
     self.mouse_event.offset_x()
   }
   fn offset_y(&self) -> f64 {
     self.mouse_event.offset_y()
   }
+  fn page_x(&self) -> f64 {
+    self.mouse_event.page_x()
+  }
+  fn page_y(&self) -> f64 {
+    self.mouse_event.page_y()
+  }
+  fn screen_x(&self) -> f64 {
+    self.mouse_event.screen_x()
+  }
+  fn screen_y(&self) -> f64 {
+    self.mouse_event.screen_y()
+  }
+  fn shift_key(&self) -> bool {
+    self.mouse_event.shift_key()
+  }
+  fn x(&self) -> f64 {
+    self.mouse_event.x()
+  }
+  fn y(&self) -> f64 {
+    self.mouse_event.y()
+  }
   fn as_mouse_event(&self) -> &MouseEvent {
     &self.mouse_event
   }
@@ -193,7 +275,7 @@ impl EventMethods for PointerEvent {
   fn cancel_bubble(&self) -> bool {
     self.mouse_event.ui_event.event.cancel_bubble()
   }
-  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.mouse_event.ui_event.event.set_cancel_bubble(value, exception_state)
   }
   fn cancelable(&self) -> bool {
@@ -202,6 +284,12 @@ impl EventMethods for PointerEvent {
   fn current_target(&self) -> EventTarget {
     self.mouse_event.ui_event.event.current_target()
   }
+  fn event_phase(&self) -> EventPhase {
+    self.mouse_event.ui_event.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.mouse_event.ui_event.event.composed_path()
+  }
   fn default_prevented(&self) -> bool {
     self.mouse_event.ui_event.event.default_prevented()
   }
@@ -220,16 +308,16 @@ impl EventMethods for PointerEvent {
   fn type_(&self) -> String {
     self.mouse_event.ui_event.event.type_()
   }
-  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), String> {
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.mouse_event.ui_event.event.init_event(type_, bubbles, cancelable, exception_state)
   }
-  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.mouse_event.ui_event.event.prevent_default(exception_state)
   }
-  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.mouse_event.ui_event.event.stop_immediate_propagation(exception_state)
   }
-  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), String> {
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.mouse_event.ui_event.event.stop_propagation(exception_state)
   }
   fn as_event(&self) -> &Event {
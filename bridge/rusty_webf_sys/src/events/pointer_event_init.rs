@@ -1,12 +1,50 @@
-// Generated by WebF TSDL, don't edit this file directly.
-// Generate command: node scripts/generate_binding_code.js
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 use std::ffi::*;
 use crate::*;
-#[repr(C)]
+
+/// Options of `Document::create_pointer_event()`, behavior as same as `PointerEventInit` in JavaScript.
+#[derive(Debug, Clone, Copy)]
 pub struct PointerEventInit {
+  pub bubbles: bool,
+  pub cancelable: bool,
+  pub is_primary: bool,
+  pub pointer_id: f64,
+  /// `PointerType::Unknown` leaves `pointerType` as an empty string.
+  pub pointer_type: PointerType,
+  pub pressure: f64,
+  pub tangential_pressure: f64,
+  pub tilt_x: f64,
+  pub tilt_y: f64,
+  pub twist: f64,
+  pub width: f64,
+  pub height: f64,
+}
+
+impl Default for PointerEventInit {
+  fn default() -> Self {
+    PointerEventInit {
+      bubbles: false,
+      cancelable: false,
+      is_primary: false,
+      pointer_id: 0.0,
+      pointer_type: PointerType::Unknown,
+      pressure: 0.0,
+      tangential_pressure: 0.0,
+      tilt_x: 0.0,
+      tilt_y: 0.0,
+      twist: 0.0,
+      width: 0.0,
+      height: 0.0,
+    }
+  }
+}
+
+/// The options of `Document::create_pointer_event()`, filled by the Rust side.
+/// The pointer type is a static string, so it outlives the call.
+#[repr(C)]
+pub struct PointerEventInitData {
   pub is_primary: i32,
   pub pointer_id: c_double,
   pub pointer_type: *const c_char,
@@ -18,3 +56,20 @@ pub struct PointerEventInit {
   pub width: c_double,
   pub height: c_double,
 }
+
+impl From<&PointerEventInit> for PointerEventInitData {
+  fn from(init: &PointerEventInit) -> Self {
+    PointerEventInitData {
+      is_primary: i32::from(init.is_primary),
+      pointer_id: init.pointer_id,
+      pointer_type: init.pointer_type.to_raw(),
+      pressure: init.pressure,
+      tangential_pressure: init.tangential_pressure,
+      tilt_x: init.tilt_x,
+      tilt_y: init.tilt_y,
+      twist: init.twist,
+      width: init.width,
+      height: init.height,
+    }
+  }
+}
@@ -0,0 +1,113 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+use std::ffi::*;
+use crate::*;
+#[repr(C)]
+pub struct PopStateEventRustMethods {
+  pub version: c_double,
+  pub event: EventRustMethods,
+  pub state: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<ScriptValueRefRustMethods>,
+}
+pub struct PopStateEvent {
+  pub event: Event,
+  method_pointer: *const PopStateEventRustMethods,
+}
+impl PopStateEvent {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const PopStateEventRustMethods, status: *const RustValueStatus) -> PopStateEvent {
+    unsafe {
+      PopStateEvent {
+        event: Event::initialize(
+          ptr,
+          context,
+          &(method_pointer).as_ref().unwrap().event,
+          status,
+        ),
+        method_pointer,
+      }
+    }
+  }
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.event.ptr()
+  }
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    self.event.context()
+  }
+  pub fn state(&self) -> Option<ScriptValue> {
+    let value = unsafe {
+      ((*self.method_pointer).state)(self.ptr())
+    };
+    let value = ScriptValueRef::initialize(value.value, self.context(), value.method_pointer);
+    value.to_script_value()
+  }
+}
+pub trait PopStateEventMethods: EventMethods {
+  fn state(&self) -> Option<ScriptValue>;
+  fn as_pop_state_event(&self) -> &PopStateEvent;
+}
+impl PopStateEventMethods for PopStateEvent {
+  fn state(&self) -> Option<ScriptValue> {
+    self.state()
+  }
+  fn as_pop_state_event(&self) -> &PopStateEvent {
+    self
+  }
+}
+impl EventMethods for PopStateEvent {
+  fn bubbles(&self) -> bool {
+    self.event.bubbles()
+  }
+  fn cancel_bubble(&self) -> bool {
+    self.event.cancel_bubble()
+  }
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.set_cancel_bubble(value, exception_state)
+  }
+  fn cancelable(&self) -> bool {
+    self.event.cancelable()
+  }
+  fn current_target(&self) -> EventTarget {
+    self.event.current_target()
+  }
+  fn event_phase(&self) -> EventPhase {
+    self.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.event.composed_path()
+  }
+  fn default_prevented(&self) -> bool {
+    self.event.default_prevented()
+  }
+  fn src_element(&self) -> EventTarget {
+    self.event.src_element()
+  }
+  fn target(&self) -> EventTarget {
+    self.event.target()
+  }
+  fn is_trusted(&self) -> bool {
+    self.event.is_trusted()
+  }
+  fn time_stamp(&self) -> f64 {
+    self.event.time_stamp()
+  }
+  fn type_(&self) -> String {
+    self.event.type_()
+  }
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.init_event(type_, bubbles, cancelable, exception_state)
+  }
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.prevent_default(exception_state)
+  }
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.stop_immediate_propagation(exception_state)
+  }
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.stop_propagation(exception_state)
+  }
+  fn as_event(&self) -> &Event {
+    &self.event
+  }
+}
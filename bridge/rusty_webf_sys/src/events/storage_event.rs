@@ -0,0 +1,180 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct StorageEventRustMethods {
+  pub version: c_double,
+  pub event: EventRustMethods,
+  pub key: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub dup_key: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub old_value: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub dup_old_value: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub new_value: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub dup_new_value: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub url: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub dup_url: extern "C" fn(ptr: *const OpaquePtr) -> *const c_char,
+  pub storage_area: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<ScriptValueRefRustMethods>,
+}
+
+fn nullable_string(value: *const c_char) -> Option<String> {
+  if value.is_null() {
+    return None;
+  }
+  let value = unsafe { std::ffi::CStr::from_ptr(value) };
+  Some(value.to_string_lossy().into_owned())
+}
+
+/// Behavior as same as `StorageEvent` in JavaScript.
+///
+/// Fired at the window as `storage` when another context of the same origin changes the local storage,
+/// changes made by the current context do not fire it.
+pub struct StorageEvent {
+  pub event: Event,
+  method_pointer: *const StorageEventRustMethods,
+}
+impl StorageEvent {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const StorageEventRustMethods, status: *const RustValueStatus) -> StorageEvent {
+    unsafe {
+      StorageEvent {
+        event: Event::initialize(
+          ptr,
+          context,
+          &(method_pointer).as_ref().unwrap().event,
+          status,
+        ),
+        method_pointer,
+      }
+    }
+  }
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.event.ptr()
+  }
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    self.event.context()
+  }
+  /// The key of the changed item, `None` when the storage was cleared.
+  pub fn key(&self) -> Option<String> {
+    let value = unsafe {
+      ((*self.method_pointer).key)(self.ptr())
+    };
+    nullable_string(value)
+  }
+  /// The value of the item before the change, `None` when the item was added or the storage was cleared.
+  pub fn old_value(&self) -> Option<String> {
+    let value = unsafe {
+      ((*self.method_pointer).old_value)(self.ptr())
+    };
+    nullable_string(value)
+  }
+  /// The value of the item after the change, `None` when the item was removed or the storage was cleared.
+  pub fn new_value(&self) -> Option<String> {
+    let value = unsafe {
+      ((*self.method_pointer).new_value)(self.ptr())
+    };
+    nullable_string(value)
+  }
+  /// The URL of the document whose storage changed.
+  pub fn url(&self) -> String {
+    let value = unsafe {
+      ((*self.method_pointer).url)(self.ptr())
+    };
+    nullable_string(value).unwrap_or_default()
+  }
+  /// The storage which changed, `None` for the events created without a storage area.
+  /// Only the local storage is shared between contexts, so it is the only storage area of the events fired by WebF.
+  pub fn storage_area(&self) -> Option<Storage> {
+    let value = unsafe {
+      ((*self.method_pointer).storage_area)(self.ptr())
+    };
+    let value = ScriptValueRef::initialize(value.value, self.context(), value.method_pointer);
+    value.to_script_value()?;
+    Some(self.context().local_storage())
+  }
+}
+pub trait StorageEventMethods: EventMethods {
+  fn key(&self) -> Option<String>;
+  fn old_value(&self) -> Option<String>;
+  fn new_value(&self) -> Option<String>;
+  fn url(&self) -> String;
+  fn storage_area(&self) -> Option<Storage>;
+  fn as_storage_event(&self) -> &StorageEvent;
+}
+impl StorageEventMethods for StorageEvent {
+  fn key(&self) -> Option<String> {
+    self.key()
+  }
+  fn old_value(&self) -> Option<String> {
+    self.old_value()
+  }
+  fn new_value(&self) -> Option<String> {
+    self.new_value()
+  }
+  fn url(&self) -> String {
+    self.url()
+  }
+  fn storage_area(&self) -> Option<Storage> {
+    self.storage_area()
+  }
+  fn as_storage_event(&self) -> &StorageEvent {
+    self
+  }
+}
+impl EventMethods for StorageEvent {
+  fn bubbles(&self) -> bool {
+    self.event.bubbles()
+  }
+  fn cancel_bubble(&self) -> bool {
+    self.event.cancel_bubble()
+  }
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.set_cancel_bubble(value, exception_state)
+  }
+  fn cancelable(&self) -> bool {
+    self.event.cancelable()
+  }
+  fn current_target(&self) -> EventTarget {
+    self.event.current_target()
+  }
+  fn event_phase(&self) -> EventPhase {
+    self.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.event.composed_path()
+  }
+  fn default_prevented(&self) -> bool {
+    self.event.default_prevented()
+  }
+  fn src_element(&self) -> EventTarget {
+    self.event.src_element()
+  }
+  fn target(&self) -> EventTarget {
+    self.event.target()
+  }
+  fn is_trusted(&self) -> bool {
+    self.event.is_trusted()
+  }
+  fn time_stamp(&self) -> f64 {
+    self.event.time_stamp()
+  }
+  fn type_(&self) -> String {
+    self.event.type_()
+  }
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.init_event(type_, bubbles, cancelable, exception_state)
+  }
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.prevent_default(exception_state)
+  }
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.stop_immediate_propagation(exception_state)
+  }
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event.stop_propagation(exception_state)
+  }
+  fn as_event(&self) -> &Event {
+    &self.event
+  }
+}
@@ -0,0 +1,192 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+use std::ffi::*;
+use crate::*;
+#[repr(C)]
+pub struct TouchEventRustMethods {
+  pub version: c_double,
+  pub ui_event: UIEventRustMethods,
+  pub touches: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<TouchListRustMethods>,
+  pub target_touches: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<TouchListRustMethods>,
+  pub changed_touches: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<TouchListRustMethods>,
+  pub alt_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub meta_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub ctrl_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+  pub shift_key: extern "C" fn(ptr: *const OpaquePtr) -> i32,
+}
+pub struct TouchEvent {
+  pub ui_event: UIEvent,
+  method_pointer: *const TouchEventRustMethods,
+}
+impl TouchEvent {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const TouchEventRustMethods, status: *const RustValueStatus) -> TouchEvent {
+    unsafe {
+      TouchEvent {
+        ui_event: UIEvent::initialize(
+          ptr,
+          context,
+          &(method_pointer).as_ref().unwrap().ui_event,
+          status,
+        ),
+        method_pointer,
+      }
+    }
+  }
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ui_event.ptr()
+  }
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    self.ui_event.context()
+  }
+  pub fn touches(&self) -> TouchList {
+    let value = unsafe {
+      ((*self.method_pointer).touches)(self.ptr())
+    };
+    TouchList::initialize(value.value, self.context(), value.method_pointer, value.status)
+  }
+  pub fn target_touches(&self) -> TouchList {
+    let value = unsafe {
+      ((*self.method_pointer).target_touches)(self.ptr())
+    };
+    TouchList::initialize(value.value, self.context(), value.method_pointer, value.status)
+  }
+  pub fn changed_touches(&self) -> TouchList {
+    let value = unsafe {
+      ((*self.method_pointer).changed_touches)(self.ptr())
+    };
+    TouchList::initialize(value.value, self.context(), value.method_pointer, value.status)
+  }
+  pub fn alt_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).alt_key)(self.ptr())
+    };
+    value != 0
+  }
+  pub fn meta_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).meta_key)(self.ptr())
+    };
+    value != 0
+  }
+  pub fn ctrl_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).ctrl_key)(self.ptr())
+    };
+    value != 0
+  }
+  pub fn shift_key(&self) -> bool {
+    let value = unsafe {
+      ((*self.method_pointer).shift_key)(self.ptr())
+    };
+    value != 0
+  }
+}
+pub trait TouchEventMethods: UIEventMethods {
+  fn touches(&self) -> TouchList;
+  fn target_touches(&self) -> TouchList;
+  fn changed_touches(&self) -> TouchList;
+  fn alt_key(&self) -> bool;
+  fn meta_key(&self) -> bool;
+  fn ctrl_key(&self) -> bool;
+  fn shift_key(&self) -> bool;
+  fn as_touch_event(&self) -> &TouchEvent;
+}
+impl TouchEventMethods for TouchEvent {
+  fn touches(&self) -> TouchList {
+    self.touches()
+  }
+  fn target_touches(&self) -> TouchList {
+    self.target_touches()
+  }
+  fn changed_touches(&self) -> TouchList {
+    self.changed_touches()
+  }
+  fn alt_key(&self) -> bool {
+    self.alt_key()
+  }
+  fn meta_key(&self) -> bool {
+    self.meta_key()
+  }
+  fn ctrl_key(&self) -> bool {
+    self.ctrl_key()
+  }
+  fn shift_key(&self) -> bool {
+    self.shift_key()
+  }
+  fn as_touch_event(&self) -> &TouchEvent {
+    self
+  }
+}
+impl UIEventMethods for TouchEvent {
+  fn detail(&self) -> f64 {
+    self.ui_event.detail()
+  }
+  fn view(&self) -> Window {
+    self.ui_event.view()
+  }
+  fn which(&self) -> f64 {
+    self.ui_event.which()
+  }
+  fn as_ui_event(&self) -> &UIEvent {
+    &self.ui_event
+  }
+}
+impl EventMethods for TouchEvent {
+  fn bubbles(&self) -> bool {
+    self.ui_event.event.bubbles()
+  }
+  fn cancel_bubble(&self) -> bool {
+    self.ui_event.event.cancel_bubble()
+  }
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.ui_event.event.set_cancel_bubble(value, exception_state)
+  }
+  fn cancelable(&self) -> bool {
+    self.ui_event.event.cancelable()
+  }
+  fn current_target(&self) -> EventTarget {
+    self.ui_event.event.current_target()
+  }
+  fn event_phase(&self) -> EventPhase {
+    self.ui_event.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.ui_event.event.composed_path()
+  }
+  fn default_prevented(&self) -> bool {
+    self.ui_event.event.default_prevented()
+  }
+  fn src_element(&self) -> EventTarget {
+    self.ui_event.event.src_element()
+  }
+  fn target(&self) -> EventTarget {
+    self.ui_event.event.target()
+  }
+  fn is_trusted(&self) -> bool {
+    self.ui_event.event.is_trusted()
+  }
+  fn time_stamp(&self) -> f64 {
+    self.ui_event.event.time_stamp()
+  }
+  fn type_(&self) -> String {
+    self.ui_event.event.type_()
+  }
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.ui_event.event.init_event(type_, bubbles, cancelable, exception_state)
+  }
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.ui_event.event.prevent_default(exception_state)
+  }
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.ui_event.event.stop_immediate_propagation(exception_state)
+  }
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.ui_event.event.stop_propagation(exception_state)
+  }
+  fn as_event(&self) -> &Event {
+    &self.ui_event.event
+  }
+}
@@ -0,0 +1,235 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+use std::ffi::*;
+use crate::*;
+
+/// The unit of the delta values of a WheelEvent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaMode {
+  /// The delta values are specified in pixels.
+  Pixel,
+  /// The delta values are specified in lines.
+  Line,
+  /// The delta values are specified in pages.
+  Page,
+}
+
+impl DeltaMode {
+  fn from_raw(value: f64) -> DeltaMode {
+    match value as u32 {
+      1 => DeltaMode::Line,
+      2 => DeltaMode::Page,
+      _ => DeltaMode::Pixel,
+    }
+  }
+}
+
+#[repr(C)]
+pub struct WheelEventRustMethods {
+  pub version: c_double,
+  pub mouse_event: MouseEventRustMethods,
+  pub delta_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub delta_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub delta_z: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub delta_mode: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+}
+pub struct WheelEvent {
+  pub mouse_event: MouseEvent,
+  method_pointer: *const WheelEventRustMethods,
+}
+impl WheelEvent {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const WheelEventRustMethods, status: *const RustValueStatus) -> WheelEvent {
+    unsafe {
+      WheelEvent {
+        mouse_event: MouseEvent::initialize(
+          ptr,
+          context,
+          &(method_pointer).as_ref().unwrap().mouse_event,
+          status,
+        ),
+        method_pointer,
+      }
+    }
+  }
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.mouse_event.ptr()
+  }
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    self.mouse_event.context()
+  }
+  pub fn delta_x(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).delta_x)(self.ptr())
+    }
+  }
+  pub fn delta_y(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).delta_y)(self.ptr())
+    }
+  }
+  pub fn delta_z(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).delta_z)(self.ptr())
+    }
+  }
+  pub fn delta_mode(&self) -> DeltaMode {
+    let value = unsafe {
+      ((*self.method_pointer).delta_mode)(self.ptr())
+    };
+    DeltaMode::from_raw(value)
+  }
+}
+pub trait WheelEventMethods: MouseEventMethods {
+  fn delta_x(&self) -> f64;
+  fn delta_y(&self) -> f64;
+  fn delta_z(&self) -> f64;
+  fn delta_mode(&self) -> DeltaMode;
+  fn as_wheel_event(&self) -> &WheelEvent;
+}
+impl WheelEventMethods for WheelEvent {
+  fn delta_x(&self) -> f64 {
+    self.delta_x()
+  }
+  fn delta_y(&self) -> f64 {
+    self.delta_y()
+  }
+  fn delta_z(&self) -> f64 {
+    self.delta_z()
+  }
+  fn delta_mode(&self) -> DeltaMode {
+    self.delta_mode()
+  }
+  fn as_wheel_event(&self) -> &WheelEvent {
+    self
+  }
+}
+impl MouseEventMethods for WheelEvent {
+  fn alt_key(&self) -> bool {
+    self.mouse_event.alt_key()
+  }
+  fn button(&self) -> MouseButton {
+    self.mouse_event.button()
+  }
+  fn buttons(&self) -> MouseButtons {
+    self.mouse_event.buttons()
+  }
+  fn client_x(&self) -> f64 {
+    self.mouse_event.client_x()
+  }
+  fn client_y(&self) -> f64 {
+    self.mouse_event.client_y()
+  }
+  fn ctrl_key(&self) -> bool {
+    self.mouse_event.ctrl_key()
+  }
+  fn meta_key(&self) -> bool {
+    self.mouse_event.meta_key()
+  }
+  fn movement_x(&self) -> f64 {
+    self.mouse_event.movement_x()
+  }
+  fn movement_y(&self) -> f64 {
+    self.mouse_event.movement_y()
+  }
+  fn offset_x(&self) -> f64 {
+    self.mouse_event.offset_x()
+  }
+  fn offset_y(&self) -> f64 {
+    self.mouse_event.offset_y()
+  }
+  fn page_x(&self) -> f64 {
+    self.mouse_event.page_x()
+  }
+  fn page_y(&self) -> f64 {
+    self.mouse_event.page_y()
+  }
+  fn screen_x(&self) -> f64 {
+    self.mouse_event.screen_x()
+  }
+  fn screen_y(&self) -> f64 {
+    self.mouse_event.screen_y()
+  }
+  fn shift_key(&self) -> bool {
+    self.mouse_event.shift_key()
+  }
+  fn x(&self) -> f64 {
+    self.mouse_event.x()
+  }
+  fn y(&self) -> f64 {
+    self.mouse_event.y()
+  }
+  fn as_mouse_event(&self) -> &MouseEvent {
+    &self.mouse_event
+  }
+}
+impl UIEventMethods for WheelEvent {
+  fn detail(&self) -> f64 {
+    self.mouse_event.ui_event.detail()
+  }
+  fn view(&self) -> Window {
+    self.mouse_event.ui_event.view()
+  }
+  fn which(&self) -> f64 {
+    self.mouse_event.ui_event.which()
+  }
+  fn as_ui_event(&self) -> &UIEvent {
+    &self.mouse_event.ui_event
+  }
+}
+impl EventMethods for WheelEvent {
+  fn bubbles(&self) -> bool {
+    self.mouse_event.ui_event.event.bubbles()
+  }
+  fn cancel_bubble(&self) -> bool {
+    self.mouse_event.ui_event.event.cancel_bubble()
+  }
+  fn set_cancel_bubble(&self, value: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.mouse_event.ui_event.event.set_cancel_bubble(value, exception_state)
+  }
+  fn cancelable(&self) -> bool {
+    self.mouse_event.ui_event.event.cancelable()
+  }
+  fn current_target(&self) -> EventTarget {
+    self.mouse_event.ui_event.event.current_target()
+  }
+  fn event_phase(&self) -> EventPhase {
+    self.mouse_event.ui_event.event.event_phase()
+  }
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.mouse_event.ui_event.event.composed_path()
+  }
+  fn default_prevented(&self) -> bool {
+    self.mouse_event.ui_event.event.default_prevented()
+  }
+  fn src_element(&self) -> EventTarget {
+    self.mouse_event.ui_event.event.src_element()
+  }
+  fn target(&self) -> EventTarget {
+    self.mouse_event.ui_event.event.target()
+  }
+  fn is_trusted(&self) -> bool {
+    self.mouse_event.ui_event.event.is_trusted()
+  }
+  fn time_stamp(&self) -> f64 {
+    self.mouse_event.ui_event.event.time_stamp()
+  }
+  fn type_(&self) -> String {
+    self.mouse_event.ui_event.event.type_()
+  }
+  fn init_event(&self, type_: &str, bubbles: bool, cancelable: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.mouse_event.ui_event.event.init_event(type_, bubbles, cancelable, exception_state)
+  }
+  fn prevent_default(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.mouse_event.ui_event.event.prevent_default(exception_state)
+  }
+  fn stop_immediate_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.mouse_event.ui_event.event.stop_immediate_propagation(exception_state)
+  }
+  fn stop_propagation(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.mouse_event.ui_event.event.stop_propagation(exception_state)
+  }
+  fn as_event(&self) -> &Event {
+    &self.mouse_event.ui_event.event
+  }
+}
@@ -0,0 +1,14 @@
+// Generated by WebF TSDL, don't edit this file directly.
+// Generate command: node scripts/generate_binding_code.js
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+use std::ffi::*;
+use crate::*;
+#[repr(C)]
+pub struct WheelEventInit {
+  pub delta_x: c_double,
+  pub delta_y: c_double,
+  pub delta_z: c_double,
+  pub delta_mode: c_double,
+}
@@ -2,11 +2,12 @@
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 
-use std::ffi::{c_char, c_double, c_void};
+use std::ffi::{c_char, c_double, c_void, CStr};
 use std::{ptr};
 use libc::c_uint;
 #[cfg(target_os = "windows")]
 use windows::*;
+use crate::error::WebFError;
 use crate::executing_context::ExecutingContext;
 use crate::memory_utils::safe_free_cpp_ptr;
 use crate::OpaquePtr;
@@ -21,6 +22,8 @@ pub struct ExceptionStateRustMethods {
     errmsg: *mut *mut c_char,
     strlen: *mut c_uint,
   ) -> c_void,
+  pub name: extern "C" fn(executing_context: *const OpaquePtr, shared_exception_state: *const OpaquePtr) -> *mut c_char,
+  pub code: extern "C" fn(shared_exception_state: *const OpaquePtr) -> i32,
 }
 
 pub struct ExceptionState {
@@ -63,6 +66,36 @@ impl ExceptionState {
       return message;
     }
   }
+
+  /// The name of the exception, such as `TypeError` or `QuotaExceededError`, `None` when there is no exception.
+  pub fn exception_name(&self, context: &ExecutingContext) -> Option<String> {
+    let name = unsafe {
+      ((*self.method_pointer).name)(context.ptr, self.ptr)
+    };
+    if name.is_null() {
+      return None;
+    }
+    let value = unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(name);
+    Some(value)
+  }
+
+  /// The legacy code of the exception when it's a `DOMException`, such as 22 for `QuotaExceededError`.
+  /// `None` when there is no exception, or the exception is not a `DOMException` with a code.
+  pub fn exception_code(&self) -> Option<u16> {
+    let code = unsafe {
+      ((*self.method_pointer).code)(self.ptr)
+    };
+    if code <= 0 {
+      return None;
+    }
+    u16::try_from(code).ok()
+  }
+
+  /// The exception as a `WebFError`, with the variant matching the name of the exception.
+  pub fn to_error(&self, context: &ExecutingContext) -> WebFError {
+    WebFError::from_exception_message(&self.stringify(context))
+  }
 }
 
 impl Drop for ExceptionState {
@@ -2,7 +2,12 @@
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::*;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
 use native_value::NativeValue;
 
 use crate::*;
@@ -25,11 +30,77 @@ pub struct ExecutingContextRustMethods {
   pub clear_interval: extern "C" fn(*const OpaquePtr, c_int, *const OpaquePtr),
   pub add_rust_future_task: extern "C" fn(*const OpaquePtr, *const WebFNativeFunctionContext, *const NativeLibraryMetaData, *const OpaquePtr) -> c_void,
   pub remove_rust_future_task: extern "C" fn(*const OpaquePtr, *const WebFNativeFunctionContext, *const NativeLibraryMetaData, *const OpaquePtr) -> c_void,
+  pub request_animation_frame: extern "C" fn(*const OpaquePtr, *const WebFNativeFunctionContext, *const OpaquePtr) -> c_uint,
+  pub cancel_animation_frame: extern "C" fn(*const OpaquePtr, c_uint, *const OpaquePtr),
+  pub create_module_event_target: extern "C" fn(*const OpaquePtr, *const c_char, *const c_char, *const OpaquePtr) -> RustValue<EventTargetRustMethods>,
+  pub remove_module_event_target: extern "C" fn(*const OpaquePtr, *const c_char, *const c_char),
+  pub get_message_event_data: extern "C" fn(*const OpaquePtr, *const OpaquePtr, *mut MessageEventData, *const OpaquePtr),
+  pub print: extern "C" fn(*const OpaquePtr, *const c_char, *const c_char),
+  pub create_mutation_observer: extern "C" fn(*const OpaquePtr, *const WebFNativeFunctionContext, *const OpaquePtr) -> RustValue<MutationObserverRustMethods>,
+  pub parse_from_string: extern "C" fn(*const OpaquePtr, *const c_char, i32, *const OpaquePtr) -> RustValue<DocumentRustMethods>,
+  pub queue_microtask: extern "C" fn(*const OpaquePtr, *const WebFNativeFunctionContext, *const OpaquePtr),
+  pub get_performance: extern "C" fn(*const OpaquePtr) -> RustValue<PerformanceRustMethods>,
+  pub get_script_value_ref_methods: extern "C" fn() -> *const ScriptValueRefRustMethods,
+  pub get_script_promise_ref_methods: extern "C" fn() -> *const ScriptPromiseRefRustMethods,
+  pub run_gc: extern "C" fn(*const OpaquePtr),
 }
 
 pub type TimeoutCallback = Box<dyn Fn()>;
 pub type IntervalCallback = Box<dyn Fn()>;
 pub type RunRustFutureTasksCallback = Box<dyn Fn()>;
+pub type AnimationFrameCallback = Box<dyn FnMut(f64)>;
+pub type IdleRequestCallback = Box<dyn FnOnce(IdleDeadline)>;
+pub type MicrotaskCallback = Box<dyn FnOnce()>;
+
+/// The handle of a timer registered by `set_timeout` or `set_interval`.
+///
+/// It wraps the id assigned by the C++ side and can be passed to `clear_timeout` or `clear_interval`
+/// to cancel the pending callback.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TimerId(pub i32);
+
+/// The handle of a callback registered by `request_animation_frame`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FrameCallbackId(pub u32);
+
+/// The handle of a callback registered by `request_idle_callback`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IdleCallbackId(pub u32);
+
+/// Behavior as same as `IdleDeadline` in JavaScript.
+#[derive(Debug, Copy, Clone)]
+pub struct IdleDeadline {
+  deadline: Instant,
+  did_timeout: bool,
+}
+
+impl IdleDeadline {
+  /// The milliseconds left in the current idle period, 0 when the period has ended.
+  pub fn time_remaining(&self) -> f64 {
+    self.deadline.saturating_duration_since(Instant::now()).as_secs_f64() * 1000.0
+  }
+
+  /// Whether the callback is running because its timeout expired rather than in an idle period.
+  pub fn did_timeout(&self) -> bool {
+    self.did_timeout
+  }
+}
+
+// The engine doesn't report idle time, so an idle period is taken as the rest of the frame after rendering:
+// it starts once the tasks queued by an animation frame have run, and ends one frame after the animation frame.
+const IDLE_PERIOD: Duration = Duration::from_millis(16);
+
+static NEXT_IDLE_CALLBACK_ID: AtomicU32 = AtomicU32::new(1);
+
+struct IdleRequest {
+  callback: IdleRequestCallback,
+  frame_id: Option<FrameCallbackId>,
+  timer_id: Option<TimerId>,
+}
+
+thread_local! {
+  static IDLE_REQUESTS: RefCell<HashMap<u32, IdleRequest>> = RefCell::new(HashMap::new());
+}
 
 #[repr(C)]
 pub struct NativeLibraryMetaData {
@@ -91,10 +162,38 @@ impl ExecutingContext {
     return Document::initialize::<DocumentRustMethods>(result.value, self, result.method_pointer, result.status);
   }
 
+  /// Behavior as same as `structuredClone()` in JavaScript.
+  /// A `ScriptValue` owns all its members, so it never contains cycles or shares references with JavaScript,
+  /// and the clone is a deep copy. Every `ScriptValue` can be cloned, numbers which are not finite are kept as they are.
+  pub fn structured_clone(&self, value: &ScriptValue) -> ScriptValue {
+    value.clone()
+  }
+
+  /// Runs the garbage collector of JavaScript, the same as the `__gc__()` global in JavaScript.
+  /// Objects which are not referenced from JavaScript, the DOM or a living Rust value are freed,
+  /// then `WeakEventTarget::upgrade()` returns None for the targets among them.
+  pub fn run_gc(&self) {
+    unsafe {
+      ((*self.method_pointer).run_gc)(self.ptr);
+    }
+  }
+
+  /// Obtain the performance instance from ExecutingContext.
+  pub fn performance(&self) -> Performance {
+    let result = unsafe {
+      ((*self.method_pointer).get_performance)(self.ptr)
+    };
+    Performance::initialize(result.value, self, result.method_pointer, result.status)
+  }
+
   pub fn navigator(&self) -> Navigator {
     Navigator::initialize(self)
   }
 
+  pub fn console(&self) -> Console {
+    Console::initialize(self)
+  }
+
   pub fn async_storage(&self) -> AsyncStorage {
     AsyncStorage::initialize(self)
   }
@@ -107,6 +206,41 @@ impl ExecutingContext {
     Storage::initialize(self, "SessionStorage")
   }
 
+  /// Spawns a future onto the engine's event loop.
+  ///
+  /// The future runs on the JS thread of this context, the same thread that invokes event listeners and
+  /// timer callbacks, which is why it doesn't need to be `Send`. It is polled whenever the engine runs
+  /// Rust future tasks: after timers, module callbacks (such as fetch responses), animation frames and
+  /// microtask checkpoints.
+  pub fn spawn<F>(&self, future: F)
+  where
+    F: Future<Output = ()> + 'static,
+  {
+    webf_future::spawn(self.clone(), future);
+  }
+
+  /// Starts a network request. The returned future settles when the engine delivers the response,
+  /// and dropping it before then aborts the request.
+  pub fn fetch(&self, url: &str, init: FetchInit, exception_state: &ExceptionState) -> WebFFuture<Response> {
+    fetch::fetch(self, url, init, exception_state)
+  }
+
+  /// Behavior as same as `new WebSocket(url, protocols)` in JavaScript.
+  /// Returns an error if the scheme of `url` is not `ws` or `wss`.
+  pub fn web_socket(&self, url: &str, protocols: &[&str], exception_state: &ExceptionState) -> Result<WebSocket, WebFError> {
+    WebSocket::connect(self, url, protocols, exception_state)
+  }
+
+  /// Creates a handle of the context which could be cloned and moved into callbacks, see `ContextHandle`.
+  pub fn handle(&self) -> ContextHandle {
+    ContextHandle::new(self.clone())
+  }
+
+  /// Whether the page of the context is unloaded, the modules can't be invoked anymore once it is.
+  pub fn is_disposed(&self) -> bool {
+    !self.status.is_null() && unsafe { (*self.status).disposed }
+  }
+
   pub fn create_exception_state(&self) -> ExceptionState {
     let result = unsafe {
       ((*self.method_pointer).create_exception_state)()
@@ -120,7 +254,10 @@ impl ExecutingContext {
     }
   }
 
-  pub fn webf_invoke_module(&self, module_name: &str, method: &str, exception_state: &ExceptionState) -> Result<NativeValue, String> {
+  pub fn webf_invoke_module(&self, module_name: &str, method: &str, exception_state: &ExceptionState) -> Result<NativeValue, WebFError> {
+    if self.is_disposed() {
+      return Err(WebFError::Disposed);
+    }
     let module_name = CString::new(module_name).unwrap();
     let method = CString::new(method).unwrap();
     let result = unsafe {
@@ -128,13 +265,16 @@ impl ExecutingContext {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
     Ok(result)
   }
 
-  pub fn webf_invoke_module_with_params(&self, module_name: &str, method: &str, params: &NativeValue, exception_state: &ExceptionState) -> Result<NativeValue, String> {
+  pub fn webf_invoke_module_with_params(&self, module_name: &str, method: &str, params: &NativeValue, exception_state: &ExceptionState) -> Result<NativeValue, WebFError> {
+    if self.is_disposed() {
+      return Err(WebFError::Disposed);
+    }
     let module_name = CString::new(module_name).unwrap();
     let method = CString::new(method).unwrap();
     let result = unsafe {
@@ -142,13 +282,16 @@ impl ExecutingContext {
     };
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
     Ok(result)
   }
 
-  pub fn webf_invoke_module_with_params_and_callback(&self, module_name: &str, method: &str, params: &NativeValue, callback: WebFNativeFunction, exception_state: &ExceptionState) -> Result<NativeValue, String> {
+  pub fn webf_invoke_module_with_params_and_callback(&self, module_name: &str, method: &str, params: &NativeValue, callback: WebFNativeFunction, exception_state: &ExceptionState) -> Result<NativeValue, WebFError> {
+    if self.is_disposed() {
+      return Err(WebFError::Disposed);
+    }
     let module_name = CString::new(module_name).unwrap();
     let method = CString::new(method).unwrap();
 
@@ -172,7 +315,7 @@ impl ExecutingContext {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
     Ok(result)
@@ -184,11 +327,11 @@ impl ExecutingContext {
     }
   }
 
-  pub fn set_timeout_with_callback(&self, callback: TimeoutCallback, exception_state: &ExceptionState) -> Result<i32, String> {
+  pub fn set_timeout_with_callback(&self, callback: TimeoutCallback, exception_state: &ExceptionState) -> Result<TimerId, WebFError> {
     self.set_timeout_with_callback_and_timeout(callback, 0, exception_state)
   }
 
-  pub fn set_timeout_with_callback_and_timeout(&self, callback: TimeoutCallback, timeout: i32, exception_state: &ExceptionState) -> Result<i32, String> {
+  pub fn set_timeout_with_callback_and_timeout(&self, callback: TimeoutCallback, timeout: i32, exception_state: &ExceptionState) -> Result<TimerId, WebFError> {
     let general_callback: WebFNativeFunction = Box::new(move |argc, argv| {
       if argc != 0 {
         println!("Invalid argument count for timeout callback");
@@ -218,17 +361,17 @@ impl ExecutingContext {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
-    Ok(result)
+    Ok(TimerId(result))
   }
 
-  pub fn set_interval_with_callback(&self, callback: IntervalCallback, exception_state: &ExceptionState) -> Result<i32, String> {
+  pub fn set_interval_with_callback(&self, callback: IntervalCallback, exception_state: &ExceptionState) -> Result<TimerId, WebFError> {
     self.set_interval_with_callback_and_timeout(callback, 0, exception_state)
   }
 
-  pub fn set_interval_with_callback_and_timeout(&self, callback: IntervalCallback, interval: i32, exception_state: &ExceptionState) -> Result<i32, String> {
+  pub fn set_interval_with_callback_and_timeout(&self, callback: IntervalCallback, interval: i32, exception_state: &ExceptionState) -> Result<TimerId, WebFError> {
     let general_callback: WebFNativeFunction = Box::new(move |argc, argv| {
       if argc != 0 {
         println!("Invalid argument count for interval callback");
@@ -258,25 +401,199 @@ impl ExecutingContext {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
-    Ok(result)
+    Ok(TimerId(result))
+  }
+
+  /// Cancels a timeout previously established by calling `set_timeout_with_callback`.
+  pub fn clear_timeout(&self, timeout_id: TimerId, exception_state: &ExceptionState) {
+    unsafe {
+      ((*self.method_pointer).clear_timeout)(self.ptr, timeout_id.0, exception_state.ptr)
+    }
   }
 
-  pub fn clear_timeout(&self, timeout_id: i32, exception_state: &ExceptionState) {
+  /// Cancels a repeating action which was previously established by calling `set_interval_with_callback`.
+  pub fn clear_interval(&self, interval_id: TimerId, exception_state: &ExceptionState) {
+    unsafe {
+      ((*self.method_pointer).clear_interval)(self.ptr, interval_id.0, exception_state.ptr)
+    }
+  }
+
+  /// Behavior as same as `queueMicrotask()` in JavaScript.
+  ///
+  /// The callback is queued to the microtask queue shared with JavaScript promises,
+  /// so it runs once the current task finishes and before the next timer or animation frame.
+  pub fn queue_microtask(&self, callback: MicrotaskCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let callback = RefCell::new(Some(callback));
+    let general_callback: WebFNativeFunction = Box::new(move |argc, _| {
+      if argc != 0 {
+        println!("Invalid argument count for microtask callback");
+        return NativeValue::new_null();
+      }
+      if let Some(callback) = callback.borrow_mut().take() {
+        callback();
+      }
+      NativeValue::new_null()
+    });
+
+    let callback_data = Box::new(WebFNativeFunctionContextData {
+      func: general_callback,
+    });
+    let callback_context_data_ptr = Box::into_raw(callback_data);
+    let callback_context = Box::new(WebFNativeFunctionContext {
+      callback: invoke_webf_native_function,
+      free_ptr: release_webf_native_function,
+      ptr: callback_context_data_ptr,
+    });
+    let callback_context_ptr = Box::into_raw(callback_context);
+
     unsafe {
-      ((*self.method_pointer).clear_timeout)(self.ptr, timeout_id, exception_state.ptr)
+      ((*self.method_pointer).queue_microtask)(self.ptr, callback_context_ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      unsafe {
+        let _ = Box::from_raw(callback_context_ptr);
+        let _ = Box::from_raw(callback_context_data_ptr);
+      }
+      return Err(exception_state.to_error(self));
     }
+
+    Ok(())
   }
 
-  pub fn clear_interval(&self, interval_id: i32, exception_state: &ExceptionState) {
+  /// Tells the engine to call the callback before the next repaint, with the high resolution timestamp of the frame.
+  ///
+  /// The callback only fires once, call `request_animation_frame` again inside the callback to animate continuously.
+  /// The boxed closure is owned by the engine until it fires or got cancelled by `cancel_animation_frame`.
+  pub fn request_animation_frame(&self, callback: AnimationFrameCallback, exception_state: &ExceptionState) -> Result<FrameCallbackId, WebFError> {
+    let callback = RefCell::new(callback);
+    let general_callback: WebFNativeFunction = Box::new(move |argc, argv| {
+      if argc != 1 {
+        println!("Invalid argument count for animation frame callback");
+        return NativeValue::new_null();
+      }
+      let high_res_time_stamp = unsafe { (*argv).clone() }.to_float64();
+      (callback.borrow_mut())(high_res_time_stamp);
+      NativeValue::new_null()
+    });
+
+    let callback_data = Box::new(WebFNativeFunctionContextData {
+      func: general_callback,
+    });
+    let callback_context_data_ptr = Box::into_raw(callback_data);
+    let callback_context = Box::new(WebFNativeFunctionContext {
+      callback: invoke_webf_native_function,
+      free_ptr: release_webf_native_function,
+      ptr: callback_context_data_ptr,
+    });
+    let callback_context_ptr = Box::into_raw(callback_context);
+
+    let result = unsafe {
+      ((*self.method_pointer).request_animation_frame)(self.ptr, callback_context_ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      unsafe {
+        let _ = Box::from_raw(callback_context_ptr);
+        let _ = Box::from_raw(callback_context_data_ptr);
+      }
+      return Err(exception_state.to_error(self));
+    }
+
+    Ok(FrameCallbackId(result))
+  }
+
+  /// Cancels an animation frame request previously scheduled through a call to `request_animation_frame`.
+  pub fn cancel_animation_frame(&self, request_id: FrameCallbackId, exception_state: &ExceptionState) {
     unsafe {
-      ((*self.method_pointer).clear_interval)(self.ptr, interval_id, exception_state.ptr)
+      ((*self.method_pointer).cancel_animation_frame)(self.ptr, request_id.0, exception_state.ptr)
     }
   }
 
-  pub fn add_rust_future_task(&self, callback: RunRustFutureTasksCallback, exception_state: &ExceptionState) -> Result<(), String> {
+  /// Behavior as same as `requestIdleCallback()` in JavaScript.
+  ///
+  /// The callback fires once in an idle period after the next frame, it's deferred to later frames while the frames are busy.
+  /// When `timeout` is a positive number of milliseconds, the callback fires once the timeout expires even if no idle period came,
+  /// with `IdleDeadline::did_timeout()` returning true.
+  pub fn request_idle_callback(&self, callback: IdleRequestCallback, timeout: Option<u32>, exception_state: &ExceptionState) -> Result<IdleCallbackId, WebFError> {
+    let id = NEXT_IDLE_CALLBACK_ID.fetch_add(1, Ordering::Relaxed);
+    IDLE_REQUESTS.with(|requests| requests.borrow_mut().insert(id, IdleRequest {
+      callback,
+      frame_id: None,
+      timer_id: None,
+    }));
+
+    if let Err(err) = self.request_idle_period(id, exception_state) {
+      IDLE_REQUESTS.with(|requests| requests.borrow_mut().remove(&id));
+      return Err(err);
+    }
+
+    if let Some(timeout) = timeout.filter(|timeout| *timeout > 0) {
+      let context = self.clone();
+      let timer_id = self.set_timeout_with_callback_and_timeout(Box::new(move || {
+        run_idle_request(&context, id, Instant::now(), true);
+      }), timeout as i32, exception_state);
+      match timer_id {
+        Ok(timer_id) => IDLE_REQUESTS.with(|requests| {
+          if let Some(request) = requests.borrow_mut().get_mut(&id) {
+            request.timer_id = Some(timer_id);
+          }
+        }),
+        Err(err) => {
+          self.cancel_idle_callback(IdleCallbackId(id), exception_state);
+          return Err(err);
+        }
+      }
+    }
+
+    Ok(IdleCallbackId(id))
+  }
+
+  /// Behavior as same as `cancelIdleCallback()` in JavaScript, cancels a callback registered by `request_idle_callback`.
+  pub fn cancel_idle_callback(&self, id: IdleCallbackId, exception_state: &ExceptionState) {
+    let request = IDLE_REQUESTS.with(|requests| requests.borrow_mut().remove(&id.0));
+    if let Some(request) = request {
+      if let Some(frame_id) = request.frame_id {
+        self.cancel_animation_frame(frame_id, exception_state);
+      }
+      if let Some(timer_id) = request.timer_id {
+        self.clear_timeout(timer_id, exception_state);
+      }
+    }
+  }
+
+  // Waits for the next frame, and runs the idle request after the tasks queued by the frame,
+  // or waits for another frame when no time is left in the frame.
+  fn request_idle_period(&self, id: u32, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let context = self.clone();
+    let frame_id = self.request_animation_frame(Box::new(move |_| {
+      let frame_start = Instant::now();
+      let context_in_timer = context.clone();
+      let exception_state = context.create_exception_state();
+      let _ = context.set_timeout_with_callback(Box::new(move || {
+        let deadline = frame_start + IDLE_PERIOD;
+        if deadline > Instant::now() {
+          run_idle_request(&context_in_timer, id, deadline, false);
+          return;
+        }
+        let exception_state = context_in_timer.create_exception_state();
+        if context_in_timer.request_idle_period(id, &exception_state).is_err() {
+          run_idle_request(&context_in_timer, id, Instant::now(), false);
+        }
+      }), &exception_state);
+    }), exception_state)?;
+    IDLE_REQUESTS.with(|requests| {
+      if let Some(request) = requests.borrow_mut().get_mut(&id) {
+        request.frame_id = Some(frame_id);
+      }
+    });
+    Ok(())
+  }
+
+  pub fn add_rust_future_task(&self, callback: RunRustFutureTasksCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
     let general_callback: WebFNativeFunction = Box::new(move |argc, argv| {
       if argc != 0 {
         println!("Invalid argument count for run rust future tasks callback");
@@ -306,14 +623,14 @@ impl ExecutingContext {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
     Ok(())
 
   }
 
-  pub fn remove_rust_future_task(&self, callback: RunRustFutureTasksCallback, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn remove_rust_future_task(&self, callback: RunRustFutureTasksCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
     let general_callback: WebFNativeFunction = Box::new(move |argc, argv| {
       if argc != 0 {
         println!("Invalid argument count for run rust future tasks callback");
@@ -343,7 +660,7 @@ impl ExecutingContext {
         let _ = Box::from_raw(callback_context_ptr);
         let _ = Box::from_raw(callback_context_data_ptr);
       }
-      return Err(exception_state.stringify(self));
+      return Err(exception_state.to_error(self));
     }
 
     Ok(())
@@ -373,3 +690,19 @@ impl Clone for ExecutingContext {
     }
   }
 }
+
+// Runs the idle request if it's still pending, and cancels the other way it's waiting for.
+fn run_idle_request(context: &ExecutingContext, id: u32, deadline: Instant, did_timeout: bool) {
+  let Some(request) = IDLE_REQUESTS.with(|requests| requests.borrow_mut().remove(&id)) else {
+    return;
+  };
+  let exception_state = context.create_exception_state();
+  if did_timeout {
+    if let Some(frame_id) = request.frame_id {
+      context.cancel_animation_frame(frame_id, &exception_state);
+    }
+  } else if let Some(timer_id) = request.timer_id {
+    context.clear_timeout(timer_id, &exception_state);
+  }
+  (request.callback)(IdleDeadline { deadline, did_timeout });
+}
@@ -0,0 +1,130 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ops::Range;
+use std::rc::Rc;
+use crate::*;
+
+/// Behavior as same as `Blob` in JavaScript, the bytes are held by the Rust side.
+///
+/// A blob is immutable, so the clones and the slices of a blob share the same bytes.
+#[derive(Debug, Clone)]
+pub struct Blob {
+  context: *const ExecutingContext,
+  bytes: Rc<[u8]>,
+  range: Range<usize>,
+  type_: String,
+}
+
+impl Blob {
+  /// Behavior as same as `new Blob([bytes], { type })` in JavaScript.
+  /// The type is lowercased, and it is empty when it contains characters outside of the printable ASCII range.
+  pub fn from_bytes(context: &ExecutingContext, bytes: &[u8], type_: &str) -> Blob {
+    Blob {
+      context,
+      bytes: Rc::from(bytes),
+      range: 0..bytes.len(),
+      type_: normalize_type(type_),
+    }
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `Blob.size` in JavaScript, in bytes.
+  pub fn size(&self) -> usize {
+    self.range.len()
+  }
+
+  /// Behavior as same as `Blob.type` in JavaScript.
+  pub fn type_(&self) -> &str {
+    &self.type_
+  }
+
+  /// Behavior as same as `Blob.slice()` in JavaScript.
+  ///
+  /// Negative offsets are counted from the end, and the offsets are clamped to the size of the blob.
+  /// `start` defaults to 0 and `end` to the size, and the type of the new blob is empty when `content_type` is not set.
+  pub fn slice(&self, start: Option<i64>, end: Option<i64>, content_type: Option<&str>) -> Blob {
+    let size = self.size() as i64;
+    let resolve = |offset: i64| if offset < 0 { (size + offset).max(0) } else { offset.min(size) } as usize;
+    let start = start.map_or(0, resolve);
+    let end = end.map_or(size as usize, resolve).max(start);
+    Blob {
+      context: self.context,
+      bytes: self.bytes.clone(),
+      range: self.range.start + start..self.range.start + end,
+      type_: normalize_type(content_type.unwrap_or("")),
+    }
+  }
+
+  /// Behavior as same as `Blob.text()` in JavaScript, the bytes are decoded as UTF-8 with invalid sequences replaced.
+  pub async fn text(&self) -> String {
+    String::from_utf8_lossy(self.bytes()).into_owned()
+  }
+
+  /// Behavior as same as `Blob.arrayBuffer()` in JavaScript.
+  pub async fn array_buffer(&self) -> Vec<u8> {
+    self.bytes().to_vec()
+  }
+
+  pub(crate) fn bytes(&self) -> &[u8] {
+    &self.bytes[self.range.clone()]
+  }
+}
+
+/// Behavior as same as `File` in JavaScript, a `Blob` with a name and a modification time.
+#[derive(Debug, Clone)]
+pub struct File {
+  blob: Blob,
+  name: String,
+  last_modified: i64,
+}
+
+impl File {
+  /// Behavior as same as `new File([bytes], name, { type, lastModified })` in JavaScript.
+  /// `last_modified` is in milliseconds since the Unix epoch, and it defaults to the current time.
+  pub fn from_bytes(context: &ExecutingContext, bytes: &[u8], name: &str, type_: &str, last_modified: Option<i64>) -> File {
+    let last_modified = last_modified.unwrap_or_else(|| {
+      std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as i64)
+    });
+    File {
+      blob: Blob::from_bytes(context, bytes, type_),
+      name: name.to_string(),
+      last_modified,
+    }
+  }
+
+  /// Behavior as same as `File.name` in JavaScript.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Behavior as same as `File.lastModified` in JavaScript, in milliseconds since the Unix epoch.
+  pub fn last_modified(&self) -> i64 {
+    self.last_modified
+  }
+
+  pub fn as_blob(&self) -> &Blob {
+    &self.blob
+  }
+}
+
+impl From<File> for Blob {
+  fn from(file: File) -> Self {
+    file.blob
+  }
+}
+
+fn normalize_type(type_: &str) -> String {
+  if type_.bytes().all(|byte| (0x20..=0x7E).contains(&byte)) {
+    type_.to_ascii_lowercase()
+  } else {
+    String::new()
+  }
+}
@@ -0,0 +1,6 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+pub mod blob;
+
+pub use blob::*;
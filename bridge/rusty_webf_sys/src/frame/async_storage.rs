@@ -4,8 +4,8 @@
 
 use crate::*;
 
-pub type GetItemCallback = Box<dyn Fn(Result<Option<String>, String>)>;
-pub type SetItemCallback = Box<dyn Fn(Result<Option<String>, String>)>;
+pub type GetItemCallback = Box<dyn Fn(Result<Option<String>, WebFError>)>;
+pub type SetItemCallback = Box<dyn Fn(Result<Option<String>, WebFError>)>;
 
 pub struct AsyncStorage {
   context: *const ExecutingContext,
@@ -31,7 +31,7 @@ impl AsyncStorage {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -62,7 +62,7 @@ impl AsyncStorage {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -86,7 +86,7 @@ impl AsyncStorage {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -110,7 +110,7 @@ impl AsyncStorage {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -134,7 +134,7 @@ impl AsyncStorage {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
@@ -158,7 +158,7 @@ impl AsyncStorage {
       if argc == 1 {
         let error_string = unsafe { (*argv).clone() };
         let error_string = error_string.to_string();
-        future_in_callback.set_result(Err(error_string));
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
         return NativeValue::new_null();
       }
       if argc == 2 {
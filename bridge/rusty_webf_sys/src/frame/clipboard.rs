@@ -0,0 +1,83 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use crate::*;
+
+/// Behavior as same as `navigator.clipboard` in JavaScript, only plain text is supported.
+pub struct Clipboard {
+  context: *const ExecutingContext,
+}
+
+impl Clipboard {
+  pub fn initialize(context: *const ExecutingContext) -> Clipboard {
+    Clipboard {
+      context
+    }
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `navigator.clipboard.readText()` in JavaScript.
+  /// Resolves to an empty string when the clipboard holds no text,
+  /// and to `Err(WebFError::DomException)` named `NotAllowedError` when the platform denies the access.
+  pub fn read_text(&self, exception_state: &ExceptionState) -> WebFFuture<String> {
+    let (future, resolver) = WebFFuture::<String>::new();
+    let callback: WebFNativeFunction = Box::new(move |argc, argv| {
+      if argc == 1 {
+        let error_string = unsafe { (*argv).clone() };
+        resolver.resolve(Err(to_clipboard_error(error_string.to_string())));
+        return NativeValue::new_null();
+      }
+      if argc == 2 {
+        let text = unsafe { (*argv.wrapping_add(1)).clone() };
+        resolver.resolve(Ok(if text.is_string() { text.to_string() } else { String::new() }));
+        return NativeValue::new_null();
+      }
+      println!("Invalid argument count for clipboard callback");
+      NativeValue::new_null()
+    });
+    self.invoke("readText", &NativeValue::new_null(), callback, future, exception_state)
+  }
+
+  /// Behavior as same as `navigator.clipboard.writeText()` in JavaScript.
+  /// Resolves to `Err(WebFError::DomException)` named `NotAllowedError` when the platform denies the access.
+  pub fn write_text(&self, text: &str, exception_state: &ExceptionState) -> WebFFuture<()> {
+    let (future, resolver) = WebFFuture::<()>::new();
+    let callback: WebFNativeFunction = Box::new(move |argc, argv| {
+      if argc == 1 {
+        let error_string = unsafe { (*argv).clone() };
+        resolver.resolve(Err(to_clipboard_error(error_string.to_string())));
+        return NativeValue::new_null();
+      }
+      if argc == 2 {
+        resolver.resolve(Ok(()));
+        return NativeValue::new_null();
+      }
+      println!("Invalid argument count for clipboard callback");
+      NativeValue::new_null()
+    });
+    self.invoke("writeText", &NativeValue::new_string(text), callback, future, exception_state)
+  }
+
+  fn invoke<T>(&self, method: &str, params: &NativeValue, callback: WebFNativeFunction, future: WebFFuture<T>, exception_state: &ExceptionState) -> WebFFuture<T> {
+    if let Err(err) = self.context().webf_invoke_module_with_params_and_callback("Clipboard", method, params, callback, exception_state) {
+      let (failed_future, failed_resolver) = WebFFuture::<T>::new();
+      failed_resolver.resolve(Err(err));
+      return failed_future;
+    }
+    future
+  }
+}
+
+// The Clipboard module replies with the error raised by the platform followed by the Dart stack,
+// which is a denied access in browsers' terms.
+fn to_clipboard_error(error: String) -> WebFError {
+  WebFError::DomException {
+    name: "NotAllowedError".to_string(),
+    message: error.lines().next().unwrap_or_default().to_string(),
+  }
+}
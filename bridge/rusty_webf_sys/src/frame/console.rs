@@ -0,0 +1,75 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+/// The console of the engine, messages are shown in the same place as the ones logged by JavaScript.
+pub struct Console {
+  context: *const ExecutingContext,
+}
+
+impl Console {
+  pub fn initialize(context: *const ExecutingContext) -> Console {
+    Console {
+      context
+    }
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `console.log()` in JavaScript.
+  pub fn log(&self, values: &[ScriptValue]) -> Result<(), WebFError> {
+    self.print(values, "log")
+  }
+
+  /// Behavior as same as `console.info()` in JavaScript.
+  pub fn info(&self, values: &[ScriptValue]) -> Result<(), WebFError> {
+    self.print(values, "info")
+  }
+
+  /// Behavior as same as `console.warn()` in JavaScript.
+  pub fn warn(&self, values: &[ScriptValue]) -> Result<(), WebFError> {
+    self.print(values, "warn")
+  }
+
+  /// Behavior as same as `console.error()` in JavaScript.
+  pub fn error(&self, values: &[ScriptValue]) -> Result<(), WebFError> {
+    self.print(values, "error")
+  }
+
+  /// Behavior as same as `console.debug()` in JavaScript.
+  pub fn debug(&self, values: &[ScriptValue]) -> Result<(), WebFError> {
+    self.print(values, "debug")
+  }
+
+  /// Returns the message printed for `values`: strings as they are and other values as JSON, separated by spaces.
+  pub fn format(values: &[ScriptValue]) -> String {
+    values.iter()
+      .map(|value| match value {
+        ScriptValue::String(value) => value.clone(),
+        value => value.to_json(),
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+
+  // Returns `Err(WebFError::Disposed)` once the context is disposed, there is no console left to print to.
+  fn print(&self, values: &[ScriptValue], level: &str) -> Result<(), WebFError> {
+    let context = self.context();
+    if context.is_disposed() {
+      return Err(WebFError::Disposed);
+    }
+    // Interior NUL bytes would truncate the message on the C++ side.
+    let message_c_string = CString::new(Console::format(values).replace('\0', "")).unwrap();
+    let level_c_string = CString::new(level).unwrap();
+    unsafe {
+      (context.method_pointer().print)(context.ptr, message_c_string.as_ptr(), level_c_string.as_ptr());
+    }
+    Ok(())
+  }
+}
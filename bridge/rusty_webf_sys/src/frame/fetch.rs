@@ -0,0 +1,270 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use crate::*;
+
+// Identifies the requests in flight in the Fetch module, so that a dropped future only aborts its own request.
+static NEXT_FETCH_REQUEST_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Options of a fetch request.
+#[derive(Debug, Clone, Default)]
+pub struct FetchInit {
+  /// The request method, `GET` when not set.
+  pub method: Option<String>,
+  pub headers: Headers,
+  pub body: Option<FetchBody>,
+}
+
+/// The body of a fetch request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchBody {
+  /// Sent UTF-8 encoded.
+  Text(String),
+  Bytes(Vec<u8>),
+}
+
+impl From<String> for FetchBody {
+  fn from(value: String) -> Self {
+    FetchBody::Text(value)
+  }
+}
+
+impl From<&str> for FetchBody {
+  fn from(value: &str) -> Self {
+    FetchBody::Text(value.to_string())
+  }
+}
+
+impl From<Vec<u8>> for FetchBody {
+  fn from(value: Vec<u8>) -> Self {
+    FetchBody::Bytes(value)
+  }
+}
+
+impl FetchInit {
+  /// Sets the body to `value` serialized as JSON.
+  /// Adds a `Content-Type: application/json` header unless a content type is already set.
+  pub fn set_json_body(&mut self, value: &ScriptValue) {
+    self.body = Some(FetchBody::Text(value.to_json()));
+    if !self.headers.has("content-type") {
+      self.headers.append("Content-Type", "application/json");
+    }
+  }
+
+  /// Sets the body to `form_data` encoded as `multipart/form-data`.
+  /// The `Content-Type` header is replaced, since it has to carry the boundary of the encoded body.
+  pub fn set_form_data_body(&mut self, form_data: &FormData) {
+    let (boundary, body) = form_data.encode_multipart();
+    self.body = Some(FetchBody::Bytes(body));
+    self.headers.set("Content-Type", &format!("multipart/form-data; boundary={}", boundary));
+  }
+
+  fn to_json(&self, request_id: u32) -> String {
+    let mut members = vec![format!("\"requestId\":{}", request_id)];
+    if let Some(method) = &self.method {
+      members.push(format!("\"method\":{}", to_json_string_literal(method)));
+    }
+    // The combined values are sent, since the names of a JSON object are unique.
+    let headers = self.headers.iter()
+      .map(|(name, value)| format!("{}:{}", to_json_string_literal(&name), to_json_string_literal(&value)))
+      .collect::<Vec<_>>();
+    members.push(format!("\"headers\":{{{}}}", headers.join(",")));
+    // Bytes are sent beside the options, see `fetch()`.
+    if let Some(FetchBody::Text(body)) = &self.body {
+      members.push(format!("\"body\":{}", to_json_string_literal(body)));
+    }
+    format!("{{{}}}", members.join(","))
+  }
+}
+
+/// Behavior as same as `Headers` in JavaScript, used by both `FetchInit` and `Response`.
+///
+/// Names are compared case-insensitively, and the values of the same name are combined with `, ` when read.
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+  entries: Vec<(String, String)>,
+}
+
+impl Headers {
+  pub fn new() -> Headers {
+    Headers::default()
+  }
+
+  /// Behavior as same as `Headers.append()` in JavaScript, the value is added after the existing values of the name.
+  pub fn append(&mut self, name: &str, value: &str) {
+    self.entries.push((name.to_string(), normalize_header_value(value)));
+  }
+
+  /// Behavior as same as `Headers.set()` in JavaScript, the existing values of the name are replaced.
+  pub fn set(&mut self, name: &str, value: &str) {
+    self.delete(name);
+    self.append(name, value);
+  }
+
+  /// Behavior as same as `Headers.get()` in JavaScript.
+  /// Returns all the values of the name combined with `, `, or `None` when the name is not set.
+  pub fn get(&self, name: &str) -> Option<String> {
+    let values = self.entries.iter()
+      .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+      .map(|(_, value)| value.as_str())
+      .collect::<Vec<_>>();
+    if values.is_empty() {
+      return None;
+    }
+    Some(values.join(", "))
+  }
+
+  /// Behavior as same as `Headers.has()` in JavaScript.
+  pub fn has(&self, name: &str) -> bool {
+    self.entries.iter().any(|(key, _)| key.eq_ignore_ascii_case(name))
+  }
+
+  /// Behavior as same as `Headers.delete()` in JavaScript.
+  pub fn delete(&mut self, name: &str) {
+    self.entries.retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+  }
+
+  /// Behavior as same as iterating `Headers` in JavaScript.
+  /// The pairs are sorted by the lowercased names, with the values of the same name combined,
+  /// except for `set-cookie` whose values are returned one by one.
+  pub fn iter(&self) -> std::vec::IntoIter<(String, String)> {
+    let mut names = self.entries.iter().map(|(name, _)| name.to_ascii_lowercase()).collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+
+    let mut pairs = Vec::new();
+    for name in names {
+      if name == "set-cookie" {
+        for (_, value) in self.entries.iter().filter(|(key, _)| key.eq_ignore_ascii_case(&name)) {
+          pairs.push((name.clone(), value.clone()));
+        }
+      } else {
+        let value = self.get(&name).unwrap_or_default();
+        pairs.push((name, value));
+      }
+    }
+    pairs.into_iter()
+  }
+}
+
+impl IntoIterator for &Headers {
+  type Item = (String, String);
+  type IntoIter = std::vec::IntoIter<(String, String)>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+// Leading and trailing HTTP whitespace is removed from header values.
+fn normalize_header_value(value: &str) -> String {
+  value.trim_matches(|c| c == ' ' || c == '\t' || c == '\r' || c == '\n').to_string()
+}
+
+pub struct Response {
+  status: u16,
+  headers: Headers,
+  body: Vec<u8>,
+}
+
+impl Response {
+  pub fn status(&self) -> u16 {
+    self.status
+  }
+
+  /// Whether the status is in the range 200-299.
+  pub fn ok(&self) -> bool {
+    (200..300).contains(&self.status)
+  }
+
+  pub fn headers(&self) -> &Headers {
+    &self.headers
+  }
+
+  pub async fn text(&self) -> Result<String, WebFError> {
+    String::from_utf8(self.body.clone()).map_err(|err| WebFError::TypeError(err.to_string()))
+  }
+
+  pub async fn json(&self) -> Result<JsonValue, WebFError> {
+    let text = self.text().await?;
+    JsonValue::parse(&text)
+  }
+
+  pub async fn array_buffer(&self) -> Result<Vec<u8>, WebFError> {
+    Ok(self.body.clone())
+  }
+}
+
+pub(crate) fn fetch(context: &ExecutingContext, url: &str, init: FetchInit, exception_state: &ExceptionState) -> WebFFuture<Response> {
+  start_fetch(context, url, init, exception_state).0
+}
+
+// Also returns the id of the request in the Fetch module, which is passed to `abort_fetch`.
+pub(crate) fn start_fetch(context: &ExecutingContext, url: &str, init: FetchInit, exception_state: &ExceptionState) -> (WebFFuture<Response>, u32) {
+  let (future, resolver) = WebFFuture::<Response>::new();
+  let request_id = NEXT_FETCH_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+  let params = match &init.body {
+    Some(FetchBody::Bytes(bytes)) => NativeValue::new_list(vec![
+      NativeValue::new_json(&init.to_json(request_id)),
+      NativeValue::new_u8_bytes(bytes),
+    ]),
+    _ => NativeValue::new_json(&init.to_json(request_id)),
+  };
+  let callback: WebFNativeFunction = Box::new(move |argc, argv| {
+    if argc == 1 {
+      let error_string = unsafe { (*argv).clone() };
+      resolver.resolve(Err(WebFError::NetworkError(error_string.to_string())));
+      return NativeValue::new_null();
+    }
+    if argc == 2 {
+      let data = unsafe { (*argv.wrapping_add(1)).clone() };
+      resolver.resolve(to_response(&data));
+      return NativeValue::new_null();
+    }
+    println!("Invalid argument count for fetch callback");
+    NativeValue::new_null()
+  });
+
+  if let Err(err) = context.webf_invoke_module_with_params_and_callback("Fetch", url, &params, callback, exception_state) {
+    let (failed_future, failed_resolver) = WebFFuture::<Response>::new();
+    failed_resolver.resolve(Err(err));
+    return (failed_future, request_id);
+  }
+
+  let context_for_cancel = context.clone();
+  future.set_cancel_handler(Box::new(move || abort_fetch(&context_for_cancel, request_id)));
+  (future, request_id)
+}
+
+pub(crate) fn abort_fetch(context: &ExecutingContext, request_id: u32) {
+  let exception_state = context.create_exception_state();
+  let _ = context.webf_invoke_module_with_params("Fetch", "abortRequest", &NativeValue::new_int64(request_id as i64), &exception_state);
+}
+
+// The Fetch module replies with [errmsg, statusCode, body, headers], where headers is a flat list of name/value pairs.
+fn to_response(data: &NativeValue) -> Result<Response, WebFError> {
+  let data = data.to_list();
+  if data.len() < 3 {
+    return Err(WebFError::NetworkError("Invalid response of fetch".to_string()));
+  }
+  let error_string = if data[0].is_string() { data[0].to_string() } else { String::new() };
+  if data[1].is_null() {
+    return Err(WebFError::NetworkError(error_string));
+  }
+  let status = data[1].to_int64() as u16;
+  let body = if data[2].is_u8_bytes() { data[2].to_u8_bytes() } else { Vec::new() };
+  let mut headers = Headers::new();
+  if data.len() > 3 && data[3].is_list() {
+    let pairs = data[3].to_list();
+    for pair in pairs.chunks_exact(2) {
+      headers.append(&pair[0].to_string(), &pair[1].to_string());
+    }
+  }
+  Ok(Response {
+    status,
+    headers,
+    body,
+  })
+}
@@ -0,0 +1,120 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use crate::*;
+
+/// Behavior as same as `FormDataEntryValue` in JavaScript.
+#[derive(Debug, Clone)]
+pub enum FormDataEntryValue {
+  String(String),
+  File {
+    blob: Blob,
+    filename: String,
+  },
+}
+
+/// Behavior as same as `FormData` in JavaScript.
+///
+/// The entries keep the order they were appended in, including the entries of the same name.
+/// Use `FetchInit::set_form_data_body()` to send it as the body of a fetch request.
+#[derive(Debug, Clone)]
+pub struct FormData {
+  context: *const ExecutingContext,
+  entries: Vec<(String, FormDataEntryValue)>,
+}
+
+impl FormData {
+  /// Behavior as same as `new FormData()` in JavaScript.
+  pub fn new(context: &ExecutingContext) -> FormData {
+    FormData {
+      context,
+      entries: Vec::new(),
+    }
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `FormData.append(name, value)` in JavaScript.
+  pub fn append_string(&mut self, name: &str, value: &str) {
+    self.entries.push((name.to_string(), FormDataEntryValue::String(value.to_string())));
+  }
+
+  /// Behavior as same as `FormData.append(name, blob, filename)` in JavaScript.
+  /// The filename is `blob` when not set.
+  pub fn append_blob(&mut self, name: &str, blob: &Blob, filename: Option<&str>) {
+    let filename = filename.unwrap_or("blob").to_string();
+    self.entries.push((name.to_string(), FormDataEntryValue::File { blob: blob.clone(), filename }));
+  }
+
+  /// Behavior as same as `FormData.get()` in JavaScript, returns the first value of the name.
+  pub fn get(&self, name: &str) -> Option<&FormDataEntryValue> {
+    self.entries.iter().find(|(entry_name, _)| entry_name == name).map(|(_, value)| value)
+  }
+
+  /// Behavior as same as `FormData.getAll()` in JavaScript, the values are in the order they were appended in.
+  pub fn get_all(&self, name: &str) -> Vec<&FormDataEntryValue> {
+    self.entries.iter().filter(|(entry_name, _)| entry_name == name).map(|(_, value)| value).collect()
+  }
+
+  /// Behavior as same as `FormData.has()` in JavaScript.
+  pub fn has(&self, name: &str) -> bool {
+    self.entries.iter().any(|(entry_name, _)| entry_name == name)
+  }
+
+  /// Behavior as same as `FormData.delete()` in JavaScript, all the values of the name are removed.
+  pub fn delete(&mut self, name: &str) {
+    self.entries.retain(|(entry_name, _)| entry_name != name);
+  }
+
+  // https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart-form-data
+  // Returns the boundary and the encoded body.
+  pub(crate) fn encode_multipart(&self) -> (String, Vec<u8>) {
+    let boundary = generate_boundary();
+    let mut body = Vec::new();
+    for (name, value) in &self.entries {
+      body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+      match value {
+        FormDataEntryValue::String(value) => {
+          body.extend_from_slice(format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n", escape_multipart_name(name)).as_bytes());
+          body.extend_from_slice(normalize_line_breaks(value).as_bytes());
+        }
+        FormDataEntryValue::File { blob, filename } => {
+          let content_type = if blob.type_().is_empty() { "application/octet-stream" } else { blob.type_() };
+          body.extend_from_slice(format!(
+            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            escape_multipart_name(name),
+            escape_multipart_name(filename),
+            content_type
+          ).as_bytes());
+          body.extend_from_slice(blob.bytes());
+        }
+      }
+      body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+    (boundary, body)
+  }
+}
+
+fn generate_boundary() -> String {
+  let mut hasher = RandomState::new().build_hasher();
+  hasher.write_u128(std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .map_or(0, |duration| duration.as_nanos()));
+  format!("----WebFFormBoundary{:016x}", hasher.finish())
+}
+
+fn escape_multipart_name(name: &str) -> String {
+  name.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+// Line breaks of the values are sent as CRLF.
+fn normalize_line_breaks(value: &str) -> String {
+  value.replace("\r\n", "\n").replace('\r', "\n").replace('\n', "\r\n")
+}
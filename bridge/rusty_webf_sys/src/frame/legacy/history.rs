@@ -0,0 +1,87 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use crate::*;
+
+/// The session history of the page, returned by `Window::history()`.
+pub struct History {
+  context: *const ExecutingContext,
+}
+
+impl History {
+  pub fn initialize(context: *const ExecutingContext) -> History {
+    History {
+      context
+    }
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `history.length` in JavaScript.
+  pub fn length(&self, exception_state: &ExceptionState) -> Result<u32, WebFError> {
+    let length = self.context().webf_invoke_module("History", "length", exception_state)?;
+    length.to_string().parse::<u32>().map_err(|err| WebFError::Other(err.to_string()))
+  }
+
+  /// Behavior as same as `history.state` in JavaScript, `None` if the current entry has no state.
+  pub fn state(&self, exception_state: &ExceptionState) -> Result<Option<ScriptValue>, WebFError> {
+    let state = self.context().webf_invoke_module("History", "state", exception_state)?;
+    match ScriptValue::from_json(&state.to_string())? {
+      ScriptValue::Null => Ok(None),
+      state => Ok(Some(state)),
+    }
+  }
+
+  /// Behavior as same as `history.back()` in JavaScript.
+  /// The `popstate` event fires on the window with the state of the new current entry.
+  pub fn back(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.context().webf_invoke_module("History", "back", exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `history.forward()` in JavaScript.
+  /// The `popstate` event fires on the window with the state of the new current entry.
+  pub fn forward(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.context().webf_invoke_module("History", "forward", exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `history.go()` in JavaScript.
+  /// Nothing happens if there are fewer than `delta` entries in that direction.
+  pub fn go(&self, delta: i32, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let delta_native_value = NativeValue::new_int64(delta as i64);
+    self.context().webf_invoke_module_with_params("History", "go", &delta_native_value, exception_state)?;
+    Ok(())
+  }
+
+  /// Behavior as same as `history.pushState()` in JavaScript.
+  /// The URL is resolved against the current page and must have the same host. Returns an error if it is not a valid URL.
+  pub fn push_state(&self, state: &ScriptValue, title: &str, url: Option<&str>, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.change_state("pushState", state, title, url, exception_state)
+  }
+
+  /// Behavior as same as `history.replaceState()` in JavaScript.
+  /// The URL is resolved against the current page and must have the same host. Returns an error if it is not a valid URL.
+  pub fn replace_state(&self, state: &ScriptValue, title: &str, url: Option<&str>, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.change_state("replaceState", state, title, url, exception_state)
+  }
+
+  fn change_state(&self, method: &str, state: &ScriptValue, title: &str, url: Option<&str>, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if let Some(url) = url {
+      if !is_valid_url(url) {
+        return Err(WebFError::SyntaxError(format!("Failed to execute '{}' on 'History': '{}' is not a valid URL.", method, url)));
+      }
+    }
+    let params = NativeValue::new_list(vec![
+      NativeValue::new_json(&state.to_json()),
+      NativeValue::new_string(title),
+      url.map_or(NativeValue::new_null(), |url| NativeValue::new_string(url.trim())),
+    ]);
+    self.context().webf_invoke_module_with_params("History", method, &params, exception_state)?;
+    Ok(())
+  }
+}
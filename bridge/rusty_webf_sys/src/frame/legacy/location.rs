@@ -26,9 +26,10 @@ impl Location {
     href_string.to_string()
   }
 
-  pub fn set_href(&self, href: &str, exception_state: &ExceptionState) {
-    let href_string_native_value = NativeValue::new_string(href);
-    self.context().webf_invoke_module_with_params("Navigation", "goTo", &href_string_native_value, exception_state);
+  /// Behavior as same as setting `location.href` in JavaScript.
+  /// Returns an error if `href` is not a valid URL.
+  pub fn set_href(&self, href: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.navigate("href", href, "goTo", exception_state)
   }
 
   pub fn origin(&self, exception_state: &ExceptionState) -> String {
@@ -71,18 +72,74 @@ impl Location {
     hash_string.to_string()
   }
 
-  pub fn assign(&self, url: &str, exception_state: &ExceptionState) {
-    let url_string_native_value = NativeValue::new_string(url);
-    self.context().webf_invoke_module_with_params("Navigation", "goTo", &url_string_native_value, exception_state);
+  /// Behavior as same as setting `location.hash` in JavaScript.
+  /// A leading `#` is optional. The `hashchange` event fires on the window once the hash has changed.
+  pub fn set_hash(&self, hash: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let fragment = hash.strip_prefix('#').unwrap_or(hash);
+    self.navigate("hash", &format!("#{}", fragment), "goTo", exception_state)
   }
 
+  /// Behavior as same as `location.assign()` in JavaScript.
+  /// Returns an error if `url` is not a valid URL. Navigating to a URL which only differs in the hash fires the
+  /// `hashchange` event instead of loading the page.
+  pub fn assign(&self, url: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.navigate("assign", url, "goTo", exception_state)
+  }
+
+  /// Behavior as same as `location.reload()` in JavaScript.
   pub fn reload(&self, exception_state: &ExceptionState) {
     self.context().webf_location_reload(exception_state);
   }
 
-  pub fn replace(&self, url: &str, exception_state: &ExceptionState) {
-    let url_string_native_value = NativeValue::new_string(url);
-    self.context().webf_invoke_module_with_params("Navigation", "goTo", &url_string_native_value, exception_state);
+  /// Behavior as same as `location.replace()` in JavaScript.
+  /// Returns an error if `url` is not a valid URL. The current history entry is replaced, so the history length is unchanged.
+  pub fn replace(&self, url: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.navigate("replace", url, "replace", exception_state)
+  }
+
+  // `module_method` is the method of the `Navigation` module: `goTo` adds a history entry, `replace` replaces the current one.
+  fn navigate(&self, method: &str, url: &str, module_method: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if !is_valid_url(url) {
+      return Err(WebFError::SyntaxError(format!("Failed to execute '{}' on 'Location': '{}' is not a valid URL.", method, url)));
+    }
+    let url_string_native_value = NativeValue::new_string(url.trim());
+    self.context().webf_invoke_module_with_params("Navigation", module_method, &url_string_native_value, exception_state)?;
+    Ok(())
   }
+}
 
+// Relative URLs are resolved against the current page, so only the parts which can never be resolved are rejected:
+// whitespace or control characters, a malformed scheme and a hierarchical URL without a host.
+pub(crate) fn is_valid_url(url: &str) -> bool {
+  let url = url.trim();
+  if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+    return false;
+  }
+  let Some(colon) = url.find(':') else {
+    return true;
+  };
+  let scheme = &url[..colon];
+  // A colon after the path starts, such as in `./a:b`, does not end a scheme.
+  if scheme.contains(['/', '?', '#']) {
+    return true;
+  }
+  let mut scheme_chars = scheme.chars();
+  let scheme_is_valid = scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+    && scheme_chars.all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+  if !scheme_is_valid {
+    return false;
+  }
+  match scheme.to_ascii_lowercase().as_str() {
+    "http" | "https" | "ws" | "wss" | "ftp" => {
+      let rest = &url[colon + 1..];
+      let Some(authority) = rest.strip_prefix("//") else {
+        return false;
+      };
+      let host = authority.split(['/', '?', '#']).next().unwrap_or_default();
+      let host = host.rsplit('@').next().unwrap_or_default();
+      let host = host.split(':').next().unwrap_or_default();
+      !host.is_empty()
+    }
+    _ => true,
+  }
 }
@@ -1,6 +1,8 @@
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
+pub mod history;
 pub mod location;
 
+pub use history::*;
 pub use location::*;
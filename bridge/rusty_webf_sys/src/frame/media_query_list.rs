@@ -0,0 +1,374 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use crate::*;
+
+/// Invoked with the new match state when the result of a `MediaQueryList` flips.
+pub type MediaQueryListChangeCallback = Box<dyn FnMut(bool)>;
+
+// The font size which `em` and `rem` are relative to, the same as the default of the root element.
+const DEFAULT_FONT_SIZE: f64 = 16.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MediaFeatureRange {
+  Min,
+  Max,
+  Exact,
+}
+
+impl MediaFeatureRange {
+  fn matches(&self, actual: f64, expected: f64) -> bool {
+    match self {
+      MediaFeatureRange::Min => actual >= expected,
+      MediaFeatureRange::Max => actual <= expected,
+      MediaFeatureRange::Exact => (actual - expected).abs() < f64::EPSILON,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MediaFeature {
+  // In CSS pixels.
+  Width(MediaFeatureRange, f64),
+  Height(MediaFeatureRange, f64),
+  AspectRatio(MediaFeatureRange, f64),
+  // In dots per CSS pixel.
+  Resolution(MediaFeatureRange, f64),
+  Portrait(bool),
+  // A media feature WebF doesn't know, such as `hover`, it never matches.
+  Unknown,
+}
+
+// The size of the viewport which the media features are evaluated against.
+struct MediaViewport {
+  width: f64,
+  height: f64,
+  device_pixel_ratio: f64,
+}
+
+impl MediaFeature {
+  fn matches(&self, viewport: &MediaViewport) -> bool {
+    match *self {
+      MediaFeature::Width(range, value) => range.matches(viewport.width, value),
+      MediaFeature::Height(range, value) => range.matches(viewport.height, value),
+      MediaFeature::AspectRatio(range, value) => viewport.height > 0.0 && range.matches(viewport.width / viewport.height, value),
+      MediaFeature::Resolution(range, value) => range.matches(viewport.device_pixel_ratio, value),
+      MediaFeature::Portrait(portrait) => (viewport.height >= viewport.width) == portrait,
+      MediaFeature::Unknown => false,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MediaQuery {
+  negated: bool,
+  // False for the media types which never match in WebF, such as `print`.
+  media_type_matches: bool,
+  features: Vec<MediaFeature>,
+}
+
+impl MediaQuery {
+  fn matches(&self, viewport: &MediaViewport) -> bool {
+    // The query with an unknown media feature is false, even when it is negated.
+    if self.features.contains(&MediaFeature::Unknown) {
+      return false;
+    }
+    let matches = self.media_type_matches && self.features.iter().all(|feature| feature.matches(viewport));
+    matches != self.negated
+  }
+}
+
+fn syntax_error(query: &str, reason: &str) -> WebFError {
+  WebFError::SyntaxError(format!("Failed to execute 'matchMedia' on 'Window': '{}' {}.", query, reason))
+}
+
+// Splits a media query into words, keeping each parenthesized media feature as one token.
+fn tokenize_media_query<'a>(media: &str, query: &'a str) -> Result<Vec<&'a str>, WebFError> {
+  let mut tokens = Vec::new();
+  let mut start = None;
+  let mut depth = 0;
+  for (index, char) in query.char_indices() {
+    match char {
+      '(' => {
+        if depth == 0 {
+          if let Some(word_start) = start.take() {
+            tokens.push(&query[word_start..index]);
+          }
+          start = Some(index);
+        }
+        depth += 1;
+      }
+      ')' => {
+        if depth == 0 {
+          return Err(syntax_error(media, "has an unbalanced parenthesis"));
+        }
+        depth -= 1;
+        if depth == 0 {
+          tokens.push(&query[start.take().unwrap()..=index]);
+        }
+      }
+      _ if char.is_whitespace() && depth == 0 => {
+        if let Some(word_start) = start.take() {
+          tokens.push(&query[word_start..index]);
+        }
+      }
+      _ => {
+        if start.is_none() {
+          start = Some(index);
+        }
+      }
+    }
+  }
+  if depth != 0 {
+    return Err(syntax_error(media, "has an unbalanced parenthesis"));
+  }
+  if let Some(word_start) = start {
+    tokens.push(&query[word_start..]);
+  }
+  Ok(tokens)
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+  value.parse::<f64>().ok().filter(|number| number.is_finite() && *number >= 0.0)
+}
+
+fn parse_length(value: &str) -> Option<f64> {
+  if let Some(number) = value.strip_suffix("px") {
+    return parse_number(number);
+  }
+  if let Some(number) = value.strip_suffix("rem").or_else(|| value.strip_suffix("em")) {
+    return parse_number(number).map(|number| number * DEFAULT_FONT_SIZE);
+  }
+  // Only zero lengths may omit the unit.
+  parse_number(value).filter(|number| *number == 0.0)
+}
+
+fn parse_ratio(value: &str) -> Option<f64> {
+  let (width, height) = match value.split_once('/') {
+    Some((width, height)) => (parse_number(width.trim())?, parse_number(height.trim())?),
+    None => (parse_number(value)?, 1.0),
+  };
+  if height == 0.0 {
+    return None;
+  }
+  Some(width / height)
+}
+
+fn parse_resolution(value: &str) -> Option<f64> {
+  if let Some(number) = value.strip_suffix("dppx").or_else(|| value.strip_suffix('x')) {
+    return parse_number(number);
+  }
+  if let Some(number) = value.strip_suffix("dpcm") {
+    return parse_number(number).map(|number| number * 2.54 / 96.0);
+  }
+  if let Some(number) = value.strip_suffix("dpi") {
+    return parse_number(number).map(|number| number / 96.0);
+  }
+  None
+}
+
+fn parse_media_feature(media: &str, token: &str) -> Result<MediaFeature, WebFError> {
+  let content = &token[1..token.len() - 1];
+  let (name, value) = match content.split_once(':') {
+    Some((name, value)) => (name.trim(), Some(value.trim())),
+    None => (content.trim(), None),
+  };
+  let (range, feature_name) = if let Some(feature_name) = name.strip_prefix("min-") {
+    (MediaFeatureRange::Min, feature_name)
+  } else if let Some(feature_name) = name.strip_prefix("max-") {
+    (MediaFeatureRange::Max, feature_name)
+  } else {
+    (MediaFeatureRange::Exact, name)
+  };
+  if !matches!(feature_name, "width" | "height" | "aspect-ratio" | "resolution" | "orientation") {
+    return Ok(MediaFeature::Unknown);
+  }
+  let Some(value) = value else {
+    return Err(syntax_error(media, &format!("has the media feature '{}' without a value", name)));
+  };
+  let feature = match feature_name {
+    "width" => parse_length(value).map(|value| MediaFeature::Width(range, value)),
+    "height" => parse_length(value).map(|value| MediaFeature::Height(range, value)),
+    "aspect-ratio" => parse_ratio(value).map(|value| MediaFeature::AspectRatio(range, value)),
+    "resolution" => parse_resolution(value).map(|value| MediaFeature::Resolution(range, value)),
+    "orientation" if range == MediaFeatureRange::Exact => match value {
+      "portrait" => Some(MediaFeature::Portrait(true)),
+      "landscape" => Some(MediaFeature::Portrait(false)),
+      _ => None,
+    },
+    _ => None,
+  };
+  feature.ok_or_else(|| syntax_error(media, &format!("has an invalid value '{}' of the media feature '{}'", value, name)))
+}
+
+fn parse_media_query(media: &str, query: &str) -> Result<MediaQuery, WebFError> {
+  let tokens = tokenize_media_query(media, query)?;
+  let mut tokens = tokens.into_iter().peekable();
+  let negated = match tokens.peek() {
+    Some(&"not") => {
+      tokens.next();
+      true
+    }
+    Some(&"only") => {
+      tokens.next();
+      false
+    }
+    _ => false,
+  };
+
+  let mut media_query = MediaQuery {
+    negated,
+    media_type_matches: true,
+    features: Vec::new(),
+  };
+  let mut expects_feature = true;
+  match tokens.peek() {
+    Some(token) if !token.starts_with('(') => {
+      media_query.media_type_matches = match *token {
+        "all" | "screen" => true,
+        "print" | "speech" => false,
+        _ => return Err(syntax_error(media, &format!("has the unknown media type '{}'", token))),
+      };
+      tokens.next();
+      expects_feature = false;
+    }
+    None => return Err(syntax_error(media, "has an empty media query")),
+    _ => {}
+  }
+
+  for token in tokens {
+    if expects_feature {
+      if !token.starts_with('(') {
+        return Err(syntax_error(media, &format!("expects a media feature instead of '{}'", token)));
+      }
+      media_query.features.push(parse_media_feature(media, token)?);
+      expects_feature = false;
+    } else if token == "and" {
+      expects_feature = true;
+    } else {
+      return Err(syntax_error(media, &format!("expects 'and' instead of '{}'", token)));
+    }
+  }
+  if expects_feature {
+    return Err(syntax_error(media, "ends without a media feature"));
+  }
+  Ok(media_query)
+}
+
+fn parse_media_query_list(media: &str) -> Result<Vec<MediaQuery>, WebFError> {
+  let lower_case_media = media.to_ascii_lowercase();
+  if lower_case_media.trim().is_empty() {
+    return Ok(Vec::new());
+  }
+  lower_case_media.split(',').map(|query| parse_media_query(media, query)).collect()
+}
+
+struct MediaQueryListInner {
+  context: *const ExecutingContext,
+  media: String,
+  queries: Vec<MediaQuery>,
+  // The match state when the listeners were notified last time.
+  matches: Cell<bool>,
+  listeners: RefCell<Vec<MediaQueryListChangeCallback>>,
+  resize_listener: RefCell<Option<ListenerGuard>>,
+}
+
+impl MediaQueryListInner {
+  fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  fn evaluate(&self, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    // An empty list matches all, the same as `all`.
+    if self.queries.is_empty() {
+      return Ok(true);
+    }
+    let window = self.context().window();
+    let viewport = MediaViewport {
+      width: window.inner_width(exception_state)?,
+      height: window.inner_height(exception_state)?,
+      device_pixel_ratio: window.device_pixel_ratio(exception_state)?,
+    };
+    Ok(self.queries.iter().any(|query| query.matches(&viewport)))
+  }
+
+  fn notify_if_changed(&self) {
+    let exception_state = self.context().create_exception_state();
+    let Ok(matches) = self.evaluate(&exception_state) else {
+      return;
+    };
+    if matches == self.matches.get() {
+      return;
+    }
+    self.matches.set(matches);
+
+    // The listeners are taken out while being invoked, so that they are able to add more listeners.
+    let mut listeners = std::mem::take(&mut *self.listeners.borrow_mut());
+    for listener in listeners.iter_mut() {
+      listener(matches);
+    }
+    let mut added_listeners = self.listeners.borrow_mut();
+    listeners.append(&mut added_listeners);
+    *added_listeners = listeners;
+  }
+}
+
+/// Behavior as same as `MediaQueryList` in JavaScript, created by `Window::match_media()`.
+///
+/// The queries are evaluated against the viewport of the window. The supported media types are `all`, `screen`,
+/// `print` and `speech`, and the supported media features are `width`, `height`, `aspect-ratio` and `resolution`
+/// with their `min-` and `max-` forms, and `orientation`. A query with any other media feature, such as `hover`
+/// or `prefers-color-scheme`, never matches.
+pub struct MediaQueryList {
+  inner: Rc<MediaQueryListInner>,
+}
+
+impl MediaQueryList {
+  pub(crate) fn initialize(media: &str, context: &ExecutingContext, exception_state: &ExceptionState) -> Result<MediaQueryList, WebFError> {
+    let inner = MediaQueryListInner {
+      context,
+      media: media.trim().to_string(),
+      queries: parse_media_query_list(media)?,
+      matches: Cell::new(false),
+      listeners: RefCell::new(Vec::new()),
+      resize_listener: RefCell::new(None),
+    };
+    inner.matches.set(inner.evaluate(exception_state)?);
+    Ok(MediaQueryList {
+      inner: Rc::new(inner),
+    })
+  }
+
+  /// Behavior as same as `MediaQueryList.media` in JavaScript.
+  pub fn media(&self) -> &str {
+    &self.inner.media
+  }
+
+  /// Behavior as same as `MediaQueryList.matches` in JavaScript, the queries are evaluated against the current viewport.
+  pub fn matches(&self) -> bool {
+    let exception_state = self.inner.context().create_exception_state();
+    self.inner.evaluate(&exception_state).unwrap_or(self.inner.matches.get())
+  }
+
+  /// Behavior as same as `MediaQueryList.addEventListener('change')` in JavaScript.
+  /// The callback is invoked with the new match state when it flips after the window is resized,
+  /// such as on an orientation change. The listeners are removed when the `MediaQueryList` is dropped.
+  pub fn add_change_listener(&self, callback: MediaQueryListChangeCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let mut resize_listener = self.inner.resize_listener.borrow_mut();
+    if resize_listener.is_none() {
+      let inner: Weak<MediaQueryListInner> = Rc::downgrade(&self.inner);
+      let guard = self.inner.context().window().on_resize(Box::new(move |_| {
+        if let Some(inner) = inner.upgrade() {
+          inner.notify_if_changed();
+        }
+      }), exception_state)?;
+      *resize_listener = Some(guard);
+    }
+    self.inner.listeners.borrow_mut().push(callback);
+    Ok(())
+  }
+}
@@ -2,13 +2,29 @@
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
 pub mod async_storage;
+pub mod clipboard;
+pub mod console;
+pub mod fetch;
+pub mod form_data;
+pub mod media_query_list;
 pub mod navigator;
+pub mod performance;
 pub mod window;
 pub mod storage;
+pub mod web_socket;
+pub mod xml_http_request;
 pub mod legacy;
 
 pub use async_storage::*;
+pub use clipboard::*;
+pub use console::*;
+pub use fetch::*;
+pub use form_data::*;
+pub use media_query_list::*;
 pub use navigator::*;
+pub use performance::*;
 pub use window::*;
 pub use storage::*;
+pub use web_socket::*;
+pub use xml_http_request::*;
 pub use legacy::*;
@@ -20,24 +20,64 @@ impl Navigator {
     unsafe { &*self.context }
   }
 
+  /// Behavior as same as `navigator.clipboard` in JavaScript.
+  pub fn clipboard(&self) -> Clipboard {
+    Clipboard::initialize(self.context)
+  }
+
+  /// Behavior as same as `navigator.userAgent` in JavaScript.
   pub fn user_agent(&self, exception_state: &ExceptionState) -> String {
     let ua_string = self.context().webf_invoke_module("Navigator", "getUserAgent", exception_state).unwrap();
     ua_string.to_string()
   }
 
+  /// Behavior as same as `navigator.platform` in JavaScript.
+  /// The operating system name reported by Dart, such as `android`, `ios`, `macos`, `linux` or `windows`.
   pub fn platform(&self, exception_state: &ExceptionState) -> String {
     let platform_string = self.context().webf_invoke_module("Navigator", "getPlatform", exception_state).unwrap();
     platform_string.to_string()
   }
 
+  /// Behavior as same as `navigator.language` in JavaScript.
   pub fn language(&self, exception_state: &ExceptionState) -> String {
     let language_string = self.context().webf_invoke_module("Navigator", "getLanguage", exception_state).unwrap();
     language_string.to_string()
   }
 
-  pub fn languages(&self, exception_state: &ExceptionState) -> String {
-    let languages_string = self.context().webf_invoke_module("Navigator", "getLanguages", exception_state).unwrap();
-    languages_string.to_string()
+  /// Behavior as same as `navigator.languages` in JavaScript.
+  /// The preferred languages of the user as BCP 47 language tags, the most preferred first.
+  pub fn languages(&self, exception_state: &ExceptionState) -> Result<Vec<String>, WebFError> {
+    let languages_string = self.context().webf_invoke_module("Navigator", "getLanguages", exception_state)?;
+    let languages = JsonValue::parse(&languages_string.to_string())?;
+    Ok(languages.as_array().map_or_else(Vec::new, |languages| {
+      languages.iter().filter_map(|language| language.as_str().map(str::to_string)).collect()
+    }))
+  }
+
+  /// Behavior as same as `navigator.onLine` in JavaScript, the network interfaces are listed again for every call.
+  /// Resolves to false only when the device has no network interface other than loopback, being online does not mean the network is reachable.
+  pub fn online(&self, exception_state: &ExceptionState) -> WebFFuture<bool> {
+    let (future, resolver) = WebFFuture::<bool>::new();
+    let callback: WebFNativeFunction = Box::new(move |argc, argv| {
+      if argc == 1 {
+        let error_string = unsafe { (*argv).clone() };
+        resolver.resolve(Err(WebFError::from(error_string.to_string())));
+        return NativeValue::new_null();
+      }
+      if argc == 2 {
+        let online = unsafe { (*argv.wrapping_add(1)).clone() };
+        resolver.resolve(Ok(online.is_bool() && online.to_bool()));
+        return NativeValue::new_null();
+      }
+      println!("Invalid argument count for navigator callback");
+      NativeValue::new_null()
+    });
+    if let Err(err) = self.context().webf_invoke_module_with_params_and_callback("Navigator", "getOnLine", &NativeValue::new_null(), callback, exception_state) {
+      let (failed_future, failed_resolver) = WebFFuture::<bool>::new();
+      failed_resolver.resolve(Err(err));
+      return failed_future;
+    }
+    future
   }
 
   pub fn app_name(&self, exception_state: &ExceptionState) -> String {
@@ -50,6 +90,8 @@ impl Navigator {
     app_version_string.to_string()
   }
 
+  /// Behavior as same as `navigator.hardwareConcurrency` in JavaScript.
+  /// The number of logical processors, which can be used to size thread pools.
   pub fn hardware_concurrency(&self, exception_state: &ExceptionState) -> i32 {
     let hardware_concurrency = self.context().webf_invoke_module("Navigator", "getHardwareConcurrency", exception_state).unwrap();
     let concurrency_string = hardware_concurrency.to_string();
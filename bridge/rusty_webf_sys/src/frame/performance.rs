@@ -0,0 +1,107 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct PerformanceRustMethods {
+  pub version: c_double,
+  pub now: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub time_origin: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub mark: extern "C" fn(ptr: *const OpaquePtr, name: *const c_char, exception_state: *const OpaquePtr) -> c_void,
+  pub measure: extern "C" fn(ptr: *const OpaquePtr, name: *const c_char, start_mark: *const c_char, end_mark: *const c_char, exception_state: *const OpaquePtr) -> c_double,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for PerformanceRustMethods {}
+
+/// Behavior as same as `performance` in JavaScript, returned by `ExecutingContext::performance()`.
+///
+/// The times are in milliseconds since the time origin of the context, which `Event::time_stamp()` is measured from too.
+pub struct Performance {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const PerformanceRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl Performance {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const PerformanceRustMethods, status: *const RustValueStatus) -> Performance {
+    Performance {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `performance.now()` in JavaScript, but with microsecond precision.
+  /// The clock is monotonic within a context and starts from zero at the time origin.
+  pub fn now(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).now)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `performance.timeOrigin` in JavaScript, the milliseconds since the Unix epoch.
+  pub fn time_origin(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).time_origin)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `performance.mark()` in JavaScript.
+  pub fn mark(&self, name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let name_c_string = CString::new(name).unwrap();
+    unsafe {
+      ((*self.method_pointer).mark)(self.ptr(), name_c_string.as_ptr(), exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `performance.measure()` in JavaScript, returns the duration of the created measure.
+  /// The measure starts from the time origin without a start mark, and ends now without an end mark.
+  pub fn measure(&self, name: &str, start_mark: Option<&str>, end_mark: Option<&str>, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    let name_c_string = CString::new(name).unwrap();
+    let start_mark_c_string = start_mark.map(|mark| CString::new(mark).unwrap());
+    let end_mark_c_string = end_mark.map(|mark| CString::new(mark).unwrap());
+    let duration = unsafe {
+      ((*self.method_pointer).measure)(
+        self.ptr(),
+        name_c_string.as_ptr(),
+        start_mark_c_string.as_ref().map_or(std::ptr::null(), |mark| mark.as_ptr()),
+        end_mark_c_string.as_ref().map_or(std::ptr::null(), |mark| mark.as_ptr()),
+        exception_state.ptr,
+      )
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(duration)
+  }
+}
+
+impl Drop for Performance {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
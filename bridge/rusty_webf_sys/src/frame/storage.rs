@@ -22,13 +22,9 @@ impl Storage {
     unsafe { &*self.context }
   }
 
-  pub fn get_item(&self, key: &str, exception_state: &ExceptionState) -> Result<Option<String>, String> {
+  pub fn get_item(&self, key: &str, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
     let key_string = NativeValue::new_string(key);
-    let item_string = self.context().webf_invoke_module_with_params(&self.module_name, "getItem", &key_string, exception_state).unwrap();
-
-    if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
-    }
+    let item_string = self.context().webf_invoke_module_with_params(&self.module_name, "getItem", &key_string, exception_state)?;
 
     if item_string.is_null() {
       return Ok(None);
@@ -36,7 +32,7 @@ impl Storage {
     Ok(Some(item_string.to_string()))
   }
 
-  pub fn set_item(&self, key: &str, value: &str, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn set_item(&self, key: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
     let key_string = NativeValue::new_string(key);
     let value_string = NativeValue::new_string(value);
     let params_vec = vec![key_string, value_string];
@@ -45,33 +41,31 @@ impl Storage {
     self.context().webf_invoke_module_with_params(&self.module_name, "setItem", &params, exception_state);
 
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
 
     Ok(())
   }
 
-  pub fn remove_item(&self, key: &str, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn remove_item(&self, key: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
     let key_string = NativeValue::new_string(key);
-    let result = self.context().webf_invoke_module_with_params(&self.module_name, "removeItem", &key_string, exception_state);
-
-    if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
-    }
-    match result {
-      Ok(result) => Ok(()),
-      Err(err) => Err(err),
-    }
+    self.context().webf_invoke_module_with_params(&self.module_name, "removeItem", &key_string, exception_state)?;
+    Ok(())
   }
 
-  pub fn clear(&self, exception_state: &ExceptionState) {
-    self.context().webf_invoke_module(&self.module_name, "clear", exception_state);
+  pub fn clear(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.context().webf_invoke_module(&self.module_name, "clear", exception_state)?;
+    Ok(())
   }
 
-  pub fn key(&self, index: u32, exception_state: &ExceptionState) -> String {
+  pub fn key(&self, index: u32, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
     let index_string = NativeValue::new_int64(index.into());
-    let key_string = self.context().webf_invoke_module_with_params(&self.module_name, "key", &index_string, exception_state).unwrap();
-    key_string.to_string()
+    let key_string = self.context().webf_invoke_module_with_params(&self.module_name, "key", &index_string, exception_state)?;
+
+    if key_string.is_null() {
+      return Ok(None);
+    }
+    Ok(Some(key_string.to_string()))
   }
 
   pub fn get_all_keys(&self, exception_state: &ExceptionState) -> Vec<String> {
@@ -80,8 +74,8 @@ impl Storage {
     result
   }
 
-  pub fn length(&self, exception_state: &ExceptionState) -> i64 {
-    let length = self.context().webf_invoke_module(&self.module_name, "length", exception_state).unwrap();
-    length.to_int64()
+  pub fn length(&self, exception_state: &ExceptionState) -> Result<u32, WebFError> {
+    let length = self.context().webf_invoke_module(&self.module_name, "length", exception_state)?;
+    Ok(length.to_int64() as u32)
   }
 }
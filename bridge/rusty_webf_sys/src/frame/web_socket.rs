@@ -0,0 +1,245 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::*;
+use std::rc::Rc;
+use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
+
+const MODULE_NAME: &str = "WebSocket";
+
+/// The data of a MessageEvent, filled by the C++ side.
+#[repr(C)]
+pub struct MessageEventData {
+  pub bytes: *mut u8,
+  pub length: i64,
+  pub is_binary: i32,
+}
+
+/// Behavior as same as `WebSocket.readyState` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebSocketReadyState {
+  Connecting,
+  Open,
+  Closing,
+  Closed,
+}
+
+/// A message received by a `WebSocket`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebSocketMessage<'a> {
+  Text(&'a str),
+  Binary(&'a [u8]),
+}
+
+pub type WebSocketMessageCallback = Box<dyn Fn(WebSocketMessage)>;
+pub type CloseEventCallback = Box<dyn Fn(&CloseEvent)>;
+
+/// A WebSocket connection, created by `ExecutingContext::web_socket()`.
+///
+/// Events of the connection are dispatched to `event_target()`, so listeners can also be added with the event listener APIs.
+/// Dropping the WebSocket closes the connection and stops dispatching its events.
+pub struct WebSocket {
+  id: String,
+  url: String,
+  ready_state: Rc<Cell<WebSocketReadyState>>,
+  handlers: RefCell<HashMap<&'static str, ListenerGuard>>,
+  event_target: EventTarget,
+  // Boxed so that the address held by the event target stays the same when the WebSocket is moved.
+  context: Box<ExecutingContext>,
+}
+
+impl WebSocket {
+  pub(crate) fn connect(context: &ExecutingContext, url: &str, protocols: &[&str], exception_state: &ExceptionState) -> Result<WebSocket, WebFError> {
+    let scheme = url.split(':').next().unwrap_or_default();
+    if !url.contains(':') || (scheme != "ws" && scheme != "wss") {
+      return Err(WebFError::SyntaxError(format!("Failed to construct 'WebSocket': The URL's scheme must be either 'ws' or 'wss'. '{}' is not allowed.", scheme)));
+    }
+
+    let protocols = protocols.iter().map(|protocol| NativeValue::new_string(protocol)).collect();
+    let params = NativeValue::new_list(vec![NativeValue::new_string(url), NativeValue::new_list(protocols)]);
+    let id = context.webf_invoke_module_with_params(MODULE_NAME, "init", &params, exception_state)?;
+    if !id.is_string() {
+      return Err(WebFError::NetworkError("Failed to construct 'WebSocket': The connection could not be created.".to_string()));
+    }
+    let id = id.to_string();
+
+    let context = Box::new(context.clone());
+    let module_name_c_string = CString::new(MODULE_NAME).unwrap();
+    let id_c_string = CString::new(id.as_str()).unwrap();
+    let event_target_value = unsafe {
+      (context.method_pointer().create_module_event_target)(context.ptr, module_name_c_string.as_ptr(), id_c_string.as_ptr(), exception_state.ptr)
+    };
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(&context));
+    }
+    let event_target = EventTarget::initialize(event_target_value.value, &*context, event_target_value.method_pointer, event_target_value.status);
+
+    let web_socket = WebSocket {
+      id,
+      url: url.to_string(),
+      ready_state: Rc::new(Cell::new(WebSocketReadyState::Connecting)),
+      handlers: RefCell::new(HashMap::new()),
+      event_target,
+      context,
+    };
+
+    // These listeners are added first, so the ready state is already updated when the handlers of users run.
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    let state_changes = [
+      ("open", WebSocketReadyState::Open),
+      ("close", WebSocketReadyState::Closed),
+      ("error", WebSocketReadyState::Closed),
+    ];
+    for (event_type, state) in state_changes {
+      let ready_state = web_socket.ready_state.clone();
+      web_socket.event_target.add_event_listener(event_type, Box::new(move |_| ready_state.set(state)), &options, exception_state)?;
+    }
+
+    // The WebSocket module only emits the events which have been subscribed.
+    for event_type in ["open", "message", "close", "error"] {
+      let params = NativeValue::new_list(vec![NativeValue::new_string(&web_socket.id), NativeValue::new_string(event_type)]);
+      web_socket.context.webf_invoke_module_with_params(MODULE_NAME, "addEvent", &params, exception_state)?;
+    }
+
+    Ok(web_socket)
+  }
+
+  pub fn url(&self) -> &str {
+    &self.url
+  }
+
+  pub fn ready_state(&self) -> WebSocketReadyState {
+    self.ready_state.get()
+  }
+
+  /// The event target which receives the `open`, `message`, `close` and `error` events of the connection.
+  pub fn event_target(&self) -> &EventTarget {
+    &self.event_target
+  }
+
+  /// Behavior as same as `WebSocket.send()` with a string in JavaScript.
+  pub fn send_text(&self, data: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.send(NativeValue::new_string(data), exception_state)
+  }
+
+  /// Behavior as same as `WebSocket.send()` with an ArrayBuffer in JavaScript.
+  pub fn send_binary(&self, data: &[u8], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.send(NativeValue::new_u8_bytes(data), exception_state)
+  }
+
+  fn send(&self, message: NativeValue, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    match self.ready_state() {
+      WebSocketReadyState::Connecting => Err(WebFError::dom_exception("InvalidStateError", "Failed to execute 'send' on 'WebSocket': Still in CONNECTING state.")),
+      // Messages sent after the connection starts closing are discarded, the same as in browsers.
+      WebSocketReadyState::Closing | WebSocketReadyState::Closed => Ok(()),
+      WebSocketReadyState::Open => {
+        let params = NativeValue::new_list(vec![NativeValue::new_string(&self.id), message]);
+        self.context.webf_invoke_module_with_params(MODULE_NAME, "send", &params, exception_state)?;
+        Ok(())
+      }
+    }
+  }
+
+  /// Behavior as same as `WebSocket.close()` in JavaScript.
+  /// The code must be either 1000 or in the range 3000-4999, and the reason must not be longer than 123 bytes.
+  pub fn close(&self, code: Option<u16>, reason: Option<&str>, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if let Some(code) = code {
+      if code != 1000 && !(3000..=4999).contains(&code) {
+        return Err(WebFError::dom_exception("InvalidAccessError", &format!("Failed to execute 'close' on 'WebSocket': The code must be either 1000, or between 3000 and 4999. {} is neither.", code)));
+      }
+    }
+    if reason.is_some_and(|reason| reason.len() > 123) {
+      return Err(WebFError::SyntaxError("Failed to execute 'close' on 'WebSocket': The message must not be greater than 123 bytes.".to_string()));
+    }
+    if matches!(self.ready_state(), WebSocketReadyState::Closing | WebSocketReadyState::Closed) {
+      return Ok(());
+    }
+
+    let params = NativeValue::new_list(vec![
+      NativeValue::new_string(&self.id),
+      code.map_or(NativeValue::new_null(), |code| NativeValue::new_int64(code as i64)),
+      reason.map_or(NativeValue::new_null(), NativeValue::new_string),
+    ]);
+    self.context.webf_invoke_module_with_params(MODULE_NAME, "close", &params, exception_state)?;
+    self.ready_state.set(WebSocketReadyState::Closing);
+    Ok(())
+  }
+
+  /// Behavior as same as setting `WebSocket.onopen` in JavaScript, the previous handler is replaced.
+  pub fn on_open(&self, callback: EventListenerCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_handler("open", callback, exception_state)
+  }
+
+  /// Behavior as same as setting `WebSocket.onmessage` in JavaScript, the previous handler is replaced.
+  pub fn on_message(&self, callback: WebSocketMessageCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let context = self.context.as_ref().clone();
+    self.set_handler("message", Box::new(move |event| {
+      let exception_state = context.create_exception_state();
+      let mut data = MessageEventData {
+        bytes: std::ptr::null_mut(),
+        length: 0,
+        is_binary: 0,
+      };
+      unsafe {
+        (context.method_pointer().get_message_event_data)(context.ptr, event.ptr(), &mut data, exception_state.ptr);
+      }
+      if exception_state.has_exception() || data.bytes.is_null() {
+        return;
+      }
+
+      let bytes = unsafe { std::slice::from_raw_parts(data.bytes, data.length as usize) };
+      if data.is_binary != 0 {
+        callback(WebSocketMessage::Binary(bytes));
+      } else {
+        callback(WebSocketMessage::Text(&String::from_utf8_lossy(bytes)));
+      }
+      safe_free_cpp_ptr(data.bytes);
+    }), exception_state)
+  }
+
+  /// Behavior as same as setting `WebSocket.onclose` in JavaScript, the previous handler is replaced.
+  pub fn on_close(&self, callback: CloseEventCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_handler("close", Box::new(move |event| {
+      if let Ok(close_event) = event.as_close_event() {
+        callback(&close_event);
+      }
+    }), exception_state)
+  }
+
+  /// Behavior as same as setting `WebSocket.onerror` in JavaScript, the previous handler is replaced.
+  pub fn on_error(&self, callback: EventListenerCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.set_handler("error", callback, exception_state)
+  }
+
+  fn set_handler(&self, event_type: &'static str, callback: EventListenerCallback, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    let guard = self.event_target.add_event_listener_guarded(event_type, callback, &options, exception_state)?;
+    // Dropping the previous guard removes the previous handler.
+    self.handlers.borrow_mut().insert(event_type, guard);
+    Ok(())
+  }
+}
+
+impl Drop for WebSocket {
+  fn drop(&mut self) {
+    let exception_state = self.context.create_exception_state();
+    let _ = self.close(None, None, &exception_state);
+    let module_name_c_string = CString::new(MODULE_NAME).unwrap();
+    let id_c_string = CString::new(self.id.as_str()).unwrap();
+    unsafe {
+      (self.context.method_pointer().remove_module_event_target)(self.context.ptr, module_name_c_string.as_ptr(), id_c_string.as_ptr());
+    }
+  }
+}
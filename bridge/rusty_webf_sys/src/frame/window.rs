@@ -10,30 +10,233 @@ pub struct WindowRustMethods {
   pub version: c_double,
   pub event_target: EventTargetRustMethods,
   pub scroll_to_with_x_and_y: extern "C" fn(*const OpaquePtr, c_double, c_double, *const OpaquePtr),
+  pub get_computed_style: extern "C" fn(*const OpaquePtr, *const OpaquePtr, *const c_char, *const OpaquePtr) -> RustValue<CSSStyleDeclarationRustMethods>,
+  pub inner_width: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub inner_height: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub device_pixel_ratio: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub scroll_x: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
+  pub scroll_y: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_double,
 }
 
 impl RustMethods for WindowRustMethods {}
 
+pub type UIEventCallback = Box<dyn Fn(&UIEvent)>;
+pub type HashchangeEventCallback = Box<dyn Fn(&HashchangeEvent)>;
+pub type PopStateEventCallback = Box<dyn Fn(&PopStateEvent)>;
+pub type StorageEventCallback = Box<dyn Fn(&StorageEvent)>;
+
 pub struct Window {
-  ptr: *const OpaquePtr,
+  pub event_target: EventTarget,
   method_pointer: *const WindowRustMethods,
-  status: *const RustValueStatus,
 }
 
-
 impl Window {
-  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const WindowRustMethods, status: *const RustValueStatus) -> Window {
-    Window {
-      ptr,
-      method_pointer,
-      status,
-    }
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    self.event_target.context()
   }
 
   pub fn scroll_to_with_x_and_y(&self, x: f64, y: f64, exception_state: &ExceptionState) {
     unsafe {
-      ((*self.method_pointer).scroll_to_with_x_and_y)(self.ptr, x, y, exception_state.ptr)
+      ((*self.method_pointer).scroll_to_with_x_and_y)(self.ptr(), x, y, exception_state.ptr)
+    }
+  }
+
+  /// Behavior as same as `window.getComputedStyle()` in JavaScript.
+  /// Pending style and layout changes are applied first, so the returned values are resolved, such as `rgb(255, 0, 0)` for colors.
+  ///
+  /// The returned declaration is read-only: `set_property()` and `remove_property()` on it return `Err`.
+  pub fn get_computed_style(&self, element: &Element, pseudo: Option<&str>, exception_state: &ExceptionState) -> Result<CSSStyleDeclaration, WebFError> {
+    let pseudo_c_string = pseudo.map(|pseudo| CString::new(pseudo).unwrap());
+    let pseudo_ptr = pseudo_c_string.as_ref().map_or(std::ptr::null(), |pseudo| pseudo.as_ptr());
+    let style_value = unsafe {
+      ((*self.method_pointer).get_computed_style)(self.ptr(), element.ptr(), pseudo_ptr, exception_state.ptr)
+    };
+
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
     }
+
+    if style_value.value.is_null() {
+      return Err(WebFError::Other("Failed to execute 'getComputedStyle' on 'Window': The style of the element is not available.".to_string()));
+    }
+
+    Ok(CSSStyleDeclaration::initialize(style_value.value, self.context(), style_value.method_pointer, style_value.status))
+  }
+
+  /// Behavior as same as `window.innerWidth` in JavaScript, in CSS pixels.
+  pub fn inner_width(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    let value = unsafe {
+      ((*self.method_pointer).inner_width)(self.ptr(), exception_state.ptr)
+    };
+    self.to_result(value, exception_state)
+  }
+
+  /// Behavior as same as `window.innerHeight` in JavaScript, in CSS pixels.
+  pub fn inner_height(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    let value = unsafe {
+      ((*self.method_pointer).inner_height)(self.ptr(), exception_state.ptr)
+    };
+    self.to_result(value, exception_state)
   }
 
+  /// Behavior as same as `window.devicePixelRatio` in JavaScript.
+  pub fn device_pixel_ratio(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    let value = unsafe {
+      ((*self.method_pointer).device_pixel_ratio)(self.ptr(), exception_state.ptr)
+    };
+    self.to_result(value, exception_state)
+  }
+
+  /// Behavior as same as `window.scrollX` in JavaScript.
+  /// Pending layout changes are applied first, so the returned offset is current.
+  pub fn scroll_x(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    let value = unsafe {
+      ((*self.method_pointer).scroll_x)(self.ptr(), exception_state.ptr)
+    };
+    self.to_result(value, exception_state)
+  }
+
+  /// Behavior as same as `window.scrollY` in JavaScript.
+  /// Pending layout changes are applied first, so the returned offset is current.
+  pub fn scroll_y(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    let value = unsafe {
+      ((*self.method_pointer).scroll_y)(self.ptr(), exception_state.ptr)
+    };
+    self.to_result(value, exception_state)
+  }
+
+  /// Behavior as same as `window.location` in JavaScript.
+  pub fn location(&self) -> Location {
+    Location::initialize(self.context())
+  }
+
+  /// Behavior as same as `window.history` in JavaScript.
+  pub fn history(&self) -> History {
+    History::initialize(self.context())
+  }
+
+  /// Behavior as same as `window.matchMedia()` in JavaScript.
+  /// Unlike in JavaScript, a query which can not be parsed or uses an unsupported media feature returns a `SyntaxError`
+  /// instead of never matching.
+  pub fn match_media(&self, query: &str, exception_state: &ExceptionState) -> Result<MediaQueryList, WebFError> {
+    MediaQueryList::initialize(query, self.context(), exception_state)
+  }
+
+  /// Listens to the `resize` event of the window, which fires after the viewport changes its size.
+  /// The listener is removed when the returned guard is dropped.
+  pub fn on_resize(&self, callback: UIEventCallback, exception_state: &ExceptionState) -> Result<ListenerGuard, WebFError> {
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    self.event_target.add_event_listener_guarded("resize", Box::new(move |event| {
+      if let Ok(ui_event) = event.as_ui_event() {
+        callback(&ui_event);
+      }
+    }), &options, exception_state)
+  }
+
+  /// Listens to the `hashchange` event of the window, which fires after the hash of the location has changed,
+  /// such as by `Location::set_hash()`. The listener is removed when the returned guard is dropped.
+  pub fn on_hashchange(&self, callback: HashchangeEventCallback, exception_state: &ExceptionState) -> Result<ListenerGuard, WebFError> {
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    self.event_target.add_event_listener_guarded("hashchange", Box::new(move |event| {
+      if let Ok(hashchange_event) = event.as_hashchange_event() {
+        callback(&hashchange_event);
+      }
+    }), &options, exception_state)
+  }
+
+  /// Listens to the `popstate` event of the window, which fires when the current history entry changes by
+  /// `History::back()`, `History::forward()` or `History::go()`. `PopStateEvent::state()` returns the state stored by
+  /// `History::push_state()` or `History::replace_state()`. The listener is removed when the returned guard is dropped.
+  pub fn on_popstate(&self, callback: PopStateEventCallback, exception_state: &ExceptionState) -> Result<ListenerGuard, WebFError> {
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    self.event_target.add_event_listener_guarded("popstate", Box::new(move |event| {
+      if let Ok(pop_state_event) = event.as_pop_state_event() {
+        callback(&pop_state_event);
+      }
+    }), &options, exception_state)
+  }
+
+  /// Listens to the `storage` event of the window, which fires when another context of the same origin changes
+  /// the local storage. Changes made by this context do not fire it. The listener is removed when the returned guard is dropped.
+  pub fn on_storage(&self, callback: StorageEventCallback, exception_state: &ExceptionState) -> Result<ListenerGuard, WebFError> {
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture: 0,
+    };
+    self.event_target.add_event_listener_guarded("storage", Box::new(move |event| {
+      if let Ok(storage_event) = event.as_storage_event() {
+        callback(&storage_event);
+      }
+    }), &options, exception_state)
+  }
+
+  fn to_result(&self, value: f64, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(value)
+  }
+}
+
+impl EventTargetMethods for Window {
+  /// Initialize the instance from cpp raw pointer.
+  fn initialize<T: RustMethods>(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const T, status: *const RustValueStatus) -> Self where Self: Sized {
+    unsafe {
+      Window {
+        event_target: EventTarget::initialize(
+          ptr,
+          context,
+          &(method_pointer as *const WindowRustMethods).as_ref().unwrap().event_target,
+          status,
+        ),
+        method_pointer: method_pointer as *const WindowRustMethods,
+      }
+    }
+  }
+
+  fn ptr(&self) -> *const OpaquePtr {
+    self.event_target.ptr
+  }
+
+  fn add_event_listener(&self,
+                        event_name: &str,
+                        callback: EventListenerCallback,
+                        options: &AddEventListenerOptions,
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.add_event_listener(event_name, callback, options, exception_state)
+  }
+
+  fn remove_event_listener(&self,
+                           event_name: &str,
+                           callback: EventListenerCallback,
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.remove_event_listener(event_name, callback, exception_state)
+  }
+
+  fn dispatch_event(&self,
+                    event: &Event,
+                    exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.event_target.dispatch_event(event, exception_state)
+  }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.event_target.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.event_target.downgrade()
+  }
 }
@@ -0,0 +1,317 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::*;
+
+const FORBIDDEN_METHODS: [&str; 3] = ["CONNECT", "TRACE", "TRACK"];
+const NORMALIZED_METHODS: [&str; 6] = ["DELETE", "GET", "HEAD", "OPTIONS", "POST", "PUT"];
+
+/// Behavior as same as `XMLHttpRequest.readyState` in JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XMLHttpRequestReadyState {
+  Unsent,
+  Opened,
+  HeadersReceived,
+  Loading,
+  Done,
+}
+
+pub type XMLHttpRequestCallback = Box<dyn Fn(&XMLHttpRequest)>;
+
+#[derive(Clone, Copy)]
+enum XMLHttpRequestEvent {
+  ReadyStateChange,
+  Load,
+  Error,
+}
+
+type XMLHttpRequestHandler = Rc<dyn Fn(&XMLHttpRequest)>;
+
+#[derive(Default)]
+struct XMLHttpRequestHandlers {
+  ready_state_change: Option<XMLHttpRequestHandler>,
+  load: Option<XMLHttpRequestHandler>,
+  error: Option<XMLHttpRequestHandler>,
+}
+
+struct XMLHttpRequestState {
+  method: String,
+  url: String,
+  request_headers: Headers,
+  ready_state: XMLHttpRequestReadyState,
+  send_flag: bool,
+  status: u16,
+  response_headers: Headers,
+  response_body: Vec<u8>,
+  // Increased by `open()` and `abort()`, so that the response of a previous request is ignored.
+  request_id: u64,
+  // The id of the sent request in the Fetch module, used to abort it.
+  fetch_request_id: Option<u32>,
+}
+
+impl XMLHttpRequestState {
+  fn reset_response(&mut self) {
+    self.status = 0;
+    self.response_headers = Headers::default();
+    self.response_body.clear();
+  }
+}
+
+struct XMLHttpRequestInner {
+  context: ExecutingContext,
+  state: RefCell<XMLHttpRequestState>,
+  handlers: RefCell<XMLHttpRequestHandlers>,
+}
+
+/// Behavior as same as `XMLHttpRequest` in JavaScript, the request is sent by the same networking as `ExecutingContext::fetch()`.
+///
+/// Only asynchronous requests are supported, since the response is delivered by the engine after the current task.
+/// A pending request keeps running after the `XMLHttpRequest` is dropped, until it completes or `abort()` is called.
+pub struct XMLHttpRequest {
+  inner: Rc<XMLHttpRequestInner>,
+}
+
+impl XMLHttpRequest {
+  pub fn new(context: &ExecutingContext) -> XMLHttpRequest {
+    XMLHttpRequest {
+      inner: Rc::new(XMLHttpRequestInner {
+        context: context.clone(),
+        state: RefCell::new(XMLHttpRequestState {
+          method: String::new(),
+          url: String::new(),
+          request_headers: Headers::new(),
+          ready_state: XMLHttpRequestReadyState::Unsent,
+          send_flag: false,
+          status: 0,
+          response_headers: Headers::default(),
+          response_body: Vec::new(),
+          request_id: 0,
+          fetch_request_id: None,
+        }),
+        handlers: RefCell::new(XMLHttpRequestHandlers::default()),
+      }),
+    }
+  }
+
+  /// Behavior as same as `XMLHttpRequest.open(method, url)` in JavaScript, the request is always asynchronous.
+  /// The pending request is aborted without firing any event, and the request headers are cleared.
+  pub fn open(&self, method: &str, url: &str) -> Result<(), WebFError> {
+    let is_token = !method.is_empty() && method.bytes().all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte));
+    if !is_token {
+      return Err(WebFError::SyntaxError(format!("Failed to execute 'open' on 'XMLHttpRequest': '{}' is not a valid HTTP method.", method)));
+    }
+    let upper_method = method.to_ascii_uppercase();
+    if FORBIDDEN_METHODS.contains(&upper_method.as_str()) {
+      return Err(WebFError::dom_exception("SecurityError", &format!("Failed to execute 'open' on 'XMLHttpRequest': '{}' HTTP method is unsupported.", method)));
+    }
+
+    let (was_opened, pending_request) = {
+      let mut state = self.inner.state.borrow_mut();
+      let was_opened = state.ready_state == XMLHttpRequestReadyState::Opened;
+      state.request_id += 1;
+      state.method = if NORMALIZED_METHODS.contains(&upper_method.as_str()) { upper_method } else { method.to_string() };
+      state.url = url.to_string();
+      state.request_headers = Headers::new();
+      state.send_flag = false;
+      state.reset_response();
+      state.ready_state = XMLHttpRequestReadyState::Opened;
+      (was_opened, state.fetch_request_id.take())
+    };
+    if let Some(fetch_request_id) = pending_request {
+      abort_fetch(&self.inner.context, fetch_request_id);
+    }
+    if !was_opened {
+      self.dispatch(XMLHttpRequestEvent::ReadyStateChange);
+    }
+    Ok(())
+  }
+
+  /// Behavior as same as `XMLHttpRequest.setRequestHeader()` in JavaScript.
+  /// Setting a header again appends the value to the previous one, separated by `, `.
+  pub fn set_request_header(&self, name: &str, value: &str) -> Result<(), WebFError> {
+    let mut state = self.inner.state.borrow_mut();
+    if state.ready_state != XMLHttpRequestReadyState::Opened || state.send_flag {
+      return Err(WebFError::dom_exception("InvalidStateError", "Failed to execute 'setRequestHeader' on 'XMLHttpRequest': The object's state must be OPENED."));
+    }
+    state.request_headers.append(name, value);
+    Ok(())
+  }
+
+  /// Behavior as same as `XMLHttpRequest.send()` in JavaScript.
+  ///
+  /// The body is ignored for `GET` and `HEAD` requests.
+  /// A `Content-Type: text/plain;charset=UTF-8` header is added for a body unless a content type is already set.
+  pub fn send(&self, body: Option<&[u8]>, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let (url, init, request_id) = {
+      let mut state = self.inner.state.borrow_mut();
+      if state.ready_state != XMLHttpRequestReadyState::Opened || state.send_flag {
+        return Err(WebFError::dom_exception("InvalidStateError", "Failed to execute 'send' on 'XMLHttpRequest': The object's state must be OPENED."));
+      }
+
+      let body = match body {
+        Some(_) if state.method == "GET" || state.method == "HEAD" => None,
+        Some(body) => Some(FetchBody::Bytes(body.to_vec())),
+        None => None,
+      };
+      let mut headers = state.request_headers.clone();
+      if body.is_some() && !headers.has("content-type") {
+        headers.append("Content-Type", "text/plain;charset=UTF-8");
+      }
+      state.send_flag = true;
+      let init = FetchInit {
+        method: Some(state.method.clone()),
+        headers,
+        body,
+      };
+      (state.url.clone(), init, state.request_id)
+    };
+
+    let (response, fetch_request_id) = start_fetch(&self.inner.context, &url, init, exception_state);
+    self.inner.state.borrow_mut().fetch_request_id = Some(fetch_request_id);
+    let inner = self.inner.clone();
+    self.inner.context.spawn(async move {
+      let result = match response.await {
+        Ok(response) => response.array_buffer().await.map(|body| (response.status(), response.headers().clone(), body)),
+        Err(err) => Err(err),
+      };
+      XMLHttpRequest { inner }.complete(request_id, result);
+    });
+    Ok(())
+  }
+
+  /// Behavior as same as `XMLHttpRequest.abort()` in JavaScript.
+  pub fn abort(&self) {
+    let (pending_request, fire_ready_state_change) = {
+      let mut state = self.inner.state.borrow_mut();
+      state.request_id += 1;
+      state.reset_response();
+      let was_sending = state.send_flag;
+      let fire_ready_state_change = was_sending || matches!(state.ready_state, XMLHttpRequestReadyState::HeadersReceived | XMLHttpRequestReadyState::Loading);
+      state.send_flag = false;
+      if fire_ready_state_change {
+        state.ready_state = XMLHttpRequestReadyState::Done;
+      }
+      (state.fetch_request_id.take(), fire_ready_state_change)
+    };
+
+    if let Some(fetch_request_id) = pending_request {
+      abort_fetch(&self.inner.context, fetch_request_id);
+    }
+    if fire_ready_state_change {
+      self.dispatch(XMLHttpRequestEvent::ReadyStateChange);
+    }
+    // No readystatechange is fired for this change, unless the handler above has reopened the request.
+    let mut state = self.inner.state.borrow_mut();
+    if state.ready_state == XMLHttpRequestReadyState::Done {
+      state.ready_state = XMLHttpRequestReadyState::Unsent;
+    }
+  }
+
+  pub fn ready_state(&self) -> XMLHttpRequestReadyState {
+    self.inner.state.borrow().ready_state
+  }
+
+  /// Behavior as same as `XMLHttpRequest.status` in JavaScript, 0 until the response is received or when the request failed.
+  pub fn status(&self) -> u16 {
+    self.inner.state.borrow().status
+  }
+
+  /// Behavior as same as `XMLHttpRequest.responseText` in JavaScript, empty until the request is done.
+  pub fn response_text(&self) -> String {
+    String::from_utf8_lossy(&self.inner.state.borrow().response_body).into_owned()
+  }
+
+  /// Behavior as same as `XMLHttpRequest.getResponseHeader()` in JavaScript, the name is compared case-insensitively.
+  pub fn get_response_header(&self, name: &str) -> Option<String> {
+    self.inner.state.borrow().response_headers.get(name)
+  }
+
+  /// Behavior as same as setting `XMLHttpRequest.onreadystatechange` in JavaScript, the previous handler is replaced.
+  pub fn on_ready_state_change(&self, callback: XMLHttpRequestCallback) {
+    self.inner.handlers.borrow_mut().ready_state_change = Some(Rc::from(callback));
+  }
+
+  /// Behavior as same as setting `XMLHttpRequest.onload` in JavaScript, the previous handler is replaced.
+  pub fn on_load(&self, callback: XMLHttpRequestCallback) {
+    self.inner.handlers.borrow_mut().load = Some(Rc::from(callback));
+  }
+
+  /// Behavior as same as setting `XMLHttpRequest.onerror` in JavaScript, the previous handler is replaced.
+  /// The handler runs when the request fails without a response, such as a network error or an invalid URL.
+  pub fn on_error(&self, callback: XMLHttpRequestCallback) {
+    self.inner.handlers.borrow_mut().error = Some(Rc::from(callback));
+  }
+
+  fn complete(&self, request_id: u64, result: Result<(u16, Headers, Vec<u8>), WebFError>) {
+    if !self.is_current(request_id) {
+      return;
+    }
+    self.inner.state.borrow_mut().fetch_request_id = None;
+
+    let (status, headers, body) = match result {
+      Ok(response) => response,
+      Err(_) => {
+        {
+          let mut state = self.inner.state.borrow_mut();
+          state.reset_response();
+          state.send_flag = false;
+          state.ready_state = XMLHttpRequestReadyState::Done;
+        }
+        self.dispatch(XMLHttpRequestEvent::ReadyStateChange);
+        if self.is_current(request_id) {
+          self.dispatch(XMLHttpRequestEvent::Error);
+        }
+        return;
+      }
+    };
+
+    {
+      let mut state = self.inner.state.borrow_mut();
+      state.status = status;
+      state.response_headers = headers;
+      state.ready_state = XMLHttpRequestReadyState::HeadersReceived;
+    }
+    self.dispatch(XMLHttpRequestEvent::ReadyStateChange);
+    // The handlers may abort or reopen the request.
+    if !self.is_current(request_id) {
+      return;
+    }
+    self.inner.state.borrow_mut().ready_state = XMLHttpRequestReadyState::Loading;
+    self.dispatch(XMLHttpRequestEvent::ReadyStateChange);
+    if !self.is_current(request_id) {
+      return;
+    }
+    {
+      let mut state = self.inner.state.borrow_mut();
+      state.response_body = body;
+      state.send_flag = false;
+      state.ready_state = XMLHttpRequestReadyState::Done;
+    }
+    self.dispatch(XMLHttpRequestEvent::ReadyStateChange);
+    if self.is_current(request_id) {
+      self.dispatch(XMLHttpRequestEvent::Load);
+    }
+  }
+
+  fn is_current(&self, request_id: u64) -> bool {
+    self.inner.state.borrow().request_id == request_id
+  }
+
+  fn dispatch(&self, event: XMLHttpRequestEvent) {
+    // The handler is cloned out, so that it can replace the handlers or call the methods of the request.
+    let handler = {
+      let handlers = self.inner.handlers.borrow();
+      match event {
+        XMLHttpRequestEvent::ReadyStateChange => handlers.ready_state_change.clone(),
+        XMLHttpRequestEvent::Load => handlers.load.clone(),
+        XMLHttpRequestEvent::Error => handlers.error.clone(),
+      }
+    };
+    if let Some(handler) = handler {
+      handler(self);
+    }
+  }
+}
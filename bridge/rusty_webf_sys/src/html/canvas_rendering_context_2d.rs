@@ -0,0 +1,286 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct CanvasRenderingContext2DRustMethods {
+  pub version: c_double,
+  pub fill_rect: extern "C" fn(*const OpaquePtr, c_double, c_double, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub stroke_rect: extern "C" fn(*const OpaquePtr, c_double, c_double, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub clear_rect: extern "C" fn(*const OpaquePtr, c_double, c_double, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub begin_path: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_void,
+  pub move_to: extern "C" fn(*const OpaquePtr, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub line_to: extern "C" fn(*const OpaquePtr, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub arc: extern "C" fn(*const OpaquePtr, c_double, c_double, c_double, c_double, c_double, i32, *const OpaquePtr) -> c_void,
+  pub fill: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_void,
+  pub stroke: extern "C" fn(*const OpaquePtr, *const OpaquePtr) -> c_void,
+  pub set_fill_style: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> c_void,
+  pub set_font: extern "C" fn(*const OpaquePtr, *const c_char, *const OpaquePtr) -> c_void,
+  pub fill_text: extern "C" fn(*const OpaquePtr, *const c_char, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub draw_image_bytes: extern "C" fn(*const OpaquePtr, *const u8, i64, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub put_image_data: extern "C" fn(*const OpaquePtr, *const u8, i64, i64, i64, c_double, c_double, *const OpaquePtr) -> c_void,
+  pub get_image_data: extern "C" fn(*const OpaquePtr, i64, i64, i64, i64, *const WebFNativeFunctionContext, *const OpaquePtr) -> c_void,
+  pub release: extern "C" fn(*const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for CanvasRenderingContext2DRustMethods {}
+
+/// The encoding of the bytes drawn by `CanvasRenderingContext2D::draw_image_bytes()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+  Png,
+  Jpeg,
+  Gif,
+  Webp,
+  Bmp,
+}
+
+impl ImageFormat {
+  fn name(&self) -> &'static str {
+    match self {
+      ImageFormat::Png => "PNG",
+      ImageFormat::Jpeg => "JPEG",
+      ImageFormat::Gif => "GIF",
+      ImageFormat::Webp => "WebP",
+      ImageFormat::Bmp => "BMP",
+    }
+  }
+
+  fn matches(&self, bytes: &[u8]) -> bool {
+    match self {
+      ImageFormat::Png => bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]),
+      ImageFormat::Jpeg => bytes.starts_with(&[0xFF, 0xD8, 0xFF]),
+      ImageFormat::Gif => bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a"),
+      ImageFormat::Webp => bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP",
+      ImageFormat::Bmp => bytes.starts_with(b"BM"),
+    }
+  }
+}
+
+/// Behavior as same as `CanvasRenderingContext2D` in JavaScript, returned by `Element::get_context_2d()`.
+/// The drawing commands are sent to the canvas in order and painted in the next frame.
+pub struct CanvasRenderingContext2D {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const CanvasRenderingContext2DRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl CanvasRenderingContext2D {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const CanvasRenderingContext2DRustMethods, status: *const RustValueStatus) -> CanvasRenderingContext2D {
+    CanvasRenderingContext2D {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.fillRect()` in JavaScript.
+  pub fn fill_rect(&self, x: f64, y: f64, width: f64, height: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).fill_rect)(self.ptr(), x, y, width, height, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.strokeRect()` in JavaScript.
+  pub fn stroke_rect(&self, x: f64, y: f64, width: f64, height: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).stroke_rect)(self.ptr(), x, y, width, height, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.clearRect()` in JavaScript.
+  pub fn clear_rect(&self, x: f64, y: f64, width: f64, height: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).clear_rect)(self.ptr(), x, y, width, height, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.beginPath()` in JavaScript.
+  pub fn begin_path(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).begin_path)(self.ptr(), exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.moveTo()` in JavaScript.
+  pub fn move_to(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).move_to)(self.ptr(), x, y, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.lineTo()` in JavaScript.
+  pub fn line_to(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).line_to)(self.ptr(), x, y, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.arc()` in JavaScript, the angles are in radians.
+  #[allow(clippy::too_many_arguments)]
+  pub fn arc(&self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64, anticlockwise: bool, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).arc)(self.ptr(), x, y, radius, start_angle, end_angle, i32::from(anticlockwise), exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.fill()` in JavaScript, fills the current path.
+  pub fn fill(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).fill)(self.ptr(), exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.stroke()` in JavaScript, strokes the current path.
+  pub fn stroke(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    unsafe {
+      ((*self.method_pointer).stroke)(self.ptr(), exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as setting `CanvasRenderingContext2D.fillStyle` to a CSS color in JavaScript.
+  pub fn set_fill_style(&self, color: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let color_c_string = CString::new(color).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_fill_style)(self.ptr(), color_c_string.as_ptr(), exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as setting `CanvasRenderingContext2D.font` in JavaScript, such as `"16px sans-serif"`.
+  pub fn set_font(&self, font: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let font_c_string = CString::new(font).unwrap();
+    unsafe {
+      ((*self.method_pointer).set_font)(self.ptr(), font_c_string.as_ptr(), exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.fillText()` in JavaScript.
+  pub fn fill_text(&self, text: &str, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    let text_c_string = CString::new(text).unwrap();
+    unsafe {
+      ((*self.method_pointer).fill_text)(self.ptr(), text_c_string.as_ptr(), x, y, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Draws the image encoded in `bytes` at its natural size, with the top left corner at `(dx, dy)`.
+  /// Returns `Err` with an `InvalidStateError` when the bytes do not start with the signature of `format`.
+  ///
+  /// The image is decoded asynchronously, the drawing commands issued after this call are held back until it is decoded,
+  /// so the image is drawn in order. An image which fails to decode is skipped.
+  pub fn draw_image_bytes(&self, bytes: &[u8], format: ImageFormat, dx: f64, dy: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if !format.matches(bytes) {
+      return Err(WebFError::dom_exception("InvalidStateError", &format!("Failed to execute 'drawImageBytes': The bytes are not a {} image.", format.name())));
+    }
+    unsafe {
+      ((*self.method_pointer).draw_image_bytes)(self.ptr(), bytes.as_ptr(), bytes.len() as i64, dx, dy, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.putImageData()` in JavaScript, with the pixels of the `ImageData`
+  /// given as `data`: `width * height` pixels in RGBA order, 4 bytes per pixel, row by row.
+  /// The pixels replace the pixels of the canvas instead of being composited.
+  /// Returns `Err` with an `IndexSizeError` when the width or height is zero, or the length of `data` is not `width * height * 4`.
+  pub fn put_image_data(&self, data: &[u8], width: u32, height: u32, dx: f64, dy: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if width == 0 || height == 0 {
+      return Err(WebFError::dom_exception("IndexSizeError", "Failed to execute 'putImageData': The source width and height must be greater than zero."));
+    }
+    if data.len() as u64 != width as u64 * height as u64 * 4 {
+      return Err(WebFError::dom_exception("IndexSizeError", "Failed to execute 'putImageData': The length of the data is not equal to (4 * width * height)."));
+    }
+    unsafe {
+      ((*self.method_pointer).put_image_data)(self.ptr(), data.as_ptr(), data.len() as i64, width as i64, height as i64, dx, dy, exception_state.ptr);
+    }
+    self.to_result(exception_state)
+  }
+
+  /// Behavior as same as `CanvasRenderingContext2D.getImageData()` in JavaScript, resolved with the `data` of the
+  /// `ImageData`: `sw * sh` pixels in RGBA order, 4 bytes per pixel, row by row, not premultiplied.
+  /// The pixels are read once the drawing commands issued before, including the images being decoded, are painted.
+  /// Resolves with `Err` with an `IndexSizeError` when the width or height is zero.
+  pub fn get_image_data(&self, sx: i32, sy: i32, sw: u32, sh: u32, exception_state: &ExceptionState) -> WebFNativeFuture<Vec<u8>> {
+    let future_for_return = WebFNativeFuture::<Vec<u8>>::new();
+    if sw == 0 || sh == 0 {
+      future_for_return.set_result(Err(WebFError::dom_exception("IndexSizeError", "Failed to execute 'getImageData': The source width and height must be greater than zero.")));
+      return future_for_return;
+    }
+    let future_in_callback = future_for_return.clone();
+    let general_callback: WebFNativeFunction = Box::new(move |argc, argv| {
+      if argc == 1 {
+        let error_string = unsafe { (*argv).clone() };
+        let error_string = error_string.to_string();
+        future_in_callback.set_result(Err(WebFError::Other(error_string)));
+        return NativeValue::new_null();
+      }
+      if argc == 2 {
+        let result = unsafe { (*argv.wrapping_add(1)).clone() };
+        let value = result.to_u8_bytes();
+        future_in_callback.set_result(Ok(Some(value)));
+        return NativeValue::new_null();
+      }
+      println!("Invalid argument count for getImageData callback");
+      NativeValue::new_null()
+    });
+    let callback_data = Box::new(WebFNativeFunctionContextData {
+      func: general_callback,
+    });
+    let callback_context_data_ptr = Box::into_raw(callback_data);
+    let callback_context = Box::new(WebFNativeFunctionContext {
+      callback: invoke_webf_native_function,
+      free_ptr: release_webf_native_function,
+      ptr: callback_context_data_ptr,
+    });
+    let callback_context_ptr = Box::into_raw(callback_context);
+    unsafe {
+      ((*self.method_pointer).get_image_data)(self.ptr(), sx as i64, sy as i64, sw as i64, sh as i64, callback_context_ptr, exception_state.ptr);
+    }
+    if exception_state.has_exception() {
+      future_for_return.set_result(Err(exception_state.to_error(self.context())));
+    }
+    future_for_return
+  }
+
+  fn to_result(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    if exception_state.has_exception() {
+      return Err(exception_state.to_error(self.context()));
+    }
+    Ok(())
+  }
+}
+
+impl Drop for CanvasRenderingContext2D {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
@@ -0,0 +1,131 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct HtmlCollectionRustMethods {
+  pub version: c_double,
+  pub length: extern "C" fn(ptr: *const OpaquePtr) -> u32,
+  pub item: extern "C" fn(ptr: *const OpaquePtr, index: u32) -> RustValue<ElementRustMethods>,
+  pub named_item: extern "C" fn(ptr: *const OpaquePtr, name: *const c_char) -> RustValue<ElementRustMethods>,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for HtmlCollectionRustMethods {}
+
+/// A collection of elements, such as the result of `get_elements_by_class_name()` or `get_elements_by_tag_name()`.
+///
+/// Unlike the live `HTMLCollection` in browsers, the collection is a snapshot: the elements are looked up when the
+/// collection is created and it doesn't change when the DOM changes, so look the elements up again after a mutation.
+pub struct HtmlCollection {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const HtmlCollectionRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl HtmlCollection {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const HtmlCollectionRustMethods, status: *const RustValueStatus) -> HtmlCollection {
+    HtmlCollection {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `HTMLCollection.length` in JavaScript.
+  pub fn len(&self) -> usize {
+    let value = unsafe {
+      ((*self.method_pointer).length)(self.ptr())
+    };
+    value as usize
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Behavior as same as `HTMLCollection.item()` in JavaScript.
+  /// Returns None when the index is out of range.
+  pub fn item(&self, index: usize) -> Option<Element> {
+    if index >= self.len() {
+      return None;
+    }
+    let value = unsafe {
+      ((*self.method_pointer).item)(self.ptr(), index as u32)
+    };
+    if value.value.is_null() {
+      return None;
+    }
+    Some(Element::initialize(value.value, self.context, value.method_pointer, value.status))
+  }
+
+  /// Behavior as same as `HTMLCollection.namedItem()` in JavaScript.
+  /// Returns the first element whose `id` is the name, or else the first element whose `name` attribute is the name.
+  /// Returns None when no element matches or the name is empty.
+  pub fn named_item(&self, name: &str) -> Option<Element> {
+    let name_c_string = CString::new(name).unwrap();
+    let value = unsafe {
+      ((*self.method_pointer).named_item)(self.ptr(), name_c_string.as_ptr())
+    };
+    if value.value.is_null() {
+      return None;
+    }
+    Some(Element::initialize(value.value, self.context, value.method_pointer, value.status))
+  }
+
+  pub fn iter(&self) -> HtmlCollectionIter<'_> {
+    HtmlCollectionIter {
+      collection: self,
+      index: 0,
+    }
+  }
+}
+
+impl Drop for HtmlCollection {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
+
+pub struct HtmlCollectionIter<'a> {
+  collection: &'a HtmlCollection,
+  index: usize,
+}
+
+impl Iterator for HtmlCollectionIter<'_> {
+  type Item = Element;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let element = self.collection.item(self.index)?;
+    self.index += 1;
+    Some(element)
+  }
+}
+
+impl<'a> IntoIterator for &'a HtmlCollection {
+  type Item = Element;
+  type IntoIter = HtmlCollectionIter<'a>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
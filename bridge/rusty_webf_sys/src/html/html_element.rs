@@ -28,19 +28,271 @@ impl ElementMethods for HTMLElement {
   fn to_blob_with_device_pixel_ratio(&self, device_pixel_ratio: f64, exception_state: &ExceptionState) -> WebFNativeFuture<Vec<u8>> {
     self.element.to_blob_with_device_pixel_ratio(device_pixel_ratio, exception_state)
   }
+
+  fn query_selector(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.element.query_selector(selectors, exception_state)
+  }
+
+  fn query_selector_all(&self, selectors: &str, exception_state: &ExceptionState) -> Result<NodeList, WebFError> {
+    self.element.query_selector_all(selectors, exception_state)
+  }
+
+  fn matches(&self, selectors: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.element.matches(selectors, exception_state)
+  }
+
+  fn closest(&self, selectors: &str, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.element.closest(selectors, exception_state)
+  }
+
+  fn class_list(&self) -> DomTokenList {
+    self.element.class_list()
+  }
+
+  fn dataset(&self) -> DomStringMap {
+    self.element.dataset()
+  }
+
+  fn attributes(&self) -> NamedNodeMap {
+    self.element.attributes()
+  }
+
+  fn tag_name(&self) -> String {
+    self.element.tag_name()
+  }
+
+  fn id(&self) -> String {
+    self.element.id()
+  }
+
+  fn set_id(&self, id: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_id(id, exception_state)
+  }
+
+  fn class_name(&self) -> String {
+    self.element.class_name()
+  }
+
+  fn set_class_name(&self, class_name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_class_name(class_name, exception_state)
+  }
+
+  fn set_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_pointer_capture(pointer_id, exception_state)
+  }
+
+  fn release_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.release_pointer_capture(pointer_id, exception_state)
+  }
+
+  fn has_pointer_capture(&self, pointer_id: i64, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.element.has_pointer_capture(pointer_id, exception_state)
+  }
+  fn request_fullscreen(&self, exception_state: &ExceptionState) -> Result<Promise<()>, WebFError> {
+    self.element.request_fullscreen(exception_state)
+  }
+  fn get_context_2d(&self, exception_state: &ExceptionState) -> Result<CanvasRenderingContext2D, WebFError> {
+    self.element.get_context_2d(exception_state)
+  }
+  fn set_text(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_text(text, exception_state)
+  }
+
+  fn get_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.element.get_attribute(name, exception_state)
+  }
+
+  fn set_attribute(&self, name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_attribute(name, value, exception_state)
+  }
+  fn set_attribute_ns(&self, namespace: Option<&str>, qualified_name: &str, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_attribute_ns(namespace, qualified_name, value, exception_state)
+  }
+
+  fn remove_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.remove_attribute(name, exception_state)
+  }
+
+  fn has_attribute(&self, name: &str, exception_state: &ExceptionState) -> Result<bool, WebFError> {
+    self.element.has_attribute(name, exception_state)
+  }
+
+  fn inner_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.element.inner_html(exception_state)
+  }
+
+  fn set_inner_html(&self, html: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_inner_html(html, exception_state)
+  }
+
+  fn outer_html(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
+    self.element.outer_html(exception_state)
+  }
+
+  fn style(&self) -> Option<CSSStyleDeclaration> {
+    self.element.style()
+  }
+
+  fn get_bounding_client_rect(&self, exception_state: &ExceptionState) -> Result<DomRect, WebFError> {
+    self.element.get_bounding_client_rect(exception_state)
+  }
+
+  fn scroll_to(&self, x: f64, y: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.scroll_to(x, y, exception_state)
+  }
+
+  fn scroll_by(&self, dx: f64, dy: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.scroll_by(dx, dy, exception_state)
+  }
+
+  fn scroll_into_view(&self, options: ScrollIntoViewOptions, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.scroll_into_view(options, exception_state)
+  }
+
+  fn offset_top(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.element.offset_top(exception_state)
+  }
+
+  fn offset_left(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.element.offset_left(exception_state)
+  }
+
+  fn offset_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.element.offset_width(exception_state)
+  }
+
+  fn offset_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.element.offset_height(exception_state)
+  }
+
+  fn offset_parent(&self, exception_state: &ExceptionState) -> Result<Option<Element>, WebFError> {
+    self.element.offset_parent(exception_state)
+  }
+
+  fn client_width(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.element.client_width(exception_state)
+  }
+
+  fn client_height(&self, exception_state: &ExceptionState) -> Result<i64, WebFError> {
+    self.element.client_height(exception_state)
+  }
+
+  fn scroll_top(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    self.element.scroll_top(exception_state)
+  }
+
+  fn set_scroll_top(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_scroll_top(value, exception_state)
+  }
+
+  fn scroll_left(&self, exception_state: &ExceptionState) -> Result<f64, WebFError> {
+    self.element.scroll_left(exception_state)
+  }
+
+  fn set_scroll_left(&self, value: f64, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_scroll_left(value, exception_state)
+  }
+
+  fn get_elements_by_class_name(&self, class_names: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    self.element.get_elements_by_class_name(class_names, exception_state)
+  }
+
+  fn get_elements_by_tag_name(&self, tag_name: &str, exception_state: &ExceptionState) -> Result<HtmlCollection, WebFError> {
+    self.element.get_elements_by_tag_name(tag_name, exception_state)
+  }
+
+  fn append(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.append(items, exception_state)
+  }
+
+  fn prepend(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.prepend(items, exception_state)
+  }
+
+  fn before(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.before(items, exception_state)
+  }
+
+  fn after(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.after(items, exception_state)
+  }
+
+  fn replace_with(&self, items: &[NodeOrString], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.replace_with(items, exception_state)
+  }
 }
 
 impl ContainerNodeMethods for HTMLElement {}
 
 impl NodeMethods for HTMLElement {
-  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn append_child(&self, new_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.element.append_child(new_node, exception_state)
   }
 
-  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, String> {
+  fn remove_child(&self, target_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
     self.element.remove_child(target_node, exception_state)
   }
 
+  fn text_content(&self, exception_state: &ExceptionState) -> Result<Option<String>, WebFError> {
+    self.element.text_content(exception_state)
+  }
+
+  fn set_text_content(&self, text: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.set_text_content(text, exception_state)
+  }
+
+  fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.element.insert_before(new_node, reference_node, exception_state)
+  }
+
+  fn replace_child(&self, new_node: &Node, old_node: &Node, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.element.replace_child(new_node, old_node, exception_state)
+  }
+
+  fn first_child(&self) -> Option<Node> {
+    self.element.first_child()
+  }
+
+  fn last_child(&self) -> Option<Node> {
+    self.element.last_child()
+  }
+
+  fn parent_node(&self) -> Option<Node> {
+    self.element.parent_node()
+  }
+
+  fn next_sibling(&self) -> Option<Node> {
+    self.element.next_sibling()
+  }
+
+  fn previous_sibling(&self) -> Option<Node> {
+    self.element.previous_sibling()
+  }
+
+  fn child_nodes(&self) -> NodeList {
+    self.element.child_nodes()
+  }
+
+  fn append_children(&self, nodes: &[&dyn NodeMethods], exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.append_children(nodes, exception_state)
+  }
+
+  fn clone_node(&self, deep: bool, exception_state: &ExceptionState) -> Result<Node, WebFError> {
+    self.element.clone_node(deep, exception_state)
+  }
+
+  fn contains(&self, other: &dyn NodeMethods) -> bool {
+    self.element.contains(other)
+  }
+
+  fn compare_document_position(&self, other: &dyn NodeMethods) -> DocumentPosition {
+    self.element.compare_document_position(other)
+  }
+
+  fn normalize(&self, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.normalize(exception_state)
+  }
+
   fn as_node(&self) -> &Node {
     self.element.as_node()
   }
@@ -69,20 +321,28 @@ impl EventTargetMethods for HTMLElement {
                         event_name: &str,
                         callback: EventListenerCallback,
                         options: &AddEventListenerOptions,
-                        exception_state: &ExceptionState) -> Result<(), String> {
+                        exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.element.add_event_listener(event_name, callback, options, exception_state)
   }
 
   fn remove_event_listener(&self,
                            event_name: &str,
                            callback: EventListenerCallback,
-                           exception_state: &ExceptionState) -> Result<(), String> {
+                           exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.element.remove_event_listener(event_name, callback, exception_state)
   }
 
-  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> bool {
+  fn dispatch_event(&self, event: &Event, exception_state: &ExceptionState) -> Result<bool, WebFError> {
     self.element.dispatch_event(event, exception_state)
   }
+
+  fn add_event_listener_with_signal(&self, event_name: &str, callback: EventListenerCallback, options: &AddEventListenerOptions, signal: &AbortSignal, exception_state: &ExceptionState) -> Result<(), WebFError> {
+    self.element.add_event_listener_with_signal(event_name, callback, options, signal, exception_state)
+  }
+
+  fn downgrade(&self) -> WeakEventTarget {
+    self.element.downgrade()
+  }
 }
 
 impl HTMLElementMethods for HTMLElement {
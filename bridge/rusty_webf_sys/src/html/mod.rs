@@ -1,6 +1,10 @@
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
+pub mod canvas_rendering_context_2d;
+pub mod html_collection;
 pub mod html_element;
 
+pub use canvas_rendering_context_2d::*;
+pub use html_collection::*;
 pub use html_element::*;
@@ -1,6 +1,10 @@
 /*
 * Copyright (C) 2022-present The WebF authors. All rights reserved.
 */
+pub mod touch;
 pub mod touch_init;
+pub mod touch_list;
 
+pub use touch::*;
 pub use touch_init::*;
+pub use touch_list::*;
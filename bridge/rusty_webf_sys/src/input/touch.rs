@@ -0,0 +1,147 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct TouchRustMethods {
+  pub version: c_double,
+  pub identifier: extern "C" fn(ptr: *const OpaquePtr) -> i64,
+  pub client_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub client_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub page_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub page_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub screen_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub screen_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub radius_x: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub radius_y: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub force: extern "C" fn(ptr: *const OpaquePtr) -> c_double,
+  pub target: extern "C" fn(ptr: *const OpaquePtr) -> RustValue<EventTargetRustMethods>,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for TouchRustMethods {}
+
+/// A single point of contact on the screen, such as an item of `TouchEvent::touches()`.
+pub struct Touch {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const TouchRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl Touch {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const TouchRustMethods, status: *const RustValueStatus) -> Touch {
+    Touch {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `Touch.identifier` in JavaScript.
+  /// The identifier stays the same for the whole contact, from `touchstart` to `touchend` or `touchcancel`.
+  pub fn identifier(&self) -> i64 {
+    unsafe {
+      ((*self.method_pointer).identifier)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.clientX` in JavaScript.
+  pub fn client_x(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).client_x)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.clientY` in JavaScript.
+  pub fn client_y(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).client_y)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.pageX` in JavaScript.
+  pub fn page_x(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).page_x)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.pageY` in JavaScript.
+  pub fn page_y(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).page_y)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.screenX` in JavaScript.
+  pub fn screen_x(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).screen_x)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.screenY` in JavaScript.
+  pub fn screen_y(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).screen_y)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.radiusX` in JavaScript.
+  pub fn radius_x(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).radius_x)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.radiusY` in JavaScript.
+  pub fn radius_y(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).radius_y)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.force` in JavaScript, from 0.0 to 1.0.
+  pub fn force(&self) -> f64 {
+    unsafe {
+      ((*self.method_pointer).force)(self.ptr())
+    }
+  }
+
+  /// Behavior as same as `Touch.target` in JavaScript, the element the contact started on.
+  /// Returns None when the touch has no target.
+  pub fn target(&self) -> Option<EventTarget> {
+    let value = unsafe {
+      ((*self.method_pointer).target)(self.ptr())
+    };
+    if value.value.is_null() {
+      return None;
+    }
+    Some(EventTarget::initialize(value.value, self.context, value.method_pointer, value.status))
+  }
+}
+
+impl Drop for Touch {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
@@ -0,0 +1,113 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::ffi::*;
+use crate::*;
+
+#[repr(C)]
+pub struct TouchListRustMethods {
+  pub version: c_double,
+  pub length: extern "C" fn(ptr: *const OpaquePtr) -> u32,
+  pub item: extern "C" fn(ptr: *const OpaquePtr, index: u32) -> RustValue<TouchRustMethods>,
+  pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
+}
+
+impl RustMethods for TouchListRustMethods {}
+
+/// A list of touch points, such as the result of `TouchEvent::touches()`.
+pub struct TouchList {
+  pub ptr: *const OpaquePtr,
+  context: *const ExecutingContext,
+  method_pointer: *const TouchListRustMethods,
+  status: *const RustValueStatus,
+}
+
+impl TouchList {
+  pub fn initialize(ptr: *const OpaquePtr, context: *const ExecutingContext, method_pointer: *const TouchListRustMethods, status: *const RustValueStatus) -> TouchList {
+    TouchList {
+      ptr,
+      context,
+      method_pointer,
+      status,
+    }
+  }
+
+  pub fn ptr(&self) -> *const OpaquePtr {
+    self.ptr
+  }
+
+  pub fn context<'a>(&self) -> &'a ExecutingContext {
+    assert!(!self.context.is_null(), "Context PTR must not be null");
+    unsafe { &*self.context }
+  }
+
+  /// Behavior as same as `TouchList.length` in JavaScript.
+  pub fn len(&self) -> usize {
+    let value = unsafe {
+      ((*self.method_pointer).length)(self.ptr())
+    };
+    value as usize
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Behavior as same as `TouchList.item()` in JavaScript.
+  /// Returns None when the index is out of range.
+  pub fn get(&self, index: usize) -> Option<Touch> {
+    if index >= self.len() {
+      return None;
+    }
+    let value = unsafe {
+      ((*self.method_pointer).item)(self.ptr(), index as u32)
+    };
+    if value.value.is_null() {
+      return None;
+    }
+    Some(Touch::initialize(value.value, self.context, value.method_pointer, value.status))
+  }
+
+  pub fn iter(&self) -> TouchListIter<'_> {
+    TouchListIter {
+      touch_list: self,
+      index: 0,
+    }
+  }
+}
+
+impl Drop for TouchList {
+  fn drop(&mut self) {
+    unsafe {
+      if (*self.status).disposed {
+        return;
+      }
+      ((*self.method_pointer).release)(self.ptr());
+    }
+  }
+}
+
+pub struct TouchListIter<'a> {
+  touch_list: &'a TouchList,
+  index: usize,
+}
+
+impl Iterator for TouchListIter<'_> {
+  type Item = Touch;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let touch = self.touch_list.get(self.index)?;
+    self.index += 1;
+    Some(touch)
+  }
+}
+
+impl<'a> IntoIterator for &'a TouchList {
+  type Item = Touch;
+  type IntoIter = TouchListIter<'a>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
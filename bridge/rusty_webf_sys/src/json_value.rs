@@ -0,0 +1,291 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::fmt::Write;
+use crate::WebFError;
+
+/// A parsed JSON document.
+///
+/// Object members keep their source order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<JsonValue>),
+  Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+  /// Behavior as same as `JSON.parse()` in JavaScript, invalid JSON is a `SyntaxError`.
+  /// Arrays and objects nested deeper than 512 levels are a `SyntaxError` as well, instead of overflowing the stack.
+  pub fn parse(input: &str) -> Result<JsonValue, WebFError> {
+    let mut parser = JsonParser {
+      chars: input.chars().collect(),
+      index: 0,
+      depth: 0,
+    };
+    parser.skip_whitespace();
+    let value = parser.parse_value().map_err(WebFError::SyntaxError)?;
+    parser.skip_whitespace();
+    if parser.index < parser.chars.len() {
+      return Err(WebFError::SyntaxError(format!("Unexpected token in JSON at position {}", parser.index)));
+    }
+    Ok(value)
+  }
+
+  /// Returns the member named `key` when this value is an object.
+  pub fn get(&self, key: &str) -> Option<&JsonValue> {
+    match self {
+      JsonValue::Object(members) => members.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+      _ => None,
+    }
+  }
+
+  pub fn is_null(&self) -> bool {
+    matches!(self, JsonValue::Null)
+  }
+
+  pub fn as_bool(&self) -> Option<bool> {
+    match self {
+      JsonValue::Bool(value) => Some(*value),
+      _ => None,
+    }
+  }
+
+  pub fn as_f64(&self) -> Option<f64> {
+    match self {
+      JsonValue::Number(value) => Some(*value),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      JsonValue::String(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+    match self {
+      JsonValue::Array(values) => Some(values),
+      _ => None,
+    }
+  }
+}
+
+/// Encodes `value` as a quoted JSON string literal.
+pub(crate) fn to_json_string_literal(value: &str) -> String {
+  let mut result = String::with_capacity(value.len() + 2);
+  result.push('"');
+  for c in value.chars() {
+    match c {
+      '"' => result.push_str("\\\""),
+      '\\' => result.push_str("\\\\"),
+      '\n' => result.push_str("\\n"),
+      '\r' => result.push_str("\\r"),
+      '\t' => result.push_str("\\t"),
+      c if (c as u32) < 0x20 => {
+        let _ = write!(result, "\\u{:04x}", c as u32);
+      }
+      c => result.push(c),
+    }
+  }
+  result.push('"');
+  result
+}
+
+// Each nested array or object takes a few frames of the recursive parser.
+const MAX_JSON_DEPTH: usize = 512;
+
+struct JsonParser {
+  chars: Vec<char>,
+  index: usize,
+  depth: usize,
+}
+
+impl JsonParser {
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.index).copied()
+  }
+
+  fn next(&mut self) -> Option<char> {
+    let c = self.peek();
+    self.index += 1;
+    c
+  }
+
+  fn error<T>(&self) -> Result<T, String> {
+    match self.peek() {
+      Some(c) => Err(format!("Unexpected token {} in JSON at position {}", c, self.index)),
+      None => Err("Unexpected end of JSON input".to_string()),
+    }
+  }
+
+  fn skip_whitespace(&mut self) {
+    while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+      self.index += 1;
+    }
+  }
+
+  fn expect_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+    for expected in literal.chars() {
+      if self.peek() != Some(expected) {
+        return self.error();
+      }
+      self.index += 1;
+    }
+    Ok(value)
+  }
+
+  fn parse_value(&mut self) -> Result<JsonValue, String> {
+    match self.peek() {
+      Some('n') => self.expect_literal("null", JsonValue::Null),
+      Some('t') => self.expect_literal("true", JsonValue::Bool(true)),
+      Some('f') => self.expect_literal("false", JsonValue::Bool(false)),
+      Some('"') => Ok(JsonValue::String(self.parse_string()?)),
+      Some('[' | '{') => {
+        if self.depth == MAX_JSON_DEPTH {
+          return Err(format!("JSON nested too deeply at position {}", self.index));
+        }
+        self.depth += 1;
+        let value = if self.peek() == Some('[') { self.parse_array() } else { self.parse_object() };
+        self.depth -= 1;
+        value
+      }
+      Some('-' | '0'..='9') => self.parse_number(),
+      _ => self.error(),
+    }
+  }
+
+  fn parse_number(&mut self) -> Result<JsonValue, String> {
+    let start = self.index;
+    while let Some('-' | '+' | '.' | 'e' | 'E' | '0'..='9') = self.peek() {
+      self.index += 1;
+    }
+    let literal: String = self.chars[start..self.index].iter().collect();
+    literal
+      .parse::<f64>()
+      .map(JsonValue::Number)
+      .map_err(|_| format!("Invalid number {} in JSON at position {}", literal, start))
+  }
+
+  fn parse_hex4(&mut self) -> Result<u32, String> {
+    let mut code = 0;
+    for _ in 0..4 {
+      let digit = match self.peek().and_then(|c| c.to_digit(16)) {
+        Some(digit) => digit,
+        None => return self.error(),
+      };
+      code = code * 16 + digit;
+      self.index += 1;
+    }
+    Ok(code)
+  }
+
+  fn parse_string(&mut self) -> Result<String, String> {
+    // Skip the opening quote.
+    self.index += 1;
+    let mut result = String::new();
+    loop {
+      match self.next() {
+        Some('"') => return Ok(result),
+        Some('\\') => {
+          let escaped = match self.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+              let mut code = self.parse_hex4()?;
+              if (0xD800..0xDC00).contains(&code) && self.peek() == Some('\\') && self.chars.get(self.index + 1) == Some(&'u') {
+                let low_start = self.index;
+                self.index += 2;
+                let low = self.parse_hex4()?;
+                if !(0xDC00..0xE000).contains(&low) {
+                  return Err(format!("Invalid low surrogate in JSON at position {}", low_start));
+                }
+                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+              }
+              // A lone surrogate can't be kept in a Rust string.
+              char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            _ => {
+              self.index -= 1;
+              return self.error();
+            }
+          };
+          result.push(escaped);
+        }
+        Some(c) => result.push(c),
+        None => return Err("Unterminated string in JSON".to_string()),
+      }
+    }
+  }
+
+  fn parse_array(&mut self) -> Result<JsonValue, String> {
+    // Skip the opening bracket.
+    self.index += 1;
+    let mut values = Vec::new();
+    self.skip_whitespace();
+    if self.peek() == Some(']') {
+      self.index += 1;
+      return Ok(JsonValue::Array(values));
+    }
+    loop {
+      self.skip_whitespace();
+      values.push(self.parse_value()?);
+      self.skip_whitespace();
+      match self.next() {
+        Some(',') => continue,
+        Some(']') => return Ok(JsonValue::Array(values)),
+        _ => {
+          self.index -= 1;
+          return self.error();
+        }
+      }
+    }
+  }
+
+  fn parse_object(&mut self) -> Result<JsonValue, String> {
+    // Skip the opening brace.
+    self.index += 1;
+    let mut members = Vec::new();
+    self.skip_whitespace();
+    if self.peek() == Some('}') {
+      self.index += 1;
+      return Ok(JsonValue::Object(members));
+    }
+    loop {
+      self.skip_whitespace();
+      if self.peek() != Some('"') {
+        return self.error();
+      }
+      let key = self.parse_string()?;
+      self.skip_whitespace();
+      if self.next() != Some(':') {
+        self.index -= 1;
+        return self.error();
+      }
+      self.skip_whitespace();
+      let value = self.parse_value()?;
+      members.push((key, value));
+      self.skip_whitespace();
+      match self.next() {
+        Some(',') => continue,
+        Some('}') => return Ok(JsonValue::Object(members)),
+        _ => {
+          self.index -= 1;
+          return self.error();
+        }
+      }
+    }
+  }
+}
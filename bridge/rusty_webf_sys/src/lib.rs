@@ -5,14 +5,20 @@
 
 pub mod dom;
 pub mod events;
+pub mod fileapi;
 pub mod frame;
 pub mod html;
 pub mod input;
 
+pub mod context_handle;
+pub mod error;
 pub mod exception_state;
 pub mod executing_context;
+pub mod json_value;
 mod memory_utils;
 pub mod native_value;
+pub mod promise;
+pub mod script_value;
 pub mod script_value_ref;
 pub mod webf_event_listener;
 pub mod webf_function;
@@ -20,18 +26,26 @@ pub mod webf_future;
 
 pub use dom::*;
 pub use events::*;
+pub use fileapi::*;
 pub use frame::*;
 pub use html::*;
 pub use input::*;
 
+pub use context_handle::*;
+pub use error::*;
 pub use exception_state::*;
 pub use executing_context::*;
+pub use json_value::*;
 pub use native_value::*;
+pub use promise::*;
+pub use script_value::*;
 pub use script_value_ref::*;
 pub use webf_event_listener::*;
 pub use webf_function::*;
 pub use webf_future::*;
 
+pub use webf_macros::html;
+
 #[repr(C)]
 pub struct OpaquePtr;
 
@@ -47,8 +61,62 @@ pub struct RustValue<T> {
   pub status: *const RustValueStatus,
 }
 
-pub fn initialize_webf_api(value: RustValue<ExecutingContextRustMethods>, meta_data: *const NativeLibraryMetaData) -> ExecutingContext {
-  ExecutingContext::initialize(value.value, value.method_pointer, meta_data, value.status)
+/// The version of the method tables which this crate is built against, it's the `version` field of every `*RustMethods` struct.
+/// WebF bumps the fraction when methods are appended to the tables, and the integer part when the existing layout changes.
+pub const WEBF_API_VERSION: f64 = 2.0;
+
+// Tables with the same major version and at least the expected fraction have every method this crate reads.
+fn check_api_version(actual: f64) -> Result<(), VersionMismatch> {
+  if actual.trunc() == WEBF_API_VERSION.trunc() && actual >= WEBF_API_VERSION {
+    return Ok(());
+  }
+  Err(VersionMismatch {
+    expected: WEBF_API_VERSION,
+    actual,
+  })
+}
+
+// The nested tables are separated statics in WebF, check the ones reachable from the context before handing it out.
+// The tables outlive the values, so each value is released right after it's fetched, and none leaks when a check fails.
+fn check_nested_api_versions(context: &ExecutingContext) -> Result<(), VersionMismatch> {
+  let methods = context.method_pointer();
+
+  let document = (methods.get_document)(context.ptr);
+  drop(Document::initialize(document.value, context, document.method_pointer, document.status));
+  let document_methods = unsafe { &*document.method_pointer };
+  check_api_version(document_methods.version)?;
+  check_api_version(document_methods.container_node.version)?;
+  check_api_version(document_methods.container_node.node.version)?;
+  check_api_version(document_methods.container_node.node.event_target.version)?;
+
+  let window = (methods.get_window)(context.ptr);
+  drop(Window::initialize(window.value, context, window.method_pointer, window.status));
+  let window_methods = unsafe { &*window.method_pointer };
+  check_api_version(window_methods.version)?;
+  check_api_version(window_methods.event_target.version)?;
+
+  let exception_state = (methods.create_exception_state)();
+  drop(ExceptionState::initialize(exception_state.value, exception_state.method_pointer));
+  check_api_version(unsafe { (*exception_state.method_pointer).version })?;
+
+  check_api_version(unsafe { (*(methods.get_script_value_ref_methods)()).version })?;
+  check_api_version(unsafe { (*(methods.get_script_promise_ref_methods)()).version })?;
+  Ok(())
+}
+
+/// Creates the `ExecutingContext` from the values passed to the entrypoint of the library.
+/// Returns `Err` when the version of the method tables reported by WebF is not compatible with `WEBF_API_VERSION`,
+/// calling any method of them in that case is undefined behavior.
+pub fn initialize_webf_api(value: RustValue<ExecutingContextRustMethods>, meta_data: *const NativeLibraryMetaData) -> Result<ExecutingContext, VersionMismatch> {
+  let actual = if value.method_pointer.is_null() {
+    0.0
+  } else {
+    unsafe { (*value.method_pointer).version }
+  };
+  check_api_version(actual)?;
+  let context = ExecutingContext::initialize(value.value, value.method_pointer, meta_data, value.status);
+  check_nested_api_versions(&context)?;
+  Ok(context)
 }
 
 // This is the entrypoint when your rust app compiled as dynamic library and loaded & executed by WebF.
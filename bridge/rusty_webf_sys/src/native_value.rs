@@ -96,7 +96,8 @@ impl NativeValue {
   }
 
   pub fn new_string(val: &str) -> Self {
-    let len = val.len();
+    // The native string is UTF-16, so its length differs from the UTF-8 length of non-ASCII strings.
+    let len = val.encode_utf16().count();
     let shared_string_ptr = Self::create_string_ptr(val, len);
     let mut value = Self::new();
     value.tag = NativeTag::TagString as i32;
@@ -105,6 +106,16 @@ impl NativeValue {
     value
   }
 
+  /// Creates a value holding a JSON document, which is decoded on the Dart side.
+  pub fn new_json(json: &str) -> Self {
+    let shared_string_ptr = Self::create_string_ptr(json, json.encode_utf16().count());
+    let mut value = Self::new();
+    value.tag = NativeTag::TagJson as i32;
+    value.u.ptr = shared_string_ptr as *mut c_void;
+    value.uint32 = 0;
+    value
+  }
+
   pub fn is_string(&self) -> bool {
     self.tag == NativeTag::TagString as i32
   }
@@ -227,6 +238,28 @@ impl NativeValue {
     values
   }
 
+  pub fn new_u8_bytes(val: &[u8]) -> Self {
+    // Allocate at least one byte so that empty bytes still have a valid pointer.
+    let size = val.len().max(1);
+
+    #[cfg(target_os = "windows")]
+    let ptr = unsafe { CoTaskMemAlloc(size) };
+
+    #[cfg(not(target_os = "windows"))]
+    let ptr = unsafe { libc::malloc(size) };
+
+    let ptr = ptr as *mut u8;
+    unsafe {
+      std::ptr::copy_nonoverlapping(val.as_ptr(), ptr, val.len());
+    }
+
+    let mut value = Self::new();
+    value.tag = NativeTag::TagUint8Bytes as i32;
+    value.u.ptr = ptr as *mut c_void;
+    value.uint32 = val.len() as u32;
+    value
+  }
+
   pub fn is_u8_bytes(&self) -> bool {
     self.tag == NativeTag::TagUint8Bytes as i32
   }
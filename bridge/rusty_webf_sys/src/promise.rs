@@ -0,0 +1,150 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+use std::ffi::*;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use crate::*;
+
+#[repr(C)]
+pub struct ScriptPromiseRefRustMethods {
+  pub version: c_double,
+  pub then: extern "C" fn(script_promise_ref: *const OpaquePtr, callback_context: *const WebFNativeFunctionContext, exception_state: *const OpaquePtr) -> c_void,
+  pub release: extern "C" fn(script_promise_ref: *const OpaquePtr) -> c_void,
+}
+
+/// Values a `Promise` can be fulfilled with.
+pub trait FromPromiseValue: Sized {
+  fn from_promise_value(value: NativeValue) -> Result<Self, WebFError>;
+}
+
+impl FromPromiseValue for () {
+  fn from_promise_value(_value: NativeValue) -> Result<Self, WebFError> {
+    Ok(())
+  }
+}
+
+impl FromPromiseValue for NativeValue {
+  fn from_promise_value(value: NativeValue) -> Result<Self, WebFError> {
+    Ok(value)
+  }
+}
+
+impl FromPromiseValue for String {
+  fn from_promise_value(value: NativeValue) -> Result<Self, WebFError> {
+    if !value.is_string() {
+      return Err(WebFError::TypeError("The promise was not fulfilled with a string".to_string()));
+    }
+    Ok(value.to_string())
+  }
+}
+
+impl FromPromiseValue for bool {
+  fn from_promise_value(value: NativeValue) -> Result<Self, WebFError> {
+    if !value.is_bool() {
+      return Err(WebFError::TypeError("The promise was not fulfilled with a boolean".to_string()));
+    }
+    Ok(value.to_bool())
+  }
+}
+
+impl FromPromiseValue for f64 {
+  fn from_promise_value(value: NativeValue) -> Result<Self, WebFError> {
+    if value.is_float64() {
+      return Ok(value.to_float64());
+    }
+    if value.is_int64() {
+      return Ok(value.to_int64() as f64);
+    }
+    Err(WebFError::TypeError("The promise was not fulfilled with a number".to_string()))
+  }
+}
+
+impl FromPromiseValue for Vec<u8> {
+  fn from_promise_value(value: NativeValue) -> Result<Self, WebFError> {
+    if !value.is_u8_bytes() {
+      return Err(WebFError::TypeError("The promise was not fulfilled with bytes".to_string()));
+    }
+    Ok(value.to_u8_bytes())
+  }
+}
+
+/// A JavaScript promise, awaitable from Rust.
+///
+/// Resolves to `Ok` with the fulfilled value, or to `Err` with the reason when the promise is rejected.
+pub struct Promise<T> {
+  ptr: *const OpaquePtr,
+  method_pointer: *const ScriptPromiseRefRustMethods,
+  future: WebFFuture<NativeValue>,
+  _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromPromiseValue> Promise<T> {
+  pub(crate) fn initialize(ptr: *const OpaquePtr, context: &ExecutingContext, method_pointer: *const ScriptPromiseRefRustMethods) -> Promise<T> {
+    let (future, resolver) = WebFFuture::<NativeValue>::new();
+    let callback: WebFNativeFunction = Box::new(move |argc, argv| {
+      if argc == 1 {
+        let reason = unsafe { (*argv).clone() };
+        resolver.resolve(Err(WebFError::from_exception_message(&reason.to_string())));
+        return NativeValue::new_null();
+      }
+      if argc == 2 {
+        let value = unsafe { (*argv.wrapping_add(1)).clone() };
+        resolver.resolve(Ok(value));
+        return NativeValue::new_null();
+      }
+      println!("Invalid argument count for promise callback");
+      NativeValue::new_null()
+    });
+    let callback_data = Box::new(WebFNativeFunctionContextData {
+      func: callback,
+    });
+    let callback_context_data_ptr = Box::into_raw(callback_data);
+    let callback_context = Box::new(WebFNativeFunctionContext {
+      callback: invoke_webf_native_function,
+      free_ptr: release_webf_native_function,
+      ptr: callback_context_data_ptr,
+    });
+    let callback_context_ptr = Box::into_raw(callback_context);
+
+    let exception_state = context.create_exception_state();
+    unsafe {
+      ((*method_pointer).then)(ptr, callback_context_ptr, exception_state.ptr);
+    }
+
+    let future = if exception_state.has_exception() {
+      let (failed_future, failed_resolver) = WebFFuture::<NativeValue>::new();
+      failed_resolver.resolve(Err(exception_state.to_error(context)));
+      failed_future
+    } else {
+      future
+    };
+
+    Promise {
+      ptr,
+      method_pointer,
+      future,
+      _marker: PhantomData,
+    }
+  }
+}
+
+impl<T: FromPromiseValue> Future for Promise<T> {
+  type Output = Result<T, WebFError>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+    Pin::new(&mut self.get_mut().future)
+      .poll(cx)
+      .map(|result| result.and_then(T::from_promise_value))
+  }
+}
+
+impl<T> Drop for Promise<T> {
+  fn drop(&mut self) {
+    unsafe {
+      ((*self.method_pointer).release)(self.ptr);
+    }
+  }
+}
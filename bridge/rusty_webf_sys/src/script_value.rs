@@ -0,0 +1,280 @@
+/*
+* Copyright (C) 2022-present The WebF authors. All rights reserved.
+*/
+
+use std::collections::HashMap;
+use crate::*;
+
+/// A JavaScript value which can be passed across the bridge, such as the detail of a `CustomEvent`.
+///
+/// Values are transferred as JSON, so only JSON-serializable values can be represented.
+/// Enable the `serde_json` feature to convert from and to `serde_json::Value`,
+/// or the `serde` feature to convert from and to any type implementing `Serialize` and `Deserialize`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptValue {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<ScriptValue>),
+  Object(HashMap<String, ScriptValue>),
+}
+
+impl ScriptValue {
+  /// Parses a JSON document into a `ScriptValue`.
+  pub fn from_json(json: &str) -> Result<ScriptValue, WebFError> {
+    JsonValue::parse(json).map(ScriptValue::from_json_value)
+  }
+
+  /// Serializes the value as JSON. Numbers which are not finite become `null`, the same as `JSON.stringify()`.
+  pub fn to_json(&self) -> String {
+    match self {
+      ScriptValue::Null => "null".to_string(),
+      ScriptValue::Bool(value) => value.to_string(),
+      ScriptValue::Number(value) if value.is_finite() => value.to_string(),
+      ScriptValue::Number(_) => "null".to_string(),
+      ScriptValue::String(value) => to_json_string_literal(value),
+      ScriptValue::Array(values) => {
+        let values = values.iter().map(|value| value.to_json()).collect::<Vec<_>>();
+        format!("[{}]", values.join(","))
+      }
+      ScriptValue::Object(members) => {
+        let members = members.iter()
+          .map(|(key, value)| format!("{}:{}", to_json_string_literal(key), value.to_json()))
+          .collect::<Vec<_>>();
+        format!("{{{}}}", members.join(","))
+      }
+    }
+  }
+
+  // The name of the type used by the messages of failed conversions.
+  fn type_name(&self) -> &'static str {
+    match self {
+      ScriptValue::Null => "null",
+      ScriptValue::Bool(_) => "a boolean",
+      ScriptValue::Number(_) => "a number",
+      ScriptValue::String(_) => "a string",
+      ScriptValue::Array(_) => "an array",
+      ScriptValue::Object(_) => "an object",
+    }
+  }
+
+  fn type_mismatch(&self, expected: &str) -> WebFError {
+    WebFError::TypeMismatch(format!("The value is {}, not {}.", self.type_name(), expected))
+  }
+
+  fn from_json_value(value: JsonValue) -> ScriptValue {
+    match value {
+      JsonValue::Null => ScriptValue::Null,
+      JsonValue::Bool(value) => ScriptValue::Bool(value),
+      JsonValue::Number(value) => ScriptValue::Number(value),
+      JsonValue::String(value) => ScriptValue::String(value),
+      JsonValue::Array(values) => ScriptValue::Array(values.into_iter().map(ScriptValue::from_json_value).collect()),
+      JsonValue::Object(members) => ScriptValue::Object(
+        members.into_iter().map(|(key, value)| (key, ScriptValue::from_json_value(value))).collect()
+      ),
+    }
+  }
+}
+
+impl From<JsonValue> for ScriptValue {
+  fn from(value: JsonValue) -> Self {
+    ScriptValue::from_json_value(value)
+  }
+}
+
+impl From<bool> for ScriptValue {
+  fn from(value: bool) -> Self {
+    ScriptValue::Bool(value)
+  }
+}
+
+impl From<f64> for ScriptValue {
+  fn from(value: f64) -> Self {
+    ScriptValue::Number(value)
+  }
+}
+
+impl From<i32> for ScriptValue {
+  fn from(value: i32) -> Self {
+    ScriptValue::Number(value as f64)
+  }
+}
+
+impl From<&str> for ScriptValue {
+  fn from(value: &str) -> Self {
+    ScriptValue::String(value.to_string())
+  }
+}
+
+impl From<String> for ScriptValue {
+  fn from(value: String) -> Self {
+    ScriptValue::String(value)
+  }
+}
+
+impl<T: Into<ScriptValue>> From<Vec<T>> for ScriptValue {
+  fn from(values: Vec<T>) -> Self {
+    ScriptValue::Array(values.into_iter().map(Into::into).collect())
+  }
+}
+
+impl<T: Into<ScriptValue>> From<HashMap<String, T>> for ScriptValue {
+  fn from(members: HashMap<String, T>) -> Self {
+    ScriptValue::Object(members.into_iter().map(|(key, value)| (key, value.into())).collect())
+  }
+}
+
+/// `None` becomes `null`.
+impl<T: Into<ScriptValue>> From<Option<T>> for ScriptValue {
+  fn from(value: Option<T>) -> Self {
+    value.map_or(ScriptValue::Null, Into::into)
+  }
+}
+
+impl TryFrom<ScriptValue> for bool {
+  type Error = WebFError;
+
+  fn try_from(value: ScriptValue) -> Result<Self, Self::Error> {
+    match value {
+      ScriptValue::Bool(value) => Ok(value),
+      value => Err(value.type_mismatch("a boolean")),
+    }
+  }
+}
+
+impl TryFrom<ScriptValue> for f64 {
+  type Error = WebFError;
+
+  fn try_from(value: ScriptValue) -> Result<Self, Self::Error> {
+    match value {
+      ScriptValue::Number(value) => Ok(value),
+      value => Err(value.type_mismatch("a number")),
+    }
+  }
+}
+
+impl TryFrom<ScriptValue> for String {
+  type Error = WebFError;
+
+  fn try_from(value: ScriptValue) -> Result<Self, Self::Error> {
+    match value {
+      ScriptValue::String(value) => Ok(value),
+      value => Err(value.type_mismatch("a string")),
+    }
+  }
+}
+
+impl<T: TryFrom<ScriptValue, Error = WebFError>> TryFrom<ScriptValue> for Vec<T> {
+  type Error = WebFError;
+
+  fn try_from(value: ScriptValue) -> Result<Self, Self::Error> {
+    match value {
+      ScriptValue::Array(values) => values.into_iter().map(T::try_from).collect(),
+      value => Err(value.type_mismatch("an array")),
+    }
+  }
+}
+
+impl<T: TryFrom<ScriptValue, Error = WebFError>> TryFrom<ScriptValue> for HashMap<String, T> {
+  type Error = WebFError;
+
+  fn try_from(value: ScriptValue) -> Result<Self, Self::Error> {
+    match value {
+      ScriptValue::Object(members) => members.into_iter()
+        .map(|(key, value)| T::try_from(value).map(|value| (key, value)))
+        .collect(),
+      value => Err(value.type_mismatch("an object")),
+    }
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Value> for ScriptValue {
+  fn from(value: serde_json::Value) -> Self {
+    match value {
+      serde_json::Value::Null => ScriptValue::Null,
+      serde_json::Value::Bool(value) => ScriptValue::Bool(value),
+      serde_json::Value::Number(value) => value.as_f64().map_or(ScriptValue::Null, ScriptValue::Number),
+      serde_json::Value::String(value) => ScriptValue::String(value),
+      serde_json::Value::Array(values) => ScriptValue::Array(values.into_iter().map(ScriptValue::from).collect()),
+      serde_json::Value::Object(members) => ScriptValue::Object(
+        members.into_iter().map(|(key, value)| (key, ScriptValue::from(value))).collect()
+      ),
+    }
+  }
+}
+
+/// Numbers which are not finite become `null`, the same as `ScriptValue::to_json()`.
+/// Integral numbers in the safe integer range become JSON integers, so they can be deserialized into integer types.
+#[cfg(feature = "serde_json")]
+impl From<ScriptValue> for serde_json::Value {
+  fn from(value: ScriptValue) -> Self {
+    match value {
+      ScriptValue::Null => serde_json::Value::Null,
+      ScriptValue::Bool(value) => serde_json::Value::Bool(value),
+      ScriptValue::Number(value) => to_json_number(value),
+      ScriptValue::String(value) => serde_json::Value::String(value),
+      ScriptValue::Array(values) => serde_json::Value::Array(values.into_iter().map(serde_json::Value::from).collect()),
+      ScriptValue::Object(members) => serde_json::Value::Object(
+        members.into_iter().map(|(key, value)| (key, serde_json::Value::from(value))).collect()
+      ),
+    }
+  }
+}
+
+// Number.MAX_SAFE_INTEGER in JavaScript, the integers beyond it can't be represented exactly by a number.
+#[cfg(feature = "serde_json")]
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+#[cfg(feature = "serde_json")]
+fn to_json_number(value: f64) -> serde_json::Value {
+  if value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER {
+    return serde_json::Value::Number(serde_json::Number::from(value as i64));
+  }
+  serde_json::Number::from_f64(value).map_or(serde_json::Value::Null, serde_json::Value::Number)
+}
+
+#[cfg(feature = "serde")]
+impl ScriptValue {
+  /// Converts any `Serialize` value into a `ScriptValue`.
+  /// Structs and maps become objects, enums use the externally tagged representation of serde and `None` becomes `null`.
+  /// Returns `Err` with a `RangeError` for integers beyond `Number.MAX_SAFE_INTEGER`, which would change when converted into numbers.
+  pub fn from_serde<T: serde::Serialize>(value: &T) -> Result<ScriptValue, WebFError> {
+    let value = serde_json::to_value(value).map_err(|err| WebFError::TypeError(err.to_string()))?;
+    ScriptValue::from_serde_json_exact(value)
+  }
+
+  /// Converts the value into any `DeserializeOwned` type, the reverse of `ScriptValue::from_serde()`.
+  /// Missing object members deserialize as `None`, the same as `undefined` in JavaScript.
+  /// Integral numbers beyond `Number.MAX_SAFE_INTEGER` are not exact, so deserializing them into integer types returns `Err`.
+  pub fn to_serde<T: serde::de::DeserializeOwned>(&self) -> Result<T, WebFError> {
+    serde_json::from_value(serde_json::Value::from(self.clone())).map_err(|err| WebFError::TypeError(err.to_string()))
+  }
+
+  // The same as `From<serde_json::Value>`, but rejects the integers which can't be represented exactly.
+  fn from_serde_json_exact(value: serde_json::Value) -> Result<ScriptValue, WebFError> {
+    match value {
+      serde_json::Value::Number(number) => {
+        let exact = match (number.as_i64(), number.as_u64()) {
+          (Some(integer), _) => (integer.unsigned_abs() as f64) <= MAX_SAFE_INTEGER,
+          (None, Some(integer)) => (integer as f64) <= MAX_SAFE_INTEGER,
+          (None, None) => true,
+        };
+        if !exact {
+          return Err(WebFError::RangeError(format!("The integer {} can't be represented exactly as a number.", number)));
+        }
+        Ok(ScriptValue::from(serde_json::Value::Number(number)))
+      }
+      serde_json::Value::Array(values) => values.into_iter()
+        .map(ScriptValue::from_serde_json_exact)
+        .collect::<Result<Vec<_>, _>>()
+        .map(ScriptValue::Array),
+      serde_json::Value::Object(members) => members.into_iter()
+        .map(|(key, value)| ScriptValue::from_serde_json_exact(value).map(|value| (key, value)))
+        .collect::<Result<HashMap<_, _>, _>>()
+        .map(ScriptValue::Object),
+      value => Ok(ScriptValue::from(value)),
+    }
+  }
+}
@@ -3,12 +3,15 @@
 */
 use std::ffi::*;
 use crate::*;
+use crate::memory_utils::safe_free_cpp_ptr;
 
 #[repr(C)]
 pub struct ScriptValueRefRustMethods {
+  pub version: c_double,
   pub to_string: extern "C" fn(script_value_ref: *const OpaquePtr, exception_state: *const OpaquePtr) -> *const c_char,
   pub set_as_string: extern "C" fn(script_value_ref: *const OpaquePtr, value: *const c_char, exception_state: *const OpaquePtr),
   pub release: extern "C" fn(script_value_ref: *const OpaquePtr) -> c_void,
+  pub to_json: extern "C" fn(script_value_ref: *const OpaquePtr, exception_state: *const OpaquePtr) -> *mut c_char,
 }
 
 pub struct ScriptValueRef {
@@ -35,12 +38,12 @@ impl ScriptValueRef {
     unsafe { &*self.context }
   }
 
-  pub fn to_string(&self, exception_state: &ExceptionState) -> Result<String, String> {
+  pub fn to_string(&self, exception_state: &ExceptionState) -> Result<String, WebFError> {
     unsafe {
       let c_string = ((*self.method_pointer).to_string)(self.ptr, exception_state.ptr);
 
       if exception_state.has_exception() {
-        return Err(exception_state.stringify(self.context()));
+        return Err(exception_state.to_error(self.context()));
       }
 
       let c_str = CStr::from_ptr(c_string);
@@ -49,18 +52,35 @@ impl ScriptValueRef {
     }
   }
 
-  pub fn set_as_string(&self, value: &str, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn set_as_string(&self, value: &str, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       let c_string = CString::new(value).unwrap();
       ((*self.method_pointer).set_as_string)(self.ptr, c_string.as_ptr(), exception_state.ptr);
 
       if exception_state.has_exception() {
-        return Err(exception_state.stringify(self.context()));
+        return Err(exception_state.to_error(self.context()));
       }
 
       Ok(())
     }
   }
+
+  /// Converts the value into a `ScriptValue`.
+  /// Returns None for `null`, `undefined` and values which can not be serialized as JSON.
+  pub fn to_script_value(&self) -> Option<ScriptValue> {
+    let exception_state = self.context().create_exception_state();
+    let json = unsafe {
+      ((*self.method_pointer).to_json)(self.ptr, exception_state.ptr)
+    };
+
+    if json.is_null() {
+      return None;
+    }
+
+    let json_string = unsafe { CStr::from_ptr(json) }.to_string_lossy().into_owned();
+    safe_free_cpp_ptr(json);
+    ScriptValue::from_json(&json_string).ok()
+  }
 }
 
 impl Drop for ScriptValueRef {
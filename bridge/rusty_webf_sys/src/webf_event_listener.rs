@@ -5,13 +5,24 @@ use std::ffi::*;
 use crate::*;
 
 pub type EventListenerCallback = Box<dyn Fn(&Event)>;
+pub type EventListenerMutCallback = Box<dyn FnMut(&Event)>;
+pub type EventListenerWithHandleCallback = Box<dyn Fn(&Event, &ListenerHandle)>;
+pub type MouseEventListenerCallback = Box<dyn Fn(&MouseEvent)>;
+pub type FocusEventListenerCallback = Box<dyn Fn(&FocusEvent)>;
+pub type PointerEventListenerCallback = Box<dyn Fn(&PointerEvent)>;
+pub type KeyboardEventListenerCallback = Box<dyn Fn(&KeyboardEvent)>;
+pub type InputEventListenerCallback = Box<dyn Fn(&InputEvent)>;
+pub type CompositionEventListenerCallback = Box<dyn Fn(&CompositionEvent)>;
+pub type TouchEventListenerCallback = Box<dyn Fn(&TouchEvent)>;
 
 pub struct EventCallbackContextData {
   pub executing_context_ptr: *const OpaquePtr,
   pub executing_context_method_pointer: *const ExecutingContextRustMethods,
   pub executing_context_meta_data: *const NativeLibraryMetaData,
   pub executing_context_status: *const RustValueStatus,
-  pub func: EventListenerCallback,
+  // Taken after the first invocation of a `once` listener.
+  pub func: Option<EventListenerCallback>,
+  pub once: bool,
 }
 
 impl Drop for EventCallbackContextData {
@@ -29,6 +40,7 @@ pub struct EventCallbackContext {
                               exception_state: *const OpaquePtr) -> *const c_void,
   pub free_ptr: extern "C" fn(event_callback_context_ptr: *const OpaquePtr) -> *const c_void,
   pub ptr: *const EventCallbackContextData,
+  pub listener_id: u32,
 }
 
 // Define the callback function
@@ -43,16 +55,22 @@ pub extern "C" fn invoke_event_listener_callback(
   let event_callback_context = unsafe {
     &(*(event_callback_context_ptr as *mut EventCallbackContext))
   };
-  let callback_context_data = unsafe {
-    &(*(event_callback_context.ptr as *mut EventCallbackContextData))
-  };
+  let callback_context_data = event_callback_context.ptr as *mut EventCallbackContextData;
 
   unsafe {
-    let func = &(*callback_context_data).func;
     let callback_data = &(*callback_context_data);
-    let executing_context = ExecutingContext::initialize(callback_data.executing_context_ptr, callback_data.executing_context_method_pointer, callback_context_data.executing_context_meta_data, callback_data.executing_context_status);
+    let Some(func) = &callback_data.func else {
+      return std::ptr::null();
+    };
+    let executing_context = ExecutingContext::initialize(callback_data.executing_context_ptr, callback_data.executing_context_method_pointer, callback_data.executing_context_meta_data, callback_data.executing_context_status);
     let event = Event::initialize(event_ptr, &executing_context, event_method_pointer, status);
     func(&event);
+
+    // A `once` listener never fires again, drop the closure and everything it captured right away
+    // instead of waiting for the C++ side to release the listener.
+    if (*callback_context_data).once {
+      (*callback_context_data).func = None;
+    }
   }
 
   std::ptr::null()
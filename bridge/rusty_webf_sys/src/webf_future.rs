@@ -5,7 +5,7 @@ use std::pin::Pin;
 use std::rc::Rc;
 use std::task::{Context, Poll, Waker};
 use futures::task;
-use crate::ExecutingContext;
+use crate::{ExecutingContext, WebFError};
 
 type Task = Pin<Box<dyn Future<Output = ()>>>;
 
@@ -61,7 +61,7 @@ pub struct WebFNativeFuture<T> {
 }
 
 struct Inner<T> {
-  result: Option<Result<Option<T>, String>>,
+  result: Option<Result<Option<T>, WebFError>>,
 }
 
 impl<T> WebFNativeFuture<T> {
@@ -73,7 +73,7 @@ impl<T> WebFNativeFuture<T> {
     }
   }
 
-  pub fn set_result(&self, result: Result<Option<T>, String>) {
+  pub fn set_result(&self, result: Result<Option<T>, WebFError>) {
     let mut inner = self.inner.borrow_mut();
     inner.result = Some(result);
   }
@@ -83,7 +83,7 @@ impl<T> Future for WebFNativeFuture<T>
 where
   T: 'static,
 {
-  type Output = Result<Option<T>, String>;
+  type Output = Result<Option<T>, WebFError>;
 
   fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
     let mut inner = self.inner.borrow_mut();
@@ -119,3 +119,79 @@ where
   let exception_state = context.create_exception_state();
   context.add_rust_future_task(runtime_run_task_callback, &exception_state).unwrap();
 }
+
+/// A future resolved by a callback from the engine side.
+///
+/// Unlike `WebFNativeFuture`, dropping a `WebFFuture` before it settles runs its cancel handler,
+/// which lets the pending operation (such as a network request) be aborted.
+pub struct WebFFuture<T> {
+  inner: Rc<RefCell<WebFFutureInner<T>>>,
+}
+
+struct WebFFutureInner<T> {
+  result: Option<Result<T, WebFError>>,
+  settled: bool,
+  cancel_handler: Option<Box<dyn FnOnce()>>,
+}
+
+pub(crate) struct WebFFutureResolver<T> {
+  inner: Rc<RefCell<WebFFutureInner<T>>>,
+}
+
+impl<T> WebFFuture<T> {
+  pub(crate) fn new() -> (WebFFuture<T>, WebFFutureResolver<T>) {
+    let inner = Rc::new(RefCell::new(WebFFutureInner {
+      result: None,
+      settled: false,
+      cancel_handler: None,
+    }));
+    let resolver = WebFFutureResolver {
+      inner: inner.clone(),
+    };
+    (WebFFuture { inner }, resolver)
+  }
+
+  pub(crate) fn set_cancel_handler(&self, handler: Box<dyn FnOnce()>) {
+    self.inner.borrow_mut().cancel_handler = Some(handler);
+  }
+}
+
+impl<T> WebFFutureResolver<T> {
+  pub(crate) fn resolve(&self, result: Result<T, WebFError>) {
+    let mut inner = self.inner.borrow_mut();
+    if inner.settled {
+      return;
+    }
+    inner.settled = true;
+    inner.result = Some(result);
+    inner.cancel_handler = None;
+  }
+}
+
+impl<T> Future for WebFFuture<T> {
+  type Output = Result<T, WebFError>;
+
+  fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Self::Output> {
+    match self.inner.borrow_mut().result.take() {
+      Some(result) => Poll::Ready(result),
+      None => Poll::Pending,
+    }
+  }
+}
+
+impl<T> Drop for WebFFuture<T> {
+  fn drop(&mut self) {
+    let cancel_handler = {
+      let mut inner = self.inner.borrow_mut();
+      if inner.settled {
+        None
+      } else {
+        inner.settled = true;
+        inner.cancel_handler.take()
+      }
+    };
+    if let Some(cancel_handler) = cancel_handler {
+      cancel_handler();
+    }
+  }
+}
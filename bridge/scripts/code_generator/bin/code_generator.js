@@ -183,6 +183,7 @@ const pluginApiList = [
   'dom/events/event_init.d.ts',
   'events/animation_event_init.d.ts',
   'events/close_event_init.d.ts',
+  'events/composition_event_init.d.ts',
   'events/focus_event_init.d.ts',
   'events/gesture_event_init.d.ts',
   'events/hashchange_event_init.d.ts',
@@ -194,6 +195,7 @@ const pluginApiList = [
   'events/transition_event_init.d.ts',
   'input/touch_init.d.ts',
   'events/ui_event_init.d.ts',
+  'events/wheel_event_init.d.ts',
   'dom/events/event.d.ts',
   'dom/events/custom_event.d.ts',
   'events/animation_event.d.ts',
@@ -203,10 +205,29 @@ const pluginApiList = [
   'events/hashchange_event.d.ts',
   'events/input_event.d.ts',
   'events/intersection_change_event.d.ts',
+  'events/keyboard_event.d.ts',
   'events/mouse_event.d.ts',
   'events/pointer_event.d.ts',
   'events/transition_event.d.ts',
   'events/ui_event.d.ts',
+  'events/wheel_event.d.ts',
+  'events/pop_state_event.d.ts',
+  'events/composition_event.d.ts',
+  'events/touch_event.d.ts',
+  'events/storage_event.d.ts',
+];
+
+// Rust bindings of these files are maintained by hand in rusty_webf_sys.
+const rustManualImplementedList = [
+  'events/wheel_event.d.ts',
+  'events/input_event.d.ts',
+  'events/pointer_event.d.ts',
+  'events/gesture_event.d.ts',
+  'events/mouse_event.d.ts',
+  'events/mouse_event_init.d.ts',
+  'events/close_event_init.d.ts',
+  'events/pointer_event_init.d.ts',
+  'events/storage_event.d.ts',
 ];
 
 genCodeFromTypeDefine();
@@ -276,6 +297,7 @@ function genRustCodeFromTypeDefine() {
 
   for (let i = 0; i < blobs.length; i ++) {
     let b = blobs[i];
+    if (rustManualImplementedList.includes(pluginApiList[i])) continue;
     let result = generateRustSource(b);
     const folders = b.source.replace(source, '').replace(b.filename + '.d.ts', '').split(path.sep)
       .filter(f => f !== '').join(path.sep);
@@ -6,7 +6,7 @@ import _ from 'lodash';
 import {ClassObject, FunctionArguments, FunctionArgumentType} from '../declaration';
 import {GenerateOptions, generateSupportedOptions} from '../generator';
 import {ParameterType} from '../analyzer';
-import {getPointerType, isPointerType} from '../generateSource';
+import {getPointerType, isPointerType, isTypeHaveNull} from '../generateSource';
 
 function readHeaderTemplate(name: string) {
   return fs.readFileSync(path.join(__dirname, '../../../templates/idl_templates/plugin_api_templates/' + name + '.h.tpl'), {encoding: 'utf-8'});
@@ -47,10 +47,20 @@ function getClassName(blob: IDLBlob) {
 
 export function isStringType(type: ParameterType): boolean {
   return type.value === FunctionArgumentType.dom_string
-    || type.value === FunctionArgumentType.legacy_dom_string;
+    || type.value === FunctionArgumentType.legacy_dom_string
+    || isNullableStringType(type);
+}
+
+// `string | null`, the getters return nullptr when the value is null.
+export function isNullableStringType(type: ParameterType): boolean {
+  if (type.isArray || !Array.isArray(type.value) || type.value.length !== 2) return false;
+  return isTypeHaveNull(type) && type.value.some(t => isStringType(t));
 }
 
 function generatePublicReturnTypeValue(type: ParameterType, is32Bit: boolean = false): string {
+  if (isNullableStringType(type)) {
+    return is32Bit ? 'const char*' : 'SharedNativeString*';
+  }
   if (isPointerType(type)) {
     const pointerType = getPointerType(type);
     return `WebFValue<${pointerType}, ${pointerType}PublicMethods>`;
@@ -177,6 +187,8 @@ function generatePluginAPIHeaderFile(blob: IDLBlob, options: GenerateOptions) {
     switch(templateKind) {
       case TemplateKind.Interface: {
         object = object as ClassObject;
+        // Static members are class constants and have no per-instance accessor to expose.
+        object.props = object.props.filter(prop => !prop.typeMode?.static);
 
         let dependentTypes = new Set<string>();
 
@@ -282,6 +294,8 @@ function generatePluginAPISourceFile(blob: IDLBlob, options: GenerateOptions) {
     switch(templateKind) {
       case TemplateKind.Interface: {
         object = object as ClassObject;
+        // Static members are class constants and have no per-instance accessor to expose.
+        object.props = object.props.filter(prop => !prop.typeMode?.static);
 
         let dependentTypes = new Set<string>();
 
@@ -328,6 +342,7 @@ function generatePluginAPISourceFile(blob: IDLBlob, options: GenerateOptions) {
           isPointerType,
           getPointerType,
           isStringType,
+          isNullableStringType,
           isAnyType,
           dependentTypes: Array.from(dependentTypes),
           subClasses: _.uniq(subClasses),
@@ -62,7 +62,7 @@ function generateMethodReturnType(type: ParameterType) {
       return 'i64';
     }
     case FunctionArgumentType.any: {
-      return 'ScriptValueRef';
+      return 'Option<ScriptValue>';
     }
     case FunctionArgumentType.double: {
       return 'f64';
@@ -267,6 +267,10 @@ function generateMethodReturnStatements(type: ParameterType) {
       return `let value = unsafe { std::ffi::CStr::from_ptr(value) };
     Ok(value.to_str().unwrap().to_string())`;
     }
+    case FunctionArgumentType.any: {
+      return `let value = ScriptValueRef::initialize(value.value, self.context(), value.method_pointer);
+    Ok(value.to_script_value())`;
+    }
     default:
       return 'Ok(value)';
   }
@@ -287,7 +291,8 @@ function generatePropReturnStatements(type: ParameterType) {
     value.to_str().unwrap().to_string()`;
     }
     case FunctionArgumentType.any: {
-      return `ScriptValueRef::initialize(value.value, self.context(), value.method_pointer)`;
+      return `let value = ScriptValueRef::initialize(value.value, self.context(), value.method_pointer);
+    value.to_script_value()`;
     }
     default:
       return 'value';
@@ -303,6 +308,8 @@ function generateRustSourceFile(blob: IDLBlob, options: GenerateOptions) {
     switch(templateKind) {
       case TemplateKind.Interface: {
         object = object as ClassObject;
+        // Static members are class constants and have no per-instance accessor to expose.
+        object.props = object.props.filter(prop => !prop.typeMode?.static);
 
         const inheritedObjects: ClassObject[] = [];
 
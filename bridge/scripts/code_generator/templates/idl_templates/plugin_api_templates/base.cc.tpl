@@ -15,15 +15,21 @@
 #include "core/dom/events/event_target.h"
 #include "core/events/animation_event.h"
 #include "core/events/close_event.h"
+#include "core/events/composition_event.h"
 #include "core/events/focus_event.h"
 #include "core/events/gesture_event.h"
 #include "core/events/hashchange_event.h"
 #include "core/events/input_event.h"
 #include "core/events/intersection_change_event.h"
+#include "core/events/keyboard_event.h"
 #include "core/events/mouse_event.h"
 #include "core/api/exception_state.h"
 #include "core/events/pointer_event.h"
+#include "core/events/pop_state_event.h"
+#include "core/events/storage_event.h"
+#include "core/events/touch_event.h"
 #include "core/events/transition_event.h"
 #include "core/events/ui_event.h"
+#include "core/events/wheel_event.h"
 
 <%= content %>
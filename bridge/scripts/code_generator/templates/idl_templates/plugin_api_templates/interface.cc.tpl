@@ -10,6 +10,9 @@ namespace webf {
   return WebFValue<ScriptValueRef, ScriptValueRefPublicMethods>{
       new ScriptValueRef{<%= _.snakeCase(className) %>->GetExecutingContext(), <%= _.snakeCase(className) %>-><%= prop.name %>()}, ScriptValueRef::publicMethods(),
       nullptr};
+  <% } else if (isNullableStringType(prop.type)) { %>
+  const AtomicString& value = <%= _.snakeCase(className) %>-><%= prop.name %>();
+  return value.IsNull() ? nullptr : value.ToStringView().Characters8();
   <% } else if (isStringType(prop.type)) { %>
   return <%= _.snakeCase(className) %>-><%= prop.name %>().ToStringView().Characters8();
   <% } else { %>
@@ -23,6 +26,12 @@ void <%= className %>PublicMethods::Set<%= _.startCase(prop.name).replace(/ /g,
   <% } %>
   <% if (isStringType(prop.type)) { %>
 <%= generatePublicReturnTypeValue(prop.type, true) %> <%= className %>PublicMethods::Dup<%= _.startCase(prop.name).replace(/ /g, '') %>(<%= className %>* <%= _.snakeCase(className) %>) {
+  <% if (isNullableStringType(prop.type)) { %>
+  const AtomicString& value = <%= _.snakeCase(className) %>-><%= prop.name %>();
+  if (value.IsNull()) {
+    return nullptr;
+  }
+  <% } %>
   const char* buffer = <%= _.snakeCase(className) %>-><%= prop.name %>().ToStringView().Characters8();
   return strdup(buffer);
 }
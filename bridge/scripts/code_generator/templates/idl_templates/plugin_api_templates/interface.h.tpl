@@ -52,6 +52,9 @@ using Public<%= className %><%= methodName %> = <%= generatePublicReturnTypeValu
 using Public<%= className %>Release = void (*)(<%= className %>*);
 using Public<%= className %>DynamicTo = WebFValue<<%= className %>, WebFPublicMethods> (*)(<%= className %>*, <%= className %>Type);
 <% } %>
+<% if (className === 'Event') { %>
+using PublicEventComposedPath = WebFValue<EventTarget, EventTargetPublicMethods>* (*)(Event*, int64_t*);
+<% } %>
 
 struct <%= className %>PublicMethods : public WebFPublicMethods {
 
@@ -75,7 +78,11 @@ struct <%= className %>PublicMethods : public WebFPublicMethods {
   static void Release(<%= className %>* <%= _.snakeCase(className) %>);
   static WebFValue<<%= className %>, WebFPublicMethods> DynamicTo(<%= className %>* <%= _.snakeCase(className) %>, <%= className %>Type <%= _.snakeCase(className) %>_type);
   <% } %>
-  double version{1.0};
+  <% if (className === 'Event') { %>
+  // Implemented in core/api/event.cc.
+  static WebFValue<EventTarget, EventTargetPublicMethods>* ComposedPath(Event* event, int64_t* length);
+  <% } %>
+  double version{2.0};
 
   <% if (object.parent) { %>
   <%= object.parent %>PublicMethods <%= _.snakeCase(object.parent) %>;
@@ -101,6 +108,9 @@ struct <%= className %>PublicMethods : public WebFPublicMethods {
   Public<%= className %>Release <%= _.snakeCase(className) %>_release{Release};
   Public<%= className %>DynamicTo <%= _.snakeCase(className) %>_dynamic_to{DynamicTo};
   <% } %>
+  <% if (className === 'Event') { %>
+  PublicEventComposedPath event_composed_path{ComposedPath};
+  <% } %>
 };
 
 }  // namespace webf
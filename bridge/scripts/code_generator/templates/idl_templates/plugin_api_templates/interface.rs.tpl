@@ -35,6 +35,9 @@ pub struct <%= className %>RustMethods {
   pub release: extern "C" fn(ptr: *const OpaquePtr) -> c_void,
   pub dynamic_to: extern "C" fn(ptr: *const OpaquePtr, type_: <%= className %>Type) -> RustValue<c_void>,
   <% } %>
+  <% if (className === 'Event') { %>
+  pub composed_path: extern "C" fn(ptr: *const OpaquePtr, length: *mut i64) -> *mut RustValue<EventTargetRustMethods>,
+  <% } %>
 }
 
 <% if (object.parent) { %>
@@ -114,12 +117,12 @@ impl <%= className %> {
     <% } %>
 
     <% if (!prop.readonly) { %>
-  pub fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).set_<%= _.snakeCase(prop.name) %>)(self.ptr(), <%= generateMethodParametersName([{name: 'value', type: prop.type}]) %>exception_state.ptr)
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
@@ -129,28 +132,49 @@ impl <%= className %> {
   <% _.forEach(object.methods, function(method, index) { %>
     <% var methodName = generateValidRustIdentifier(_.snakeCase(method.name)); %>
     <% if (isVoidType(method.returnType)) { %>
-  pub fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), String> {
+  pub fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), WebFError> {
     unsafe {
       ((*self.method_pointer).<%= methodName %>)(self.ptr(), <%= generateMethodParametersName(method.args) %>exception_state.ptr);
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     Ok(())
   }
     <% } else { %>
-  pub fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, String> {
+  pub fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, WebFError> {
     let value = unsafe {
       ((*self.method_pointer).<%= methodName %>)(self.ptr(), <%= generateMethodParametersName(method.args) %>exception_state.ptr)
     };
     if exception_state.has_exception() {
-      return Err(exception_state.stringify(self.context()));
+      return Err(exception_state.to_error(self.context()));
     }
     <%= generateMethodReturnStatements(method.returnType) %>
   }
     <% } %>
   <% }); %>
 
+  <% if (className === 'Event') { %>
+  /// Behavior as same as `Event.composedPath()` in JavaScript.
+  /// Returns the targets the event propagates through, from the target up to the document and the window.
+  /// The path is only available while the event is being dispatched, it's empty otherwise.
+  pub fn composed_path(&self) -> Vec<EventTarget> {
+    let mut length: i64 = 0;
+    let path_ptr = unsafe {
+      ((*self.method_pointer).composed_path)(self.ptr(), &mut length)
+    };
+    if path_ptr.is_null() {
+      return Vec::new();
+    }
+    let path = unsafe { std::slice::from_raw_parts(path_ptr, length as usize) }
+      .iter()
+      .map(|target| EventTarget::initialize(target.value, self.context(), target.method_pointer, target.status))
+      .collect();
+    crate::memory_utils::safe_free_cpp_ptr(path_ptr);
+    path
+  }
+  <% } %>
+
   <% if (!object.parent) { %>
 
     <% _.forEach(subClasses, function (subClass, index) { %>
@@ -173,6 +197,9 @@ impl <%= className %> {
 impl Drop for <%= className %> {
   fn drop(&mut self) {
     unsafe {
+      if (*self.status).disposed {
+        return;
+      }
       ((*self.method_pointer).release)(self.ptr());
     }
   }
@@ -190,18 +217,21 @@ pub trait <%= className %>Methods<%= parentMethodsSuperTrait %> {
     <% } %>
 
     <% if (!prop.readonly) { %>
-  fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), String>;
+  fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), WebFError>;
     <% } %>
   <% }); %>
 
   <% _.forEach(object.methods, function(method, index) { %>
     <% var methodName = generateValidRustIdentifier(_.snakeCase(method.name)); %>
     <% if (isVoidType(method.returnType)) { %>
-  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), String>;
+  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), WebFError>;
     <% } else { %>
-  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, String>;
+  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, WebFError>;
     <% } %>
   <% }); %>
+  <% if (className === 'Event') { %>
+  fn composed_path(&self) -> Vec<EventTarget>;
+  <% } %>
   fn as_<%= _.snakeCase(className) %>(&self) -> &<%= className %>;
 }
 
@@ -219,7 +249,7 @@ impl <%= className %>Methods for <%= className %> {
     <% } %>
 
     <% if (!prop.readonly) { %>
-  fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.set_<%= _.snakeCase(prop.name) %>(value, exception_state)
   }
     <% } %>
@@ -228,15 +258,20 @@ impl <%= className %>Methods for <%= className %> {
   <% _.forEach(object.methods, function(method, index) { %>
     <% var methodName = generateValidRustIdentifier(_.snakeCase(method.name)); %>
     <% if (isVoidType(method.returnType)) { %>
-  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), String> {
+  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.<%= methodName %>(<%= generateParentMethodParametersName(method.args) %>exception_state)
   }
     <% } else { %>
-  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, String> {
+  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, WebFError> {
     self.<%= methodName %>(<%= generateParentMethodParametersName(method.args) %>exception_state)
   }
     <% } %>
   <% }); %>
+  <% if (className === 'Event') { %>
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.composed_path()
+  }
+  <% } %>
   fn as_<%= _.snakeCase(className) %>(&self) -> &<%= className %> {
     self
   }
@@ -259,7 +294,7 @@ impl <%= parentObject.name %>Methods for <%= className %> {
     <% } %>
 
     <% if (!prop.readonly) { %>
-  fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), String> {
+  fn set_<%= _.snakeCase(prop.name) %>(&self, value: <%= generateMethodReturnType(prop.type) %>, exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.<%= parentKey %>.set_<%= _.snakeCase(prop.name) %>(value, exception_state)
   }
     <% } %>
@@ -268,15 +303,20 @@ impl <%= parentObject.name %>Methods for <%= className %> {
   <% _.forEach(parentObject.methods, function(method, index) { %>
     <% var methodName = generateValidRustIdentifier(_.snakeCase(method.name)); %>
     <% if (isVoidType(method.returnType)) { %>
-  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), String> {
+  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<(), WebFError> {
     self.<%= parentKey %>.<%= methodName %>(<%= generateParentMethodParametersName(method.args) %>exception_state)
   }
     <% } else { %>
-  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, String> {
+  fn <%= methodName %>(&self, <%= generateMethodParametersTypeWithName(method.args) %>exception_state: &ExceptionState) -> Result<<%= generateMethodReturnType(method.returnType) %>, WebFError> {
     self.<%= parentKey %>.<%= methodName %>(<%= generateParentMethodParametersName(method.args) %>exception_state)
   }
     <% } %>
   <% }); %>
+  <% if (parentObject.name === 'Event') { %>
+  fn composed_path(&self) -> Vec<EventTarget> {
+    self.<%= parentKey %>.composed_path()
+  }
+  <% } %>
   fn as_<%= _.snakeCase(parentObject.name) %>(&self) -> &<%= parentObject.name %> {
     &self.<%= parentKey %>
   }
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+use webf_sys::{Console, ExecutingContext, ScriptValue};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_console_print_all_levels(context: ExecutingContext) {
+  let console = context.console();
+  let detail = ScriptValue::from(HashMap::from([("count".to_string(), 1)]));
+  assert!(console.log(&["log from rust".into(), detail.clone()]).is_ok());
+  assert!(console.info(&["info from rust".into(), 1.5.into()]).is_ok());
+  assert!(console.warn(&["warn from rust".into(), true.into()]).is_ok());
+  assert!(console.error(&["error from rust".into(), ScriptValue::Null]).is_ok());
+  assert!(console.debug(&["debug from rust".into(), vec![1, 2].into()]).is_ok());
+  assert!(console.log(&[]).is_ok());
+}
+
+#[webf_test]
+pub fn test_console_format(_context: ExecutingContext) {
+  let detail = ScriptValue::from(HashMap::from([("count".to_string(), 1)]));
+  assert_eq!(Console::format(&["log from rust".into(), detail]), "log from rust {\"count\":1}");
+  assert_eq!(Console::format(&["info".into(), 1.5.into(), true.into(), ScriptValue::Null]), "info 1.5 true null");
+  assert_eq!(Console::format(&[vec![1, 2].into()]), "[1,2]");
+  assert_eq!(Console::format(&[]), "");
+}
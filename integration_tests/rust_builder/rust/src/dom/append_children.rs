@@ -0,0 +1,144 @@
+use std::time::Instant;
+use webf_sys::{Element, ExecutingContext, NodeMethods, NodeOrString};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_append_children(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let list = document.create_element("ul", &exception_state).unwrap();
+  let first = document.create_element("li", &exception_state).unwrap();
+  let second = document.create_text_node("second", &exception_state).unwrap();
+  let third = document.create_element("li", &exception_state).unwrap();
+  first.set_text_content("first", &exception_state).unwrap();
+  third.set_text_content("third", &exception_state).unwrap();
+
+  list.append_children(&[&first, &second, &third], &exception_state).unwrap();
+  assert_eq!(list.child_nodes().len(), 3);
+  assert_eq!(list.text_content(&exception_state).unwrap(), Some(String::from("firstsecondthird")));
+
+  list.append_children(&[], &exception_state).unwrap();
+  assert_eq!(list.child_nodes().len(), 3);
+}
+
+#[webf_test]
+pub fn test_append_children_rejects_ancestor(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let parent = document.create_element("div", &exception_state).unwrap();
+  let child = document.create_element("div", &exception_state).unwrap();
+  parent.append_child(child.as_node(), &exception_state).unwrap();
+
+  let sibling = document.create_element("span", &exception_state).unwrap();
+  let result = child.append_children(&[&sibling, &parent], &exception_state);
+  assert!(result.is_err());
+  assert!(child.first_child().is_none());
+}
+
+// Appending 10k nodes with a single batched call must not be slower than appending them one by one.
+#[webf_test]
+pub fn test_append_children_benchmark(context: ExecutingContext) {
+  const NODE_COUNT: usize = 10000;
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+
+  let create_items = || -> Vec<Element> {
+    (0..NODE_COUNT).map(|_| document.create_element("li", &exception_state).unwrap()).collect()
+  };
+
+  let one_by_one_list = document.create_element("ul", &exception_state).unwrap();
+  let items = create_items();
+  let start = Instant::now();
+  for item in &items {
+    one_by_one_list.append_child(item.as_node(), &exception_state).unwrap();
+  }
+  let one_by_one_duration = start.elapsed();
+
+  let batched_list = document.create_element("ul", &exception_state).unwrap();
+  let items = create_items();
+  let start = Instant::now();
+  let nodes: Vec<&dyn NodeMethods> = items.iter().map(|item| item as &dyn NodeMethods).collect();
+  batched_list.append_children(&nodes, &exception_state).unwrap();
+  let batched_duration = start.elapsed();
+
+  assert_eq!(one_by_one_list.child_nodes().len(), NODE_COUNT);
+  assert_eq!(batched_list.child_nodes().len(), NODE_COUNT);
+  assert!(
+    batched_duration <= one_by_one_duration,
+    "append_children took {:?} for {} nodes, append_child took {:?}",
+    batched_duration, NODE_COUNT, one_by_one_duration
+  );
+}
+
+#[webf_test]
+pub fn test_append_children_keeps_order(context: ExecutingContext) {
+  const NODE_COUNT: usize = 100;
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let list = document.create_element("ul", &exception_state).unwrap();
+  let existing = document.create_element("li", &exception_state).unwrap();
+  existing.set_text_content("existing", &exception_state).unwrap();
+  list.append_child(existing.as_node(), &exception_state).unwrap();
+
+  let items: Vec<Element> = (0..NODE_COUNT).map(|index| {
+    let item = document.create_element("li", &exception_state).unwrap();
+    item.set_text_content(&index.to_string(), &exception_state).unwrap();
+    item
+  }).collect();
+  let nodes: Vec<&dyn NodeMethods> = items.iter().map(|item| item as &dyn NodeMethods).collect();
+  list.append_children(&nodes, &exception_state).unwrap();
+
+  let children = list.child_nodes();
+  assert_eq!(children.len(), NODE_COUNT + 1);
+  let texts: Vec<Option<String>> = list.children_iter().map(|child| child.text_content(&exception_state).unwrap()).collect();
+  let mut expected = vec![Some(String::from("existing"))];
+  expected.extend((0..NODE_COUNT).map(|index| Some(index.to_string())));
+  assert_eq!(texts, expected);
+  assert!(items.iter().all(|item| list.contains(item)));
+}
+
+#[webf_test]
+pub fn test_append_and_prepend_nodes_and_strings(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+  span.set_text_content("b", &exception_state).unwrap();
+
+  div.append(&["a".into(), (&span).into(), "c".into()], &exception_state).unwrap();
+  assert_eq!(div.child_nodes().len(), 3);
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("abc")));
+
+  div.prepend(&[NodeOrString::String("0")], &exception_state).unwrap();
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("0abc")));
+  assert_eq!(div.inner_html(&exception_state).unwrap(), "0a<span>b</span>c");
+
+  div.append(&[], &exception_state).unwrap();
+  assert_eq!(div.child_nodes().len(), 4);
+}
+
+#[webf_test]
+pub fn test_before_after_and_replace_with(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let first = document.create_element("i", &exception_state).unwrap();
+  let second = document.create_element("b", &exception_state).unwrap();
+  div.append(&[(&first).into(), (&second).into()], &exception_state).unwrap();
+
+  first.before(&["x".into()], &exception_state).unwrap();
+  first.after(&["y".into(), "z".into()], &exception_state).unwrap();
+  assert_eq!(div.inner_html(&exception_state).unwrap(), "x<i></i>yz<b></b>");
+
+  // The element itself could be one of the items.
+  second.replace_with(&["w".into(), (&second).into()], &exception_state).unwrap();
+  assert_eq!(div.inner_html(&exception_state).unwrap(), "x<i></i>yzw<b></b>");
+  first.replace_with(&["i".into()], &exception_state).unwrap();
+  assert_eq!(div.inner_html(&exception_state).unwrap(), "xiyzw<b></b>");
+  assert!(first.parent_node().is_none());
+
+  // Nothing happens for an element without parent.
+  first.before(&["ignored".into()], &exception_state).unwrap();
+  first.replace_with(&["ignored".into()], &exception_state).unwrap();
+  assert!(first.parent_node().is_none());
+}
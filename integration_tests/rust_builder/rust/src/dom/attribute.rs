@@ -0,0 +1,97 @@
+use webf_sys::{ExecutingContext, NodeMethods, SVG_NAMESPACE_URI, XLINK_NAMESPACE_URI};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_element_attribute_accessors(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+
+  assert!(!div.has_attribute("title", &exception_state).unwrap());
+  assert_eq!(div.get_attribute("title", &exception_state).unwrap(), None);
+
+  div.set_attribute("title", "hello", &exception_state).unwrap();
+  assert!(div.has_attribute("title", &exception_state).unwrap());
+  assert_eq!(div.get_attribute("title", &exception_state).unwrap(), Some(String::from("hello")));
+
+  div.remove_attribute("title", &exception_state).unwrap();
+  assert!(!div.has_attribute("title", &exception_state).unwrap());
+  assert_eq!(div.get_attribute("title", &exception_state).unwrap(), None);
+}
+
+#[webf_test]
+pub fn test_element_empty_attribute_is_distinct_from_missing(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+
+  div.set_attribute("data-empty", "", &exception_state).unwrap();
+  assert_eq!(div.get_attribute("data-empty", &exception_state).unwrap(), Some(String::new()));
+  assert_eq!(div.get_attribute("data-missing", &exception_state).unwrap(), None);
+}
+
+#[webf_test]
+pub fn test_element_tag_name_id_and_class_name(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+
+  assert_eq!(div.tag_name(), "DIV");
+  assert_eq!(div.id(), "");
+  assert_eq!(div.class_name(), "");
+
+  div.set_id("main", &exception_state).unwrap();
+  assert_eq!(div.id(), "main");
+  assert_eq!(div.get_attribute("id", &exception_state).unwrap(), Some(String::from("main")));
+
+  div.set_class_name("box primary", &exception_state).unwrap();
+  assert_eq!(div.class_name(), "box primary");
+  assert!(div.class_list().contains("primary", &exception_state).unwrap());
+
+  div.set_attribute("class", "other", &exception_state).unwrap();
+  assert_eq!(div.class_name(), "other");
+}
+
+#[webf_test]
+pub fn test_set_attribute_ns_on_svg_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let svg = document.create_element_ns(SVG_NAMESPACE_URI, "svg", &exception_state).unwrap();
+  let use_element = document.create_element_ns(SVG_NAMESPACE_URI, "use", &exception_state).unwrap();
+  svg.append_child(use_element.as_node(), &exception_state).unwrap();
+
+  use_element.set_attribute_ns(Some(XLINK_NAMESPACE_URI), "xlink:href", "#shape", &exception_state).unwrap();
+  assert_eq!(use_element.get_attribute("xlink:href", &exception_state).unwrap(), Some("#shape".to_string()));
+
+  let result = use_element.set_attribute_ns(None, "xlink:href", "#shape", &exception_state);
+  assert_eq!(result.unwrap_err().name(), Some("NamespaceError"));
+  let result = use_element.set_attribute_ns(Some(XLINK_NAMESPACE_URI), "xmlns:xlink", XLINK_NAMESPACE_URI, &exception_state);
+  assert_eq!(result.unwrap_err().name(), Some("NamespaceError"));
+}
+
+#[webf_test]
+pub fn test_element_attributes_enumeration(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let source = document.create_element("div", &exception_state).unwrap();
+  assert!(source.attributes().is_empty());
+
+  source.set_attribute("title", "hello", &exception_state).unwrap();
+  source.set_attribute("data-id", "1", &exception_state).unwrap();
+  source.set_attribute("aria-label", "label", &exception_state).unwrap();
+
+  let attributes = source.attributes();
+  assert_eq!(attributes.len(), 3);
+  assert_eq!(attributes.item(0), Some(("aria-label", "label")));
+  assert_eq!(attributes.item(3), None);
+  assert_eq!(attributes.get_named_item("title"), Some("hello"));
+  assert_eq!(attributes.get_named_item("TITLE"), Some("hello"));
+  assert_eq!(attributes.get_named_item("missing"), None);
+
+  let target = document.create_element("span", &exception_state).unwrap();
+  for (name, value) in attributes {
+    target.set_attribute(&name, &value, &exception_state).unwrap();
+  }
+  assert_eq!(target.attributes(), source.attributes());
+  assert_eq!(target.get_attribute("data-id", &exception_state).unwrap(), Some(String::from("1")));
+}
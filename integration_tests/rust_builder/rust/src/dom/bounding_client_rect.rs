@@ -0,0 +1,70 @@
+use webf_sys::{ElementMethods, EventTargetMethods, ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_get_bounding_client_rect_reflects_style(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  style.set_property("width", "100px", None, &exception_state).unwrap();
+  style.set_property("height", "50px", None, &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+
+  let rect = div.get_bounding_client_rect(&exception_state).unwrap();
+  assert_eq!(rect.width, 100.0);
+  assert_eq!(rect.height, 50.0);
+  assert_eq!(rect.right - rect.left, rect.width);
+  assert_eq!(rect.bottom - rect.top, rect.height);
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_offset_geometry_relative_to_offset_parent(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("div", &exception_state).unwrap();
+  let container_style = container.style().unwrap();
+  container_style.set_property("position", "relative", None, &exception_state).unwrap();
+  container_style.set_property("padding", "10px", None, &exception_state).unwrap();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  style.set_property("width", "100px", None, &exception_state).unwrap();
+  style.set_property("height", "50px", None, &exception_state).unwrap();
+  style.set_property("padding", "5px", None, &exception_state).unwrap();
+  style.set_property("border", "2px solid black", None, &exception_state).unwrap();
+  container.append_child(div.as_node(), &exception_state).unwrap();
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  assert_eq!(div.offset_width(&exception_state).unwrap(), 114);
+  assert_eq!(div.offset_height(&exception_state).unwrap(), 64);
+  assert_eq!(div.client_width(&exception_state).unwrap(), 110);
+  assert_eq!(div.client_height(&exception_state).unwrap(), 60);
+  assert_eq!(div.offset_top(&exception_state).unwrap(), 10);
+  assert_eq!(div.offset_left(&exception_state).unwrap(), 10);
+
+  let offset_parent = div.offset_parent(&exception_state).unwrap().unwrap();
+  assert_eq!(offset_parent.as_node().ptr(), container.as_node().ptr());
+  assert!(document.body().offset_parent(&exception_state).unwrap().is_none());
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_offset_and_client_sizes_are_rounded(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  style.set_property("width", "100.6px", None, &exception_state).unwrap();
+  style.set_property("height", "50.4px", None, &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+
+  assert_eq!(div.offset_width(&exception_state).unwrap(), 101);
+  assert_eq!(div.offset_height(&exception_state).unwrap(), 50);
+  assert_eq!(div.client_width(&exception_state).unwrap(), 101);
+  assert_eq!(div.client_height(&exception_state).unwrap(), 50);
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
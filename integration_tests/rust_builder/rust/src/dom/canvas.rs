@@ -0,0 +1,117 @@
+use webf_sys::{ExecutingContext, ImageFormat, NodeMethods, WebFError};
+use webf_test_macros::{webf_test, webf_test_async};
+
+fn pixel_at(pixels: &[u8], width: usize, x: usize, y: usize) -> &[u8] {
+  let offset = (y * width + x) * 4;
+  &pixels[offset..offset + 4]
+}
+
+#[webf_test]
+pub fn test_canvas_context_2d_draws_rects_paths_and_text(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let canvas = document.create_element("canvas", &exception_state).unwrap();
+  document.body().append_child(canvas.as_node(), &exception_state).unwrap();
+
+  let ctx = canvas.get_context_2d(&exception_state).unwrap();
+  ctx.set_fill_style("#ff0000", &exception_state).unwrap();
+  ctx.fill_rect(0.0, 0.0, 50.0, 50.0, &exception_state).unwrap();
+  ctx.stroke_rect(10.0, 10.0, 30.0, 30.0, &exception_state).unwrap();
+  ctx.clear_rect(20.0, 20.0, 10.0, 10.0, &exception_state).unwrap();
+
+  ctx.begin_path(&exception_state).unwrap();
+  ctx.move_to(0.0, 0.0, &exception_state).unwrap();
+  ctx.line_to(100.0, 100.0, &exception_state).unwrap();
+  ctx.arc(50.0, 50.0, 20.0, 0.0, std::f64::consts::PI, false, &exception_state).unwrap();
+  ctx.stroke(&exception_state).unwrap();
+  ctx.fill(&exception_state).unwrap();
+
+  ctx.set_font("16px sans-serif", &exception_state).unwrap();
+  ctx.fill_text("hello", 10.0, 80.0, &exception_state).unwrap();
+}
+
+#[webf_test_async]
+pub async fn test_canvas_context_2d_reads_back_pixels(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let canvas = document.create_element("canvas", &exception_state).unwrap();
+  document.body().append_child(canvas.as_node(), &exception_state).unwrap();
+  let ctx = canvas.get_context_2d(&exception_state).unwrap();
+
+  ctx.set_fill_style("#ff0000", &exception_state).unwrap();
+  ctx.fill_rect(0.0, 0.0, 50.0, 50.0, &exception_state).unwrap();
+  ctx.clear_rect(20.0, 20.0, 10.0, 10.0, &exception_state).unwrap();
+
+  let pixels = ctx.get_image_data(0, 0, 60, 60, &exception_state).await.unwrap().unwrap();
+  assert_eq!(pixels.len(), 60 * 60 * 4);
+  assert_eq!(pixel_at(&pixels, 60, 5, 5), [255, 0, 0, 255]);
+  assert_eq!(pixel_at(&pixels, 60, 25, 25), [0, 0, 0, 0]);
+  assert_eq!(pixel_at(&pixels, 60, 55, 55), [0, 0, 0, 0]);
+
+  let pixels = ctx.get_image_data(45, 45, 10, 10, &exception_state).await.unwrap().unwrap();
+  assert_eq!(pixel_at(&pixels, 10, 0, 0), [255, 0, 0, 255]);
+  assert_eq!(pixel_at(&pixels, 10, 9, 9), [0, 0, 0, 0]);
+
+  let result = ctx.get_image_data(0, 0, 0, 10, &exception_state).await;
+  assert_eq!(result.err().and_then(|error| error.name().map(String::from)), Some(String::from("IndexSizeError")));
+}
+
+#[webf_test]
+pub fn test_canvas_context_2d_rejects_non_canvas_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let result = div.get_context_2d(&exception_state);
+  assert!(matches!(result, Err(WebFError::TypeError(_))));
+}
+
+#[webf_test_async]
+pub async fn test_canvas_context_2d_puts_rgba_pixels(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let canvas = document.create_element("canvas", &exception_state).unwrap();
+  document.body().append_child(canvas.as_node(), &exception_state).unwrap();
+  let ctx = canvas.get_context_2d(&exception_state).unwrap();
+
+  let pixels: Vec<u8> = [255, 0, 0, 255].repeat(4 * 2);
+  ctx.put_image_data(&pixels, 4, 2, 10.0, 10.0, &exception_state).unwrap();
+  let read = ctx.get_image_data(10, 10, 4, 2, &exception_state).await.unwrap().unwrap();
+  assert_eq!(read, pixels);
+  let read = ctx.get_image_data(9, 9, 1, 1, &exception_state).await.unwrap().unwrap();
+  assert_eq!(read, [0, 0, 0, 0]);
+
+  let result = ctx.put_image_data(&pixels, 3, 2, 0.0, 0.0, &exception_state);
+  assert_eq!(result.err().and_then(|error| error.name().map(String::from)), Some(String::from("IndexSizeError")));
+  let result = ctx.put_image_data(&[], 0, 0, 0.0, 0.0, &exception_state);
+  assert!(result.is_err());
+}
+
+#[webf_test_async]
+pub async fn test_canvas_context_2d_draws_image_bytes(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let canvas = document.create_element("canvas", &exception_state).unwrap();
+  document.body().append_child(canvas.as_node(), &exception_state).unwrap();
+  let ctx = canvas.get_context_2d(&exception_state).unwrap();
+
+  // A 1x1 GIF.
+  let gif: [u8; 35] = [
+    0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00, 0xff, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44,
+    0x01, 0x00, 0x3b,
+  ];
+  ctx.draw_image_bytes(&gif, ImageFormat::Gif, 0.0, 0.0, &exception_state).unwrap();
+  ctx.fill_rect(2.0, 2.0, 4.0, 4.0, &exception_state).unwrap();
+
+  // The rect issued after the image is drawn over it once the image is decoded.
+  ctx.draw_image_bytes(&gif, ImageFormat::Gif, 3.0, 3.0, &exception_state).unwrap();
+  ctx.fill_rect(3.0, 3.0, 1.0, 1.0, &exception_state).unwrap();
+  let pixels = ctx.get_image_data(0, 0, 8, 8, &exception_state).await.unwrap().unwrap();
+  assert_eq!(pixel_at(&pixels, 8, 0, 0), [255, 0, 0, 255]);
+  assert_eq!(pixel_at(&pixels, 8, 2, 2), [0, 0, 0, 255]);
+  assert_eq!(pixel_at(&pixels, 8, 3, 3), [0, 0, 0, 255]);
+  assert_eq!(pixel_at(&pixels, 8, 7, 7), [0, 0, 0, 0]);
+
+  let result = ctx.draw_image_bytes(&gif, ImageFormat::Png, 0.0, 0.0, &exception_state);
+  assert_eq!(result.err().and_then(|error| error.name().map(String::from)), Some(String::from("InvalidStateError")));
+}
@@ -0,0 +1,42 @@
+use webf_sys::ExecutingContext;
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_class_list_add_and_remove(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let class_list = div.class_list();
+  assert!(class_list.is_empty());
+
+  class_list.add(&["foo", "bar"], &exception_state).unwrap();
+  assert_eq!(class_list.len(), 2);
+  assert!(class_list.contains("foo", &exception_state).unwrap());
+  assert!(class_list.contains("bar", &exception_state).unwrap());
+
+  class_list.remove(&["foo"], &exception_state).unwrap();
+  assert_eq!(class_list.len(), 1);
+  assert!(!class_list.contains("foo", &exception_state).unwrap());
+}
+
+#[webf_test]
+pub fn test_class_list_toggle(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let class_list = div.class_list();
+
+  assert!(class_list.toggle("active", &exception_state).unwrap());
+  assert!(class_list.contains("active", &exception_state).unwrap());
+  assert!(!class_list.toggle("active", &exception_state).unwrap());
+  assert!(!class_list.contains("active", &exception_state).unwrap());
+}
+
+#[webf_test]
+pub fn test_class_list_rejects_invalid_token(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let result = div.class_list().add(&["has space"], &exception_state);
+  assert!(result.is_err());
+}
@@ -0,0 +1,65 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use webf_sys::{AddEventListenerOptions, EventTargetMethods, ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_clone_node_shallow(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.set_attribute("id", "source", &exception_state).unwrap();
+  div.set_text_content("child", &exception_state).unwrap();
+
+  let clone = div.clone_node(false, &exception_state).unwrap();
+  let clone = clone.event_target.as_element().unwrap();
+  assert_eq!(clone.get_attribute("id", &exception_state).unwrap(), Some(String::from("source")));
+  assert!(clone.first_child().is_none());
+  assert!(clone.parent_node().is_none());
+}
+
+#[webf_test]
+pub fn test_clone_node_deep(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let list = document.create_element("ul", &exception_state).unwrap();
+  list.set_attribute("class", "items", &exception_state).unwrap();
+  list.set_inner_html("<li title=\"first\">first</li><li>second <span>nested</span></li>", &exception_state).unwrap();
+
+  let clone = list.clone_node(true, &exception_state).unwrap();
+  let clone = clone.event_target.as_element().unwrap();
+  assert_eq!(clone.outer_html(&exception_state).unwrap(), list.outer_html(&exception_state).unwrap());
+
+  // The clone does not share nodes with the source.
+  clone.first_child().unwrap().set_text_content("changed", &exception_state).unwrap();
+  assert_eq!(list.text_content(&exception_state).unwrap(), Some(String::from("firstsecond nested")));
+}
+
+#[webf_test]
+pub fn test_clone_node_skips_event_listeners(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_callback = called_times.clone();
+  div.add_event_listener("click", Box::new(move |_| {
+    called_times_in_callback.set(called_times_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  let clone = div.clone_node(true, &exception_state).unwrap();
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  clone.dispatch_event(&click_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 0);
+}
+
+#[webf_test]
+pub fn test_clone_node_rejects_document(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  assert!(document.clone_node(false, &exception_state).is_err());
+}
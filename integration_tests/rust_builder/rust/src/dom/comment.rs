@@ -0,0 +1,33 @@
+use webf_sys::{ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_create_comment_data(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let comment = document.create_comment("placeholder", &exception_state).unwrap();
+  assert_eq!(comment.data(&exception_state).unwrap(), "placeholder");
+
+  comment.set_data("if:visible", &exception_state).unwrap();
+  assert_eq!(comment.data(&exception_state).unwrap(), "if:visible");
+  assert_eq!(comment.text_content(&exception_state).unwrap(), Some(String::from("if:visible")));
+}
+
+#[webf_test]
+pub fn test_comment_as_placeholder(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("div", &exception_state).unwrap();
+  let marker = document.create_comment("slot", &exception_state).unwrap();
+  container.append_child(marker.as_node(), &exception_state).unwrap();
+  assert!(marker.parent_node().is_some());
+  assert_eq!(container.child_nodes().len(), 1);
+
+  let content = document.create_text_node("content", &exception_state).unwrap();
+  container.insert_before(content.as_node(), Some(marker.as_node()), &exception_state).unwrap();
+  assert_eq!(container.text_content(&exception_state).unwrap(), Some(String::from("content")));
+
+  container.remove_child(marker.as_node(), &exception_state).unwrap();
+  assert!(marker.parent_node().is_none());
+  assert_eq!(container.child_nodes().len(), 1);
+}
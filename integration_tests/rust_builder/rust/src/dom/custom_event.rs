@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use webf_sys::{AddEventListenerOptions, CustomEventInit, EventMethods, EventTargetMethods, ExecutingContext, ScriptValue};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_create_custom_event_with_detail(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let received_detail = Rc::new(RefCell::new(None));
+  let received_detail_in_callback = received_detail.clone();
+  div.add_event_listener("custom", Box::new(move |event| {
+    let custom_event = event.as_custom_event().unwrap();
+    *received_detail_in_callback.borrow_mut() = custom_event.detail();
+  }), &options, &exception_state).unwrap();
+
+  let mut detail = HashMap::new();
+  detail.insert("name".to_string(), ScriptValue::String("webf".to_string()));
+  detail.insert("count".to_string(), ScriptValue::Number(2.0));
+  detail.insert("items".to_string(), ScriptValue::Array(vec![ScriptValue::Bool(true), ScriptValue::Null]));
+  let detail = ScriptValue::Object(detail);
+
+  let custom_event = document.create_custom_event("custom", Some(detail.clone()), CustomEventInit {
+    bubbles: true,
+    cancelable: true,
+  }, &exception_state).unwrap();
+  assert!(custom_event.bubbles());
+  assert!(custom_event.cancelable());
+  div.dispatch_event(custom_event.as_event(), &exception_state).unwrap();
+
+  assert_eq!(*received_detail.borrow(), Some(detail));
+}
+
+#[webf_test]
+pub fn test_create_custom_event_without_detail(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let custom_event = document.create_custom_event("custom", None, CustomEventInit::default(), &exception_state).unwrap();
+  assert_eq!(custom_event.type_(), "custom");
+  assert!(!custom_event.bubbles());
+  assert_eq!(custom_event.detail(), None);
+}
@@ -0,0 +1,65 @@
+use webf_sys::ExecutingContext;
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_dataset_get_and_set(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let dataset = div.dataset();
+
+  div.set_attribute("data-user-id", "42", &exception_state).unwrap();
+  assert_eq!(dataset.get("userId"), Some("42".to_string()));
+  assert_eq!(dataset.get("userName"), None);
+
+  dataset.set("userName", "webf", &exception_state).unwrap();
+  assert_eq!(div.get_attribute("data-user-name", &exception_state).unwrap(), Some("webf".to_string()));
+  assert_eq!(dataset.get("userName"), Some("webf".to_string()));
+
+  dataset.set("empty", "", &exception_state).unwrap();
+  assert_eq!(dataset.get("empty"), Some("".to_string()));
+
+  assert!(dataset.set("user-name", "webf", &exception_state).is_err());
+}
+
+#[webf_test]
+pub fn test_dataset_remove(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let dataset = div.dataset();
+
+  dataset.set("userId", "42", &exception_state).unwrap();
+  dataset.remove("userId", &exception_state).unwrap();
+  assert_eq!(dataset.get("userId"), None);
+  assert!(!div.has_attribute("data-user-id", &exception_state).unwrap());
+
+  dataset.remove("missing", &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_dataset_iter(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.set_attribute("class", "card", &exception_state).unwrap();
+  let dataset = div.dataset();
+  assert_eq!(dataset.iter().count(), 0);
+  assert!(dataset.keys().is_empty());
+  // Attributes other than data-* don't belong to the dataset.
+  assert_eq!(dataset.get(""), None);
+
+  div.set_attribute("data-user-id", "42", &exception_state).unwrap();
+  div.set_attribute("data-role", "admin", &exception_state).unwrap();
+  let mut entries: Vec<(String, String)> = dataset.iter().collect();
+  entries.sort();
+  assert_eq!(entries, vec![
+    ("role".to_string(), "admin".to_string()),
+    ("userId".to_string(), "42".to_string()),
+  ]);
+
+  let mut keys = dataset.keys();
+  keys.sort();
+  assert_eq!(keys, vec!["role".to_string(), "userId".to_string()]);
+  assert_eq!((&dataset).into_iter().count(), 2);
+}
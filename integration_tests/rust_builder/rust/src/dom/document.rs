@@ -0,0 +1,46 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use webf_sys::{DocumentReadyState, ExecutingContext, NodeMethods, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_get_element_by_id_with_unknown_id(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let element = document.get_element_by_id("an-id-that-does-not-exist", &exception_state).unwrap();
+  assert!(element.is_none());
+}
+
+#[webf_test]
+pub fn test_get_element_by_id(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.set_attribute("id", "get-element-by-id-target", &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+
+  let element = document.get_element_by_id("get-element-by-id-target", &exception_state).unwrap();
+  assert!(element.is_some());
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test_async]
+pub async fn test_document_on_ready(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+
+  let future: WebFNativeFuture<()> = WebFNativeFuture::new();
+  let future_in_callback = future.clone();
+  document.on_ready(Box::new(move || {
+    future_in_callback.set_result(Ok(Some(())));
+  }), &exception_state).unwrap();
+  future.await.unwrap();
+  assert_ne!(document.ready_state(), DocumentReadyState::Loading);
+
+  // Once the document is ready, the callback is called right away.
+  let called = Rc::new(Cell::new(false));
+  let called_in_callback = called.clone();
+  document.on_ready(Box::new(move || called_in_callback.set(true)), &exception_state).unwrap();
+  assert!(called.get());
+}
@@ -0,0 +1,52 @@
+use webf_sys::{ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_document_fragment_append_moves_children(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let fragment = document.create_document_fragment(&exception_state).unwrap();
+  for text in ["first", "second", "third"] {
+    let item = document.create_element("li", &exception_state).unwrap();
+    item.set_text_content(text, &exception_state).unwrap();
+    fragment.append_child(item.as_node(), &exception_state).unwrap();
+  }
+  assert_eq!(fragment.child_nodes().len(), 3);
+  assert!(fragment.parent_node().is_none());
+
+  let list = document.create_element("ul", &exception_state).unwrap();
+  list.append_child(fragment.as_node(), &exception_state).unwrap();
+  assert_eq!(list.child_nodes().len(), 3);
+  assert_eq!(list.text_content(&exception_state).unwrap(), Some(String::from("firstsecondthird")));
+  assert!(fragment.first_child().is_none());
+  assert!(fragment.child_nodes().is_empty());
+}
+
+#[webf_test]
+pub fn test_document_fragment_insert_before(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let list = document.create_element("div", &exception_state).unwrap();
+  let last = document.create_text_node("last", &exception_state).unwrap();
+  list.append_child(last.as_node(), &exception_state).unwrap();
+
+  let fragment = document.create_document_fragment(&exception_state).unwrap();
+  let first = document.create_text_node("first ", &exception_state).unwrap();
+  let middle = document.create_text_node("middle ", &exception_state).unwrap();
+  fragment.append_children(&[&first, &middle], &exception_state).unwrap();
+
+  list.insert_before(fragment.as_node(), list.first_child().as_ref(), &exception_state).unwrap();
+  assert_eq!(list.text_content(&exception_state).unwrap(), Some(String::from("first middle last")));
+  assert!(fragment.child_nodes().is_empty());
+}
+
+#[webf_test]
+pub fn test_document_fragment_downcast(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let fragment = document.create_document_fragment(&exception_state).unwrap();
+  assert!(fragment.as_node().event_target.as_document_fragment().is_ok());
+
+  let div = document.create_element("div", &exception_state).unwrap();
+  assert!(div.as_node().event_target.as_document_fragment().is_err());
+}
@@ -0,0 +1,45 @@
+use webf_sys::{DocumentPosition, ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_node_contains(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let popup = document.create_element("div", &exception_state).unwrap();
+  let button = document.create_element("button", &exception_state).unwrap();
+  let label = document.create_text_node("close", &exception_state).unwrap();
+  let outside = document.create_element("div", &exception_state).unwrap();
+  popup.append_child(button.as_node(), &exception_state).unwrap();
+  button.append_child(label.as_node(), &exception_state).unwrap();
+
+  assert!(popup.contains(&popup));
+  assert!(popup.contains(&button));
+  assert!(popup.contains(&label));
+  assert!(!button.contains(&popup));
+  assert!(!popup.contains(&outside));
+}
+
+#[webf_test]
+pub fn test_compare_document_position(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let parent = document.create_element("div", &exception_state).unwrap();
+  let first = document.create_element("span", &exception_state).unwrap();
+  let second = document.create_element("span", &exception_state).unwrap();
+  let nested = document.create_text_node("nested", &exception_state).unwrap();
+  parent.append_children(&[&first, &second], &exception_state).unwrap();
+  second.append_child(nested.as_node(), &exception_state).unwrap();
+
+  assert_eq!(first.compare_document_position(&first), DocumentPosition::EQUIVALENT);
+  assert_eq!(first.compare_document_position(&second), DocumentPosition::FOLLOWING);
+  assert_eq!(second.compare_document_position(&first), DocumentPosition::PRECEDING);
+  assert_eq!(first.compare_document_position(&nested), DocumentPosition::FOLLOWING);
+  assert_eq!(parent.compare_document_position(&nested), DocumentPosition::CONTAINED_BY | DocumentPosition::FOLLOWING);
+  assert_eq!(nested.compare_document_position(&parent), DocumentPosition::CONTAINS | DocumentPosition::PRECEDING);
+
+  let detached = document.create_element("div", &exception_state).unwrap();
+  let position = parent.compare_document_position(&detached);
+  assert!(position.contains(DocumentPosition::DISCONNECTED | DocumentPosition::IMPLEMENTATION_SPECIFIC));
+  let reversed = detached.compare_document_position(&parent);
+  assert_ne!(position.contains(DocumentPosition::FOLLOWING), reversed.contains(DocumentPosition::FOLLOWING));
+}
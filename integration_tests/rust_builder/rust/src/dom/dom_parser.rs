@@ -0,0 +1,68 @@
+use webf_sys::{DOMParser, ExecutingContext, MimeType, NodeMethods, WebFError};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_parse_html_from_string(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let parser = DOMParser::new(&context);
+  let document = parser.parse_from_string("<ul><li class=\"item\">one</li><li>two", MimeType::TextHtml, &exception_state).unwrap();
+  let list = document.body().first_child().unwrap();
+  assert_eq!(list.child_nodes().len(), 2);
+  assert_eq!(list.text_content(&exception_state).unwrap(), Some(String::from("onetwo")));
+  let item = list.first_child().unwrap().event_target.as_element().unwrap();
+  assert_eq!(item.get_attribute("class", &exception_state).unwrap(), Some(String::from("item")));
+
+  let empty = parser.parse_from_string("", MimeType::TextHtml, &exception_state).unwrap();
+  assert!(empty.body().first_child().is_none());
+}
+
+#[webf_test]
+pub fn test_parse_xml_from_string(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let parser = DOMParser::new(&context);
+  let input = "<?xml version=\"1.0\"?><!-- feed --><root><item id=\"first\">a &amp; b</item><![CDATA[<raw>]]></root>";
+  let document = parser.parse_from_string(input, MimeType::TextXml, &exception_state).unwrap();
+  assert_eq!(document.child_nodes().len(), 2);
+  let root = document.last_child().unwrap();
+  assert_eq!(root.child_nodes().len(), 2);
+  assert_eq!(root.text_content(&exception_state).unwrap(), Some(String::from("a & b<raw>")));
+  let item = root.first_child().unwrap().event_target.as_element().unwrap();
+  assert_eq!(item.get_attribute("id", &exception_state).unwrap(), Some(String::from("first")));
+}
+
+#[webf_test]
+pub fn test_parse_deeply_nested_xml_from_string(context: ExecutingContext) {
+  let parser = DOMParser::new(&context);
+  let exception_state = context.create_exception_state();
+  let nested = format!("{}{}", "<a>".repeat(256), "</a>".repeat(256));
+  assert!(parser.parse_from_string(&nested, MimeType::TextXml, &exception_state).is_ok());
+
+  let exception_state = context.create_exception_state();
+  let too_deep = format!("{}{}", "<a>".repeat(100_000), "</a>".repeat(100_000));
+  let result = parser.parse_from_string(&too_deep, MimeType::TextXml, &exception_state);
+  assert!(matches!(result, Err(WebFError::SyntaxError(_))));
+}
+
+#[webf_test]
+pub fn test_parse_malformed_xml_from_string(context: ExecutingContext) {
+  let parser = DOMParser::new(&context);
+  for input in ["<root><item></root>", "<root>", "<root a=1/>", "<root>&unknown;</root>", "<a/><b/>", ""] {
+    let exception_state = context.create_exception_state();
+    assert!(parser.parse_from_string(input, MimeType::TextXml, &exception_state).is_err(), "{} should not be parsed", input);
+  }
+}
+
+#[webf_test]
+pub fn test_parse_svg_from_string(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let parser = DOMParser::new(&context);
+  let input = "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 10 10\"><circle r=\"5\"/></svg>";
+  let document = parser.parse_from_string(input, MimeType::ImageSvgXml, &exception_state).unwrap();
+  let svg = document.first_child().unwrap();
+  let circle = svg.first_child().unwrap().event_target.as_element().unwrap();
+  assert_eq!(circle.get_attribute("r", &exception_state).unwrap(), Some(String::from("5")));
+  assert!(circle.first_child().is_none());
+
+  let exception_state = context.create_exception_state();
+  assert!(parser.parse_from_string("<svg><circle></svg>", MimeType::ImageSvgXml, &exception_state).is_err());
+}
@@ -0,0 +1,42 @@
+use webf_sys::{Comment, Document, Element, ExecutingContext, NodeMethods, Text, Window};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_downcast_event_target(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let text = document.create_text_node("hello", &exception_state).unwrap();
+  let comment = document.create_comment("note", &exception_state).unwrap();
+  div.append_child(text.as_node(), &exception_state).unwrap();
+  div.append_child(comment.as_node(), &exception_state).unwrap();
+
+  let first = div.first_child().unwrap();
+  let text = first.event_target.downcast::<Text>().unwrap();
+  assert_eq!(text.data(&exception_state).unwrap(), "hello");
+  assert!(first.event_target.downcast::<Element>().is_none());
+  assert!(first.event_target.downcast::<Comment>().is_none());
+
+  let last = div.last_child().unwrap();
+  let comment = last.event_target.as_comment().unwrap();
+  assert_eq!(comment.data(&exception_state).unwrap(), "note");
+  assert!(last.event_target.downcast::<Text>().is_none());
+
+  let parent = first.parent_node().unwrap();
+  assert_eq!(parent.event_target.downcast::<Element>().unwrap().tag_name(), "DIV");
+  assert!(parent.event_target.downcast::<Window>().is_none());
+  assert!(parent.event_target.downcast::<Document>().is_none());
+}
+
+#[webf_test]
+pub fn test_downcast_window_and_document(context: ExecutingContext) {
+  let window = context.window();
+  assert!(window.event_target.downcast::<Window>().is_some());
+  assert!(window.event_target.downcast::<Element>().is_none());
+
+  let document = context.document();
+  let root = document.document_element();
+  let document_node = root.parent_node().unwrap();
+  assert!(document_node.event_target.downcast::<Document>().is_some());
+  assert!(document_node.event_target.downcast::<Element>().is_none());
+}
@@ -0,0 +1,40 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use webf_sys::{EventTargetMethods, ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_build_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let clicks = Rc::new(Cell::new(0));
+  let clicks_in_handler = clicks.clone();
+
+  let span = document.build_element("span").text("world").build(&exception_state).unwrap();
+  let div = document.build_element("div")
+    .attr("id", "greeting")
+    .attr("class", "base")
+    .class("foo")
+    .class("bar")
+    .text("hello ")
+    .child(span)
+    .on("click", Box::new(move |_| clicks_in_handler.set(clicks_in_handler.get() + 1)))
+    .build(&exception_state)
+    .unwrap();
+
+  assert_eq!(div.get_attribute("id", &exception_state).unwrap(), Some("greeting".to_string()));
+  assert_eq!(div.get_attribute("class", &exception_state).unwrap(), Some("base foo bar".to_string()));
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some("hello world".to_string()));
+  assert_eq!(div.child_nodes().len(), 2);
+
+  let click = document.create_event("click", &exception_state).unwrap();
+  div.dispatch_event(&click, &exception_state).unwrap();
+  assert_eq!(clicks.get(), 1);
+}
+
+#[webf_test]
+pub fn test_build_element_error(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  assert!(document.build_element("div").attr("1invalid", "x").build(&exception_state).is_err());
+}
@@ -0,0 +1,97 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{AddEventListenerOptions, Event, EventMethods, EventTargetMethods, ExecutingContext, NodeMethods, RustValueStatus, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_drop_disposed_event_skips_release(context: ExecutingContext) {
+  let status = RustValueStatus { disposed: true };
+  // Null method pointers would crash if the drop tried to release the disposed C++ object.
+  let event = Event::initialize(std::ptr::null(), &context, std::ptr::null(), &status);
+  drop(event);
+}
+
+#[webf_test]
+pub fn test_gesture_event_fields(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let event = document.create_event("gesturestart", &exception_state).unwrap();
+  let gesture_event = event.as_gesture_event().unwrap();
+
+  assert_eq!(gesture_event.type_(), "gesturestart");
+  assert_eq!(gesture_event.state(), None);
+  assert_eq!(gesture_event.direction(), "");
+  assert_eq!(gesture_event.delta_x(), 0.0);
+  assert_eq!(gesture_event.delta_y(), 0.0);
+  assert_eq!(gesture_event.velocity_x(), 0.0);
+  assert_eq!(gesture_event.velocity_y(), 0.0);
+  assert_eq!(gesture_event.scale(), 0.0);
+  assert_eq!(gesture_event.rotation(), 0.0);
+}
+
+#[webf_test_async]
+pub async fn test_transition_end_event_fields(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.style().unwrap().set_property("width", "100px", None, &exception_state).unwrap();
+  div.style().unwrap().set_property("opacity", "1", None, &exception_state).unwrap();
+  div.style().unwrap().set_property("transition", "opacity 50ms", None, &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+  div.get_bounding_client_rect(&exception_state).unwrap();
+
+  let future: WebFNativeFuture<(String, f64, String)> = WebFNativeFuture::new();
+  let pending_future = Rc::new(RefCell::new(Some(future.clone())));
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+  div.add_event_listener("transitionend", Box::new(move |event| {
+    let transition_event = event.as_transition_event().unwrap();
+    if let Some(future) = pending_future.borrow_mut().take() {
+      future.set_result(Ok(Some((transition_event.property_name(), transition_event.elapsed_time(), transition_event.pseudo_element()))));
+    }
+  }), &options, &exception_state).unwrap();
+
+  div.style().unwrap().set_property("opacity", "0", None, &exception_state).unwrap();
+  let (property_name, elapsed_time, pseudo_element) = future.await.unwrap().unwrap();
+  assert_eq!(property_name, "opacity");
+  assert_eq!(elapsed_time, 0.05);
+  assert_eq!(pseudo_element, "");
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test_async]
+pub async fn test_transition_event_reports_css_property_name(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  style.set_property("width", "100px", None, &exception_state).unwrap();
+  style.set_property("background-color", "red", None, &exception_state).unwrap();
+  style.set_property("transition", "background-color 50ms", None, &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+  div.get_bounding_client_rect(&exception_state).unwrap();
+
+  let future: WebFNativeFuture<String> = WebFNativeFuture::new();
+  let pending_future = Rc::new(RefCell::new(Some(future.clone())));
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+  div.add_event_listener("transitionend", Box::new(move |event| {
+    let transition_event = event.as_transition_event().unwrap();
+    if let Some(future) = pending_future.borrow_mut().take() {
+      future.set_result(Ok(Some(transition_event.property_name())));
+    }
+  }), &options, &exception_state).unwrap();
+
+  style.set_property("background-color", "blue", None, &exception_state).unwrap();
+  let property_name = future.await.unwrap().unwrap();
+  assert_eq!(property_name, "background-color");
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
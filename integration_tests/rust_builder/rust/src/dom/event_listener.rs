@@ -0,0 +1,713 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use webf_sys::{AbortController, AddEventListenerOptions, CustomEventInit, Element, EventMethods, EventPhase, EventTargetMethods, ExecutingContext, NodeMethods, WebFError};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_add_mouse_event_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_callback = called_times.clone();
+  div.add_mouse_event_listener("click", Box::new(move |event| {
+    assert_eq!(event.client_x(), 0.0);
+    called_times_in_callback.set(called_times_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  div.dispatch_event(&click_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 1);
+}
+
+#[webf_test]
+pub fn test_dispatch_event_returns_false_when_default_prevented(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+  let init = CustomEventInit {
+    bubbles: false,
+    cancelable: true,
+  };
+
+  let event = document.create_custom_event("submit", None, init, &exception_state).unwrap();
+  assert!(div.dispatch_event(event.as_event(), &exception_state).unwrap());
+
+  div.add_event_listener("submit", Box::new(|event| {
+    let exception_state = event.context().create_exception_state();
+    event.prevent_default(&exception_state).unwrap();
+  }), &options, &exception_state).unwrap();
+  let event = document.create_custom_event("submit", None, init, &exception_state).unwrap();
+  assert!(!div.dispatch_event(event.as_event(), &exception_state).unwrap());
+  assert!(event.default_prevented());
+}
+
+#[webf_test]
+pub fn test_once_listener_drops_callback_after_first_dispatch(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 1,
+    capture: 0,
+  };
+
+  let call_count = Rc::new(Cell::new(0));
+  let call_count_in_callback = call_count.clone();
+  div.add_event_listener("custom_click", Box::new(move |_| {
+    call_count_in_callback.set(call_count_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+  assert_eq!(Rc::strong_count(&call_count), 2);
+
+  let event = document.create_event("custom_click", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(call_count.get(), 1);
+  assert_eq!(Rc::strong_count(&call_count), 1);
+
+  let event = document.create_event("custom_click", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(call_count.get(), 1);
+}
+
+#[webf_test]
+pub fn test_prevent_default_in_passive_listener_returns_err(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 1,
+    once: 0,
+    capture: 0,
+  };
+
+  let prevent_default_result = Rc::new(RefCell::new(None));
+  let prevent_default_result_in_callback = prevent_default_result.clone();
+  div.add_event_listener("wheel", Box::new(move |event| {
+    let exception_state = event.context().create_exception_state();
+    *prevent_default_result_in_callback.borrow_mut() = Some(event.prevent_default(&exception_state));
+  }), &options, &exception_state).unwrap();
+
+  let wheel_event = document.create_custom_event("wheel", None, CustomEventInit {
+    bubbles: true,
+    cancelable: true,
+  }, &exception_state).unwrap();
+  assert!(div.dispatch_event(wheel_event.as_event(), &exception_state).unwrap());
+  let Some(Err(err)) = prevent_default_result.borrow_mut().take() else {
+    panic!("preventDefault in a passive listener should fail");
+  };
+  assert_eq!(err, WebFError::dom_exception("NotAllowedError", "Unable to preventDefault inside passive event listener invocation."));
+  assert!(!wheel_event.default_prevented());
+}
+
+#[webf_test]
+pub fn test_typed_event_listener_ignores_other_event_types(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_callback = called_times.clone();
+  div.add_pointer_event_listener("custom", Box::new(move |_| {
+    called_times_in_callback.set(called_times_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  let custom_event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&custom_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 0);
+}
+
+#[webf_test]
+pub fn test_listener_guard_removes_listener_on_drop(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_first = called_times.clone();
+  let called_times_in_second = called_times.clone();
+  let event_target = &div.as_node().event_target;
+  let first_guard = event_target.add_event_listener_guarded("custom", Box::new(move |_| {
+    called_times_in_first.set(called_times_in_first.get() + 1);
+  }), &options, &exception_state).unwrap();
+  let second_guard = event_target.add_event_listener_guarded("custom", Box::new(move |_| {
+    called_times_in_second.set(called_times_in_second.get() + 1);
+  }), &options, &exception_state).unwrap();
+  assert_eq!(first_guard.event_name(), "custom");
+
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 2);
+
+  drop(first_guard);
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 3);
+
+  drop(second_guard);
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 3);
+}
+
+#[webf_test]
+pub fn test_remove_event_listener_by_key(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let event_target = &div.as_node().event_target;
+  let keys = (0..2).map(|_| {
+    let called_times_in_callback = called_times.clone();
+    event_target.add_event_listener_keyed("custom", Box::new(move |_| {
+      called_times_in_callback.set(called_times_in_callback.get() + 1);
+    }), &options, &exception_state).unwrap()
+  }).collect::<Vec<_>>();
+  assert_eq!(keys[0].event_name(), "custom");
+  assert_ne!(keys[0], keys[1]);
+
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 2);
+
+  event_target.remove_event_listener_by_key(&keys[0], &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 3);
+
+  // Removing the same key again leaves the other listener registered.
+  event_target.remove_event_listener_by_key(&keys[0], &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 4);
+
+  event_target.remove_event_listener_by_key(&keys[1], &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 4);
+}
+
+#[webf_test]
+pub fn test_listener_removes_itself_with_handle(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let self_removing_times = Rc::new(Cell::new(0));
+  let later_times = Rc::new(Cell::new(0));
+  let self_removing_times_in_callback = self_removing_times.clone();
+  let later_times_in_callback = later_times.clone();
+  let event_target = &div.as_node().event_target;
+  event_target.add_event_listener_with_handle("custom", Box::new(move |_, handle| {
+    self_removing_times_in_callback.set(self_removing_times_in_callback.get() + 1);
+    assert_eq!(handle.event_name(), "custom");
+    handle.remove().unwrap();
+    // Removing it twice does nothing.
+    handle.remove().unwrap();
+  }), &options, &exception_state).unwrap();
+  event_target.add_event_listener("custom", Box::new(move |_| {
+    later_times_in_callback.set(later_times_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  // The listener registered after the removed one is still invoked by the same dispatch.
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(self_removing_times.get(), 1);
+  assert_eq!(later_times.get(), 1);
+
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(self_removing_times.get(), 1);
+  assert_eq!(later_times.get(), 2);
+}
+
+#[webf_test]
+pub fn test_listener_count_follows_add_and_remove(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let event_target = &div.as_node().event_target;
+  assert_eq!(event_target.listener_count("custom").unwrap(), 0);
+  assert!(!event_target.has_listener("custom").unwrap());
+
+  div.add_event_listener("custom", Box::new(|_| {}), &options, &exception_state).unwrap();
+  let guard = event_target.add_event_listener_guarded("custom", Box::new(|_| {}), &options, &exception_state).unwrap();
+  assert_eq!(event_target.listener_count("custom").unwrap(), 2);
+  assert!(event_target.has_listener("custom").unwrap());
+  assert!(!event_target.has_listener("other").unwrap());
+
+  drop(guard);
+  assert_eq!(event_target.listener_count("custom").unwrap(), 1);
+
+  div.remove_event_listener("custom", Box::new(|_| {}), &exception_state).unwrap();
+  assert_eq!(event_target.listener_count("custom").unwrap(), 0);
+  assert!(!event_target.has_listener("custom").unwrap());
+}
+
+#[webf_test]
+pub fn test_context_handle_in_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let handle = context.handle();
+  let handle_in_listener = handle.clone();
+  assert!(handle.ptr_eq(&handle_in_listener));
+  assert!(!handle.is_disposed());
+
+  let created = Rc::new(Cell::new(0));
+  let created_in_listener = created.clone();
+  div.add_event_listener("custom", Box::new(move |_| {
+    let context = handle_in_listener.get();
+    let exception_state = context.create_exception_state();
+    let span = context.document().create_element("span", &exception_state).unwrap();
+    assert_eq!(span.tag_name(), "SPAN");
+    created_in_listener.set(created_in_listener.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  let event = handle.document().create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(created.get(), 2);
+}
+
+#[webf_test]
+pub fn test_weak_event_target_in_own_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let weak_div = div.as_node().event_target.downgrade();
+  assert!(!weak_div.is_dropped());
+  let listener_count = Rc::new(Cell::new(0));
+  let listener_count_in_listener = listener_count.clone();
+  div.add_event_listener("custom", Box::new(move |_| {
+    let Some(div) = weak_div.upgrade() else {
+      return;
+    };
+    listener_count_in_listener.set(div.listener_count("custom").unwrap());
+  }), &options, &exception_state).unwrap();
+
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(listener_count.get(), 1);
+
+  let weak_div = div.as_node().event_target.downgrade();
+  let upgraded = weak_div.upgrade().unwrap();
+  assert!(upgraded.downcast::<Element>().is_some());
+}
+
+#[webf_test]
+pub fn test_weak_event_target_is_dropped_after_gc(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let weak_div_in_listener = div.downgrade();
+  div.add_event_listener("custom", Box::new(move |_| {
+    assert!(weak_div_in_listener.upgrade().is_some());
+  }), &options, &exception_state).unwrap();
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  drop(event);
+
+  let weak_div = div.downgrade();
+  context.run_gc();
+  assert!(weak_div.upgrade().is_some());
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+  drop(div);
+  context.run_gc();
+  assert!(weak_div.is_dropped());
+  assert!(weak_div.upgrade().is_none());
+}
+
+#[webf_test]
+pub fn test_capture_listeners_run_before_bubble_listeners(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let outer = document.create_element("div", &exception_state).unwrap();
+  let inner = document.create_element("div", &exception_state).unwrap();
+  outer.append_child(inner.as_node(), &exception_state).unwrap();
+  document.body().append_child(outer.as_node(), &exception_state).unwrap();
+
+  let calls = Rc::new(RefCell::new(Vec::new()));
+  let listeners = [("outer", &outer, 1), ("outer", &outer, 0), ("inner", &inner, 1), ("inner", &inner, 0)];
+  for (name, element, capture) in listeners {
+    let options = AddEventListenerOptions {
+      passive: 0,
+      once: 0,
+      capture,
+    };
+    let calls_in_callback = calls.clone();
+    element.add_event_listener("custom", Box::new(move |event| {
+      calls_in_callback.borrow_mut().push((name, capture != 0, event.event_phase()));
+    }), &options, &exception_state).unwrap();
+  }
+
+  let event = document.create_custom_event("custom", None, CustomEventInit {
+    bubbles: true,
+    cancelable: false,
+  }, &exception_state).unwrap();
+  assert_eq!(event.event_phase(), EventPhase::None);
+  inner.dispatch_event(event.as_event(), &exception_state).unwrap();
+  assert_eq!(event.event_phase(), EventPhase::None);
+
+  assert_eq!(*calls.borrow(), vec![
+    ("outer", true, EventPhase::Capturing),
+    ("inner", true, EventPhase::AtTarget),
+    ("inner", false, EventPhase::AtTarget),
+    ("outer", false, EventPhase::Bubbling),
+  ]);
+
+  document.body().remove_child(outer.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_composed_path_during_dispatch(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let outer = document.create_element("div", &exception_state).unwrap();
+  let inner = document.create_element("div", &exception_state).unwrap();
+  outer.append_child(inner.as_node(), &exception_state).unwrap();
+  document.body().append_child(outer.as_node(), &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let path = Rc::new(RefCell::new(Vec::new()));
+  let path_in_callback = path.clone();
+  outer.add_event_listener("custom", Box::new(move |event| {
+    *path_in_callback.borrow_mut() = event.composed_path().iter().map(|target| target.ptr).collect();
+  }), &options, &exception_state).unwrap();
+
+  let event = document.create_custom_event("custom", None, CustomEventInit {
+    bubbles: true,
+    cancelable: false,
+  }, &exception_state).unwrap();
+  inner.dispatch_event(event.as_event(), &exception_state).unwrap();
+
+  let path = path.borrow();
+  assert_eq!(path.len(), 6);
+  assert_eq!(path[0], inner.ptr());
+  assert_eq!(path[1], outer.ptr());
+  assert_eq!(path[2], document.body().ptr());
+  assert_eq!(path[4], document.ptr());
+  assert_eq!(path[5], context.window().event_target.ptr);
+  assert!(event.composed_path().is_empty());
+
+  document.body().remove_child(outer.as_node(), &exception_state).unwrap();
+}
+
+// The path is built once when the dispatch starts, detaching the target does not change it.
+#[webf_test]
+pub fn test_composed_path_is_kept_when_the_tree_changes(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let outer = document.create_element("div", &exception_state).unwrap();
+  let inner = document.create_element("div", &exception_state).unwrap();
+  outer.append_child(inner.as_node(), &exception_state).unwrap();
+  document.body().append_child(outer.as_node(), &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  inner.add_event_listener("custom", Box::new(|event| {
+    let context = event.context();
+    let exception_state = context.create_exception_state();
+    let outer = event.target().as_node().unwrap().parent_node().unwrap();
+    context.document().body().remove_child(&outer, &exception_state).unwrap();
+  }), &options, &exception_state).unwrap();
+  let path = Rc::new(RefCell::new(Vec::new()));
+  let path_in_callback = path.clone();
+  outer.add_event_listener("custom", Box::new(move |event| {
+    *path_in_callback.borrow_mut() = event.composed_path().iter().map(|target| target.ptr).collect();
+  }), &options, &exception_state).unwrap();
+
+  let event = document.create_custom_event("custom", None, CustomEventInit {
+    bubbles: true,
+    cancelable: false,
+  }, &exception_state).unwrap();
+  inner.dispatch_event(event.as_event(), &exception_state).unwrap();
+
+  let path = path.borrow();
+  assert_eq!(path.len(), 6);
+  assert_eq!(path[1], outer.ptr());
+  assert_eq!(path[2], document.body().ptr());
+  assert_eq!(path[5], context.window().event_target.ptr);
+  assert!(outer.parent_node().is_none());
+}
+
+#[webf_test]
+pub fn test_abort_controller_removes_listeners(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+  let controller = AbortController::new();
+  let signal = controller.signal();
+
+  let called_times = Rc::new(Cell::new(0));
+  let event_target = &div.as_node().event_target;
+  for event_name in ["first", "second"] {
+    let called_times_in_callback = called_times.clone();
+    event_target.add_event_listener_with_signal(event_name, Box::new(move |_| {
+      called_times_in_callback.set(called_times_in_callback.get() + 1);
+    }), &options, &signal, &exception_state).unwrap();
+  }
+
+  let first_event = document.create_event("first", &exception_state).unwrap();
+  let second_event = document.create_event("second", &exception_state).unwrap();
+  div.dispatch_event(&first_event, &exception_state).unwrap();
+  div.dispatch_event(&second_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 2);
+
+  controller.abort(&exception_state).unwrap();
+  assert!(signal.aborted());
+  div.dispatch_event(&first_event, &exception_state).unwrap();
+  div.dispatch_event(&second_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 2);
+
+  let called_times_in_callback = called_times.clone();
+  event_target.add_event_listener_with_signal("first", Box::new(move |_| {
+    called_times_in_callback.set(called_times_in_callback.get() + 1);
+  }), &options, &signal, &exception_state).unwrap();
+  div.dispatch_event(&first_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 2);
+}
+
+#[webf_test]
+pub fn test_dropped_abort_controller_keeps_listeners(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  {
+    let controller = AbortController::new();
+    let called_times_in_callback = called_times.clone();
+    div.add_event_listener_with_signal("custom", Box::new(move |_| {
+      called_times_in_callback.set(called_times_in_callback.get() + 1);
+    }), &options, &controller.signal(), &exception_state).unwrap();
+  }
+
+  let event = document.create_event("custom", &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  div.dispatch_event(&event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 2);
+}
+
+#[webf_test]
+pub fn test_as_keyboard_event_rejects_plain_event(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let keydown_event = document.create_event("keydown", &exception_state).unwrap();
+  assert!(keydown_event.as_keyboard_event().is_err());
+
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  assert!(click_event.as_keyboard_event().is_err());
+  assert!(click_event.as_mouse_event().is_ok());
+}
+
+#[webf_test]
+pub fn test_as_wheel_event_rejects_mouse_event(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  assert!(click_event.as_wheel_event().is_err());
+}
+
+#[webf_test]
+pub fn test_add_event_listener_mut_keeps_state(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let clicks = Rc::new(Cell::new(0));
+  let clicks_in_callback = clicks.clone();
+  let mut counter = 0;
+  div.add_event_listener_mut("click", Box::new(move |_event| {
+    counter += 1;
+    clicks_in_callback.set(counter);
+  }), &options, &exception_state).unwrap();
+
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  div.dispatch_event(&click_event, &exception_state).unwrap();
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  div.dispatch_event(&click_event, &exception_state).unwrap();
+  assert_eq!(clicks.get(), 2);
+}
+
+#[webf_test]
+pub fn test_add_input_event_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let input = document.create_element("input", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_callback = called_times.clone();
+  input.add_input_event_listener("input", Box::new(move |event| {
+    assert_eq!(event.input_type(), "");
+    assert_eq!(event.data(), None);
+    assert!(!event.is_composing());
+    assert!(event.data_transfer().is_none());
+    called_times_in_callback.set(called_times_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  let input_event = document.create_event("input", &exception_state).unwrap();
+  input.dispatch_event(&input_event, &exception_state).unwrap();
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  input.dispatch_event(&click_event, &exception_state).unwrap();
+  assert_eq!(called_times.get(), 1);
+}
+
+#[webf_test]
+pub fn test_add_composition_event_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let input = document.create_element("input", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_callback = called_times.clone();
+  for event_type in ["compositionstart", "compositionupdate", "compositionend"] {
+    let called_times_in_callback = called_times_in_callback.clone();
+    input.add_composition_event_listener(event_type, Box::new(move |event| {
+      assert_eq!(event.data(), "");
+      called_times_in_callback.set(called_times_in_callback.get() + 1);
+    }), &options, &exception_state).unwrap();
+  }
+
+  for event_type in ["compositionstart", "compositionupdate", "compositionend"] {
+    let composition_event = document.create_event(event_type, &exception_state).unwrap();
+    assert!(composition_event.as_input_event().is_err());
+    input.dispatch_event(&composition_event, &exception_state).unwrap();
+  }
+  assert_eq!(called_times.get(), 3);
+}
+
+#[webf_test]
+pub fn test_add_touch_event_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let called_times = Rc::new(Cell::new(0));
+  let called_times_in_callback = called_times.clone();
+  div.add_touch_event_listener("touchstart", Box::new(move |event| {
+    assert!(event.touches().is_empty());
+    assert!(event.target_touches().is_empty());
+    assert!(event.changed_touches().is_empty());
+    assert!(event.changed_touches().get(0).is_none());
+    assert_eq!(event.changed_touches().iter().count(), 0);
+    assert!(!event.alt_key());
+    assert!(!event.ctrl_key());
+    assert!(!event.shift_key());
+    assert!(!event.meta_key());
+    called_times_in_callback.set(called_times_in_callback.get() + 1);
+  }), &options, &exception_state).unwrap();
+
+  let touch_event = document.create_event("touchstart", &exception_state).unwrap();
+  assert!(touch_event.as_touch_event().is_ok());
+  assert!(touch_event.as_mouse_event().is_err());
+  div.dispatch_event(&touch_event, &exception_state).unwrap();
+
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  assert!(click_event.as_touch_event().is_err());
+  div.add_touch_event_listener("click", Box::new(|_| {
+    panic!("The callback should not be invoked by a MouseEvent.");
+  }), &options, &exception_state).unwrap();
+  div.dispatch_event(&click_event, &exception_state).unwrap();
+
+  assert_eq!(called_times.get(), 1);
+}
+
+#[webf_test]
+pub fn test_as_pointer_event_rejects_mouse_event(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let click_event = document.create_event("click", &exception_state).unwrap();
+  assert!(click_event.as_pointer_event().is_err());
+  assert!(click_event.as_mouse_event().is_ok());
+}
@@ -0,0 +1,36 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use webf_sys::{AddEventListenerOptions, EventTargetMethods, ExecutingContext, WebFError};
+use webf_test_macros::webf_test_async;
+
+#[webf_test_async]
+pub async fn test_request_fullscreen_rejects_disconnected_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let error_event_fired = Rc::new(Cell::new(false));
+  let error_event_fired_in_callback = error_event_fired.clone();
+  div.add_event_listener("fullscreenerror", Box::new(move |_| {
+    error_event_fired_in_callback.set(true);
+  }), &options, &exception_state).unwrap();
+
+  let result = div.request_fullscreen(&exception_state).unwrap().await;
+  assert!(matches!(result, Err(WebFError::TypeError(_))));
+  assert!(error_event_fired.get());
+  assert!(document.fullscreen_element().is_none());
+}
+
+#[webf_test_async]
+pub async fn test_exit_fullscreen_rejects_without_fullscreen_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  assert!(document.fullscreen_element().is_none());
+  let result = document.exit_fullscreen(&exception_state).unwrap().await;
+  assert!(matches!(result, Err(WebFError::TypeError(_))));
+}
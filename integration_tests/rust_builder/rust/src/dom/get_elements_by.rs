@@ -0,0 +1,105 @@
+use webf_sys::{ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_document_get_elements_by_class_name(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("section", &exception_state).unwrap();
+  for class_name in ["card active", "card", "active"] {
+    let child = document.create_element("div", &exception_state).unwrap();
+    child.set_attribute("class", class_name, &exception_state).unwrap();
+    container.append_child(child.as_node(), &exception_state).unwrap();
+  }
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  let cards = document.get_elements_by_class_name("card", &exception_state).unwrap();
+  assert_eq!(cards.len(), 2);
+  assert!(cards.item(1).is_some());
+  assert!(cards.item(2).is_none());
+  assert_eq!(cards.iter().count(), 2);
+
+  let active_cards = document.get_elements_by_class_name("active card", &exception_state).unwrap();
+  assert_eq!(active_cards.len(), 1);
+  let first = active_cards.item(0).unwrap();
+  assert_eq!(first.get_attribute("class", &exception_state).unwrap(), Some("card active".to_string()));
+
+  // The collection is a snapshot, new matching elements are only found by looking them up again.
+  let card = document.create_element("div", &exception_state).unwrap();
+  card.set_attribute("class", "card", &exception_state).unwrap();
+  container.append_child(card.as_node(), &exception_state).unwrap();
+  assert_eq!(cards.len(), 2);
+  assert_eq!(document.get_elements_by_class_name("card", &exception_state).unwrap().len(), 3);
+
+  assert!(document.get_elements_by_class_name("missing", &exception_state).unwrap().is_empty());
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_document_get_elements_by_tag_name(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("section", &exception_state).unwrap();
+  for _ in 0..3 {
+    let child = document.create_element("p", &exception_state).unwrap();
+    container.append_child(child.as_node(), &exception_state).unwrap();
+  }
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  let paragraphs = document.get_elements_by_tag_name("p", &exception_state).unwrap();
+  assert_eq!(paragraphs.len(), 3);
+  assert_eq!(paragraphs.iter().count(), 3);
+  assert!(document.get_elements_by_tag_name("article", &exception_state).unwrap().is_empty());
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_element_get_elements_by(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("div", &exception_state).unwrap();
+  let outside = document.create_element("span", &exception_state).unwrap();
+  outside.set_attribute("class", "item", &exception_state).unwrap();
+  document.body().append_child(outside.as_node(), &exception_state).unwrap();
+  for _ in 0..2 {
+    let child = document.create_element("span", &exception_state).unwrap();
+    child.set_attribute("class", "item", &exception_state).unwrap();
+    container.append_child(child.as_node(), &exception_state).unwrap();
+  }
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  assert_eq!(container.get_elements_by_class_name("item", &exception_state).unwrap().len(), 2);
+  assert_eq!(container.get_elements_by_tag_name("span", &exception_state).unwrap().len(), 2);
+  assert!(container.get_elements_by_tag_name("div", &exception_state).unwrap().is_empty());
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+  document.body().remove_child(outside.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_html_collection_named_item(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("form", &exception_state).unwrap();
+  let named = document.create_element("input", &exception_state).unwrap();
+  named.set_attribute("name", "email", &exception_state).unwrap();
+  container.append_child(named.as_node(), &exception_state).unwrap();
+  let with_id = document.create_element("input", &exception_state).unwrap();
+  with_id.set_attribute("id", "email", &exception_state).unwrap();
+  with_id.set_attribute("name", "contact", &exception_state).unwrap();
+  container.append_child(with_id.as_node(), &exception_state).unwrap();
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  let inputs = container.get_elements_by_tag_name("input", &exception_state).unwrap();
+  // The element matched by its id takes precedence over the one matched by name.
+  let email = inputs.named_item("email").unwrap();
+  assert_eq!(email.get_attribute("name", &exception_state).unwrap(), Some("contact".to_string()));
+  let contact = inputs.named_item("contact").unwrap();
+  assert_eq!(contact.get_attribute("id", &exception_state).unwrap(), Some("email".to_string()));
+  assert!(inputs.named_item("missing").is_none());
+  assert!(inputs.named_item("").is_none());
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
@@ -0,0 +1,40 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{html, EventTargetMethods, ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_html_macro(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let text = "world";
+  let count = 2;
+  let events = Rc::new(RefCell::new(Vec::new()));
+  let events_in_handler = events.clone();
+
+  let div = html!(context, <div class="box" data-count={count}>
+    "hello "
+    <span id="name">{text}</span>
+    <input type="text" />
+    <button @click={move |event| events_in_handler.borrow_mut().push(event.type_())}>{count + 1}</button>
+  </div>).unwrap();
+
+  assert_eq!(div.get_attribute("class", &exception_state).unwrap(), Some("box".to_string()));
+  assert_eq!(div.get_attribute("data-count", &exception_state).unwrap(), Some("2".to_string()));
+  assert_eq!(div.child_nodes().len(), 4);
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some("hello world3".to_string()));
+
+  let button = div.query_selector("button", &exception_state).unwrap().unwrap();
+  let click = document.create_event("click", &exception_state).unwrap();
+  button.dispatch_event(&click, &exception_state).unwrap();
+  assert_eq!(*events.borrow(), vec!["click".to_string()]);
+}
+
+#[webf_test]
+pub fn test_html_macro_self_closing_root(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let context_ref = &context;
+  let img = html!(context_ref, <img alt={"logo"} />).unwrap();
+  assert_eq!(img.get_attribute("alt", &exception_state).unwrap(), Some("logo".to_string()));
+  assert_eq!(img.child_nodes().len(), 0);
+}
@@ -0,0 +1,25 @@
+use webf_sys::ExecutingContext;
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_inner_html(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  assert_eq!(div.inner_html(&exception_state).unwrap(), "");
+
+  div.set_inner_html("<span>hello</span>", &exception_state).unwrap();
+  assert_eq!(div.inner_html(&exception_state).unwrap(), "<span>hello</span>");
+  assert!(div.query_selector("span", &exception_state).unwrap().is_some());
+}
+
+#[webf_test]
+pub fn test_outer_html(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.set_inner_html("text", &exception_state).unwrap();
+  let outer_html = div.outer_html(&exception_state).unwrap();
+  assert!(outer_html.starts_with("<div"));
+  assert!(outer_html.ends_with(">text</div>"));
+}
@@ -0,0 +1,64 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use webf_sys::{ExecutingContext, IntersectionObserver, IntersectionObserverInit, NodeMethods, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_intersection_observer_options(_context: ExecutingContext) {
+  let observer = IntersectionObserver::new(Box::new(|_| {}), IntersectionObserverInit {
+    thresholds: vec![1.0, 0.5, 0.0, 0.5],
+  }).unwrap();
+  assert_eq!(observer.thresholds(), &[0.0, 0.5, 1.0]);
+
+  let default_observer = IntersectionObserver::new(Box::new(|_| {}), IntersectionObserverInit::default()).unwrap();
+  assert_eq!(default_observer.thresholds(), &[0.0]);
+
+  let invalid_threshold = IntersectionObserver::new(Box::new(|_| {}), IntersectionObserverInit {
+    thresholds: vec![1.5],
+  });
+  assert!(invalid_threshold.is_err());
+}
+
+#[webf_test]
+pub fn test_intersection_observer_unobserve(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let observer = IntersectionObserver::new(Box::new(|_| {}), IntersectionObserverInit::default()).unwrap();
+  observer.observe(&div, &exception_state).unwrap();
+  observer.observe(&div, &exception_state).unwrap();
+  observer.unobserve(&div);
+  observer.observe(&div, &exception_state).unwrap();
+  observer.disconnect();
+}
+
+#[webf_test_async]
+pub async fn test_intersection_observer_reports_visible_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.style().unwrap().set_property("width", "100px", None, &exception_state).unwrap();
+  div.style().unwrap().set_property("height", "100px", None, &exception_state).unwrap();
+
+  let future_for_entry = WebFNativeFuture::<(f64, bool, bool)>::new();
+  let future_in_callback = future_for_entry.clone();
+  let reported = Rc::new(Cell::new(false));
+  let observer = IntersectionObserver::new(Box::new(move |entries| {
+    if reported.replace(true) {
+      return;
+    }
+    let entry = &entries[0];
+    let target_is_div = entry.target().get_attribute("id", &context.create_exception_state()).unwrap() == Some(String::from("observed"));
+    future_in_callback.set_result(Ok(Some((entry.intersection_ratio(), entry.is_intersecting(), target_is_div))));
+  }), IntersectionObserverInit::default()).unwrap();
+
+  div.set_attribute("id", "observed", &exception_state).unwrap();
+  observer.observe(&div, &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+
+  let (intersection_ratio, is_intersecting, target_is_div) = future_for_entry.await.unwrap().unwrap();
+  assert!(intersection_ratio > 0.0);
+  assert!(is_intersecting);
+  assert!(target_is_div);
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
@@ -0,0 +1,32 @@
+pub mod append_children;
+pub mod attribute;
+pub mod bounding_client_rect;
+pub mod canvas;
+pub mod class_list;
+pub mod clone_node;
+pub mod comment;
+pub mod custom_event;
+pub mod dataset;
+pub mod document;
+pub mod document_fragment;
+pub mod document_position;
+pub mod dom_parser;
+pub mod downcast;
+pub mod element_builder;
+pub mod event;
+pub mod event_listener;
+pub mod fullscreen;
+pub mod get_elements_by;
+pub mod html_macro;
+pub mod inner_html;
+pub mod mouse_event;
+pub mod intersection_observer;
+pub mod mutation_observer;
+pub mod node;
+pub mod pointer_capture;
+pub mod pointer_event;
+pub mod query_selector;
+pub mod resize_observer;
+pub mod scroll;
+pub mod style;
+pub mod style_sheet;
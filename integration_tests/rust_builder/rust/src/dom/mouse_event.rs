@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{AddEventListenerOptions, EventMethods, EventTargetMethods, ExecutingContext, MouseButton, MouseButtons, MouseEventInit};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_create_mouse_event_with_coordinates(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let received_position = Rc::new(RefCell::new(None));
+  let received_position_in_callback = received_position.clone();
+  div.add_event_listener("click", Box::new(move |event| {
+    let mouse_event = event.as_mouse_event().unwrap();
+    *received_position_in_callback.borrow_mut() = Some((mouse_event.client_x(), mouse_event.client_y(), mouse_event.shift_key()));
+  }), &options, &exception_state).unwrap();
+
+  let mouse_event = document.create_mouse_event("click", MouseEventInit {
+    bubbles: true,
+    cancelable: true,
+    client_x: 10.0,
+    client_y: 20.0,
+    button: 0,
+    buttons: 1,
+    shift_key: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+  assert_eq!(mouse_event.type_(), "click");
+  assert!(mouse_event.bubbles());
+  assert!(mouse_event.cancelable());
+  assert!(mouse_event.shift_key());
+  assert!(!mouse_event.ctrl_key());
+  assert!(!mouse_event.alt_key());
+  assert!(!mouse_event.meta_key());
+  div.dispatch_event(mouse_event.as_event(), &exception_state).unwrap();
+
+  assert_eq!(*received_position.borrow(), Some((10.0, 20.0, true)));
+}
+
+#[webf_test]
+pub fn test_create_mouse_event_with_default_init(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let mouse_event = document.create_mouse_event("mousedown", MouseEventInit::default(), &exception_state).unwrap();
+  assert!(!mouse_event.bubbles());
+  assert_eq!(mouse_event.client_x(), 0.0);
+  assert_eq!(mouse_event.client_y(), 0.0);
+  assert!(!mouse_event.ctrl_key());
+  assert!(!mouse_event.alt_key());
+  assert!(!mouse_event.shift_key());
+  assert!(!mouse_event.meta_key());
+}
+
+#[webf_test]
+pub fn test_mouse_event_button_and_buttons(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let mouse_event = document.create_mouse_event("mousedown", MouseEventInit {
+    client_x: 5.0,
+    client_y: 8.0,
+    button: 2,
+    buttons: 3,
+    ctrl_key: true,
+    alt_key: false,
+    meta_key: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+
+  assert_eq!(mouse_event.button(), MouseButton::Right);
+  let buttons = mouse_event.buttons();
+  assert!(buttons.contains(MouseButtons::LEFT | MouseButtons::RIGHT));
+  assert!(!buttons.contains(MouseButtons::MIDDLE));
+  assert_eq!(buttons & MouseButtons::RIGHT, MouseButtons::RIGHT);
+  assert_eq!(buttons.bits(), 3);
+
+  assert!(mouse_event.ctrl_key());
+  assert!(mouse_event.meta_key());
+  assert!(!mouse_event.alt_key());
+  assert!(!mouse_event.shift_key());
+  assert_eq!((mouse_event.x(), mouse_event.y()), (5.0, 8.0));
+  assert_eq!((mouse_event.page_x(), mouse_event.page_y()), (5.0, 8.0));
+}
+
+#[webf_test]
+pub fn test_mouse_event_without_pressed_buttons(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let mouse_event = document.create_mouse_event("mousemove", MouseEventInit {
+    button: 4,
+    ..Default::default()
+  }, &exception_state).unwrap();
+
+  assert_eq!(mouse_event.button(), MouseButton::Forward);
+  assert!(mouse_event.buttons().is_empty());
+  assert_eq!(mouse_event.buttons(), MouseButtons::default());
+  assert_eq!((mouse_event.movement_x(), mouse_event.movement_y()), (0.0, 0.0));
+}
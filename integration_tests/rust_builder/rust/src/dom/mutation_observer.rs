@@ -0,0 +1,146 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{ExecutingContext, MutationObserver, MutationObserverInit, MutationRecordType, NodeMethods, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_mutation_observer_take_records(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let observer = MutationObserver::new(Box::new(|_| {}));
+  observer.observe(div.as_node(), MutationObserverInit {
+    child_list: true,
+    attribute_old_value: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+
+  div.set_attribute("id", "first", &exception_state).unwrap();
+  div.set_attribute("id", "second", &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+
+  let records = observer.take_records();
+  assert_eq!(records.len(), 3);
+  assert_eq!(records[0].record_type(), MutationRecordType::Attributes);
+  assert_eq!(records[0].attribute_name(), Some("id"));
+  assert_eq!(records[0].old_value(), None);
+  assert_eq!(records[1].old_value(), Some("first"));
+  assert_eq!(records[2].record_type(), MutationRecordType::ChildList);
+  assert_eq!(records[2].added_nodes().len(), 1);
+  assert!(records[2].removed_nodes().is_empty());
+  assert!(records[2].target().contains(div.as_node()));
+  assert!(observer.take_records().is_empty());
+
+  observer.disconnect();
+  div.set_attribute("id", "third", &exception_state).unwrap();
+  assert!(observer.take_records().is_empty());
+}
+
+#[webf_test]
+pub fn test_mutation_observer_options(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let observer = MutationObserver::new(Box::new(|_| {}));
+  let result = observer.observe(div.as_node(), MutationObserverInit::default(), &exception_state);
+  assert!(result.is_err());
+
+  let exception_state = context.create_exception_state();
+  observer.observe(div.as_node(), MutationObserverInit {
+    attribute_filter: Some(vec!["class".to_string()]),
+    subtree: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+  span.set_attribute("id", "ignored", &exception_state).unwrap();
+  span.set_attribute("class", "observed", &exception_state).unwrap();
+
+  let records = observer.take_records();
+  assert_eq!(records.len(), 1);
+  assert_eq!(records[0].attribute_name(), Some("class"));
+  assert!(records[0].target().contains(span.as_node()));
+}
+
+#[webf_test]
+pub fn test_mutation_observer_character_data(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let text = document.create_text_node("before", &exception_state).unwrap();
+  let observer = MutationObserver::new(Box::new(|_| {}));
+  observer.observe(text.as_node(), MutationObserverInit {
+    character_data_old_value: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+
+  text.set_text_content("after", &exception_state).unwrap();
+  let records = observer.take_records();
+  assert_eq!(records.len(), 1);
+  assert_eq!(records[0].record_type(), MutationRecordType::CharacterData);
+  assert_eq!(records[0].old_value(), Some("before"));
+}
+
+#[webf_test_async]
+pub async fn test_mutation_observer_delivers_records(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+
+  let future = WebFNativeFuture::new();
+  let future_in_callback = Rc::new(RefCell::new(Some(future.clone())));
+  let observer = MutationObserver::new(Box::new(move |records| {
+    let removed_count = records.iter().map(|record| record.removed_nodes().len()).sum::<usize>();
+    if let Some(future) = future_in_callback.borrow_mut().take() {
+      future.set_result(Ok(Some((records.len(), removed_count))));
+    }
+  }));
+  observer.observe(div.as_node(), MutationObserverInit {
+    child_list: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+
+  let span = document.create_element("span", &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+  div.remove_child(span.as_node(), &exception_state).unwrap();
+
+  // Both mutations are delivered together in the same callback.
+  let (record_count, removed_count) = future.await.unwrap().unwrap();
+  assert_eq!(record_count, 2);
+  assert_eq!(removed_count, 1);
+}
+
+#[webf_test_async]
+pub async fn test_mutation_observer_delivers_records_of_mutations_in_callback(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+
+  let future = WebFNativeFuture::new();
+  let future_in_callback = Rc::new(RefCell::new(Some(future.clone())));
+  let old_values = Rc::new(RefCell::new(Vec::new()));
+  let old_values_in_callback = old_values.clone();
+  let observer = MutationObserver::new(Box::new(move |records| {
+    old_values_in_callback.borrow_mut().extend(records.iter().map(|record| record.old_value().map(String::from)));
+    // The mutation made by the callback itself is delivered after this invocation returns.
+    if old_values_in_callback.borrow().len() == 1 {
+      let target = records[0].target().event_target.as_element().unwrap();
+      let exception_state = records[0].target().event_target.context().create_exception_state();
+      target.set_attribute("data-step", "second", &exception_state).unwrap();
+      return;
+    }
+    if let Some(future) = future_in_callback.borrow_mut().take() {
+      future.set_result(Ok(Some(old_values_in_callback.borrow().len())));
+    }
+  }));
+  observer.observe(div.as_node(), MutationObserverInit {
+    attribute_old_value: true,
+    ..Default::default()
+  }, &exception_state).unwrap();
+
+  div.set_attribute("data-step", "first", &exception_state).unwrap();
+
+  let delivered = future.await.unwrap().unwrap();
+  assert_eq!(delivered, 2);
+  assert_eq!(*old_values.borrow(), vec![None, Some(String::from("first"))]);
+}
@@ -0,0 +1,326 @@
+use std::time::{Duration, Instant};
+use webf_sys::{EventTargetMethods, ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_text_content(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::new()));
+
+  let span = document.create_element("span", &exception_state).unwrap();
+  let hello = document.create_text_node("hello ", &exception_state).unwrap();
+  let world = document.create_text_node("world", &exception_state).unwrap();
+  let comment = document.create_comment("ignored", &exception_state).unwrap();
+  div.append_child(hello.as_node(), &exception_state).unwrap();
+  div.append_child(comment.character_data.node.as_node(), &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+  span.append_child(world.as_node(), &exception_state).unwrap();
+
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("hello world")));
+  assert_eq!(comment.character_data.node.text_content(&exception_state).unwrap(), Some(String::from("ignored")));
+  assert_eq!(document.text_content(&exception_state).unwrap(), None);
+}
+
+#[webf_test]
+pub fn test_set_text_content(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+
+  div.set_text_content("replaced", &exception_state).unwrap();
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("replaced")));
+  assert!(div.query_selector("span", &exception_state).unwrap().is_none());
+}
+
+#[webf_test]
+pub fn test_insert_before(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let second = document.create_text_node("2", &exception_state).unwrap();
+  div.append_child(second.as_node(), &exception_state).unwrap();
+
+  let first = document.create_text_node("1", &exception_state).unwrap();
+  div.insert_before(first.as_node(), Some(second.as_node()), &exception_state).unwrap();
+  let third = document.create_text_node("3", &exception_state).unwrap();
+  div.insert_before(third.as_node(), None, &exception_state).unwrap();
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("123")));
+}
+
+#[webf_test]
+pub fn test_insert_before_with_non_child_reference(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let not_a_child = document.create_element("span", &exception_state).unwrap();
+  let new_node = document.create_text_node("new", &exception_state).unwrap();
+  let result = div.insert_before(new_node.as_node(), Some(not_a_child.as_node()), &exception_state);
+  assert!(result.is_err());
+}
+
+#[webf_test]
+pub fn test_replace_child(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let old_node = document.create_text_node("old", &exception_state).unwrap();
+  div.append_child(old_node.as_node(), &exception_state).unwrap();
+
+  let new_node = document.create_text_node("new", &exception_state).unwrap();
+  div.replace_child(new_node.as_node(), old_node.as_node(), &exception_state).unwrap();
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("new")));
+
+  let result = div.replace_child(new_node.as_node(), old_node.as_node(), &exception_state);
+  assert!(result.is_err());
+}
+
+#[webf_test]
+pub fn test_node_navigation(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  assert!(div.first_child().is_none());
+  assert!(div.last_child().is_none());
+  assert!(div.parent_node().is_none());
+  assert!(div.child_nodes().is_empty());
+
+  let first = document.create_text_node("first", &exception_state).unwrap();
+  let last = document.create_text_node("last", &exception_state).unwrap();
+  div.append_child(first.as_node(), &exception_state).unwrap();
+  div.append_child(last.as_node(), &exception_state).unwrap();
+
+  let first_child = div.first_child().unwrap();
+  assert_eq!(first_child.text_content(&exception_state).unwrap(), Some(String::from("first")));
+  assert!(first_child.previous_sibling().is_none());
+  let next = first_child.next_sibling().unwrap();
+  assert_eq!(next.text_content(&exception_state).unwrap(), Some(String::from("last")));
+  assert!(next.next_sibling().is_none());
+
+  let last_child = div.last_child().unwrap();
+  assert_eq!(last_child.text_content(&exception_state).unwrap(), Some(String::from("last")));
+  assert!(last_child.parent_node().is_some());
+
+  let child_nodes = div.child_nodes();
+  assert_eq!(child_nodes.len(), 2);
+  let texts: Vec<Option<String>> = child_nodes.iter().map(|node| node.text_content(&exception_state).unwrap()).collect();
+  assert_eq!(texts, vec![Some(String::from("first")), Some(String::from("last"))]);
+}
+
+#[webf_test]
+pub fn test_child_nodes_is_live(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let child_nodes = div.child_nodes();
+  assert!(child_nodes.is_empty());
+
+  let text = document.create_text_node("added", &exception_state).unwrap();
+  div.append_child(text.as_node(), &exception_state).unwrap();
+  assert_eq!(child_nodes.len(), 1);
+  assert_eq!(child_nodes.get(0).unwrap().text_content(&exception_state).unwrap(), Some(String::from("added")));
+
+  div.remove_child(text.as_node(), &exception_state).unwrap();
+  assert!(child_nodes.is_empty());
+}
+
+#[webf_test]
+pub fn test_node_is_not_disposed(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let text = document.create_text_node("text", &exception_state).unwrap();
+  div.append_child(text.as_node(), &exception_state).unwrap();
+
+  assert!(!div.as_node().event_target.is_disposed());
+  let first_child = div.first_child().unwrap();
+  assert!(!first_child.event_target.is_disposed());
+  let parent = first_child.parent_node().unwrap();
+  assert!(!parent.event_target.is_disposed());
+  assert_eq!(parent.text_content(&exception_state).unwrap(), Some(String::from("text")));
+}
+
+#[webf_test]
+pub fn test_children_iter(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  assert_eq!(div.children_iter().count(), 0);
+
+  for text in ["a", "b", "c"] {
+    let span = document.create_element("span", &exception_state).unwrap();
+    span.set_text_content(text, &exception_state).unwrap();
+    div.append_child(span.as_node(), &exception_state).unwrap();
+  }
+
+  let texts = div.children_iter()
+    .map(|child| child.text_content(&exception_state).unwrap().unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(texts, vec!["a", "b", "c"]);
+
+  // Removing the children while iterating still visits all of them.
+  let mut visited = 0;
+  for child in div.children_iter() {
+    div.remove_child(&child, &exception_state).unwrap();
+    visited += 1;
+  }
+  assert_eq!(visited, 3);
+  assert!(div.first_child().is_none());
+}
+
+#[webf_test]
+pub fn test_create_text_node_fmt(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let (x, y) = (12.5, 40);
+  let position = document.create_text_node_fmt(format_args!("x: {}, y: {}", x, y), &exception_state).unwrap();
+  assert_eq!(position.as_node().text_content(&exception_state).unwrap(), Some(String::from("x: 12.5, y: 40")));
+
+  // The buffer is reused, a shorter text must not keep the tail of the previous one.
+  let short = document.create_text_node_fmt(format_args!("{}", 7), &exception_state).unwrap();
+  assert_eq!(short.as_node().text_content(&exception_state).unwrap(), Some(String::from("7")));
+  assert_eq!(position.as_node().text_content(&exception_state).unwrap(), Some(String::from("x: 12.5, y: 40")));
+}
+
+// Updating a position display every frame for 10 seconds at 60fps, formatting into the reused buffer
+// must not be slower than formatting into a new `String` for each frame.
+#[webf_test]
+pub fn test_create_text_node_fmt_benchmark(context: ExecutingContext) {
+  const FRAME_COUNT: usize = 600;
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+
+  // The two paths run in turn on every frame so that both see the same noise.
+  let mut fmt_duration = Duration::ZERO;
+  let mut format_duration = Duration::ZERO;
+  for frame in 0..FRAME_COUNT {
+    let (x, y) = (frame as f64 * 1.5, frame * 2);
+
+    let start = Instant::now();
+    let text = document.create_text_node_fmt(format_args!("x: {}, y: {}", x, y), &exception_state).unwrap();
+    fmt_duration += start.elapsed();
+    drop(text);
+
+    let start = Instant::now();
+    let text = document.create_text_node(format!("x: {}, y: {}", x, y).as_str(), &exception_state).unwrap();
+    format_duration += start.elapsed();
+    drop(text);
+  }
+
+  // The creation of the node dominates, allow 10% of noise.
+  assert!(
+    fmt_duration <= format_duration + format_duration / 10,
+    "create_text_node_fmt took {:?} for {} frames, create_text_node with format! took {:?}",
+    fmt_duration, FRAME_COUNT, format_duration
+  );
+}
+
+#[webf_test]
+pub fn test_element_set_text_reuses_text_node(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+
+  div.set_text("x: 0", &exception_state).unwrap();
+  let text_node = div.first_child().unwrap();
+  div.set_text("x: 1", &exception_state).unwrap();
+  assert_eq!(div.child_nodes().len(), 1);
+  assert_eq!(div.first_child().unwrap().ptr(), text_node.ptr());
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("x: 1")));
+
+  let text = text_node.event_target.as_text().unwrap();
+  text.set_data("x: 2", &exception_state).unwrap();
+  assert_eq!(text.data(&exception_state).unwrap(), "x: 2");
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("x: 2")));
+
+  // Other children are replaced by a single text node.
+  let span = document.create_element("span", &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+  div.set_text("x: 3", &exception_state).unwrap();
+  assert_eq!(div.child_nodes().len(), 1);
+  assert_eq!(div.text_content(&exception_state).unwrap(), Some(String::from("x: 3")));
+}
+
+#[webf_test]
+pub fn test_text_split_text_and_whole_text(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let text = document.create_text_node("hello world", &exception_state).unwrap();
+  div.append_child(text.as_node(), &exception_state).unwrap();
+  assert_eq!(text.length(), 11);
+
+  let rest = text.split_text(5, &exception_state).unwrap();
+  assert_eq!(text.data(&exception_state).unwrap(), "hello");
+  assert_eq!(rest.data(&exception_state).unwrap(), " world");
+  assert_eq!(rest.length(), 6);
+  assert_eq!(div.child_nodes().len(), 2);
+  assert_eq!(text.next_sibling().unwrap().ptr(), rest.ptr());
+  assert_eq!(text.whole_text(&exception_state).unwrap(), "hello world");
+  assert_eq!(rest.whole_text(&exception_state).unwrap(), "hello world");
+
+  // Non text siblings end the run of adjacent text nodes.
+  let span = document.create_element("span", &exception_state).unwrap();
+  div.insert_before(span.as_node(), Some(rest.as_node()), &exception_state).unwrap();
+  assert_eq!(text.whole_text(&exception_state).unwrap(), "hello");
+  assert_eq!(rest.whole_text(&exception_state).unwrap(), " world");
+
+  let result = text.split_text(6, &exception_state);
+  assert_eq!(result.err().and_then(|error| error.name().map(String::from)), Some(String::from("IndexSizeError")));
+}
+
+#[webf_test]
+pub fn test_normalize_merges_adjacent_text_nodes(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  for data in ["a", "b", "c"] {
+    let text = document.create_text_node(data, &exception_state).unwrap();
+    div.append_child(text.as_node(), &exception_state).unwrap();
+  }
+  let span = document.create_element("span", &exception_state).unwrap();
+  for data in ["", "d", "", "e"] {
+    let text = document.create_text_node(data, &exception_state).unwrap();
+    span.append_child(text.as_node(), &exception_state).unwrap();
+  }
+  let empty = document.create_element("p", &exception_state).unwrap();
+  let empty_text = document.create_text_node("", &exception_state).unwrap();
+  empty.append_child(empty_text.as_node(), &exception_state).unwrap();
+  div.append_child(span.as_node(), &exception_state).unwrap();
+  div.append_child(empty.as_node(), &exception_state).unwrap();
+  assert_eq!(div.child_nodes().len(), 5);
+
+  div.normalize(&exception_state).unwrap();
+
+  assert_eq!(div.child_nodes().len(), 3);
+  let merged = div.first_child().unwrap().event_target.as_text().unwrap();
+  assert_eq!(merged.data(&exception_state).unwrap(), "abc");
+  assert_eq!(span.child_nodes().len(), 1);
+  assert_eq!(span.text_content(&exception_state).unwrap(), Some(String::from("de")));
+  assert_eq!(empty.child_nodes().len(), 0);
+}
+
+#[webf_test]
+pub fn test_remove_detaches_from_parent(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+  let text = document.create_text_node("text", &exception_state).unwrap();
+  div.append_children(&[&span, &text], &exception_state).unwrap();
+  assert_eq!(div.child_nodes().len(), 2);
+
+  span.remove(&exception_state).unwrap();
+  assert!(span.parent_node().is_none());
+  assert_eq!(div.child_nodes().len(), 1);
+
+  text.remove(&exception_state).unwrap();
+  assert!(div.first_child().is_none());
+
+  // Removing a node without parent does nothing.
+  span.remove(&exception_state).unwrap();
+  assert!(span.parent_node().is_none());
+}
@@ -0,0 +1,37 @@
+use webf_sys::{ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_set_pointer_capture_requires_active_pointer(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let slider = document.create_element("div", &exception_state).unwrap();
+  document.body().append_child(slider.as_node(), &exception_state).unwrap();
+
+  let Err(err) = slider.set_pointer_capture(1, &exception_state) else {
+    panic!("capturing an inactive pointer should fail");
+  };
+  assert_eq!(err.name(), Some("NotFoundError"));
+
+  let exception_state = context.create_exception_state();
+  assert!(!slider.has_pointer_capture(1, &exception_state).unwrap());
+  let Err(err) = slider.release_pointer_capture(1, &exception_state) else {
+    panic!("releasing an inactive pointer should fail");
+  };
+  assert_eq!(err.name(), Some("NotFoundError"));
+
+  let exception_state = context.create_exception_state();
+  document.body().remove_child(slider.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_set_pointer_capture_on_disconnected_element(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let slider = document.create_element("div", &exception_state).unwrap();
+
+  let Err(err) = slider.set_pointer_capture(1, &exception_state) else {
+    panic!("capturing on a disconnected element should fail");
+  };
+  assert_eq!(err.name(), Some("InvalidStateError"));
+}
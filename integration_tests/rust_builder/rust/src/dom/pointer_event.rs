@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{AddEventListenerOptions, EventMethods, EventTargetMethods, ExecutingContext, PointerEventInit, PointerType};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_create_pointer_event_with_init(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let pointer_event = document.create_pointer_event("pointerdown", PointerEventInit {
+    bubbles: true,
+    cancelable: true,
+    is_primary: true,
+    pointer_id: 7.0,
+    pointer_type: PointerType::Pen,
+    pressure: 0.5,
+    tangential_pressure: -0.25,
+    tilt_x: 30.0,
+    tilt_y: -45.0,
+    twist: 90.0,
+    width: 2.0,
+    height: 3.0,
+  }, &exception_state).unwrap();
+
+  assert_eq!(pointer_event.type_(), "pointerdown");
+  assert!(pointer_event.bubbles());
+  assert!(pointer_event.cancelable());
+  assert!(pointer_event.is_primary());
+  assert_eq!(pointer_event.pointer_id(), 7.0);
+  assert_eq!(pointer_event.pointer_type(), PointerType::Pen);
+  assert_eq!(pointer_event.pressure(), 0.5);
+  assert_eq!(pointer_event.tangential_pressure(), -0.25);
+  assert_eq!(pointer_event.tilt_x(), 30.0);
+  assert_eq!(pointer_event.tilt_y(), -45.0);
+  assert_eq!(pointer_event.twist(), 90.0);
+  assert_eq!(pointer_event.width(), 2.0);
+  assert_eq!(pointer_event.height(), 3.0);
+}
+
+#[webf_test]
+pub fn test_dispatch_pointer_event(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+
+  let received = Rc::new(RefCell::new(None));
+  let received_in_callback = received.clone();
+  div.add_event_listener("pointermove", Box::new(move |event| {
+    let pointer_event = event.as_pointer_event().unwrap();
+    *received_in_callback.borrow_mut() = Some((pointer_event.pointer_type(), pointer_event.pressure()));
+  }), &options, &exception_state).unwrap();
+
+  for pointer_type in [PointerType::Mouse, PointerType::Touch, PointerType::Unknown] {
+    let pointer_event = document.create_pointer_event("pointermove", PointerEventInit {
+      pointer_type,
+      pressure: 1.0,
+      ..Default::default()
+    }, &exception_state).unwrap();
+    div.dispatch_event(pointer_event.as_event(), &exception_state).unwrap();
+    assert_eq!(*received.borrow(), Some((pointer_type, 1.0)));
+  }
+}
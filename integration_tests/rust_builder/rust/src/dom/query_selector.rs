@@ -0,0 +1,95 @@
+use webf_sys::{ExecutingContext, NodeMethods};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_document_query_selector(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let section = document.create_element("section", &exception_state).unwrap();
+  document.body().append_child(section.as_node(), &exception_state).unwrap();
+
+  let found = document.query_selector("section", &exception_state).unwrap();
+  assert!(found.is_some());
+
+  let missing = document.query_selector("article", &exception_state).unwrap();
+  assert!(missing.is_none());
+
+  document.body().remove_child(section.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_document_query_selector_all(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("section", &exception_state).unwrap();
+  for _ in 0..3 {
+    let child = document.create_element("p", &exception_state).unwrap();
+    container.append_child(child.as_node(), &exception_state).unwrap();
+  }
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  let node_list = document.query_selector_all("section p", &exception_state).unwrap();
+  assert_eq!(node_list.len(), 3);
+  assert!(node_list.get(2).is_some());
+  assert!(node_list.get(3).is_none());
+  assert_eq!(node_list.iter().count(), 3);
+
+  let empty_list = document.query_selector_all("article", &exception_state).unwrap();
+  assert!(empty_list.is_empty());
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_element_query_selector(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("div", &exception_state).unwrap();
+  let child = document.create_element("span", &exception_state).unwrap();
+  container.append_child(child.as_node(), &exception_state).unwrap();
+
+  let found = container.query_selector("span", &exception_state).unwrap();
+  assert!(found.is_some());
+  assert!(container.query_selector("p", &exception_state).unwrap().is_none());
+
+  let node_list = container.query_selector_all("span", &exception_state).unwrap();
+  assert_eq!(node_list.len(), 1);
+}
+
+#[webf_test]
+pub fn test_query_selector_with_invalid_selector(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let result = document.query_selector("!!invalid", &exception_state);
+  assert!(result.is_err());
+}
+
+#[webf_test]
+pub fn test_element_matches_and_closest(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let list = document.create_element("ul", &exception_state).unwrap();
+  list.set_attribute("class", "menu", &exception_state).unwrap();
+  let item = document.create_element("li", &exception_state).unwrap();
+  item.set_attribute("data-id", "1", &exception_state).unwrap();
+  let link = document.create_element("a", &exception_state).unwrap();
+  item.append_child(link.as_node(), &exception_state).unwrap();
+  list.append_child(item.as_node(), &exception_state).unwrap();
+  document.body().append_child(list.as_node(), &exception_state).unwrap();
+
+  assert!(item.matches("li[data-id]", &exception_state).unwrap());
+  assert!(item.matches(".menu > li", &exception_state).unwrap());
+  assert!(!link.matches("li", &exception_state).unwrap());
+
+  let closest_item = link.closest("li", &exception_state).unwrap().unwrap();
+  assert_eq!(closest_item.get_attribute("data-id", &exception_state).unwrap(), Some(String::from("1")));
+  let closest_self = link.closest("a", &exception_state).unwrap().unwrap();
+  assert!(closest_self.matches("a", &exception_state).unwrap());
+  assert!(link.closest("table", &exception_state).unwrap().is_none());
+
+  assert!(item.matches("li[", &exception_state).is_err());
+  let exception_state = context.create_exception_state();
+  assert!(link.closest("li[", &exception_state).is_err());
+
+  document.body().remove_child(list.as_node(), &exception_state).unwrap();
+}
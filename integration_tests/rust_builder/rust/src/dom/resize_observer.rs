@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{ExecutingContext, NodeMethods, ResizeObserver, ResizeObserverBoxOptions, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_resize_observer_unobserve(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let observer = ResizeObserver::new(Box::new(|_| {}));
+  observer.observe(&div, ResizeObserverBoxOptions::ContentBox, &exception_state).unwrap();
+  observer.observe(&div, ResizeObserverBoxOptions::BorderBox, &exception_state).unwrap();
+  observer.unobserve(&div);
+  observer.observe(&div, ResizeObserverBoxOptions::default(), &exception_state).unwrap();
+  observer.disconnect();
+}
+
+#[webf_test_async]
+pub async fn test_resize_observer_coalesces_changes(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.style().unwrap().set_property("width", "100px", None, &exception_state).unwrap();
+  div.style().unwrap().set_property("height", "50px", None, &exception_state).unwrap();
+  div.style().unwrap().set_property("padding", "10px", None, &exception_state).unwrap();
+
+  let next_notification: Rc<RefCell<Option<WebFNativeFuture<(usize, f64, f64)>>>> = Rc::new(RefCell::new(None));
+  let next_notification_in_callback = next_notification.clone();
+  let observer = ResizeObserver::new(Box::new(move |entries| {
+    let entry = &entries[0];
+    if let Some(future) = next_notification_in_callback.borrow_mut().take() {
+      future.set_result(Ok(Some((entries.len(), entry.content_rect().width, entry.border_box_size().inline_size))));
+    }
+  }));
+  observer.observe(&div, ResizeObserverBoxOptions::ContentBox, &exception_state).unwrap();
+
+  let future = WebFNativeFuture::new();
+  next_notification.replace(Some(future.clone()));
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+  let (entry_count, content_width, border_box_width) = future.await.unwrap().unwrap();
+  assert_eq!(entry_count, 1);
+  assert_eq!(content_width, 100.0);
+  assert_eq!(border_box_width, 120.0);
+
+  let future = WebFNativeFuture::new();
+  next_notification.replace(Some(future.clone()));
+  div.style().unwrap().set_property("width", "200px", None, &exception_state).unwrap();
+  div.get_bounding_client_rect(&exception_state).unwrap();
+  div.style().unwrap().set_property("width", "300px", None, &exception_state).unwrap();
+  let (entry_count, content_width, _) = future.await.unwrap().unwrap();
+  assert_eq!(entry_count, 1);
+  assert_eq!(content_width, 300.0);
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
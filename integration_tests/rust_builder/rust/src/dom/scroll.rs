@@ -0,0 +1,63 @@
+use webf_sys::{ExecutingContext, NodeMethods, ScrollBehavior, ScrollIntoViewOptions, ScrollLogicalPosition};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_scroll_to_and_scroll_by(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("div", &exception_state).unwrap();
+  let style = container.style().unwrap();
+  style.set_property("width", "100px", None, &exception_state).unwrap();
+  style.set_property("height", "100px", None, &exception_state).unwrap();
+  style.set_property("overflow", "scroll", None, &exception_state).unwrap();
+  let content = document.create_element("div", &exception_state).unwrap();
+  content.style().unwrap().set_property("width", "1000px", None, &exception_state).unwrap();
+  content.style().unwrap().set_property("height", "1000px", None, &exception_state).unwrap();
+  container.append_child(content.as_node(), &exception_state).unwrap();
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  container.scroll_to(20.0, 30.0, &exception_state).unwrap();
+  assert_eq!(container.scroll_left(&exception_state).unwrap(), 20.0);
+  assert_eq!(container.scroll_top(&exception_state).unwrap(), 30.0);
+
+  container.scroll_by(5.0, 10.0, &exception_state).unwrap();
+  assert_eq!(container.scroll_left(&exception_state).unwrap(), 25.0);
+  assert_eq!(container.scroll_top(&exception_state).unwrap(), 40.0);
+
+  container.set_scroll_top(0.0, &exception_state).unwrap();
+  container.set_scroll_left(0.0, &exception_state).unwrap();
+  assert_eq!(container.scroll_top(&exception_state).unwrap(), 0.0);
+  assert_eq!(container.scroll_left(&exception_state).unwrap(), 0.0);
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_scroll_into_view_aligns_to_start(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let container = document.create_element("div", &exception_state).unwrap();
+  let style = container.style().unwrap();
+  style.set_property("height", "100px", None, &exception_state).unwrap();
+  style.set_property("overflow", "scroll", None, &exception_state).unwrap();
+  let spacer = document.create_element("div", &exception_state).unwrap();
+  spacer.style().unwrap().set_property("height", "500px", None, &exception_state).unwrap();
+  let target = document.create_element("div", &exception_state).unwrap();
+  target.style().unwrap().set_property("height", "50px", None, &exception_state).unwrap();
+  let trailer = document.create_element("div", &exception_state).unwrap();
+  trailer.style().unwrap().set_property("height", "500px", None, &exception_state).unwrap();
+  container.append_child(spacer.as_node(), &exception_state).unwrap();
+  container.append_child(target.as_node(), &exception_state).unwrap();
+  container.append_child(trailer.as_node(), &exception_state).unwrap();
+  document.body().append_child(container.as_node(), &exception_state).unwrap();
+
+  let options = ScrollIntoViewOptions {
+    behavior: ScrollBehavior::Auto,
+    block: ScrollLogicalPosition::Start,
+    inline: ScrollLogicalPosition::Nearest,
+  };
+  target.scroll_into_view(options, &exception_state).unwrap();
+  assert_eq!(container.scroll_top(&exception_state).unwrap(), 500.0);
+
+  document.body().remove_child(container.as_node(), &exception_state).unwrap();
+}
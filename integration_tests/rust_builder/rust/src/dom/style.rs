@@ -0,0 +1,76 @@
+use webf_sys::{ExecutingContext, NodeMethods, WebFError};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_style_set_and_remove_property(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  assert_eq!(style.get_property_value("color", &exception_state).unwrap(), "");
+
+  style.set_property("color", "red", None, &exception_state).unwrap();
+  assert_eq!(style.get_property_value("color", &exception_state).unwrap(), "red");
+
+  assert_eq!(style.remove_property("color", &exception_state).unwrap(), "red");
+  assert_eq!(style.get_property_value("color", &exception_state).unwrap(), "");
+}
+
+#[webf_test]
+pub fn test_style_empty_value_removes_property(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  style.set_property("display", "none", None, &exception_state).unwrap();
+  style.set_property("display", "", None, &exception_state).unwrap();
+  assert_eq!(style.get_property_value("display", &exception_state).unwrap(), "");
+}
+
+#[webf_test]
+pub fn test_style_set_property_with_priority(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let style = div.style().unwrap();
+  style.set_property("width", "10px", Some(""), &exception_state).unwrap();
+  assert_eq!(style.get_property_value("width", &exception_state).unwrap(), "10px");
+
+  style.set_property("width", "20px", Some("important"), &exception_state).unwrap();
+  assert_eq!(style.get_property_value("width", &exception_state).unwrap(), "20px");
+  style.set_property("height", "5px", Some("IMPORTANT"), &exception_state).unwrap();
+  assert_eq!(style.get_property_value("height", &exception_state).unwrap(), "5px");
+
+  let result = style.set_property("width", "30px", Some("urgent"), &exception_state);
+  assert!(matches!(result, Err(WebFError::DomException { ref name, .. }) if name == "SyntaxError"));
+  let exception_state = context.create_exception_state();
+  assert_eq!(style.get_property_value("width", &exception_state).unwrap(), "20px");
+}
+
+#[webf_test]
+pub fn test_style_is_none_for_unstyled_elements(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let item = document.create_element_ns("https://example.com/items", "item", &exception_state).unwrap();
+  assert!(item.style().is_none());
+}
+
+#[webf_test]
+pub fn test_get_computed_style(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.style().unwrap().set_property("color", "red", None, &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+
+  let computed_style = context.window().get_computed_style(&div, None, &exception_state).unwrap();
+  assert_eq!(computed_style.get_property_value("color", &exception_state).unwrap(), "rgb(255, 0, 0)");
+
+  assert!(computed_style.set_property("color", "blue", None, &exception_state).is_err());
+  let exception_state = context.create_exception_state();
+  assert!(computed_style.remove_property("color", &exception_state).is_err());
+  let exception_state = context.create_exception_state();
+  assert_eq!(computed_style.get_property_value("color", &exception_state).unwrap(), "rgb(255, 0, 0)");
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
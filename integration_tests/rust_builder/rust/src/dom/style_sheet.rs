@@ -0,0 +1,63 @@
+use webf_sys::{ExecutingContext, NodeMethods, WebFError};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_style_sheet_insert_and_delete_rule(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let sheet = document.create_style_sheet(&exception_state).unwrap();
+  assert!(sheet.css_rules(&exception_state).unwrap().is_empty());
+
+  assert_eq!(sheet.insert_rule(".a { color: red; }", 0, &exception_state).unwrap(), 0);
+  assert_eq!(sheet.insert_rule(".b { color: blue; }", 1, &exception_state).unwrap(), 1);
+  assert_eq!(sheet.insert_rule(".c { color: green; }", 0, &exception_state).unwrap(), 0);
+  assert_eq!(sheet.css_rules(&exception_state).unwrap(), vec![
+    ".c { color: green; }".to_string(),
+    ".a { color: red; }".to_string(),
+    ".b { color: blue; }".to_string(),
+  ]);
+
+  sheet.delete_rule(1, &exception_state).unwrap();
+  assert_eq!(sheet.css_rules(&exception_state).unwrap(), vec![
+    ".c { color: green; }".to_string(),
+    ".b { color: blue; }".to_string(),
+  ]);
+}
+
+#[webf_test]
+pub fn test_style_sheet_rules_apply_to_elements(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  div.set_class_name("style-sheet-target", &exception_state).unwrap();
+  document.body().append_child(div.as_node(), &exception_state).unwrap();
+
+  let sheet = document.create_style_sheet(&exception_state).unwrap();
+  sheet.insert_rule(".style-sheet-target { color: red; }", 0, &exception_state).unwrap();
+  let computed_style = context.window().get_computed_style(&div, None, &exception_state).unwrap();
+  assert_eq!(computed_style.get_property_value("color", &exception_state).unwrap(), "rgb(255, 0, 0)");
+
+  sheet.delete_rule(0, &exception_state).unwrap();
+  let computed_style = context.window().get_computed_style(&div, None, &exception_state).unwrap();
+  assert_ne!(computed_style.get_property_value("color", &exception_state).unwrap(), "rgb(255, 0, 0)");
+
+  document.body().remove_child(div.as_node(), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_style_sheet_rejects_invalid_rules(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let sheet = document.create_style_sheet(&exception_state).unwrap();
+
+  let result = sheet.insert_rule(".a { color: red; }", 1, &exception_state);
+  assert!(matches!(result, Err(WebFError::DomException { ref name, .. }) if name == "IndexSizeError"));
+  let exception_state = context.create_exception_state();
+  let result = sheet.insert_rule("", 0, &exception_state);
+  assert!(matches!(result, Err(WebFError::SyntaxError(_))));
+  let exception_state = context.create_exception_state();
+  let result = sheet.delete_rule(0, &exception_state);
+  assert!(matches!(result, Err(WebFError::DomException { ref name, .. }) if name == "IndexSizeError"));
+  let exception_state = context.create_exception_state();
+  assert!(sheet.css_rules(&exception_state).unwrap().is_empty());
+}
@@ -0,0 +1,158 @@
+use std::cell::Cell;
+use webf_sys::{initialize_webf_api, AddEventListenerOptions, DOMParser, DocumentRustMethods, EventTarget, EventTargetMethods, EventTargetRustMethods, ExecutingContext, JsonValue, MimeType, NodeMethods, OpaquePtr, RustValue, RustValueStatus, VersionMismatch, WebFError, XMLHttpRequest, WEBF_API_VERSION};
+use webf_test_macros::webf_test;
+
+type GetDocument = extern "C" fn(*const OpaquePtr) -> RustValue<DocumentRustMethods>;
+type ReleaseEventTarget = extern "C" fn(*const OpaquePtr);
+
+thread_local! {
+  static GET_DOCUMENT: Cell<Option<GetDocument>> = const { Cell::new(None) };
+  static RELEASE_EVENT_TARGET: Cell<Option<ReleaseEventTarget>> = const { Cell::new(None) };
+  static MISMATCHED_DOCUMENT_METHODS: Cell<*const DocumentRustMethods> = const { Cell::new(std::ptr::null()) };
+  static RELEASED_DOCUMENTS: Cell<usize> = const { Cell::new(0) };
+}
+
+extern "C" fn release_counted(event_target: *const OpaquePtr) {
+  RELEASED_DOCUMENTS.with(|count| count.set(count.get() + 1));
+  RELEASE_EVENT_TARGET.with(|release| release.get().unwrap())(event_target);
+}
+
+// Returns the real document with a copy of its table, which reports another version and counts the releases.
+extern "C" fn get_mismatched_document(context: *const OpaquePtr) -> RustValue<DocumentRustMethods> {
+  let document = GET_DOCUMENT.with(|get_document| get_document.get().unwrap())(context);
+  MISMATCHED_DOCUMENT_METHODS.with(|methods| {
+    if methods.get().is_null() {
+      let mut mismatched = unsafe { std::ptr::read(document.method_pointer) };
+      mismatched.version = WEBF_API_VERSION + 1.0;
+      RELEASE_EVENT_TARGET.with(|release| release.set(Some(mismatched.container_node.node.event_target.release)));
+      mismatched.container_node.node.event_target.release = release_counted;
+      methods.set(Box::into_raw(Box::new(mismatched)));
+    }
+    RustValue {
+      value: document.value,
+      method_pointer: methods.get(),
+      status: document.status,
+    }
+  })
+}
+
+#[webf_test]
+pub fn test_dom_exception_error(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+
+  let Err(err) = div.remove_child(span.as_node(), &exception_state) else {
+    panic!("Removing a node which is not a child should fail");
+  };
+  match &err {
+    WebFError::DomException { name, message } => {
+      assert_eq!(name, "NotFoundError");
+      assert_eq!(message, "The node to be removed is not a child of this node.");
+    }
+    _ => panic!("Expected a NotFoundError, got {:?}", err),
+  }
+  assert_eq!(err.name(), Some("NotFoundError"));
+  assert_eq!(err.to_string(), "NotFoundError: The node to be removed is not a child of this node.");
+}
+
+#[webf_test]
+pub fn test_syntax_error(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let parser = DOMParser::new(&context);
+  let Err(err) = parser.parse_from_string("<a><b></a>", MimeType::TextXml, &exception_state) else {
+    panic!("Mismatched tags should not be parsed");
+  };
+  assert!(matches!(err, WebFError::SyntaxError(_)), "{:?}", err);
+  assert!(err.to_string().starts_with("SyntaxError: Failed to parse XML"));
+
+  let err = JsonValue::parse("{").unwrap_err();
+  assert!(matches!(err, WebFError::SyntaxError(_)), "{:?}", err);
+}
+
+#[webf_test]
+pub fn test_rust_side_errors(context: ExecutingContext) {
+  let request = XMLHttpRequest::new(&context);
+  let err = request.set_request_header("Accept", "text/plain").unwrap_err();
+  assert_eq!(err, WebFError::dom_exception("InvalidStateError", "Failed to execute 'setRequestHeader' on 'XMLHttpRequest': The object's state must be OPENED."));
+  assert!(matches!(request.open("GE T", "http://localhost/"), Err(WebFError::SyntaxError(_))));
+
+  assert_eq!(WebFError::TypeError("bad".to_string()).to_string(), "TypeError: bad");
+  assert_eq!(WebFError::from("plain").to_string(), "plain");
+  assert_eq!(WebFError::Disposed.name(), Some("InvalidStateError"));
+}
+
+#[webf_test]
+pub fn test_exception_name_and_code(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  assert_eq!(exception_state.exception_name(&context), None);
+  assert_eq!(exception_state.exception_code(), None);
+
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let span = document.create_element("span", &exception_state).unwrap();
+  assert!(div.remove_child(span.as_node(), &exception_state).is_err());
+  assert_eq!(exception_state.exception_name(&context), Some("NotFoundError".to_string()));
+  assert_eq!(exception_state.exception_code(), Some(8));
+
+  let exception_state = context.create_exception_state();
+  let parser = DOMParser::new(&context);
+  assert!(parser.parse_from_string("<a>", MimeType::TextXml, &exception_state).is_err());
+  assert_eq!(exception_state.exception_name(&context), Some("SyntaxError".to_string()));
+  assert_eq!(exception_state.exception_code(), None);
+}
+
+#[webf_test]
+pub fn test_version_mismatch_error(_context: ExecutingContext) {
+  let error = VersionMismatch {
+    expected: WEBF_API_VERSION,
+    actual: 2.0,
+  };
+  let message = error.to_string();
+  assert!(message.contains("2"));
+  assert!(message.contains(&WEBF_API_VERSION.to_string()));
+}
+
+#[webf_test]
+pub fn test_initialize_webf_api_rejects_mismatched_versions(context: ExecutingContext) {
+  // A copy of the real table, which reports another version.
+  let mut methods = unsafe { std::ptr::read(context.method_pointer()) };
+  methods.version = WEBF_API_VERSION + 1.0;
+  let handle = RustValue { value: context.ptr, method_pointer: &methods, status: context.status };
+  let error = initialize_webf_api(handle, context.meta_data).err().unwrap();
+  assert_eq!(error.actual, WEBF_API_VERSION + 1.0);
+
+  methods.version = WEBF_API_VERSION;
+  GET_DOCUMENT.with(|get_document| get_document.set(Some(methods.get_document)));
+  methods.get_document = get_mismatched_document;
+  let handle = RustValue { value: context.ptr, method_pointer: &methods, status: context.status };
+  let error = initialize_webf_api(handle, context.meta_data).err().unwrap();
+  assert_eq!(error.actual, WEBF_API_VERSION + 1.0);
+  // The document fetched for the check is released even though the check failed.
+  assert_eq!(RELEASED_DOCUMENTS.with(|count| count.get()), 1);
+}
+
+#[webf_test]
+pub fn test_disposed_target_error(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let div = document.create_element("div", &exception_state).unwrap();
+  let event = document.create_event("Event", &exception_state).unwrap();
+
+  // The status of a target whose C++ impl is gone, the method table is never read once it's disposed.
+  let status = RustValueStatus { disposed: true };
+  let disposed = EventTarget::initialize(div.ptr(), &context, std::ptr::null::<EventTargetRustMethods>(), &status);
+  assert!(disposed.is_disposed());
+  let options = AddEventListenerOptions {
+    passive: 0,
+    once: 0,
+    capture: 0,
+  };
+  assert_eq!(disposed.add_event_listener("click", Box::new(|_| {}), &options, &exception_state), Err(WebFError::Disposed));
+  assert_eq!(disposed.remove_event_listener("click", Box::new(|_| {}), &exception_state), Err(WebFError::Disposed));
+  assert_eq!(disposed.dispatch_event(&event, &exception_state), Err(WebFError::Disposed));
+  assert_eq!(disposed.listener_count("click"), Err(WebFError::Disposed));
+  assert_eq!(disposed.has_listener("click"), Err(WebFError::Disposed));
+  assert!(!div.as_node().event_target.is_disposed());
+}
@@ -0,0 +1,67 @@
+use webf_sys::{ExecutingContext, FetchInit, Headers, JsonValue, WebFError};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test_async]
+pub async fn test_fetch_rejects_url_without_host(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let result = context.fetch("http://", FetchInit::default(), &exception_state).await;
+  assert!(result.is_err());
+}
+
+#[webf_test]
+pub fn test_json_value_parse(_context: ExecutingContext) {
+  let value = JsonValue::parse(r#"{"name": "webf", "tags": [1, true, null], "escaped": "a\"bé"}"#).unwrap();
+  assert_eq!(value.get("name").and_then(|name| name.as_str()), Some("webf"));
+  let tags = value.get("tags").and_then(|tags| tags.as_array()).unwrap();
+  assert_eq!(tags[0].as_f64(), Some(1.0));
+  assert_eq!(tags[1].as_bool(), Some(true));
+  assert!(tags[2].is_null());
+  assert_eq!(value.get("escaped").and_then(|escaped| escaped.as_str()), Some("a\"bé"));
+  assert!(value.get("missing").is_none());
+
+  assert!(JsonValue::parse("{\"unterminated\": ").is_err());
+  assert!(JsonValue::parse("[1, 2] 3").is_err());
+}
+
+#[webf_test]
+pub fn test_json_value_parse_surrogate_pairs(_context: ExecutingContext) {
+  let value = JsonValue::parse(r#""\ud83d\ude00""#).unwrap();
+  assert_eq!(value.as_str(), Some("\u{1F600}"));
+  assert!(matches!(JsonValue::parse(r#""\ud83d\u0041""#), Err(WebFError::SyntaxError(_))));
+}
+
+#[webf_test]
+pub fn test_json_value_parse_rejects_deep_nesting(_context: ExecutingContext) {
+  let nested = format!("{}{}", "[".repeat(512), "]".repeat(512));
+  assert!(JsonValue::parse(&nested).is_ok());
+
+  let too_deep = "[".repeat(100_000);
+  assert!(matches!(JsonValue::parse(&too_deep), Err(WebFError::SyntaxError(_))));
+}
+
+#[webf_test]
+pub fn test_headers(_context: ExecutingContext) {
+  let mut headers = Headers::new();
+  headers.append("Accept", "text/html");
+  headers.append("accept", " application/json ");
+  headers.set("X-Request-Id", "1");
+  headers.set("x-request-id", "2");
+  headers.append("Set-Cookie", "a=1");
+  headers.append("Set-Cookie", "b=2");
+  assert_eq!(headers.get("ACCEPT"), Some("text/html, application/json".to_string()));
+  assert_eq!(headers.get("x-request-id"), Some("2".to_string()));
+  assert!(headers.has("set-cookie"));
+  assert!(headers.get("missing").is_none());
+
+  let pairs = headers.iter().collect::<Vec<_>>();
+  assert_eq!(pairs, vec![
+    ("accept".to_string(), "text/html, application/json".to_string()),
+    ("set-cookie".to_string(), "a=1".to_string()),
+    ("set-cookie".to_string(), "b=2".to_string()),
+    ("x-request-id".to_string(), "2".to_string()),
+  ]);
+
+  headers.delete("Accept");
+  assert!(!headers.has("accept"));
+  assert_eq!((&headers).into_iter().count(), 3);
+}
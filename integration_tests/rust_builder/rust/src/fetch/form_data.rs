@@ -0,0 +1,70 @@
+use webf_sys::{Blob, ExecutingContext, FetchBody, FetchInit, FormData, FormDataEntryValue};
+use webf_test_macros::webf_test;
+
+fn as_string(value: &FormDataEntryValue) -> &str {
+  match value {
+    FormDataEntryValue::String(value) => value,
+    FormDataEntryValue::File { .. } => panic!("Expected a string entry"),
+  }
+}
+
+#[webf_test]
+pub fn test_form_data_entries(context: ExecutingContext) {
+  let mut form_data = FormData::new(&context);
+  assert!(!form_data.has("tag"));
+  assert!(form_data.get("tag").is_none());
+
+  form_data.append_string("tag", "a");
+  form_data.append_string("name", "webf");
+  form_data.append_string("tag", "b");
+  assert!(form_data.has("tag"));
+  assert_eq!(as_string(form_data.get("tag").unwrap()), "a");
+  let tags = form_data.get_all("tag").into_iter().map(as_string).collect::<Vec<_>>();
+  assert_eq!(tags, vec!["a", "b"]);
+
+  let blob = Blob::from_bytes(&context, b"hello", "Text/Plain");
+  form_data.append_blob("file", &blob, None);
+  form_data.append_blob("file", &blob, Some("hello.txt"));
+  let files = form_data.get_all("file");
+  assert_eq!(files.len(), 2);
+  match files[0] {
+    FormDataEntryValue::File { blob, filename } => {
+      assert_eq!(filename, "blob");
+      assert_eq!(blob.size(), 5);
+      assert_eq!(blob.type_(), "text/plain");
+    }
+    FormDataEntryValue::String(_) => panic!("Expected a file entry"),
+  }
+  match files[1] {
+    FormDataEntryValue::File { filename, .. } => assert_eq!(filename, "hello.txt"),
+    FormDataEntryValue::String(_) => panic!("Expected a file entry"),
+  }
+
+  form_data.delete("tag");
+  assert!(!form_data.has("tag"));
+  assert!(form_data.get_all("tag").is_empty());
+  assert_eq!(as_string(form_data.get("name").unwrap()), "webf");
+}
+
+#[webf_test]
+pub fn test_form_data_multipart_body(context: ExecutingContext) {
+  let mut form_data = FormData::new(&context);
+  form_data.append_string("say\"hi", "line1\nline2");
+  form_data.append_blob("file", &Blob::from_bytes(&context, b"\x00\x01", ""), Some("data.bin"));
+
+  let mut init = FetchInit::default();
+  init.headers.append("Content-Type", "text/plain");
+  init.set_form_data_body(&form_data);
+
+  let content_type = init.headers.get("content-type").unwrap();
+  let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap().to_string();
+  assert!(!boundary.is_empty());
+
+  let mut expected = Vec::new();
+  expected.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+  expected.extend_from_slice(b"Content-Disposition: form-data; name=\"say%22hi\"\r\n\r\nline1\r\nline2\r\n");
+  expected.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+  expected.extend_from_slice(b"Content-Disposition: form-data; name=\"file\"; filename=\"data.bin\"\r\nContent-Type: application/octet-stream\r\n\r\n\x00\x01\r\n");
+  expected.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+  assert_eq!(init.body, Some(FetchBody::Bytes(expected)));
+}
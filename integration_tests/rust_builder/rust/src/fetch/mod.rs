@@ -0,0 +1,3 @@
+pub mod fetch;
+pub mod form_data;
+pub mod xml_http_request;
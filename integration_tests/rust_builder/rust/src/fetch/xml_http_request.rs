@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{ExecutingContext, WebFNativeFuture, XMLHttpRequest, XMLHttpRequestReadyState};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_xml_http_request_states(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let request = XMLHttpRequest::new(&context);
+  assert_eq!(request.ready_state(), XMLHttpRequestReadyState::Unsent);
+  assert!(request.set_request_header("Accept", "text/plain").is_err());
+  assert!(request.send(None, &exception_state).is_err());
+  assert!(request.open("TRACE", "http://localhost/").is_err());
+  assert!(request.open("GE T", "http://localhost/").is_err());
+
+  let changes = Rc::new(RefCell::new(Vec::new()));
+  let changes_in_handler = changes.clone();
+  request.on_ready_state_change(Box::new(move |request| changes_in_handler.borrow_mut().push(request.ready_state())));
+  request.open("get", "http://localhost/").unwrap();
+  assert_eq!(request.ready_state(), XMLHttpRequestReadyState::Opened);
+  assert_eq!(*changes.borrow(), vec![XMLHttpRequestReadyState::Opened]);
+  request.set_request_header("Accept", "text/plain").unwrap();
+  request.set_request_header("accept", "text/html").unwrap();
+  assert_eq!(request.status(), 0);
+  assert_eq!(request.response_text(), "");
+  assert!(request.get_response_header("content-type").is_none());
+}
+
+#[webf_test_async]
+pub async fn test_xml_http_request_error(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let request = XMLHttpRequest::new(&context);
+  let changes = Rc::new(RefCell::new(Vec::new()));
+  let changes_in_handler = changes.clone();
+  request.on_ready_state_change(Box::new(move |request| changes_in_handler.borrow_mut().push(request.ready_state())));
+  let failed = WebFNativeFuture::<u16>::new();
+  let failed_in_handler = failed.clone();
+  request.on_error(Box::new(move |request| failed_in_handler.set_result(Ok(Some(request.status())))));
+  request.on_load(Box::new(|_| panic!("The request without a host should not be loaded.")));
+
+  request.open("POST", "http://").unwrap();
+  request.send(Some(b"payload"), &exception_state).unwrap();
+  assert!(request.send(None, &exception_state).is_err());
+
+  let status = failed.await.unwrap();
+  assert_eq!(status, Some(0));
+  assert_eq!(request.ready_state(), XMLHttpRequestReadyState::Done);
+  assert_eq!(*changes.borrow(), vec![XMLHttpRequestReadyState::Opened, XMLHttpRequestReadyState::Done]);
+}
+
+#[webf_test_async]
+pub async fn test_xml_http_request_abort(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let request = XMLHttpRequest::new(&context);
+  let changes = Rc::new(RefCell::new(Vec::new()));
+  let changes_in_handler = changes.clone();
+  request.on_ready_state_change(Box::new(move |request| changes_in_handler.borrow_mut().push(request.ready_state())));
+  let errors = Rc::new(RefCell::new(0));
+  let failed = WebFNativeFuture::<u16>::new();
+  let errors_in_handler = errors.clone();
+  let failed_in_handler = failed.clone();
+  request.on_error(Box::new(move |request| {
+    *errors_in_handler.borrow_mut() += 1;
+    failed_in_handler.set_result(Ok(Some(request.status())));
+  }));
+
+  request.open("GET", "http://").unwrap();
+  request.send(None, &exception_state).unwrap();
+  request.abort();
+  assert_eq!(request.ready_state(), XMLHttpRequestReadyState::Unsent);
+  assert_eq!(*changes.borrow(), vec![XMLHttpRequestReadyState::Opened, XMLHttpRequestReadyState::Done]);
+
+  // Only the second request reports its failure, the aborted one is ignored.
+  request.open("GET", "http://").unwrap();
+  request.send(None, &exception_state).unwrap();
+  failed.await.unwrap();
+  assert_eq!(*errors.borrow(), 1);
+  assert_eq!(request.ready_state(), XMLHttpRequestReadyState::Done);
+}
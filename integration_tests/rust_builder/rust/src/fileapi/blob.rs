@@ -0,0 +1,43 @@
+use webf_sys::{Blob, ExecutingContext, File};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_blob_slice(context: ExecutingContext) {
+  let blob = Blob::from_bytes(&context, b"hello webf", "Text/Plain");
+  assert_eq!(blob.size(), 10);
+  assert_eq!(blob.type_(), "text/plain");
+  assert_eq!(Blob::from_bytes(&context, b"", "text/\u{e9}").type_(), "");
+
+  let sliced = blob.slice(Some(6), None, None);
+  assert_eq!(sliced.size(), 4);
+  assert_eq!(sliced.type_(), "");
+  assert_eq!(blob.slice(Some(-4), Some(-1), Some("TEXT/HTML")).type_(), "text/html");
+  assert_eq!(blob.slice(Some(-4), Some(-1), None).size(), 3);
+  assert_eq!(blob.slice(Some(8), Some(2), None).size(), 0);
+  assert_eq!(blob.slice(Some(-100), Some(100), None).size(), 10);
+  assert_eq!(sliced.slice(Some(1), Some(3), None).size(), 2);
+}
+
+#[webf_test_async]
+pub async fn test_blob_read(context: ExecutingContext) {
+  let blob = Blob::from_bytes(&context, b"hello webf", "text/plain");
+  assert_eq!(blob.text().await, "hello webf");
+  assert_eq!(blob.slice(Some(6), None, None).slice(Some(1), Some(3), None).text().await, "eb");
+  assert_eq!(blob.slice(Some(0), Some(5), None).array_buffer().await, b"hello".to_vec());
+  assert_eq!(Blob::from_bytes(&context, &[0x68, 0xFF], "").text().await, "h\u{FFFD}");
+}
+
+#[webf_test_async]
+pub async fn test_file(context: ExecutingContext) {
+  let file = File::from_bytes(&context, b"{}", "data.json", "application/json", Some(1700000000000));
+  assert_eq!(file.name(), "data.json");
+  assert_eq!(file.last_modified(), 1700000000000);
+  assert_eq!(file.as_blob().size(), 2);
+  assert_eq!(file.as_blob().type_(), "application/json");
+  assert_eq!(file.as_blob().text().await, "{}");
+
+  let now = File::from_bytes(&context, b"", "empty.txt", "", None);
+  assert!(now.last_modified() > 0);
+  let blob: Blob = now.into();
+  assert_eq!(blob.size(), 0);
+}
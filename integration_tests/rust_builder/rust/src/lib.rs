@@ -3,12 +3,27 @@ use webf_sys::executing_context::ExecutingContextRustMethods;
 use webf_sys::{initialize_webf_api, ExecutingContext, NativeLibraryMetaData, RustValue};
 
 pub mod async_storage;
+pub mod console;
+pub mod dom;
+pub mod error;
+pub mod fetch;
+pub mod fileapi;
 pub mod navigator;
+pub mod script_value;
 pub mod storage;
+pub mod timer;
+pub mod web_socket;
+pub mod window;
 
 #[no_mangle]
 pub extern "C" fn init_webf_test_app(handle: RustValue<ExecutingContextRustMethods>, meta_data: *const NativeLibraryMetaData) -> *mut c_void {
-  let context: ExecutingContext = initialize_webf_api(handle, meta_data);
+  let context: ExecutingContext = match initialize_webf_api(handle, meta_data) {
+    Ok(context) => context,
+    Err(error) => {
+      eprintln!("{}", error);
+      return std::ptr::null_mut();
+    }
+  };
 
   webf_test_utils::sync_runner::run_tests(context.clone());
 
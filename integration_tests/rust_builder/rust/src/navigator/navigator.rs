@@ -1,5 +1,5 @@
 use webf_sys::ExecutingContext;
-use webf_test_macros::webf_test;
+use webf_test_macros::{webf_test, webf_test_async};
 
 #[webf_test]
 pub fn test_hardware_concurrency(context: ExecutingContext) {
@@ -18,3 +18,40 @@ pub fn test_user_agent(context: ExecutingContext) {
 
   assert!(ua_string.contains("WebF"));
 }
+
+#[webf_test]
+pub fn test_languages(context: ExecutingContext) {
+  let navigator = context.navigator();
+  let exception_state = context.create_exception_state();
+  let languages = navigator.languages(&exception_state).unwrap();
+
+  assert!(!languages.is_empty());
+  assert_eq!(languages[0], navigator.language(&exception_state));
+}
+
+#[webf_test]
+pub fn test_platform(context: ExecutingContext) {
+  let navigator = context.navigator();
+  let exception_state = context.create_exception_state();
+
+  assert!(!navigator.platform(&exception_state).is_empty());
+}
+
+#[webf_test_async]
+pub async fn test_clipboard_write_and_read_text(context: ExecutingContext) {
+  let clipboard = context.navigator().clipboard();
+  let exception_state = context.create_exception_state();
+  clipboard.write_text("webf clipboard", &exception_state).await.unwrap();
+  let text = clipboard.read_text(&exception_state).await.unwrap();
+
+  assert_eq!(text, "webf clipboard");
+}
+
+#[webf_test_async]
+pub async fn test_online(context: ExecutingContext) {
+  let navigator = context.navigator();
+  let exception_state = context.create_exception_state();
+  let online = navigator.online(&exception_state).await;
+
+  assert!(online.is_ok());
+}
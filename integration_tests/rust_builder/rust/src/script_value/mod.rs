@@ -0,0 +1 @@
+pub mod script_value;
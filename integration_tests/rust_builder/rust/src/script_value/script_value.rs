@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use webf_sys::{ExecutingContext, FetchBody, FetchInit, Headers, JsonValue, ScriptValue, WebFError};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_script_value_conversions(_context: ExecutingContext) {
+  assert_eq!(ScriptValue::from(true), ScriptValue::Bool(true));
+  assert_eq!(ScriptValue::from(1.5), ScriptValue::Number(1.5));
+  assert_eq!(ScriptValue::from("webf"), ScriptValue::String("webf".to_string()));
+  assert_eq!(ScriptValue::from(None::<bool>), ScriptValue::Null);
+  assert_eq!(ScriptValue::from(vec![1, 2]), ScriptValue::Array(vec![ScriptValue::Number(1.0), ScriptValue::Number(2.0)]));
+
+  let flag: bool = ScriptValue::Bool(false).try_into().unwrap();
+  assert!(!flag);
+  let number: f64 = ScriptValue::Number(3.0).try_into().unwrap();
+  assert_eq!(number, 3.0);
+  let text: Result<String, WebFError> = ScriptValue::Number(3.0).try_into();
+  assert!(matches!(text, Err(WebFError::TypeMismatch(_))));
+  assert_eq!(text.unwrap_err().name(), Some("TypeError"));
+  let names: Vec<String> = ScriptValue::from(vec!["a", "b"]).try_into().unwrap();
+  assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+
+  let mut members = HashMap::new();
+  members.insert("count".to_string(), 2.0);
+  let object = ScriptValue::from(members.clone());
+  let round_trip: HashMap<String, f64> = object.try_into().unwrap();
+  assert_eq!(round_trip, members);
+}
+
+#[webf_test]
+pub fn test_script_value_json(_context: ExecutingContext) {
+  let value = ScriptValue::from_json(r#"{"list": [1, "two", null], "nested": {"ok": true}}"#).unwrap();
+  assert_eq!(ScriptValue::from_json(&value.to_json()).unwrap(), value);
+  assert_eq!(ScriptValue::from(JsonValue::parse("[true]").unwrap()), ScriptValue::Array(vec![ScriptValue::Bool(true)]));
+  assert_eq!(ScriptValue::Number(f64::NAN).to_json(), "null");
+  assert_eq!(ScriptValue::from("a\"b").to_json(), "\"a\\\"b\"");
+  assert!(ScriptValue::from_json("{").is_err());
+}
+
+#[webf_test]
+pub fn test_fetch_init_json_body(_context: ExecutingContext) {
+  let mut init = FetchInit::default();
+  init.set_json_body(&ScriptValue::from(vec![true]));
+  assert_eq!(init.body, Some(FetchBody::Text("[true]".to_string())));
+  assert_eq!(init.headers.iter().collect::<Vec<_>>(), vec![("content-type".to_string(), "application/json".to_string())]);
+
+  let mut headers = Headers::new();
+  headers.set("content-type", "text/plain");
+  let mut init = FetchInit {
+    headers,
+    ..FetchInit::default()
+  };
+  init.set_json_body(&ScriptValue::Null);
+  assert_eq!(init.headers.get("Content-Type"), Some("text/plain".to_string()));
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+  Circle { radius: f64 },
+  Square(u32),
+  Empty,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Drawing {
+  name: String,
+  layers: Vec<u8>,
+  shapes: Vec<Shape>,
+  parent: Option<Box<Drawing>>,
+  id: i64,
+}
+
+#[webf_test]
+pub fn test_script_value_serde_round_trip(_context: ExecutingContext) {
+  let drawing = Drawing {
+    name: "webf".to_string(),
+    layers: vec![1, 2],
+    shapes: vec![Shape::Circle { radius: 1.5 }, Shape::Square(4), Shape::Empty],
+    parent: Some(Box::new(Drawing {
+      name: "root".to_string(),
+      layers: vec![],
+      shapes: vec![],
+      parent: None,
+      id: -1,
+    })),
+    id: 42,
+  };
+
+  let value = ScriptValue::from_serde(&drawing).unwrap();
+  let ScriptValue::Object(members) = &value else {
+    panic!("A struct should become an object");
+  };
+  let ScriptValue::Array(shapes) = &members["shapes"] else {
+    panic!("A vector should become an array");
+  };
+  assert_eq!(shapes[1], ScriptValue::from_json(r#"{"Square": 4}"#).unwrap());
+  assert_eq!(shapes[2], ScriptValue::from("Empty"));
+
+  assert_eq!(value.to_serde::<Drawing>().unwrap(), drawing);
+  assert_eq!(ScriptValue::from_json(&value.to_json()).unwrap().to_serde::<Drawing>().unwrap(), drawing);
+
+  let without_parent = ScriptValue::from_json(r#"{"name": "a", "layers": [], "shapes": [], "id": 1}"#).unwrap();
+  assert_eq!(without_parent.to_serde::<Drawing>().unwrap().parent, None);
+  assert!(ScriptValue::from(true).to_serde::<Drawing>().is_err());
+}
+
+#[webf_test]
+pub fn test_script_value_serde_integers_are_exact(_context: ExecutingContext) {
+  const MAX_SAFE_INTEGER: i64 = 9007199254740991;
+  let value = ScriptValue::from_serde(&vec![MAX_SAFE_INTEGER, -MAX_SAFE_INTEGER]).unwrap();
+  assert_eq!(value.to_serde::<Vec<i64>>().unwrap(), vec![MAX_SAFE_INTEGER, -MAX_SAFE_INTEGER]);
+
+  assert!(matches!(ScriptValue::from_serde(&(MAX_SAFE_INTEGER + 1)), Err(WebFError::RangeError(_))));
+  assert!(matches!(ScriptValue::from_serde(&vec![u64::MAX]), Err(WebFError::RangeError(_))));
+  assert!(matches!(ScriptValue::from_serde(&i64::MIN), Err(WebFError::RangeError(_))));
+
+  assert!(ScriptValue::Number(2f64.powi(60)).to_serde::<u64>().is_err());
+  assert_eq!(ScriptValue::Number(2f64.powi(60)).to_serde::<f64>().unwrap(), 2f64.powi(60));
+}
+
+#[webf_test]
+pub fn test_structured_clone_script_value(context: ExecutingContext) {
+  let mut members = HashMap::new();
+  members.insert("items".to_string(), ScriptValue::from(vec![1, 2]));
+  members.insert("title".to_string(), ScriptValue::from("webf"));
+  let state = ScriptValue::Object(members);
+
+  let mut snapshot = context.structured_clone(&state);
+  assert_eq!(snapshot, state);
+  if let ScriptValue::Object(members) = &mut snapshot {
+    members.insert("title".to_string(), ScriptValue::from("changed"));
+  }
+  assert_ne!(snapshot, state);
+
+  let numbers = context.structured_clone(&ScriptValue::from(vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY]));
+  match numbers {
+    ScriptValue::Array(values) => {
+      assert!(matches!(values[0], ScriptValue::Number(value) if value.is_nan()));
+      assert_eq!(values[1], ScriptValue::Number(f64::INFINITY));
+      assert_eq!(values[2], ScriptValue::Number(f64::NEG_INFINITY));
+    }
+    _ => panic!("The clone of an array should be an array"),
+  }
+}
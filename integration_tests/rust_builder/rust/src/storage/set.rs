@@ -1,11 +1,13 @@
-use webf_sys::ExecutingContext;
+use std::cell::Cell;
+use std::rc::Rc;
+use webf_sys::{EventMethods, ExecutingContext};
 use webf_test_macros::webf_test;
 
 #[webf_test]
 pub fn test_local_storage_method_access(context: ExecutingContext) {
   let storage = context.local_storage();
   let exception_state = context.create_exception_state();
-  storage.clear(&exception_state);
+  storage.clear(&exception_state).unwrap();
 
   let keys = storage.get_all_keys(&exception_state);
   assert_eq!(keys.len(), 0);
@@ -25,7 +27,7 @@ pub fn test_local_storage_method_access(context: ExecutingContext) {
 pub fn test_session_storage_method_access(context: ExecutingContext) {
   let storage = context.session_storage();
   let exception_state = context.create_exception_state();
-  storage.clear(&exception_state);
+  storage.clear(&exception_state).unwrap();
 
   let keys = storage.get_all_keys(&exception_state);
   assert_eq!(keys.len(), 0);
@@ -40,3 +42,55 @@ pub fn test_session_storage_method_access(context: ExecutingContext) {
   let keys = storage.get_all_keys(&exception_state);
   assert_eq!(keys.len(), 0);
 }
+
+#[webf_test]
+pub fn test_local_storage_key_and_length(context: ExecutingContext) {
+  let storage = context.local_storage();
+  let exception_state = context.create_exception_state();
+  storage.clear(&exception_state).unwrap();
+  assert_eq!(storage.length(&exception_state).unwrap(), 0);
+  assert_eq!(storage.key(0, &exception_state).unwrap(), None);
+  assert_eq!(storage.get_item("missing", &exception_state).unwrap(), None);
+
+  storage.set_item("name", "user1", &exception_state).unwrap();
+  assert_eq!(storage.length(&exception_state).unwrap(), 1);
+  assert_eq!(storage.key(0, &exception_state).unwrap(), Some("name".to_string()));
+  assert_eq!(storage.get_item("name", &exception_state).unwrap(), Some("user1".to_string()));
+
+  storage.clear(&exception_state).unwrap();
+  assert_eq!(storage.length(&exception_state).unwrap(), 0);
+}
+
+#[webf_test]
+pub fn test_storage_event_fields(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let event = document.create_event("storage", &exception_state).unwrap();
+  let storage_event = event.as_storage_event().unwrap();
+
+  assert_eq!(storage_event.type_(), "storage");
+  assert_eq!(storage_event.key(), None);
+  assert_eq!(storage_event.old_value(), None);
+  assert_eq!(storage_event.new_value(), None);
+  assert_eq!(storage_event.url(), "");
+  assert!(storage_event.storage_area().is_none());
+
+  let click = document.create_event("click", &exception_state).unwrap();
+  assert!(click.as_storage_event().is_err());
+}
+
+#[webf_test]
+pub fn test_local_storage_change_not_fire_storage_event_in_same_context(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let storage = context.local_storage();
+  let fired = Rc::new(Cell::new(false));
+  let fired_in_listener = fired.clone();
+  let guard = context.window().on_storage(Box::new(move |_event| {
+    fired_in_listener.set(true);
+  }), &exception_state).unwrap();
+  assert_eq!(guard.event_name(), "storage");
+
+  storage.set_item("name", "user1", &exception_state).unwrap();
+  storage.remove_item("name", &exception_state).unwrap();
+  assert!(!fired.get());
+}
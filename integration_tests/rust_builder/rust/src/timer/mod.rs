@@ -0,0 +1,2 @@
+pub mod performance;
+pub mod timer;
@@ -0,0 +1,37 @@
+use webf_sys::{ExecutingContext, WebFError};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_performance_now_is_monotonic(context: ExecutingContext) {
+  let performance = context.performance();
+  let first = performance.now();
+  let second = performance.now();
+  assert!(first >= 0.0);
+  assert!(second >= first);
+  assert!(performance.time_origin() > 0.0);
+}
+
+#[webf_test]
+pub fn test_event_time_stamp_shares_time_origin(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let performance = context.performance();
+  let before = performance.now();
+  let event = context.document().create_event("custom", &exception_state).unwrap();
+  let after = performance.now();
+  assert!(event.time_stamp() >= before.floor());
+  assert!(event.time_stamp() <= after.ceil());
+}
+
+#[webf_test]
+pub fn test_performance_mark_and_measure(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let performance = context.performance();
+  performance.mark("rust-start", &exception_state).unwrap();
+  performance.mark("rust-end", &exception_state).unwrap();
+  let duration = performance.measure("rust-work", Some("rust-start"), Some("rust-end"), &exception_state).unwrap();
+  assert!(duration >= 0.0);
+  assert!(performance.measure("rust-since-start", Some("rust-start"), None, &exception_state).unwrap() >= 0.0);
+
+  let result = performance.measure("rust-missing", Some("rust-missing-mark"), None, &exception_state);
+  assert!(matches!(result, Err(WebFError::TypeError(_))));
+}
@@ -0,0 +1,108 @@
+use webf_sys::{ExecutingContext, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_timer_ids_should_be_unique(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let timeout_id = context.set_timeout_with_callback_and_timeout(Box::new(|| {}), 1000, &exception_state).unwrap();
+  let interval_id = context.set_interval_with_callback_and_timeout(Box::new(|| {}), 1000, &exception_state).unwrap();
+
+  let timers = vec![timeout_id, interval_id];
+  assert_ne!(timers[0], timers[1]);
+
+  context.clear_timeout(timeout_id, &exception_state);
+  context.clear_interval(interval_id, &exception_state);
+  assert!(!exception_state.has_exception());
+}
+
+#[webf_test_async]
+pub async fn test_request_animation_frame(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let future_for_frame = WebFNativeFuture::<f64>::new();
+  let future_in_callback = future_for_frame.clone();
+
+  context.request_animation_frame(Box::new(move |time_stamp| {
+    future_in_callback.set_result(Ok(Some(time_stamp)));
+  }), &exception_state).unwrap();
+
+  let time_stamp = future_for_frame.await.unwrap().unwrap();
+  assert!(time_stamp > 0.0);
+}
+
+#[webf_test_async]
+pub async fn test_spawned_future_awaits_timer(context: ExecutingContext) {
+  let future_for_spawned = WebFNativeFuture::<bool>::new();
+  let future_in_spawned = future_for_spawned.clone();
+  let context_in_spawned = context.clone();
+
+  context.spawn(async move {
+    let exception_state = context_in_spawned.create_exception_state();
+    let future_for_timer = WebFNativeFuture::<bool>::new();
+    let future_in_timer = future_for_timer.clone();
+    context_in_spawned.set_timeout_with_callback_and_timeout(Box::new(move || {
+      future_in_timer.set_result(Ok(Some(true)));
+    }), 10, &exception_state).unwrap();
+    let fired = future_for_timer.await.unwrap().unwrap();
+    future_in_spawned.set_result(Ok(Some(fired)));
+  });
+
+  assert!(future_for_spawned.await.unwrap().unwrap());
+}
+
+#[webf_test_async]
+pub async fn test_request_idle_callback(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let future_for_idle = WebFNativeFuture::<(f64, bool)>::new();
+  let future_in_callback = future_for_idle.clone();
+
+  context.request_idle_callback(Box::new(move |deadline| {
+    future_in_callback.set_result(Ok(Some((deadline.time_remaining(), deadline.did_timeout()))));
+  }), None, &exception_state).unwrap();
+
+  let (time_remaining, did_timeout) = future_for_idle.await.unwrap().unwrap();
+  assert!(time_remaining > 0.0 && time_remaining <= 16.0);
+  assert!(!did_timeout);
+}
+
+#[webf_test_async]
+pub async fn test_cancel_idle_callback(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let fired = std::rc::Rc::new(std::cell::Cell::new(false));
+  let fired_in_callback = fired.clone();
+  let idle_id = context.request_idle_callback(Box::new(move |_| {
+    fired_in_callback.set(true);
+  }), Some(10), &exception_state).unwrap();
+  let other_id = context.request_idle_callback(Box::new(|_| {}), None, &exception_state).unwrap();
+  assert_ne!(idle_id, other_id);
+  context.cancel_idle_callback(idle_id, &exception_state);
+
+  let future_for_timer = WebFNativeFuture::<bool>::new();
+  let future_in_timer = future_for_timer.clone();
+  context.set_timeout_with_callback_and_timeout(Box::new(move || {
+    future_in_timer.set_result(Ok(Some(true)));
+  }), 50, &exception_state).unwrap();
+  future_for_timer.await.unwrap();
+  assert!(!fired.get());
+}
+
+#[webf_test_async]
+pub async fn test_queue_microtask_runs_before_timeout(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let order = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+  let future_for_timer = WebFNativeFuture::<bool>::new();
+  let future_in_timer = future_for_timer.clone();
+
+  let order_in_timer = order.clone();
+  context.set_timeout_with_callback_and_timeout(Box::new(move || {
+    order_in_timer.borrow_mut().push("timeout");
+    future_in_timer.set_result(Ok(Some(true)));
+  }), 0, &exception_state).unwrap();
+  let order_in_microtask = order.clone();
+  context.queue_microtask(Box::new(move || {
+    order_in_microtask.borrow_mut().push("microtask");
+  }), &exception_state).unwrap();
+  order.borrow_mut().push("sync");
+
+  future_for_timer.await.unwrap();
+  assert_eq!(*order.borrow(), vec!["sync", "microtask", "timeout"]);
+}
@@ -0,0 +1 @@
+pub mod web_socket;
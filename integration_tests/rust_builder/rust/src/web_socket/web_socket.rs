@@ -0,0 +1,46 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{CloseEventInit, EventMethods, ExecutingContext, WebSocketReadyState};
+use webf_test_macros::webf_test;
+
+#[webf_test]
+pub fn test_web_socket_rejects_invalid_scheme(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let result = context.web_socket("http://localhost:8080", &[], &exception_state);
+  assert!(result.is_err());
+}
+
+#[webf_test]
+pub fn test_web_socket_connecting(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let web_socket = context.web_socket("ws://localhost:1", &["chat"], &exception_state).unwrap();
+  assert_eq!(web_socket.url(), "ws://localhost:1");
+  assert_eq!(web_socket.ready_state(), WebSocketReadyState::Connecting);
+  assert!(web_socket.send_text("hello", &exception_state).is_err());
+  assert!(web_socket.close(Some(1001), None, &exception_state).is_err());
+  assert!(web_socket.close(Some(1000), Some(&"a".repeat(124)), &exception_state).is_err());
+  web_socket.close(Some(1000), Some("done"), &exception_state).unwrap();
+  assert_eq!(web_socket.ready_state(), WebSocketReadyState::Closing);
+}
+
+#[webf_test]
+pub fn test_web_socket_on_close_with_synthetic_close_event(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let document = context.document();
+  let web_socket = context.web_socket("ws://localhost:1", &[], &exception_state).unwrap();
+
+  let received = Rc::new(RefCell::new(None));
+  let received_in_callback = received.clone();
+  web_socket.on_close(Box::new(move |event| {
+    *received_in_callback.borrow_mut() = Some((event.code(), event.reason(), event.was_clean()));
+  }), &exception_state).unwrap();
+
+  let close_event = document.create_close_event("close", CloseEventInit {
+    code: 1006,
+    reason: "connection lost".to_string(),
+    ..Default::default()
+  }, &exception_state).unwrap();
+  web_socket.event_target().dispatch_event(close_event.as_event(), &exception_state).unwrap();
+
+  assert_eq!(received.borrow().clone(), Some((1006, "connection lost".to_string(), false)));
+}
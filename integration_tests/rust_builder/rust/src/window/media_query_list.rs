@@ -0,0 +1,90 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use webf_sys::{ExecutingContext, NativeValue, WebFError, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+// Resizes the viewport of the test app, -1 resets the size. The change is applied in the next frame.
+fn resize_viewport(context: &ExecutingContext, width: f64, height: f64) {
+  let exception_state = context.create_exception_state();
+  let params = NativeValue::new_list(vec![
+    NativeValue::new_string("resizeViewport"),
+    NativeValue::new_list(vec![NativeValue::new_float64(width), NativeValue::new_float64(height)]),
+  ]);
+  context.webf_invoke_module_with_params_and_callback("MethodChannel", "invokeMethod", &params, Box::new(|_, _| NativeValue::new_null()), &exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_match_media_evaluates_viewport(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  let width = window.inner_width(&exception_state).unwrap();
+  let height = window.inner_height(&exception_state).unwrap();
+
+  let min_width = window.match_media(&format!("(min-width: {}px)", width), &exception_state).unwrap();
+  assert_eq!(min_width.media(), format!("(min-width: {}px)", width));
+  assert!(min_width.matches());
+  assert!(!window.match_media(&format!("(max-width: {}px)", width - 1.0), &exception_state).unwrap().matches());
+  assert!(window.match_media("screen and (min-width: 1px)", &exception_state).unwrap().matches());
+  assert!(!window.match_media("print", &exception_state).unwrap().matches());
+  assert!(window.match_media("not print", &exception_state).unwrap().matches());
+  assert!(window.match_media("print, (min-width: 0px)", &exception_state).unwrap().matches());
+  assert!(window.match_media("", &exception_state).unwrap().matches());
+
+  let portrait = window.match_media("(orientation: portrait)", &exception_state).unwrap();
+  assert_eq!(portrait.matches(), height >= width);
+}
+
+#[webf_test]
+pub fn test_match_media_rejects_invalid_query(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  for query in ["(min-width: 600)", "(min-width)", "screen and", "(min-width: 600px"] {
+    let result = window.match_media(query, &exception_state);
+    assert!(matches!(result, Err(WebFError::SyntaxError(_))), "{} should be rejected", query);
+  }
+}
+
+#[webf_test]
+pub fn test_match_media_unknown_feature_never_matches(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  for query in ["(hover: hover)", "(hover)", "(prefers-color-scheme: dark)", "not (hover: hover)", "screen and (any-pointer: fine)"] {
+    assert!(!window.match_media(query, &exception_state).unwrap().matches(), "{} should not match", query);
+  }
+  assert!(window.match_media("(hover: hover), (min-width: 0px)", &exception_state).unwrap().matches());
+}
+
+#[webf_test_async]
+pub async fn test_media_query_list_change_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  let width = window.inner_width(&exception_state).unwrap();
+  let listener_count = window.event_target.listener_count("resize").unwrap();
+
+  let media_query_list = window.match_media(&format!("(max-width: {}px)", width), &exception_state).unwrap();
+  assert!(media_query_list.matches());
+  let changed: Rc<RefCell<Option<WebFNativeFuture<bool>>>> = Rc::new(RefCell::new(None));
+  let changed_in_listener = changed.clone();
+  media_query_list.add_change_listener(Box::new(move |matches| {
+    if let Some(changed) = changed_in_listener.borrow_mut().take() {
+      changed.set_result(Ok(Some(matches)));
+    }
+  }), &exception_state).unwrap();
+  media_query_list.add_change_listener(Box::new(|_| {}), &exception_state).unwrap();
+  assert_eq!(window.event_target.listener_count("resize").unwrap(), listener_count + 1);
+
+  let widened = WebFNativeFuture::<bool>::new();
+  *changed.borrow_mut() = Some(widened.clone());
+  resize_viewport(&context, width + 100.0, -1.0);
+  assert_eq!(widened.await.unwrap(), Some(false));
+  assert!(!media_query_list.matches());
+
+  let restored = WebFNativeFuture::<bool>::new();
+  *changed.borrow_mut() = Some(restored.clone());
+  resize_viewport(&context, -1.0, -1.0);
+  assert_eq!(restored.await.unwrap(), Some(true));
+  assert!(media_query_list.matches());
+
+  drop(media_query_list);
+  assert_eq!(window.event_target.listener_count("resize").unwrap(), listener_count);
+}
@@ -0,0 +1,2 @@
+pub mod media_query_list;
+pub mod window;
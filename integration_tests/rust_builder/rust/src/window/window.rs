@@ -0,0 +1,128 @@
+use webf_sys::{ExecutingContext, ScriptValue, WebFNativeFuture};
+use webf_test_macros::{webf_test, webf_test_async};
+
+#[webf_test]
+pub fn test_window_viewport(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  assert!(window.inner_width(&exception_state).unwrap() > 0.0);
+  assert!(window.inner_height(&exception_state).unwrap() > 0.0);
+  assert!(window.device_pixel_ratio(&exception_state).unwrap() > 0.0);
+}
+
+#[webf_test]
+pub fn test_window_scroll_offset(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  window.scroll_to_with_x_and_y(0.0, 0.0, &exception_state);
+  assert_eq!(window.scroll_x(&exception_state).unwrap(), 0.0);
+  assert_eq!(window.scroll_y(&exception_state).unwrap(), 0.0);
+}
+
+#[webf_test]
+pub fn test_window_location(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let location = context.window().location();
+  assert!(!location.href(&exception_state).is_empty());
+}
+
+#[webf_test]
+pub fn test_window_resize_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  let guard = window.on_resize(Box::new(|event| {
+    let exception_state = event.view().context().create_exception_state();
+    assert!(event.view().inner_width(&exception_state).unwrap() > 0.0);
+  }), &exception_state).unwrap();
+  assert_eq!(guard.event_name(), "resize");
+  guard.remove(&exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_location_getters(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let location = context.window().location();
+  let href = location.href(&exception_state);
+  assert!(href.starts_with(&location.protocol(&exception_state)));
+  assert!(location.search(&exception_state).is_empty() || location.search(&exception_state).starts_with('?'));
+  assert!(location.hash(&exception_state).is_empty() || location.hash(&exception_state).starts_with('#'));
+  assert!(!location.host(&exception_state).is_empty() || !location.pathname(&exception_state).is_empty());
+}
+
+#[webf_test]
+pub fn test_location_rejects_invalid_url(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let location = context.window().location();
+  assert!(location.assign("http://", &exception_state).is_err());
+  assert!(location.assign("https://exa mple.com", &exception_state).is_err());
+  assert!(location.assign("1http://example.com", &exception_state).is_err());
+  assert!(location.replace("http:example.com", &exception_state).is_err());
+}
+
+#[webf_test]
+pub fn test_location_set_hash_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  let guard = window.on_hashchange(Box::new(|event| {
+    assert!(event.new_url().ends_with("#rust"));
+  }), &exception_state).unwrap();
+  assert_eq!(guard.event_name(), "hashchange");
+  window.location().set_hash("rust", &exception_state).unwrap();
+}
+
+#[webf_test_async]
+pub async fn test_location_replace_keeps_history_length(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  let history = window.history();
+  let length = history.length(&exception_state).unwrap();
+
+  let future_for_hashchange = WebFNativeFuture::<String>::new();
+  let future_in_callback = future_for_hashchange.clone();
+  let guard = window.on_hashchange(Box::new(move |event| {
+    future_in_callback.set_result(Ok(Some(event.new_url())));
+  }), &exception_state).unwrap();
+  window.location().replace("#replaced", &exception_state).unwrap();
+
+  let new_url = future_for_hashchange.await.unwrap().unwrap();
+  assert!(new_url.ends_with("#replaced"));
+  assert_eq!(history.length(&exception_state).unwrap(), length);
+  assert_eq!(window.location().hash(&exception_state), "#replaced");
+  guard.remove(&exception_state).unwrap();
+}
+
+#[webf_test]
+pub fn test_history_push_and_replace_state(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let history = context.window().history();
+  let length = history.length(&exception_state).unwrap();
+  history.push_state(&ScriptValue::from("first"), "", Some("#first"), &exception_state).unwrap();
+  assert_eq!(history.length(&exception_state).unwrap(), length + 1);
+  assert_eq!(history.state(&exception_state).unwrap(), Some(ScriptValue::from("first")));
+
+  history.replace_state(&ScriptValue::from(2), "", None, &exception_state).unwrap();
+  assert_eq!(history.length(&exception_state).unwrap(), length + 1);
+  assert_eq!(history.state(&exception_state).unwrap(), Some(ScriptValue::from(2)));
+}
+
+#[webf_test]
+pub fn test_history_rejects_invalid_url(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let history = context.window().history();
+  let result = history.push_state(&ScriptValue::Null, "", Some("http://"), &exception_state);
+  assert!(result.is_err());
+}
+
+#[webf_test]
+pub fn test_history_popstate_listener(context: ExecutingContext) {
+  let exception_state = context.create_exception_state();
+  let window = context.window();
+  let history = window.history();
+  history.push_state(&ScriptValue::from("previous"), "", None, &exception_state).unwrap();
+  history.push_state(&ScriptValue::from("current"), "", None, &exception_state).unwrap();
+  let guard = window.on_popstate(Box::new(|event| {
+    assert_eq!(event.state(), Some(ScriptValue::from("previous")));
+  }), &exception_state).unwrap();
+  assert_eq!(guard.event_name(), "popstate");
+  history.back(&exception_state).unwrap();
+}
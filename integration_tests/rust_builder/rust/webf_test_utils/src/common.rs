@@ -1,12 +1,12 @@
-use webf_sys::{ExecutingContext, NodeMethods};
+use webf_sys::{ExecutingContext, NodeMethods, TimerId};
 
 fn clear_all_timer(context: ExecutingContext) {
   let exception_state = context.create_exception_state();
   let callback = Box::new(|| {});
   let end_timer = context.set_timeout_with_callback(callback, &exception_state).unwrap();
 
-  for timer in 1..=end_timer {
-    context.clear_timeout(timer, &exception_state);
+  for timer in 1..=end_timer.0 {
+    context.clear_timeout(TimerId(timer), &exception_state);
   }
 }
 
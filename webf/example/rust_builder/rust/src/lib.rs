@@ -1,13 +1,19 @@
 use std::ffi::c_void;
 use webf_sys::event::Event;
 use webf_sys::executing_context::ExecutingContextRustMethods;
-use webf_sys::{initialize_webf_api, AddEventListenerOptions, EventTargetMethods, NativeLibraryMetaData, RustValue};
+use webf_sys::{initialize_webf_api, EventTargetMethods, NativeLibraryMetaData, RustValue};
 use webf_sys::element::Element;
 use webf_sys::node::NodeMethods;
 
 #[no_mangle]
 pub extern "C" fn init_webf_app(handle: RustValue<ExecutingContextRustMethods>, meta_data: *const NativeLibraryMetaData) -> *mut c_void {
-  let context = initialize_webf_api(handle, meta_data);
+  let context = match initialize_webf_api(handle, meta_data) {
+    Ok(context) => context,
+    Err(error) => {
+      println!("{}", error);
+      return std::ptr::null_mut();
+    }
+  };
   println!("Context created");
   let exception_state = context.create_exception_state();
   let document = context.document();
@@ -34,39 +40,31 @@ pub extern "C" fn init_webf_app(handle: RustValue<ExecutingContextRustMethods>,
   });
 
   let click_event = document.create_event("custom_click", &exception_state).unwrap();
-  document.dispatch_event(&click_event, &exception_state);
-
-  let div_element = document.create_element("div", &exception_state).unwrap();
-
-  let event_listener_options = AddEventListenerOptions {
-    passive: 0,
-    once: 0,
-    capture: 0,
-  };
-
-  let event_handler = Box::new(|event: &Event| {
-    let context = event.context();
+  match document.dispatch_event(&click_event, &exception_state) {
+    Ok(not_canceled) => println!("custom_click dispatched, canceled: {}", !not_canceled),
+    Err(err) => println!("Dispatch custom_click failed: {err}"),
+  }
+
+  let context_handle = context.handle();
+  let event_handler = Box::new(move |_: &Event| {
+    let context = context_handle.get();
     let exception_state = context.create_exception_state();
     let document = context.document();
     let div = document.create_element("div", &exception_state).unwrap();
-    let text_node = document.create_text_node("Created By Event Handler", &exception_state).unwrap();
-    div.append_child(&text_node.as_node(), &exception_state).unwrap();
+    div.append(&["Created By Event Handler".into()], &exception_state).unwrap();
     document.body().append_child(&div.as_node(), &exception_state).unwrap();
   });
 
-  div_element.add_event_listener("custom_click", event_handler.clone(), &event_listener_options, &exception_state).unwrap();
+  let click_position = document.create_element("div", &exception_state).unwrap();
+  document.body().append_child(&click_position.as_node(), &exception_state).unwrap();
 
   let real_click_handler = Box::new(move |event: &Event| {
     match event.as_mouse_event() {
       Ok(mouse_event) => {
         let x = mouse_event.offset_x();
         let y = mouse_event.offset_y();
-        let document = context.document();
         let exception_state = context.create_exception_state();
-        let div = document.create_element("div", &exception_state).unwrap();
-        let text_node = document.create_text_node(format!("Mouse Clicked at x: {}, y: {}", x, y).as_str(), &exception_state).unwrap();
-        div.append_child(&text_node.as_node(), &exception_state).unwrap();
-        document.body().append_child(&div.as_node(), &exception_state).unwrap();
+        click_position.set_text(&format!("Mouse Clicked at x: {}, y: {}", x, y), &exception_state).unwrap();
       },
       Err(_) => {
         println!("Not a mouse event");
@@ -82,7 +80,9 @@ pub extern "C" fn init_webf_app(handle: RustValue<ExecutingContextRustMethods>,
       Ok(custom_click_event) => {
         let event_target = event.target();
         let element: Element = event_target.as_element().unwrap();
-        let _ = element.dispatch_event(&custom_click_event, &exception_state);
+        if let Err(err) = element.dispatch_event(&custom_click_event, &exception_state) {
+          println!("{err}");
+        }
       },
       Err(err) => {
         println!("{err}");
@@ -90,28 +90,33 @@ pub extern "C" fn init_webf_app(handle: RustValue<ExecutingContextRustMethods>,
     }
   });
 
-  div_element.add_event_listener("click", real_click_handler, &event_listener_options, &exception_state).unwrap();
-
-  let text_node = document.create_text_node("From Rust", &exception_state).unwrap();
-
-  div_element.append_child(&text_node.as_node(), &exception_state).expect("append Node Failed");
+  let div_element = document.build_element("div")
+    .on("custom_click", event_handler.clone())
+    .on("click", real_click_handler)
+    .text("From Rust")
+    .build(&exception_state)
+    .unwrap();
 
   document.body().append_child(&div_element.as_node(), &exception_state).unwrap();
 
-  let event_cleaner_element = document.create_element("button", &exception_state).unwrap();
-
-  let event_cleaner_text_node = document.create_text_node("Remove Event", &exception_state).unwrap();
-
-  event_cleaner_element.append_child(&event_cleaner_text_node.as_node(), &exception_state).unwrap();
-
+  // Capture a weak reference so the div is freed once it's removed from the DOM.
+  let weak_div_element = div_element.as_node().event_target.downgrade();
   let event_cleaner_handler = Box::new(move |event: &Event| {
+    let Some(div_element) = weak_div_element.upgrade() else {
+      return;
+    };
     let context = event.context();
     let exception_state = context.create_exception_state();
 
     let _ = div_element.remove_event_listener("custom_click", event_handler.clone(), &exception_state);
+    println!("custom_click listeners left: {}", div_element.listener_count("custom_click").unwrap());
   });
 
-  event_cleaner_element.add_event_listener("click", event_cleaner_handler, &event_listener_options, &exception_state).unwrap();
+  let event_cleaner_element = document.build_element("button")
+    .text("Remove Event")
+    .on("click", event_cleaner_handler)
+    .build(&exception_state)
+    .unwrap();
 
   document.body().append_child(&event_cleaner_element.as_node(), &exception_state).unwrap();
 
@@ -8,8 +8,18 @@ import 'package:webf/css.dart';
 
 /// https://drafts.csswg.org/cssom/#the-cssstylerule-interface
 class CSSStyleRule extends CSSRule {
+  // https://drafts.csswg.org/cssom/#serialize-a-css-rule
   @override
-  String get cssText => declaration.cssText;
+  String get cssText {
+    List<String> declarations = [];
+    for (MapEntry<String, CSSPropertyValue> entry in declaration) {
+      declarations.add('${kebabize(entry.key)}: ${entry.value.value};');
+    }
+    if (declarations.isEmpty) {
+      return '${selectorGroup.selectorText} { }';
+    }
+    return '${selectorGroup.selectorText} { ${declarations.join(' ')} }';
+  }
 
   @override
   int get type => CSSRule.STYLE_RULE;
@@ -395,6 +395,56 @@ mixin ElementOverflowMixin on ElementBase {
     _scrollTo(x: x, y: y, withAnimation: withAnimation);
   }
 
+  // https://drafts.csswg.org/cssom-view/#dom-element-scrollintoview
+  // Scrolls every scrollable ancestor, from the nearest one outwards, so that this element becomes visible.
+  void scrollIntoView({String block = 'start', String inline = 'nearest', bool withAnimation = false}) {
+    if (!isRendererAttached) return;
+    _ensureRenderObjectHasLayout();
+    RenderBoxModel target = renderBoxModel!;
+    if (!target.hasSize) return;
+
+    Element? ancestor = (this as Element).parentElement;
+    while (ancestor != null) {
+      RenderBoxModel? ancestorBox = ancestor.renderBoxModel;
+      WebFScrollable? scrollableX = ancestor._getScrollable(Axis.horizontal);
+      WebFScrollable? scrollableY = ancestor._getScrollable(Axis.vertical);
+      if (ancestorBox != null && ancestorBox.hasSize && (scrollableX != null || scrollableY != null)) {
+        // The position of the target relative to the visible area of the ancestor's padding box.
+        Offset offset = target.getOffsetToAncestor(Offset.zero, ancestorBox);
+        if (scrollableX != null) {
+          double dx = _scrollIntoViewDelta(offset.dx, target.size.width, ancestorBox.clientWidth, inline);
+          ancestor._scroll(ancestor.scrollLeft + dx, Axis.horizontal, withAnimation: withAnimation);
+        }
+        if (scrollableY != null) {
+          double dy = _scrollIntoViewDelta(offset.dy, target.size.height, ancestorBox.clientHeight, block);
+          ancestor._scroll(ancestor.scrollTop + dy, Axis.vertical, withAnimation: withAnimation);
+        }
+      }
+      ancestor = ancestor.parentElement;
+    }
+  }
+
+  // Returns the scroll distance which aligns a box at |start| with |length| inside a viewport of |viewportLength|.
+  static double _scrollIntoViewDelta(double start, double length, double viewportLength, String alignment) {
+    switch (alignment) {
+      case 'end':
+        return start + length - viewportLength;
+      case 'center':
+        return start + length / 2 - viewportLength / 2;
+      case 'nearest':
+        bool startIsOutside = start < 0;
+        bool endIsOutside = start + length > viewportLength;
+        // Already fully visible, or covering the whole viewport.
+        if (startIsOutside == endIsOutside) return 0;
+        // Align the edge closest to the viewport, or the start edge when the box is larger than the viewport.
+        if (startIsOutside || length > viewportLength) return start;
+        return start + length - viewportLength;
+      case 'start':
+      default:
+        return start;
+    }
+  }
+
   void _ensureRenderObjectHasLayout() {
     if (renderBoxModel?.needsLayout == true) {
       renderBoxModel!.owner?.flushLayout();
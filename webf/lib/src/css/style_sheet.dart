@@ -23,9 +23,16 @@ class CSSStyleSheet implements StyleSheet, Comparable {
 
   CSSStyleSheet(this.cssRules, {this.disabled = false, this.href});
 
-  insertRule(String text, int index) {
+  /// Inserts the rules parsed from [text] before the rule at [index], and returns [index].
+  /// Nested rules are flattened by the parser, so one text may insert more than one rule.
+  int insertRule(String text, int index) {
+    RangeError.checkValueInInterval(index, 0, cssRules.length, 'index');
     List<CSSRule> rules = CSSParser(text).parseRules();
-    cssRules.addAll(rules);
+    if (rules.isEmpty) {
+      throw FormatException('Failed to parse the rule.', text);
+    }
+    cssRules.insertAll(index, rules);
+    return index;
   }
 
   /// Removes a rule from the stylesheet object.
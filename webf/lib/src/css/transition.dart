@@ -3,6 +3,8 @@
  * Copyright (C) 2022-present The WebF authors. All rights reserved.
  */
 
+import 'dart:math' as math;
+
 import 'package:flutter/rendering.dart';
 import 'package:flutter/animation.dart' show Curve;
 import 'package:webf/css.dart';
@@ -383,9 +385,10 @@ mixin CSSTransitionMixin on RenderStyle {
 
       CSSTransitionHandlers[propertyName];
 
+      double elapsedTime = math.max(0.0, (animation.currentTime ?? 0.0) / 1000);
       animation.cancel();
       // An Event fired when a CSS transition has been cancelled.
-      target.dispatchEvent(Event(EVENT_TRANSITION_CANCEL));
+      target.dispatchEvent(TransitionEvent(EVENT_TRANSITION_CANCEL, propertyName: kebabize(propertyName), elapsedTime: elapsedTime));
     }
 
     if (begin == null || (begin is String && begin.isEmpty)) {
@@ -409,21 +412,24 @@ mixin CSSTransitionMixin on RenderStyle {
     Animation animation = Animation(effect, target.ownerDocument.animationTimeline);
     _propertyRunningTransition[propertyName] = animation;
 
+    // The events report the property name as it's written in CSS, such as background-color.
+    String cssPropertyName = kebabize(propertyName);
     animation.onstart = () {
       // An Event fired when a CSS transition is created,
       // when it is added to a set of running transitions,
       // though not necessarily started.
-      target.dispatchEvent(Event(EVENT_TRANSITION_START));
+      target.dispatchEvent(TransitionEvent(EVENT_TRANSITION_START, propertyName: cssPropertyName));
     };
 
     animation.onfinish = (AnimationPlaybackEvent event) {
       _propertyRunningTransition.remove(propertyName);
       target.setRenderStyle(propertyName, end);
       // An Event fired when a CSS transition has finished playing.
-      target.dispatchEvent(Event(EVENT_TRANSITION_END));
+      target.dispatchEvent(TransitionEvent(EVENT_TRANSITION_END,
+          propertyName: cssPropertyName, elapsedTime: (options?.duration ?? 0.0) / 1000));
     };
 
-    target.dispatchEvent(Event(EVENT_TRANSITION_RUN));
+    target.dispatchEvent(TransitionEvent(EVENT_TRANSITION_RUN, propertyName: cssPropertyName));
 
     animation.play();
   }
@@ -293,6 +293,7 @@ abstract class Element extends ContainerNode with ElementBase, ElementEventMixin
     properties['offsetLeft'] = BindingObjectProperty(getter: () => offsetLeft);
     properties['offsetWidth'] = BindingObjectProperty(getter: () => offsetWidth);
     properties['offsetHeight'] = BindingObjectProperty(getter: () => offsetHeight);
+    properties['offsetParent'] = BindingObjectProperty(getter: () => offsetParent);
 
     properties['scrollTop'] =
         BindingObjectProperty(getter: () => scrollTop, setter: (value) => scrollTop = castToType<double>(value));
@@ -323,6 +324,11 @@ abstract class Element extends ContainerNode with ElementBase, ElementEventMixin
         BindingObjectMethodSync(call: (args) => scrollBy(castToType<double>(args[0]), castToType<double>(args[1])));
     methods['scrollTo'] =
         BindingObjectMethodSync(call: (args) => scrollTo(castToType<double>(args[0]), castToType<double>(args[1])));
+    methods['scrollIntoView'] = BindingObjectMethodSync(
+        call: (args) => scrollIntoView(
+            block: castToType<String>(args[0]),
+            inline: castToType<String>(args[1]),
+            withAnimation: castToType<bool>(args[2])));
     methods['click'] = BindingObjectMethodSync(call: (_) => click());
     methods['getElementsByClassName'] = BindingObjectMethodSync(call: (args) => getElementsByClassName(args));
     methods['getElementsByTagName'] = BindingObjectMethodSync(call: (args) => getElementsByTagName(args));
@@ -330,6 +336,14 @@ abstract class Element extends ContainerNode with ElementBase, ElementEventMixin
     methods['querySelector'] = BindingObjectMethodSync(call: (args) => querySelector(args));
     methods['matches'] = BindingObjectMethodSync(call: (args) => matches(args));
     methods['closest'] = BindingObjectMethodSync(call: (args) => closest(args));
+    methods['setPointerCapture'] =
+        BindingObjectMethodSync(call: (args) => setPointerCapture(castToType<int>(args[0])));
+    methods['releasePointerCapture'] =
+        BindingObjectMethodSync(call: (args) => releasePointerCapture(castToType<int>(args[0])));
+    methods['hasPointerCapture'] =
+        BindingObjectMethodSync(call: (args) => hasPointerCapture(castToType<int>(args[0])));
+    methods['requestFullscreen'] = BindingObjectMethodSync(call: (_) => requestFullscreen());
+    methods['exitFullscreen'] = BindingObjectMethodSync(call: (_) => exitFullscreen());
 
     if (kDebugMode || kProfileMode) {
       methods['__test_global_to_local__'] = BindingObjectMethodSync(call: (args) => testGlobalToLocal(args[0], args[1]));
@@ -364,6 +378,65 @@ abstract class Element extends ContainerNode with ElementBase, ElementEventMixin
     return QuerySelector.closest(this, args.first);
   }
 
+  // https://w3c.github.io/pointerevents/#dom-element-setpointercapture
+  // Returns false when the pointer is not active, which is thrown as a NotFoundError by the bridge.
+  bool setPointerCapture(int pointerId) {
+    return ownerDocument.controller.gestureDispatcher.setPointerCapture(pointerId, this);
+  }
+
+  // https://w3c.github.io/pointerevents/#dom-element-releasepointercapture
+  bool releasePointerCapture(int pointerId) {
+    return ownerDocument.controller.gestureDispatcher.releasePointerCapture(pointerId, this);
+  }
+
+  bool hasPointerCapture(int pointerId) {
+    return ownerDocument.controller.gestureDispatcher.hasPointerCapture(pointerId, this);
+  }
+
+  static const Map<String, String> _fullscreenStyle = {
+    POSITION: 'fixed',
+    TOP: '0',
+    LEFT: '0',
+    WIDTH: '100vw',
+    HEIGHT: '100vh',
+    Z_INDEX: '2147483647',
+  };
+
+  // The inline styles replaced by the fullscreen styles, null when the element is not fullscreen.
+  Map<String, String>? _inlineStyleBeforeFullscreen;
+
+  // https://fullscreen.spec.whatwg.org/#dom-element-requestfullscreen
+  // The bridge tracks the fullscreen element, the element is only laid over the viewport here.
+  // Returns false when there is no transient user activation, which rejects the request at the bridge.
+  bool requestFullscreen() {
+    if (_inlineStyleBeforeFullscreen != null) return true;
+    if (!ownerDocument.controller.gestureDispatcher.consumeTransientUserActivation()) return false;
+    final Map<String, String> inlineStyleBeforeFullscreen = {};
+    _fullscreenStyle.forEach((property, value) {
+      inlineStyleBeforeFullscreen[property] = inlineStyle[property] ?? EMPTY_STRING;
+      setInlineStyle(property, value);
+    });
+    _inlineStyleBeforeFullscreen = inlineStyleBeforeFullscreen;
+    style.flushPendingProperties();
+    return true;
+  }
+
+  // https://fullscreen.spec.whatwg.org/#dom-document-exitfullscreen
+  void exitFullscreen() {
+    final inlineStyleBeforeFullscreen = _inlineStyleBeforeFullscreen;
+    if (inlineStyleBeforeFullscreen == null) return;
+    _inlineStyleBeforeFullscreen = null;
+    inlineStyleBeforeFullscreen.forEach((property, value) {
+      if (value.isEmpty) {
+        inlineStyle.remove(property);
+        style.removeProperty(property, true);
+      } else {
+        setInlineStyle(property, value);
+      }
+    });
+    recalculateStyle();
+  }
+
   void updateRenderBoxModel({ bool forceUpdate = false }) {
     RenderBoxModel nextRenderBoxModel;
     if (isWidgetElement) {
@@ -15,6 +15,9 @@ enum AppearEventType { none, appear, disappear }
 
 const String EVENT_CLICK = 'click';
 const String EVENT_INPUT = 'input';
+const String EVENT_COMPOSITION_START = 'compositionstart';
+const String EVENT_COMPOSITION_UPDATE = 'compositionupdate';
+const String EVENT_COMPOSITION_END = 'compositionend';
 const String EVENT_APPEAR = 'appear';
 const String EVENT_DISAPPEAR = 'disappear';
 const String EVENT_COLOR_SCHEME_CHANGE = 'colorschemechange';
@@ -24,6 +27,8 @@ const String EVENT_TOUCH_START = 'touchstart';
 const String EVENT_TOUCH_MOVE = 'touchmove';
 const String EVENT_TOUCH_END = 'touchend';
 const String EVENT_TOUCH_CANCEL = 'touchcancel';
+const String EVENT_GOT_POINTER_CAPTURE = 'gotpointercapture';
+const String EVENT_LOST_POINTER_CAPTURE = 'lostpointercapture';
 const String EVENT_MESSAGE = 'message';
 const String EVENT_CLOSE = 'close';
 const String EVENT_OPEN = 'open';
@@ -50,6 +55,7 @@ const String EVENT_PAUSE = 'pause';
 const String EVENT_POP_STATE = 'popstate';
 const String EVENT_HYBRID_ROUTER_CHANGE = 'hybridrouterchange';
 const String EVENT_HASH_CHANGE = 'hashchange';
+const String EVENT_STORAGE = 'storage';
 const String EVENT_PLAY = 'play';
 const String EVENT_SEEKED = 'seeked';
 const String EVENT_SEEKING = 'seeking';
@@ -314,6 +320,65 @@ class HashChangeEvent extends Event {
   }
 }
 
+class StorageEvent extends Event {
+  final String? key;
+  final String? oldValue;
+  final String? newValue;
+  final String url;
+  // The name of the global storage object, such as `localStorage`.
+  final String storageArea;
+
+  StorageEvent({this.key, this.oldValue, this.newValue, required this.url, required this.storageArea})
+      : super(EVENT_STORAGE);
+
+  @override
+  Pointer<NativeType> toRaw([int extraLength = 0, bool isCustomEvent = false]) {
+    List<int> methods = [
+      key != null ? stringToNativeString(key!).address : nullptr.address,
+      oldValue != null ? stringToNativeString(oldValue!).address : nullptr.address,
+      newValue != null ? stringToNativeString(newValue!).address : nullptr.address,
+      stringToNativeString(url).address,
+      stringToNativeString(storageArea).address
+    ];
+
+    Pointer<RawEvent> rawEvent = super.toRaw(methods.length).cast<RawEvent>();
+    int currentStructSize = rawEvent.ref.length + methods.length;
+    Uint64List bytes = rawEvent.ref.bytes.asTypedList(currentStructSize);
+    bytes.setAll(rawEvent.ref.length, methods);
+    rawEvent.ref.length = currentStructSize;
+
+    return rawEvent;
+  }
+}
+
+class TransitionEvent extends Event {
+  // The name of the CSS property associated with the transition.
+  final String propertyName;
+  // The amount of time the transition has been running, in seconds.
+  final double elapsedTime;
+  final String pseudoElement;
+
+  TransitionEvent(String type, {required this.propertyName, this.elapsedTime = 0.0, this.pseudoElement = ''})
+      : super(type);
+
+  @override
+  Pointer<NativeType> toRaw([int extraLength = 0, bool isCustomEvent = false]) {
+    List<int> methods = [
+      doubleToUint64(elapsedTime),
+      stringToNativeString(propertyName).address,
+      stringToNativeString(pseudoElement).address
+    ];
+
+    Pointer<RawEvent> rawEvent = super.toRaw(methods.length).cast<RawEvent>();
+    int currentStructSize = rawEvent.ref.length + methods.length;
+    Uint64List bytes = rawEvent.ref.bytes.asTypedList(currentStructSize);
+    bytes.setAll(rawEvent.ref.length, methods);
+    rawEvent.ref.length = currentStructSize;
+
+    return rawEvent;
+  }
+}
+
 class UIEvent extends Event {
   // Returns a long with details about the event, depending on the event type.
   // For click or dblclick events, UIEvent.detail is the current click count.
@@ -384,29 +449,72 @@ class FocusEvent extends UIEvent {
 
 /// reference: https://developer.mozilla.org/zh-CN/docs/Web/API/MouseEvent
 class MouseEvent extends UIEvent {
+  bool altKey;
+  // The button which changed state, 0 is the main button, 1 the auxiliary button and 2 the secondary button.
+  double button;
+  // The buttons held down as a bitmask, 1 is the main button, 2 the secondary button and 4 the auxiliary button.
+  double buttons;
   double clientX;
   double clientY;
+  bool ctrlKey;
+  bool metaKey;
+  double movementX;
+  double movementY;
   double offsetX;
   double offsetY;
+  double pageX;
+  double pageY;
+  double screenX;
+  double screenY;
+  bool shiftKey;
 
   MouseEvent(
     String type, {
+    this.altKey = false,
+    this.button = 0.0,
+    this.buttons = 0.0,
     this.clientX = 0.0,
     this.clientY = 0.0,
+    this.ctrlKey = false,
+    this.metaKey = false,
+    this.movementX = 0.0,
+    this.movementY = 0.0,
     this.offsetX = 0.0,
     this.offsetY = 0.0,
+    double? pageX,
+    double? pageY,
+    this.screenX = 0.0,
+    this.screenY = 0.0,
+    this.shiftKey = false,
     double detail = 0.0,
     EventTarget? view,
     double which = 0.0,
-  }) : super(type, detail: detail, view: view, which: which, bubbles: true, cancelable: true, composed: false);
+  })  : pageX = pageX ?? clientX,
+        pageY = pageY ?? clientY,
+        super(type, detail: detail, view: view, which: which, bubbles: true, cancelable: true, composed: false);
 
+  // The members must keep the same order as the NativeMouseEvent struct, which is generated from mouse_event.d.ts.
   @override
   Pointer toRaw([int extraLength = 0, bool isCustomEvent = false]) {
     List<int> methods = [
+      altKey ? 1 : 0,
+      doubleToUint64(button),
+      doubleToUint64(buttons),
       doubleToUint64(clientX),
       doubleToUint64(clientY),
+      ctrlKey ? 1 : 0,
+      metaKey ? 1 : 0,
+      doubleToUint64(movementX),
+      doubleToUint64(movementY),
       doubleToUint64(offsetX),
-      doubleToUint64(offsetY)
+      doubleToUint64(offsetY),
+      doubleToUint64(pageX),
+      doubleToUint64(pageY),
+      doubleToUint64(screenX),
+      doubleToUint64(screenY),
+      shiftKey ? 1 : 0,
+      doubleToUint64(clientX),
+      doubleToUint64(clientY)
     ];
 
     Pointer<RawEvent> rawEvent = super.toRaw(methods.length + extraLength).cast<RawEvent>();
@@ -504,10 +612,16 @@ class InputEvent extends UIEvent {
   // deleteContentBackward, insertFromPaste, and formatBold.
   final String inputType;
   final String data;
+  // Whether the event fires between compositionstart and compositionend.
+  final bool isComposing;
 
   @override
   Pointer toRaw([int extraLength = 0, bool isCustomEvent = false]) {
-    List<int> methods = [stringToNativeString(inputType).address, stringToNativeString(data).address];
+    List<int> methods = [
+      stringToNativeString(inputType).address,
+      stringToNativeString(data).address,
+      isComposing ? 1 : 0
+    ];
 
     Pointer<RawEvent> rawEvent = super.toRaw(methods.length).cast<RawEvent>();
     int currentStructSize = rawEvent.ref.length + methods.length;
@@ -521,12 +635,40 @@ class InputEvent extends UIEvent {
   InputEvent({
     this.inputType = '',
     this.data = '',
+    this.isComposing = false,
     super.bubbles,
     super.cancelable,
     super.composed,
   }) : super(EVENT_INPUT);
 }
 
+// https://w3c.github.io/uievents/#interface-compositionevent
+class CompositionEvent extends UIEvent {
+  // The characters generated by the input method, or the text being replaced when the composition starts.
+  final String data;
+
+  @override
+  Pointer toRaw([int extraLength = 0, bool isCustomEvent = false]) {
+    List<int> methods = [stringToNativeString(data).address];
+
+    Pointer<RawEvent> rawEvent = super.toRaw(methods.length).cast<RawEvent>();
+    int currentStructSize = rawEvent.ref.length + methods.length;
+    Uint64List bytes = rawEvent.ref.bytes.asTypedList(currentStructSize);
+    bytes.setAll(rawEvent.ref.length, methods);
+    rawEvent.ref.length = currentStructSize;
+
+    return rawEvent;
+  }
+
+  CompositionEvent(
+    String type, {
+    this.data = '',
+    super.bubbles,
+    super.cancelable,
+    super.composed,
+  }) : super(type);
+}
+
 class AppearEvent extends Event {
   AppearEvent() : super(EVENT_APPEAR);
 }
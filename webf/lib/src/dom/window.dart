@@ -117,9 +117,8 @@ class Window extends EventTarget {
   @override
   Future<void> dispatchEvent(Event event) async {
     // Events such as EVENT_DOM_CONTENT_LOADED need to ensure that listeners are flushed and registered.
-    if (contextId != null && event.type == EVENT_DOM_CONTENT_LOADED ||
-        event.type == EVENT_LOAD ||
-        event.type == EVENT_ERROR) {
+    if (contextId != null &&
+        (event.type == EVENT_DOM_CONTENT_LOADED || event.type == EVENT_LOAD || event.type == EVENT_ERROR)) {
       flushUICommandWithContextId(contextId!, pointer!);
     }
     return super.dispatchEvent(event);
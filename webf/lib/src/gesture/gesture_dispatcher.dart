@@ -161,6 +161,81 @@ class GestureDispatcher {
     _pointTargets.remove(touchPoint.id);
   }
 
+  // The targets which captured the pointers by setPointerCapture(), and the targets they were bound with before that.
+  // https://w3c.github.io/pointerevents/#pointer-capture
+  final Map<int, EventTarget> _pointerCaptureTargets = {};
+  final Map<int, EventTarget> _targetsBeforeCapture = {};
+
+  // Route the following touch events of the pointer to the target, even when the pointer leaves it.
+  // Returns false when the pointer is not active.
+  bool setPointerCapture(int pointer, EventTarget target) {
+    if (!_touchPoints.containsKey(pointer)) {
+      return false;
+    }
+    EventTarget? previousTarget = _pointerCaptureTargets[pointer];
+    if (previousTarget == target) {
+      return true;
+    }
+
+    if (previousTarget == null && _pointTargets.containsKey(pointer)) {
+      _targetsBeforeCapture[pointer] = _pointTargets[pointer]!;
+    }
+    _pointerCaptureTargets[pointer] = target;
+    _pointTargets[pointer] = target;
+    // The capturing target may not be in the path of the pointer down event.
+    target.getEventHandlers().keys.forEach((eventType) {
+      _eventsInPath[eventType] = true;
+    });
+
+    previousTarget?.dispatchEvent(Event(EVENT_LOST_POINTER_CAPTURE, bubbles: true));
+    target.dispatchEvent(Event(EVENT_GOT_POINTER_CAPTURE, bubbles: true));
+    return true;
+  }
+
+  // Returns false when the pointer is not active, releasing a pointer captured by another target does nothing.
+  bool releasePointerCapture(int pointer, EventTarget target) {
+    if (!_touchPoints.containsKey(pointer)) {
+      return false;
+    }
+    if (_pointerCaptureTargets[pointer] == target) {
+      _clearPointerCapture(pointer);
+    }
+    return true;
+  }
+
+  bool hasPointerCapture(int pointer, EventTarget target) {
+    return _pointerCaptureTargets[pointer] == target;
+  }
+
+  void _clearPointerCapture(int pointer) {
+    EventTarget? target = _pointerCaptureTargets.remove(pointer);
+    EventTarget? targetBeforeCapture = _targetsBeforeCapture.remove(pointer);
+    if (target == null) {
+      return;
+    }
+    if (targetBeforeCapture != null && _pointTargets.containsKey(pointer)) {
+      _pointTargets[pointer] = targetBeforeCapture;
+    }
+    target.dispatchEvent(Event(EVENT_LOST_POINTER_CAPTURE, bubbles: true));
+  }
+
+  // Chromium keeps the transient activation for 5 seconds.
+  // https://html.spec.whatwg.org/multipage/interaction.html#transient-activation-duration
+  static const Duration _transientActivationDuration = Duration(seconds: 5);
+  DateTime? _lastUserActivationTime;
+
+  // Returns whether the user pressed or lifted a pointer recently, the activation is consumed if so.
+  // https://html.spec.whatwg.org/multipage/interaction.html#consume-user-activation
+  bool consumeTransientUserActivation() {
+    DateTime? lastUserActivationTime = _lastUserActivationTime;
+    if (lastUserActivationTime == null ||
+        DateTime.now().difference(lastUserActivationTime) > _transientActivationDuration) {
+      return false;
+    }
+    _lastUserActivationTime = null;
+    return true;
+  }
+
   TouchPoint _toTouchPoint(PointerEvent pointerEvent) {
     PointState pointState = PointState.Cancel;
     if (pointerEvent is PointerDownEvent) {
@@ -237,6 +312,10 @@ class GestureDispatcher {
       return;
     }
 
+    if (event is PointerDownEvent || event is PointerUpEvent) {
+      _lastUserActivationTime = DateTime.now();
+    }
+
     // Stores the current TouchPoint to trigger the corresponding event.
     TouchPoint touchPoint = _toTouchPoint(event);
 
@@ -263,6 +342,8 @@ class GestureDispatcher {
     }
 
     if (event is PointerUpEvent || event is PointerCancelEvent) {
+      // The capture is released implicitly after the pointer is lifted.
+      _clearPointerCapture(touchPoint.id);
       _removePoint(touchPoint);
       _unbindEventTargetWithTouchPoint(touchPoint);
       // Clear target in the next task or start a timer.
@@ -378,12 +459,17 @@ class GestureDispatcher {
     double clientX = globalOffset.dx;
     double clientY = globalOffset.dy;
 
+    Window window = (_target as Node).ownerDocument.defaultView;
     Event event = MouseEvent(type,
         clientX: clientX,
         clientY: clientY,
         offsetX: localPosition.dx,
         offsetY: localPosition.dy,
-        view: (_target as Node).ownerDocument.defaultView);
+        pageX: clientX + window.scrollX,
+        pageY: clientY + window.scrollY,
+        screenX: globalPosition.dx,
+        screenY: globalPosition.dy,
+        view: window);
     _target?.dispatchEvent(event);
   }
 
@@ -2,6 +2,7 @@
  * Copyright (C) 2019-2022 The Kraken authors. All rights reserved.
  * Copyright (C) 2022-present The WebF authors. All rights reserved.
  */
+import 'dart:async';
 import 'dart:math' as math;
 import 'dart:core';
 import 'dart:typed_data';
@@ -9,6 +10,7 @@ import 'dart:ui';
 import 'dart:ffi' as ffi;
 
 import 'package:flutter/painting.dart';
+import 'package:flutter/scheduler.dart';
 import 'package:webf/bridge.dart';
 import 'package:webf/foundation.dart';
 import 'package:webf/css.dart';
@@ -301,6 +303,26 @@ class CanvasRenderingContext2D extends DynamicBindingObject {
     methods['createPattern'] = BindingObjectMethodSync(
         call: (args) => createPattern(
             CanvasImageSource(args[0]), castToType<String>(args[1])));
+
+    // Only called by the native plugins, the bytes are only valid during the call so they are copied.
+    methods['__drawImageBytes__'] = BindingObjectMethodSync(
+        call: (args) => drawImageBytes(
+            Uint8List.fromList(args[0]),
+            castToType<num>(args[1]).toDouble(),
+            castToType<num>(args[2]).toDouble()));
+    methods['__putImageData__'] = BindingObjectMethodSync(
+        call: (args) => putImageData(
+            Uint8List.fromList(args[0]),
+            castToType<int>(args[1]),
+            castToType<int>(args[2]),
+            castToType<num>(args[3]).toDouble(),
+            castToType<num>(args[4]).toDouble()));
+    methods['__getImageData__'] = AsyncBindingObjectMethod(
+        call: (args) => getImageBytes(
+            castToType<int>(args[0]),
+            castToType<int>(args[1]),
+            castToType<int>(args[2]),
+            castToType<int>(args[3])));
   }
 
   @override
@@ -367,8 +389,10 @@ class CanvasRenderingContext2D extends DynamicBindingObject {
 
   @override
   Future<void> dispose() async {
+    _disposed = true;
     _actions.clear();
     _pendingActions.clear();
+    _actionsWaitingForImages.clear();
     super.dispose();
   }
 
@@ -389,7 +413,17 @@ class CanvasRenderingContext2D extends DynamicBindingObject {
   List<CanvasAction> _actions = [];
   List<CanvasAction> _pendingActions = [];
 
+  // The actions added while images are decoding, held back so that they are drawn after the images.
+  final List<CanvasAction> _actionsWaitingForImages = [];
+  int _decodingImageCount = 0;
+
+  bool _disposed = false;
+
   void addAction(CanvasAction action) {
+    if (_decodingImageCount > 0) {
+      _actionsWaitingForImages.add(action);
+      return;
+    }
     _actions.add(action);
     // Must trigger repaint after action
     canvas.repaintNotifier
@@ -716,6 +750,89 @@ class CanvasRenderingContext2D extends DynamicBindingObject {
     });
   }
 
+  // Draw the image decoded from the encoded bytes, such as PNG or JPEG, at its natural size.
+  void drawImageBytes(Uint8List bytes, double dx, double dy) {
+    _addImageAction(_decodeImageBytes(bytes), (Canvas canvas, Image image) {
+      canvas.drawImage(image, Offset(dx, dy), Paint());
+    });
+  }
+
+  // Draw the RGBA pixels, which replace the pixels of the canvas instead of being composited.
+  void putImageData(Uint8List data, int width, int height, double dx, double dy) {
+    Completer<Image> completer = Completer();
+    decodeImageFromPixels(data, width, height, PixelFormat.rgba8888, completer.complete);
+    _addImageAction(completer.future, (Canvas canvas, Image image) {
+      canvas.drawImage(image, Offset(dx, dy), Paint()..blendMode = BlendMode.src);
+    });
+  }
+
+  Future<Image> _decodeImageBytes(Uint8List bytes) async {
+    Codec codec = await instantiateImageCodec(bytes);
+    FrameInfo frame = await codec.getNextFrame();
+    codec.dispose();
+    return frame.image;
+  }
+
+  void _addImageAction(Future<Image> image, void Function(Canvas canvas, Image image) draw) {
+    Image? decodedImage;
+    _actionsWaitingForImages.add((Canvas canvas, Size size) {
+      if (decodedImage != null) draw(canvas, decodedImage!);
+    });
+    _decodingImageCount++;
+    image.then((Image value) {
+      decodedImage = value;
+    }).catchError((error, stack) {
+      // The image is skipped when the bytes can not be decoded, the same as a broken image.
+      print('Failed to decode the image drawn on canvas: $error\n$stack');
+    }).whenComplete(() {
+      // The actions are dropped when the context is disposed while decoding.
+      if (_disposed) return;
+      _decodingImageCount--;
+      if (_decodingImageCount == 0) {
+        List<CanvasAction> actions = List.of(_actionsWaitingForImages);
+        _actionsWaitingForImages.clear();
+        actions.forEach(addAction);
+      }
+    });
+  }
+
+  // Read the RGBA pixels, not premultiplied, of the rectangle of the canvas bitmap,
+  // once the actions added before are painted.
+  Future<Uint8List> getImageBytes(int sx, int sy, int sw, int sh) async {
+    if (!canvas.isRendererAttached) {
+      throw FlutterError('Failed to read the pixels: the canvas is not rendered.');
+    }
+    CanvasPainter painter = canvas.painter;
+    while (_decodingImageCount > 0 || _actions.isNotEmpty || painter.updatingSnapshot) {
+      await SchedulerBinding.instance.endOfFrame;
+      if (_disposed) {
+        throw FlutterError('Failed to read the pixels: the rendering context is disposed.');
+      }
+    }
+
+    final PictureRecorder pictureRecorder = PictureRecorder();
+    final Canvas recordCanvas = Canvas(pictureRecorder);
+    // Clone the snapshot, the painter disposes it when painting the next actions.
+    Image? snapshot = painter.snapshot?.clone();
+    if (snapshot != null) {
+      double scaleX = painter.scaleX;
+      double scaleY = painter.scaleY;
+      recordCanvas.drawImageRect(
+          snapshot,
+          Rect.fromLTWH(sx * scaleX, sy * scaleY, sw * scaleX, sh * scaleY),
+          Rect.fromLTWH(0, 0, sw.toDouble(), sh.toDouble()),
+          Paint()..blendMode = BlendMode.src);
+    }
+    final Picture picture = pictureRecorder.endRecording();
+    Image image = await picture.toImage(sw, sh);
+    picture.dispose();
+    snapshot?.dispose();
+
+    ByteData? bytes = await image.toByteData(format: ImageByteFormat.rawStraightRgba);
+    image.dispose();
+    return bytes!.buffer.asUint8List(bytes.offsetInBytes, bytes.lengthInBytes);
+  }
+
   void ellipse(double x, double y, double radiusX, double radiusY,
       double rotation, double startAngle, double endAngle,
       {bool anticlockwise = false}) {
@@ -24,6 +24,7 @@ class CanvasPainter extends CustomPainter {
   bool _shouldRepaint = false;
   // Indicate that snapshot is not generated yet, should not to perform next frame now.
   bool _updatingSnapshot = false;
+  bool get updatingSnapshot => _updatingSnapshot;
 
   bool get _shouldPainting => context != null && context!.actionCount > 0;
   bool get _hasSnapshot => context != null && _snapshot != null;
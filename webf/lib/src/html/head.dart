@@ -277,6 +277,53 @@ mixin StyleElementMixin on Element {
         setter: (value) => type = attributeToProperty<String>(value));
   }
 
+  @override
+  void initializeMethods(Map<String, BindingObjectMethod> methods) {
+    super.initializeMethods(methods);
+    // The style sheet API for the native plugins, the failures are returned as values since the native side
+    // can't catch the exceptions thrown here.
+    methods['__insertRule__'] = BindingObjectMethodSync(
+        call: (args) => _insertRule(castToType<String>(args[0]), castToType<num>(args[1]).toInt()));
+    methods['__deleteRule__'] = BindingObjectMethodSync(call: (args) => _deleteRule(castToType<num>(args[0]).toInt()));
+    methods['__cssRules__'] =
+        BindingObjectMethodSync(call: (_) => _styleSheet?.cssRules.map((rule) => rule.cssText).toList() ?? <String>[]);
+  }
+
+  // Returns the index of the inserted rules, -1 when the index is out of range and -2 when no rule could be parsed.
+  int _insertRule(String text, int index) {
+    CSSStyleSheet styleSheet = _styleSheet ??= CSSStyleSheet([]);
+    if (index < 0 || index > styleSheet.cssRules.length) {
+      return -1;
+    }
+    try {
+      styleSheet.insertRule(text, index);
+    } on FormatException {
+      return -2;
+    }
+    _styleSheetChanged();
+    return index;
+  }
+
+  // Returns false when there is no rule at the index.
+  bool _deleteRule(int index) {
+    CSSStyleSheet? styleSheet = _styleSheet;
+    if (styleSheet == null || index < 0 || index >= styleSheet.cssRules.length) {
+      return false;
+    }
+    styleSheet.deleteRule(index);
+    _styleSheetChanged();
+    return true;
+  }
+
+  void _styleSheetChanged() {
+    if (!isConnected || _type != _CSS_MIME) {
+      return;
+    }
+    ownerDocument.markElementStyleDirty(ownerDocument.documentElement!);
+    ownerDocument.styleNodeManager.appendPendingStyleSheet(_styleSheet!);
+    ownerDocument.updateStyleIfNeeded();
+  }
+
   void _recalculateStyle() {
     if (enableWebFProfileTracking) {
       WebFProfiler.instance.startTrackUICommandStep('$this.parseInlineStyle');
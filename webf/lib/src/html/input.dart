@@ -115,13 +115,84 @@ mixin BaseInputElement on WidgetElement {
         controller.value = TextEditingValue(text: value.toString());
       }
     }
+    _lastEditingValue = controller.value;
     hasDirtyValue = true;
   }
 
+  // The editing value when the last input event was dispatched, which is compared with the next one to describe the edit.
+  TextEditingValue _lastEditingValue = TextEditingValue.empty;
+  bool _isComposing = false;
+
+  // Dispatches the input event for an edit made by the user, surrounded by the composition events
+  // when the edit comes from an input method.
+  // https://w3c.github.io/input-events/#interface-InputEvent-Attributes
+  void dispatchInputEvents(TextEditingValue newValue) {
+    TextEditingValue oldValue = _lastEditingValue;
+    _lastEditingValue = newValue;
+    bool isComposing = newValue.composing.isValid && !newValue.composing.isCollapsed;
+    bool wasComposing = _isComposing;
+    _isComposing = isComposing;
+
+    String oldText = oldValue.text;
+    String newText = newValue.text;
+    int prefix = 0;
+    while (prefix < oldText.length && prefix < newText.length && oldText.codeUnitAt(prefix) == newText.codeUnitAt(prefix)) {
+      prefix++;
+    }
+    int suffix = 0;
+    while (suffix < oldText.length - prefix &&
+        suffix < newText.length - prefix &&
+        oldText.codeUnitAt(oldText.length - 1 - suffix) == newText.codeUnitAt(newText.length - 1 - suffix)) {
+      suffix++;
+    }
+    String inserted = newText.substring(prefix, newText.length - suffix);
+    bool hasRemoved = oldText.length - prefix - suffix > 0;
+
+    if (isComposing && !wasComposing) {
+      String replaced = oldValue.selection.isValid ? oldValue.selection.textInside(oldText) : '';
+      dispatchEvent(CompositionEvent(EVENT_COMPOSITION_START, data: replaced));
+    }
+
+    String inputType;
+    String data = inserted;
+    if (isComposing) {
+      data = newValue.composing.textInside(newText);
+      dispatchEvent(CompositionEvent(EVENT_COMPOSITION_UPDATE, data: data));
+      inputType = 'insertCompositionText';
+    } else if (inserted.isNotEmpty) {
+      inputType = 'insertText';
+    } else if (hasRemoved) {
+      // The caret stays before the removed text when deleting forward.
+      bool isForward = oldValue.selection.isValid &&
+          oldValue.selection.isCollapsed &&
+          oldValue.selection.baseOffset == prefix;
+      inputType = isForward ? 'deleteContentForward' : 'deleteContentBackward';
+    } else {
+      inputType = 'insertText';
+    }
+    dispatchEvent(InputEvent(inputType: inputType, data: data, isComposing: isComposing));
+
+    if (wasComposing && !isComposing) {
+      dispatchEvent(CompositionEvent(EVENT_COMPOSITION_END, data: inserted));
+    }
+  }
+
+  // Input methods may commit the composing text without changing it, which ends the composition without an edit.
+  void _handleEditingValueChange() {
+    TextEditingValue newValue = controller.value;
+    if (_isComposing && !newValue.composing.isValid && newValue.text == _lastEditingValue.text) {
+      String committed = _lastEditingValue.composing.isValid ? _lastEditingValue.composing.textInside(newValue.text) : '';
+      _lastEditingValue = newValue;
+      _isComposing = false;
+      dispatchEvent(CompositionEvent(EVENT_COMPOSITION_END, data: committed));
+    }
+  }
+
   @override
   void initState() {
     _focusNode ??= FocusNode();
     _focusNode!.addListener(handleFocusChange);
+    controller.addListener(_handleEditingValueChange);
   }
 
   @override
@@ -294,8 +365,7 @@ mixin BaseInputElement on WidgetElement {
     FlutterFormElementContext? formContext = context.dependOnInheritedWidgetOfExactType<FlutterFormElementContext>();
     onChanged(String newValue) {
       setState(() {
-        InputEvent inputEvent = InputEvent(inputType: '', data: newValue);
-        dispatchEvent(inputEvent);
+        dispatchInputEvents(controller.value);
       });
       hasDirtyValue = true;
     }
@@ -317,7 +387,8 @@ mixin BaseInputElement on WidgetElement {
                   onPressed: () {
                     setState(() {
                       controller.clear();
-                      InputEvent inputEvent = InputEvent(inputType: '', data: '');
+                      _lastEditingValue = controller.value;
+                      InputEvent inputEvent = InputEvent(inputType: 'deleteContent');
                       dispatchEvent(inputEvent);
                     });
                   },
@@ -383,6 +454,7 @@ mixin BaseInputElement on WidgetElement {
     super.didDetachRenderer();
     _focusNode?.removeListener(handleFocusChange);
     _focusNode?.unfocus();
+    controller.removeListener(_handleEditingValueChange);
   }
 
   FocusNode? _focusNode;
@@ -687,7 +687,9 @@ class WebFViewController implements WidgetsBindingObserver {
     }
   }
 
-  Future<void> handleNavigationAction(String? sourceUrl, String targetUrl, WebFNavigationType navigationType) async {
+  // When [replace] is true, the current history entry is replaced instead of adding a new one, as in `location.replace()`.
+  Future<void> handleNavigationAction(String? sourceUrl, String targetUrl, WebFNavigationType navigationType,
+      {bool replace = false}) async {
     WebFNavigationAction action = WebFNavigationAction(sourceUrl, targetUrl, navigationType);
 
     WebFNavigationDelegate _delegate = navigationDelegate!;
@@ -703,17 +705,26 @@ class WebFViewController implements WidgetsBindingObserver {
         targetPath = rootController.uriParser!.resolve(Uri.parse(base), Uri.parse(targetPath)).toString();
       }
 
-      if (action.target.trim().startsWith('#')) {
+      // Navigating to the current document with another fragment only changes the hash.
+      Uri targetUri = Uri.parse(targetPath);
+      bool isSameDocument = targetUri.hasFragment &&
+          targetUri.removeFragment() == Uri.parse(rootController.url).removeFragment();
+      if (action.target.trim().startsWith('#') || isSameDocument) {
         String oldUrl = rootController.url;
         HistoryModule historyModule = rootController.module.moduleManager.getModule('History')!;
-        historyModule.pushState(null, url: targetPath);
+        if (replace) {
+          historyModule.replaceState(null, url: targetPath);
+        } else {
+          historyModule.pushState(null, url: targetPath);
+        }
         window.dispatchEvent(HashChangeEvent(newUrl: targetPath, oldUrl: oldUrl));
         return;
       }
 
       switch (action.navigationType) {
         case WebFNavigationType.navigate:
-          await rootController.load(rootController.getPreloadBundleFromUrl(targetPath) ?? WebFBundle.fromUrl(targetPath));
+          await rootController.load(rootController.getPreloadBundleFromUrl(targetPath) ?? WebFBundle.fromUrl(targetPath),
+              replace: replace);
           break;
         case WebFNavigationType.reload:
           await rootController.reload();
@@ -1179,6 +1190,11 @@ class WebFController {
     historyModule.add(bundle);
   }
 
+  _replaceHistory(WebFBundle bundle) {
+    HistoryModule historyModule = module.moduleManager.getModule<HistoryModule>('History')!;
+    historyModule.replace(bundle);
+  }
+
   void _replaceCurrentHistory(WebFBundle bundle) {
     HistoryModule historyModule = module.moduleManager.getModule<HistoryModule>('History')!;
     previousHistoryStack.clear();
@@ -1226,7 +1242,8 @@ class WebFController {
     return completer.future;
   }
 
-  Future<void> load(WebFBundle bundle) async {
+  // When [replace] is true, the bundle replaces the current history entry instead of being added after it.
+  Future<void> load(WebFBundle bundle, {bool replace = false}) async {
     assert(!_view._disposed, 'WebF have already disposed');
 
     if (devToolsService != null) {
@@ -1246,7 +1263,11 @@ class WebFController {
 
     // Update entrypoint.
     _entrypoint = bundle;
-    _addHistory(bundle);
+    if (replace) {
+      _replaceHistory(bundle);
+    } else {
+      _addHistory(bundle);
+    }
 
     Completer completer = Completer();
 
@@ -1753,7 +1774,7 @@ class WebFController {
   }
 
   Future<void> dispatchWindowResizeEvent() async {
-    Event event = Event(EVENT_RESIZE);
+    UIEvent event = UIEvent(EVENT_RESIZE, view: _view.window);
     await _view.window.dispatchEvent(event);
   }
 }
@@ -36,7 +36,7 @@ class ClipBoardModule extends BaseModule {
       ClipBoardModule.writeText(params).then((_) {
         callback();
       }).catchError((e, stack) {
-        callback(error: '');
+        callback(error: '$e');
       });
     }
     return '';
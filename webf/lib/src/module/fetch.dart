@@ -84,22 +84,57 @@ class FetchModule extends BaseModule {
     });
   }
 
+  // The requests in flight which are sent with a requestId, removed once they complete.
+  final Map<int, HttpClientRequest> _requests = {};
+  // The ids aborted before their requests are opened.
+  final Set<int> _abortedRequestIds = {};
   HttpClientRequest? _currentRequest;
 
-  void _abortRequest() {
+  void _abortRequest(params) {
+    if (params is int) {
+      HttpClientRequest? request = _requests.remove(params);
+      if (request != null) {
+        request.abort();
+      } else {
+        _abortedRequestIds.add(params);
+      }
+      return;
+    }
+
+    // Requests without an id abort the latest request which is still in flight.
     _currentRequest?.abort();
     _currentRequest = null;
   }
 
+  void _completeRequest(int? requestId, HttpClientRequest? request) {
+    if (requestId != null) {
+      _requests.remove(requestId);
+      _abortedRequestIds.remove(requestId);
+    }
+    if (request != null && identical(_currentRequest, request)) {
+      _currentRequest = null;
+    }
+  }
+
   @override
   String invoke(String method, params, InvokeModuleCallback callback) {
     if (method == 'abortRequest') {
-      _abortRequest();
+      _abortRequest(params);
       return '';
     }
 
     Uri uri = _resolveUri(method);
-    Map<String, dynamic> options = params;
+    Map<String, dynamic> options;
+    dynamic body;
+    if (params is List) {
+      // A binary body is sent beside the options as [options, bytes]. The bytes are copied
+      // since the native buffer is only alive during this call.
+      options = params[0];
+      body = Uint8List.fromList(params[1]);
+    } else {
+      options = params;
+      body = options['body'];
+    }
 
     _handleError(Object error, StackTrace? stackTrace) {
       String errmsg = '$error';
@@ -119,8 +154,18 @@ class FetchModule extends BaseModule {
         currentNetworkOp = WebFProfiler.instance.startTrackNetwork(uri.toString());
       }
 
-      getRequest(uri, options['method'], options['headers'], options['body']).then((HttpClientRequest request) {
+      int? requestId = options['requestId'];
+      HttpClientRequest? currentRequest;
+      getRequest(uri, options['method'], options['headers'], body).then((HttpClientRequest request) {
         if (_disposed) return Future.value(null);
+        currentRequest = request;
+        if (requestId != null) {
+          if (_abortedRequestIds.remove(requestId)) {
+            request.abort();
+            return Future.value(null);
+          }
+          _requests[requestId] = request;
+        }
         _currentRequest = request;
         return request.close();
       }).then((HttpClientResponse? res) {
@@ -132,11 +177,16 @@ class FetchModule extends BaseModule {
         }
       }).then((Uint8List? bytes) {
         if (bytes != null) {
-          callback(data: [EMPTY_STRING, response?.statusCode, bytes]);
+          List<String> headers = [];
+          response?.headers.forEach((String name, List<String> values) {
+            headers..add(name)..add(values.join(', '));
+          });
+          callback(data: [EMPTY_STRING, response?.statusCode, bytes, headers]);
         } else {
           throw FlutterError('Failed to read response.');
         }
       }).catchError(_handleError).then((value) {
+        _completeRequest(requestId, currentRequest);
         if (enableWebFProfileTracking) {
           WebFProfiler.instance.finishTrackNetwork(currentNetworkOp!);
         }
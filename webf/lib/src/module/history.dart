@@ -40,6 +40,14 @@ class HistoryModule extends BaseModule {
     _addItem(history);
   }
 
+  // Replaces the current entry with [bundle], the history length is unchanged.
+  void replace(WebFBundle bundle) {
+    if (_previousStack.isNotEmpty) {
+      _previousStack.removeFirst();
+    }
+    _addItem(HistoryItem(bundle, null, true));
+  }
+
   void _addItem(HistoryItem historyItem) {
     _previousStack.addFirst(historyItem);
 
@@ -6,7 +6,9 @@ import 'dart:async';
 import 'package:archive/archive.dart';
 import 'package:path/path.dart' as path;
 import 'package:hive/hive.dart';
+import 'package:webf/dom.dart';
 import 'package:webf/foundation.dart';
+import 'package:webf/launcher.dart';
 import 'package:webf/module.dart';
 
 class LocalStorageModule extends BaseModule {
@@ -45,20 +47,33 @@ class LocalStorageModule extends BaseModule {
       case 'getItem':
         return box.get(params);
       case 'setItem':
+        String? oldValue = box.get(params[0]);
         box.put(params[0], params[1]);
+        if (oldValue != params[1]) {
+          _dispatchStorageEvent(params[0], oldValue, params[1]);
+        }
         break;
       case 'removeItem':
+        String? removedValue = box.get(params);
         box.delete(params);
+        if (removedValue != null) {
+          _dispatchStorageEvent(params, removedValue, null);
+        }
         break;
       case '_getAllKeys':
         List<dynamic> keys = box.keys.toList();
         return keys;
       case 'key':
+        if (params < 0 || params >= box.length) return null;
         return box.keyAt(params);
       case 'clear':
+        bool isEmpty = box.isEmpty;
         box.keys.forEach((key) {
           box.delete(key);
         });
+        if (!isEmpty) {
+          _dispatchStorageEvent(null, null, null);
+        }
         break;
       case 'length':
         return box.length;
@@ -68,4 +83,16 @@ class LocalStorageModule extends BaseModule {
 
     return '';
   }
+
+  // Notify the other contexts of the same origin, the context which changed the storage is not notified.
+  // https://html.spec.whatwg.org/multipage/webstorage.html#send-a-storage-notification
+  void _dispatchStorageEvent(String? key, String? oldValue, String? newValue) {
+    WebFController controller = moduleManager!.controller;
+    String url = controller.url;
+    WebFController.getControllerMap().values.forEach((WebFController? other) {
+      if (other == null || other == controller || other.disposed || other.origin != controller.origin) return;
+      other.view.window.dispatchEvent(StorageEvent(
+          key: key, oldValue: oldValue, newValue: newValue, url: url, storageArea: 'localStorage'));
+    });
+  }
 }
@@ -41,14 +41,16 @@ class NavigationModule extends BaseModule {
   void dispose() {}
 
   // Navigate kraken page to target Url.
-  Future<void> goTo(String targetUrl) async {
+  // When [replace] is true, the current history entry is replaced instead of adding a new one.
+  Future<void> goTo(String targetUrl, {bool replace = false}) async {
     String? sourceUrl = moduleManager!.controller.url;
 
     Uri targetUri = Uri.parse(targetUrl);
     Uri sourceUri = Uri.parse(sourceUrl);
 
     await moduleManager!.controller.view.handleNavigationAction(
-        sourceUrl, targetUrl, targetUri == sourceUri ? WebFNavigationType.reload : WebFNavigationType.navigate);
+        sourceUrl, targetUrl, targetUri == sourceUri ? WebFNavigationType.reload : WebFNavigationType.navigate,
+        replace: replace);
   }
 
   @override
@@ -56,6 +58,9 @@ class NavigationModule extends BaseModule {
     if (method == 'goTo') {
       assert(params is String, 'URL must be string.');
       goTo(params);
+    } else if (method == 'replace') {
+      assert(params is String, 'URL must be string.');
+      goTo(params, replace: true);
     }
 
     return '';
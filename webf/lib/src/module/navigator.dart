@@ -2,7 +2,7 @@
  * Copyright (C) 2019-2022 The Kraken authors. All rights reserved.
  * Copyright (C) 2022-present The WebF authors. All rights reserved.
  */
-import 'dart:io' show Platform;
+import 'dart:io' show NetworkInterface, Platform;
 import 'dart:ui';
 
 import 'package:webf/bridge.dart';
@@ -36,6 +36,13 @@ class NavigatorModule extends BaseModule {
         return getAppName();
       case 'getAppVersion':
         return getAppVersion();
+      case 'getOnLine':
+        getOnLine().then((bool onLine) {
+          callback(data: onLine);
+        }).catchError((e, stack) {
+          callback(error: '$e');
+        });
+        return '';
       default:
         return '';
     }
@@ -58,6 +65,11 @@ class NavigatorModule extends BaseModule {
     return Platform.numberOfProcessors.toString();
   }
 
+  static Future<bool> getOnLine() async {
+    List<NetworkInterface> interfaces = await NetworkInterface.list();
+    return interfaces.isNotEmpty;
+  }
+
   static String getAppName() {
     WebFInfo info = getWebFInfo();
     return info.appName;
@@ -32,6 +32,7 @@ class SessionStorageModule extends BaseModule {
         controller.sessionStorage.remove(params);
         break;
       case 'key':
+        if (params < 0 || params >= controller.sessionStorage.length) return null;
         return controller.sessionStorage.keys.elementAt(params);
       case 'clear':
         controller.sessionStorage.clear();
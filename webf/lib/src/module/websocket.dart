@@ -29,9 +29,12 @@ class WebSocketModule extends BaseModule {
   @override
   String invoke(String method, params, callback) {
     if (method == 'init') {
-      return init(params, (String id, Event event) {
+      // Native plugins pass [url, protocols] while the JavaScript polyfill passes the url only.
+      String url = params is List ? params[0] : params;
+      List<String>? protocols = params is List && params.length > 1 && params[1] is List ? List<String>.from(params[1]) : null;
+      return init(url, (String id, Event event) {
         moduleManager!.emitModuleEvent(name, event: event, data: id);
-      });
+      }, protocols: protocols);
     } else if (method == 'addEvent') {
       addEvent(params[0], params[1]);
     } else if (method == 'send') {
@@ -53,9 +56,9 @@ class WebSocketModule extends BaseModule {
   }
 
   String init(String url, WebSocketEventCallback callback,
-      {String? protocols}) {
+      {List<String>? protocols}) {
     var id = (_clientId++).toString();
-    WebSocket.connect(url,protocols: protocols != null ? [protocols] : null,
+    WebSocket.connect(url,protocols: protocols != null && protocols.isNotEmpty ? protocols : null,
         headers: {'origin': moduleManager!.controller.url})
         .then((webSocket) {
       IOWebSocketChannel client = IOWebSocketChannel(webSocket);
@@ -85,7 +88,8 @@ class WebSocketModule extends BaseModule {
     return id;
   }
 
-  void send(String? id, String? message) {
+  // The message is either a String or a Uint8List, which is sent as a binary frame.
+  void send(String? id, dynamic message) {
     IOWebSocketChannel? client = _clientMap[id!];
 
     if (client == null) return;
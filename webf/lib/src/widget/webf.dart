@@ -422,10 +422,22 @@ class WebFRootRenderObjectWidget extends MultiChildRenderObjectWidget {
 
     controller.name = shortHash(_webfWidget);
 
+    RenderViewportBox root = renderObject as RenderViewportBox;
+    bool viewportSizeChanged = false;
+    if (_webfWidget.viewportWidth != null && _webfWidget.viewportHeight != null) {
+      ui.Size viewportSize = ui.Size(_webfWidget.viewportWidth!, _webfWidget.viewportHeight!);
+      viewportSizeChanged = root.viewportSize != viewportSize;
+      root.viewportSize = viewportSize;
+    }
+
     // Should schedule to the next frame to make sure the RenderViewportBox(WebF's root renderObject) had been layout.
     SchedulerBinding.instance.addPostFrameCallback((_) async {
       // Sync viewport size to the documentElement.
       controller.view.document.initializeRootElementSize();
+      if (viewportSizeChanged) {
+        controller.view.window.resizeViewportRelatedElements();
+        await controller.dispatchWindowResizeEvent();
+      }
     });
   }
 